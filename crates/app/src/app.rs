@@ -1,3 +1,4 @@
+mod oauth;
 mod services;
 
 pub mod builder;
@@ -21,7 +22,7 @@ use sapic_main::{
 };
 use sapic_onboarding::OnboardingWindow;
 use sapic_platform::{
-    environment::environment_service_fs::EnvironmentServiceFs,
+    environment::environment_service_fs::EnvironmentServiceFs, oauth::OAuth2Client,
     project::project_service_fs::ProjectServiceFs,
 };
 use sapic_system::{
@@ -97,6 +98,7 @@ pub struct App<R: AppRuntime> {
     pub(crate) tao_handle: TauriAppHandle<R::EventLoop>,
     pub(crate) fs: Arc<dyn FileSystem>,
     pub(crate) keyring: Arc<dyn KeyringClient>,
+    pub(crate) oauth2_client: Arc<OAuth2Client>,
     pub(crate) storage: Arc<dyn KvStorage>,
     pub(crate) server_api_client: Arc<dyn ServerApiClient>,
     pub(crate) github_api_client: Arc<dyn GitHubApiClient>,
@@ -250,6 +252,7 @@ impl<R: AppRuntime> App<R> {
                 workspace,
                 workspace_ops,
                 environment_ops,
+                self.oauth2_client.clone(),
             )
             .await?;
 
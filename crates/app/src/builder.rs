@@ -1,3 +1,4 @@
+use joinerror::ResultExt;
 use moss_app_delegate::AppDelegate;
 use moss_applib::AppRuntime;
 use moss_fs::FileSystem;
@@ -7,6 +8,7 @@ use sapic_platform::{
     environment::environment_service_fs::EnvironmentServiceFs,
     extension::unpacker::ExtensionUnpackerImpl,
     language::loader::LanguagePackLoader,
+    oauth::OAuth2Client,
     theme::loader::ColorThemeLoader,
     workspace::{
         workspace_edit_backend::WorkspaceFsEditBackend, workspace_service_fs::WorkspaceServiceFs,
@@ -29,7 +31,7 @@ use sapic_system::{
 use std::sync::Arc;
 
 use crate::{
-    App, AppCommands, AppServices, command::CommandDecl,
+    App, AppCommands, AppServices, command::CommandDecl, oauth::AppOAuth2Prompt,
     services::extension_service::ExtensionService, windows::WindowManager,
 };
 
@@ -80,7 +82,11 @@ impl<R: AppRuntime> AppBuilder<R> {
         self
     }
 
-    pub async fn build(self, ctx: &R::AsyncContext, delegate: &AppDelegate<R>) -> App<R> {
+    pub async fn build(
+        self,
+        ctx: &R::AsyncContext,
+        delegate: &AppDelegate<R>,
+    ) -> joinerror::Result<App<R>> {
         // Ensure all the required folders exist
         let required_folders = vec![
             delegate.tmp_dir(),
@@ -164,12 +170,25 @@ impl<R: AppRuntime> AppBuilder<R> {
             extension_api_service,
         };
 
+        // Token requests must not follow redirects, see `oauth2::AsyncHttpClient`
+        let oauth2_http_client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .join_err::<()>("failed to build oauth2 http client")?;
+        let oauth2_client = OAuth2Client::new(
+            oauth2_http_client,
+            self.keyring.clone(),
+            Arc::new(AppOAuth2Prompt::new(delegate.clone())),
+        )
+        .into();
+
         let windows = WindowManager::new(self.storage.clone());
 
-        App {
+        Ok(App {
             tao_handle: delegate.handle(),
             fs: self.fs,
             keyring: self.keyring,
+            oauth2_client,
             storage: self.storage,
             server_api_client: self.server_api_client,
             github_api_client: self.github_api_client,
@@ -179,6 +198,6 @@ impl<R: AppRuntime> AppBuilder<R> {
             commands: self.commands,
             windows,
             services,
-        }
+        })
     }
 }
@@ -0,0 +1,53 @@
+use moss_app_delegate::{AppDelegate, broadcast::ToLocation};
+use moss_applib::AppRuntime;
+use sapic_base::{language::i18n::NO_TRANSLATE_KEY, localize};
+use sapic_platform::oauth::OAuth2Prompt;
+
+/// Opens the pages of the authorization server in the default browser, and tells the user
+/// what to do there with a notification of the app.
+pub(crate) struct AppOAuth2Prompt<R: AppRuntime> {
+    delegate: AppDelegate<R>,
+}
+
+impl<R: AppRuntime> AppOAuth2Prompt<R> {
+    pub fn new(delegate: AppDelegate<R>) -> Self {
+        Self { delegate }
+    }
+}
+
+impl<R: AppRuntime> OAuth2Prompt for AppOAuth2Prompt<R> {
+    fn open_authorization_url(&self, url: &str) {
+        if let Err(e) = webbrowser::open(url) {
+            tracing::warn!("failed to open the oauth2 authorization url: {}", e);
+
+            let _ = self.delegate.emit_oneshot(ToLocation::Notification {
+                activity_id: "oauth2_authorization",
+                title: localize!(
+                    "app.oauth2_authorization.title",
+                    "Authorize access in your browser"
+                ),
+                detail: Some(localize!(NO_TRANSLATE_KEY, url)),
+            });
+        }
+    }
+
+    fn show_device_code(&self, verification_uri: &str, user_code: &str) {
+        tracing::info!(
+            "waiting for the oauth2 device code to be entered at {}",
+            verification_uri
+        );
+
+        let _ = self.delegate.emit_oneshot(ToLocation::Notification {
+            activity_id: "oauth2_device_code",
+            title: localize!(
+                NO_TRANSLATE_KEY,
+                format!("Enter the code {} to authorize access", user_code)
+            ),
+            detail: Some(localize!(NO_TRANSLATE_KEY, verification_uri)),
+        });
+
+        if let Err(e) = webbrowser::open(verification_uri) {
+            tracing::warn!("failed to open the oauth2 verification uri: {}", e);
+        }
+    }
+}
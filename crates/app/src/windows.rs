@@ -11,6 +11,7 @@ use sapic_main::{
     workspace_ops::MainWindowWorkspaceOps,
 };
 use sapic_onboarding::{ONBOARDING_WINDOW_LABEL, OnboardingWindow};
+use sapic_platform::oauth::OAuth2Client;
 use sapic_welcome::{
     WELCOME_WINDOW_LABEL, WelcomeWindow, environment_ops::WelcomeWindowEnvironmentOps,
     workspace_ops::WelcomeWindowWorkspaceOps,
@@ -250,6 +251,7 @@ impl<R: AppRuntime> WindowManager<R> {
         workspace: Arc<dyn Workspace>,
         workspace_ops: MainWindowWorkspaceOps,
         environment_ops: MainWindowEnvironmentOps,
+        oauth2_client: Arc<OAuth2Client>,
     ) -> joinerror::Result<MainWindow<R>> {
         let window = MainWindow::new(
             delegate,
@@ -258,6 +260,7 @@ impl<R: AppRuntime> WindowManager<R> {
             workspace.clone(),
             workspace_ops,
             environment_ops,
+            oauth2_client,
         )
         .await?;
 
@@ -139,7 +139,8 @@ pub async fn setup_test_app() -> (
             language_registry,
         )
         .build(&app_init_ctx, &delegate)
-        .await;
+        .await
+        .unwrap();
 
         app
     };
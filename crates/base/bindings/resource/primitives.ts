@@ -13,4 +13,19 @@ export type ResourceKind = "Dir" | "Item" | "Case";
 /**
  * @category Primitive
  */
-export type ResourceProtocol = "Get" | "Post" | "Put" | "Delete" | "WebSocket" | "Graphql" | "Grpc";
+export type ResourceProtocol =
+  | "Get"
+  | "Head"
+  | "Post"
+  | "Put"
+  | "Patch"
+  | "Delete"
+  | "Connect"
+  | "Options"
+  | "Trace"
+  | { "Custom": string }
+  | "WebSocket"
+  | "Graphql"
+  | "Grpc"
+  | "Amqp"
+  | "Mqtt";
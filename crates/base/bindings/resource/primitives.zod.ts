@@ -7,10 +7,20 @@ export const resourceKindSchema = z.union([z.literal("Dir"), z.literal("Item"),
 
 export const resourceProtocolSchema = z.union([
   z.literal("Get"),
+  z.literal("Head"),
   z.literal("Post"),
   z.literal("Put"),
+  z.literal("Patch"),
   z.literal("Delete"),
+  z.literal("Connect"),
+  z.literal("Options"),
+  z.literal("Trace"),
+  z.object({
+    "Custom": z.string(),
+  }),
   z.literal("WebSocket"),
   z.literal("Graphql"),
   z.literal("Grpc"),
+  z.literal("Amqp"),
+  z.literal("Mqtt"),
 ]);
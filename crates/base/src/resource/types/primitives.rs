@@ -1,5 +1,6 @@
 use moss_id_macro::ids;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use ts_rs::TS;
 
 ids!([ResourceId]);
@@ -38,24 +39,130 @@ pub enum ResourceKind {
 #[ts(export, export_to = "resource/primitives.ts")]
 pub enum ResourceProtocol {
     Get,
+    Head,
     Post,
     Put,
+    Patch,
     Delete,
+    Connect,
+    Options,
+    Trace,
+    /// Any other method, such as the WebDAV ones, given as it's sent on the wire.
+    Custom(String),
     WebSocket,
     Graphql,
     Grpc,
+    Amqp,
+    Mqtt,
+}
+
+impl ResourceProtocol {
+    /// Whether the protocol is an HTTP method, rather than a protocol of its own.
+    pub fn is_http(&self) -> bool {
+        !matches!(
+            self,
+            ResourceProtocol::WebSocket
+                | ResourceProtocol::Graphql
+                | ResourceProtocol::Grpc
+                | ResourceProtocol::Amqp
+                | ResourceProtocol::Mqtt
+        )
+    }
+
+    /// Fails if a custom method isn't a valid RFC 9110 token, or spells one of the built-in protocols.
+    pub fn validate(&self) -> Result<(), String> {
+        let ResourceProtocol::Custom(method) = self else {
+            return Ok(());
+        };
+
+        if method.is_empty() || !method.bytes().all(is_tchar) {
+            return Err(format!("invalid http method `{}`", method));
+        }
+        if RESERVED_METHODS
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(method))
+        {
+            return Err(format!(
+                "`{}` is a built-in protocol and can't be used as a custom method",
+                method
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// Compared case-insensitively, so that custom methods can't be confused with the built-in ones
+const RESERVED_METHODS: [&str; 14] = [
+    "GET",
+    "HEAD",
+    "POST",
+    "PUT",
+    "PATCH",
+    "DELETE",
+    "CONNECT",
+    "OPTIONS",
+    "TRACE",
+    "WEBSOCKET",
+    "GRAPHQL",
+    "GRPC",
+    "AMQP",
+    "MQTT",
+];
+
+fn is_tchar(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&c)
 }
 
 impl ToString for ResourceProtocol {
     fn to_string(&self) -> String {
         match self {
             ResourceProtocol::Get => "Get".to_string(),
+            ResourceProtocol::Head => "Head".to_string(),
             ResourceProtocol::Post => "Post".to_string(),
             ResourceProtocol::Put => "Put".to_string(),
+            ResourceProtocol::Patch => "Patch".to_string(),
             ResourceProtocol::Delete => "Delete".to_string(),
+            ResourceProtocol::Connect => "Connect".to_string(),
+            ResourceProtocol::Options => "Options".to_string(),
+            ResourceProtocol::Trace => "Trace".to_string(),
+            ResourceProtocol::Custom(method) => method.clone(),
             ResourceProtocol::WebSocket => "WebSocket".to_string(),
             ResourceProtocol::Graphql => "Graphql".to_string(),
             ResourceProtocol::Grpc => "Grpc".to_string(),
+            ResourceProtocol::Amqp => "Amqp".to_string(),
+            ResourceProtocol::Mqtt => "Mqtt".to_string(),
         }
     }
 }
+
+/// Parses the representation produced by `to_string`, anything unknown being a custom method.
+impl FromStr for ResourceProtocol {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let protocol = match s {
+            "Get" => ResourceProtocol::Get,
+            "Head" => ResourceProtocol::Head,
+            "Post" => ResourceProtocol::Post,
+            "Put" => ResourceProtocol::Put,
+            "Patch" => ResourceProtocol::Patch,
+            "Delete" => ResourceProtocol::Delete,
+            "Connect" => ResourceProtocol::Connect,
+            "Options" => ResourceProtocol::Options,
+            "Trace" => ResourceProtocol::Trace,
+            "WebSocket" => ResourceProtocol::WebSocket,
+            "Graphql" => ResourceProtocol::Graphql,
+            "Grpc" => ResourceProtocol::Grpc,
+            "Amqp" => ResourceProtocol::Amqp,
+            "Mqtt" => ResourceProtocol::Mqtt,
+            _ => {
+                let protocol = ResourceProtocol::Custom(s.to_string());
+                protocol.validate()?;
+                protocol
+            }
+        };
+
+        Ok(protocol)
+    }
+}
@@ -0,0 +1,181 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "@repo/moss-bindingutils";
+import type {
+  GrpcMetadataEntry,
+  QueueMessageInfo,
+  RecordedExchangeInfo,
+  ResponseHeader,
+  SseEventInfo,
+  WebSocketFrameInfo,
+} from "./types";
+
+/**
+ * @category Event
+ */
+export type ExecuteResourceEvent =
+  | { "status": { status: number; statusText: string } }
+  | { "headers": { headers: Array<ResponseHeader> } }
+  | { "body": { chunk: Array<number> } };
+
+/**
+ * @category Event
+ */
+export type GraphqlEvent = {
+  /**
+   * Sent for the response of a query or mutation, and for every result of a subscription.
+   */
+  "result": { result: JsonValue };
+};
+
+/**
+ * @category Event
+ */
+export type GrpcEvent =
+  | {
+    /**
+     * Sent once the server responded with its initial metadata.
+     */
+    "headers": { metadata: Array<GrpcMetadataEntry> };
+  }
+  | {
+    /**
+     * Sent for every response message, as JSON.
+     */
+    "message": { message: JsonValue };
+  };
+
+/**
+ * @category Event
+ */
+export type MockServerEvent =
+  | {
+    /**
+     * Sent once, as soon as the server is listening.
+     */
+    "started": { port: number };
+  }
+  | {
+    /**
+     * Sent for every request served, when requests are logged.
+     */
+    "request": {
+      method: string;
+      /**
+       * Path of the request, including its query.
+       */
+      path: string;
+      status: number;
+      /**
+       * The endpoint the request was routed to, if any.
+       */
+      resourceId?: string;
+      /**
+       * The case whose expected response was sent, if any.
+       */
+      caseId?: string;
+      /**
+       * Time elapsed from receiving the request until responding, latency included.
+       */
+      durationMs: number;
+    };
+  };
+
+/**
+ * @category Event
+ */
+export type QueueEvent =
+  | {
+    /**
+     * Sent once the broker accepted the subscription, before any message.
+     */
+    "subscribed": {
+      /**
+       * Queue consumed from for AMQP, topic filter subscribed to for MQTT.
+       */
+      source: string;
+    };
+  }
+  | {
+    /**
+     * Sent for every message received.
+     */
+    "message": { message: QueueMessageInfo };
+  };
+
+/**
+ * @category Event
+ */
+export type RecordingProxyEvent =
+  | {
+    /**
+     * Sent once, as soon as the proxy is listening.
+     */
+    "started": {
+      port: number;
+      /**
+       * The certificate of the authority https traffic is intercepted with, in PEM format.
+       * Clients must trust it for their https requests to be recorded.
+       */
+      caCertificatePath: string;
+    };
+  }
+  | {
+    /**
+     * Sent for every exchange relayed by the proxy.
+     */
+    "exchange": { exchange: RecordedExchangeInfo };
+  };
+
+/**
+ * @category Event
+ */
+export type SseEvent =
+  | {
+    /**
+     * Sent every time the server accepts a connection, with the headers of its response.
+     */
+    "connected": { status: number; headers: Array<ResponseHeader> };
+  }
+  | {
+    /**
+     * Sent for every event dispatched by the server.
+     */
+    "event": { event: SseEventInfo };
+  }
+  | {
+    /**
+     * Sent before waiting to reconnect, once the stream ended or the connection was lost.
+     */
+    "reconnecting": {
+      /**
+       * Attempts to reconnect in a row, starting from 1.
+       */
+      attempt: number;
+      delayMs: number;
+      lastEventId?: string;
+    };
+  };
+
+/**
+ * @category Event
+ */
+export type WebSocketEvent =
+  | {
+    /**
+     * Sent for every frame sent or received over the session.
+     */
+    "frame": { frame: WebSocketFrameInfo };
+  }
+  | {
+    /**
+     * Sent once, when the connection is closed by either side or lost.
+     */
+    "closed": {
+      code?: number;
+      reason?: string;
+      /**
+       * Why the connection was lost, when it wasn't closed by either side.
+       */
+      error?: string;
+    };
+  };
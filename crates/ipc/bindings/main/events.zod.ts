@@ -0,0 +1,130 @@
+// Generated by ts-to-zod
+import { jsonValueSchema } from "@repo/moss-bindingutils";
+import { z } from "zod";
+import {
+  grpcMetadataEntrySchema,
+  queueMessageInfoSchema,
+  recordedExchangeInfoSchema,
+  responseHeaderSchema,
+  sseEventInfoSchema,
+  webSocketFrameInfoSchema,
+} from "./types.zod";
+
+export const executeResourceEventSchema = z.union([
+  z.object({
+    "status": z.object({
+      status: z.number(),
+      statusText: z.string(),
+    }),
+  }),
+  z.object({
+    "headers": z.object({
+      headers: z.array(responseHeaderSchema),
+    }),
+  }),
+  z.object({
+    "body": z.object({
+      chunk: z.array(z.number()),
+    }),
+  }),
+]);
+
+export const mockServerEventSchema = z.union([
+  z.object({
+    "started": z.object({
+      port: z.number(),
+    }),
+  }),
+  z.object({
+    "request": z.object({
+      method: z.string(),
+      path: z.string(),
+      status: z.number(),
+      resourceId: z.string().optional(),
+      caseId: z.string().optional(),
+      durationMs: z.number(),
+    }),
+  }),
+]);
+
+export const queueEventSchema = z.union([
+  z.object({
+    "subscribed": z.object({
+      source: z.string(),
+    }),
+  }),
+  z.object({
+    "message": z.object({
+      message: queueMessageInfoSchema,
+    }),
+  }),
+]);
+
+export const recordingProxyEventSchema = z.union([
+  z.object({
+    "started": z.object({
+      port: z.number(),
+      caCertificatePath: z.string(),
+    }),
+  }),
+  z.object({
+    "exchange": z.object({
+      exchange: recordedExchangeInfoSchema,
+    }),
+  }),
+]);
+
+export const sseEventSchema = z.union([
+  z.object({
+    "connected": z.object({
+      status: z.number(),
+      headers: z.array(responseHeaderSchema),
+    }),
+  }),
+  z.object({
+    "event": z.object({
+      event: sseEventInfoSchema,
+    }),
+  }),
+  z.object({
+    "reconnecting": z.object({
+      attempt: z.number(),
+      delayMs: z.number(),
+      lastEventId: z.string().optional(),
+    }),
+  }),
+]);
+
+export const webSocketEventSchema = z.union([
+  z.object({
+    "frame": z.object({
+      frame: webSocketFrameInfoSchema,
+    }),
+  }),
+  z.object({
+    "closed": z.object({
+      code: z.number().optional(),
+      reason: z.string().optional(),
+      error: z.string().optional(),
+    }),
+  }),
+]);
+
+export const graphqlEventSchema = z.object({
+  "result": z.object({
+    result: jsonValueSchema,
+  }),
+});
+
+export const grpcEventSchema = z.union([
+  z.object({
+    "headers": z.object({
+      metadata: z.array(grpcMetadataEntrySchema),
+    }),
+  }),
+  z.object({
+    "message": z.object({
+      message: jsonValueSchema,
+    }),
+  }),
+]);
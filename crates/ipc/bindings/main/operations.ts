@@ -1,5 +1,118 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
-import type { ListProjectResourceItem, ListProjectResourcesMode, OpenInTarget } from "./types";
+import { ResourceProtocol } from "@repo/base";
+import type { JsonValue } from "@repo/moss-bindingutils";
+import type { ImportSkippedItem } from "../types";
+import type {
+  GraphqlEncoding,
+  GraphqlValidationError,
+  GrpcMetadataEntry,
+  GrpcServiceInfo,
+  ListProjectResourceItem,
+  ListProjectResourcesMode,
+  MockEndpointOptions,
+  OpenInTarget,
+  OutgoingWebSocketMessage,
+  PromotedExchange,
+  RecordedExchangeInfo,
+  ResolvedBody,
+  ResolvedParam,
+  RunResultInfo,
+  RunStatus,
+  SnippetLanguage,
+  SseEventInfo,
+  UnresolvedVariable,
+  VariableSubstitution,
+  WebSocketFrameInfo,
+} from "./types";
+
+/**
+ * @category Operation
+ */
+export type ExecuteResourceInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ExecuteResourceOutput = {
+  status: number;
+  /**
+   * Total size of the response body in bytes.
+   */
+  size: number;
+  /**
+   * Time elapsed from sending the request until the body was fully read.
+   */
+  durationMs: number;
+};
+
+/**
+ * @category Operation
+ */
+export type ExportHttpFileInput = {
+  projectId: string;
+  /**
+   * The directory whose endpoints are written, including the ones of its subdirectories.
+   */
+  resourceId: string;
+  /**
+   * Path to the folder containing the output `.http` file
+   */
+  destination: string;
+};
+
+/**
+ * @category Operation
+ */
+export type ExportHttpFileOutput = {
+  /**
+   * Path to the created `.http` file, named after the directory.
+   */
+  path: string;
+};
+
+/**
+ * @category Operation
+ */
+export type GenerateSnippetInput = {
+  projectId: string;
+  resourceId: string;
+  language: SnippetLanguage;
+  /**
+   * Substitutes the variables of the active environments, which are otherwise
+   * left as `{{name}}` placeholders.
+   */
+  resolveVariables?: boolean;
+};
+
+/**
+ * @category Operation
+ */
+export type GenerateSnippetOutput = { snippet: string };
+
+/**
+ * @category Operation
+ */
+export type ImportCurlInput = {
+  projectId: string;
+  /**
+   * Directory of the project the endpoint is created in.
+   */
+  path: string;
+  order: number;
+  /**
+   * Defaults to the method and the url of the command.
+   */
+  name?: string;
+  /**
+   * The command line, as copied from a terminal or the devtools of a browser.
+   */
+  command: string;
+};
+
+/**
+ * @category Operation
+ */
+export type ImportCurlOutput = { id: string; name: string; skipped: Array<ImportSkippedItem> };
 
 /**
  * @category Operation
@@ -54,3 +167,439 @@ export type MainWindow_UpdateWorkspaceInput = {
  * @category Operation
  */
 export type MainWindow_UpdateWorkspaceOutput = Record<string, never>;
+
+/**
+ * @category Operation
+ */
+export type StartMockServerInput = {
+  projectId: string;
+  /**
+   * Port to listen on, on the loopback interface. A free one is picked when omitted.
+   */
+  port?: number;
+  /**
+   * Delay before every response is sent, in milliseconds.
+   */
+  latencyMs?: number;
+  /**
+   * Overrides the status of every response routed to an endpoint, unless the endpoint
+   * overrides it.
+   */
+  status?: number;
+  endpoints?: Array<MockEndpointOptions>;
+  /**
+   * Reports every request served with a `request` event.
+   */
+  logRequests?: boolean;
+};
+
+/**
+ * @category Operation
+ */
+export type StartMockServerOutput = {
+  /**
+   * Number of requests served until the server was stopped.
+   */
+  requests: number;
+};
+
+/**
+ * @category Operation
+ */
+export type StartRecordingProxyInput = {
+  /**
+   * Port to listen on, on the loopback interface. A free one is picked when omitted.
+   */
+  port?: number;
+  /**
+   * Relays requests to servers whose certificate cannot be verified, such as
+   * self-signed ones.
+   */
+  acceptInvalidCerts?: boolean;
+  /**
+   * Hosts whose https traffic is recorded, along with their subdomains. The certificate
+   * authority can only impersonate these and the loopback hosts, so tunnels to any other
+   * host are passed through without being recorded.
+   */
+  interceptedHosts?: Array<string>;
+};
+
+/**
+ * @category Operation
+ */
+export type StartRecordingProxyOutput = {
+  /**
+   * Number of exchanges recorded until the proxy was stopped.
+   */
+  exchanges: number;
+};
+
+/**
+ * @category Operation
+ */
+export type ListRecordedExchangesOutput = {
+  /**
+   * The exchanges recorded from this window, from the oldest to the most recent.
+   */
+  exchanges: Array<RecordedExchangeInfo>;
+};
+
+/**
+ * @category Operation
+ */
+export type PromoteRecordedExchangesInput = {
+  projectId: string;
+  /**
+   * Directory of the project the endpoints are created in.
+   */
+  path: string;
+  exchangeIds: Array<string>;
+};
+
+/**
+ * @category Operation
+ */
+export type PromoteRecordedExchangesOutput = {
+  /**
+   * One per exchange, in the order they were given.
+   */
+  exchanges: Array<PromotedExchange>;
+};
+
+/**
+ * @category Operation
+ */
+export type ResolveResourceInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ResolveResourceOutput = {
+  protocol?: ResourceProtocol;
+  url: string;
+  headers: Array<ResolvedParam>;
+  pathParams: Array<ResolvedParam>;
+  queryParams: Array<ResolvedParam>;
+  body?: ResolvedBody;
+  substitutions: Array<VariableSubstitution>;
+  /**
+   * Variables without a value are left in place as written.
+   */
+  unresolved: Array<UnresolvedVariable>;
+};
+
+/**
+ * @category Operation
+ */
+export type RunCollectionInput = {
+  projectId: string;
+  /**
+   * The directory whose endpoints are run, its subdirectories included.
+   * The whole project is run when it's empty.
+   */
+  path: string;
+  /**
+   * A CSV file with a header row, or a JSON file holding an array of objects. The endpoints
+   * are run once for every row, with its columns bound as variables.
+   */
+  dataFile?: string;
+  /**
+   * Number of endpoints sent at once. The endpoints are sent one after another when omitted.
+   */
+  concurrency?: number;
+  /**
+   * Time to wait for the response of every endpoint, in milliseconds. Defaults to 30 seconds.
+   */
+  requestTimeoutMs?: number;
+};
+
+/**
+ * @category Operation
+ */
+export type RunCollectionOutput = {
+  status: RunStatus;
+  iterations: number;
+  passed: number;
+  failed: number;
+  /**
+   * Number of endpoints left unsent once the run was canceled or timed out.
+   */
+  skipped: number;
+  durationMs: number;
+  /**
+   * Results of the endpoints sent, in the order they are run.
+   */
+  results: Array<RunResultInfo>;
+};
+
+/**
+ * @category Operation
+ */
+export type ConnectWebSocketInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ConnectWebSocketOutput = {
+  sessionId: string;
+  /**
+   * The subprotocol the server picked among the ones offered.
+   */
+  protocol?: string;
+};
+
+/**
+ * @category Operation
+ */
+export type SendWebSocketMessageInput = { sessionId: string; message: OutgoingWebSocketMessage };
+
+/**
+ * @category Operation
+ */
+export type SendWebSocketMessageOutput = { frame: WebSocketFrameInfo };
+
+/**
+ * @category Operation
+ */
+export type CloseWebSocketInput = {
+  sessionId: string;
+  /**
+   * Defaults to 1000, a normal closure.
+   */
+  code?: number;
+  reason?: string;
+};
+
+/**
+ * @category Operation
+ */
+export type CloseWebSocketOutput = Record<string, never>;
+
+/**
+ * @category Operation
+ */
+export type ListWebSocketFramesInput = { sessionId: string };
+
+/**
+ * @category Operation
+ */
+export type ListWebSocketFramesOutput = {
+  /**
+   * The most recent frames of the session, from the oldest to the most recent.
+   */
+  frames: Array<WebSocketFrameInfo>;
+  open: boolean;
+};
+
+/**
+ * @category Operation
+ */
+export type IntrospectGraphqlSchemaInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type IntrospectGraphqlSchemaOutput = {
+  /**
+   * The `__schema` object returned by the endpoint, as cached for the resource.
+   */
+  schema: JsonValue;
+};
+
+/**
+ * @category Operation
+ */
+export type ValidateGraphqlOperationInput = {
+  projectId: string;
+  resourceId: string;
+  /**
+   * Document to validate instead of the saved one, such as the one being edited.
+   */
+  query?: string;
+};
+
+/**
+ * @category Operation
+ */
+export type ValidateGraphqlOperationOutput = {
+  /**
+   * Problems found against the cached schema, in the order they appear in the document.
+   */
+  errors: Array<GraphqlValidationError>;
+};
+
+/**
+ * @category Operation
+ */
+export type ExecuteGraphqlInput = {
+  projectId: string;
+  resourceId: string;
+  /**
+   * How queries and mutations are sent, defaults to `post`. Subscriptions are always sent
+   * over the graphql-ws protocol.
+   */
+  encoding?: GraphqlEncoding;
+};
+
+/**
+ * @category Operation
+ */
+export type ExecuteGraphqlOutput = {
+  /**
+   * Status of the response, absent for subscriptions.
+   */
+  status?: number;
+  /**
+   * Number of results received.
+   */
+  results: number;
+  /**
+   * Time elapsed from sending the operation until its last result was received.
+   */
+  durationMs: number;
+};
+
+/**
+ * @category Operation
+ */
+export type ListGrpcServicesInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ListGrpcServicesOutput = {
+  /**
+   * Services described by the `.proto` files of the endpoint, or by its server reflection.
+   */
+  services: Array<GrpcServiceInfo>;
+};
+
+/**
+ * @category Operation
+ */
+export type ExecuteGrpcInput = {
+  projectId: string;
+  resourceId: string;
+  /**
+   * Request messages to send instead of the saved ones, such as the ones being edited.
+   */
+  messages?: Array<JsonValue>;
+};
+
+/**
+ * @category Operation
+ */
+export type ExecuteGrpcOutput = {
+  /**
+   * Status code the call ended with, `0` when it succeeded.
+   */
+  code: number;
+  /**
+   * Message of the status, when the call failed.
+   */
+  message?: string;
+  /**
+   * Number of response messages received.
+   */
+  received: number;
+  /**
+   * Metadata sent by the server once the call ended.
+   */
+  trailers: Array<GrpcMetadataEntry>;
+  /**
+   * Time elapsed from starting the call until it ended.
+   */
+  durationMs: number;
+};
+
+/**
+ * @category Operation
+ */
+export type ExecuteSseInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ExecuteSseOutput = {
+  /**
+   * Number of events received, across reconnections.
+   */
+  received: number;
+  reconnects: number;
+  lastEventId?: string;
+  /**
+   * Time elapsed from sending the request until the stream stopped.
+   */
+  durationMs: number;
+};
+
+/**
+ * @category Operation
+ */
+export type ListSseEventsInput = { projectId: string; resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ListSseEventsOutput = {
+  /**
+   * The most recent events recorded in the last stream of the endpoint, from the oldest to
+   * the most recent.
+   */
+  events: Array<SseEventInfo>;
+};
+
+/**
+ * @category Operation
+ */
+export type PublishQueueMessageInput = {
+  projectId: string;
+  resourceId: string;
+  /**
+   * Published instead of the payload of the endpoint.
+   */
+  payload?: string;
+};
+
+/**
+ * @category Operation
+ */
+export type PublishQueueMessageOutput = {
+  /**
+   * Routing key or topic the message was published with, after rendering its variables.
+   */
+  topic: string;
+  /**
+   * Size of the payload, in bytes.
+   */
+  size: number;
+  /**
+   * Time elapsed from connecting to the broker until it acknowledged the message, as
+   * required by the quality of service level for MQTT.
+   */
+  durationMs: number;
+};
+
+/**
+ * @category Operation
+ */
+export type SubscribeQueueInput = {
+  projectId: string;
+  resourceId: string;
+  /**
+   * Stops the subscription once that many messages were received. Otherwise it goes on
+   * until the request is canceled.
+   */
+  maxMessages?: number;
+};
+
+/**
+ * @category Operation
+ */
+export type SubscribeQueueOutput = {
+  received: number;
+  /**
+   * Time elapsed from connecting to the broker until the subscription stopped.
+   */
+  durationMs: number;
+};
@@ -1,6 +1,81 @@
 // Generated by ts-to-zod
+import { resourceProtocolSchema } from "@repo/base";
+import { jsonValueSchema } from "@repo/moss-bindingutils";
 import { z } from "zod";
-import { listProjectResourceItemSchema, listProjectResourcesModeSchema, openInTargetSchema } from "./types.zod";
+import { importSkippedItemSchema } from "../types.zod";
+import {
+  graphqlEncodingSchema,
+  graphqlValidationErrorSchema,
+  grpcMetadataEntrySchema,
+  grpcServiceInfoSchema,
+  listProjectResourceItemSchema,
+  listProjectResourcesModeSchema,
+  mockEndpointOptionsSchema,
+  openInTargetSchema,
+  outgoingWebSocketMessageSchema,
+  promotedExchangeSchema,
+  recordedExchangeInfoSchema,
+  resolvedBodySchema,
+  resolvedParamSchema,
+  runResultInfoSchema,
+  runStatusSchema,
+  snippetLanguageSchema,
+  sseEventInfoSchema,
+  unresolvedVariableSchema,
+  variableSubstitutionSchema,
+  webSocketFrameInfoSchema,
+} from "./types.zod";
+
+export const executeResourceInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
+
+export const executeResourceOutputSchema = z.object({
+  status: z.number(),
+  size: z.number(),
+  durationMs: z.number(),
+});
+
+export const exportHttpFileInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  destination: z.string(),
+});
+
+export const exportHttpFileOutputSchema = z.object({
+  path: z.string(),
+});
+
+export const generateSnippetInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  language: snippetLanguageSchema,
+  resolveVariables: z.boolean().optional(),
+});
+
+export const generateSnippetOutputSchema = z.object({
+  snippet: z.string(),
+});
+
+export const importCurlInputSchema = z.object({
+  projectId: z.string(),
+  path: z.string(),
+  order: z.number(),
+  name: z.string().optional(),
+  command: z.string(),
+});
+
+export const importCurlOutputSchema = z.object({
+  id: z.string(),
+  name: z.string(),
+  skipped: z.array(importSkippedItemSchema),
+});
+
+export const resolveResourceInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
 
 export const mainWindowCreateWorkspaceOutputSchema = z.object({
   id: z.string(),
@@ -30,3 +105,203 @@ export const mainWindowOpenWorkspaceInputSchema = z.object({
   id: z.string(),
   openInTarget: openInTargetSchema,
 });
+
+export const resolveResourceOutputSchema = z.object({
+  protocol: resourceProtocolSchema.optional(),
+  url: z.string(),
+  headers: z.array(resolvedParamSchema),
+  pathParams: z.array(resolvedParamSchema),
+  queryParams: z.array(resolvedParamSchema),
+  body: resolvedBodySchema.optional(),
+  substitutions: z.array(variableSubstitutionSchema),
+  unresolved: z.array(unresolvedVariableSchema),
+});
+
+export const startMockServerInputSchema = z.object({
+  projectId: z.string(),
+  port: z.number().optional(),
+  latencyMs: z.number().optional(),
+  status: z.number().optional(),
+  endpoints: z.array(mockEndpointOptionsSchema).optional(),
+  logRequests: z.boolean().optional(),
+});
+
+export const startMockServerOutputSchema = z.object({
+  requests: z.number(),
+});
+
+export const startRecordingProxyInputSchema = z.object({
+  port: z.number().optional(),
+  acceptInvalidCerts: z.boolean().optional(),
+  interceptedHosts: z.array(z.string()).optional(),
+});
+
+export const startRecordingProxyOutputSchema = z.object({
+  exchanges: z.number(),
+});
+
+export const listRecordedExchangesOutputSchema = z.object({
+  exchanges: z.array(recordedExchangeInfoSchema),
+});
+
+export const promoteRecordedExchangesInputSchema = z.object({
+  projectId: z.string(),
+  path: z.string(),
+  exchangeIds: z.array(z.string()),
+});
+
+export const promoteRecordedExchangesOutputSchema = z.object({
+  exchanges: z.array(promotedExchangeSchema),
+});
+
+export const runCollectionInputSchema = z.object({
+  projectId: z.string(),
+  path: z.string(),
+  dataFile: z.string().optional(),
+  concurrency: z.number().optional(),
+  requestTimeoutMs: z.number().optional(),
+});
+
+export const runCollectionOutputSchema = z.object({
+  status: runStatusSchema,
+  iterations: z.number(),
+  passed: z.number(),
+  failed: z.number(),
+  skipped: z.number(),
+  durationMs: z.number(),
+  results: z.array(runResultInfoSchema),
+});
+
+export const connectWebSocketInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
+
+export const connectWebSocketOutputSchema = z.object({
+  sessionId: z.string(),
+  protocol: z.string().optional(),
+});
+
+export const sendWebSocketMessageInputSchema = z.object({
+  sessionId: z.string(),
+  message: outgoingWebSocketMessageSchema,
+});
+
+export const sendWebSocketMessageOutputSchema = z.object({
+  frame: webSocketFrameInfoSchema,
+});
+
+export const closeWebSocketInputSchema = z.object({
+  sessionId: z.string(),
+  code: z.number().optional(),
+  reason: z.string().optional(),
+});
+
+export const closeWebSocketOutputSchema = z.record(z.string(), z.never());
+
+export const listWebSocketFramesInputSchema = z.object({
+  sessionId: z.string(),
+});
+
+export const listWebSocketFramesOutputSchema = z.object({
+  frames: z.array(webSocketFrameInfoSchema),
+  open: z.boolean(),
+});
+
+export const introspectGraphqlSchemaInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
+
+export const introspectGraphqlSchemaOutputSchema = z.object({
+  schema: jsonValueSchema,
+});
+
+export const validateGraphqlOperationInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  query: z.string().optional(),
+});
+
+export const validateGraphqlOperationOutputSchema = z.object({
+  errors: z.array(graphqlValidationErrorSchema),
+});
+
+export const executeGraphqlInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  encoding: graphqlEncodingSchema.optional(),
+});
+
+export const executeGraphqlOutputSchema = z.object({
+  status: z.number().optional(),
+  results: z.number(),
+  durationMs: z.number(),
+});
+
+export const listGrpcServicesInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
+
+export const listGrpcServicesOutputSchema = z.object({
+  services: z.array(grpcServiceInfoSchema),
+});
+
+export const executeGrpcInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  messages: z.array(jsonValueSchema).optional(),
+});
+
+export const executeGrpcOutputSchema = z.object({
+  code: z.number(),
+  message: z.string().optional(),
+  received: z.number(),
+  trailers: z.array(grpcMetadataEntrySchema),
+  durationMs: z.number(),
+});
+
+export const executeSseInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
+
+export const executeSseOutputSchema = z.object({
+  received: z.number(),
+  reconnects: z.number(),
+  lastEventId: z.string().optional(),
+  durationMs: z.number(),
+});
+
+export const listSseEventsInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+});
+
+export const listSseEventsOutputSchema = z.object({
+  events: z.array(sseEventInfoSchema),
+});
+
+export const publishQueueMessageInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  payload: z.string().optional(),
+});
+
+export const publishQueueMessageOutputSchema = z.object({
+  topic: z.string(),
+  size: z.number(),
+  durationMs: z.number(),
+});
+
+export const subscribeQueueInputSchema = z.object({
+  projectId: z.string(),
+  resourceId: z.string(),
+  maxMessages: z.number().optional(),
+});
+
+export const subscribeQueueOutputSchema = z.object({
+  received: z.number(),
+  durationMs: z.number(),
+});
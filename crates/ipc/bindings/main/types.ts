@@ -1,6 +1,75 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
 import { ResourceClass, ResourceKind, ResourceProtocol } from "@repo/base";
+import { JsonValue } from "@repo/moss-bindingutils";
+
+/**
+ * @category Type
+ */
+export type EnvironmentScope = "project" | "workspace";
+
+/**
+ * @category Type
+ */
+export type GraphqlEncoding = "post" | "get";
+
+/**
+ * @category Type
+ */
+export type GraphqlValidationError = {
+  message: string;
+  /**
+   * Line of the document the error is found at, starting from 1.
+   */
+  line: number;
+  /**
+   * Column of the line the error is found at, starting from 1.
+   */
+  column: number;
+};
+
+/**
+ * @category Type
+ */
+export type GrpcMetadataEntry = {
+  name: string;
+  /**
+   * Values of binary entries, whose name ends with `-bin`, are base64 encoded.
+   */
+  value: string;
+};
+
+/**
+ * @category Type
+ */
+export type GrpcMethodInfo = {
+  name: string;
+  kind: GrpcMethodKind;
+  /**
+   * Fully qualified name of the request message.
+   */
+  inputType: string;
+  /**
+   * Fully qualified name of the response message.
+   */
+  outputType: string;
+};
+
+/**
+ * @category Type
+ */
+export type GrpcMethodKind = "unary" | "serverStreaming" | "clientStreaming" | "bidiStreaming";
+
+/**
+ * @category Type
+ */
+export type GrpcServiceInfo = {
+  /**
+   * Fully qualified name of the service.
+   */
+  name: string;
+  methods: Array<GrpcMethodInfo>;
+};
 
 export type ListProjectResourceItem = {
   id: string;
@@ -13,12 +82,318 @@ export type ListProjectResourceItem = {
 
 export type ListProjectResourcesMode = "LOAD_ROOT" | { "RELOAD_PATH": string };
 
+/**
+ * @category Type
+ */
+export type MockEndpointOptions = {
+  resourceId: string;
+  /**
+   * The case whose expected response is sent. Defaults to the first case of the endpoint
+   * that has an expected response.
+   */
+  caseId?: string;
+  /**
+   * Overrides the status of the response.
+   */
+  status?: number;
+  /**
+   * Overrides the latency of the server for this endpoint, in milliseconds.
+   */
+  latencyMs?: number;
+};
+
 /**
  * @category Type
  */
 export type OpenInTarget = "NEW_WINDOW" | "CURRENT_WINDOW";
 
+/**
+ * @category Type
+ */
+export type OutgoingWebSocketMessage =
+  | {
+    /**
+     * One of the messages saved to the endpoint.
+     */
+    "saved": { messageId: string };
+  }
+  | { "text": string }
+  | { "json": JsonValue }
+  | {
+    /**
+     * Path of the file whose content is sent.
+     */
+    "binary": string;
+  };
+
+/**
+ * @category Type
+ */
+export type PromotedExchange = {
+  exchangeId: string;
+  /**
+   * The endpoint of the exchange, which already existed when not `created`.
+   */
+  resourceId: string;
+  created: boolean;
+};
+
+/**
+ * @category Type
+ */
+export type QueueMessageInfo = {
+  /**
+   * Increases with every message of the subscription, starting from 1.
+   */
+  seq: number;
+  /**
+   * Routing key of AMQP messages, topic of MQTT ones.
+   */
+  topic: string;
+  /**
+   * Exchange AMQP messages were published to, empty for the default exchange.
+   */
+  exchange?: string;
+  /**
+   * When the message was received, in RFC 3339 format.
+   */
+  timestamp: string;
+  /**
+   * Size of the payload, in bytes.
+   */
+  size: number;
+  /**
+   * Payload of the messages that are valid UTF-8.
+   */
+  text?: string;
+  /**
+   * Payload of the other messages.
+   */
+  data?: Array<number>;
+  /**
+   * Properties of the message set by the publisher, such as its content type, named as
+   * in the protocol.
+   */
+  properties: Array<QueueMessageProperty>;
+  /**
+   * Headers of AMQP messages, user properties of MQTT 5 ones.
+   */
+  headers: Array<QueueMessageProperty>;
+  /**
+   * Quality of service level MQTT messages were delivered with.
+   */
+  qos?: number;
+  /**
+   * Whether the broker delivered a message it retained, or one it delivered before for
+   * AMQP.
+   */
+  redelivered: boolean;
+};
+
+/**
+ * @category Type
+ */
+export type QueueMessageProperty = { name: string; value: string };
+
+/**
+ * @category Type
+ */
+export type RecordedExchangeInfo = {
+  id: string;
+  /**
+   * When the request was received by the proxy, in RFC 3339 format.
+   */
+  startedAt: string;
+  method: string;
+  url: string;
+  status: number;
+  /**
+   * Size of the response body, in bytes.
+   */
+  size: number;
+  /**
+   * Time elapsed from receiving the request until the response was received.
+   */
+  durationMs: number;
+};
+
+/**
+ * @category Type
+ */
+export type ResolvedBody =
+  | { "text": string }
+  | { "json": JsonValue }
+  | { "xml": string }
+  | { "binary": string }
+  | { "urlencoded": Array<ResolvedParam> }
+  | { "formData": Array<ResolvedParam> };
+
+/**
+ * The part of the resource a variable was found in. Params are referenced by their id.
+ *
+ * @category Type
+ */
+export type ResolvedField =
+  | "url"
+  | { "header": string }
+  | { "pathParam": string }
+  | { "queryParam": string }
+  | { "body": string }
+  | { "bodyParam": string };
+
+/**
+ * @category Type
+ */
+export type ResolvedParam = { id: string; name: string; value: string };
+
 /**
  * @category Primitive
  */
 export type ResourcePath = { raw: string; segments: Array<string> };
+
+/**
+ * @category Type
+ */
+export type ResponseHeader = { name: string; value: string };
+
+/**
+ * @category Type
+ */
+export type RunResultInfo = {
+  /**
+   * Index of the data row the endpoint was sent with, starting at 0.
+   */
+  iteration: number;
+  resourceId: string;
+  name: string;
+  path: string;
+  method: string;
+  /**
+   * The url the request was sent to, once resolved.
+   */
+  url: string;
+  /**
+   * Status of the response, if one was received.
+   */
+  status?: number;
+  /**
+   * Time elapsed from sending the request until the whole response was received,
+   * or until the request failed.
+   */
+  durationMs: number;
+  /**
+   * Whether a response was received with a status below 400.
+   */
+  passed: boolean;
+  /**
+   * Why no response was received.
+   */
+  error?: string;
+};
+
+/**
+ * @category Type
+ */
+export type RunStatus = "completed" | "canceled" | "timedOut";
+
+/**
+ * @category Type
+ */
+export type SnippetLanguage = "curl" | "httpie" | "pythonRequests" | "javascriptFetch" | "goNetHttp" | "rustReqwest";
+
+/**
+ * @category Type
+ */
+export type SseEventInfo = {
+  /**
+   * Increases with every event of the stream, across reconnections, starting from 1.
+   */
+  seq: number;
+  /**
+   * Type of the event, `message` unless the server set one.
+   */
+  event: string;
+  data: string;
+  /**
+   * Last event id set by the server, which is sent back when reconnecting.
+   */
+  id?: string;
+  /**
+   * When the event was received, in RFC 3339 format.
+   */
+  timestamp: string;
+};
+
+/**
+ * @category Type
+ */
+export type UnresolvedVariable = { name: string; kind: VariableKind; location: VariableLocation };
+
+/**
+ * @category Type
+ */
+export type VariableKind = "environment" | "pathParam";
+
+/**
+ * Byte offsets of a variable, including its delimiters, in the original value of the field.
+ *
+ * @category Type
+ */
+export type VariableLocation = { field: ResolvedField; start: number; end: number };
+
+/**
+ * @category Type
+ */
+export type VariableSource =
+  | "pathParam"
+  | { "environment": { environmentId: string; scope: EnvironmentScope; value: VariableValueKind } }
+  | "data";
+
+/**
+ * @category Type
+ */
+export type VariableSubstitution = { name: string; location: VariableLocation; source: VariableSource };
+
+/**
+ * @category Type
+ */
+export type VariableValueKind = "local" | "global";
+
+/**
+ * @category Type
+ */
+export type WebSocketFrameDirection = "sent" | "received";
+
+/**
+ * @category Type
+ */
+export type WebSocketFrameInfo = {
+  /**
+   * Increases with every frame of the session, starting from 1.
+   */
+  id: number;
+  direction: WebSocketFrameDirection;
+  kind: WebSocketFrameKind;
+  /**
+   * When the frame was sent or received, in RFC 3339 format.
+   */
+  timestamp: string;
+  /**
+   * Size of the payload, in bytes.
+   */
+  size: number;
+  /**
+   * Payload of text frames, and reason of close frames.
+   */
+  text?: string;
+  /**
+   * Payload of binary, ping and pong frames.
+   */
+  data?: Array<number>;
+  closeCode?: number;
+};
+
+/**
+ * @category Type
+ */
+export type WebSocketFrameKind = "text" | "binary" | "ping" | "pong" | "close";
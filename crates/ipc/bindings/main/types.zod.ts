@@ -1,5 +1,6 @@
 // Generated by ts-to-zod
 import { resourceClassSchema, resourceKindSchema, resourceProtocolSchema } from "@repo/base";
+import { jsonValueSchema } from "@repo/moss-bindingutils";
 import { z } from "zod";
 
 export const resourcePathSchema = z.object({
@@ -14,6 +15,143 @@ export const listProjectResourcesModeSchema = z.union([
   }),
 ]);
 
+export const responseHeaderSchema = z.object({
+  name: z.string(),
+  value: z.string(),
+});
+
+export const environmentScopeSchema = z.union([z.literal("project"), z.literal("workspace")]);
+
+export const resolvedParamSchema = z.object({
+  id: z.string(),
+  name: z.string(),
+  value: z.string(),
+});
+
+export const resolvedBodySchema = z.union([
+  z.object({
+    "text": z.string(),
+  }),
+  z.object({
+    "json": jsonValueSchema,
+  }),
+  z.object({
+    "xml": z.string(),
+  }),
+  z.object({
+    "binary": z.string(),
+  }),
+  z.object({
+    "urlencoded": z.array(resolvedParamSchema),
+  }),
+  z.object({
+    "formData": z.array(resolvedParamSchema),
+  }),
+]);
+
+export const resolvedFieldSchema = z.union([
+  z.literal("url"),
+  z.object({
+    "header": z.string(),
+  }),
+  z.object({
+    "pathParam": z.string(),
+  }),
+  z.object({
+    "queryParam": z.string(),
+  }),
+  z.object({
+    "body": z.string(),
+  }),
+  z.object({
+    "bodyParam": z.string(),
+  }),
+]);
+
+export const snippetLanguageSchema = z.union([
+  z.literal("curl"),
+  z.literal("httpie"),
+  z.literal("pythonRequests"),
+  z.literal("javascriptFetch"),
+  z.literal("goNetHttp"),
+  z.literal("rustReqwest"),
+]);
+
+export const sseEventInfoSchema = z.object({
+  seq: z.number(),
+  event: z.string(),
+  data: z.string(),
+  id: z.string().optional(),
+  timestamp: z.string(),
+});
+
+export const variableKindSchema = z.union([z.literal("environment"), z.literal("pathParam")]);
+
+export const variableLocationSchema = z.object({
+  field: resolvedFieldSchema,
+  start: z.number(),
+  end: z.number(),
+});
+
+export const variableValueKindSchema = z.union([z.literal("local"), z.literal("global")]);
+
+export const variableSourceSchema = z.union([
+  z.literal("pathParam"),
+  z.object({
+    "environment": z.object({
+      environmentId: z.string(),
+      scope: environmentScopeSchema,
+      value: variableValueKindSchema,
+    }),
+  }),
+  z.literal("data"),
+]);
+
+export const variableSubstitutionSchema = z.object({
+  name: z.string(),
+  location: variableLocationSchema,
+  source: variableSourceSchema,
+});
+
+export const unresolvedVariableSchema = z.object({
+  name: z.string(),
+  kind: variableKindSchema,
+  location: variableLocationSchema,
+});
+
+export const mockEndpointOptionsSchema = z.object({
+  resourceId: z.string(),
+  caseId: z.string().optional(),
+  status: z.number().optional(),
+  latencyMs: z.number().optional(),
+});
+export const promotedExchangeSchema = z.object({
+  exchangeId: z.string(),
+  resourceId: z.string(),
+  created: z.boolean(),
+});
+export const recordedExchangeInfoSchema = z.object({
+  id: z.string(),
+  startedAt: z.string(),
+  method: z.string(),
+  url: z.string(),
+  status: z.number(),
+  size: z.number(),
+  durationMs: z.number(),
+});
+export const runResultInfoSchema = z.object({
+  iteration: z.number(),
+  resourceId: z.string(),
+  name: z.string(),
+  path: z.string(),
+  method: z.string(),
+  url: z.string(),
+  status: z.number().optional(),
+  durationMs: z.number(),
+  passed: z.boolean(),
+  error: z.string().optional(),
+});
+export const runStatusSchema = z.union([z.literal("completed"), z.literal("canceled"), z.literal("timedOut")]);
 export const openInTargetSchema = z.union([z.literal("NEW_WINDOW"), z.literal("CURRENT_WINDOW")]);
 export const listProjectResourceItemSchema = z.object({
   id: z.string(),
@@ -23,3 +161,80 @@ export const listProjectResourceItemSchema = z.object({
   kind: resourceKindSchema,
   protocol: resourceProtocolSchema.optional(),
 });
+export const outgoingWebSocketMessageSchema = z.union([
+  z.object({
+    "saved": z.object({
+      messageId: z.string(),
+    }),
+  }),
+  z.object({
+    "text": z.string(),
+  }),
+  z.object({
+    "json": jsonValueSchema,
+  }),
+  z.object({
+    "binary": z.string(),
+  }),
+]);
+export const webSocketFrameDirectionSchema = z.union([z.literal("sent"), z.literal("received")]);
+export const webSocketFrameKindSchema = z.union([
+  z.literal("text"),
+  z.literal("binary"),
+  z.literal("ping"),
+  z.literal("pong"),
+  z.literal("close"),
+]);
+export const webSocketFrameInfoSchema = z.object({
+  id: z.number(),
+  direction: webSocketFrameDirectionSchema,
+  kind: webSocketFrameKindSchema,
+  timestamp: z.string(),
+  size: z.number(),
+  text: z.string().optional(),
+  data: z.array(z.number()).optional(),
+  closeCode: z.number().optional(),
+});
+export const graphqlEncodingSchema = z.union([z.literal("post"), z.literal("get")]);
+export const graphqlValidationErrorSchema = z.object({
+  message: z.string(),
+  line: z.number(),
+  column: z.number(),
+});
+export const grpcMetadataEntrySchema = z.object({
+  name: z.string(),
+  value: z.string(),
+});
+export const grpcMethodKindSchema = z.union([
+  z.literal("unary"),
+  z.literal("serverStreaming"),
+  z.literal("clientStreaming"),
+  z.literal("bidiStreaming"),
+]);
+export const grpcMethodInfoSchema = z.object({
+  name: z.string(),
+  kind: grpcMethodKindSchema,
+  inputType: z.string(),
+  outputType: z.string(),
+});
+export const grpcServiceInfoSchema = z.object({
+  name: z.string(),
+  methods: z.array(grpcMethodInfoSchema),
+});
+export const queueMessagePropertySchema = z.object({
+  name: z.string(),
+  value: z.string(),
+});
+export const queueMessageInfoSchema = z.object({
+  seq: z.number(),
+  topic: z.string(),
+  exchange: z.string().optional(),
+  timestamp: z.string(),
+  size: z.number(),
+  text: z.string().optional(),
+  data: z.array(z.number()).optional(),
+  properties: z.array(queueMessagePropertySchema),
+  headers: z.array(queueMessagePropertySchema),
+  qos: z.number().optional(),
+  redelivered: z.boolean(),
+});
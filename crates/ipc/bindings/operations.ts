@@ -14,7 +14,9 @@ import type {
   AddVariableParams,
   Contributor,
   CreateProjectGitParams,
+  ExportProjectFormat,
   ImportProjectSource,
+  ImportSkippedItem,
   ListEnvironmentItem,
   ListProjectItem,
   UpdateEnvironmentParams,
@@ -174,12 +176,18 @@ export type ExportProjectInput = {
    * Path to the folder containing the output archive file
    */
   destination: string;
+  format?: ExportProjectFormat;
 };
 
 /**
  * @category Operation
  */
-export type ExportProjectOutput = { archivePath: string };
+export type ExportProjectOutput = {
+  /**
+   * Path to the created archive, OpenAPI document or HAR log.
+   */
+  archivePath: string;
+};
 
 /**
  * @category Operation
@@ -209,7 +217,16 @@ export type ImportProjectInput = { name: string; source: ImportProjectSource; ic
 /**
  * @category Operation
  */
-export type ImportProjectOutput = { id: string; name: string; iconPath?: string };
+export type ImportProjectOutput = {
+  id: string;
+  name: string;
+  iconPath?: string;
+  /**
+   * Constructs of the imported document that were left out.
+   * Always empty when importing an existing project.
+   */
+  skipped: Array<ImportSkippedItem>;
+};
 
 /**
  * @category Operation
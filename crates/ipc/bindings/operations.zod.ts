@@ -16,7 +16,9 @@ import {
   addVariableParamsSchema,
   contributorSchema,
   createProjectGitParamsSchema,
+  exportProjectFormatSchema,
   importProjectSourceSchema,
+  importSkippedItemSchema,
   listEnvironmentItemSchema,
   listProjectItemSchema,
   updateEnvironmentParamsSchema,
@@ -110,6 +112,7 @@ export const downloadExtensionInputSchema = z.object({
 export const exportProjectInputSchema = z.object({
   id: z.string(),
   destination: z.string(),
+  format: exportProjectFormatSchema.optional(),
 });
 
 export const exportProjectOutputSchema = z.object({
@@ -129,6 +132,7 @@ export const importProjectOutputSchema = z.object({
   id: z.string(),
   name: z.string(),
   iconPath: z.string().optional(),
+  skipped: z.array(importSkippedItemSchema),
 });
 
 export const listProjectEnvironmentsInputSchema = z.object({
@@ -33,12 +33,18 @@ export type CreateProjectParams = {
   iconPath?: string;
 };
 
+/**
+ * @category Type
+ */
+export type ExportProjectFormat = "archive" | "openApiYaml" | "openApiJson" | "har";
+
 export type ExportProjectParams = {
   id: string;
   /**
    * Path to the folder containing the output archive file
    */
   destination: string;
+  format?: ExportProjectFormat;
 };
 
 export type GitHubCreateParams = {
@@ -74,6 +80,16 @@ export type GitLabVcsInfo = { branch: BranchInfo; url: string; updatedAt?: strin
  */
 export type ImportArchiveParams = { archivePath: string };
 
+/**
+ * @category Type
+ */
+export type ImportBrunoParams = {
+  /**
+   * Path to the folder holding the `bruno.json` file of the collection.
+   */
+  collectionPath: string;
+};
+
 /**
  * @category Type
  */
@@ -103,6 +119,58 @@ export type ImportGitLabParams = {
   branch?: string;
 };
 
+/**
+ * @category Type
+ */
+export type ImportHarParams = {
+  filePath: string;
+  /**
+   * Indices of the entries of the log to import. All of them are imported if not provided.
+   */
+  entries?: Array<number>;
+};
+
+/**
+ * @category Type
+ */
+export type ImportHttpFileParams = {
+  /**
+   * Path to the `.http` file. Bodies read from relative paths are resolved against its folder.
+   */
+  filePath: string;
+};
+
+/**
+ * @category Type
+ */
+export type ImportInsomniaParams = {
+  /**
+   * Path to a JSON or YAML export.
+   */
+  filePath: string;
+};
+
+/**
+ * @category Type
+ */
+export type ImportOpenApiParams = {
+  /**
+   * Path to a JSON or YAML document.
+   */
+  specPath: string;
+};
+
+/**
+ * @category Type
+ */
+export type ImportPostmanParams = {
+  collectionPath: string;
+  /**
+   * Postman environment files, each becoming an environment of the project.
+   */
+  environmentPaths?: Array<string>;
+};
+
 export type ImportProjectParams = { name: string; source: ImportProjectSource; iconPath?: string };
 
 /**
@@ -112,7 +180,30 @@ export type ImportProjectSource =
   | { "gitHub": ImportGitHubParams }
   | { "gitLab": ImportGitLabParams }
   | { "archive": ImportArchiveParams }
-  | { "disk": ImportDiskParams };
+  | { "disk": ImportDiskParams }
+  | { "openApi": ImportOpenApiParams }
+  | { "postman": ImportPostmanParams }
+  | { "httpFile": ImportHttpFileParams }
+  | { "har": ImportHarParams }
+  | { "insomnia": ImportInsomniaParams }
+  | { "bruno": ImportBrunoParams };
+
+/**
+ * A construct of an imported document that has no counterpart in a project.
+ *
+ * @category Type
+ */
+export type ImportSkippedItem = {
+  /**
+   * JSON pointer to the construct in the imported document.
+   * For `.http` files, it points to the request by its index in the file, such as `/0`,
+   * and for curl commands to the argument by its index in the command line.
+   * For HAR logs, it points to the entry, such as `/log/entries/0`,
+   * and for Bruno collections to the file, such as `/users/Get user.bru`.
+   */
+  pointer: string;
+  reason: string;
+};
 
 /**
  * @category Type
@@ -39,9 +39,17 @@ export const createProjectGitParamsSchema = z.union([
   }),
 ]);
 
+export const exportProjectFormatSchema = z.union([
+  z.literal("archive"),
+  z.literal("openApiYaml"),
+  z.literal("openApiJson"),
+  z.literal("har"),
+]);
+
 export const exportProjectParamsSchema = z.object({
   id: z.string(),
   destination: z.string(),
+  format: exportProjectFormatSchema.optional(),
 });
 
 export const gitHubVcsInfoSchema = z.object({
@@ -62,6 +70,10 @@ export const importArchiveParamsSchema = z.object({
   archivePath: z.string(),
 });
 
+export const importBrunoParamsSchema = z.object({
+  collectionPath: z.string(),
+});
+
 export const importDiskParamsSchema = z.object({
   externalPath: z.string(),
 });
@@ -78,6 +90,28 @@ export const importGitLabParamsSchema = z.object({
   branch: z.string().optional(),
 });
 
+export const importHarParamsSchema = z.object({
+  filePath: z.string(),
+  entries: z.array(z.number()).optional(),
+});
+
+export const importHttpFileParamsSchema = z.object({
+  filePath: z.string(),
+});
+
+export const importInsomniaParamsSchema = z.object({
+  filePath: z.string(),
+});
+
+export const importOpenApiParamsSchema = z.object({
+  specPath: z.string(),
+});
+
+export const importPostmanParamsSchema = z.object({
+  collectionPath: z.string(),
+  environmentPaths: z.array(z.string()).optional(),
+});
+
 export const importProjectSourceSchema = z.union([
   z.object({
     "gitHub": importGitHubParamsSchema,
@@ -91,8 +125,31 @@ export const importProjectSourceSchema = z.union([
   z.object({
     "disk": importDiskParamsSchema,
   }),
+  z.object({
+    "openApi": importOpenApiParamsSchema,
+  }),
+  z.object({
+    "postman": importPostmanParamsSchema,
+  }),
+  z.object({
+    "httpFile": importHttpFileParamsSchema,
+  }),
+  z.object({
+    "har": importHarParamsSchema,
+  }),
+  z.object({
+    "insomnia": importInsomniaParamsSchema,
+  }),
+  z.object({
+    "bruno": importBrunoParamsSchema,
+  }),
 ]);
 
+export const importSkippedItemSchema = z.object({
+  pointer: z.string(),
+  reason: z.string(),
+});
+
 export const listEnvironmentItemSchema = z.object({
   id: z.string(),
   isActive: z.boolean(),
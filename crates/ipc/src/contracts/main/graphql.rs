@@ -0,0 +1,122 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum GraphqlEncoding {
+    /// The operation is sent as a json body.
+    #[default]
+    Post,
+    /// The operation is sent in the query string, which only queries can be.
+    Get,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct GraphqlValidationError {
+    pub message: String,
+    /// Line of the document the error is found at, starting from 1.
+    pub line: usize,
+    /// Column of the line the error is found at, starting from 1.
+    pub column: usize,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum GraphqlEvent {
+    /// Sent for the response of a query or mutation, and for every result of a subscription.
+    Result {
+        #[ts(type = "JsonValue")]
+        result: JsonValue,
+    },
+}
+
+//
+// Introspect Graphql Schema
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct IntrospectGraphqlSchemaInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct IntrospectGraphqlSchemaOutput {
+    /// The `__schema` object returned by the endpoint, as cached for the resource.
+    #[ts(type = "JsonValue")]
+    pub schema: JsonValue,
+}
+
+//
+// Validate Graphql Operation
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ValidateGraphqlOperationInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    /// Document to validate instead of the saved one, such as the one being edited.
+    pub query: Option<String>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ValidateGraphqlOperationOutput {
+    /// Problems found against the cached schema, in the order they appear in the document.
+    pub errors: Vec<GraphqlValidationError>,
+}
+
+//
+// Execute Graphql
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteGraphqlInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    /// How queries and mutations are sent, defaults to `post`. Subscriptions are always sent
+    /// over the graphql-ws protocol.
+    pub encoding: Option<GraphqlEncoding>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteGraphqlOutput {
+    /// Status of the response, absent for subscriptions.
+    pub status: Option<u16>,
+    /// Number of results received.
+    #[ts(type = "number")]
+    pub results: u64,
+    /// Time elapsed from sending the operation until its last result was received.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
@@ -0,0 +1,122 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum GrpcMethodKind {
+    Unary,
+    ServerStreaming,
+    ClientStreaming,
+    BidiStreaming,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct GrpcMethodInfo {
+    pub name: String,
+    pub kind: GrpcMethodKind,
+    /// Fully qualified name of the request message.
+    pub input_type: String,
+    /// Fully qualified name of the response message.
+    pub output_type: String,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct GrpcServiceInfo {
+    /// Fully qualified name of the service.
+    pub name: String,
+    pub methods: Vec<GrpcMethodInfo>,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct GrpcMetadataEntry {
+    pub name: String,
+    /// Values of binary entries, whose name ends with `-bin`, are base64 encoded.
+    pub value: String,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum GrpcEvent {
+    /// Sent once the server responded with its initial metadata.
+    Headers { metadata: Vec<GrpcMetadataEntry> },
+    /// Sent for every response message, as JSON.
+    Message {
+        #[ts(type = "JsonValue")]
+        message: JsonValue,
+    },
+}
+
+//
+// List Grpc Services
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListGrpcServicesInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListGrpcServicesOutput {
+    /// Services described by the `.proto` files of the endpoint, or by its server reflection.
+    pub services: Vec<GrpcServiceInfo>,
+}
+
+//
+// Execute Grpc
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteGrpcInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    /// Request messages to send instead of the saved ones, such as the ones being edited.
+    #[ts(type = "Array<JsonValue>")]
+    pub messages: Option<Vec<JsonValue>>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteGrpcOutput {
+    /// Status code the call ended with, `0` when it succeeded.
+    pub code: i32,
+    /// Message of the status, when the call failed.
+    pub message: Option<String>,
+    /// Number of response messages received.
+    #[ts(type = "number")]
+    pub received: u64,
+    /// Metadata sent by the server once the call ended.
+    pub trailers: Vec<GrpcMetadataEntry>,
+    /// Time elapsed from starting the call until it ended.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
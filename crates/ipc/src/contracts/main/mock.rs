@@ -0,0 +1,86 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+//
+// Start Mock Server
+//
+
+/// @category Type
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/types.ts")]
+pub struct MockEndpointOptions {
+    pub resource_id: ResourceId,
+    /// The case whose expected response is sent. Defaults to the first case of the endpoint
+    /// that has an expected response.
+    pub case_id: Option<String>,
+    /// Overrides the status of the response.
+    #[validate(range(min = 100, max = 599))]
+    pub status: Option<u16>,
+    /// Overrides the latency of the server for this endpoint, in milliseconds.
+    pub latency_ms: Option<u32>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct StartMockServerInput {
+    pub project_id: ProjectId,
+    /// Port to listen on, on the loopback interface. A free one is picked when omitted.
+    pub port: Option<u16>,
+    /// Delay before every response is sent, in milliseconds.
+    pub latency_ms: Option<u32>,
+    /// Overrides the status of every response routed to an endpoint, unless the endpoint
+    /// overrides it.
+    #[validate(range(min = 100, max = 599))]
+    pub status: Option<u16>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<MockEndpointOptions>>", optional)]
+    #[validate(nested)]
+    pub endpoints: Vec<MockEndpointOptions>,
+    /// Reports every request served with a `request` event.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub log_requests: bool,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum MockServerEvent {
+    /// Sent once, as soon as the server is listening.
+    Started { port: u16 },
+    /// Sent for every request served, when requests are logged.
+    #[serde(rename_all = "camelCase")]
+    Request {
+        method: String,
+        /// Path of the request, including its query.
+        path: String,
+        status: u16,
+        /// The endpoint the request was routed to, if any.
+        #[ts(optional)]
+        resource_id: Option<ResourceId>,
+        /// The case whose expected response was sent, if any.
+        #[ts(optional)]
+        case_id: Option<String>,
+        /// Time elapsed from receiving the request until responding, latency included.
+        #[ts(type = "number")]
+        duration_ms: u64,
+    },
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct StartMockServerOutput {
+    /// Number of requests served until the server was stopped.
+    #[ts(type = "number")]
+    pub requests: u64,
+}
@@ -1,6 +1,14 @@
 pub mod environment;
+pub mod graphql;
+pub mod grpc;
+pub mod mock;
 pub mod project;
+pub mod proxy;
+pub mod queue;
 pub mod resource;
+pub mod runner;
+pub mod sse;
+pub mod websocket;
 pub mod workspace;
 
 use serde::{Deserialize, Serialize};
@@ -248,6 +248,19 @@ pub enum ImportProjectSource {
     GitLab(ImportGitLabParams),
     Archive(ImportArchiveParams),
     Disk(ImportDiskParams),
+    /// Creates the project from an OpenAPI 3.0 or 3.1 document.
+    OpenApi(ImportOpenApiParams),
+    /// Creates the project from a Postman collection v2.1, along with its environments.
+    Postman(ImportPostmanParams),
+    /// Creates the project from a `.http` file of the JetBrains HTTP Client or the VS Code
+    /// REST Client, along with an environment holding the variables of the file.
+    HttpFile(ImportHttpFileParams),
+    /// Creates the project from a HAR 1.2 log, such as one saved from the devtools of a browser.
+    Har(ImportHarParams),
+    /// Creates the project from an Insomnia v4 export, along with its environments.
+    Insomnia(ImportInsomniaParams),
+    /// Creates the project from the folder of a Bruno collection, along with its environments.
+    Bruno(ImportBrunoParams),
 }
 
 // FIXME: Validation for provider specific url?
@@ -295,6 +308,81 @@ pub struct ImportDiskParams {
     pub external_path: PathBuf,
 }
 
+/// @category Type
+#[derive(Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportOpenApiParams {
+    /// Path to a JSON or YAML document.
+    pub spec_path: PathBuf,
+}
+
+/// @category Type
+#[derive(Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportPostmanParams {
+    pub collection_path: PathBuf,
+    /// Postman environment files, each becoming an environment of the project.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<PathBuf>>", optional)]
+    pub environment_paths: Vec<PathBuf>,
+}
+
+/// @category Type
+#[derive(Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportHttpFileParams {
+    /// Path to the `.http` file. Bodies read from relative paths are resolved against its folder.
+    pub file_path: PathBuf,
+}
+
+/// @category Type
+#[derive(Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportHarParams {
+    pub file_path: PathBuf,
+    /// Indices of the entries of the log to import. All of them are imported if not provided.
+    pub entries: Option<Vec<usize>>,
+}
+
+/// @category Type
+#[derive(Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportInsomniaParams {
+    /// Path to a JSON or YAML export.
+    pub file_path: PathBuf,
+}
+
+/// @category Type
+#[derive(Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportBrunoParams {
+    /// Path to the folder holding the `bruno.json` file of the collection.
+    pub collection_path: PathBuf,
+}
+
+/// A construct of an imported document that has no counterpart in a project.
+///
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct ImportSkippedItem {
+    /// JSON pointer to the construct in the imported document.
+    /// For `.http` files, it points to the request by its index in the file, such as `/0`,
+    /// and for curl commands to the argument by its index in the command line.
+    /// For HAR logs, it points to the entry, such as `/log/entries/0`,
+    /// and for Bruno collections to the file, such as `/users/Get user.bru`.
+    pub pointer: String,
+    pub reason: String,
+}
+
 /// @category Operation
 #[derive(Debug, Serialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -306,6 +394,9 @@ pub struct ImportProjectOutput {
     // Since a cloned/imported project already has a name
     pub name: String,
     pub icon_path: Option<PathBuf>,
+    /// Constructs of the imported document that were left out.
+    /// Always empty when importing an existing project.
+    pub skipped: Vec<ImportSkippedItem>,
 
     #[serde(skip)]
     #[ts(skip)]
@@ -338,9 +429,29 @@ pub struct ExportProjectParams {
     /// Path to the folder containing the output archive file
     #[validate(custom(function = "validate_export_destination"))]
     pub destination: PathBuf,
+    #[serde(default)]
+    #[ts(as = "Option<ExportProjectFormat>", optional)]
+    pub format: ExportProjectFormat,
 }
 
-fn validate_export_destination(destination: &Path) -> Result<(), ValidationError> {
+/// @category Type
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub enum ExportProjectFormat {
+    /// A zip archive of the whole project, which can be imported back as is.
+    #[default]
+    Archive,
+    /// An OpenAPI 3.1 document of the endpoints and schemas, written as YAML.
+    OpenApiYaml,
+    /// An OpenAPI 3.1 document of the endpoints and schemas, written as JSON.
+    OpenApiJson,
+    /// A HAR 1.2 log of the last 100 requests sent to the endpoints of the project and the
+    /// responses they received. Fails if no request has been sent yet.
+    Har,
+}
+
+pub(crate) fn validate_export_destination(destination: &Path) -> Result<(), ValidationError> {
     if !destination.is_dir() {
         return Err(ValidationError::new("destination must be a directory"));
     }
@@ -352,6 +463,7 @@ fn validate_export_destination(destination: &Path) -> Result<(), ValidationError
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "operations.ts")]
 pub struct ExportProjectOutput {
+    /// Path to the created archive, OpenAPI document or HAR log.
     pub archive_path: PathBuf,
 }
 
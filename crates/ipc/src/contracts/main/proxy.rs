@@ -0,0 +1,125 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct RecordedExchangeInfo {
+    pub id: String,
+    /// When the request was received by the proxy, in RFC 3339 format.
+    pub started_at: String,
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    /// Size of the response body, in bytes.
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Time elapsed from receiving the request until the response was received.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
+
+//
+// Start Recording Proxy
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct StartRecordingProxyInput {
+    /// Port to listen on, on the loopback interface. A free one is picked when omitted.
+    pub port: Option<u16>,
+    /// Relays requests to servers whose certificate cannot be verified, such as
+    /// self-signed ones.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub accept_invalid_certs: bool,
+    /// Hosts whose https traffic is recorded, along with their subdomains. The certificate
+    /// authority can only impersonate these and the loopback hosts, so tunnels to any other
+    /// host are passed through without being recorded.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<String>>", optional)]
+    pub intercepted_hosts: Vec<String>,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum RecordingProxyEvent {
+    /// Sent once, as soon as the proxy is listening.
+    #[serde(rename_all = "camelCase")]
+    Started {
+        port: u16,
+        /// The certificate of the authority https traffic is intercepted with, in PEM format.
+        /// Clients must trust it for their https requests to be recorded.
+        ca_certificate_path: PathBuf,
+    },
+    /// Sent for every exchange relayed by the proxy.
+    Exchange { exchange: RecordedExchangeInfo },
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct StartRecordingProxyOutput {
+    /// Number of exchanges recorded until the proxy was stopped.
+    #[ts(type = "number")]
+    pub exchanges: u64,
+}
+
+//
+// List Recorded Exchanges
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListRecordedExchangesOutput {
+    /// The exchanges recorded from this window, from the oldest to the most recent.
+    pub exchanges: Vec<RecordedExchangeInfo>,
+}
+
+//
+// Promote Recorded Exchanges
+//
+
+/// @category Type
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct PromotedExchange {
+    pub exchange_id: String,
+    /// The endpoint of the exchange, which already existed when not `created`.
+    pub resource_id: ResourceId,
+    pub created: bool,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct PromoteRecordedExchangesInput {
+    pub project_id: ProjectId,
+    /// Directory of the project the endpoints are created in.
+    pub path: PathBuf,
+    #[validate(length(min = 1))]
+    pub exchange_ids: Vec<String>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct PromoteRecordedExchangesOutput {
+    /// One per exchange, in the order they were given.
+    pub exchanges: Vec<PromotedExchange>,
+}
@@ -0,0 +1,124 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct QueueMessageProperty {
+    pub name: String,
+    pub value: String,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/types.ts")]
+pub struct QueueMessageInfo {
+    /// Increases with every message of the subscription, starting from 1.
+    #[ts(type = "number")]
+    pub seq: u64,
+    /// Routing key of AMQP messages, topic of MQTT ones.
+    pub topic: String,
+    /// Exchange AMQP messages were published to, empty for the default exchange.
+    pub exchange: Option<String>,
+    /// When the message was received, in RFC 3339 format.
+    pub timestamp: String,
+    /// Size of the payload, in bytes.
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Payload of the messages that are valid UTF-8.
+    pub text: Option<String>,
+    /// Payload of the other messages.
+    pub data: Option<Vec<u8>>,
+    /// Properties of the message set by the publisher, such as its content type, named as
+    /// in the protocol.
+    pub properties: Vec<QueueMessageProperty>,
+    /// Headers of AMQP messages, user properties of MQTT 5 ones.
+    pub headers: Vec<QueueMessageProperty>,
+    /// Quality of service level MQTT messages were delivered with.
+    pub qos: Option<u8>,
+    /// Whether the broker delivered a message it retained, or one it delivered before for
+    /// AMQP.
+    pub redelivered: bool,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum QueueEvent {
+    /// Sent once the broker accepted the subscription, before any message.
+    Subscribed {
+        /// Queue consumed from for AMQP, topic filter subscribed to for MQTT.
+        source: String,
+    },
+    /// Sent for every message received.
+    Message { message: QueueMessageInfo },
+}
+
+//
+// Publish Queue Message
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct PublishQueueMessageInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    /// Published instead of the payload of the endpoint.
+    pub payload: Option<String>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct PublishQueueMessageOutput {
+    /// Routing key or topic the message was published with, after rendering its variables.
+    pub topic: String,
+    /// Size of the payload, in bytes.
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Time elapsed from connecting to the broker until it acknowledged the message, as
+    /// required by the quality of service level for MQTT.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
+
+//
+// Subscribe Queue
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct SubscribeQueueInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    /// Stops the subscription once that many messages were received. Otherwise it goes on
+    /// until the request is canceled.
+    #[ts(type = "number")]
+    #[validate(range(min = 1))]
+    pub max_messages: Option<u64>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct SubscribeQueueOutput {
+    #[ts(type = "number")]
+    pub received: u64,
+    /// Time elapsed from connecting to the broker until the subscription stopped.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
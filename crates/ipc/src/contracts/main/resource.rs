@@ -1,11 +1,16 @@
 use std::path::PathBuf;
 
 use sapic_base::{
+    environment::types::primitives::EnvironmentId,
     project::types::primitives::ProjectId,
     resource::types::{ResourceSummary, primitives::*},
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use ts_rs::TS;
+use validator::Validate;
+
+use crate::contracts::main::project::{ImportSkippedItem, validate_export_destination};
 
 //
 // List Project Resources
@@ -86,3 +91,299 @@ pub struct ListProjectResourcesOutput {
     #[ts(type = "ListProjectResourceItem[]")]
     pub items: Vec<ListProjectResourceItem>,
 }
+
+//
+// Execute Resource
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteResourceInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct ResponseHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum ExecuteResourceEvent {
+    /// Sent once, as soon as the response status line is received.
+    #[serde(rename_all = "camelCase")]
+    Status { status: u16, status_text: String },
+    /// Sent once, right after the status, with the headers in the order
+    /// they were received.
+    Headers { headers: Vec<ResponseHeader> },
+    /// Sent for every chunk of the response body as it arrives.
+    Body { chunk: Vec<u8> },
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteResourceOutput {
+    pub status: u16,
+    /// Total size of the response body in bytes.
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Time elapsed from sending the request until the body was fully read.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
+
+//
+// Resolve Resource
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ResolveResourceInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct ResolvedParam {
+    pub id: String,
+    pub name: String,
+    pub value: String,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum ResolvedBody {
+    Text(String),
+    Json(#[ts(type = "JsonValue")] JsonValue),
+    Xml(String),
+    Binary(PathBuf),
+    Urlencoded(Vec<ResolvedParam>),
+    FormData(Vec<ResolvedParam>),
+}
+
+/// The part of the resource a variable was found in. Params are referenced by their id.
+///
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum ResolvedField {
+    Url,
+    Header(String),
+    PathParam(String),
+    QueryParam(String),
+    /// JSON pointer to the string inside a json body, empty for text and xml bodies.
+    Body(String),
+    BodyParam(String),
+}
+
+/// Byte offsets of a variable, including its delimiters, in the original value of the field.
+///
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct VariableLocation {
+    pub field: ResolvedField,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum VariableKind {
+    /// A `{{name}}` variable, resolved from the active environments.
+    Environment,
+    /// A `:name` segment of the url path, resolved from the path params.
+    PathParam,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum EnvironmentScope {
+    Project,
+    Workspace,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum VariableValueKind {
+    Local,
+    Global,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum VariableSource {
+    PathParam,
+    #[serde(rename_all = "camelCase")]
+    Environment {
+        environment_id: EnvironmentId,
+        scope: EnvironmentScope,
+        value: VariableValueKind,
+    },
+    /// A column of the data file row the iteration of a collection run is bound to.
+    Data,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct VariableSubstitution {
+    pub name: String,
+    pub location: VariableLocation,
+    pub source: VariableSource,
+}
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub struct UnresolvedVariable {
+    pub name: String,
+    pub kind: VariableKind,
+    pub location: VariableLocation,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ResolveResourceOutput {
+    pub protocol: Option<ResourceProtocol>,
+    pub url: String,
+    pub headers: Vec<ResolvedParam>,
+    pub path_params: Vec<ResolvedParam>,
+    pub query_params: Vec<ResolvedParam>,
+    pub body: Option<ResolvedBody>,
+    pub substitutions: Vec<VariableSubstitution>,
+    /// Variables without a value are left in place as written.
+    pub unresolved: Vec<UnresolvedVariable>,
+}
+
+//
+// Export Http File
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExportHttpFileInput {
+    pub project_id: ProjectId,
+    /// The directory whose endpoints are written, including the ones of its subdirectories.
+    pub resource_id: ResourceId,
+    /// Path to the folder containing the output `.http` file
+    #[validate(custom(function = "validate_export_destination"))]
+    pub destination: PathBuf,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExportHttpFileOutput {
+    /// Path to the created `.http` file, named after the directory.
+    pub path: PathBuf,
+}
+
+//
+// Import Curl
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ImportCurlInput {
+    pub project_id: ProjectId,
+    /// Directory of the project the endpoint is created in.
+    pub path: PathBuf,
+    pub order: isize,
+    /// Defaults to the method and the url of the command.
+    #[validate(length(min = 1))]
+    pub name: Option<String>,
+    /// The command line, as copied from a terminal or the devtools of a browser.
+    #[validate(length(min = 1))]
+    pub command: String,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ImportCurlOutput {
+    pub id: ResourceId,
+    pub name: String,
+    pub skipped: Vec<ImportSkippedItem>,
+}
+
+//
+// Generate Snippet
+//
+
+/// @category Type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum SnippetLanguage {
+    Curl,
+    Httpie,
+    PythonRequests,
+    JavascriptFetch,
+    GoNetHttp,
+    RustReqwest,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct GenerateSnippetInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    pub language: SnippetLanguage,
+    /// Substitutes the variables of the active environments, which are otherwise
+    /// left as `{{name}}` placeholders.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub resolve_variables: bool,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct GenerateSnippetOutput {
+    pub snippet: String,
+}
@@ -0,0 +1,85 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum RunStatus {
+    /// Every endpoint was sent in every iteration.
+    Completed,
+    /// The run was canceled before every endpoint was sent.
+    Canceled,
+    /// The run exceeded its timeout before every endpoint was sent.
+    TimedOut,
+}
+
+/// @category Type
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/types.ts")]
+pub struct RunResultInfo {
+    /// Index of the data row the endpoint was sent with, starting at 0.
+    pub iteration: u32,
+    pub resource_id: ResourceId,
+    pub name: String,
+    pub path: PathBuf,
+    pub method: String,
+    /// The url the request was sent to, once resolved.
+    pub url: String,
+    /// Status of the response, if one was received.
+    pub status: Option<u16>,
+    /// Time elapsed from sending the request until the whole response was received,
+    /// or until the request failed.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+    /// Whether a response was received with a status below 400.
+    pub passed: bool,
+    /// Why no response was received.
+    pub error: Option<String>,
+}
+
+//
+// Run Collection
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct RunCollectionInput {
+    pub project_id: ProjectId,
+    /// The directory whose endpoints are run, its subdirectories included.
+    /// The whole project is run when it's empty.
+    pub path: PathBuf,
+    /// A CSV file with a header row, or a JSON file holding an array of objects. The endpoints
+    /// are run once for every row, with its columns bound as variables.
+    pub data_file: Option<PathBuf>,
+    /// Number of endpoints sent at once. The endpoints are sent one after another when omitted.
+    #[validate(range(min = 1, max = 32))]
+    pub concurrency: Option<u32>,
+    /// Time to wait for the response of every endpoint, in milliseconds. Defaults to 30 seconds.
+    pub request_timeout_ms: Option<u32>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct RunCollectionOutput {
+    pub status: RunStatus,
+    pub iterations: u32,
+    pub passed: u32,
+    pub failed: u32,
+    /// Number of endpoints left unsent once the run was canceled or timed out.
+    pub skipped: u32,
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+    /// Results of the endpoints sent, in the order they are run.
+    pub results: Vec<RunResultInfo>,
+}
@@ -0,0 +1,101 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::contracts::main::resource::ResponseHeader;
+
+/// @category Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/types.ts")]
+pub struct SseEventInfo {
+    /// Increases with every event of the stream, across reconnections, starting from 1.
+    #[ts(type = "number")]
+    pub seq: u64,
+    /// Type of the event, `message` unless the server set one.
+    pub event: String,
+    pub data: String,
+    /// Last event id set by the server, which is sent back when reconnecting.
+    pub id: Option<String>,
+    /// When the event was received, in RFC 3339 format.
+    pub timestamp: String,
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum SseEvent {
+    /// Sent every time the server accepts a connection, with the headers of its response.
+    Connected {
+        status: u16,
+        headers: Vec<ResponseHeader>,
+    },
+    /// Sent for every event dispatched by the server.
+    Event { event: SseEventInfo },
+    /// Sent before waiting to reconnect, once the stream ended or the connection was lost.
+    #[serde(rename_all = "camelCase")]
+    Reconnecting {
+        /// Attempts to reconnect in a row, starting from 1.
+        attempt: usize,
+        #[ts(type = "number")]
+        delay_ms: u64,
+        #[ts(optional)]
+        last_event_id: Option<String>,
+    },
+}
+
+//
+// Execute Sse
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteSseInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ExecuteSseOutput {
+    /// Number of events received, across reconnections.
+    #[ts(type = "number")]
+    pub received: u64,
+    #[ts(type = "number")]
+    pub reconnects: u64,
+    pub last_event_id: Option<String>,
+    /// Time elapsed from sending the request until the stream stopped.
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+}
+
+//
+// List Sse Events
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListSseEventsInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListSseEventsOutput {
+    /// The most recent events recorded in the last stream of the endpoint, from the oldest to
+    /// the most recent.
+    pub events: Vec<SseEventInfo>,
+}
@@ -0,0 +1,174 @@
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::path::PathBuf;
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum WebSocketFrameDirection {
+    Sent,
+    Received,
+}
+
+/// @category Type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum WebSocketFrameKind {
+    Text,
+    Binary,
+    Ping,
+    Pong,
+    Close,
+}
+
+/// @category Type
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/types.ts")]
+pub struct WebSocketFrameInfo {
+    /// Increases with every frame of the session, starting from 1.
+    #[ts(type = "number")]
+    pub id: u64,
+    pub direction: WebSocketFrameDirection,
+    pub kind: WebSocketFrameKind,
+    /// When the frame was sent or received, in RFC 3339 format.
+    pub timestamp: String,
+    /// Size of the payload, in bytes.
+    #[ts(type = "number")]
+    pub size: u64,
+    /// Payload of text frames, and reason of close frames.
+    pub text: Option<String>,
+    /// Payload of binary, ping and pong frames.
+    pub data: Option<Vec<u8>>,
+    pub close_code: Option<u16>,
+}
+
+/// @category Type
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/types.ts")]
+pub enum OutgoingWebSocketMessage {
+    /// One of the messages saved to the endpoint.
+    #[serde(rename_all = "camelCase")]
+    Saved {
+        message_id: String,
+    },
+    Text(String),
+    Json(#[ts(type = "JsonValue")] JsonValue),
+    /// Path of the file whose content is sent.
+    Binary(PathBuf),
+}
+
+/// @category Event
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/events.ts")]
+pub enum WebSocketEvent {
+    /// Sent for every frame sent or received over the session.
+    Frame { frame: WebSocketFrameInfo },
+    /// Sent once, when the connection is closed by either side or lost.
+    Closed {
+        #[ts(optional)]
+        code: Option<u16>,
+        #[ts(optional)]
+        reason: Option<String>,
+        /// Why the connection was lost, when it wasn't closed by either side.
+        #[ts(optional)]
+        error: Option<String>,
+    },
+}
+
+//
+// Connect WebSocket
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ConnectWebSocketInput {
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ConnectWebSocketOutput {
+    pub session_id: String,
+    /// The subprotocol the server picked among the ones offered.
+    pub protocol: Option<String>,
+}
+
+//
+// Send WebSocket Message
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct SendWebSocketMessageInput {
+    pub session_id: String,
+    pub message: OutgoingWebSocketMessage,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct SendWebSocketMessageOutput {
+    pub frame: WebSocketFrameInfo,
+}
+
+//
+// Close WebSocket
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct CloseWebSocketInput {
+    pub session_id: String,
+    /// Defaults to 1000, a normal closure.
+    pub code: Option<u16>,
+    pub reason: Option<String>,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct CloseWebSocketOutput {}
+
+//
+// List WebSocket Frames
+//
+
+/// @category Operation
+#[derive(Debug, Clone, Deserialize, Serialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListWebSocketFramesInput {
+    pub session_id: String,
+}
+
+/// @category Operation
+#[derive(Debug, Clone, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "main/operations.ts")]
+pub struct ListWebSocketFramesOutput {
+    /// The most recent frames of the session, from the oldest to the most recent.
+    pub frames: Vec<WebSocketFrameInfo>,
+    pub open: bool,
+}
@@ -0,0 +1,44 @@
+pub mod har;
+pub mod http_file;
+pub mod openapi;
+
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_project::models::operations::DescribeResourceOutput;
+use sapic_base::resource::types::primitives::ResourceId;
+use std::path::PathBuf;
+
+use crate::project::RuntimeProject;
+
+/// A resource of a project, as it's written into the document of another tool.
+#[derive(Debug, Clone)]
+pub struct ExportedResource {
+    pub id: ResourceId,
+    /// Path of the resource relative to the root of the project, including its own name.
+    pub path: PathBuf,
+    pub description: DescribeResourceOutput,
+}
+
+/// Describes every resource of the project, ordered by path so that exports are stable.
+pub async fn describe_resources<R: AppRuntime>(
+    ctx: &R::AsyncContext,
+    app_delegate: &AppDelegate<R>,
+    project: &RuntimeProject,
+) -> joinerror::Result<Vec<ExportedResource>> {
+    let mut summaries = project.resources(ctx, vec![PathBuf::new()]).await?;
+    summaries.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut resources = Vec::with_capacity(summaries.len());
+    for summary in summaries {
+        let description = project
+            .describe_resource(ctx, app_delegate, summary.id.clone())
+            .await?;
+        resources.push(ExportedResource {
+            id: summary.id,
+            path: summary.path,
+            description,
+        });
+    }
+
+    Ok(resources)
+}
@@ -0,0 +1,289 @@
+use chrono::SecondsFormat;
+use serde::Serialize;
+
+use crate::{import::form_pairs, resource::history::Execution};
+
+const HAR_VERSION: &str = "1.2";
+const CREATOR_NAME: &str = "Sapic";
+
+/// A HAR 1.2 log. Typed rather than built as JSON, so that its fields
+/// are written in the order of the specification, as browsers write them.
+#[derive(Debug, Serialize)]
+pub struct Har {
+    log: Log,
+}
+
+#[derive(Debug, Serialize)]
+struct Log {
+    version: &'static str,
+    creator: Creator,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Serialize)]
+struct Creator {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    started_date_time: String,
+    time: u64,
+    request: Request,
+    response: Response,
+    cache: Cache,
+    timings: Timings,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+    method: String,
+    url: String,
+    http_version: String,
+    cookies: Vec<NameValue>,
+    headers: Vec<NameValue>,
+    query_string: Vec<NameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostData>,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostData {
+    mime_type: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    params: Vec<NameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Response {
+    status: u16,
+    status_text: String,
+    http_version: String,
+    cookies: Vec<NameValue>,
+    headers: Vec<NameValue>,
+    content: Content,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Content {
+    size: usize,
+    mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Cache {}
+
+/// Only the total time of an execution is known, which is reported as waiting for the response.
+#[derive(Debug, Serialize)]
+struct Timings {
+    send: u64,
+    wait: u64,
+    receive: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct NameValue {
+    name: String,
+    value: String,
+}
+
+/// Converts the executions of a project into a HAR log, in the order they were sent.
+/// Sizes unknown to the history, such as the ones of headers, are written as `-1`.
+pub fn convert(executions: &[Execution]) -> Har {
+    Har {
+        log: Log {
+            version: HAR_VERSION,
+            creator: Creator {
+                name: CREATOR_NAME,
+                version: env!("CARGO_PKG_VERSION"),
+            },
+            entries: executions.iter().map(entry).collect(),
+        },
+    }
+}
+
+fn entry(execution: &Execution) -> Entry {
+    let request = &execution.request;
+    let response = &execution.response;
+
+    let query = request
+        .url
+        .split_once('?')
+        .map(|(_, query)| query.split('#').next().unwrap_or_default())
+        .unwrap_or_default();
+    let post_data = request.body.as_ref().map(|body| PostData {
+        mime_type: body.mime_type.clone(),
+        params: name_values(&body.params),
+        text: body.text.clone(),
+    });
+    let body_size = request
+        .body
+        .as_ref()
+        .map_or(Some(0), |body| body.size)
+        .map_or(-1, |size| size as i64);
+
+    Entry {
+        started_date_time: execution
+            .started_at
+            .to_rfc3339_opts(SecondsFormat::Millis, true),
+        time: execution.duration_ms,
+        request: Request {
+            method: request.method.clone(),
+            url: request.url.clone(),
+            http_version: request.http_version.clone(),
+            cookies: vec![],
+            headers: name_values(&request.headers),
+            query_string: form_pairs(query)
+                .map(|(name, value)| NameValue { name, value })
+                .collect(),
+            post_data,
+            headers_size: -1,
+            body_size,
+        },
+        response: Response {
+            status: response.status,
+            status_text: response.status_text.clone(),
+            http_version: request.http_version.clone(),
+            cookies: vec![],
+            headers: name_values(&response.headers),
+            content: Content {
+                size: response.size,
+                mime_type: response.mime_type(),
+                text: response.text(),
+            },
+            redirect_url: response
+                .headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default(),
+            headers_size: -1,
+            body_size: response.size as i64,
+        },
+        cache: Cache {},
+        timings: Timings {
+            send: 0,
+            wait: execution.duration_ms,
+            receive: 0,
+        },
+    }
+}
+
+fn name_values(pairs: &[(String, String)]) -> Vec<NameValue> {
+    pairs
+        .iter()
+        .map(|(name, value)| NameValue {
+            name: name.clone(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use moss_project::models::operations::BatchCreateResourceKind;
+    use sapic_ipc::contracts::main::resource::{ExecuteResourceEvent, ResponseHeader};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        import,
+        resource::history::{RecordedBody, RecordedRequest, RecordedResponse},
+    };
+
+    fn execution() -> Execution {
+        let mut response = RecordedResponse::default();
+        for event in [
+            ExecuteResourceEvent::Status {
+                status: 201,
+                status_text: "Created".to_string(),
+            },
+            ExecuteResourceEvent::Headers {
+                headers: vec![ResponseHeader {
+                    name: "content-type".to_string(),
+                    value: "application/json".to_string(),
+                }],
+            },
+            ExecuteResourceEvent::Body {
+                chunk: b"{\"id\":1}".to_vec(),
+            },
+        ] {
+            response.record(&event);
+        }
+
+        Execution {
+            started_at: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+            duration_ms: 42,
+            request: RecordedRequest {
+                method: "POST".to_string(),
+                url: "http://localhost:8080/pets?tag=good%20boy".to_string(),
+                http_version: "HTTP/1.1".to_string(),
+                headers: vec![("content-type".to_string(), "application/json".to_string())],
+                body: Some(RecordedBody {
+                    mime_type: "application/json".to_string(),
+                    size: Some(14),
+                    text: Some("{\"name\":\"Rex\"}".to_string()),
+                    params: vec![],
+                }),
+            },
+            response,
+        }
+    }
+
+    #[test]
+    fn test_convert() {
+        let har = serde_json::to_value(convert(&[execution()])).unwrap();
+        let entry = &har["log"]["entries"][0];
+
+        assert_eq!(har["log"]["version"], "1.2");
+        assert_eq!(entry["startedDateTime"], "2025-01-02T03:04:05.000Z");
+        assert_eq!(entry["time"], 42);
+        assert_eq!(
+            entry["request"]["queryString"],
+            json!([{ "name": "tag", "value": "good boy" }])
+        );
+        assert_eq!(entry["request"]["bodySize"], 14);
+        assert_eq!(entry["request"]["postData"]["text"], "{\"name\":\"Rex\"}");
+        assert_eq!(entry["response"]["status"], 201);
+        assert_eq!(
+            entry["response"]["content"],
+            json!({ "size": 8, "mimeType": "application/json", "text": "{\"id\":1}" })
+        );
+        assert_eq!(entry["response"]["redirectURL"], "");
+    }
+
+    #[test]
+    fn test_imports_exported_log() {
+        let har = serde_json::to_value(convert(&[execution(), execution()])).unwrap();
+        let imported = import::har::convert(&har, None).unwrap();
+
+        let items = imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].url.as_deref(), Some("http://localhost:8080/pets"));
+        assert_eq!(imported.skipped.len(), 1);
+    }
+}
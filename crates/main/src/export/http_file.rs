@@ -0,0 +1,409 @@
+use moss_project::models::types::{ApiKeyLocation, AuthScheme, BodyInfo};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceKind};
+
+use crate::{
+    export::ExportedResource,
+    resource::{http_method, value_to_string},
+};
+
+const FORM_DATA_BOUNDARY: &str = "SapicFormBoundary";
+
+/// Writes the endpoints found in `dir`, at any depth, as the requests of a `.http` file.
+///
+/// The headers and the auth the endpoints inherit are written into every request, since
+/// the file has nothing to share them. Auths the format can't express are left as comments.
+pub fn convert(dir: &ExportedResource, resources: &[ExportedResource]) -> String {
+    let requests = resources
+        .iter()
+        .filter(|resource| {
+            resource.path.starts_with(&dir.path)
+                && resource.description.kind == ResourceKind::Item
+                && resource.description.class == ResourceClass::Endpoint
+        })
+        .filter_map(request)
+        .collect::<Vec<_>>();
+
+    requests.join("\n")
+}
+
+/// Requests of other protocols than http are left out.
+fn request(resource: &ExportedResource) -> Option<String> {
+    let description = &resource.description;
+    let method = http_method(description.protocol.as_ref()?)?;
+    let mut lines = vec![format!("### {}", description.name)];
+    lines.extend(
+        description
+            .description
+            .iter()
+            .flat_map(|text| text.lines())
+            .map(|line| format!("# {}", line).trim_end().to_string()),
+    );
+
+    let mut url = description.url.clone().unwrap_or_default();
+    for param in &description.path_params {
+        let value = value_to_string(&param.value);
+        let value = if value.is_empty() {
+            format!("{{{{{}}}}}", param.name)
+        } else {
+            value
+        };
+        url = replace_path_variable(&url, &param.name, &value);
+    }
+
+    let mut query = description
+        .query_params
+        .iter()
+        .filter(|param| !param.disabled)
+        .map(|param| (param.name.clone(), value_to_string(&param.value)))
+        .collect::<Vec<_>>();
+    let mut headers = description
+        .headers
+        .iter()
+        .filter(|header| !header.disabled)
+        .map(|header| (header.name.clone(), value_to_string(&header.value)))
+        .collect::<Vec<_>>();
+
+    match description.auth.as_ref().map(|auth| &auth.scheme) {
+        None | Some(AuthScheme::None) | Some(AuthScheme::Inherit) => {}
+        Some(AuthScheme::Basic { username, password }) => headers.push((
+            "Authorization".to_string(),
+            format!("Basic {} {}", username, password),
+        )),
+        Some(AuthScheme::Digest { username, password }) => headers.push((
+            "Authorization".to_string(),
+            format!("Digest {} {}", username, password),
+        )),
+        Some(AuthScheme::Bearer { token }) => {
+            headers.push(("Authorization".to_string(), format!("Bearer {}", token)))
+        }
+        Some(AuthScheme::ApiKey {
+            key,
+            value,
+            location,
+        }) => match location {
+            ApiKeyLocation::Header => headers.push((key.clone(), value.clone())),
+            ApiKeyLocation::Query => query.push((key.clone(), value.clone())),
+        },
+        Some(AuthScheme::OAuth2(_)) => {
+            lines.push("# The OAuth 2.0 auth of the request isn't exported".to_string())
+        }
+        Some(AuthScheme::AwsSigV4(_)) => {
+            lines.push("# The AWS Signature V4 auth of the request isn't exported".to_string())
+        }
+    }
+
+    if !query.is_empty() {
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(&form_pairs(&query));
+    }
+    lines.push(format!("{} {}", method, url));
+
+    let body = description.body.as_ref().map(|body| match body {
+        BodyInfo::Text(text) => ("text/plain".to_string(), text.clone()),
+        BodyInfo::Json(json) => (
+            "application/json".to_string(),
+            serde_json::to_string_pretty(json).unwrap_or_else(|_| json.to_string()),
+        ),
+        BodyInfo::Xml(xml) => ("application/xml".to_string(), xml.clone()),
+        BodyInfo::Binary(path) => (
+            "application/octet-stream".to_string(),
+            format!("< {}", path.display()),
+        ),
+        BodyInfo::Urlencoded(params) => (
+            "application/x-www-form-urlencoded".to_string(),
+            form_pairs(
+                &params
+                    .iter()
+                    .filter(|param| !param.disabled)
+                    .map(|param| (param.name.clone(), value_to_string(&param.value)))
+                    .collect::<Vec<_>>(),
+            ),
+        ),
+        BodyInfo::FormData(params) => (
+            format!("multipart/form-data; boundary={}", FORM_DATA_BOUNDARY),
+            form_data(
+                params
+                    .iter()
+                    .filter(|param| !param.disabled)
+                    .map(|param| (param.name.as_str(), value_to_string(&param.value))),
+            ),
+        ),
+    });
+
+    // The content type is sent for every kind of body, unless the request sets its own
+    if let Some((content_type, _)) = &body {
+        let header = headers
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        let is_form = matches!(
+            description.body,
+            Some(BodyInfo::Urlencoded(_) | BodyInfo::FormData(_))
+        );
+        match header {
+            Some(index) if is_form => headers[index].1 = content_type.clone(),
+            Some(_) => {}
+            None => headers.push(("Content-Type".to_string(), content_type.clone())),
+        }
+    }
+
+    lines.extend(
+        headers
+            .into_iter()
+            .map(|(name, value)| format!("{}: {}", name, value)),
+    );
+    if let Some((_, body)) = body {
+        lines.push(String::new());
+        lines.push(body);
+    }
+    lines.push(String::new());
+
+    Some(lines.join("\n"))
+}
+
+/// Replaces the `:name` segments of the path of the url.
+fn replace_path_variable(url: &str, name: &str, value: &str) -> String {
+    let segment = format!(":{}", name);
+    url.split('/')
+        .map(|part| if part == segment { value } else { part })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Encodes the pairs of a query or of an urlencoded body.
+pub(crate) fn form_pairs(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Encodes a component of a query or of an urlencoded body, leaving its `{{name}}`
+/// variables as they are so that the client substitutes them.
+fn encode(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((before, after)) = rest.split_once("{{") {
+        let Some((name, after)) = after.split_once("}}") else {
+            break;
+        };
+        result.push_str(&urlencoding::encode(before));
+        result.push_str("{{");
+        result.push_str(name);
+        result.push_str("}}");
+        rest = after;
+    }
+    result.push_str(&urlencoding::encode(rest));
+
+    result
+}
+
+fn form_data<'a>(params: impl Iterator<Item = (&'a str, String)>) -> String {
+    let mut body = String::new();
+    for (name, value) in params {
+        body.push_str(&format!(
+            "--{}\nContent-Disposition: form-data; name=\"{}\"\n\n{}\n",
+            FORM_DATA_BOUNDARY, name, value
+        ));
+    }
+    body.push_str(&format!("--{}--", FORM_DATA_BOUNDARY));
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use moss_project::models::{
+        operations::{BatchCreateResourceKind, DescribeResourceOutput},
+        primitives::{FormDataParamId, HeaderId, PathParamId, QueryParamId},
+        types::{
+            AuthInfo, FormDataParamInfo, HeaderInfo, PathParamInfo, QueryParamInfo,
+            http::AddBodyParams,
+        },
+    };
+    use sapic_base::resource::types::primitives::{ResourceId, ResourceProtocol};
+    use serde_json::json;
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+    use crate::import;
+
+    fn resource(path: &str, kind: ResourceKind) -> ExportedResource {
+        ExportedResource {
+            id: ResourceId::new(),
+            path: PathBuf::from(path),
+            description: DescribeResourceOutput {
+                name: Path::new(path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string(),
+                description: None,
+                class: ResourceClass::Endpoint,
+                kind,
+                protocol: None,
+                url: None,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: None,
+                body_component: None,
+                body_schema: None,
+                components: vec![],
+                auth: None,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            },
+        }
+    }
+
+    fn endpoint(path: &str, protocol: ResourceProtocol, url: &str) -> ExportedResource {
+        let mut resource = resource(path, ResourceKind::Item);
+        resource.description.protocol = Some(protocol);
+        resource.description.url = Some(url.to_string());
+        resource
+    }
+
+    fn header(name: &str, value: &str, disabled: bool) -> HeaderInfo {
+        HeaderInfo {
+            id: HeaderId::new(),
+            name: name.to_string(),
+            value: json!(value),
+            description: None,
+            disabled,
+            propagate: false,
+            order: None,
+            inherited_from: None,
+            component: None,
+        }
+    }
+
+    #[test]
+    fn test_requests_of_dir() {
+        let dir = resource("Pets", ResourceKind::Dir);
+
+        let mut get = endpoint(
+            "Pets/Get pet",
+            ResourceProtocol::Get,
+            "{{baseUrl}}/pets/:id",
+        );
+        get.description.description = Some("Gets a pet.\n\nBy its id.".to_string());
+        get.description.path_params = vec![PathParamInfo {
+            id: PathParamId::new(),
+            name: "id".to_string(),
+            value: json!(""),
+            description: None,
+            disabled: false,
+            propagate: false,
+            order: None,
+            inherited_from: None,
+            component: None,
+        }];
+        get.description.query_params = vec![QueryParamInfo {
+            id: QueryParamId::new(),
+            name: "fields".to_string(),
+            value: json!("name age"),
+            description: None,
+            disabled: false,
+            propagate: false,
+            order: None,
+            inherited_from: None,
+            component: None,
+        }];
+        get.description.headers = vec![
+            header("Accept", "application/json", false),
+            header("X-Debug", "1", true),
+        ];
+        get.description.auth = Some(AuthInfo {
+            scheme: AuthScheme::Bearer {
+                token: "{{token}}".to_string(),
+            },
+            inherited_from: Some(dir.id.clone()),
+        });
+
+        let mut create = endpoint(
+            "Pets/Admin/Create",
+            ResourceProtocol::Post,
+            "{{baseUrl}}/pets",
+        );
+        create.description.body = Some(BodyInfo::Json(json!({ "name": "{{name}}" })));
+
+        let other = endpoint("Users/List", ResourceProtocol::Get, "{{baseUrl}}/users");
+
+        let content = convert(&dir, &[dir.clone(), create, get, other]);
+        assert_eq!(
+            content,
+            "### Create\n\
+             POST {{baseUrl}}/pets\n\
+             Content-Type: application/json\n\
+             \n\
+             {\n  \"name\": \"{{name}}\"\n}\n\
+             \n\
+             ### Get pet\n\
+             # Gets a pet.\n\
+             #\n\
+             # By its id.\n\
+             GET {{baseUrl}}/pets/{{id}}?fields=name%20age\n\
+             Accept: application/json\n\
+             Authorization: Bearer {{token}}\n"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = resource("Pets", ResourceKind::Dir);
+        let mut upload = endpoint(
+            "Pets/Upload",
+            ResourceProtocol::Put,
+            "https://example.com/pets/photo",
+        );
+        upload.description.body = Some(BodyInfo::Binary(PathBuf::from("/tmp/rex.png")));
+        let mut create = endpoint(
+            "Pets/Create",
+            ResourceProtocol::Post,
+            "https://example.com/pets",
+        );
+        create.description.body = Some(BodyInfo::FormData(vec![FormDataParamInfo {
+            id: FormDataParamId::new(),
+            name: "name".to_string(),
+            value: json!("{{name}}"),
+            description: None,
+            disabled: false,
+            propagate: false,
+            order: None,
+            inherited_from: None,
+        }]));
+
+        let content = convert(&dir, &[dir.clone(), create, upload]);
+        let imported = import::http_file::convert(&content, "Pets", Path::new("/tmp")).unwrap();
+        assert!(imported.skipped.is_empty());
+
+        let items = imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(items[0].name, "Create");
+        assert!(items[0].headers.is_empty());
+        let Some(AddBodyParams::FormData(fields)) = &items[0].body else {
+            panic!("expected a form data body");
+        };
+        assert_eq!(fields[0].name, "name");
+        assert_eq!(fields[0].value, json!("{{name}}"));
+
+        assert_eq!(items[1].name, "Upload");
+        assert_eq!(items[1].protocol, Some(ResourceProtocol::Put));
+        assert!(items[1].headers.is_empty());
+        assert!(
+            matches!(&items[1].body, Some(AddBodyParams::Binary(path)) if path == Path::new("/tmp/rex.png"))
+        );
+    }
+}
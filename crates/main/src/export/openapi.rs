@@ -0,0 +1,846 @@
+use moss_project::models::{
+    operations::DescribeResourceOutput,
+    types::{BodyInfo, HeaderInfo},
+};
+use sapic_base::resource::types::primitives::{
+    ResourceClass, ResourceId, ResourceKind, ResourceProtocol,
+};
+use serde::Serialize;
+use serde_json::{Map, Value as JsonValue, json};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+};
+
+use crate::export::ExportedResource;
+
+const OPENAPI_VERSION: &str = "3.1.0";
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+/// The variable the importer uses for documents without servers, which maps back to a relative server.
+const BASE_URL_VARIABLE: &str = "{{baseUrl}}";
+
+/// An OpenAPI 3.1 document. Typed rather than built as JSON, so that its
+/// fields are written in the order readers of the specification expect.
+#[derive(Debug, Serialize)]
+pub struct Document {
+    openapi: &'static str,
+    info: Info,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    servers: Vec<Server>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Tag>,
+    paths: BTreeMap<String, BTreeMap<&'static str, Operation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Components>,
+}
+
+#[derive(Debug, Serialize)]
+struct Info {
+    title: String,
+    version: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Server {
+    url: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    variables: BTreeMap<String, ServerVariable>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ServerVariable {
+    default: String,
+    description: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Tag {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Operation {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    summary: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    servers: Vec<Server>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parameters: Vec<Parameter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_body: Option<RequestBody>,
+    responses: BTreeMap<&'static str, Response>,
+}
+
+#[derive(Debug, Serialize)]
+struct Parameter {
+    name: String,
+    #[serde(rename = "in")]
+    location: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    required: bool,
+    schema: JsonValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<JsonValue>,
+}
+
+#[derive(Debug, Serialize)]
+struct RequestBody {
+    content: BTreeMap<String, MediaType>,
+}
+
+#[derive(Debug, Serialize)]
+struct MediaType {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<JsonValue>,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    description: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Components {
+    schemas: BTreeMap<String, JsonValue>,
+}
+
+/// Converts the resources of a project into an OpenAPI 3.1 document.
+///
+/// Endpoints become the operations of `paths`, tagged with the directories containing them,
+/// and schema resources become `components/schemas`. Endpoints that can't be described
+/// by OpenAPI, such as the ones of other protocols, are left out.
+pub fn convert(title: &str, resources: &[ExportedResource]) -> Document {
+    let dir_names = resources
+        .iter()
+        .filter(|resource| resource.description.kind == ResourceKind::Dir)
+        .map(|resource| (resource.path.as_path(), resource.description.name.as_str()))
+        .collect::<HashMap<_, _>>();
+    let schema_names = schema_names(resources);
+
+    let endpoints = resources
+        .iter()
+        .filter(|resource| {
+            resource.description.kind == ResourceKind::Item
+                && resource.description.class == ResourceClass::Endpoint
+        })
+        .collect::<Vec<_>>();
+    let servers = document_servers(&endpoints);
+
+    let mut tags = Vec::<String>::new();
+    let mut paths = BTreeMap::<String, BTreeMap<&'static str, Operation>>::new();
+    for resource in endpoints {
+        let description = &resource.description;
+        let Some(method) = description.protocol.as_ref().and_then(method) else {
+            tracing::warn!(
+                "resource `{}` is not exported, its protocol has no openapi counterpart",
+                resource.path.display()
+            );
+            continue;
+        };
+
+        let url = description.url.as_deref().unwrap_or_default();
+        let (origin, url_path) = split_url(url);
+        let path = openapi_path(url_path);
+        let operations = paths.entry(path.clone()).or_default();
+        if operations.contains_key(method) {
+            tracing::warn!(
+                "resource `{}` is not exported, another resource is already exported as `{} {}`",
+                resource.path.display(),
+                method,
+                path
+            );
+            continue;
+        }
+
+        let tag = tag(&resource.path, &dir_names);
+        if let Some(tag) = &tag
+            && !tags.contains(tag)
+        {
+            tags.push(tag.clone());
+        }
+
+        let server = server(origin);
+        let operation = Operation {
+            tags: tag.into_iter().collect(),
+            summary: description.name.clone(),
+            servers: if servers.first().map(|first| &first.url) == Some(&server.url) {
+                vec![]
+            } else {
+                vec![server]
+            },
+            parameters: parameters(description, &path),
+            request_body: request_body(description, &schema_names),
+            responses: BTreeMap::from([(
+                "default",
+                Response {
+                    description: "Default response",
+                },
+            )]),
+        };
+        operations.insert(method, operation);
+    }
+
+    let schemas = resources
+        .iter()
+        .filter(|resource| resource.description.class == ResourceClass::Schema)
+        .filter_map(|resource| {
+            let name = schema_names.get(&resource.id)?;
+            let schema = resource.description.schema.as_ref()?;
+            Some((name.clone(), component_schema(name, schema, &schema_names)))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    Document {
+        openapi: OPENAPI_VERSION,
+        info: Info {
+            title: title.to_string(),
+            version: "1.0.0".to_string(),
+        },
+        servers,
+        tags: tags.into_iter().map(|name| Tag { name }).collect(),
+        paths,
+        components: (!schemas.is_empty()).then_some(Components { schemas }),
+    }
+}
+
+fn method(protocol: &ResourceProtocol) -> Option<&'static str> {
+    match protocol {
+        ResourceProtocol::Get => Some("get"),
+        ResourceProtocol::Put => Some("put"),
+        ResourceProtocol::Post => Some("post"),
+        ResourceProtocol::Delete => Some("delete"),
+        ResourceProtocol::Options => Some("options"),
+        ResourceProtocol::Head => Some("head"),
+        ResourceProtocol::Patch => Some("patch"),
+        ResourceProtocol::Trace => Some("trace"),
+        _ => None,
+    }
+}
+
+/// Names schema resources after themselves, as far as component names allow.
+fn schema_names(resources: &[ExportedResource]) -> HashMap<ResourceId, String> {
+    let mut taken = HashSet::new();
+    resources
+        .iter()
+        .filter(|resource| {
+            resource.description.kind == ResourceKind::Item
+                && resource.description.class == ResourceClass::Schema
+        })
+        .map(|resource| {
+            let name = component_name(&resource.description.name);
+            let mut unique = name.clone();
+            let mut suffix = 2;
+            while !taken.insert(unique.clone()) {
+                unique = format!("{}_{}", name, suffix);
+                suffix += 1;
+            }
+
+            (resource.id.clone(), unique)
+        })
+        .collect()
+}
+
+/// Component names can only be made of letters, digits and `.`, `-` and `_`.
+fn component_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || ".-_".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Schema resources carry the schemas they reference in their `$defs`. Those that are
+/// exported as components themselves are referenced there instead, and references to
+/// the rest are made relative to the document, since embedded schemas have no `$id`.
+fn component_schema(
+    name: &str,
+    schema: &JsonValue,
+    schema_names: &HashMap<ResourceId, String>,
+) -> JsonValue {
+    let mut schema = schema.clone();
+    let components = schema_names.values().collect::<HashSet<_>>();
+
+    let mut defs = match schema.as_object_mut().and_then(|s| s.remove("$defs")) {
+        Some(JsonValue::Object(defs)) => defs,
+        _ => Map::new(),
+    };
+    defs.retain(|def, _| !components.contains(def));
+    let kept = defs.keys().cloned().collect::<HashSet<_>>();
+
+    let rewrite = |reference: &str| -> Option<String> {
+        let rest = reference.strip_prefix("#/$defs/")?;
+        let (def, rest) = rest.split_once('/').unwrap_or((rest, ""));
+        let suffix = if rest.is_empty() {
+            String::new()
+        } else {
+            format!("/{}", rest)
+        };
+
+        if kept.contains(def) {
+            Some(format!(
+                "{}{}/$defs/{}{}",
+                SCHEMA_REF_PREFIX, name, def, suffix
+            ))
+        } else {
+            Some(format!("{}{}{}", SCHEMA_REF_PREFIX, def, suffix))
+        }
+    };
+    rewrite_refs(&mut schema, &rewrite);
+
+    let mut defs = JsonValue::Object(defs);
+    rewrite_refs(&mut defs, &rewrite);
+    if let (Some(schema), JsonValue::Object(defs)) = (schema.as_object_mut(), defs)
+        && !defs.is_empty()
+    {
+        schema.insert("$defs".to_string(), JsonValue::Object(defs));
+    }
+
+    schema
+}
+
+fn rewrite_refs(value: &mut JsonValue, rewrite: &impl Fn(&str) -> Option<String>) {
+    match value {
+        JsonValue::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    JsonValue::String(reference) if key == "$ref" => {
+                        if let Some(rewritten) = rewrite(reference) {
+                            *reference = rewritten;
+                        }
+                    }
+                    _ => rewrite_refs(value, rewrite),
+                }
+            }
+        }
+        JsonValue::Array(values) => {
+            for value in values {
+                rewrite_refs(value, rewrite);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Tags an endpoint with the names of the directories containing it.
+fn tag(path: &Path, dir_names: &HashMap<&Path, &str>) -> Option<String> {
+    let mut names = path
+        .ancestors()
+        .skip(1)
+        .filter(|ancestor| !ancestor.as_os_str().is_empty())
+        .map(|ancestor| {
+            dir_names
+                .get(ancestor)
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| {
+                    ancestor
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_default()
+                })
+        })
+        .collect::<Vec<_>>();
+    names.reverse();
+
+    (!names.is_empty()).then(|| names.join(" / "))
+}
+
+/// The origin most endpoints are sent to becomes the server of the document.
+fn document_servers(endpoints: &[&ExportedResource]) -> Vec<Server> {
+    let mut counts = Vec::<(&str, usize)>::new();
+    for endpoint in endpoints.iter().filter(|endpoint| {
+        endpoint
+            .description
+            .protocol
+            .as_ref()
+            .and_then(method)
+            .is_some()
+    }) {
+        let url = endpoint.description.url.as_deref().unwrap_or_default();
+        let (origin, _) = split_url(url);
+        match counts.iter_mut().find(|(existing, _)| *existing == origin) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((origin, 1)),
+        }
+    }
+
+    // The first one wins a tie, so that the choice doesn't depend on hashing
+    let mut best: Option<(&str, usize)> = None;
+    for (origin, count) in counts {
+        if best.is_none_or(|(_, best_count)| count > best_count) {
+            best = Some((origin, count));
+        }
+    }
+
+    best.map(|(origin, _)| vec![server(origin)])
+        .unwrap_or_default()
+}
+
+/// Splits a url into its origin and its path, without the query.
+fn split_url(url: &str) -> (&str, &str) {
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+
+    let origin_end = if let Some(scheme_end) = url.find("://") {
+        let authority = scheme_end + "://".len();
+        url[authority..]
+            .find('/')
+            .map_or(url.len(), |end| authority + end)
+    } else if url.starts_with("{{") {
+        url.find("}}").map_or(0, |end| end + "}}".len())
+    } else {
+        0
+    };
+
+    url.split_at(origin_end)
+}
+
+fn server(origin: &str) -> Server {
+    if origin.is_empty() || origin == BASE_URL_VARIABLE {
+        return Server {
+            url: "/".to_string(),
+            variables: BTreeMap::new(),
+        };
+    }
+
+    // Environment variables of the origin become server variables defaulting to themselves,
+    // so that importing the document again keeps using the environment
+    let mut url = String::new();
+    let mut variables = BTreeMap::new();
+    let mut rest = origin;
+    while let Some((before, after)) = rest.split_once("{{") {
+        url.push_str(before);
+        let Some((variable, after)) = after.split_once("}}") else {
+            url.push_str("{{");
+            rest = after;
+            continue;
+        };
+
+        url.push_str(&format!("{{{}}}", variable));
+        variables.insert(
+            variable.to_string(),
+            ServerVariable {
+                default: format!("{{{{{}}}}}", variable),
+                description: format!("Value of the `{}` environment variable", variable),
+            },
+        );
+        rest = after;
+    }
+    url.push_str(rest);
+
+    Server { url, variables }
+}
+
+/// Turns the `:name` path variables of a url path into `{name}` templates.
+fn openapi_path(url_path: &str) -> String {
+    let mut path = String::with_capacity(url_path.len() + 1);
+    if !url_path.starts_with('/') {
+        path.push('/');
+    }
+
+    let mut chars = url_path.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != ':' {
+            path.push(c);
+            continue;
+        }
+
+        let mut variable = String::new();
+        while let Some(&c) = chars.peek()
+            && (c.is_alphanumeric() || c == '_')
+        {
+            variable.push(c);
+            chars.next();
+        }
+
+        if variable.is_empty() {
+            path.push(':');
+        } else {
+            path.push_str(&format!("{{{}}}", variable));
+        }
+    }
+
+    path
+}
+
+fn path_variables(path: &str) -> impl Iterator<Item = &str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(variable, _)| variable))
+}
+
+fn parameters(description: &DescribeResourceOutput, path: &str) -> Vec<Parameter> {
+    let mut parameters = Vec::new();
+
+    // Every template of the path must be described, even without a path param for it
+    for variable in path_variables(path) {
+        let param = description
+            .path_params
+            .iter()
+            .find(|param| param.name == variable);
+        parameters.push(Parameter {
+            name: variable.to_string(),
+            location: "path",
+            description: param.and_then(|param| param.description.clone()),
+            required: true,
+            schema: value_schema(param.map(|param| &param.value)),
+            example: param.and_then(|param| example(&param.value)),
+        });
+    }
+
+    for param in &description.query_params {
+        parameters.push(Parameter {
+            name: param.name.clone(),
+            location: "query",
+            description: param.description.clone(),
+            required: !param.disabled,
+            schema: value_schema(Some(&param.value)),
+            example: example(&param.value),
+        });
+    }
+
+    // The content type is the media type of the request body instead
+    for header in description
+        .headers
+        .iter()
+        .filter(|header| !header.name.eq_ignore_ascii_case("content-type"))
+    {
+        parameters.push(Parameter {
+            name: header.name.clone(),
+            location: "header",
+            description: header.description.clone(),
+            required: !header.disabled,
+            schema: value_schema(Some(&header.value)),
+            example: example(&header.value),
+        });
+    }
+
+    parameters
+}
+
+fn example(value: &JsonValue) -> Option<JsonValue> {
+    match value {
+        JsonValue::Null => None,
+        JsonValue::String(value) if value.is_empty() => None,
+        value => Some(value.clone()),
+    }
+}
+
+fn value_schema(value: Option<&JsonValue>) -> JsonValue {
+    let kind = match value {
+        Some(JsonValue::Bool(_)) => "boolean",
+        Some(JsonValue::Number(number)) if number.is_f64() => "number",
+        Some(JsonValue::Number(_)) => "integer",
+        Some(JsonValue::Array(_)) => "array",
+        Some(JsonValue::Object(_)) => "object",
+        _ => "string",
+    };
+
+    json!({ "type": kind })
+}
+
+fn request_body(
+    description: &DescribeResourceOutput,
+    schema_names: &HashMap<ResourceId, String>,
+) -> Option<RequestBody> {
+    let content_type = description
+        .headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case("content-type") && !header.disabled)
+        .and_then(|header: &HeaderInfo| header.value.as_str());
+    let media_type =
+        |default: &str| content_type.map_or_else(|| default.to_string(), str::to_string);
+
+    let (media_type, media) = match description.body.as_ref()? {
+        BodyInfo::Json(value) => (
+            media_type("application/json"),
+            MediaType {
+                schema: description
+                    .body_schema
+                    .as_ref()
+                    .and_then(|id| schema_names.get(id))
+                    .map(|name| json!({ "$ref": format!("{}{}", SCHEMA_REF_PREFIX, name) })),
+                example: Some(value.clone()),
+            },
+        ),
+        BodyInfo::Text(text) => (
+            media_type("text/plain"),
+            MediaType {
+                schema: Some(json!({ "type": "string" })),
+                example: example(&JsonValue::String(text.clone())),
+            },
+        ),
+        BodyInfo::Xml(text) => (
+            media_type("application/xml"),
+            MediaType {
+                schema: None,
+                example: example(&JsonValue::String(text.clone())),
+            },
+        ),
+        BodyInfo::Binary(_) => (
+            media_type("application/octet-stream"),
+            MediaType {
+                schema: Some(json!({ "type": "string", "format": "binary" })),
+                example: None,
+            },
+        ),
+        BodyInfo::Urlencoded(params) => (
+            "application/x-www-form-urlencoded".to_string(),
+            MediaType {
+                schema: Some(form_schema(
+                    params.iter().map(|param| (&param.name, &param.value)),
+                )),
+                example: None,
+            },
+        ),
+        BodyInfo::FormData(params) => (
+            "multipart/form-data".to_string(),
+            MediaType {
+                schema: Some(form_schema(
+                    params.iter().map(|param| (&param.name, &param.value)),
+                )),
+                example: None,
+            },
+        ),
+    };
+
+    Some(RequestBody {
+        content: BTreeMap::from([(media_type, media)]),
+    })
+}
+
+fn form_schema<'a>(fields: impl Iterator<Item = (&'a String, &'a JsonValue)>) -> JsonValue {
+    let properties = fields
+        .map(|(name, value)| {
+            let mut property = value_schema(Some(value));
+            if let Some(example) = example(value) {
+                property["example"] = example;
+            }
+
+            (name.clone(), property)
+        })
+        .collect::<Map<_, _>>();
+
+    json!({ "type": "object", "properties": properties })
+}
+
+#[cfg(test)]
+mod tests {
+    use moss_project::models::{
+        primitives::{HeaderId, QueryParamId},
+        types::{HeaderInfo, QueryParamInfo},
+    };
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::import;
+
+    fn resource(
+        path: &str,
+        kind: ResourceKind,
+        class: ResourceClass,
+        protocol: Option<ResourceProtocol>,
+        url: Option<&str>,
+    ) -> ExportedResource {
+        ExportedResource {
+            id: ResourceId::new(),
+            path: PathBuf::from(path),
+            description: DescribeResourceOutput {
+                name: Path::new(path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string(),
+                description: None,
+                class,
+                kind,
+                protocol,
+                url: url.map(str::to_string),
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: None,
+                body_component: None,
+                body_schema: None,
+                components: vec![],
+                auth: None,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            },
+        }
+    }
+
+    fn endpoint(path: &str, protocol: ResourceProtocol, url: &str) -> ExportedResource {
+        resource(
+            path,
+            ResourceKind::Item,
+            ResourceClass::Endpoint,
+            Some(protocol),
+            Some(url),
+        )
+    }
+
+    #[test]
+    fn test_split_url() {
+        assert_eq!(
+            split_url("https://example.com:8080/pets/:id?limit=1"),
+            ("https://example.com:8080", "/pets/:id")
+        );
+        assert_eq!(
+            split_url("https://example.com"),
+            ("https://example.com", "")
+        );
+        assert_eq!(split_url("{{baseUrl}}/pets"), ("{{baseUrl}}", "/pets"));
+        assert_eq!(split_url("/pets"), ("", "/pets"));
+    }
+
+    #[test]
+    fn test_openapi_path() {
+        assert_eq!(openapi_path("/pets/:pet_id/toys"), "/pets/{pet_id}/toys");
+        assert_eq!(openapi_path(""), "/");
+        assert_eq!(openapi_path("/a:/b"), "/a:/b");
+    }
+
+    #[test]
+    fn test_server_variables() {
+        let server = server("https://{{host}}");
+        assert_eq!(server.url, "https://{host}");
+        assert_eq!(server.variables["host"].default, "{{host}}");
+    }
+
+    #[test]
+    fn test_component_schema_refs() {
+        let pet = ResourceId::new();
+        let schema_names = HashMap::from([(pet, "Pet".to_string())]);
+        let schema = component_schema(
+            "Owner",
+            &json!({
+                "properties": {
+                    "pet": { "$ref": "#/$defs/Pet" },
+                    "address": { "$ref": "#/$defs/Address" }
+                },
+                "$defs": {
+                    "Pet": { "type": "object" },
+                    "Address": { "properties": { "owner": { "$ref": "#/$defs/Owner/properties/name" } } }
+                }
+            }),
+            &schema_names,
+        );
+
+        assert_eq!(
+            schema,
+            json!({
+                "properties": {
+                    "pet": { "$ref": "#/components/schemas/Pet" },
+                    "address": { "$ref": "#/components/schemas/Owner/$defs/Address" }
+                },
+                "$defs": {
+                    "Address": { "properties": { "owner": { "$ref": "#/components/schemas/Owner/properties/name" } } }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut list = endpoint(
+            "pets/List pets",
+            ResourceProtocol::Get,
+            "https://example.com/v1/pets",
+        );
+        list.description.query_params.push(QueryParamInfo {
+            id: QueryParamId::new(),
+            name: "limit".to_string(),
+            value: json!(20),
+            description: None,
+            disabled: true,
+            propagate: false,
+            order: Some(0),
+            inherited_from: None,
+            component: None,
+        });
+        let mut create = endpoint(
+            "pets/Create pet",
+            ResourceProtocol::Post,
+            "https://example.com/v1/pets",
+        );
+        create.description.body = Some(BodyInfo::Json(json!({ "name": "Rex" })));
+        create.description.headers.push(HeaderInfo {
+            id: HeaderId::new(),
+            name: "Content-Type".to_string(),
+            value: json!("application/merge-patch+json"),
+            description: None,
+            disabled: false,
+            propagate: false,
+            order: Some(0),
+            inherited_from: None,
+            component: None,
+        });
+        let socket = endpoint("Socket", ResourceProtocol::WebSocket, "wss://example.com");
+        let mut pets = resource(
+            "pets",
+            ResourceKind::Dir,
+            ResourceClass::Endpoint,
+            None,
+            None,
+        );
+        pets.description.name = "Pets".to_string();
+
+        let document = convert("Petstore", &[pets, create, list, socket]);
+        let document = serde_json::to_value(&document).unwrap();
+        assert_eq!(
+            document["servers"],
+            json!([{ "url": "https://example.com" }])
+        );
+        assert_eq!(document["tags"], json!([{ "name": "Pets" }]));
+        assert_eq!(
+            document["paths"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["/v1/pets"]
+        );
+
+        let imported = import::openapi::convert(&document).unwrap();
+        let items = imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                moss_project::models::operations::BatchCreateResourceKind::Item(item) => Some(item),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "List pets");
+        assert_eq!(items[0].path, PathBuf::from("Pets"));
+        assert_eq!(items[0].url.as_deref(), Some("https://example.com/v1/pets"));
+        assert_eq!(items[0].query_params[0].value, json!(20));
+        assert!(items[0].query_params[0].options.disabled);
+
+        assert_eq!(items[1].name, "Create pet");
+        assert_eq!(
+            items[1].headers[0].value,
+            json!("application/merge-patch+json")
+        );
+        let Some(moss_project::models::types::http::AddBodyParams::Json(body)) = &items[1].body
+        else {
+            panic!("expected a json body");
+        };
+        assert_eq!(body, &json!({ "name": "Rex" }));
+    }
+}
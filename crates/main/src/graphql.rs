@@ -0,0 +1,1213 @@
+use async_graphql_parser::{
+    Pos, Positioned,
+    types::{
+        Directive, ExecutableDocument, Field, FragmentDefinition, OperationDefinition,
+        OperationType, Selection, SelectionSet, VariableDefinition,
+    },
+};
+use async_graphql_value::{Name, Value};
+use futures::{SinkExt, StreamExt};
+use joinerror::{Error, OptionExt, ResultExt};
+use moss_project::models::{operations::DescribeResourceOutput, types::graphql::GraphqlInfo};
+use reqwest::{
+    Client, Method, RequestBuilder,
+    header::{ACCEPT, CONTENT_TYPE},
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_core::context::{self, AnyAsyncContext, ContextResultExt};
+use sapic_ipc::contracts::main::{
+    graphql::{GraphqlEncoding, GraphqlValidationError},
+    resource::{ResolveResourceOutput, ResolvedParam},
+};
+use serde::Deserialize;
+use serde_json::{Value as JsonValue, json};
+use std::collections::{HashMap, HashSet};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream,
+    tungstenite::{Message, handshake::client::Request},
+};
+
+use crate::{
+    resource::{
+        EnvironmentVariables,
+        auth::{self, AuthorizedRequest},
+    },
+    websocket,
+};
+
+/// Subprotocol of the graphql-ws library, which subscriptions are sent over.
+pub const GRAPHQL_TRANSPORT_WS: &str = "graphql-transport-ws";
+
+/// Id of the only subscription started over a connection.
+const SUBSCRIPTION_ID: &str = "1";
+
+/// Fetches everything the validation of operations relies on.
+pub const INTROSPECTION_QUERY: &str = r#"query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types { ...FullType }
+    directives {
+      name
+      description
+      locations
+      args { ...InputValue }
+    }
+  }
+}
+
+fragment FullType on __Type {
+  kind
+  name
+  description
+  fields(includeDeprecated: true) {
+    name
+    description
+    args { ...InputValue }
+    type { ...TypeRef }
+    isDeprecated
+    deprecationReason
+  }
+  inputFields { ...InputValue }
+  interfaces { ...TypeRef }
+  enumValues(includeDeprecated: true) {
+    name
+    description
+    isDeprecated
+    deprecationReason
+  }
+  possibleTypes { ...TypeRef }
+}
+
+fragment InputValue on __InputValue {
+  name
+  description
+  type { ...TypeRef }
+  defaultValue
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+          ofType {
+            kind
+            name
+            ofType {
+              kind
+              name
+              ofType {
+                kind
+                name
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+/// The `__schema` object of an introspection result, down to what operations are validated
+/// against.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schema {
+    pub query_type: Option<RootType>,
+    pub mutation_type: Option<RootType>,
+    pub subscription_type: Option<RootType>,
+    pub types: Vec<FullType>,
+    #[serde(default)]
+    pub directives: Vec<DirectiveType>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RootType {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FullType {
+    pub kind: TypeKind,
+    pub name: String,
+    /// Fields of object and interface types.
+    pub fields: Option<Vec<FieldType>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FieldType {
+    pub name: String,
+    #[serde(default)]
+    pub args: Vec<InputValue>,
+    #[serde(rename = "type")]
+    pub ty: TypeRef,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputValue {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: TypeRef,
+    pub default_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DirectiveType {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeRef {
+    pub kind: TypeKind,
+    pub name: Option<String>,
+    pub of_type: Option<Box<TypeRef>>,
+}
+
+impl TypeRef {
+    /// Name of the type once the list and non-null wrappers are removed.
+    pub fn named(&self) -> Option<&str> {
+        match &self.of_type {
+            Some(of_type) => of_type.named(),
+            None => self.name.as_deref(),
+        }
+    }
+}
+
+impl FullType {
+    fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Object | TypeKind::Interface | TypeKind::Union
+        )
+    }
+
+    fn is_input(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Scalar | TypeKind::Enum | TypeKind::InputObject
+        )
+    }
+}
+
+/// Fails unless the described resource is a GraphQL endpoint.
+pub fn ensure_graphql_endpoint(desc: &DescribeResourceOutput) -> joinerror::Result<()> {
+    if desc.class != ResourceClass::Endpoint || desc.protocol != Some(ResourceProtocol::Graphql) {
+        return Err(Error::new::<()>(format!(
+            "resource `{}` is not a graphql endpoint",
+            desc.name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reads the schema out of an introspection result, failing with the errors it holds when
+/// the endpoint couldn't be introspected.
+pub fn schema_from_introspection(result: &JsonValue) -> joinerror::Result<(JsonValue, Schema)> {
+    let Some(schema) = result
+        .pointer("/data/__schema")
+        .filter(|schema| !schema.is_null())
+    else {
+        return Err(Error::new::<()>(format!(
+            "failed to introspect the schema: {}",
+            result_errors(result).unwrap_or_else(|| "no schema returned".to_string())
+        )));
+    };
+
+    let parsed = serde_json::from_value(schema.clone()).join_err::<()>("invalid graphql schema")?;
+    Ok((schema.clone(), parsed))
+}
+
+fn result_errors(result: &JsonValue) -> Option<String> {
+    let errors = result.get("errors")?.as_array()?;
+    let messages = errors
+        .iter()
+        .map(
+            |error| match error.get("message").and_then(JsonValue::as_str) {
+                Some(message) => message.to_string(),
+                None => error.to_string(),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// Validates every operation and fragment of the document against the schema, returning
+/// the problems found in the order they appear in the document.
+pub fn validate(schema: &Schema, query: &str) -> Vec<GraphqlValidationError> {
+    let document = match async_graphql_parser::parse_query(query) {
+        Ok(document) => document,
+        Err(e) => return vec![parse_error(&e)],
+    };
+
+    let mut validator = Validator::new(schema, &document);
+    validator.validate_document();
+
+    let mut errors = validator.errors;
+    errors.sort_by_key(|error| (error.line, error.column));
+    errors.dedup();
+    errors
+}
+
+fn parse_error(e: &async_graphql_parser::Error) -> GraphqlValidationError {
+    let pos = e.positions().next().unwrap_or(Pos { line: 1, column: 1 });
+    let message = match e {
+        // Keep the reason, without the excerpt of the document around it
+        async_graphql_parser::Error::Syntax { message, .. } => message
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("= "))
+            .unwrap_or(message.as_str())
+            .to_string(),
+        other => other.to_string(),
+    };
+
+    GraphqlValidationError {
+        message,
+        line: pos.line,
+        column: pos.column,
+    }
+}
+
+/// Variables and fragments used by an operation or a fragment, directly.
+#[derive(Default)]
+struct Usages<'a> {
+    variables: Vec<(&'a Name, Pos)>,
+    spreads: Vec<&'a Name>,
+}
+
+struct Validator<'a> {
+    schema: &'a Schema,
+    document: &'a ExecutableDocument,
+    types: HashMap<&'a str, &'a FullType>,
+    errors: Vec<GraphqlValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(schema: &'a Schema, document: &'a ExecutableDocument) -> Self {
+        Self {
+            schema,
+            document,
+            types: schema
+                .types
+                .iter()
+                .map(|ty| (ty.name.as_str(), ty))
+                .collect(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn error(&mut self, pos: Pos, message: String) {
+        self.errors.push(GraphqlValidationError {
+            message,
+            line: pos.line,
+            column: pos.column,
+        });
+    }
+
+    fn validate_document(&mut self) {
+        let document = self.document;
+        let mut fragments = HashMap::new();
+        for (name, fragment) in &document.fragments {
+            fragments.insert(name, self.fragment(fragment));
+        }
+
+        let mut used_fragments = HashSet::new();
+        for (name, operation) in document.operations.iter() {
+            let usages = self.operation(operation);
+            let Some(usages) = usages else {
+                continue;
+            };
+
+            // Variables are defined by the operation, for the fragments it spreads as well
+            let mut variables = usages.variables;
+            let mut pending = usages.spreads;
+            let mut spread = HashSet::new();
+            while let Some(fragment) = pending.pop() {
+                if !spread.insert(fragment) {
+                    continue;
+                }
+                if let Some(usages) = fragments.get(fragment) {
+                    variables.extend(usages.variables.iter().copied());
+                    pending.extend(usages.spreads.iter().copied());
+                }
+            }
+            used_fragments.extend(spread);
+
+            self.operation_variables(name, &operation.node.variable_definitions, &variables);
+        }
+
+        for (name, fragment) in &document.fragments {
+            if !used_fragments.contains(name) {
+                self.error(fragment.pos, format!("fragment \"{name}\" is never used"));
+            }
+        }
+    }
+
+    fn operation(&mut self, operation: &'a Positioned<OperationDefinition>) -> Option<Usages<'a>> {
+        let definition = &operation.node;
+        let root = match definition.ty {
+            OperationType::Query => &self.schema.query_type,
+            OperationType::Mutation => &self.schema.mutation_type,
+            OperationType::Subscription => &self.schema.subscription_type,
+        };
+        let Some(root) = root
+            .as_ref()
+            .and_then(|root| self.types.get(root.name.as_str()).copied())
+        else {
+            self.error(
+                operation.pos,
+                format!("schema does not support {} operations", definition.ty),
+            );
+            return None;
+        };
+
+        let mut names = HashSet::new();
+        for variable in &definition.variable_definitions {
+            let name = &variable.node.name.node;
+            if !names.insert(name) {
+                self.error(
+                    variable.pos,
+                    format!("variable \"${name}\" is defined more than once"),
+                );
+            }
+            self.variable_type(variable);
+        }
+
+        let mut usages = Usages::default();
+        self.directives(&definition.directives, &mut usages);
+        self.selection_set(root, &definition.selection_set, &mut usages);
+
+        Some(usages)
+    }
+
+    fn variable_type(&mut self, variable: &'a Positioned<VariableDefinition>) {
+        let var_type = &variable.node.var_type;
+        let mut ty = &var_type.node;
+        while let async_graphql_parser::types::BaseType::List(of_type) = &ty.base {
+            ty = of_type;
+        }
+        let async_graphql_parser::types::BaseType::Named(name) = &ty.base else {
+            return;
+        };
+
+        match self.types.get(name.as_str()) {
+            None => self.error(var_type.pos, format!("unknown type \"{name}\"")),
+            Some(ty) if !ty.is_input() => self.error(
+                var_type.pos,
+                format!(
+                    "variable \"${}\" cannot be of non-input type \"{name}\"",
+                    variable.node.name.node
+                ),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    fn operation_variables(
+        &mut self,
+        operation: Option<&Name>,
+        definitions: &'a [Positioned<VariableDefinition>],
+        used: &[(&'a Name, Pos)],
+    ) {
+        let defined = definitions
+            .iter()
+            .map(|variable| &variable.node.name.node)
+            .collect::<HashSet<_>>();
+        for (name, pos) in used {
+            if !defined.contains(name) {
+                let message = match operation {
+                    Some(operation) => {
+                        format!("variable \"${name}\" is not defined by operation \"{operation}\"")
+                    }
+                    None => format!("variable \"${name}\" is not defined"),
+                };
+                self.error(*pos, message);
+            }
+        }
+
+        let used = used.iter().map(|(name, _)| *name).collect::<HashSet<_>>();
+        for variable in definitions {
+            let name = &variable.node.name.node;
+            if !used.contains(name) {
+                self.error(variable.pos, format!("variable \"${name}\" is never used"));
+            }
+        }
+    }
+
+    fn fragment(&mut self, fragment: &'a Positioned<FragmentDefinition>) -> Usages<'a> {
+        let mut usages = Usages::default();
+        self.directives(&fragment.node.directives, &mut usages);
+
+        let condition = &fragment.node.type_condition.node.on;
+        if let Some(ty) = self.type_condition(condition) {
+            self.selection_set(ty, &fragment.node.selection_set, &mut usages);
+        }
+
+        usages
+    }
+
+    fn type_condition(&mut self, name: &Positioned<Name>) -> Option<&'a FullType> {
+        match self.types.get(name.node.as_str()).copied() {
+            None => {
+                self.error(name.pos, format!("unknown type \"{}\"", name.node));
+                None
+            }
+            Some(ty) if !ty.is_composite() => {
+                self.error(
+                    name.pos,
+                    format!("fragment cannot be on non-composite type \"{}\"", name.node),
+                );
+                None
+            }
+            Some(ty) => Some(ty),
+        }
+    }
+
+    fn selection_set(
+        &mut self,
+        parent: &'a FullType,
+        selection_set: &'a Positioned<SelectionSet>,
+        usages: &mut Usages<'a>,
+    ) {
+        for selection in &selection_set.node.items {
+            match &selection.node {
+                Selection::Field(field) => self.field(parent, field, usages),
+                Selection::FragmentSpread(spread) => {
+                    self.directives(&spread.node.directives, usages);
+
+                    let name = &spread.node.fragment_name;
+                    if self.document.fragments.contains_key(&name.node) {
+                        usages.spreads.push(&name.node);
+                    } else {
+                        self.error(name.pos, format!("unknown fragment \"{}\"", name.node));
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    self.directives(&fragment.node.directives, usages);
+
+                    let ty = match &fragment.node.type_condition {
+                        Some(condition) => self.type_condition(&condition.node.on),
+                        None => Some(parent),
+                    };
+                    if let Some(ty) = ty {
+                        self.selection_set(ty, &fragment.node.selection_set, usages);
+                    }
+                }
+            }
+        }
+    }
+
+    fn field(
+        &mut self,
+        parent: &'a FullType,
+        field: &'a Positioned<Field>,
+        usages: &mut Usages<'a>,
+    ) {
+        let node = &field.node;
+        self.directives(&node.directives, usages);
+        for (_, value) in &node.arguments {
+            collect_variables(&value.node, value.pos, &mut usages.variables);
+        }
+
+        let name = node.name.node.as_str();
+        if name == "__typename" {
+            if !node.selection_set.node.items.is_empty() {
+                self.error(
+                    node.selection_set.pos,
+                    "field \"__typename\" cannot have a selection of subfields".to_string(),
+                );
+            }
+            return;
+        }
+        // The introspection fields of the query root aren't listed among its fields
+        if name.starts_with("__") {
+            return;
+        }
+
+        let definition = parent
+            .fields
+            .iter()
+            .flatten()
+            .find(|definition| definition.name == name);
+        let Some(definition) = definition else {
+            self.error(
+                node.name.pos,
+                format!(
+                    "field \"{name}\" does not exist on type \"{}\"",
+                    parent.name
+                ),
+            );
+            return;
+        };
+
+        for (argument, _) in &node.arguments {
+            if !definition
+                .args
+                .iter()
+                .any(|arg| arg.name == argument.node.as_str())
+            {
+                self.error(
+                    argument.pos,
+                    format!(
+                        "unknown argument \"{}\" on field \"{}.{name}\"",
+                        argument.node, parent.name
+                    ),
+                );
+            }
+        }
+        for arg in &definition.args {
+            let required = arg.ty.kind == TypeKind::NonNull && arg.default_value.is_none();
+            if required && node.get_argument(&arg.name).is_none() {
+                self.error(
+                    field.pos,
+                    format!(
+                        "field \"{}.{name}\" is missing required argument \"{}\"",
+                        parent.name, arg.name
+                    ),
+                );
+            }
+        }
+
+        let Some(ty) = definition
+            .ty
+            .named()
+            .and_then(|ty| self.types.get(ty).copied())
+        else {
+            return;
+        };
+        let selection_set = &node.selection_set;
+        if ty.is_composite() {
+            if selection_set.node.items.is_empty() {
+                self.error(
+                    field.pos,
+                    format!(
+                        "field \"{name}\" of type \"{}\" must have a selection of subfields",
+                        ty.name
+                    ),
+                );
+            } else {
+                self.selection_set(ty, selection_set, usages);
+            }
+        } else if !selection_set.node.items.is_empty() {
+            self.error(
+                selection_set.pos,
+                format!(
+                    "field \"{name}\" of type \"{}\" cannot have a selection of subfields",
+                    ty.name
+                ),
+            );
+        }
+    }
+
+    fn directives(&mut self, directives: &'a [Positioned<Directive>], usages: &mut Usages<'a>) {
+        for directive in directives {
+            for (_, value) in &directive.node.arguments {
+                collect_variables(&value.node, value.pos, &mut usages.variables);
+            }
+
+            // Some servers don't expose their directives, in which case any is accepted
+            let name = &directive.node.name;
+            if !self.schema.directives.is_empty()
+                && !self
+                    .schema
+                    .directives
+                    .iter()
+                    .any(|directive| directive.name == name.node.as_str())
+            {
+                self.error(name.pos, format!("unknown directive \"@{}\"", name.node));
+            }
+        }
+    }
+}
+
+fn collect_variables<'a>(value: &'a Value, pos: Pos, variables: &mut Vec<(&'a Name, Pos)>) {
+    match value {
+        Value::Variable(name) => variables.push((name, pos)),
+        Value::List(items) => {
+            for item in items {
+                collect_variables(item, pos, variables);
+            }
+        }
+        Value::Object(fields) => {
+            for value in fields.values() {
+                collect_variables(value, pos, variables);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Type of the operation of the document that is executed, which is the only one it defines
+/// unless it's picked by name.
+pub fn operation_type(
+    query: &str,
+    operation_name: Option<&str>,
+) -> joinerror::Result<OperationType> {
+    let document = async_graphql_parser::parse_query(query).map_err(|e| {
+        Error::new::<()>(format!(
+            "invalid graphql document: {}",
+            parse_error(&e).message
+        ))
+    })?;
+
+    let mut operations = document.operations.iter();
+    let operation = match operation_name {
+        Some(operation_name) => operations
+            .find(|(name, _)| name.is_some_and(|name| name == operation_name))
+            .ok_or_join_err_with::<()>(|| format!("operation `{operation_name}` not found"))?,
+        None => {
+            let operation = operations
+                .next()
+                .ok_or_join_err::<()>("document does not define any operation")?;
+            if operations.next().is_some() {
+                return Err(Error::new::<()>(
+                    "an operation name is required when the document defines several operations",
+                ));
+            }
+            operation
+        }
+    };
+
+    Ok(operation.1.node.ty)
+}
+
+/// The `{ query, operationName, variables }` object the operation of the endpoint is sent as,
+/// with the variables of the environments substituted in the values of its variables.
+pub fn operation_body(graphql: &GraphqlInfo, variables: &EnvironmentVariables) -> JsonValue {
+    let mut body = serde_json::Map::new();
+    body.insert(
+        "query".to_string(),
+        JsonValue::String(graphql.query.clone()),
+    );
+    if let Some(operation_name) = &graphql.operation_name {
+        body.insert(
+            "operationName".to_string(),
+            JsonValue::String(operation_name.clone()),
+        );
+    }
+    if let Some(values) = &graphql.variables {
+        body.insert("variables".to_string(), variables.render_json(values));
+    }
+
+    JsonValue::Object(body)
+}
+
+/// Builds the request of a resolved GraphQL endpoint, without any operation yet.
+pub fn endpoint_request(
+    client: &Client,
+    method: Method,
+    resolved: &ResolveResourceOutput,
+) -> joinerror::Result<RequestBuilder> {
+    let url = resolved.url.trim();
+    if url.is_empty() {
+        return Err(Error::new::<()>("resource has no url"));
+    }
+
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("http://{url}")
+    };
+
+    let mut request = client.request(method, &url);
+    if !resolved.query_params.is_empty() {
+        let query = resolved
+            .query_params
+            .iter()
+            .map(|p| (p.name.as_str(), p.value.as_str()))
+            .collect::<Vec<_>>();
+        request = request.query(&query);
+    }
+
+    let has_accept = resolved
+        .headers
+        .iter()
+        .any(|h| h.name.eq_ignore_ascii_case(ACCEPT.as_str()));
+    for header in &resolved.headers {
+        request = request.header(&header.name, &header.value);
+    }
+    if !has_accept {
+        request = request.header(
+            ACCEPT,
+            "application/graphql-response+json, application/json",
+        );
+    }
+
+    Ok(request)
+}
+
+/// Adds the operation to the request, as a json body or in the query string.
+pub fn add_operation(
+    request: RequestBuilder,
+    encoding: GraphqlEncoding,
+    body: &JsonValue,
+) -> joinerror::Result<RequestBuilder> {
+    match encoding {
+        GraphqlEncoding::Post => {
+            let bytes = serde_json::to_vec(body).join_err::<()>("failed to serialize operation")?;
+            Ok(request.header(CONTENT_TYPE, "application/json").body(bytes))
+        }
+        GraphqlEncoding::Get => {
+            let mut query = Vec::new();
+            for (key, value) in body.as_object().into_iter().flatten() {
+                let value = match value {
+                    JsonValue::String(value) => value.clone(),
+                    other => other.to_string(),
+                };
+                query.push((key.clone(), value));
+            }
+
+            Ok(request.query(&query))
+        }
+    }
+}
+
+/// Sends the request of a query or mutation, returning the status of the response and the
+/// result it holds.
+///
+/// Stops as soon as the context is canceled or its deadline is exceeded.
+pub async fn send_request(
+    ctx: &dyn AnyAsyncContext,
+    request: AuthorizedRequest,
+) -> joinerror::Result<(u16, JsonValue)> {
+    let AuthorizedRequest {
+        request, digest, ..
+    } = request;
+    let (client, request) = request.build_split();
+    let request = request.join_err::<()>("failed to build request")?;
+
+    context::abortable(ctx, async {
+        let response = auth::send_authorized(&client, request, digest.as_ref()).await?;
+        let status = response.status();
+        let bytes = response
+            .bytes()
+            .await
+            .join_err::<()>("failed to read response body")?;
+
+        let result = serde_json::from_slice(&bytes).map_err(|_| {
+            Error::new::<()>(format!(
+                "response is not a graphql result ({}): {}",
+                status,
+                String::from_utf8_lossy(&bytes)
+            ))
+        })?;
+
+        Ok::<_, joinerror::Error>((status.as_u16(), result))
+    })
+    .await
+    .join_err_bare()
+}
+
+/// Builds the handshake request of a subscription from the request of the endpoint, carrying
+/// the headers and query params its auth added.
+pub fn handshake_request(request: &reqwest::Request) -> joinerror::Result<Request> {
+    let resolved = ResolveResourceOutput {
+        protocol: Some(ResourceProtocol::Graphql),
+        url: request.url().to_string(),
+        headers: request
+            .headers()
+            .iter()
+            .map(|(name, value)| ResolvedParam {
+                id: name.to_string(),
+                name: name.to_string(),
+                value: String::from_utf8_lossy(value.as_bytes()).to_string(),
+            })
+            .collect(),
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        substitutions: vec![],
+        unresolved: vec![],
+    };
+
+    websocket::handshake_request(&resolved, &[GRAPHQL_TRANSPORT_WS.to_string()])
+}
+
+#[derive(Deserialize)]
+struct ServerMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    payload: Option<JsonValue>,
+}
+
+/// Runs a subscription over the graphql-ws protocol, reporting every result to `on_result`
+/// until the server completes it. Returns the number of results received.
+///
+/// Stops as soon as the context is canceled or its deadline is exceeded.
+pub async fn subscribe<F>(
+    ctx: &dyn AnyAsyncContext,
+    request: Request,
+    body: JsonValue,
+    on_result: F,
+) -> joinerror::Result<u64>
+where
+    F: Fn(JsonValue) -> joinerror::Result<()>,
+{
+    let url = request.uri().to_string();
+    context::abortable(ctx, async {
+        let (mut stream, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to connect to `{url}`: {e}")))?;
+
+        send_message(
+            &mut stream,
+            json!({ "type": "connection_init", "payload": {} }),
+        )
+        .await?;
+        loop {
+            let message = next_message(&mut stream).await?;
+            match message.kind.as_str() {
+                "connection_ack" => break,
+                "ping" => send_message(&mut stream, json!({ "type": "pong" })).await?,
+                other => {
+                    return Err(Error::new::<()>(format!(
+                        "unexpected `{other}` message before the connection was acknowledged"
+                    )));
+                }
+            }
+        }
+
+        send_message(
+            &mut stream,
+            json!({ "id": SUBSCRIPTION_ID, "type": "subscribe", "payload": body }),
+        )
+        .await?;
+
+        let mut results = 0;
+        loop {
+            let message = next_message(&mut stream).await?;
+            match message.kind.as_str() {
+                "next" => {
+                    on_result(message.payload.unwrap_or_default())?;
+                    results += 1;
+                }
+                "error" => {
+                    let errors = message.payload.unwrap_or_default();
+                    return Err(Error::new::<()>(format!("subscription failed: {errors}")));
+                }
+                "complete" => break,
+                "ping" => send_message(&mut stream, json!({ "type": "pong" })).await?,
+                _ => {}
+            }
+        }
+
+        // The server is done with the subscription, the connection isn't needed anymore
+        let _ = stream.close(None).await;
+
+        Ok::<_, joinerror::Error>(results)
+    })
+    .await
+    .join_err_bare()
+}
+
+async fn send_message(
+    stream: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
+    message: JsonValue,
+) -> joinerror::Result<()> {
+    stream
+        .send(Message::text(message.to_string()))
+        .await
+        .map_err(|e| Error::new::<()>(format!("failed to send graphql-ws message: {e}")))
+}
+
+async fn next_message(
+    stream: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
+) -> joinerror::Result<ServerMessage> {
+    loop {
+        let message = stream
+            .next()
+            .await
+            .ok_or_join_err::<()>("connection closed by the server")?
+            .map_err(|e| Error::new::<()>(format!("failed to read graphql-ws message: {e}")))?;
+
+        match message {
+            Message::Text(text) => {
+                return serde_json::from_str(&text).join_err::<()>("invalid graphql-ws message");
+            }
+            Message::Close(frame) => {
+                let reason = frame
+                    .map(|frame| format!("{} {}", u16::from(frame.code), frame.reason))
+                    .unwrap_or_default();
+                return Err(Error::new::<()>(format!(
+                    "connection closed by the server: {}",
+                    reason.trim()
+                )));
+            }
+            // Pings are answered by the stream itself
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(kind: &str, name: &str) -> JsonValue {
+        json!({ "kind": kind, "name": name, "ofType": null })
+    }
+
+    fn non_null(of_type: JsonValue) -> JsonValue {
+        json!({ "kind": "NON_NULL", "name": null, "ofType": of_type })
+    }
+
+    fn list(of_type: JsonValue) -> JsonValue {
+        json!({ "kind": "LIST", "name": null, "ofType": of_type })
+    }
+
+    fn arg(name: &str, ty: JsonValue) -> JsonValue {
+        json!({ "name": name, "type": ty, "defaultValue": null })
+    }
+
+    fn field(name: &str, ty: JsonValue, args: Vec<JsonValue>) -> JsonValue {
+        json!({ "name": name, "type": ty, "args": args })
+    }
+
+    fn object(name: &str, fields: Vec<JsonValue>) -> JsonValue {
+        json!({ "kind": "OBJECT", "name": name, "fields": fields })
+    }
+
+    fn schema() -> Schema {
+        let user = named("OBJECT", "User");
+        let result = json!({
+            "data": {
+                "__schema": {
+                    "queryType": { "name": "Query" },
+                    "mutationType": null,
+                    "subscriptionType": { "name": "Subscription" },
+                    "types": [
+                        object("Query", vec![
+                            field("user", user.clone(), vec![arg("id", non_null(named("SCALAR", "ID")))]),
+                            field(
+                                "users",
+                                non_null(list(non_null(user.clone()))),
+                                vec![arg("first", named("SCALAR", "Int"))],
+                            ),
+                            field(
+                                "search",
+                                list(named("UNION", "SearchResult")),
+                                vec![arg("text", non_null(named("SCALAR", "String")))],
+                            ),
+                        ]),
+                        object("Subscription", vec![
+                            field("userAdded", non_null(user.clone()), vec![]),
+                        ]),
+                        object("User", vec![
+                            field("id", non_null(named("SCALAR", "ID")), vec![]),
+                            field("name", named("SCALAR", "String"), vec![]),
+                            field("role", named("ENUM", "Role"), vec![]),
+                            field("friends", non_null(list(non_null(user))), vec![]),
+                        ]),
+                        object("Post", vec![field("title", named("SCALAR", "String"), vec![])]),
+                        { "kind": "UNION", "name": "SearchResult", "fields": null },
+                        { "kind": "ENUM", "name": "Role", "fields": null },
+                        { "kind": "SCALAR", "name": "ID", "fields": null },
+                        { "kind": "SCALAR", "name": "Int", "fields": null },
+                        { "kind": "SCALAR", "name": "String", "fields": null },
+                        { "kind": "SCALAR", "name": "Boolean", "fields": null },
+                    ],
+                    "directives": [
+                        { "name": "include", "args": [arg("if", non_null(named("SCALAR", "Boolean")))] },
+                        { "name": "skip", "args": [arg("if", non_null(named("SCALAR", "Boolean")))] },
+                    ],
+                }
+            }
+        });
+
+        schema_from_introspection(&result).unwrap().1
+    }
+
+    fn errors(query: &str) -> Vec<(String, usize, usize)> {
+        validate(&schema(), query)
+            .into_iter()
+            .map(|error| (error.message, error.line, error.column))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_valid_document() {
+        let query = r#"
+query Search($text: String!, $withRole: Boolean!) {
+  search(text: $text) {
+    __typename
+    ... on User { ...UserFields }
+    ... on Post { title }
+  }
+  users(first: 10) {
+    role @include(if: $withRole)
+    friends { id }
+  }
+}
+
+fragment UserFields on User {
+  id
+  name
+}
+
+subscription UserAdded { userAdded { id } }
+"#;
+
+        assert_eq!(errors(query), vec![]);
+    }
+
+    #[test]
+    fn test_validate_syntax_error() {
+        let errors = errors("query {\n  user(id: \"1\") {\n    name\n");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].1, 4);
+    }
+
+    #[test]
+    fn test_validate_unknown_field_and_arguments() {
+        let query = "query {\n  user(name: \"ann\") {\n    email\n  }\n}";
+
+        assert_eq!(
+            errors(query),
+            vec![
+                (
+                    "field \"Query.user\" is missing required argument \"id\"".to_string(),
+                    2,
+                    3
+                ),
+                (
+                    "unknown argument \"name\" on field \"Query.user\"".to_string(),
+                    2,
+                    8
+                ),
+                (
+                    "field \"email\" does not exist on type \"User\"".to_string(),
+                    3,
+                    5
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_selections() {
+        let query = "{\n  users {\n    name { length }\n    friends\n  }\n}";
+
+        assert_eq!(
+            errors(query),
+            vec![
+                (
+                    "field \"name\" of type \"String\" cannot have a selection of subfields"
+                        .to_string(),
+                    3,
+                    10
+                ),
+                (
+                    "field \"friends\" of type \"User\" must have a selection of subfields"
+                        .to_string(),
+                    4,
+                    5
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_variables() {
+        let query = "query Users($first: Int, $unused: String) {\n  users(first: $first) { ...Named }\n}\n\nfragment Named on User {\n  friends @include(if: $withFriends) { id }\n}";
+
+        assert_eq!(
+            errors(query),
+            vec![
+                ("variable \"$unused\" is never used".to_string(), 1, 26),
+                (
+                    "variable \"$withFriends\" is not defined by operation \"Users\"".to_string(),
+                    6,
+                    24
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_fragments_and_types() {
+        let query = "query($id: Unknown) {\n  user(id: $id) { ...Missing ... on Comment { id } }\n}\n\nfragment Unused on User { id }";
+
+        assert_eq!(
+            errors(query),
+            vec![
+                ("unknown type \"Unknown\"".to_string(), 1, 12),
+                ("unknown fragment \"Missing\"".to_string(), 2, 22),
+                ("unknown type \"Comment\"".to_string(), 2, 37),
+                ("fragment \"Unused\" is never used".to_string(), 5, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_unsupported_operation() {
+        let query = "query Users { users { id } }\nmutation Add { addUser { id } }";
+
+        assert_eq!(
+            errors(query),
+            vec![(
+                "schema does not support mutation operations".to_string(),
+                2,
+                1
+            )]
+        );
+    }
+
+    #[test]
+    fn test_operation_type() {
+        let query = "query Users { users { id } }\nsubscription Added { userAdded { id } }";
+
+        assert_eq!(
+            operation_type(query, Some("Added")).unwrap(),
+            OperationType::Subscription
+        );
+        assert_eq!(
+            operation_type(query, Some("Users")).unwrap(),
+            OperationType::Query
+        );
+        assert!(operation_type(query, Some("Other")).is_err());
+        assert!(operation_type(query, None).is_err());
+        assert_eq!(
+            operation_type("{ users { id } }", None).unwrap(),
+            OperationType::Query
+        );
+    }
+
+    #[test]
+    fn test_schema_from_introspection_errors() {
+        let result =
+            json!({ "data": null, "errors": [{ "message": "introspection is disabled" }] });
+
+        assert!(schema_from_introspection(&result).is_err());
+    }
+}
@@ -0,0 +1,660 @@
+use joinerror::{Error, OptionExt, ResultExt};
+use moss_project::models::operations::DescribeResourceOutput;
+use prost::Message as _;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor};
+use prost_types::FileDescriptorProto;
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_core::context::{self, AnyAsyncContext, ArcContext, ContextResultExt};
+use sapic_ipc::contracts::main::grpc::{
+    GrpcEvent, GrpcMetadataEntry, GrpcMethodInfo, GrpcMethodKind, GrpcServiceInfo,
+};
+use serde_json::Value as JsonValue;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tonic::{
+    Code, Request, Status,
+    client::Grpc,
+    codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    codegen::http::{HeaderMap, uri::PathAndQuery},
+    metadata::{KeyAndValueRef, MetadataMap},
+    transport::{Channel, ClientTlsConfig, Endpoint},
+};
+use tonic_reflection::pb::v1::{
+    ServerReflectionRequest, server_reflection_client::ServerReflectionClient,
+    server_reflection_request::MessageRequest, server_reflection_response::MessageResponse,
+};
+
+/// Prefix of the services a server exposes its reflection through.
+const REFLECTION_SERVICE_PREFIX: &str = "grpc.reflection.";
+
+/// Headers that are set by the transport itself, and can't be sent as metadata.
+const RESERVED_HEADERS: &[&str] = &["content-type", "te", "host", "connection", "user-agent"];
+
+/// Connection to a gRPC endpoint, with the metadata its calls carry and the descriptors of
+/// its services.
+pub struct GrpcTarget {
+    pub channel: Channel,
+    pub metadata: MetadataMap,
+    pub pool: DescriptorPool,
+}
+
+/// Fails unless the described resource is a gRPC endpoint.
+pub fn ensure_grpc_endpoint(desc: &DescribeResourceOutput) -> joinerror::Result<()> {
+    if desc.class != ResourceClass::Endpoint || desc.protocol != Some(ResourceProtocol::Grpc) {
+        return Err(Error::new::<()>(format!(
+            "resource `{}` is not a grpc endpoint",
+            desc.name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Url of the endpoint, which is sent plaintext unless its scheme is `https`.
+pub fn endpoint_url(url: &str) -> joinerror::Result<String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err(Error::new::<()>("resource has no url"));
+    }
+
+    if url.contains("://") {
+        Ok(url.to_string())
+    } else {
+        Ok(format!("http://{url}"))
+    }
+}
+
+/// Opens an HTTP/2 connection to the endpoint.
+pub async fn connect(url: &str) -> joinerror::Result<Channel> {
+    let mut endpoint = Endpoint::from_shared(url.to_string())
+        .map_err(|e| Error::new::<()>(format!("invalid url `{url}`: {e}")))?;
+    if url.starts_with("https://") {
+        endpoint = endpoint
+            .tls_config(ClientTlsConfig::new().with_webpki_roots())
+            .map_err(|e| Error::new::<()>(format!("failed to configure tls: {e}")))?;
+    }
+
+    endpoint
+        .connect()
+        .await
+        .map_err(|e| Error::new::<()>(format!("failed to connect to `{url}`: {e}")))
+}
+
+/// Metadata sent with calls, from the headers of the endpoint and the ones its auth adds.
+pub fn request_metadata(headers: &HeaderMap) -> MetadataMap {
+    let mut headers = headers.clone();
+    for name in RESERVED_HEADERS {
+        headers.remove(*name);
+    }
+
+    MetadataMap::from_headers(headers)
+}
+
+/// Compiles the `.proto` files, along with the files they import. Relative paths are
+/// resolved against the project directory.
+///
+/// Reads the files synchronously, it's meant to be run on a blocking thread.
+pub fn compile_protos(
+    project_path: &Path,
+    proto_files: &[PathBuf],
+    import_paths: &[PathBuf],
+) -> joinerror::Result<DescriptorPool> {
+    let proto_files = proto_files
+        .iter()
+        .map(|path| project_path.join(path))
+        .collect::<Vec<_>>();
+
+    let mut includes = import_paths
+        .iter()
+        .map(|path| project_path.join(path))
+        .collect::<Vec<_>>();
+    for file in &proto_files {
+        if let Some(parent) = file.parent()
+            && !includes.iter().any(|include| include == parent)
+        {
+            includes.push(parent.to_path_buf());
+        }
+    }
+
+    let mut compiler = protox::Compiler::new(&includes)
+        .map_err(|e| Error::new::<()>(format!("failed to compile proto files: {e}")))?;
+    compiler.include_imports(true);
+    compiler
+        .open_files(&proto_files)
+        .map_err(|e| Error::new::<()>(format!("failed to compile proto files: {e}")))?;
+
+    Ok(compiler.descriptor_pool())
+}
+
+/// Discovers the services of the endpoint through its server reflection, along with the
+/// files describing them.
+pub async fn reflect(
+    channel: Channel,
+    metadata: &MetadataMap,
+) -> joinerror::Result<DescriptorPool> {
+    // Some servers only respond with headers once they received a request, so the first one
+    // is queued before the stream is opened
+    let (tx, rx) = mpsc::unbounded_channel();
+    let send = |message_request| {
+        tx.send(ServerReflectionRequest {
+            host: String::new(),
+            message_request: Some(message_request),
+        })
+        .map_err(|_| Error::new::<()>("server reflection stream closed"))
+    };
+    send(MessageRequest::ListServices(String::new()))?;
+
+    let mut request = Request::new(UnboundedReceiverStream::new(rx));
+    *request.metadata_mut() = metadata.clone();
+    let mut responses = ServerReflectionClient::new(channel)
+        .server_reflection_info(request)
+        .await
+        .map_err(|status| status_error("server reflection failed", &status))?
+        .into_inner();
+
+    let mut receive = async || -> joinerror::Result<MessageResponse> {
+        let response = responses
+            .message()
+            .await
+            .map_err(|status| status_error("server reflection failed", &status))?
+            .ok_or_join_err::<()>("server reflection stream closed")?;
+
+        match response.message_response {
+            Some(MessageResponse::ErrorResponse(error)) => Err(Error::new::<()>(format!(
+                "server reflection failed: {}",
+                error.error_message
+            ))),
+            Some(message_response) => Ok(message_response),
+            None => Err(Error::new::<()>("empty server reflection response")),
+        }
+    };
+
+    let MessageResponse::ListServicesResponse(list) = receive().await? else {
+        return Err(Error::new::<()>("unexpected server reflection response"));
+    };
+    let services = list
+        .service
+        .into_iter()
+        .map(|service| service.name)
+        .filter(|name| !name.starts_with(REFLECTION_SERVICE_PREFIX));
+
+    // Well-known types are known already, the server doesn't have to describe them
+    let mut pool = DescriptorPool::global();
+    let mut files = Vec::new();
+    let mut seen = HashSet::new();
+    for service in services {
+        send(MessageRequest::FileContainingSymbol(service))?;
+        add_reflected_files(receive().await?, &mut files, &mut seen)?;
+    }
+
+    let mut index = 0;
+    while index < files.len() {
+        let dependencies = files[index].dependency.clone();
+        index += 1;
+
+        for dependency in dependencies {
+            if seen.contains(&dependency) || pool.get_file_by_name(&dependency).is_some() {
+                continue;
+            }
+
+            send(MessageRequest::FileByFilename(dependency))?;
+            add_reflected_files(receive().await?, &mut files, &mut seen)?;
+        }
+    }
+
+    files.retain(|file| pool.get_file_by_name(file.name()).is_none());
+    pool.add_file_descriptor_protos(files)
+        .map_err(|e| Error::new::<()>(format!("invalid file descriptors: {e}")))?;
+
+    Ok(pool)
+}
+
+fn add_reflected_files(
+    response: MessageResponse,
+    files: &mut Vec<FileDescriptorProto>,
+    seen: &mut HashSet<String>,
+) -> joinerror::Result<()> {
+    let MessageResponse::FileDescriptorResponse(response) = response else {
+        return Err(Error::new::<()>("unexpected server reflection response"));
+    };
+
+    for bytes in response.file_descriptor_proto {
+        let file = FileDescriptorProto::decode(bytes.as_slice())
+            .map_err(|e| Error::new::<()>(format!("invalid file descriptor: {e}")))?;
+        if seen.insert(file.name().to_string()) {
+            files.push(file);
+        }
+    }
+
+    Ok(())
+}
+
+/// Services of the pool, except the reflection ones, sorted by name.
+pub fn services(pool: &DescriptorPool) -> Vec<GrpcServiceInfo> {
+    let mut services = pool
+        .services()
+        .filter(|service| !service.full_name().starts_with(REFLECTION_SERVICE_PREFIX))
+        .map(|service| GrpcServiceInfo {
+            name: service.full_name().to_string(),
+            methods: service
+                .methods()
+                .map(|method| GrpcMethodInfo {
+                    name: method.name().to_string(),
+                    kind: method_kind(&method),
+                    input_type: method.input().full_name().to_string(),
+                    output_type: method.output().full_name().to_string(),
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+
+    services
+}
+
+pub fn method_kind(method: &MethodDescriptor) -> GrpcMethodKind {
+    match (method.is_client_streaming(), method.is_server_streaming()) {
+        (false, false) => GrpcMethodKind::Unary,
+        (false, true) => GrpcMethodKind::ServerStreaming,
+        (true, false) => GrpcMethodKind::ClientStreaming,
+        (true, true) => GrpcMethodKind::BidiStreaming,
+    }
+}
+
+pub fn find_method(
+    pool: &DescriptorPool,
+    service: &str,
+    method: &str,
+) -> joinerror::Result<MethodDescriptor> {
+    let service_descriptor = pool
+        .get_service_by_name(service)
+        .ok_or_join_err_with::<()>(|| format!("service `{service}` not found"))?;
+
+    service_descriptor
+        .methods()
+        .find(|descriptor| descriptor.name() == method)
+        .ok_or_join_err_with::<()>(|| format!("method `{method}` not found in `{service}`"))
+}
+
+/// Transcodes the JSON messages into the request messages of the method, following the
+/// proto3 JSON mapping.
+pub fn request_messages(
+    method: &MethodDescriptor,
+    messages: &[JsonValue],
+) -> joinerror::Result<Vec<DynamicMessage>> {
+    if !method.is_client_streaming() && messages.len() != 1 {
+        return Err(Error::new::<()>(format!(
+            "method `{}` takes exactly one request message, {} given",
+            method.name(),
+            messages.len()
+        )));
+    }
+
+    let input = method.input();
+    messages
+        .iter()
+        .map(|message| {
+            DynamicMessage::deserialize(input.clone(), message.clone()).map_err(|e| {
+                Error::new::<()>(format!("invalid `{}` message: {e}", input.full_name()))
+            })
+        })
+        .collect()
+}
+
+/// Codec of messages only known at runtime, through their descriptor.
+#[derive(Debug, Clone)]
+pub struct DynamicCodec {
+    decoded: MessageDescriptor,
+}
+
+impl DynamicCodec {
+    /// The messages decoded are of the given type, while any message can be encoded.
+    pub fn new(decoded: MessageDescriptor) -> Self {
+        Self { decoded }
+    }
+}
+
+impl Codec for DynamicCodec {
+    type Encode = DynamicMessage;
+    type Decode = DynamicMessage;
+    type Encoder = DynamicEncoder;
+    type Decoder = DynamicDecoder;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        DynamicEncoder
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        DynamicDecoder(self.decoded.clone())
+    }
+}
+
+#[derive(Debug)]
+pub struct DynamicEncoder;
+
+impl Encoder for DynamicEncoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        item.encode(dst)
+            .map_err(|e| Status::internal(format!("failed to encode message: {e}")))
+    }
+}
+
+#[derive(Debug)]
+pub struct DynamicDecoder(MessageDescriptor);
+
+impl Decoder for DynamicDecoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        DynamicMessage::decode(self.0.clone(), src)
+            .map(Some)
+            .map_err(|e| Status::internal(format!("failed to decode message: {e}")))
+    }
+}
+
+/// How a call ended.
+#[derive(Debug)]
+pub struct CallOutcome {
+    pub code: Code,
+    pub message: Option<String>,
+    pub received: u64,
+    pub trailers: Vec<GrpcMetadataEntry>,
+}
+
+/// Calls the method with the request messages, reporting the metadata and the messages
+/// the server responds with as they are received.
+///
+/// Every kind of method is called as a bidi stream, which they all are on the wire: the
+/// request messages are sent in order before the stream is half-closed. A failed call
+/// isn't an error, its status is reported in the outcome instead.
+///
+/// The call ends with a `DEADLINE_EXCEEDED` status once the timeout is reached, which is
+/// sent to the server as well. Stops as soon as the context is canceled or its deadline is
+/// exceeded.
+pub async fn call<F>(
+    ctx: &dyn AnyAsyncContext,
+    channel: Channel,
+    method: &MethodDescriptor,
+    messages: Vec<DynamicMessage>,
+    metadata: MetadataMap,
+    timeout: Option<Duration>,
+    on_event: F,
+) -> joinerror::Result<CallOutcome>
+where
+    F: Fn(GrpcEvent) -> joinerror::Result<()>,
+{
+    let path = format!("/{}/{}", method.parent_service().full_name(), method.name());
+    let path = PathAndQuery::try_from(path)
+        .map_err(|e| Error::new::<()>(format!("invalid method path: {e}")))?;
+    let codec = DynamicCodec::new(method.output());
+
+    let mut request = Request::new(tokio_stream::iter(messages));
+    *request.metadata_mut() = metadata;
+    if let Some(timeout) = timeout {
+        request.set_timeout(timeout);
+    }
+
+    let received = AtomicU64::new(0);
+    let call = async {
+        let mut grpc = Grpc::new(channel);
+        grpc.ready()
+            .await
+            .map_err(|e| Error::new::<()>(format!("connection is not ready: {e}")))?;
+
+        let response = match grpc.streaming(request, path, codec).await {
+            Ok(response) => response,
+            Err(status) => return Ok(Err(status)),
+        };
+        on_event(GrpcEvent::Headers {
+            metadata: metadata_entries(response.metadata()),
+        })?;
+
+        let mut stream = response.into_inner();
+        loop {
+            match stream.message().await {
+                Ok(Some(message)) => {
+                    let message = serde_json::to_value(&message)
+                        .join_err::<()>("failed to transcode message")?;
+                    on_event(GrpcEvent::Message { message })?;
+                    received.fetch_add(1, Ordering::Relaxed);
+                }
+                Ok(None) => break,
+                Err(status) => return Ok(Err(status)),
+            }
+        }
+
+        let trailers = stream.trailers().await.map(Option::unwrap_or_default);
+        Ok::<_, joinerror::Error>(trailers)
+    };
+
+    let result = context::abortable(ctx, async {
+        match timeout {
+            Some(timeout) => {
+                let deadline = ArcContext::background_with_timeout(timeout);
+                match context::abortable(&deadline, call).await {
+                    Ok(result) => Ok(result),
+                    Err(Ok(_)) => Ok(Err(Status::deadline_exceeded("deadline exceeded"))),
+                    Err(Err(e)) => Err(e),
+                }
+            }
+            None => call.await,
+        }
+    })
+    .await
+    .join_err_bare()?;
+
+    let received = received.load(Ordering::Relaxed);
+    Ok(match result {
+        Ok(trailers) => CallOutcome {
+            code: Code::Ok,
+            message: None,
+            received,
+            trailers: metadata_entries(&trailers),
+        },
+        Err(status) => CallOutcome {
+            code: status.code(),
+            message: Some(status.message().to_string()),
+            received,
+            trailers: metadata_entries(status.metadata()),
+        },
+    })
+}
+
+fn metadata_entries(metadata: &MetadataMap) -> Vec<GrpcMetadataEntry> {
+    metadata
+        .iter()
+        .map(|entry| match entry {
+            KeyAndValueRef::Ascii(key, value) => GrpcMetadataEntry {
+                name: key.as_str().to_string(),
+                value: String::from_utf8_lossy(value.as_encoded_bytes()).to_string(),
+            },
+            KeyAndValueRef::Binary(key, value) => GrpcMetadataEntry {
+                name: key.as_str().to_string(),
+                value: String::from_utf8_lossy(value.as_encoded_bytes()).to_string(),
+            },
+        })
+        .collect()
+}
+
+fn status_error(details: &str, status: &Status) -> Error {
+    Error::new::<()>(format!("{details}: {}", status.message()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const GREETER_PROTO: &str = r#"
+syntax = "proto3";
+
+package helloworld;
+
+import "common/types.proto";
+
+service Greeter {
+  rpc SayHello (HelloRequest) returns (HelloReply);
+  rpc SayHellos (HelloRequest) returns (stream HelloReply);
+  rpc Collect (stream HelloRequest) returns (HelloReply);
+  rpc Chat (stream HelloRequest) returns (stream HelloReply);
+}
+
+message HelloRequest {
+  string name = 1;
+  common.Tags tags = 2;
+}
+
+message HelloReply {
+  string message = 1;
+  int64 count = 2;
+}
+"#;
+
+    const TYPES_PROTO: &str = r#"
+syntax = "proto3";
+
+package common;
+
+message Tags {
+  repeated string values = 1;
+}
+"#;
+
+    fn greeter_pool() -> DescriptorPool {
+        let dir = std::env::temp_dir().join(format!("grpc-test-{}", rand_suffix()));
+        fs::create_dir_all(dir.join("protos")).unwrap();
+        fs::create_dir_all(dir.join("shared/common")).unwrap();
+        fs::write(dir.join("protos/greeter.proto"), GREETER_PROTO).unwrap();
+        fs::write(dir.join("shared/common/types.proto"), TYPES_PROTO).unwrap();
+
+        let pool = compile_protos(
+            &dir,
+            &[PathBuf::from("protos/greeter.proto")],
+            &[PathBuf::from("shared")],
+        )
+        .unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        pool
+    }
+
+    fn rand_suffix() -> u128 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    }
+
+    #[test]
+    fn test_compile_protos() {
+        let pool = greeter_pool();
+
+        let services = services(&pool);
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].name, "helloworld.Greeter");
+
+        let kinds = services[0]
+            .methods
+            .iter()
+            .map(|method| (method.name.as_str(), method.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                ("SayHello", GrpcMethodKind::Unary),
+                ("SayHellos", GrpcMethodKind::ServerStreaming),
+                ("Collect", GrpcMethodKind::ClientStreaming),
+                ("Chat", GrpcMethodKind::BidiStreaming),
+            ]
+        );
+        assert_eq!(services[0].methods[0].input_type, "helloworld.HelloRequest");
+    }
+
+    #[test]
+    fn test_compile_missing_import() {
+        let dir = std::env::temp_dir().join(format!("grpc-test-{}", rand_suffix()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("greeter.proto"), GREETER_PROTO).unwrap();
+
+        let result = compile_protos(&dir, &[PathBuf::from("greeter.proto")], &[]);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_request_messages() {
+        let pool = greeter_pool();
+        let method = find_method(&pool, "helloworld.Greeter", "SayHello").unwrap();
+
+        let messages = request_messages(
+            &method,
+            &[serde_json::json!({ "name": "Ann", "tags": { "values": ["a"] } })],
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&messages[0]).unwrap(),
+            serde_json::json!({ "name": "Ann", "tags": { "values": ["a"] } })
+        );
+
+        // Unary methods take exactly one message
+        assert!(request_messages(&method, &[]).is_err());
+        // Fields must exist in the message
+        assert!(request_messages(&method, &[serde_json::json!({ "unknown": 1 })]).is_err());
+
+        let method = find_method(&pool, "helloworld.Greeter", "Collect").unwrap();
+        let messages = request_messages(
+            &method,
+            &[
+                serde_json::json!({ "name": "a" }),
+                serde_json::json!({ "name": "b" }),
+            ],
+        )
+        .unwrap();
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn test_find_method() {
+        let pool = greeter_pool();
+
+        assert!(find_method(&pool, "helloworld.Greeter", "Chat").is_ok());
+        assert!(find_method(&pool, "helloworld.Greeter", "Unknown").is_err());
+        assert!(find_method(&pool, "helloworld.Unknown", "Chat").is_err());
+    }
+
+    #[test]
+    fn test_request_metadata() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", "Bearer token".parse().unwrap());
+        headers.insert("content-type", "application/json".parse().unwrap());
+        headers.insert("trace-bin", "AAEC".parse().unwrap());
+
+        let metadata = request_metadata(&headers);
+        let mut entries = metadata_entries(&metadata);
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(
+            entries,
+            vec![
+                GrpcMetadataEntry {
+                    name: "authorization".to_string(),
+                    value: "Bearer token".to_string(),
+                },
+                GrpcMetadataEntry {
+                    name: "trace-bin".to_string(),
+                    value: "AAEC".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,393 @@
+pub mod bruno;
+pub mod curl;
+pub mod har;
+pub mod http_file;
+pub mod insomnia;
+pub mod openapi;
+pub mod postman;
+
+use joinerror::{Error, ResultExt};
+use moss_environment::models::types::{AddVariableParams, VariableOptions};
+use moss_project::models::operations::BatchCreateResourceKind;
+use sapic_base::resource::types::primitives::ResourceProtocol;
+use sapic_ipc::contracts::main::project::ImportSkippedItem;
+use serde_json::{Map, Value as JsonValue, json};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+const METHODS: [(&str, ResourceProtocol); 9] = [
+    ("GET", ResourceProtocol::Get),
+    ("HEAD", ResourceProtocol::Head),
+    ("POST", ResourceProtocol::Post),
+    ("PUT", ResourceProtocol::Put),
+    ("PATCH", ResourceProtocol::Patch),
+    ("DELETE", ResourceProtocol::Delete),
+    ("CONNECT", ResourceProtocol::Connect),
+    ("OPTIONS", ResourceProtocol::Options),
+    ("TRACE", ResourceProtocol::Trace),
+];
+
+/// Resources converted from a document of another tool, ready to be created in a new project.
+#[derive(Debug)]
+pub struct ImportedResources {
+    pub resources: Vec<BatchCreateResourceKind>,
+    pub skipped: Vec<ImportSkippedItem>,
+    /// Environments created in the project along with the resources.
+    pub environments: Vec<ImportedEnvironment>,
+}
+
+#[derive(Debug)]
+pub struct ImportedEnvironment {
+    pub name: String,
+    pub variables: Vec<AddVariableParams>,
+}
+
+/// Parses a JSON or a YAML document.
+pub fn parse_document(content: &str) -> joinerror::Result<JsonValue> {
+    if content.trim_start().starts_with(['{', '[']) {
+        return serde_json::from_str(content).join_err::<()>("failed to parse json document");
+    }
+
+    let value: serde_norway::Value = serde_norway::from_str(content)
+        .map_err(|e| Error::new::<()>(format!("failed to parse yaml document: {}", e)))?;
+    Ok(yaml_to_json(value))
+}
+
+/// Reads and parses a JSON or a YAML document, `kind` naming it in the errors.
+pub async fn read_document(path: &Path, kind: &str) -> joinerror::Result<JsonValue> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .join_err_with::<()>(|| format!("failed to read {} {}", kind, path.display()))?;
+
+    parse_document(&content)
+}
+
+/// YAML allows keys of any type, such as the status codes of responses,
+/// which become strings in JSON.
+fn yaml_to_json(value: serde_norway::Value) -> JsonValue {
+    match value {
+        serde_norway::Value::Null => JsonValue::Null,
+        serde_norway::Value::Bool(value) => JsonValue::Bool(value),
+        serde_norway::Value::Number(number) => {
+            if let Some(value) = number.as_i64() {
+                JsonValue::from(value)
+            } else if let Some(value) = number.as_u64() {
+                JsonValue::from(value)
+            } else {
+                number
+                    .as_f64()
+                    .and_then(serde_json::Number::from_f64)
+                    .map_or(JsonValue::Null, JsonValue::Number)
+            }
+        }
+        serde_norway::Value::String(value) => JsonValue::String(value),
+        serde_norway::Value::Sequence(values) => {
+            JsonValue::Array(values.into_iter().map(yaml_to_json).collect())
+        }
+        serde_norway::Value::Mapping(mapping) => JsonValue::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| (yaml_key(key), yaml_to_json(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        serde_norway::Value::Tagged(tagged) => yaml_to_json(tagged.value),
+    }
+}
+
+fn yaml_key(key: serde_norway::Value) -> String {
+    match yaml_to_json(key) {
+        JsonValue::String(key) => key,
+        key => key.to_string(),
+    }
+}
+
+/// Builds a JSON pointer from unescaped reference tokens.
+pub(crate) fn json_pointer<'a>(tokens: impl IntoIterator<Item = &'a str>) -> String {
+    tokens
+        .into_iter()
+        .map(|token| format!("/{}", token.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Variables can only be made of letters, digits and underscores, the other characters are replaced.
+pub(crate) fn variable_name(name: &str) -> String {
+    let name = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if name.is_empty() {
+        "_".to_string()
+    } else {
+        name
+    }
+}
+
+/// The protocol of an uppercase http method, which is custom when it isn't a standard one.
+pub(crate) fn protocol(method: &str) -> Option<ResourceProtocol> {
+    if let Some((_, protocol)) = METHODS.iter().find(|(name, _)| *name == method) {
+        return Some(protocol.clone());
+    }
+
+    let protocol = ResourceProtocol::Custom(method.to_string());
+    protocol.validate().ok().map(|_| protocol)
+}
+
+/// Makes the `:name` segments of the path of a url valid path variables, returning their names.
+pub(crate) fn convert_path_variables(url: &str) -> (String, Vec<String>) {
+    let host_start = url.find("://").map_or(0, |index| index + 3);
+    let path_start = url[host_start..]
+        .find('/')
+        .map_or(url.len(), |index| host_start + index);
+
+    let mut variables = Vec::new();
+    let path = url[path_start..]
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(variable) if !variable.is_empty() => {
+                let variable = variable_name(variable);
+                let segment = format!(":{}", variable);
+                if !variables.contains(&variable) {
+                    variables.push(variable);
+                }
+                segment
+            }
+            _ => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (format!("{}{}", &url[..path_start], path), variables)
+}
+
+/// Names of the `{{name}}` variables of a value, as they are written.
+pub(crate) fn template_variables(text: &str) -> impl Iterator<Item = &str> {
+    text.split("{{")
+        .skip(1)
+        .filter_map(|part| part.split_once("}}").map(|(name, _)| name.trim()))
+}
+
+/// Rewrites `{{name}}` variables, which other tools allow to hold any character,
+/// into ones the template parser accepts. Dynamic variables such as `{{$guid}}`
+/// become regular ones.
+pub(crate) fn template(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((before, after)) = rest.split_once("{{") {
+        result.push_str(before);
+        match after.split_once("}}") {
+            Some((name, after)) => {
+                result.push_str("{{");
+                result.push_str(&variable_name(name.trim().trim_start_matches('$')));
+                result.push_str("}}");
+                rest = after;
+            }
+            None => {
+                result.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// Whether the value is nothing but a `{{name}}` variable, as secrets must be.
+pub(crate) fn is_variable_reference(value: &str) -> bool {
+    value
+        .trim()
+        .strip_prefix("{{")
+        .and_then(|value| value.strip_suffix("}}"))
+        .is_some_and(|name| !name.is_empty() && !name.contains(['{', '}']))
+}
+
+/// A variable whose name is unique among `names`. The value of a secret is only kept
+/// locally, leaving the one written to the environment file empty.
+pub(crate) fn environment_variable(
+    names: &mut HashSet<String>,
+    key: &str,
+    value: Option<&JsonValue>,
+    is_secret: bool,
+    disabled: bool,
+    description: Option<String>,
+) -> AddVariableParams {
+    let name = variable_name(key);
+    let mut unique = name.clone();
+    let mut suffix = 2;
+    while !names.insert(unique.clone()) {
+        unique = format!("{}_{}", name, suffix);
+        suffix += 1;
+    }
+
+    let value = match value {
+        Some(JsonValue::String(value)) => json!(template(value)),
+        Some(JsonValue::Null) | None => json!(""),
+        Some(value) => value.clone(),
+    };
+    let (global_value, local_value) = if is_secret {
+        (json!(""), value)
+    } else {
+        (value, JsonValue::Null)
+    };
+
+    AddVariableParams {
+        name: unique,
+        global_value,
+        local_value,
+        order: 0,
+        desc: description,
+        options: VariableOptions { disabled },
+    }
+}
+
+/// Adds the secrets moved out of auths to each environment, which are created
+/// as a single one named `name` when the document has none.
+pub(crate) fn add_secrets(
+    environments: &mut Vec<ImportedEnvironment>,
+    secrets: Vec<AddVariableParams>,
+    name: &str,
+) {
+    if secrets.is_empty() {
+        return;
+    }
+    if environments.is_empty() {
+        environments.push(ImportedEnvironment {
+            name: name.to_string(),
+            variables: vec![],
+        });
+    }
+
+    for environment in environments {
+        for secret in &secrets {
+            if environment
+                .variables
+                .iter()
+                .any(|variable| variable.name == secret.name)
+            {
+                continue;
+            }
+            environment.variables.push(AddVariableParams {
+                order: environment.variables.len() as isize,
+                ..secret.clone()
+            });
+        }
+    }
+}
+
+/// Decoded pairs of a query or of an urlencoded body.
+pub(crate) fn form_pairs(text: &str) -> impl Iterator<Item = (String, String)> {
+    text.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (decode(name), decode(value))
+    })
+}
+
+/// Leaves the text as written if it doesn't decode into valid utf-8.
+pub(crate) fn decode(text: &str) -> String {
+    let text = text.replace('+', " ");
+    urlencoding::decode(&text)
+        .map(Cow::into_owned)
+        .unwrap_or(text)
+}
+
+/// Makes resource names unique within their directory, since imported documents
+/// often name several requests the same.
+#[derive(Default)]
+pub(crate) struct UniqueNames {
+    taken: HashMap<PathBuf, HashSet<String>>,
+}
+
+impl UniqueNames {
+    pub fn claim(&mut self, dir: &Path, name: &str) -> String {
+        let taken = self.taken.entry(dir.to_path_buf()).or_default();
+        let name = match name.trim() {
+            "" => "Untitled",
+            name => name,
+        };
+
+        // Names are compared case-insensitively, since they become file names
+        let mut unique = name.to_string();
+        let mut suffix = 2;
+        while !taken.insert(unique.to_lowercase()) {
+            unique = format!("{} ({})", name, suffix);
+            suffix += 1;
+        }
+
+        unique
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_yaml_document() {
+        let document = parse_document("responses:\n  200:\n    description: OK\n").unwrap();
+        assert_eq!(
+            document,
+            json!({ "responses": { "200": { "description": "OK" } } })
+        );
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        assert_eq!(
+            json_pointer(["paths", "/pets/{id}", "get"]),
+            "/paths/~1pets~1{id}/get"
+        );
+    }
+
+    #[test]
+    fn test_variable_name() {
+        assert_eq!(variable_name("base-url"), "base_url");
+        assert_eq!(variable_name(" api.key "), "api_key");
+        assert_eq!(variable_name(""), "_");
+    }
+
+    #[test]
+    fn test_template() {
+        assert_eq!(
+            template("{{base-url}}/pets?id={{ $randomInt }}&{{"),
+            "{{base_url}}/pets?id={{randomInt}}&{{"
+        );
+        assert_eq!(
+            template_variables("{{a}}/{{ b.c }}").collect::<Vec<_>>(),
+            vec!["a", "b.c"]
+        );
+    }
+
+    #[test]
+    fn test_convert_path_variables() {
+        assert_eq!(
+            convert_path_variables("https://example.com:8080/pets/:pet-id/:pet-id"),
+            (
+                "https://example.com:8080/pets/:pet_id/:pet_id".to_string(),
+                vec!["pet_id".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_unique_names() {
+        let mut names = UniqueNames::default();
+        assert_eq!(names.claim(Path::new("pets"), "List"), "List");
+        assert_eq!(names.claim(Path::new("pets"), "list"), "list (2)");
+        assert_eq!(names.claim(Path::new("pets"), "List"), "List (3)");
+        assert_eq!(names.claim(Path::new("users"), "List"), "List");
+        assert_eq!(names.claim(Path::new("users"), " "), "Untitled");
+    }
+}
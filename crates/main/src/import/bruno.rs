@@ -0,0 +1,1180 @@
+use joinerror::{Error, ResultExt};
+use moss_environment::models::types::AddVariableParams;
+use moss_project::models::{
+    operations::BatchCreateResourceKind,
+    types::{
+        ApiKeyLocation, AuthScheme, AwsSigV4Config, CreateDirResourceParams,
+        CreateItemResourceParams, OAuth2Config, OAuth2Grant,
+        http::{
+            AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
+            AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
+            HeaderParamOptions, PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::project::{ImportBrunoParams, ImportSkippedItem};
+use serde_json::{Value as JsonValue, json};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::import::{
+    ImportedEnvironment, ImportedResources, UniqueNames, add_secrets, convert_path_variables,
+    environment_variable, form_pairs, is_variable_reference, json_pointer, protocol, template,
+};
+
+pub const CONFIG_FILE: &str = "bruno.json";
+pub const FILE_EXTENSION: &str = "bru";
+
+const COLLECTION_FILE: &str = "collection.bru";
+const FOLDER_FILE: &str = "folder.bru";
+const ENVIRONMENTS_DIR: &str = "environments";
+
+/// Folders of a collection that hold no requests.
+const IGNORED_DIRS: [&str; 2] = ["node_modules", ".git"];
+
+const METHODS: [&str; 9] = [
+    "get", "post", "put", "delete", "patch", "options", "head", "connect", "trace",
+];
+
+/// Reads the `bruno.json` and `.bru` files of the collection, all of them being
+/// parsed before any resource is created.
+pub async fn read_collection(params: &ImportBrunoParams) -> joinerror::Result<ImportedResources> {
+    let root = &params.collection_path;
+    let mut files = Vec::new();
+    let mut dirs = vec![root.clone()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir)
+            .await
+            .join_err_with::<()>(|| format!("failed to read directory {}", dir.display()))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .join_err_with::<()>(|| format!("failed to read directory {}", dir.display()))?
+        {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            if path.is_dir() {
+                if !IGNORED_DIRS.contains(&file_name.to_string_lossy().as_ref()) {
+                    dirs.push(path);
+                }
+                continue;
+            }
+
+            let is_config = dir == *root && file_name == CONFIG_FILE;
+            let is_bru = path
+                .extension()
+                .is_some_and(|extension| extension == FILE_EXTENSION);
+            if !is_config && !is_bru {
+                continue;
+            }
+
+            let content = tokio::fs::read_to_string(&path)
+                .await
+                .join_err_with::<()>(|| format!("failed to read {}", path.display()))?;
+            let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            files.push((relative, content));
+        }
+    }
+
+    convert(&files)
+}
+
+/// Converts the files of a Bruno collection into resources.
+///
+/// The files are the `bruno.json` and `.bru` files of the collection, with paths relative to
+/// its folder. Folders become directories and requests become endpoints. The collection itself
+/// only becomes a directory when it has headers, an auth or docs for it to hold. Each file of
+/// the `environments` folder becomes an environment, and the variables of the collection are
+/// gathered in one named after it. The secrets found inline in auths are added to each of them
+/// as local values.
+pub fn convert(files: &[(PathBuf, String)]) -> joinerror::Result<ImportedResources> {
+    let mut config = None;
+    let mut documents = HashMap::new();
+    for (path, content) in files {
+        if path == Path::new(CONFIG_FILE) {
+            config = Some(
+                serde_json::from_str::<JsonValue>(content)
+                    .join_err::<()>("failed to parse bruno.json")?,
+            );
+            continue;
+        }
+
+        let document = parse(content).map_err(|reason| {
+            Error::new::<()>(format!("failed to parse {}: {}", path.display(), reason))
+        })?;
+        documents.insert(path.clone(), document);
+    }
+
+    let config = config.ok_or_else(|| {
+        Error::new::<()>("the folder is not a bruno collection, it has no bruno.json file")
+    })?;
+    let name = config
+        .get("name")
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Bruno");
+
+    let mut converter = Converter {
+        documents: &documents,
+        names: UniqueNames::default(),
+        orders: HashMap::new(),
+        resources: Vec::new(),
+        skipped: Vec::new(),
+        secrets: Vec::new(),
+        secret_names: HashSet::new(),
+    };
+
+    let root = PathBuf::new();
+    let mut environments = Vec::new();
+    let dir = match documents.get(Path::new(COLLECTION_FILE)) {
+        Some(collection) => {
+            let pointer = json_pointer([COLLECTION_FILE]);
+            let description = converter.description(collection, &pointer);
+            let headers = converter.headers(collection, &pointer, true);
+            let auth = converter.auth(collection, &pointer, name);
+
+            let variables = converter.variables(collection.pairs("vars:pre-request"), &[]);
+            if !variables.is_empty() {
+                environments.push(ImportedEnvironment {
+                    name: name.to_string(),
+                    variables,
+                });
+            }
+
+            if description.is_some() || auth.is_some() || !headers.is_empty() {
+                converter.push_dir(&root, name, description, headers, auth)
+            } else {
+                root.clone()
+            }
+        }
+        None => root.clone(),
+    };
+    converter.convert_folder(&root, &dir);
+
+    let mut environment_paths = documents
+        .keys()
+        .filter(|path| path.parent() == Some(Path::new(ENVIRONMENTS_DIR)))
+        .collect::<Vec<_>>();
+    environment_paths.sort();
+    for path in environment_paths {
+        let environment = &documents[path];
+        let secrets = environment.list("vars:secret");
+        environments.push(ImportedEnvironment {
+            name: file_stem(path),
+            variables: converter.variables(environment.pairs("vars"), secrets),
+        });
+    }
+
+    add_secrets(&mut environments, converter.secrets, name);
+
+    Ok(ImportedResources {
+        resources: converter.resources,
+        skipped: converter.skipped,
+        environments,
+    })
+}
+
+#[derive(Debug, Default)]
+struct Document {
+    blocks: Vec<Block>,
+}
+
+#[derive(Debug)]
+struct Block {
+    name: String,
+    content: BlockContent,
+}
+
+#[derive(Debug)]
+enum BlockContent {
+    /// `key: value` lines, which are disabled when prefixed with `~`.
+    Pairs(Vec<Pair>),
+    /// Text kept as written, such as bodies, scripts and docs.
+    Text(String),
+    List(Vec<String>),
+}
+
+#[derive(Debug)]
+struct Pair {
+    key: String,
+    value: String,
+    disabled: bool,
+}
+
+impl Document {
+    fn block(&self, name: &str) -> Option<&Block> {
+        self.blocks.iter().find(|block| block.name == name)
+    }
+
+    fn pairs(&self, name: &str) -> &[Pair] {
+        match self.block(name).map(|block| &block.content) {
+            Some(BlockContent::Pairs(pairs)) => pairs,
+            _ => &[],
+        }
+    }
+
+    fn text(&self, name: &str) -> Option<&str> {
+        match self.block(name).map(|block| &block.content) {
+            Some(BlockContent::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn list(&self, name: &str) -> &[String] {
+        match self.block(name).map(|block| &block.content) {
+            Some(BlockContent::List(list)) => list,
+            _ => &[],
+        }
+    }
+
+    /// The value of an enabled pair of a block.
+    fn value(&self, block: &str, key: &str) -> Option<&str> {
+        self.pairs(block)
+            .iter()
+            .find(|pair| pair.key == key && !pair.disabled)
+            .map(|pair| pair.value.as_str())
+    }
+
+    fn seq(&self) -> Option<f64> {
+        self.value("meta", "seq")?.parse().ok()
+    }
+}
+
+/// Whether the content of the block is kept as written.
+fn is_text_block(name: &str) -> bool {
+    matches!(
+        name,
+        "body:json"
+            | "body:text"
+            | "body:xml"
+            | "body:sparql"
+            | "body:graphql"
+            | "body:graphql:vars"
+            | "tests"
+            | "docs"
+    ) || name.starts_with("script:")
+}
+
+/// Parses a `.bru` file, made of blocks such as `headers { ... }` or `vars:secret [ ... ]`
+/// whose closing delimiter is on a line of its own.
+fn parse(content: &str) -> Result<Document, String> {
+    let mut blocks = Vec::new();
+    let mut lines = content.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (name, end) = if let Some(name) = line.strip_suffix("{}") {
+            blocks.push(Block {
+                name: name.trim().to_string(),
+                content: BlockContent::Pairs(vec![]),
+            });
+            continue;
+        } else if let Some(name) = line.strip_suffix('{') {
+            (name.trim(), "}")
+        } else if let Some(name) = line.strip_suffix('[') {
+            (name.trim(), "]")
+        } else {
+            return Err(format!("unexpected content at line {}", index + 1));
+        };
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!("invalid block name at line {}", index + 1));
+        }
+
+        let mut body = Vec::new();
+        let mut is_closed = false;
+        for (_, line) in lines.by_ref() {
+            if line.trim_end() == end {
+                is_closed = true;
+                break;
+            }
+            body.push(line);
+        }
+        if !is_closed {
+            return Err(format!(
+                "the `{}` block opened at line {} is not closed",
+                name,
+                index + 1
+            ));
+        }
+
+        let content = if end == "]" {
+            BlockContent::List(
+                body.iter()
+                    .map(|line| line.trim().trim_end_matches(',').trim())
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            )
+        } else if is_text_block(name) {
+            // The content is indented by two spaces
+            let text = body
+                .iter()
+                .map(|line| line.strip_prefix("  ").unwrap_or(line))
+                .collect::<Vec<_>>()
+                .join("\n");
+            BlockContent::Text(text.trim_end().to_string())
+        } else {
+            BlockContent::Pairs(
+                body.iter()
+                    .filter_map(|line| {
+                        let line = line.trim();
+                        let (line, disabled) = match line.strip_prefix('~') {
+                            Some(line) => (line, true),
+                            None => (line, false),
+                        };
+                        let (key, value) = line.split_once(':')?;
+                        Some(Pair {
+                            key: key.trim().to_string(),
+                            value: value.trim().to_string(),
+                            disabled,
+                        })
+                    })
+                    .collect(),
+            )
+        };
+        blocks.push(Block {
+            name: name.to_string(),
+            content,
+        });
+    }
+
+    Ok(Document { blocks })
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+struct Converter<'a> {
+    documents: &'a HashMap<PathBuf, Document>,
+    names: UniqueNames,
+    orders: HashMap<PathBuf, isize>,
+    resources: Vec<BatchCreateResourceKind>,
+    skipped: Vec<ImportSkippedItem>,
+    secrets: Vec<AddVariableParams>,
+    secret_names: HashSet<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn skip(&mut self, pointer: &str, reason: impl Into<String>) {
+        let item = ImportSkippedItem {
+            pointer: pointer.to_string(),
+            reason: reason.into(),
+        };
+        if !self.skipped.contains(&item) {
+            self.skipped.push(item);
+        }
+    }
+
+    fn next_order(&mut self, dir: &Path) -> isize {
+        let order = self.orders.entry(dir.to_path_buf()).or_default();
+        *order += 1;
+        *order - 1
+    }
+
+    fn push_dir(
+        &mut self,
+        dir: &Path,
+        name: &str,
+        description: Option<String>,
+        headers: Vec<AddHeaderParams>,
+        auth: Option<AuthScheme>,
+    ) -> PathBuf {
+        let name = self.names.claim(dir, name);
+        let order = self.next_order(dir);
+        self.resources
+            .push(BatchCreateResourceKind::Dir(CreateDirResourceParams {
+                path: dir.to_path_buf(),
+                class: ResourceClass::Endpoint,
+                name: name.clone(),
+                description,
+                order,
+                headers,
+                path_params: vec![],
+                query_params: vec![],
+                auth,
+            }));
+
+        dir.join(name)
+    }
+
+    /// Converts the subfolders and the requests of a folder of the collection. Subfolders come
+    /// first, as Bruno shows them, and both are ordered by their `seq` then by their name.
+    fn convert_folder(&mut self, folder: &Path, dir: &Path) {
+        let documents = self.documents;
+        let mut subfolders = BTreeSet::new();
+        let mut requests = Vec::new();
+        for (path, document) in documents {
+            let Ok(relative) = path.strip_prefix(folder) else {
+                continue;
+            };
+            let mut components = relative.components();
+            let Some(first) = components.next() else {
+                continue;
+            };
+            let first = first.as_os_str();
+
+            if components.next().is_some() {
+                if folder.as_os_str().is_empty() && first == ENVIRONMENTS_DIR {
+                    continue;
+                }
+                subfolders.insert(folder.join(first));
+            } else if first != FOLDER_FILE && first != COLLECTION_FILE {
+                requests.push((path, document));
+            }
+        }
+
+        let mut subfolders = subfolders
+            .into_iter()
+            .map(|subfolder| {
+                let document = documents.get(&subfolder.join(FOLDER_FILE));
+                let seq = document.and_then(Document::seq);
+                (subfolder, document, seq)
+            })
+            .collect::<Vec<_>>();
+        subfolders.sort_by(|a, b| {
+            let a_seq = a.2.unwrap_or(f64::MAX);
+            let b_seq = b.2.unwrap_or(f64::MAX);
+            a_seq.total_cmp(&b_seq).then_with(|| a.0.cmp(&b.0))
+        });
+        requests.sort_by(|a, b| {
+            let a_seq = a.1.seq().unwrap_or(f64::MAX);
+            let b_seq = b.1.seq().unwrap_or(f64::MAX);
+            a_seq.total_cmp(&b_seq).then_with(|| a.0.cmp(b.0))
+        });
+
+        for (subfolder, document, _) in subfolders {
+            let folder_name = subfolder
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let subdir = match document {
+                Some(document) => {
+                    let pointer = self.pointer(&subfolder.join(FOLDER_FILE));
+                    let name = document
+                        .value("meta", "name")
+                        .filter(|name| !name.is_empty())
+                        .unwrap_or(&folder_name)
+                        .to_string();
+                    let description = self.description(document, &pointer);
+                    let headers = self.headers(document, &pointer, true);
+                    let auth = self.auth(document, &pointer, &name);
+                    self.skip_variables(document, &pointer);
+                    self.push_dir(dir, &name, description, headers, auth)
+                }
+                None => self.push_dir(dir, &folder_name, None, vec![], None),
+            };
+            self.convert_folder(&subfolder, &subdir);
+        }
+
+        for (path, document) in requests {
+            self.convert_request(path, document, dir);
+        }
+    }
+
+    fn convert_request(&mut self, path: &Path, document: &Document, dir: &Path) {
+        let pointer = self.pointer(path);
+        let name = document
+            .value("meta", "name")
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| file_stem(path));
+
+        match document.value("meta", "type").unwrap_or("http") {
+            "http" => {}
+            "graphql" => {
+                self.skip(&pointer, "graphql requests are not supported");
+                return;
+            }
+            kind => {
+                self.skip(&pointer, format!("`{}` requests are not supported", kind));
+                return;
+            }
+        }
+        let Some((method_block, protocol)) = document.blocks.iter().find_map(|block| {
+            METHODS
+                .contains(&block.name.as_str())
+                .then(|| protocol(&block.name.to_uppercase()))
+                .flatten()
+                .map(|protocol| (block.name.as_str(), protocol))
+        }) else {
+            self.skip(&pointer, "the request has no method");
+            return;
+        };
+
+        let url = document.value(method_block, "url").unwrap_or_default();
+        let url = self.template(url, &pointer);
+        let (url, url_query) = match url.split_once('?') {
+            Some((url, query)) => (url.to_string(), Some(query.to_string())),
+            None => (url, None),
+        };
+        let (url, path_variables) = convert_path_variables(&url);
+
+        let path_values = document.pairs("params:path");
+        let path_params = path_variables
+            .into_iter()
+            .enumerate()
+            .map(|(order, name)| {
+                let value = path_values
+                    .iter()
+                    .find(|pair| pair.key == name)
+                    .map(|pair| self.template(&pair.value, &pointer))
+                    .unwrap_or_default();
+                AddPathParamParams {
+                    name,
+                    value: json!(value),
+                    order: order as isize,
+                    description: None,
+                    options: PathParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                }
+            })
+            .collect();
+
+        // The query is also written in the url, without the disabled params
+        let query = match (document.block("params:query"), url_query) {
+            (Some(_), _) => self.pairs(document.pairs("params:query"), &pointer),
+            (None, Some(query)) => form_pairs(&query)
+                .map(|(name, value)| (name, value, false))
+                .collect(),
+            (None, None) => vec![],
+        };
+        let query_params = query
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value, disabled))| AddQueryParamParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: QueryParamOptions {
+                    disabled,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        let mut headers = self.headers(document, &pointer, false);
+        let body_mode = document.value(method_block, "body").unwrap_or("none");
+        let body = self.body(document, body_mode, &pointer, &mut headers);
+        let auth = match document.value(method_block, "auth") {
+            Some(mode) => self.auth_of_mode(document, mode, &pointer, &name),
+            None => None,
+        };
+        let description = self.description(document, &pointer);
+        self.skip_variables(document, &pointer);
+        if !document.pairs("assert").is_empty() {
+            self.skip(&pointer, "assertions are not supported");
+        }
+
+        let name = self.names.claim(dir, &name);
+        let order = self.next_order(dir);
+        self.resources
+            .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                path: dir.to_path_buf(),
+                class: ResourceClass::Endpoint,
+                name,
+                description,
+                order,
+                protocol: Some(protocol),
+                url: Some(url),
+                headers,
+                path_params,
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }));
+    }
+
+    /// Pointers to the files of the collection, relative to its folder.
+    fn pointer(&self, path: &Path) -> String {
+        json_pointer(
+            path.components()
+                .filter_map(|component| component.as_os_str().to_str()),
+        )
+    }
+
+    fn skip_variables(&mut self, document: &Document, pointer: &str) {
+        if !document.pairs("vars:pre-request").is_empty()
+            || !document.pairs("vars:post-response").is_empty()
+        {
+            self.skip(
+                pointer,
+                "variables of requests and folders are not supported, set them in an environment instead",
+            );
+        }
+    }
+
+    /// The docs, followed by the scripts which are kept in the description.
+    fn description(&mut self, document: &Document, pointer: &str) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(docs) = document.text("docs").filter(|docs| !docs.trim().is_empty()) {
+            parts.push(docs.trim().to_string());
+        }
+
+        for (block, title) in [
+            ("script:pre-request", "Pre-request script"),
+            ("script:post-response", "Post-response script"),
+            ("tests", "Test script"),
+        ] {
+            let Some(script) = document
+                .text(block)
+                .filter(|script| !script.trim().is_empty())
+            else {
+                continue;
+            };
+            parts.push(format!("{}:\n```js\n{}\n```", title, script.trim_end()));
+            self.skip(
+                pointer,
+                "scripts are not supported, the script is kept in the description",
+            );
+        }
+
+        (!parts.is_empty()).then(|| parts.join("\n\n"))
+    }
+
+    /// Name, value and whether it's disabled, of the pairs of a block such as the headers.
+    fn pairs(&mut self, pairs: &[Pair], pointer: &str) -> Vec<(String, String, bool)> {
+        pairs
+            .iter()
+            .filter(|pair| !pair.key.is_empty())
+            .map(|pair| {
+                (
+                    self.template(&pair.key, pointer),
+                    self.template(&pair.value, pointer),
+                    pair.disabled,
+                )
+            })
+            .collect()
+    }
+
+    fn headers(
+        &mut self,
+        document: &Document,
+        pointer: &str,
+        propagate: bool,
+    ) -> Vec<AddHeaderParams> {
+        self.pairs(document.pairs("headers"), pointer)
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value, disabled))| AddHeaderParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled,
+                    propagate,
+                },
+            })
+            .collect()
+    }
+
+    fn body(
+        &mut self,
+        document: &Document,
+        mode: &str,
+        pointer: &str,
+        headers: &mut Vec<AddHeaderParams>,
+    ) -> Option<AddBodyParams> {
+        let text = |converter: &mut Self, block: &str| {
+            document
+                .text(block)
+                .filter(|text| !text.trim().is_empty())
+                .map(|text| converter.template(text, pointer))
+        };
+
+        let body = match mode {
+            "none" => return None,
+            "json" => {
+                let text = text(self, "body:json")?;
+                // Variables standing for whole values make the body invalid json
+                serde_json::from_str(&text)
+                    .map(AddBodyParams::Json)
+                    .unwrap_or(AddBodyParams::Text(text))
+            }
+            "text" => AddBodyParams::Text(text(self, "body:text")?),
+            "xml" => AddBodyParams::Xml(text(self, "body:xml")?),
+            "formUrlEncoded" => AddBodyParams::Urlencoded(
+                self.pairs(document.pairs("body:form-urlencoded"), pointer)
+                    .into_iter()
+                    .enumerate()
+                    .map(
+                        |(order, (name, value, disabled))| AddUrlencodedParamParams {
+                            name,
+                            value: json!(value),
+                            order: order as isize,
+                            description: None,
+                            options: UrlencodedParamOptions {
+                                disabled,
+                                propagate: false,
+                            },
+                            id: None,
+                        },
+                    )
+                    .collect(),
+            ),
+            "multipartForm" => {
+                let mut params = Vec::new();
+                for (name, value, disabled) in
+                    self.pairs(document.pairs("body:multipart-form"), pointer)
+                {
+                    if value.starts_with("@file(") {
+                        self.skip(pointer, "files of form data bodies are not supported");
+                        continue;
+                    }
+                    params.push(AddFormDataParamParams {
+                        name,
+                        value: json!(value),
+                        order: params.len() as isize,
+                        description: None,
+                        options: FormDataParamOptions {
+                            disabled,
+                            propagate: false,
+                        },
+                        id: None,
+                    });
+                }
+                AddBodyParams::FormData(params)
+            }
+            mode => {
+                self.skip(pointer, format!("`{}` bodies are not supported", mode));
+                return None;
+            }
+        };
+
+        let implied_content_type = match &body {
+            AddBodyParams::Text(_) => Some("text/plain"),
+            AddBodyParams::Json(_) => Some("application/json"),
+            AddBodyParams::Xml(_) => Some("application/xml"),
+            AddBodyParams::Binary(_) => Some("application/octet-stream"),
+            // The boundary of form data is chosen when the request is sent
+            AddBodyParams::Urlencoded(_) | AddBodyParams::FormData(_) => None,
+        };
+        if let Some(index) = headers
+            .iter()
+            .position(|header| header.name.eq_ignore_ascii_case("content-type"))
+            && implied_content_type.is_none_or(|implied| {
+                headers[index]
+                    .value
+                    .as_str()
+                    .is_some_and(|value| value.eq_ignore_ascii_case(implied))
+            })
+        {
+            headers.remove(index);
+            for (order, header) in headers.iter_mut().enumerate() {
+                header.order = order as isize;
+            }
+        }
+
+        Some(body)
+    }
+
+    /// The auth of a collection or a folder, whose mode is written in the `auth` block.
+    fn auth(&mut self, document: &Document, pointer: &str, owner: &str) -> Option<AuthScheme> {
+        let mode = document.value("auth", "mode")?;
+        self.auth_of_mode(document, mode, pointer, owner)
+    }
+
+    fn auth_of_mode(
+        &mut self,
+        document: &Document,
+        mode: &str,
+        pointer: &str,
+        owner: &str,
+    ) -> Option<AuthScheme> {
+        let block = format!("auth:{}", mode);
+        let attribute = |converter: &mut Self, key: &str| {
+            let value = document.value(&block, key).unwrap_or_default();
+            converter.template(value, pointer)
+        };
+
+        let scheme = match mode {
+            "inherit" => return None,
+            "none" => AuthScheme::None,
+            "basic" | "digest" => {
+                let username = attribute(self, "username");
+                let password = attribute(self, "password");
+                let password = self.secret(password, owner, "password");
+                if mode == "basic" {
+                    AuthScheme::Basic { username, password }
+                } else {
+                    AuthScheme::Digest { username, password }
+                }
+            }
+            "bearer" => {
+                let token = attribute(self, "token");
+                AuthScheme::Bearer {
+                    token: self.secret(token, owner, "token"),
+                }
+            }
+            "apikey" => {
+                let location = match attribute(self, "placement").as_str() {
+                    "" | "header" => ApiKeyLocation::Header,
+                    "queryparams" => ApiKeyLocation::Query,
+                    placement => {
+                        self.skip(
+                            pointer,
+                            format!("api keys placed in `{}` are not supported", placement),
+                        );
+                        return None;
+                    }
+                };
+                let key = attribute(self, "key");
+                let value = attribute(self, "value");
+                AuthScheme::ApiKey {
+                    key,
+                    value: self.secret(value, owner, "api key"),
+                    location,
+                }
+            }
+            "oauth2" => {
+                let grant = match attribute(self, "grant_type").as_str() {
+                    "" | "authorization_code" => OAuth2Grant::AuthorizationCode,
+                    "client_credentials" => OAuth2Grant::ClientCredentials,
+                    grant => {
+                        self.skip(
+                            pointer,
+                            format!("the `{}` oauth2 grant is not supported", grant),
+                        );
+                        return None;
+                    }
+                };
+                let client_secret = Some(attribute(self, "client_secret"))
+                    .filter(|secret| !secret.is_empty())
+                    .map(|secret| self.secret(secret, owner, "client secret"));
+
+                let config = OAuth2Config {
+                    grant,
+                    auth_url: Some(attribute(self, "authorization_url"))
+                        .filter(|url| !url.is_empty()),
+                    token_url: attribute(self, "access_token_url"),
+                    device_auth_url: None,
+                    client_id: attribute(self, "client_id"),
+                    client_secret,
+                    scopes: attribute(self, "scope")
+                        .split_whitespace()
+                        .map(str::to_string)
+                        .collect(),
+                    refresh_token: None,
+                    callback_port: None,
+                };
+                if let Err(reason) = config.check_grant() {
+                    self.skip(pointer, reason);
+                    return None;
+                }
+
+                AuthScheme::OAuth2(config)
+            }
+            "awsv4" => {
+                let access_key = attribute(self, "accessKeyId");
+                let secret_key = attribute(self, "secretAccessKey");
+                let session_token =
+                    Some(attribute(self, "sessionToken")).filter(|token| !token.is_empty());
+
+                AuthScheme::AwsSigV4(AwsSigV4Config {
+                    access_key: self.secret(access_key, owner, "access key"),
+                    secret_key: self.secret(secret_key, owner, "secret key"),
+                    session_token: session_token
+                        .map(|token| self.secret(token, owner, "session token")),
+                    region: attribute(self, "region"),
+                    service: attribute(self, "service"),
+                })
+            }
+            mode => {
+                self.skip(pointer, format!("`{}` auth is not supported", mode));
+                return None;
+            }
+        };
+
+        Some(scheme)
+    }
+
+    /// Secrets of auths have to reference variables, so the ones written inline
+    /// are moved to the environments as local values.
+    fn secret(&mut self, value: String, owner: &str, field: &str) -> String {
+        if is_variable_reference(&value) {
+            return value;
+        }
+
+        let variable = environment_variable(
+            &mut self.secret_names,
+            &format!("{} {}", owner, field),
+            Some(&json!(value)),
+            true,
+            false,
+            Some(format!("The {} of `{}`.", field, owner)),
+        );
+        let reference = format!("{{{{{}}}}}", variable.name);
+        self.secrets.push(variable);
+
+        reference
+    }
+
+    /// Reports the variables read from the environment of the process, which
+    /// Bruno references as `{{process.env.NAME}}`.
+    fn template(&mut self, text: &str, pointer: &str) -> String {
+        if text.contains("{{process.env.") {
+            self.skip(
+                pointer,
+                "`process.env` variables are not supported, set them in an environment instead",
+            );
+        }
+
+        template(text)
+    }
+
+    /// Variables of an environment, whose secrets are only listed by name since
+    /// Bruno keeps their values outside of the collection.
+    fn variables(&mut self, pairs: &[Pair], secrets: &[String]) -> Vec<AddVariableParams> {
+        let mut names = HashSet::new();
+        let mut variables = pairs
+            .iter()
+            .filter(|pair| !pair.key.is_empty())
+            .map(|pair| {
+                environment_variable(
+                    &mut names,
+                    &pair.key,
+                    Some(&json!(pair.value)),
+                    false,
+                    pair.disabled,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        for secret in secrets {
+            let (secret, disabled) = match secret.strip_prefix('~') {
+                Some(secret) => (secret, true),
+                None => (secret.as_str(), false),
+            };
+            variables.push(environment_variable(
+                &mut names, secret, None, true, disabled, None,
+            ));
+        }
+        for (order, variable) in variables.iter_mut().enumerate() {
+            variable.order = order as isize;
+        }
+
+        variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    fn files(files: &[(&str, &str)]) -> Vec<(PathBuf, String)> {
+        files
+            .iter()
+            .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+            .collect()
+    }
+
+    fn items(imported: &ImportedResources) -> Vec<&CreateItemResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let document = parse(
+            "meta {\n  name: Get user\n  seq: 2\n}\n\nparams:query {\n  page: 1\n  ~limit: 10\n}\n\n\
+             body:json {\n  {\n    \"name\": \"Rex\"\n  }\n}\n\nvars:secret [\n  token,\n  ~password\n]\n",
+        )
+        .unwrap();
+
+        assert_eq!(document.value("meta", "name"), Some("Get user"));
+        assert_eq!(document.seq(), Some(2.0));
+        assert!(document.pairs("params:query")[1].disabled);
+        assert_eq!(document.value("params:query", "limit"), None);
+        assert_eq!(
+            document.text("body:json"),
+            Some("{\n  \"name\": \"Rex\"\n}")
+        );
+        assert_eq!(document.list("vars:secret"), ["token", "~password"]);
+
+        assert!(parse("meta {\n  name: Get user\n").is_err());
+        assert!(parse("name: Get user\n").is_err());
+    }
+
+    #[test]
+    fn test_rejects_folders_without_config() {
+        assert!(convert(&files(&[("Get user.bru", "meta {\n  name: Get user\n}\n")])).is_err());
+    }
+
+    #[test]
+    fn test_requests_and_folders() {
+        let imported = convert(&files(&[
+            ("bruno.json", r#"{ "version": "1", "name": "Users", "type": "collection" }"#),
+            (
+                "collection.bru",
+                "headers {\n  X-Team: users\n}\n\nauth {\n  mode: bearer\n}\n\nauth:bearer {\n  token: abc\n}\n\n\
+                 vars:pre-request {\n  baseUrl: http://localhost:8080\n}\n",
+            ),
+            (
+                "users/folder.bru",
+                "meta {\n  name: User accounts\n  seq: 1\n}\n\nauth {\n  mode: inherit\n}\n",
+            ),
+            (
+                "users/Get user.bru",
+                "meta {\n  name: Get user\n  type: http\n  seq: 2\n}\n\n\
+                 get {\n  url: {{baseUrl}}/users/:id?page=1\n  body: none\n  auth: basic\n}\n\n\
+                 params:query {\n  page: 1\n  ~limit: 10\n}\n\nparams:path {\n  id: 42\n}\n\n\
+                 auth:basic {\n  username: admin\n  password: {{password}}\n}\n\n\
+                 docs {\n  Gets a user.\n}\n",
+            ),
+            (
+                "users/Create user.bru",
+                "meta {\n  name: Create user\n  type: http\n  seq: 1\n}\n\n\
+                 post {\n  url: {{baseUrl}}/users\n  body: json\n  auth: inherit\n}\n\n\
+                 headers {\n  Content-Type: application/json\n  ~X-Debug: 1\n}\n\n\
+                 body:json {\n  {\n    \"name\": \"Rex\"\n  }\n}\n\n\
+                 script:pre-request {\n  bru.setVar(\"id\", 1);\n}\n",
+            ),
+            (
+                "users/Query.bru",
+                "meta {\n  name: Query\n  type: graphql\n  seq: 3\n}\n\npost {\n  url: {{baseUrl}}/graphql\n}\n",
+            ),
+            (
+                "environments/Local.bru",
+                "vars {\n  baseUrl: http://localhost:3000\n}\n\nvars:secret [\n  password\n]\n",
+            ),
+        ]))
+        .unwrap();
+
+        let dirs = imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Dir(dir) => Some((dir.path.clone(), dir.name.as_str())),
+                BatchCreateResourceKind::Item(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dirs,
+            vec![
+                (PathBuf::new(), "Users"),
+                (PathBuf::from("Users"), "User accounts"),
+            ]
+        );
+
+        let items = items(&imported);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "Create user");
+        assert_eq!(items[0].path, Path::new("Users/User accounts"));
+        assert_eq!(items[0].protocol, Some(ResourceProtocol::Post));
+        assert_eq!(items[0].auth, None);
+        assert_eq!(items[0].headers.len(), 1);
+        assert_eq!(items[0].headers[0].name, "X-Debug");
+        assert!(items[0].headers[0].options.disabled);
+        assert!(matches!(
+            &items[0].body,
+            Some(AddBodyParams::Json(json)) if json == &json!({ "name": "Rex" })
+        ));
+        assert!(
+            items[0]
+                .description
+                .as_deref()
+                .is_some_and(|description| description.contains("bru.setVar"))
+        );
+
+        assert_eq!(items[1].name, "Get user");
+        assert_eq!(items[1].url.as_deref(), Some("{{baseUrl}}/users/:id"));
+        assert_eq!(items[1].description.as_deref(), Some("Gets a user."));
+        assert_eq!(items[1].path_params[0].value, json!("42"));
+        assert_eq!(items[1].query_params.len(), 2);
+        assert!(items[1].query_params[1].options.disabled);
+        assert_eq!(
+            items[1].auth,
+            Some(AuthScheme::Basic {
+                username: "admin".to_string(),
+                password: "{{password}}".to_string(),
+            })
+        );
+
+        let skipped = imported
+            .skipped
+            .iter()
+            .map(|item| (item.pointer.as_str(), item.reason.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            skipped,
+            vec![
+                (
+                    "/users/Create user.bru",
+                    "scripts are not supported, the script is kept in the description"
+                ),
+                ("/users/Query.bru", "graphql requests are not supported"),
+            ]
+        );
+
+        let environments = imported
+            .environments
+            .iter()
+            .map(|environment| {
+                (
+                    environment.name.as_str(),
+                    environment
+                        .variables
+                        .iter()
+                        .map(|variable| {
+                            (
+                                variable.name.as_str(),
+                                variable.global_value.clone(),
+                                variable.local_value.clone(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            environments,
+            vec![
+                (
+                    "Users",
+                    vec![
+                        ("baseUrl", json!("http://localhost:8080"), JsonValue::Null),
+                        ("Users_token", json!(""), json!("abc")),
+                    ]
+                ),
+                (
+                    "Local",
+                    vec![
+                        ("baseUrl", json!("http://localhost:3000"), JsonValue::Null),
+                        ("password", json!(""), json!("")),
+                        ("Users_token", json!(""), json!("abc")),
+                    ]
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,876 @@
+use joinerror::Error;
+use moss_project::models::types::{
+    AuthScheme, CreateItemResourceParams,
+    http::{
+        AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
+        AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions, HeaderParamOptions,
+        PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
+    },
+};
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::project::ImportSkippedItem;
+use serde_json::json;
+use std::{iter::Peekable, path::PathBuf, str::Chars};
+
+use crate::import::{
+    convert_path_variables, form_pairs, is_variable_reference, protocol, template,
+};
+
+/// Short options, along with the long ones they stand for.
+const SHORT_OPTIONS: &[(char, &str)] = &[
+    ('0', "--http1.0"),
+    ('4', "--ipv4"),
+    ('6', "--ipv6"),
+    ('#', "--progress-bar"),
+    ('A', "--user-agent"),
+    ('b', "--cookie"),
+    ('c', "--cookie-jar"),
+    ('D', "--dump-header"),
+    ('d', "--data"),
+    ('E', "--cert"),
+    ('e', "--referer"),
+    ('F', "--form"),
+    ('f', "--fail"),
+    ('G', "--get"),
+    ('g', "--globoff"),
+    ('H', "--header"),
+    ('I', "--head"),
+    ('K', "--config"),
+    ('i', "--include"),
+    ('k', "--insecure"),
+    ('L', "--location"),
+    ('m', "--max-time"),
+    ('N', "--no-buffer"),
+    ('O', "--remote-name"),
+    ('o', "--output"),
+    ('r', "--range"),
+    ('S', "--show-error"),
+    ('s', "--silent"),
+    ('T', "--upload-file"),
+    ('u', "--user"),
+    ('v', "--verbose"),
+    ('w', "--write-out"),
+    ('X', "--request"),
+    ('x', "--proxy"),
+    ('z', "--time-cond"),
+];
+
+/// Long options taking a value, besides the ones the request is built from.
+const VALUE_OPTIONS: &[&str] = &[
+    "--aws-sigv4",
+    "--cacert",
+    "--capath",
+    "--cert",
+    "--config",
+    "--connect-timeout",
+    "--connect-to",
+    "--cookie-jar",
+    "--dump-header",
+    "--interface",
+    "--key",
+    "--limit-rate",
+    "--max-redirs",
+    "--max-time",
+    "--output",
+    "--proxy",
+    "--proxy-user",
+    "--resolve",
+    "--retry",
+    "--retry-delay",
+    "--retry-max-time",
+    "--stderr",
+    "--time-cond",
+    "--trace",
+    "--trace-ascii",
+    "--upload-file",
+    "--write-out",
+];
+
+/// Options that only change what curl prints, or that match how requests are sent anyway.
+const IGNORED_OPTIONS: &[&str] = &[
+    "--basic",
+    "--compressed",
+    "--dump-header",
+    "--fail",
+    "--fail-with-body",
+    "--globoff",
+    "--http1.0",
+    "--http1.1",
+    "--http2",
+    "--http2-prior-knowledge",
+    "--include",
+    "--ipv4",
+    "--ipv6",
+    "--location",
+    "--max-redirs",
+    "--no-buffer",
+    "--no-progress-meter",
+    "--output",
+    "--progress-bar",
+    "--remote-name",
+    "--show-error",
+    "--silent",
+    "--stderr",
+    "--verbose",
+    "--write-out",
+];
+
+/// An endpoint converted from a curl command line.
+#[derive(Debug)]
+pub struct ImportedCommand {
+    pub params: CreateItemResourceParams,
+    pub skipped: Vec<ImportSkippedItem>,
+}
+
+/// Converts a curl command line, as copied from a terminal or the devtools of a browser,
+/// into an endpoint created in the `path` directory.
+///
+/// Options that only change the output of curl are ignored, while the ones changing how the
+/// request is sent, such as `--insecure`, are reported as skipped. Credentials given with
+/// `--user` or `--oauth2-bearer` can't be stored inline, and are replaced with variables.
+pub fn convert(
+    command: &str,
+    path: PathBuf,
+    name: Option<String>,
+    order: isize,
+) -> joinerror::Result<ImportedCommand> {
+    let arguments = split_arguments(command)?;
+    let is_curl = arguments.first().is_some_and(|program| {
+        let program = program.rsplit(['/', '\\']).next().unwrap_or(program);
+        program == "curl" || program == "curl.exe"
+    });
+    if !is_curl {
+        return Err(Error::new::<()>("the command is not a curl command"));
+    }
+
+    let mut converter = Converter::default();
+    converter.parse(&arguments)?;
+    converter.into_command(path, name, order)
+}
+
+enum Data {
+    /// Sent as it is written, `@file` excepted.
+    Raw { text: String, files: bool },
+    /// A field of `--data-urlencode`, encoded when the request is sent.
+    Field { name: Option<String>, value: String },
+}
+
+#[derive(Default)]
+struct Converter {
+    method: Option<String>,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+    data: Vec<(usize, Data)>,
+    form: Vec<(String, String)>,
+    cookies: Vec<String>,
+    auth: Option<AuthScheme>,
+    digest: bool,
+    get: bool,
+    head: bool,
+    skipped: Vec<ImportSkippedItem>,
+}
+
+impl Converter {
+    fn skip(&mut self, index: usize, reason: impl Into<String>) {
+        self.skipped.push(ImportSkippedItem {
+            pointer: format!("/{}", index),
+            reason: reason.into(),
+        });
+    }
+
+    fn parse(&mut self, arguments: &[String]) -> joinerror::Result<()> {
+        let mut index = 1;
+        let mut options_ended = false;
+        while index < arguments.len() {
+            let argument = &arguments[index];
+            index += 1;
+
+            if options_ended || !argument.starts_with('-') || argument == "-" {
+                self.set_url(index - 1, argument);
+                continue;
+            }
+            if argument == "--" {
+                options_ended = true;
+                continue;
+            }
+
+            // Short options can be grouped, the last one taking the rest as its value
+            let options = match argument.strip_prefix("--") {
+                Some(_) => vec![(argument.clone(), None)],
+                None => {
+                    let mut options = Vec::new();
+                    for (offset, short) in argument.char_indices().skip(1) {
+                        let long = SHORT_OPTIONS
+                            .iter()
+                            .find(|(option, _)| *option == short)
+                            .map_or_else(|| format!("-{}", short), |(_, long)| long.to_string());
+                        if takes_value(&long) {
+                            let rest = &argument[offset + short.len_utf8()..];
+                            options.push((long, (!rest.is_empty()).then(|| rest.to_string())));
+                            break;
+                        }
+                        options.push((long, None));
+                    }
+                    options
+                }
+            };
+
+            let pointer = index - 1;
+            for (option, value) in options {
+                let value = match value {
+                    Some(value) => Some(value),
+                    None if takes_value(&option) => {
+                        let value = arguments.get(index).cloned().ok_or_else(|| {
+                            Error::new::<()>(format!("option `{}` is missing its value", option))
+                        })?;
+                        index += 1;
+                        Some(value)
+                    }
+                    None => None,
+                };
+                self.option(pointer, &option, value.unwrap_or_default());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn option(&mut self, index: usize, option: &str, value: String) {
+        match option {
+            "--request" => self.method = Some(value.to_uppercase()),
+            "--url" => self.set_url(index, &value),
+            "--header" => self.header(index, &value),
+            "--user-agent" => self.headers.push(("User-Agent".to_string(), value)),
+            "--referer" => self.headers.push(("Referer".to_string(), value)),
+            "--range" => self
+                .headers
+                .push(("Range".to_string(), format!("bytes={}", value))),
+            "--cookie" => {
+                if value.contains('=') {
+                    self.cookies.push(value);
+                } else {
+                    self.skip(index, "cookies read from files are not supported");
+                }
+            }
+            "--data" | "--data-ascii" | "--data-binary" => self.data.push((
+                index,
+                Data::Raw {
+                    text: value,
+                    files: true,
+                },
+            )),
+            "--data-raw" => self.data.push((
+                index,
+                Data::Raw {
+                    text: value,
+                    files: false,
+                },
+            )),
+            "--json" => {
+                for name in ["Content-Type", "Accept"] {
+                    if !self.has_header(name) {
+                        self.headers
+                            .push((name.to_string(), "application/json".to_string()));
+                    }
+                }
+                self.data.push((
+                    index,
+                    Data::Raw {
+                        text: value,
+                        files: true,
+                    },
+                ));
+            }
+            "--data-urlencode" => self.data_urlencode(index, value),
+            "--form" | "--form-string" => {
+                let (name, field) = value.split_once('=').unwrap_or((&value, ""));
+                if option == "--form" && field.starts_with(['@', '<']) {
+                    self.skip(index, "files of form fields are not supported");
+                } else {
+                    self.form.push((name.to_string(), field.to_string()));
+                }
+            }
+            "--user" => self.user(index, &value),
+            "--digest" => self.digest = true,
+            "--oauth2-bearer" => {
+                if !is_variable_reference(&value) {
+                    self.skip(
+                        index,
+                        "the token isn't kept, set the `token` variable in an environment instead",
+                    );
+                }
+                self.auth = Some(AuthScheme::Bearer {
+                    token: variable_or(&value, "token"),
+                });
+            }
+            "--get" => self.get = true,
+            "--head" => self.head = true,
+            option if IGNORED_OPTIONS.contains(&option) => {}
+            option => self.skip(index, format!("option `{}` is not supported", option)),
+        }
+    }
+
+    fn set_url(&mut self, index: usize, url: &str) {
+        if self.url.is_some() {
+            self.skip(index, "only the first url of the command is imported");
+        } else {
+            self.url = Some(url.to_string());
+        }
+    }
+
+    fn has_header(&self, name: &str) -> bool {
+        self.headers
+            .iter()
+            .any(|(header, _)| header.eq_ignore_ascii_case(name))
+    }
+
+    /// Headers are written as `Name: value`, or as `Name;` to send one without a value.
+    fn header(&mut self, index: usize, header: &str) {
+        if header.starts_with('@') {
+            self.skip(index, "headers read from files are not supported");
+            return;
+        }
+
+        match header.split_once(':') {
+            // An empty value removes a header curl would send by itself
+            Some((_, value)) if value.trim().is_empty() => {}
+            Some((name, value)) => self
+                .headers
+                .push((name.trim().to_string(), value.trim().to_string())),
+            None => match header.strip_suffix(';') {
+                Some(name) => self.headers.push((name.trim().to_string(), String::new())),
+                None => self.skip(index, format!("malformed header `{}`", header)),
+            },
+        }
+    }
+
+    /// Fields are written as `content`, `=content` or `name=content`, and read
+    /// from a file when written as `@file` or `name@file`.
+    fn data_urlencode(&mut self, index: usize, field: String) {
+        let (name, value) = match field.find(['=', '@']) {
+            Some(at) if field[at..].starts_with('@') => {
+                self.skip(index, "data read from files is not supported");
+                return;
+            }
+            Some(0) => (None, field[1..].to_string()),
+            Some(at) => (Some(field[..at].to_string()), field[at + 1..].to_string()),
+            None => (None, field),
+        };
+        self.data.push((index, Data::Field { name, value }));
+    }
+
+    fn user(&mut self, index: usize, credentials: &str) {
+        let (username, password) = credentials.split_once(':').unwrap_or((credentials, ""));
+        if !is_variable_reference(password) {
+            self.skip(
+                index,
+                "the password isn't kept, set the `password` variable in an environment instead",
+            );
+        }
+        self.auth = Some(AuthScheme::Basic {
+            username: username.to_string(),
+            password: variable_or(password, "password"),
+        });
+    }
+
+    fn into_command(
+        mut self,
+        path: PathBuf,
+        name: Option<String>,
+        order: isize,
+    ) -> joinerror::Result<ImportedCommand> {
+        let url = self
+            .url
+            .take()
+            .ok_or_else(|| Error::new::<()>("the command has no url"))?;
+        let url = template(url.split('#').next().unwrap_or_default());
+        let (url, query) = url.split_once('?').unwrap_or((&url, ""));
+        let mut query = form_pairs(query).collect::<Vec<_>>();
+
+        if !self.cookies.is_empty() {
+            let cookies = self.cookies.join("; ");
+            self.headers.push(("Cookie".to_string(), cookies));
+        }
+        let mut headers = self
+            .headers
+            .iter()
+            .map(|(name, value)| (template(name), template(value)))
+            .collect::<Vec<_>>();
+
+        let has_body = !self.data.is_empty() || !self.form.is_empty();
+        let body = if self.get {
+            let text = self.data_text();
+            query.extend(form_pairs(&text));
+            None
+        } else {
+            self.body(&mut headers)
+        };
+
+        let method = match self.method.take() {
+            Some(method) => method,
+            None if self.head => "HEAD".to_string(),
+            None if has_body && !self.get => "POST".to_string(),
+            None => "GET".to_string(),
+        };
+        let protocol = protocol(&method)
+            .ok_or_else(|| Error::new::<()>(format!("unsupported method `{}`", method)))?;
+
+        let auth = self.auth.take().map(|auth| match auth {
+            AuthScheme::Basic { username, password } if self.digest => {
+                AuthScheme::Digest { username, password }
+            }
+            auth => auth,
+        });
+
+        let (url, path_variables) = convert_path_variables(url);
+        let name = name.unwrap_or_else(|| format!("{} {}", method, url));
+
+        let path_params = path_variables
+            .into_iter()
+            .enumerate()
+            .map(|(order, name)| AddPathParamParams {
+                name,
+                value: json!(""),
+                order: order as isize,
+                description: None,
+                options: PathParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+        let query_params = query
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value))| AddQueryParamParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+        let headers = headers
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value))| AddHeaderParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        Ok(ImportedCommand {
+            params: CreateItemResourceParams {
+                path,
+                class: ResourceClass::Endpoint,
+                name,
+                description: None,
+                order,
+                protocol: Some(protocol),
+                url: Some(url),
+                headers,
+                path_params,
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            },
+            skipped: self.skipped,
+        })
+    }
+
+    /// The data of the command as curl sends it, joined with `&`.
+    fn data_text(&mut self) -> String {
+        let mut parts = Vec::new();
+        for (index, data) in std::mem::take(&mut self.data) {
+            match data {
+                Data::Raw { text, files } if files && text.starts_with('@') => {
+                    self.skip(index, "data read from files is not supported")
+                }
+                Data::Raw { text, .. } => parts.push(template(&text)),
+                Data::Field { name, value } => {
+                    let value = urlencoding::encode(&template(&value)).into_owned();
+                    parts.push(match name {
+                        Some(name) => format!("{}={}", name, value),
+                        None => value,
+                    });
+                }
+            }
+        }
+
+        parts.join("&")
+    }
+
+    /// The body of the request, whose kind is told by its `Content-Type` header. The header
+    /// is removed when it's the one sent for the kind of body anyway.
+    fn body(&mut self, headers: &mut Vec<(String, String)>) -> Option<AddBodyParams> {
+        let content_type_index = headers
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        let content_type = content_type_index
+            .map(|index| headers[index].1.to_lowercase())
+            .unwrap_or_default();
+
+        let body = if !self.form.is_empty() {
+            if let Some((index, _)) = self.data.first() {
+                let index = *index;
+                self.skip(index, "data can't be sent along with form fields");
+            }
+            AddBodyParams::FormData(
+                std::mem::take(&mut self.form)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(order, (name, value))| AddFormDataParamParams {
+                        name: template(&name),
+                        value: json!(template(&value)),
+                        order: order as isize,
+                        description: None,
+                        options: FormDataParamOptions {
+                            disabled: false,
+                            propagate: false,
+                        },
+                        id: None,
+                    })
+                    .collect(),
+            )
+        } else if let [(_, Data::Raw { text, files: true })] = self.data.as_slice()
+            && let Some(path) = text.strip_prefix('@')
+            && path != "-"
+        {
+            AddBodyParams::Binary(PathBuf::from(path))
+        } else if self.data.is_empty() {
+            return None;
+        } else {
+            let text = self.data_text();
+
+            // Without a content type, curl sends the data as an urlencoded form,
+            // which is rarely what json data was meant to be sent as
+            let json = serde_json::from_str(&text).ok();
+            let is_json =
+                content_type.contains("json") || (content_type.is_empty() && json.is_some());
+            if content_type.contains("xml") {
+                AddBodyParams::Xml(text)
+            } else if is_json {
+                // Variables standing for whole values make the body invalid json
+                json.map(AddBodyParams::Json)
+                    .unwrap_or(AddBodyParams::Text(text))
+            } else if content_type.is_empty()
+                || content_type.starts_with("application/x-www-form-urlencoded")
+            {
+                AddBodyParams::Urlencoded(
+                    form_pairs(&text)
+                        .enumerate()
+                        .map(|(order, (name, value))| AddUrlencodedParamParams {
+                            name,
+                            value: json!(value),
+                            order: order as isize,
+                            description: None,
+                            options: UrlencodedParamOptions {
+                                disabled: false,
+                                propagate: false,
+                            },
+                            id: None,
+                        })
+                        .collect(),
+                )
+            } else {
+                AddBodyParams::Text(text)
+            }
+        };
+
+        let implied_content_type = match &body {
+            AddBodyParams::Text(_) => Some("text/plain"),
+            AddBodyParams::Json(_) => Some("application/json"),
+            AddBodyParams::Xml(_) => Some("application/xml"),
+            AddBodyParams::Binary(_) => Some("application/octet-stream"),
+            // The boundary of form data is chosen when the request is sent
+            AddBodyParams::Urlencoded(_) | AddBodyParams::FormData(_) => None,
+        };
+        if let Some(index) = content_type_index
+            && implied_content_type.is_none_or(|implied| implied == content_type)
+        {
+            headers.remove(index);
+        }
+
+        Some(body)
+    }
+}
+
+fn takes_value(option: &str) -> bool {
+    matches!(
+        option,
+        "--request"
+            | "--url"
+            | "--header"
+            | "--user-agent"
+            | "--referer"
+            | "--range"
+            | "--cookie"
+            | "--data"
+            | "--data-ascii"
+            | "--data-binary"
+            | "--data-raw"
+            | "--json"
+            | "--data-urlencode"
+            | "--form"
+            | "--form-string"
+            | "--user"
+            | "--oauth2-bearer"
+    ) || VALUE_OPTIONS.contains(&option)
+}
+
+/// Secrets written inline are replaced with a reference to the `name` variable.
+fn variable_or(value: &str, name: &str) -> String {
+    if is_variable_reference(value) {
+        template(value.trim())
+    } else {
+        format!("{{{{{}}}}}", name)
+    }
+}
+
+/// Splits a command line into its arguments the way a POSIX shell does, handling quotes,
+/// `$'...'` strings and lines continued with a backslash.
+fn split_arguments(command: &str) -> joinerror::Result<Vec<String>> {
+    let unterminated = || Error::new::<()>("the command has an unterminated quote");
+
+    let mut arguments = Vec::new();
+    let mut current: Option<String> = None;
+    let mut chars = command.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => arguments.extend(current.take()),
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                }
+                Some(c) => current.get_or_insert_default().push(c),
+                None => {}
+            },
+            '\'' => {
+                let argument = current.get_or_insert_default();
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '\'' => break,
+                        c => argument.push(c),
+                    }
+                }
+            }
+            '"' => {
+                let argument = current.get_or_insert_default();
+                loop {
+                    match chars.next().ok_or_else(unterminated)? {
+                        '"' => break,
+                        '\\' => match chars.next().ok_or_else(unterminated)? {
+                            '\n' => {}
+                            c @ ('"' | '\\' | '$' | '`') => argument.push(c),
+                            c => {
+                                argument.push('\\');
+                                argument.push(c);
+                            }
+                        },
+                        c => argument.push(c),
+                    }
+                }
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                let text = ansi_c_string(&mut chars).ok_or_else(unterminated)?;
+                current.get_or_insert_default().push_str(&text);
+            }
+            c => current.get_or_insert_default().push(c),
+        }
+    }
+    arguments.extend(current);
+
+    Ok(arguments)
+}
+
+/// Reads a `$'...'` string up to its closing quote, decoding its escape sequences.
+/// Browsers copy requests as curl commands using these strings.
+fn ansi_c_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    let mut bytes = Vec::new();
+    loop {
+        match chars.next()? {
+            '\'' => break,
+            '\\' => match chars.next()? {
+                'a' => bytes.push(0x07),
+                'b' => bytes.push(0x08),
+                'e' | 'E' => bytes.push(0x1b),
+                'f' => bytes.push(0x0c),
+                'n' => bytes.push(b'\n'),
+                'r' => bytes.push(b'\r'),
+                't' => bytes.push(b'\t'),
+                'v' => bytes.push(0x0b),
+                'x' => bytes.push(read_number(chars, 16, 2, 0)? as u8),
+                'u' => push_char(&mut bytes, char::from_u32(read_number(chars, 16, 4, 0)?)?),
+                'U' => push_char(&mut bytes, char::from_u32(read_number(chars, 16, 8, 0)?)?),
+                c @ '0'..='7' => {
+                    let digit = c.to_digit(8)?;
+                    bytes.push(read_number(chars, 8, 2, digit).unwrap_or(digit) as u8)
+                }
+                c => push_char(&mut bytes, c),
+            },
+            c => push_char(&mut bytes, c),
+        }
+    }
+
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn push_char(bytes: &mut Vec<u8>, c: char) {
+    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+/// Reads up to `max` digits of a number in the given radix, following the `start` digits.
+fn read_number(chars: &mut Peekable<Chars>, radix: u32, max: usize, start: u32) -> Option<u32> {
+    let mut number = None;
+    for _ in 0..max {
+        let Some(digit) = chars.peek().and_then(|c| c.to_digit(radix)) else {
+            break;
+        };
+        chars.next();
+        number = Some(number.unwrap_or(start) * radix + digit);
+    }
+
+    number
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    fn convert_command(command: &str) -> ImportedCommand {
+        convert(command, PathBuf::new(), None, 0).unwrap()
+    }
+
+    #[test]
+    fn test_split_arguments() {
+        assert_eq!(
+            split_arguments("curl 'a b' \"c \\\"d\\\"\" $'e\\nf\\u00e9\\x41' g\\\n  h").unwrap(),
+            vec!["curl", "a b", "c \"d\"", "e\nféA", "g", "h"]
+        );
+        assert!(split_arguments("curl 'a").is_err());
+    }
+
+    #[test]
+    fn test_convert() {
+        let imported = convert_command(
+            "curl 'https://api.example.com/pets/:id?expand=owner&q=a%20b' \\\n\
+             -X PUT \\\n\
+             -H 'Content-Type: application/json' \\\n\
+             -H 'Accept: application/json' \\\n\
+             -u admin:hunter2 \\\n\
+             --data-raw '{\"name\":\"{{name}}\"}' \\\n\
+             --compressed --insecure",
+        );
+        let params = imported.params;
+
+        assert_eq!(params.name, "PUT https://api.example.com/pets/:id");
+        assert_eq!(params.protocol, Some(ResourceProtocol::Put));
+        assert_eq!(
+            params.url.as_deref(),
+            Some("https://api.example.com/pets/:id")
+        );
+        assert_eq!(params.path_params[0].name, "id");
+        assert_eq!(
+            params
+                .query_params
+                .iter()
+                .map(|param| (param.name.as_str(), param.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![("expand", json!("owner")), ("q", json!("a b"))]
+        );
+        // The content type is the one sent for json bodies anyway
+        assert_eq!(params.headers.len(), 1);
+        assert_eq!(params.headers[0].name, "Accept");
+        assert!(
+            matches!(&params.body, Some(AddBodyParams::Json(body)) if body == &json!({ "name": "{{name}}" }))
+        );
+        assert_eq!(
+            params.auth,
+            Some(AuthScheme::Basic {
+                username: "admin".to_string(),
+                password: "{{password}}".to_string(),
+            })
+        );
+        assert_eq!(
+            imported
+                .skipped
+                .iter()
+                .map(|item| item.pointer.as_str())
+                .collect::<Vec<_>>(),
+            vec!["/8", "/13"]
+        );
+    }
+
+    #[test]
+    fn test_convert_data() {
+        let params = convert_command(
+            "curl -d a=1 --data-urlencode 'b=x y&z' -H 'X-Token: {{token}}' example.com/pets",
+        )
+        .params;
+        assert_eq!(params.protocol, Some(ResourceProtocol::Post));
+        assert_eq!(params.url.as_deref(), Some("example.com/pets"));
+        let Some(AddBodyParams::Urlencoded(fields)) = &params.body else {
+            panic!("expected an urlencoded body");
+        };
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| (field.name.as_str(), field.value.clone()))
+                .collect::<Vec<_>>(),
+            vec![("a", json!("1")), ("b", json!("x y&z"))]
+        );
+
+        let params = convert_command("curl -G -d a=1 -d b=2 https://example.com/pets").params;
+        assert_eq!(params.protocol, Some(ResourceProtocol::Get));
+        assert!(params.body.is_none());
+        assert_eq!(params.query_params.len(), 2);
+
+        let params =
+            convert_command("curl --data-binary @rex.png -XPOST https://example.com").params;
+        assert!(
+            matches!(&params.body, Some(AddBodyParams::Binary(path)) if path == &PathBuf::from("rex.png"))
+        );
+    }
+
+    #[test]
+    fn test_convert_form() {
+        let imported =
+            convert_command("curl -sSL -F name=Rex -F photo=@rex.png https://example.com/pets");
+        assert_eq!(imported.params.protocol, Some(ResourceProtocol::Post));
+        let Some(AddBodyParams::FormData(fields)) = &imported.params.body else {
+            panic!("expected a form data body");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "name");
+        assert_eq!(imported.skipped[0].pointer, "/4");
+    }
+
+    #[test]
+    fn test_convert_invalid_command() {
+        assert!(convert("wget https://example.com", PathBuf::new(), None, 0).is_err());
+        assert!(convert("curl -s", PathBuf::new(), None, 0).is_err());
+        assert!(convert("curl -H", PathBuf::new(), None, 0).is_err());
+    }
+}
@@ -0,0 +1,590 @@
+use joinerror::Error;
+use moss_project::models::{
+    operations::BatchCreateResourceKind,
+    types::{
+        CreateDirResourceParams, CreateItemResourceParams,
+        http::{
+            AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddQueryParamParams,
+            AddUrlencodedParamParams, FormDataParamOptions, HeaderParamOptions, QueryParamOptions,
+            UrlencodedParamOptions,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::project::ImportSkippedItem;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::import::{ImportedResources, UniqueNames, form_pairs, json_pointer, protocol};
+
+/// Headers set by the http client when the request is sent, which browsers record along with the others.
+const MANAGED_HEADERS: [&str; 4] = ["host", "content-length", "connection", "keep-alive"];
+
+/// Converts a HAR 1.2 log, such as one saved from the devtools of a browser, into resources.
+///
+/// Every entry becomes an endpoint, in a directory named after the host it was sent to.
+/// Entries sending the same method to the same url become a single endpoint, taken from
+/// the first of them, the urls being compared without their query and trailing slash.
+/// Only the entries at the indices of `entries` are imported, or all of them when it's `None`.
+pub fn convert(har: &JsonValue, entries: Option<&[usize]>) -> joinerror::Result<ImportedResources> {
+    let log_entries = har
+        .pointer("/log/entries")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| Error::new::<()>("the document is not a HAR log"))?;
+
+    let selected = match entries {
+        Some(entries) => {
+            if let Some(index) = entries.iter().find(|index| **index >= log_entries.len()) {
+                return Err(Error::new::<()>(format!("the log has no entry {}", index)));
+            }
+            let mut entries = entries.to_vec();
+            entries.sort_unstable();
+            entries.dedup();
+            entries
+        }
+        None => (0..log_entries.len()).collect(),
+    };
+    if selected.is_empty() {
+        return Err(Error::new::<()>("the log has no entries to import"));
+    }
+
+    let mut converter = Converter::default();
+    for index in selected {
+        converter.convert_entry(index, &log_entries[index]);
+    }
+
+    Ok(ImportedResources {
+        resources: converter.resources,
+        skipped: converter.skipped,
+        environments: vec![],
+    })
+}
+
+#[derive(Default)]
+struct Converter {
+    names: UniqueNames,
+    orders: HashMap<PathBuf, isize>,
+    /// Directories of the hosts met so far.
+    hosts: HashMap<String, PathBuf>,
+    /// Pointers of the entries converted so far, by method and normalized url.
+    endpoints: HashMap<(String, String), String>,
+    resources: Vec<BatchCreateResourceKind>,
+    skipped: Vec<ImportSkippedItem>,
+}
+
+impl Converter {
+    fn skip(&mut self, pointer: &str, reason: impl Into<String>) {
+        self.skipped.push(ImportSkippedItem {
+            pointer: pointer.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    fn next_order(&mut self, dir: &Path) -> isize {
+        let order = self.orders.entry(dir.to_path_buf()).or_default();
+        *order += 1;
+        *order - 1
+    }
+
+    fn convert_entry(&mut self, index: usize, entry: &JsonValue) {
+        let pointer = json_pointer(["log", "entries", &index.to_string()]);
+        let request = entry.get("request");
+        let method = request
+            .and_then(|request| request.get("method"))
+            .and_then(JsonValue::as_str)
+            .map(|method| method.trim().to_uppercase());
+        let url = request
+            .and_then(|request| request.get("url"))
+            .and_then(JsonValue::as_str);
+        let (Some(method), Some(url)) = (method, url) else {
+            self.skip(&pointer, "the entry has no request");
+            return;
+        };
+
+        let Some(url) = HarUrl::parse(url) else {
+            let scheme = url.split_once(':').map_or(url, |(scheme, _)| scheme);
+            self.skip(
+                &pointer,
+                format!("`{}` requests are not supported", scheme.to_lowercase()),
+            );
+            return;
+        };
+        let Some(protocol) = protocol(&method) else {
+            self.skip(&pointer, format!("invalid http method `{}`", method));
+            return;
+        };
+
+        let key = (method.clone(), url.normalized());
+        if let Some(first) = self.endpoints.get(&key) {
+            let reason = format!("duplicate of the request of `{}`", first);
+            self.skip(&pointer, reason);
+            return;
+        }
+        self.endpoints.insert(key, pointer.clone());
+
+        let mut headers = name_values(request.and_then(|request| request.get("headers")))
+            .into_iter()
+            .filter(|(name, _)| {
+                // HTTP/2 pseudo-headers, such as `:authority`
+                !name.starts_with(':')
+                    && !MANAGED_HEADERS
+                        .iter()
+                        .any(|managed| name.eq_ignore_ascii_case(managed))
+            })
+            .collect::<Vec<_>>();
+
+        let mut query = form_pairs(url.query).collect::<Vec<_>>();
+        if query.is_empty() {
+            query = name_values(request.and_then(|request| request.get("queryString")));
+        }
+        let query_params = query
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value))| AddQueryParamParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        let body = request
+            .and_then(|request| request.get("postData"))
+            .and_then(|post_data| self.body(post_data, &mut headers, &pointer));
+        let headers = headers
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value))| AddHeaderParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        let dir = self.host_dir(&url.authority);
+        let path = if url.path.is_empty() { "/" } else { url.path };
+        let name = self.names.claim(&dir, &format!("{} {}", method, path));
+        let order = self.next_order(&dir);
+        self.resources
+            .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                path: dir,
+                class: ResourceClass::Endpoint,
+                name,
+                description: None,
+                order,
+                protocol: Some(protocol),
+                url: Some(url.normalized()),
+                headers,
+                path_params: vec![],
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth: None,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }));
+    }
+
+    /// The directory of the endpoints sent to the host, created along with its first endpoint.
+    fn host_dir(&mut self, host: &str) -> PathBuf {
+        if let Some(dir) = self.hosts.get(host) {
+            return dir.clone();
+        }
+
+        let root = PathBuf::new();
+        let name = self.names.claim(&root, host);
+        let order = self.next_order(&root);
+        self.resources
+            .push(BatchCreateResourceKind::Dir(CreateDirResourceParams {
+                path: root.clone(),
+                class: ResourceClass::Endpoint,
+                name: name.clone(),
+                description: None,
+                order,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
+            }));
+
+        let dir = root.join(name);
+        self.hosts.insert(host.to_string(), dir.clone());
+        dir
+    }
+
+    /// The body of a request, whose kind is told by the mime type of its post data.
+    /// The `Content-Type` header is removed when it's the one sent for the kind of body anyway.
+    fn body(
+        &mut self,
+        post_data: &JsonValue,
+        headers: &mut Vec<(String, String)>,
+        pointer: &str,
+    ) -> Option<AddBodyParams> {
+        let content_type_index = headers
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        let mime_type = post_data
+            .get("mimeType")
+            .and_then(JsonValue::as_str)
+            .or_else(|| content_type_index.map(|index| headers[index].1.as_str()))
+            .unwrap_or_default()
+            .to_lowercase();
+        let text = post_data
+            .get("text")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default();
+        let params = post_data
+            .get("params")
+            .and_then(JsonValue::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let body = if mime_type.starts_with("multipart/form-data") {
+            if params.is_empty() {
+                self.skip(pointer, "form data bodies without params are not supported");
+                return None;
+            }
+
+            let mut form_data = Vec::new();
+            for param in params {
+                if param.get("fileName").is_some() {
+                    self.skip(pointer, "files of form data bodies are not supported");
+                    continue;
+                }
+                form_data.push(AddFormDataParamParams {
+                    name: string(param.get("name")),
+                    value: json!(string(param.get("value"))),
+                    order: form_data.len() as isize,
+                    description: None,
+                    options: FormDataParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                    id: None,
+                });
+            }
+            AddBodyParams::FormData(form_data)
+        } else if mime_type.starts_with("application/x-www-form-urlencoded") {
+            let pairs = if text.is_empty() {
+                params
+                    .iter()
+                    .map(|param| (string(param.get("name")), string(param.get("value"))))
+                    .collect()
+            } else {
+                form_pairs(text).collect::<Vec<_>>()
+            };
+            AddBodyParams::Urlencoded(
+                pairs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(order, (name, value))| AddUrlencodedParamParams {
+                        name,
+                        value: json!(value),
+                        order: order as isize,
+                        description: None,
+                        options: UrlencodedParamOptions {
+                            disabled: false,
+                            propagate: false,
+                        },
+                        id: None,
+                    })
+                    .collect(),
+            )
+        } else {
+            if text.is_empty() {
+                return None;
+            }
+            if post_data.get("encoding").and_then(JsonValue::as_str) == Some("base64") {
+                self.skip(pointer, "base64 encoded bodies are not supported");
+                return None;
+            }
+
+            if mime_type.contains("xml") {
+                AddBodyParams::Xml(text.to_string())
+            } else if mime_type.contains("json") {
+                serde_json::from_str(text)
+                    .map(AddBodyParams::Json)
+                    .unwrap_or_else(|_| AddBodyParams::Text(text.to_string()))
+            } else {
+                AddBodyParams::Text(text.to_string())
+            }
+        };
+
+        let implied_content_type = match &body {
+            AddBodyParams::Text(_) => Some("text/plain"),
+            AddBodyParams::Json(_) => Some("application/json"),
+            AddBodyParams::Xml(_) => Some("application/xml"),
+            AddBodyParams::Binary(_) => Some("application/octet-stream"),
+            // The boundary of form data is chosen when the request is sent
+            AddBodyParams::Urlencoded(_) | AddBodyParams::FormData(_) => None,
+        };
+        if let Some(index) = content_type_index
+            && implied_content_type
+                .is_none_or(|implied| headers[index].1.eq_ignore_ascii_case(implied))
+        {
+            headers.remove(index);
+        }
+
+        Some(body)
+    }
+}
+
+/// An http or https url, split into the parts endpoints are compared by.
+struct HarUrl<'a> {
+    scheme: String,
+    /// The host along with its port, unless it's the default one of the scheme.
+    authority: String,
+    /// The path without its trailing slash.
+    path: &'a str,
+    query: &'a str,
+}
+
+impl<'a> HarUrl<'a> {
+    fn parse(url: &'a str) -> Option<Self> {
+        let (scheme, rest) = url.trim().split_once("://")?;
+        let scheme = scheme.to_lowercase();
+        let default_port = match scheme.as_str() {
+            "http" => ":80",
+            "https" => ":443",
+            _ => return None,
+        };
+
+        let rest = rest.split('#').next().unwrap_or_default();
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let (authority, path) = rest
+            .find('/')
+            .map_or((rest, ""), |index| rest.split_at(index));
+        // Credentials aren't part of the endpoint
+        let authority = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, authority)| authority)
+            .to_lowercase();
+        let authority = authority
+            .strip_suffix(default_port)
+            .map(str::to_string)
+            .unwrap_or(authority);
+        if authority.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            scheme,
+            authority,
+            path: path.trim_end_matches('/'),
+            query,
+        })
+    }
+
+    fn normalized(&self) -> String {
+        format!("{}://{}{}", self.scheme, self.authority, self.path)
+    }
+}
+
+/// Pairs of a `name` and `value` array, such as the headers of a request.
+fn name_values(values: Option<&JsonValue>) -> Vec<(String, String)> {
+    values
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .map(|value| (string(value.get("name")), string(value.get("value"))))
+        .collect()
+}
+
+fn string(value: Option<&JsonValue>) -> String {
+    value
+        .and_then(JsonValue::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    fn items(imported: &ImportedResources) -> Vec<&CreateItemResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect()
+    }
+
+    fn dirs(imported: &ImportedResources) -> Vec<&CreateDirResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Dir(dir) => Some(dir),
+                BatchCreateResourceKind::Item(_) => None,
+            })
+            .collect()
+    }
+
+    fn har() -> JsonValue {
+        json!({
+            "log": {
+                "version": "1.2",
+                "entries": [
+                    {
+                        "request": {
+                            "method": "GET",
+                            "url": "https://API.example.com:443/pets/?limit=10&tag=good%20boy",
+                            "headers": [
+                                { "name": ":authority", "value": "api.example.com" },
+                                { "name": "Accept", "value": "application/json" },
+                                { "name": "Connection", "value": "keep-alive" }
+                            ],
+                            "queryString": [
+                                { "name": "limit", "value": "10" },
+                                { "name": "tag", "value": "good%20boy" }
+                            ]
+                        }
+                    },
+                    {
+                        "request": {
+                            "method": "GET",
+                            "url": "https://api.example.com/pets?limit=20"
+                        }
+                    },
+                    {
+                        "request": {
+                            "method": "post",
+                            "url": "https://api.example.com/pets",
+                            "headers": [
+                                { "name": "Content-Type", "value": "application/json" },
+                                { "name": "Content-Length", "value": "14" }
+                            ],
+                            "postData": {
+                                "mimeType": "application/json",
+                                "text": "{\"name\":\"Rex\"}"
+                            }
+                        }
+                    },
+                    {
+                        "request": {
+                            "method": "GET",
+                            "url": "wss://api.example.com/events"
+                        }
+                    },
+                    {
+                        "request": {
+                            "method": "POST",
+                            "url": "http://localhost:8080/upload",
+                            "headers": [
+                                { "name": "Content-Type", "value": "multipart/form-data; boundary=x" }
+                            ],
+                            "postData": {
+                                "mimeType": "multipart/form-data; boundary=x",
+                                "params": [
+                                    { "name": "title", "value": "Rex" },
+                                    { "name": "photo", "fileName": "rex.png", "contentType": "image/png" }
+                                ]
+                            }
+                        }
+                    }
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn test_rejects_other_documents() {
+        assert!(convert(&json!({ "openapi": "3.1.0" }), None).is_err());
+        assert!(convert(&json!({ "log": { "entries": [] } }), None).is_err());
+        assert!(convert(&har(), Some(&[5])).is_err());
+    }
+
+    #[test]
+    fn test_entries() {
+        let imported = convert(&har(), None).unwrap();
+
+        let dirs = dirs(&imported);
+        assert_eq!(dirs.len(), 2);
+        assert_eq!(dirs[0].name, "api.example.com");
+        assert_eq!(dirs[1].name, "localhost:8080");
+
+        let items = items(&imported);
+        assert_eq!(items.len(), 3);
+
+        assert_eq!(items[0].name, "GET /pets");
+        assert_eq!(items[0].path, Path::new("api.example.com"));
+        assert_eq!(
+            items[0].url.as_deref(),
+            Some("https://api.example.com/pets")
+        );
+        assert_eq!(items[0].headers.len(), 1);
+        assert_eq!(items[0].headers[0].name, "Accept");
+        assert_eq!(items[0].query_params[1].name, "tag");
+        assert_eq!(items[0].query_params[1].value, json!("good boy"));
+
+        assert_eq!(items[1].name, "POST /pets");
+        assert_eq!(items[1].protocol, Some(ResourceProtocol::Post));
+        // The content type is the one sent for json bodies anyway
+        assert!(items[1].headers.is_empty());
+        assert!(matches!(
+            &items[1].body,
+            Some(AddBodyParams::Json(json)) if json == &json!({ "name": "Rex" })
+        ));
+
+        assert_eq!(items[2].path, Path::new("localhost:8080"));
+        assert_eq!(items[2].order, 0);
+        assert!(items[2].headers.is_empty());
+        let Some(AddBodyParams::FormData(fields)) = &items[2].body else {
+            panic!("expected a form data body");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "title");
+
+        assert_eq!(
+            imported.skipped,
+            vec![
+                ImportSkippedItem {
+                    pointer: "/log/entries/1".to_string(),
+                    reason: "duplicate of the request of `/log/entries/0`".to_string(),
+                },
+                ImportSkippedItem {
+                    pointer: "/log/entries/3".to_string(),
+                    reason: "`wss` requests are not supported".to_string(),
+                },
+                ImportSkippedItem {
+                    pointer: "/log/entries/4".to_string(),
+                    reason: "files of form data bodies are not supported".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selected_entries() {
+        let imported = convert(&har(), Some(&[1, 4, 1])).unwrap();
+
+        let items = items(&imported);
+        assert_eq!(items.len(), 2);
+        // Without the first entry, the second one is no longer a duplicate
+        assert_eq!(items[0].query_params[0].value, json!("20"));
+        assert_eq!(items[1].name, "POST /upload");
+    }
+}
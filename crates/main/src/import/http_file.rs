@@ -0,0 +1,736 @@
+use joinerror::Error;
+use moss_environment::models::types::{AddVariableParams, VariableOptions};
+use moss_project::models::{
+    operations::BatchCreateResourceKind,
+    types::{
+        CreateItemResourceParams,
+        http::{
+            AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
+            AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
+            HeaderParamOptions, PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::project::ImportSkippedItem;
+use serde_json::{Value as JsonValue, json};
+use std::path::{Path, PathBuf};
+
+use crate::import::{
+    ImportedEnvironment, ImportedResources, UniqueNames, convert_path_variables, form_pairs,
+    protocol, template, template_variables, variable_name,
+};
+
+const REQUEST_SEPARATOR: &str = "###";
+
+/// Requests of other protocols, which the HTTP Client of JetBrains IDEs writes in the same files.
+const UNSUPPORTED_METHODS: [&str; 3] = ["GRAPHQL", "GRPC", "WEBSOCKET"];
+
+/// Converts a `.http` file, as written for the HTTP Client of JetBrains IDEs
+/// or the REST Client of VS Code, into resources.
+///
+/// Every request becomes an endpoint at the root of the project, named after the text
+/// of its `###` separator or its `# @name` directive. The `@name = value` variables
+/// of the file are gathered in an environment called `name`, and the relative paths
+/// of bodies read from files are resolved against `base_dir`.
+pub fn convert(content: &str, name: &str, base_dir: &Path) -> joinerror::Result<ImportedResources> {
+    let mut converter = Converter {
+        base_dir,
+        requests: 0,
+        names: UniqueNames::default(),
+        resources: Vec::new(),
+        skipped: Vec::new(),
+        variables: Vec::new(),
+    };
+    for block in blocks(content) {
+        converter.convert_block(block);
+    }
+
+    if converter.requests == 0 {
+        return Err(Error::new::<()>("the file has no requests"));
+    }
+
+    let mut environments = Vec::new();
+    if !converter.variables.is_empty() {
+        environments.push(ImportedEnvironment {
+            name: name.to_string(),
+            variables: converter.variables,
+        });
+    }
+
+    Ok(ImportedResources {
+        resources: converter.resources,
+        skipped: converter.skipped,
+        environments,
+    })
+}
+
+/// The lines between two `###` separators, which hold at most one request.
+struct Block<'a> {
+    name: Option<&'a str>,
+    lines: Vec<&'a str>,
+}
+
+fn blocks(content: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut block = Block {
+        name: None,
+        lines: Vec::new(),
+    };
+    for line in content.lines() {
+        match line.trim_start().strip_prefix(REQUEST_SEPARATOR) {
+            Some(name) => {
+                let name = name.trim_start_matches('#').trim();
+                blocks.push(std::mem::replace(
+                    &mut block,
+                    Block {
+                        name: (!name.is_empty()).then_some(name),
+                        lines: Vec::new(),
+                    },
+                ));
+            }
+            None => block.lines.push(line),
+        }
+    }
+    blocks.push(block);
+
+    blocks
+}
+
+struct Converter<'a> {
+    base_dir: &'a Path,
+    /// Requests met so far, including the skipped ones, which gives the index of the next one.
+    requests: usize,
+    names: UniqueNames,
+    resources: Vec<BatchCreateResourceKind>,
+    skipped: Vec<ImportSkippedItem>,
+    variables: Vec<AddVariableParams>,
+}
+
+impl Converter<'_> {
+    fn skip(&mut self, pointer: &str, reason: impl Into<String>) {
+        let item = ImportSkippedItem {
+            pointer: pointer.to_string(),
+            reason: reason.into(),
+        };
+        // The same variable is often used several times in a single request
+        if !self.skipped.contains(&item) {
+            self.skipped.push(item);
+        }
+    }
+
+    fn convert_block(&mut self, block: Block) {
+        let lines = block.lines;
+        let mut name = block.name.map(str::to_string);
+        let mut description = Vec::new();
+        let mut directives = Vec::new();
+        let mut has_script = false;
+
+        // Comments, directives and variables come before the request line
+        let mut index = 0;
+        let request_line = loop {
+            let Some(line) = lines.get(index).map(|line| line.trim()) else {
+                return;
+            };
+            index += 1;
+
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = comment(line) {
+                match comment.strip_prefix('@') {
+                    Some(directive) => {
+                        let (key, value) = directive
+                            .split_once([' ', '\t', '='])
+                            .unwrap_or((directive, ""));
+                        let value = value.trim().trim_start_matches('=').trim();
+                        if key == "name" && !value.is_empty() {
+                            name.get_or_insert_with(|| value.to_string());
+                        } else {
+                            directives.push(key);
+                        }
+                    }
+                    None => description.push(comment),
+                }
+                continue;
+            }
+            if let Some(definition) = line.strip_prefix('@') {
+                self.define_variable(definition);
+                continue;
+            }
+            // Pre-request scripts, either inline or read from a file
+            if line.starts_with('<') {
+                has_script = true;
+                if line.contains("{%") && !line.contains("%}") {
+                    while let Some(line) = lines.get(index) {
+                        index += 1;
+                        if line.contains("%}") {
+                            break;
+                        }
+                    }
+                }
+                continue;
+            }
+
+            break line;
+        };
+
+        let pointer = format!("/{}", self.requests);
+        self.requests += 1;
+        for directive in directives {
+            self.skip(
+                &pointer,
+                format!("the `@{}` directive is not supported", directive),
+            );
+        }
+        if has_script {
+            self.skip(&pointer, "pre-request scripts are not supported");
+        }
+
+        let (method, target) = request_line
+            .split_once(char::is_whitespace)
+            .filter(|(method, _)| method.chars().all(|c| c.is_ascii_uppercase()))
+            .map_or(("GET", request_line), |(method, target)| {
+                (method, target.trim())
+            });
+        let mut target = match target.rsplit_once(char::is_whitespace) {
+            Some((target, version)) if version.starts_with("HTTP/") => target.trim_end(),
+            _ => target,
+        }
+        .to_string();
+
+        // Long queries can be continued on the following lines
+        while let Some(line) = lines.get(index).map(|line| line.trim())
+            && line.starts_with(['?', '&'])
+        {
+            target.push_str(line);
+            index += 1;
+        }
+
+        if UNSUPPORTED_METHODS.contains(&method) {
+            self.skip(&pointer, format!("`{}` requests are not supported", method));
+            return;
+        }
+        let Some(protocol) = protocol(method) else {
+            self.skip(&pointer, format!("invalid http method `{}`", method));
+            return;
+        };
+
+        let mut headers = Vec::new();
+        while let Some(line) = lines.get(index).map(|line| line.trim()) {
+            // Responses can be handled right after the headers, without a body in between
+            if line.is_empty() || is_response_handler(line) {
+                break;
+            }
+            index += 1;
+
+            if comment(line).is_some() {
+                continue;
+            }
+            match line.split_once(':') {
+                Some((name, value)) => headers.push((
+                    self.template(name.trim(), &pointer),
+                    self.template(value.trim(), &pointer),
+                )),
+                None => self.skip(&pointer, format!("malformed header `{}`", line)),
+            }
+        }
+
+        let mut body_lines = &lines[index..];
+        if let Some(handler) = body_lines
+            .iter()
+            .position(|line| is_response_handler(line.trim_start()))
+        {
+            self.skip(&pointer, "handling of responses is not supported");
+            body_lines = &body_lines[..handler];
+        }
+        let body = self.body(body_lines.join("\n").trim(), &mut headers, &pointer);
+
+        let target = self.template(&target, &pointer);
+        let target = target.split('#').next().unwrap_or_default();
+        let (url, query) = target.split_once('?').unwrap_or((target, ""));
+
+        // Requests can be written with nothing more than a path, when the host is given as a header
+        let mut url = url.to_string();
+        if url.starts_with('/')
+            && let Some(host) = headers
+                .iter()
+                .position(|(name, _)| name.eq_ignore_ascii_case("host"))
+        {
+            let (_, host) = headers.remove(host);
+            url = format!("http://{}{}", host, url);
+        }
+        let (url, path_variables) = convert_path_variables(&url);
+
+        let path_params = path_variables
+            .into_iter()
+            .enumerate()
+            .map(|(order, name)| AddPathParamParams {
+                name,
+                value: json!(""),
+                order: order as isize,
+                description: None,
+                options: PathParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+        let query_params = form_pairs(query)
+            .enumerate()
+            .map(|(order, (name, value))| AddQueryParamParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+        let headers = headers
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value))| AddHeaderParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        let name = name.unwrap_or_else(|| format!("{} {}", method, url));
+        let name = self.names.claim(Path::new(""), &name);
+        self.resources
+            .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                path: PathBuf::new(),
+                class: ResourceClass::Endpoint,
+                name,
+                description: (!description.is_empty()).then(|| description.join("\n")),
+                order: self.resources.len() as isize,
+                protocol: Some(protocol),
+                url: Some(url),
+                headers,
+                path_params,
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth: None,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }));
+    }
+
+    /// Variables of the file apply to every request, and the last definition of a name wins.
+    fn define_variable(&mut self, definition: &str) {
+        let (name, value) = definition.split_once('=').unwrap_or((definition, ""));
+        let name = variable_name(name);
+        let value = json!(template(value.trim()));
+
+        if let Some(variable) = self
+            .variables
+            .iter_mut()
+            .find(|variable| variable.name == name)
+        {
+            variable.global_value = value;
+            return;
+        }
+
+        self.variables.push(AddVariableParams {
+            name,
+            global_value: value,
+            local_value: JsonValue::Null,
+            order: self.variables.len() as isize,
+            desc: None,
+            options: VariableOptions { disabled: false },
+        });
+    }
+
+    /// Rewrites the `{{name}}` variables of a value into ones the template parser accepts.
+    fn template(&mut self, text: &str, pointer: &str) -> String {
+        for name in template_variables(text) {
+            if let Some(dynamic) = name.strip_prefix('$') {
+                self.skip(
+                    pointer,
+                    format!(
+                        "dynamic variable `{{{{{}}}}}` is not supported, set `{}` in an environment instead",
+                        name,
+                        variable_name(dynamic)
+                    ),
+                );
+            } else if name.contains(".response.") || name.contains(".request.") {
+                self.skip(
+                    pointer,
+                    format!(
+                        "request variable `{{{{{}}}}}` is not supported, set `{}` in an environment instead",
+                        name,
+                        variable_name(name)
+                    ),
+                );
+            }
+        }
+
+        template(text)
+    }
+
+    /// The body of a request, whose kind is told by its `Content-Type` header. The header
+    /// is removed when it's the one sent for the kind of body anyway.
+    fn body(
+        &mut self,
+        text: &str,
+        headers: &mut Vec<(String, String)>,
+        pointer: &str,
+    ) -> Option<AddBodyParams> {
+        if text.is_empty() {
+            return None;
+        }
+
+        let content_type_index = headers
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        let content_type_header = content_type_index
+            .map(|index| headers[index].1.clone())
+            .unwrap_or_default();
+        let content_type = content_type_header.to_lowercase();
+
+        let body = if let Some(path) = file_reference(text) {
+            if text.starts_with("<@") {
+                self.skip(
+                    pointer,
+                    "variables of files read as bodies are not substituted",
+                );
+            }
+            let path = Path::new(path);
+            AddBodyParams::Binary(self.base_dir.join(path.strip_prefix(".").unwrap_or(path)))
+        } else if content_type.starts_with("multipart/form-data") {
+            let Some(boundary) = header_param(&content_type_header, "boundary") else {
+                self.skip(pointer, "the boundary of the multipart body is missing");
+                return None;
+            };
+            AddBodyParams::FormData(self.form_data(text, &boundary, pointer))
+        } else if content_type.starts_with("application/x-www-form-urlencoded") {
+            let text = text.lines().map(str::trim).collect::<String>();
+            AddBodyParams::Urlencoded(
+                form_pairs(&self.template(&text, pointer))
+                    .enumerate()
+                    .map(|(order, (name, value))| AddUrlencodedParamParams {
+                        name,
+                        value: json!(value),
+                        order: order as isize,
+                        description: None,
+                        options: UrlencodedParamOptions {
+                            disabled: false,
+                            propagate: false,
+                        },
+                        id: None,
+                    })
+                    .collect(),
+            )
+        } else {
+            let text = self.template(text, pointer);
+            let is_json = content_type.contains("json")
+                || (content_type.is_empty() && text.starts_with(['{', '[']));
+            if content_type.contains("xml") {
+                AddBodyParams::Xml(text)
+            } else if is_json {
+                // Variables standing for whole values make the body invalid json
+                serde_json::from_str(&text)
+                    .map(AddBodyParams::Json)
+                    .unwrap_or(AddBodyParams::Text(text))
+            } else {
+                AddBodyParams::Text(text)
+            }
+        };
+
+        let implied_content_type = match &body {
+            AddBodyParams::Text(_) => Some("text/plain"),
+            AddBodyParams::Json(_) => Some("application/json"),
+            AddBodyParams::Xml(_) => Some("application/xml"),
+            AddBodyParams::Binary(_) => Some("application/octet-stream"),
+            // The boundary of form data is chosen when the request is sent
+            AddBodyParams::Urlencoded(_) | AddBodyParams::FormData(_) => None,
+        };
+        if let Some(index) = content_type_index
+            && implied_content_type.is_none_or(|implied| implied == content_type)
+        {
+            headers.remove(index);
+        }
+
+        Some(body)
+    }
+
+    fn form_data(
+        &mut self,
+        text: &str,
+        boundary: &str,
+        pointer: &str,
+    ) -> Vec<AddFormDataParamParams> {
+        let mut params = Vec::new();
+        for part in text.split(&format!("--{}", boundary)).skip(1) {
+            // The closing delimiter
+            if part.starts_with("--") {
+                break;
+            }
+
+            let part = part.strip_prefix('\n').unwrap_or(part);
+            let (head, value) = part.split_once("\n\n").unwrap_or((part, ""));
+            let disposition = head.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.trim()
+                    .eq_ignore_ascii_case("content-disposition")
+                    .then_some(value)
+            });
+            let Some(name) = disposition.and_then(|disposition| header_param(disposition, "name"))
+            else {
+                self.skip(pointer, "parts of form data bodies need a name");
+                continue;
+            };
+
+            let value = value.strip_suffix('\n').unwrap_or(value);
+            let is_file = disposition.is_some_and(|d| header_param(d, "filename").is_some())
+                || file_reference(value.trim()).is_some();
+            if is_file {
+                self.skip(pointer, "files of form data bodies are not supported");
+                continue;
+            }
+
+            params.push(AddFormDataParamParams {
+                name: self.template(&name, pointer),
+                value: json!(self.template(value, pointer)),
+                order: params.len() as isize,
+                description: None,
+                options: FormDataParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+                id: None,
+            });
+        }
+
+        params
+    }
+}
+
+/// The text of a `#` or `//` comment.
+fn comment(line: &str) -> Option<&str> {
+    let comment = line.strip_prefix('#').or_else(|| line.strip_prefix("//"))?;
+    Some(comment.strip_prefix(' ').unwrap_or(comment))
+}
+
+/// Response handlers, redirections of responses to files and references to previous responses.
+fn is_response_handler(line: &str) -> bool {
+    line.starts_with("> ") || line.starts_with(">>") || line.starts_with("<> ")
+}
+
+/// The path of a body read from a file, written as `< ./path`, or as `<@ ./path`
+/// to substitute the variables in it.
+fn file_reference(text: &str) -> Option<&str> {
+    if text.contains('\n') {
+        return None;
+    }
+
+    text.strip_prefix("<@")
+        .or_else(|| text.strip_prefix("< "))
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+}
+
+/// A parameter of a header value, such as the boundary of a content type.
+fn header_param(value: &str, name: &str) -> Option<String> {
+    value.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    fn items(imported: &ImportedResources) -> Vec<&CreateItemResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_requests_and_variables() {
+        let imported = convert(
+            "@base-url = https://example.com\n\
+             @token = abc\n\
+             \n\
+             ### List pets\n\
+             # Lists the pets of the store.\n\
+             # @no-log\n\
+             GET {{base-url}}/pets?limit=10\n    &name=Rex%20the+dog\n\
+             Accept: application/json\n\
+             X-Request-Id: {{$uuid}}\n\
+             \n\
+             > {% client.global.set(\"id\", response.body[0].id); %}\n\
+             \n\
+             ###\n\
+             # @name getPet\n\
+             /pets/:id HTTP/1.1\n\
+             Host: {{host}}\n",
+            "Petstore",
+            Path::new("/tmp"),
+        )
+        .unwrap();
+
+        let items = items(&imported);
+        assert_eq!(items[0].name, "List pets");
+        assert_eq!(
+            items[0].description.as_deref(),
+            Some("Lists the pets of the store.")
+        );
+        assert_eq!(items[0].protocol, Some(ResourceProtocol::Get));
+        assert_eq!(items[0].url.as_deref(), Some("{{base_url}}/pets"));
+        assert_eq!(items[0].query_params[1].name, "name");
+        assert_eq!(items[0].query_params[1].value, json!("Rex the dog"));
+        assert_eq!(items[0].headers[1].value, json!("{{uuid}}"));
+        assert!(items[0].body.is_none());
+
+        assert_eq!(items[1].name, "getPet");
+        assert_eq!(items[1].url.as_deref(), Some("http://{{host}}/pets/:id"));
+        assert!(items[1].headers.is_empty());
+        assert_eq!(items[1].path_params[0].name, "id");
+
+        let environment = &imported.environments[0];
+        assert_eq!(environment.name, "Petstore");
+        assert_eq!(environment.variables[0].name, "base_url");
+        assert_eq!(
+            environment.variables[0].global_value,
+            json!("https://example.com")
+        );
+
+        let reasons = imported
+            .skipped
+            .iter()
+            .map(|item| (item.pointer.as_str(), item.reason.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                ("/0", "the `@no-log` directive is not supported"),
+                (
+                    "/0",
+                    "dynamic variable `{{$uuid}}` is not supported, set `uuid` in an environment instead"
+                ),
+                ("/0", "handling of responses is not supported"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bodies() {
+        let imported = convert(
+            "POST https://example.com/pets\n\
+             Content-Type: application/json\n\
+             \n\
+             { \"name\": \"{{name}}\" }\n\
+             \n\
+             ###\n\
+             POST https://example.com/pets\n\
+             Content-Type: application/json\n\
+             \n\
+             { \"age\": {{age}} }\n\
+             \n\
+             ###\n\
+             PUT https://example.com/pets/photo\n\
+             Content-Type: image/png\n\
+             \n\
+             < ./rex.png\n\
+             \n\
+             ###\n\
+             POST https://example.com/login\n\
+             Content-Type: application/x-www-form-urlencoded\n\
+             \n\
+             user=rex\n\
+             &password={{password}}\n\
+             \n\
+             ###\n\
+             POST https://example.com/pets\n\
+             Content-Type: multipart/form-data; boundary=Boundary\n\
+             \n\
+             --Boundary\n\
+             Content-Disposition: form-data; name=\"name\"\n\
+             \n\
+             Rex\n\
+             --Boundary\n\
+             Content-Disposition: form-data; name=\"photo\"; filename=\"rex.png\"\n\
+             \n\
+             < ./rex.png\n\
+             --Boundary--\n",
+            "Petstore",
+            Path::new("/tmp/requests"),
+        )
+        .unwrap();
+        let items = items(&imported);
+
+        let Some(AddBodyParams::Json(body)) = &items[0].body else {
+            panic!("expected a json body");
+        };
+        assert_eq!(body, &json!({ "name": "{{name}}" }));
+        assert!(items[0].headers.is_empty());
+        assert!(
+            matches!(&items[1].body, Some(AddBodyParams::Text(text)) if text == "{ \"age\": {{age}} }")
+        );
+        assert_eq!(items[1].headers[0].name, "Content-Type");
+        assert!(
+            matches!(&items[2].body, Some(AddBodyParams::Binary(path)) if path == Path::new("/tmp/requests/rex.png"))
+        );
+        assert_eq!(items[2].headers[0].value, json!("image/png"));
+
+        let Some(AddBodyParams::Urlencoded(fields)) = &items[3].body else {
+            panic!("expected an urlencoded body");
+        };
+        assert_eq!(fields[1].name, "password");
+        assert_eq!(fields[1].value, json!("{{password}}"));
+        assert!(items[3].headers.is_empty());
+
+        let Some(AddBodyParams::FormData(fields)) = &items[4].body else {
+            panic!("expected a form data body");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].value, json!("Rex"));
+        assert_eq!(
+            imported.skipped,
+            vec![ImportSkippedItem {
+                pointer: "/4".to_string(),
+                reason: "files of form data bodies are not supported".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_rejects_files_without_requests() {
+        assert!(convert("@host = example.com\n### Empty\n", "Empty", Path::new("")).is_err());
+
+        let imported = convert("WEBSOCKET ws://example.com\n", "Socket", Path::new("")).unwrap();
+        assert!(imported.resources.is_empty());
+        assert_eq!(
+            imported.skipped[0].reason,
+            "`WEBSOCKET` requests are not supported"
+        );
+    }
+}
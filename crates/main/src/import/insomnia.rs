@@ -0,0 +1,1025 @@
+use joinerror::Error;
+use moss_environment::models::types::AddVariableParams;
+use moss_project::models::{
+    operations::BatchCreateResourceKind,
+    types::{
+        ApiKeyLocation, AuthScheme, AwsSigV4Config, CreateDirResourceParams,
+        CreateItemResourceParams, OAuth2Config, OAuth2Grant,
+        http::{
+            AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
+            AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
+            HeaderParamOptions, PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::project::{ImportInsomniaParams, ImportSkippedItem};
+use serde_json::{Map, Value as JsonValue, json};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::import::{
+    ImportedEnvironment, ImportedResources, UniqueNames, add_secrets, convert_path_variables,
+    environment_variable, form_pairs, is_variable_reference, protocol, read_document, template,
+};
+
+const EXPORT_FORMAT: u64 = 4;
+
+/// Resources of the export that hold no request, and have no counterpart to report.
+const IGNORED_TYPES: [&str; 4] = ["cookie_jar", "api_spec", "proto_file", "proto_directory"];
+
+/// Reads and converts the Insomnia export of the import.
+pub async fn read_export(params: &ImportInsomniaParams) -> joinerror::Result<ImportedResources> {
+    let export = read_document(&params.file_path, "insomnia export").await?;
+
+    convert(&export)
+}
+
+/// Converts an Insomnia v4 export into resources.
+///
+/// Request groups become directories and requests become endpoints. The workspace itself
+/// only becomes a directory when the export holds several of them. Every sub environment
+/// becomes an environment holding the variables of the base environment it extends, or
+/// the base environment itself does when it has none. The secrets found inline in auths
+/// are added to each of them as local values.
+pub fn convert(export: &JsonValue) -> joinerror::Result<ImportedResources> {
+    let format = export.get("__export_format").and_then(JsonValue::as_u64);
+    let resources = export
+        .get("resources")
+        .and_then(JsonValue::as_array)
+        .filter(|_| export.get("_type").and_then(JsonValue::as_str) == Some("export"))
+        .ok_or_else(|| Error::new::<()>("the document is not an insomnia export"))?;
+    if format != Some(EXPORT_FORMAT) {
+        return Err(Error::new::<()>(format!(
+            "insomnia export format `{}` is not supported, only v4 is",
+            format.map(|format| format.to_string()).unwrap_or_default()
+        )));
+    }
+
+    let mut converter = Converter {
+        resources: resources
+            .iter()
+            .enumerate()
+            .map(|(index, resource)| Resource {
+                pointer: format!("/resources/{}", index),
+                value: resource,
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let workspaces = converter.children_of_type(None, "workspace");
+    if workspaces.is_empty() {
+        return Err(Error::new::<()>("the export has no workspace"));
+    }
+    for resource in &converter.resources {
+        let kind = resource.kind();
+        let is_known = ["workspace", "request_group", "request", "environment"].contains(&kind)
+            || IGNORED_TYPES.contains(&kind);
+        if !is_known {
+            converter.skipped.push(ImportSkippedItem {
+                pointer: resource.pointer.clone(),
+                reason: format!("`{}` resources are not supported", kind),
+            });
+        }
+    }
+
+    let root = PathBuf::new();
+    let mut environments = Vec::new();
+    for workspace in &workspaces {
+        let workspace = converter.resources[*workspace].clone();
+        let dir = if workspaces.len() > 1 {
+            converter.push_dir(&root, &workspace, vec![], None)
+        } else {
+            root.clone()
+        };
+        converter.convert_children(workspace.id(), &dir);
+        environments.extend(converter.environments(&workspace));
+    }
+
+    let name = converter.resources[workspaces[0]].name("Insomnia");
+    add_secrets(&mut environments, converter.secrets, &name);
+
+    Ok(ImportedResources {
+        resources: converter.converted,
+        skipped: converter.skipped,
+        environments,
+    })
+}
+
+#[derive(Clone)]
+struct Resource<'a> {
+    pointer: String,
+    value: &'a JsonValue,
+}
+
+impl Resource<'_> {
+    fn field(&self, key: &str) -> Option<&JsonValue> {
+        self.value.get(key)
+    }
+
+    fn str(&self, key: &str) -> &str {
+        self.field(key)
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+    }
+
+    fn id(&self) -> &str {
+        self.str("_id")
+    }
+
+    fn kind(&self) -> &str {
+        self.str("_type")
+    }
+
+    fn name(&self, default: &str) -> String {
+        match self.str("name").trim() {
+            "" => default.to_string(),
+            name => name.to_string(),
+        }
+    }
+
+    fn description(&self) -> Option<String> {
+        Some(self.str("description").trim())
+            .filter(|description| !description.is_empty())
+            .map(str::to_string)
+    }
+
+    fn sort_key(&self) -> f64 {
+        self.field("metaSortKey")
+            .and_then(JsonValue::as_f64)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+struct Converter<'a> {
+    resources: Vec<Resource<'a>>,
+    names: UniqueNames,
+    orders: HashMap<PathBuf, isize>,
+    converted: Vec<BatchCreateResourceKind>,
+    skipped: Vec<ImportSkippedItem>,
+    secrets: Vec<AddVariableParams>,
+    secret_names: HashSet<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn skip(&mut self, pointer: &str, reason: impl Into<String>) {
+        let item = ImportSkippedItem {
+            pointer: pointer.to_string(),
+            reason: reason.into(),
+        };
+        // The same template tag is often used several times in a single request
+        if !self.skipped.contains(&item) {
+            self.skipped.push(item);
+        }
+    }
+
+    fn next_order(&mut self, dir: &Path) -> isize {
+        let order = self.orders.entry(dir.to_path_buf()).or_default();
+        *order += 1;
+        *order - 1
+    }
+
+    /// Indices of the resources of the type whose parent is `parent_id`, in the order
+    /// Insomnia shows them. `None` stands for the resources without a parent.
+    fn children_of_type(&self, parent_id: Option<&str>, kind: &str) -> Vec<usize> {
+        let mut children = self
+            .resources
+            .iter()
+            .enumerate()
+            .filter(|(_, resource)| {
+                resource.kind() == kind
+                    && match parent_id {
+                        Some(parent_id) => resource.str("parentId") == parent_id,
+                        None => resource.field("parentId").is_none_or(JsonValue::is_null),
+                    }
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        children.sort_by(|a, b| {
+            self.resources[*a]
+                .sort_key()
+                .total_cmp(&self.resources[*b].sort_key())
+        });
+
+        children
+    }
+
+    fn convert_children(&mut self, parent_id: &str, dir: &Path) {
+        let mut children = self.children_of_type(Some(parent_id), "request_group");
+        children.extend(self.children_of_type(Some(parent_id), "request"));
+        children.sort_by(|a, b| {
+            self.resources[*a]
+                .sort_key()
+                .total_cmp(&self.resources[*b].sort_key())
+        });
+
+        for index in children {
+            let resource = self.resources[index].clone();
+            if resource.kind() == "request_group" {
+                self.convert_group(&resource, dir);
+            } else {
+                self.convert_request(&resource, dir);
+            }
+        }
+    }
+
+    fn convert_group(&mut self, group: &Resource<'a>, dir: &Path) {
+        if group
+            .field("environment")
+            .and_then(JsonValue::as_object)
+            .is_some_and(|environment| !environment.is_empty())
+        {
+            self.skip(&group.pointer, "folder environments are not supported");
+        }
+
+        let headers = self.headers(group, true);
+        let auth = self.auth(group);
+        let dir = self.push_dir(dir, group, headers, auth);
+        self.convert_children(group.id(), &dir);
+    }
+
+    fn push_dir(
+        &mut self,
+        dir: &Path,
+        resource: &Resource,
+        headers: Vec<AddHeaderParams>,
+        auth: Option<AuthScheme>,
+    ) -> PathBuf {
+        let name = self.names.claim(dir, &resource.name("Untitled"));
+        let order = self.next_order(dir);
+        self.converted
+            .push(BatchCreateResourceKind::Dir(CreateDirResourceParams {
+                path: dir.to_path_buf(),
+                class: ResourceClass::Endpoint,
+                name: name.clone(),
+                description: resource.description(),
+                order,
+                headers,
+                path_params: vec![],
+                query_params: vec![],
+                auth,
+            }));
+
+        dir.join(name)
+    }
+
+    fn convert_request(&mut self, request: &Resource<'a>, dir: &Path) {
+        let method = request.str("method").trim().to_uppercase();
+        let Some(protocol) = protocol(&method) else {
+            self.skip(
+                &request.pointer,
+                format!("invalid http method `{}`", method),
+            );
+            return;
+        };
+        let mime_type = request
+            .field("body")
+            .and_then(|body| body.get("mimeType"))
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default();
+        if mime_type == "application/graphql" {
+            self.skip(&request.pointer, "graphql requests are not supported");
+            return;
+        }
+
+        let url = self.template(request.str("url").trim(), &request.pointer);
+        let (url, query) = match url.split_once('?') {
+            Some((url, query)) => (url.to_string(), form_pairs(query).collect::<Vec<_>>()),
+            None => (url, vec![]),
+        };
+        let (url, path_variables) = convert_path_variables(&url);
+
+        let path_values = self.pairs(request.field("pathParameters"), &request.pointer);
+        let path_params = path_variables
+            .into_iter()
+            .enumerate()
+            .map(|(order, name)| {
+                let value = path_values
+                    .iter()
+                    .find(|(key, _, _)| *key == name)
+                    .map(|(_, value, _)| value.clone())
+                    .unwrap_or_default();
+                AddPathParamParams {
+                    name,
+                    value: json!(value),
+                    order: order as isize,
+                    description: None,
+                    options: PathParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                }
+            })
+            .collect();
+
+        let mut parameters = query
+            .into_iter()
+            .map(|(name, value)| (name, value, false))
+            .collect::<Vec<_>>();
+        parameters.extend(self.pairs(request.field("parameters"), &request.pointer));
+        let query_params = parameters
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value, disabled))| AddQueryParamParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: QueryParamOptions {
+                    disabled,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        let mut headers = self.headers(request, false);
+        let body = self.body(request, &mut headers);
+        let auth = self.auth(request);
+
+        let name = self.names.claim(dir, &request.name("Untitled"));
+        let order = self.next_order(dir);
+        self.converted
+            .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                path: dir.to_path_buf(),
+                class: ResourceClass::Endpoint,
+                name,
+                description: request.description(),
+                order,
+                protocol: Some(protocol),
+                url: Some(url),
+                headers,
+                path_params,
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }));
+    }
+
+    /// Name, value and whether it's disabled, of the entries of a list such as the headers of a request.
+    fn pairs(&mut self, values: Option<&JsonValue>, pointer: &str) -> Vec<(String, String, bool)> {
+        values
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|value| {
+                let name = value.get("name")?.as_str()?.trim();
+                if name.is_empty() {
+                    return None;
+                }
+                let text = value
+                    .get("value")
+                    .map(|value| match value {
+                        JsonValue::String(value) => value.clone(),
+                        JsonValue::Null => String::new(),
+                        value => value.to_string(),
+                    })
+                    .unwrap_or_default();
+                Some((
+                    self.template(name, pointer),
+                    self.template(&text, pointer),
+                    value
+                        .get("disabled")
+                        .and_then(JsonValue::as_bool)
+                        .unwrap_or(false),
+                ))
+            })
+            .collect()
+    }
+
+    fn headers(&mut self, resource: &Resource, propagate: bool) -> Vec<AddHeaderParams> {
+        self.pairs(resource.field("headers"), &resource.pointer)
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value, disabled))| AddHeaderParams {
+                name,
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled,
+                    propagate,
+                },
+            })
+            .collect()
+    }
+
+    /// The body of a request, whose kind is told by its mime type. The `Content-Type` header
+    /// Insomnia adds along with the body is removed when it's the one sent for it anyway.
+    fn body(
+        &mut self,
+        request: &Resource,
+        headers: &mut Vec<AddHeaderParams>,
+    ) -> Option<AddBodyParams> {
+        let body = request.field("body")?;
+        let mime_type = body
+            .get("mimeType")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+            .to_lowercase();
+        let pointer = &request.pointer;
+
+        let body = if let Some(path) = body.get("fileName").and_then(JsonValue::as_str) {
+            AddBodyParams::Binary(PathBuf::from(path))
+        } else if mime_type == "multipart/form-data" {
+            let mut params = Vec::new();
+            for param in body.get("params").and_then(JsonValue::as_array)? {
+                if param.get("type").and_then(JsonValue::as_str) == Some("file") {
+                    self.skip(pointer, "files of form data bodies are not supported");
+                    continue;
+                }
+                let Some((name, value, disabled)) = self
+                    .pairs(Some(&json!([param])), pointer)
+                    .into_iter()
+                    .next()
+                else {
+                    continue;
+                };
+                params.push(AddFormDataParamParams {
+                    name,
+                    value: json!(value),
+                    order: params.len() as isize,
+                    description: None,
+                    options: FormDataParamOptions {
+                        disabled,
+                        propagate: false,
+                    },
+                    id: None,
+                });
+            }
+            AddBodyParams::FormData(params)
+        } else if mime_type == "application/x-www-form-urlencoded" {
+            AddBodyParams::Urlencoded(
+                self.pairs(body.get("params"), pointer)
+                    .into_iter()
+                    .enumerate()
+                    .map(
+                        |(order, (name, value, disabled))| AddUrlencodedParamParams {
+                            name,
+                            value: json!(value),
+                            order: order as isize,
+                            description: None,
+                            options: UrlencodedParamOptions {
+                                disabled,
+                                propagate: false,
+                            },
+                            id: None,
+                        },
+                    )
+                    .collect(),
+            )
+        } else {
+            let text = body.get("text").and_then(JsonValue::as_str)?;
+            if text.is_empty() {
+                return None;
+            }
+            let text = self.template(text, pointer);
+            if mime_type.contains("json") {
+                // Variables standing for whole values make the body invalid json
+                serde_json::from_str(&text)
+                    .map(AddBodyParams::Json)
+                    .unwrap_or(AddBodyParams::Text(text))
+            } else if mime_type.contains("xml") {
+                AddBodyParams::Xml(text)
+            } else {
+                AddBodyParams::Text(text)
+            }
+        };
+
+        let implied_content_type = match &body {
+            AddBodyParams::Text(_) => Some("text/plain"),
+            AddBodyParams::Json(_) => Some("application/json"),
+            AddBodyParams::Xml(_) => Some("application/xml"),
+            AddBodyParams::Binary(_) => Some("application/octet-stream"),
+            AddBodyParams::Urlencoded(_) | AddBodyParams::FormData(_) => None,
+        };
+        headers.retain(|header| {
+            !header.name.eq_ignore_ascii_case("content-type")
+                || implied_content_type.is_some_and(|implied| {
+                    header
+                        .value
+                        .as_str()
+                        .is_some_and(|value| !value.eq_ignore_ascii_case(implied))
+                })
+        });
+        for (order, header) in headers.iter_mut().enumerate() {
+            header.order = order as isize;
+        }
+
+        Some(body)
+    }
+
+    fn auth(&mut self, resource: &Resource) -> Option<AuthScheme> {
+        let auth = resource
+            .field("authentication")?
+            .as_object()
+            .filter(|auth| !auth.is_empty())?;
+        let pointer = format!("{}/authentication", resource.pointer);
+        let owner = resource.name("Untitled");
+        let attribute = |converter: &mut Self, key: &str| {
+            let value = auth
+                .get(key)
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default();
+            converter.template(value, &pointer)
+        };
+
+        if auth.get("disabled").and_then(JsonValue::as_bool) == Some(true) {
+            return Some(AuthScheme::None);
+        }
+
+        let kind = auth
+            .get("type")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default();
+        let scheme = match kind {
+            "none" => AuthScheme::None,
+            "inherit" => return None,
+            "basic" | "digest" => {
+                let username = attribute(self, "username");
+                let password = attribute(self, "password");
+                let password = self.secret(password, &owner, "password");
+                if kind == "basic" {
+                    AuthScheme::Basic { username, password }
+                } else {
+                    AuthScheme::Digest { username, password }
+                }
+            }
+            "bearer" => {
+                let prefix = attribute(self, "prefix");
+                if !prefix.is_empty() && prefix != "Bearer" {
+                    self.skip(
+                        &pointer,
+                        format!("the `{}` prefix of bearer tokens is not supported", prefix),
+                    );
+                }
+                let token = attribute(self, "token");
+                AuthScheme::Bearer {
+                    token: self.secret(token, &owner, "token"),
+                }
+            }
+            "apikey" => {
+                let location = match attribute(self, "addTo").as_str() {
+                    "" | "header" => ApiKeyLocation::Header,
+                    "queryParams" => ApiKeyLocation::Query,
+                    location => {
+                        self.skip(
+                            &pointer,
+                            format!("api keys added to `{}` are not supported", location),
+                        );
+                        return None;
+                    }
+                };
+                let key = attribute(self, "key");
+                let value = attribute(self, "value");
+                AuthScheme::ApiKey {
+                    key,
+                    value: self.secret(value, &owner, "api key"),
+                    location,
+                }
+            }
+            "oauth2" => {
+                let grant = match attribute(self, "grantType").as_str() {
+                    "" | "authorization_code" => OAuth2Grant::AuthorizationCode,
+                    "client_credentials" => OAuth2Grant::ClientCredentials,
+                    grant => {
+                        self.skip(
+                            &pointer,
+                            format!("the `{}` oauth2 grant is not supported", grant),
+                        );
+                        return None;
+                    }
+                };
+                let client_secret = Some(attribute(self, "clientSecret"))
+                    .filter(|secret| !secret.is_empty())
+                    .map(|secret| self.secret(secret, &owner, "client secret"));
+
+                let config = OAuth2Config {
+                    grant,
+                    auth_url: Some(attribute(self, "authorizationUrl"))
+                        .filter(|url| !url.is_empty()),
+                    token_url: attribute(self, "accessTokenUrl"),
+                    device_auth_url: None,
+                    client_id: attribute(self, "clientId"),
+                    client_secret,
+                    scopes: attribute(self, "scope")
+                        .split_whitespace()
+                        .map(str::to_string)
+                        .collect(),
+                    refresh_token: None,
+                    callback_port: None,
+                };
+                if let Err(reason) = config.check_grant() {
+                    self.skip(&pointer, reason);
+                    return None;
+                }
+
+                AuthScheme::OAuth2(config)
+            }
+            "iam" => {
+                let access_key = attribute(self, "accessKeyId");
+                let secret_key = attribute(self, "secretAccessKey");
+                let session_token =
+                    Some(attribute(self, "sessionToken")).filter(|token| !token.is_empty());
+
+                AuthScheme::AwsSigV4(AwsSigV4Config {
+                    access_key: self.secret(access_key, &owner, "access key"),
+                    secret_key: self.secret(secret_key, &owner, "secret key"),
+                    session_token: session_token
+                        .map(|token| self.secret(token, &owner, "session token")),
+                    region: attribute(self, "region"),
+                    service: attribute(self, "service"),
+                })
+            }
+            kind => {
+                self.skip(&pointer, format!("`{}` auth is not supported", kind));
+                return None;
+            }
+        };
+
+        Some(scheme)
+    }
+
+    /// Secrets of auths have to reference variables, so the ones written inline
+    /// are moved to the environments as local values.
+    fn secret(&mut self, value: String, owner: &str, field: &str) -> String {
+        if is_variable_reference(&value) {
+            return value;
+        }
+
+        let variable = environment_variable(
+            &mut self.secret_names,
+            &format!("{} {}", owner, field),
+            Some(&json!(value)),
+            true,
+            false,
+            Some(format!("The {} of `{}`.", field, owner)),
+        );
+        let reference = format!("{{{{{}}}}}", variable.name);
+        self.secrets.push(variable);
+
+        reference
+    }
+
+    /// Rewrites the Nunjucks variables of Insomnia, such as `{{ _.base_url }}`, into ones
+    /// the template parser accepts. Template tags, such as `{% uuid %}`, are left as written.
+    fn template(&mut self, text: &str, pointer: &str) -> String {
+        for tag in text.split("{%").skip(1) {
+            if let Some((tag, _)) = tag.split_once("%}") {
+                let name = tag.split_whitespace().next().unwrap_or_default();
+                self.skip(
+                    pointer,
+                    format!("the `{}` template tag is not supported", name),
+                );
+            }
+        }
+
+        template(&strip_context(text))
+    }
+
+    /// The environments of the workspace, whose sub environments extend the base one.
+    fn environments(&mut self, workspace: &Resource<'a>) -> Vec<ImportedEnvironment> {
+        let Some(base) = self
+            .children_of_type(Some(workspace.id()), "environment")
+            .first()
+            .map(|index| self.resources[*index].clone())
+        else {
+            return vec![];
+        };
+
+        let sub_environments = self
+            .children_of_type(Some(base.id()), "environment")
+            .into_iter()
+            .map(|index| self.resources[index].clone())
+            .collect::<Vec<_>>();
+        if sub_environments.is_empty() {
+            return vec![ImportedEnvironment {
+                name: base.name("Base Environment"),
+                variables: self.variables(&[&base]),
+            }];
+        }
+
+        sub_environments
+            .iter()
+            .map(|environment| ImportedEnvironment {
+                name: environment.name("Environment"),
+                variables: self.variables(&[&base, environment]),
+            })
+            .collect()
+    }
+
+    /// Variables of the environments, the later ones overriding the earlier ones.
+    /// The values of private environments are kept locally.
+    fn variables(&mut self, environments: &[&Resource]) -> Vec<AddVariableParams> {
+        let mut values = Vec::<(String, JsonValue, bool)>::new();
+        for environment in environments {
+            let is_private = environment
+                .field("isPrivate")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false);
+            let mut data = Vec::new();
+            if let Some(object) = environment.field("data").and_then(JsonValue::as_object) {
+                flatten("", object, &mut data);
+            }
+
+            for (name, value) in data {
+                let value = match value {
+                    JsonValue::String(value) => json!(strip_context(&value)),
+                    value => value,
+                };
+                match values.iter_mut().find(|(key, _, _)| *key == name) {
+                    Some(entry) => *entry = (name, value, is_private),
+                    None => values.push((name, value, is_private)),
+                }
+            }
+        }
+
+        let mut names = HashSet::new();
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(order, (name, value, is_private))| AddVariableParams {
+                order: order as isize,
+                ..environment_variable(&mut names, &name, Some(&value), is_private, false, None)
+            })
+            .collect()
+    }
+}
+
+/// Variables of an environment can be nested objects, which templates reference by their path.
+fn flatten(prefix: &str, object: &Map<String, JsonValue>, values: &mut Vec<(String, JsonValue)>) {
+    for (key, value) in object {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            JsonValue::Object(object) => flatten(&name, object, values),
+            value => values.push((name, value.clone())),
+        }
+    }
+}
+
+/// Removes the `_.` Insomnia prefixes the names of environment variables with in templates.
+fn strip_context(text: &str) -> String {
+    text.replace("{{ _.", "{{ ").replace("{{_.", "{{")
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    fn items(imported: &ImportedResources) -> Vec<&CreateItemResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect()
+    }
+
+    fn dirs(imported: &ImportedResources) -> Vec<&CreateDirResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Dir(dir) => Some(dir),
+                BatchCreateResourceKind::Item(_) => None,
+            })
+            .collect()
+    }
+
+    fn export(resources: JsonValue) -> JsonValue {
+        json!({
+            "_type": "export",
+            "__export_format": 4,
+            "__export_source": "insomnia.desktop.app:v2023.5.8",
+            "resources": resources
+        })
+    }
+
+    #[test]
+    fn test_rejects_other_documents() {
+        assert!(convert(&json!({ "openapi": "3.1.0" })).is_err());
+        assert!(
+            convert(&json!({ "_type": "export", "__export_format": 3, "resources": [] })).is_err()
+        );
+        assert!(convert(&export(json!([]))).is_err());
+    }
+
+    #[test]
+    fn test_requests_and_groups() {
+        let imported = convert(&export(json!([
+            { "_id": "wrk_1", "_type": "workspace", "parentId": null, "name": "Petstore" },
+            {
+                "_id": "fld_1",
+                "_type": "request_group",
+                "parentId": "wrk_1",
+                "name": "Pets",
+                "metaSortKey": -2,
+                "headers": [{ "name": "X-Team", "value": "pets" }],
+                "environment": { "limit": 10 }
+            },
+            {
+                "_id": "req_2",
+                "_type": "request",
+                "parentId": "fld_1",
+                "name": "Create pet",
+                "method": "POST",
+                "url": "{{ _.base_url }}/pets",
+                "metaSortKey": 2,
+                "body": { "mimeType": "application/json", "text": "{\"name\": \"Rex\"}" },
+                "headers": [{ "name": "Content-Type", "value": "application/json" }],
+                "authentication": { "type": "bearer", "token": "abc" }
+            },
+            {
+                "_id": "req_1",
+                "_type": "request",
+                "parentId": "fld_1",
+                "name": "Get pet",
+                "description": "Gets a pet.",
+                "method": "GET",
+                "url": "{{ _.base_url }}/pets/:id?fields=name",
+                "metaSortKey": 1,
+                "pathParameters": [{ "name": "id", "value": "42" }],
+                "parameters": [
+                    { "name": "trace", "value": "{% uuid 'v4' %}", "disabled": true }
+                ],
+                "authentication": { "type": "basic", "username": "admin", "password": "{{ _.password }}" }
+            },
+            {
+                "_id": "req_3",
+                "_type": "request",
+                "parentId": "wrk_1",
+                "name": "Query",
+                "method": "POST",
+                "url": "{{ _.base_url }}/graphql",
+                "metaSortKey": -1,
+                "body": { "mimeType": "application/graphql", "text": "{}" }
+            },
+            { "_id": "ws-req_1", "_type": "websocket_request", "parentId": "wrk_1", "name": "Events" },
+            { "_id": "jar_1", "_type": "cookie_jar", "parentId": "wrk_1" }
+        ])))
+        .unwrap();
+
+        let dirs = dirs(&imported);
+        assert_eq!(dirs.len(), 1);
+        assert_eq!(dirs[0].name, "Pets");
+        assert!(dirs[0].headers[0].options.propagate);
+
+        let items = items(&imported);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "Get pet");
+        assert_eq!(items[0].path, Path::new("Pets"));
+        assert_eq!(items[0].description.as_deref(), Some("Gets a pet."));
+        assert_eq!(items[0].url.as_deref(), Some("{{base_url}}/pets/:id"));
+        assert_eq!(items[0].path_params[0].value, json!("42"));
+        assert_eq!(items[0].query_params[0].name, "fields");
+        assert!(items[0].query_params[1].options.disabled);
+        assert_eq!(
+            items[0].auth,
+            Some(AuthScheme::Basic {
+                username: "admin".to_string(),
+                password: "{{password}}".to_string(),
+            })
+        );
+
+        assert_eq!(items[1].name, "Create pet");
+        assert_eq!(items[1].protocol, Some(ResourceProtocol::Post));
+        assert!(items[1].headers.is_empty());
+        assert!(matches!(
+            &items[1].body,
+            Some(AddBodyParams::Json(json)) if json == &json!({ "name": "Rex" })
+        ));
+        assert_eq!(
+            items[1].auth,
+            Some(AuthScheme::Bearer {
+                token: "{{Create_pet_token}}".to_string()
+            })
+        );
+
+        let pointers = imported
+            .skipped
+            .iter()
+            .map(|item| (item.pointer.as_str(), item.reason.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pointers,
+            vec![
+                (
+                    "/resources/5",
+                    "`websocket_request` resources are not supported"
+                ),
+                ("/resources/1", "folder environments are not supported"),
+                ("/resources/3", "the `uuid` template tag is not supported"),
+                ("/resources/4", "graphql requests are not supported"),
+            ]
+        );
+
+        // The inline token needs an environment to be kept in
+        assert_eq!(imported.environments.len(), 1);
+        assert_eq!(imported.environments[0].name, "Petstore");
+        assert_eq!(
+            imported.environments[0].variables[0].local_value,
+            json!("abc")
+        );
+    }
+
+    #[test]
+    fn test_environments() {
+        let imported = convert(&export(json!([
+            { "_id": "wrk_1", "_type": "workspace", "parentId": null, "name": "Petstore" },
+            {
+                "_id": "env_1",
+                "_type": "environment",
+                "parentId": "wrk_1",
+                "name": "Base Environment",
+                "data": { "base_url": "https://petstore.example.com", "api": { "version": "v1" } }
+            },
+            {
+                "_id": "env_2",
+                "_type": "environment",
+                "parentId": "env_1",
+                "name": "Local",
+                "metaSortKey": 1,
+                "data": { "base_url": "http://localhost:{{ _.port }}" }
+            },
+            {
+                "_id": "env_3",
+                "_type": "environment",
+                "parentId": "env_1",
+                "name": "Secrets",
+                "metaSortKey": 2,
+                "isPrivate": true,
+                "data": { "token": "abc" }
+            }
+        ])))
+        .unwrap();
+
+        let environments = imported
+            .environments
+            .iter()
+            .map(|environment| {
+                (
+                    environment.name.as_str(),
+                    environment
+                        .variables
+                        .iter()
+                        .map(|variable| {
+                            (
+                                variable.name.as_str(),
+                                variable.global_value.clone(),
+                                variable.local_value.clone(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            environments,
+            vec![
+                (
+                    "Local",
+                    vec![
+                        ("api_version", json!("v1"), JsonValue::Null),
+                        (
+                            "base_url",
+                            json!("http://localhost:{{port}}"),
+                            JsonValue::Null
+                        ),
+                    ]
+                ),
+                (
+                    "Secrets",
+                    vec![
+                        ("api_version", json!("v1"), JsonValue::Null),
+                        (
+                            "base_url",
+                            json!("https://petstore.example.com"),
+                            JsonValue::Null
+                        ),
+                        ("token", json!(""), json!("abc")),
+                    ]
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1194 @@
+use joinerror::Error;
+use moss_project::models::{
+    operations::BatchCreateResourceKind,
+    types::{
+        CreateDirResourceParams, CreateItemResourceParams,
+        http::{
+            AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
+            AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
+            HeaderParamOptions, PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_ipc::contracts::main::project::ImportSkippedItem;
+use serde_json::{Map, Value as JsonValue, json};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::import::{ImportedResources, UniqueNames, json_pointer};
+
+const SCHEMAS_DIR: &str = "schemas";
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+/// Used when the document doesn't declare a server, so that it can be set in an environment.
+const BASE_URL_VARIABLE: &str = "{{baseUrl}}";
+
+// Protects against reference cycles, such as a schema containing itself
+const MAX_DEPTH: usize = 16;
+
+const METHODS: [(&str, ResourceProtocol); 8] = [
+    ("get", ResourceProtocol::Get),
+    ("put", ResourceProtocol::Put),
+    ("post", ResourceProtocol::Post),
+    ("delete", ResourceProtocol::Delete),
+    ("options", ResourceProtocol::Options),
+    ("head", ResourceProtocol::Head),
+    ("patch", ResourceProtocol::Patch),
+    ("trace", ResourceProtocol::Trace),
+];
+
+/// Header parameters the specification says to ignore, since they are described
+/// by the media types and the security schemes instead.
+const IGNORED_HEADERS: [&str; 3] = ["accept", "content-type", "authorization"];
+
+/// Converts an OpenAPI 3.0 or 3.1 document into resources.
+///
+/// Operations are grouped in directories named after their first tag, or the first segment
+/// of their path if they have none. The schemas of `components/schemas` become schema
+/// resources, each carrying the schemas it references in its `$defs`.
+pub fn convert(document: &JsonValue) -> joinerror::Result<ImportedResources> {
+    let version = document
+        .get("openapi")
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| Error::new::<()>("the document is not an openapi document"))?;
+    if !version.starts_with("3.0") && !version.starts_with("3.1") {
+        return Err(Error::new::<()>(format!(
+            "openapi version `{}` is not supported, only 3.0 and 3.1 are",
+            version
+        )));
+    }
+
+    let mut converter = Converter {
+        document,
+        legacy_schemas: version.starts_with("3.0"),
+        base_url: base_url(document),
+        names: UniqueNames::default(),
+        dirs: HashMap::new(),
+        orders: HashMap::new(),
+        resources: Vec::new(),
+        skipped: Vec::new(),
+    };
+    converter.convert_schemas();
+    converter.convert_paths();
+    converter.report_unsupported();
+
+    Ok(ImportedResources {
+        resources: converter.resources,
+        skipped: converter.skipped,
+        environments: Vec::new(),
+    })
+}
+
+struct Converter<'a> {
+    document: &'a JsonValue,
+    /// OpenAPI 3.0 schemas use a few keywords of their own instead of the JSON Schema ones.
+    legacy_schemas: bool,
+    base_url: String,
+    names: UniqueNames,
+    /// Directories created so far by their key, the tag or path segment they were created for.
+    dirs: HashMap<String, PathBuf>,
+    orders: HashMap<PathBuf, isize>,
+    resources: Vec<BatchCreateResourceKind>,
+    skipped: Vec<ImportSkippedItem>,
+}
+
+impl<'a> Converter<'a> {
+    fn skip(&mut self, pointer: String, reason: impl Into<String>) {
+        self.skipped.push(ImportSkippedItem {
+            pointer,
+            reason: reason.into(),
+        });
+    }
+
+    fn next_order(&mut self, dir: &Path) -> isize {
+        let order = self.orders.entry(dir.to_path_buf()).or_default();
+        *order += 1;
+        *order - 1
+    }
+
+    fn dir(&mut self, key: &str, class: ResourceClass) -> PathBuf {
+        if let Some(path) = self.dirs.get(key) {
+            return path.clone();
+        }
+
+        let root = PathBuf::new();
+        let name = self.names.claim(&root, key);
+        let order = self.next_order(&root);
+        self.resources
+            .push(BatchCreateResourceKind::Dir(CreateDirResourceParams {
+                path: root,
+                class,
+                name: name.clone(),
+                description: None,
+                order,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
+            }));
+
+        let path = PathBuf::from(name);
+        self.dirs.insert(key.to_string(), path.clone());
+        path
+    }
+
+    /// Follows `$ref` until the referenced object, reporting the references that can't be followed.
+    fn resolve(&mut self, value: &'a JsonValue, pointer: &str) -> Option<&'a JsonValue> {
+        let mut value = value;
+        for _ in 0..MAX_DEPTH {
+            let Some(reference) = value.get("$ref").and_then(JsonValue::as_str) else {
+                return Some(value);
+            };
+
+            let Some(fragment) = reference.strip_prefix('#') else {
+                self.skip(
+                    pointer.to_string(),
+                    format!("external reference `{}` is not supported", reference),
+                );
+                return None;
+            };
+            let Some(target) = self.document.pointer(fragment) else {
+                self.skip(
+                    pointer.to_string(),
+                    format!("cannot resolve reference `{}`", reference),
+                );
+                return None;
+            };
+            value = target;
+        }
+
+        self.skip(pointer.to_string(), "references are nested too deeply");
+        None
+    }
+
+    fn convert_schemas(&mut self) {
+        let Some(schemas) = self
+            .document
+            .pointer("/components/schemas")
+            .and_then(JsonValue::as_object)
+            .filter(|schemas| !schemas.is_empty())
+        else {
+            return;
+        };
+
+        let dir = self.dir(SCHEMAS_DIR, ResourceClass::Schema);
+        for (name, schema) in schemas {
+            let pointer = json_pointer(["components", "schemas", name]);
+            let schema = self.schema_document(schema, &pointer);
+            let name = self.names.claim(&dir, name);
+            let order = self.next_order(&dir);
+
+            self.resources
+                .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                    path: dir.clone(),
+                    class: ResourceClass::Schema,
+                    name,
+                    description: None,
+                    order,
+                    protocol: None,
+                    url: None,
+                    headers: vec![],
+                    path_params: vec![],
+                    query_params: vec![],
+                    body: None,
+                    body_schema: None,
+                    components: vec![],
+                    auth: None,
+                    schema: Some(schema),
+                    websocket: None,
+                    graphql: None,
+                    grpc: None,
+                    sse: None,
+                    amqp: None,
+                    mqtt: None,
+                }));
+        }
+    }
+
+    /// Schema resources only resolve references local to their document,
+    /// so the schemas referenced from `components/schemas` are copied into `$defs`.
+    fn schema_document(&mut self, schema: &JsonValue, pointer: &str) -> JsonValue {
+        let mut pending = Vec::new();
+        let mut document = self.convert_schema(schema, pointer, &mut pending);
+
+        let mut defs = Map::new();
+        let mut seen = HashSet::new();
+        while let Some(name) = pending.pop() {
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+
+            let pointer = json_pointer(["components", "schemas", &name]);
+            if let Some(schema) = self.document.pointer(&pointer) {
+                let schema = self.convert_schema(schema, &pointer, &mut pending);
+                defs.insert(name, schema);
+            }
+        }
+
+        if let Some(document) = document.as_object_mut()
+            && !defs.is_empty()
+        {
+            let existing = document
+                .entry("$defs")
+                .or_insert_with(|| JsonValue::Object(Map::new()));
+            if let Some(existing) = existing.as_object_mut() {
+                for (name, schema) in defs {
+                    existing.entry(name).or_insert(schema);
+                }
+            }
+        }
+
+        document
+    }
+
+    /// Rewrites the references to `components/schemas` to point into `$defs`, collecting the
+    /// names of the referenced schemas in `pending`, and translates OpenAPI 3.0 keywords.
+    fn convert_schema(
+        &mut self,
+        schema: &JsonValue,
+        pointer: &str,
+        pending: &mut Vec<String>,
+    ) -> JsonValue {
+        match schema {
+            JsonValue::Object(object) => {
+                let mut converted = Map::new();
+                for (key, value) in object {
+                    let value = match (key.as_str(), value) {
+                        ("$ref", JsonValue::String(reference)) => {
+                            JsonValue::String(self.convert_schema_ref(reference, pointer, pending))
+                        }
+                        // Examples are data, not schemas
+                        ("example" | "examples" | "default" | "const" | "enum", _) => value.clone(),
+                        _ => self.convert_schema(
+                            value,
+                            &format!("{}{}", pointer, json_pointer([key.as_str()])),
+                            pending,
+                        ),
+                    };
+                    converted.insert(key.clone(), value);
+                }
+
+                if self.legacy_schemas {
+                    convert_legacy_keywords(&mut converted);
+                }
+
+                JsonValue::Object(converted)
+            }
+            JsonValue::Array(values) => JsonValue::Array(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let pointer = format!("{}/{}", pointer, index);
+                        self.convert_schema(value, &pointer, pending)
+                    })
+                    .collect(),
+            ),
+            _ => schema.clone(),
+        }
+    }
+
+    fn convert_schema_ref(
+        &mut self,
+        reference: &str,
+        pointer: &str,
+        pending: &mut Vec<String>,
+    ) -> String {
+        if let Some(rest) = reference.strip_prefix(SCHEMA_REF_PREFIX) {
+            // Only the schema itself is copied, the rest points inside of it
+            let token = rest.split('/').next().unwrap_or_default();
+            pending.push(token.replace("~1", "/").replace("~0", "~"));
+            return format!("#/$defs/{}", rest);
+        }
+
+        if !reference.starts_with('#') {
+            self.skip(
+                pointer.to_string(),
+                format!("external reference `{}` is not supported", reference),
+            );
+        }
+
+        reference.to_string()
+    }
+
+    fn convert_paths(&mut self) {
+        let Some(paths) = self.document.get("paths").and_then(JsonValue::as_object) else {
+            return;
+        };
+
+        for (path, item) in paths {
+            let pointer = json_pointer(["paths", path]);
+            let Some(item) = self.resolve(item, &pointer).and_then(JsonValue::as_object) else {
+                continue;
+            };
+
+            let shared_parameters = item
+                .get("parameters")
+                .and_then(JsonValue::as_array)
+                .map(|parameters| {
+                    parameters
+                        .iter()
+                        .enumerate()
+                        .map(|(index, parameter)| {
+                            (format!("{}/parameters/{}", pointer, index), parameter)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            for (method, protocol) in &METHODS {
+                let Some(operation) = item.get(*method).and_then(JsonValue::as_object) else {
+                    continue;
+                };
+
+                let pointer = json_pointer(["paths", path, method]);
+                self.convert_operation(
+                    path,
+                    method,
+                    protocol.clone(),
+                    operation,
+                    &shared_parameters,
+                    &pointer,
+                );
+            }
+
+            if item.contains_key("servers") {
+                self.skip(
+                    format!("{}/servers", pointer),
+                    "servers of a path are not supported, the first server of the document is used",
+                );
+            }
+        }
+    }
+
+    fn convert_operation(
+        &mut self,
+        path: &str,
+        method: &str,
+        protocol: ResourceProtocol,
+        operation: &'a Map<String, JsonValue>,
+        shared_parameters: &[(String, &'a JsonValue)],
+        pointer: &str,
+    ) {
+        let tag = operation
+            .get("tags")
+            .and_then(|tags| tags.get(0))
+            .and_then(JsonValue::as_str);
+        let segment = path
+            .split('/')
+            .find(|segment| !segment.is_empty() && !segment.starts_with('{'));
+        let dir = match tag.or(segment) {
+            Some(key) => self.dir(key, ResourceClass::Endpoint),
+            None => PathBuf::new(),
+        };
+
+        let name = ["summary", "operationId"]
+            .iter()
+            .filter_map(|key| operation.get(*key).and_then(JsonValue::as_str))
+            .map(str::trim)
+            .find(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} {}", method.to_uppercase(), path));
+        let name = self.names.claim(&dir, &name);
+        let order = self.next_order(&dir);
+
+        // Parameters of the operation override the ones of the path with the same name and location
+        let operation_parameters = operation
+            .get("parameters")
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, parameter)| (format!("{}/parameters/{}", pointer, index), parameter));
+        let mut parameters = Vec::<(String, &Map<String, JsonValue>)>::new();
+        for (pointer, parameter) in shared_parameters
+            .iter()
+            .cloned()
+            .chain(operation_parameters)
+        {
+            let Some(parameter) = self
+                .resolve(parameter, &pointer)
+                .and_then(JsonValue::as_object)
+            else {
+                continue;
+            };
+
+            let key = |parameter: &Map<String, JsonValue>| {
+                (
+                    parameter
+                        .get("name")
+                        .and_then(JsonValue::as_str)
+                        .map(str::to_string),
+                    parameter
+                        .get("in")
+                        .and_then(JsonValue::as_str)
+                        .map(str::to_string),
+                )
+            };
+            parameters.retain(|(_, existing)| key(existing) != key(parameter));
+            parameters.push((pointer, parameter));
+        }
+
+        let mut path_params = Vec::new();
+        let mut query_params = Vec::new();
+        let mut headers = Vec::new();
+        for (pointer, parameter) in &parameters {
+            let Some(name) = parameter.get("name").and_then(JsonValue::as_str) else {
+                self.skip(pointer.clone(), "parameter has no name");
+                continue;
+            };
+            let description = parameter
+                .get("description")
+                .and_then(JsonValue::as_str)
+                .map(str::to_string);
+            let value = self.parameter_value(parameter);
+            let disabled = !parameter
+                .get("required")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false);
+
+            match parameter.get("in").and_then(JsonValue::as_str) {
+                Some("path") => path_params.push(AddPathParamParams {
+                    name: path_variable(name),
+                    value,
+                    order: path_params.len() as isize,
+                    description,
+                    options: PathParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                }),
+                Some("query") => query_params.push(AddQueryParamParams {
+                    name: name.to_string(),
+                    value,
+                    order: query_params.len() as isize,
+                    description,
+                    options: QueryParamOptions {
+                        disabled,
+                        propagate: false,
+                    },
+                }),
+                Some("header") if IGNORED_HEADERS.contains(&name.to_lowercase().as_str()) => {
+                    self.skip(
+                        pointer.clone(),
+                        format!("header parameter `{}` is ignored by openapi", name),
+                    );
+                }
+                Some("header") => headers.push(AddHeaderParams {
+                    name: name.to_string(),
+                    value,
+                    order: headers.len() as isize,
+                    description,
+                    options: HeaderParamOptions {
+                        disabled,
+                        propagate: false,
+                    },
+                }),
+                Some(location) => self.skip(
+                    pointer.clone(),
+                    format!("{} parameters are not supported", location),
+                ),
+                None => self.skip(pointer.clone(), "parameter has no location"),
+            }
+        }
+
+        // Every variable of the path needs a value, even if it isn't described
+        for variable in path_variables(path) {
+            let variable = path_variable(variable);
+            if !path_params.iter().any(|param| param.name == variable) {
+                path_params.push(AddPathParamParams {
+                    name: variable,
+                    value: json!(""),
+                    order: path_params.len() as isize,
+                    description: None,
+                    options: PathParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                });
+            }
+        }
+
+        let body = match operation.get("requestBody") {
+            Some(body) => {
+                let pointer = format!("{}/requestBody", pointer);
+                self.convert_body(body, &pointer, &mut headers)
+            }
+            None => None,
+        };
+
+        for key in ["callbacks", "servers", "security"] {
+            if operation.contains_key(key) {
+                let reason = match key {
+                    "callbacks" => "callbacks are not supported",
+                    "servers" => {
+                        "servers of an operation are not supported, the first server of the document is used"
+                    }
+                    _ => {
+                        "security requirements are not imported, set the auth of the resource instead"
+                    }
+                };
+                self.skip(format!("{}{}", pointer, json_pointer([key])), reason);
+            }
+        }
+
+        self.resources
+            .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                path: dir,
+                class: ResourceClass::Endpoint,
+                name,
+                description: None,
+                order,
+                protocol: Some(protocol),
+                url: Some(format!("{}{}", self.base_url, url_path(path))),
+                headers,
+                path_params,
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth: None,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }));
+    }
+
+    fn parameter_value(&self, parameter: &Map<String, JsonValue>) -> JsonValue {
+        parameter
+            .get("example")
+            .cloned()
+            .or_else(|| self.first_example(parameter.get("examples")))
+            .or_else(|| {
+                let schema = self.resolve_quietly(parameter.get("schema")?)?;
+                ["example", "default"]
+                    .iter()
+                    .find_map(|key| schema.get(*key).cloned())
+                    .or_else(|| schema.get("enum")?.get(0).cloned())
+            })
+            .unwrap_or_else(|| json!(""))
+    }
+
+    fn first_example(&self, examples: Option<&JsonValue>) -> Option<JsonValue> {
+        let (_, example) = examples?.as_object()?.iter().next()?;
+        self.resolve_quietly(example)?.get("value").cloned()
+    }
+
+    /// Resolves local references without reporting the ones that can't be followed,
+    /// for the places where an example is only a best effort.
+    fn resolve_quietly(&self, value: &'a JsonValue) -> Option<&'a JsonValue> {
+        let mut value = value;
+        for _ in 0..MAX_DEPTH {
+            match value.get("$ref").and_then(JsonValue::as_str) {
+                Some(reference) => value = self.document.pointer(reference.strip_prefix('#')?)?,
+                None => return Some(value),
+            }
+        }
+
+        None
+    }
+
+    fn convert_body(
+        &mut self,
+        body: &'a JsonValue,
+        pointer: &str,
+        headers: &mut Vec<AddHeaderParams>,
+    ) -> Option<AddBodyParams> {
+        let content = self
+            .resolve(body, pointer)?
+            .get("content")
+            .and_then(JsonValue::as_object)?;
+
+        let Some((media_type, kind, media)) = content
+            .iter()
+            .filter_map(|(media_type, media)| {
+                let kind = BodyKind::of(media_type)?;
+                Some((media_type, kind, media))
+            })
+            .min_by_key(|(_, kind, _)| *kind)
+        else {
+            if let Some(media_type) = content.keys().next() {
+                self.skip(
+                    format!("{}{}", pointer, json_pointer(["content", media_type])),
+                    format!("request bodies of type `{}` are not supported", media_type),
+                );
+            }
+            return None;
+        };
+
+        let example = media
+            .get("example")
+            .cloned()
+            .or_else(|| self.first_example(media.get("examples")));
+        let schema = media
+            .get("schema")
+            .and_then(|schema| self.resolve_quietly(schema));
+
+        let body = match kind {
+            BodyKind::Json => {
+                // Other json media types, such as `application/problem+json`, need to be set explicitly
+                if media_type.split(';').next().map(str::trim) != Some("application/json") {
+                    headers.push(AddHeaderParams {
+                        name: "Content-Type".to_string(),
+                        value: json!(media_type),
+                        order: headers.len() as isize,
+                        description: None,
+                        options: HeaderParamOptions {
+                            disabled: false,
+                            propagate: false,
+                        },
+                    });
+                }
+
+                AddBodyParams::Json(example.unwrap_or_else(|| match schema {
+                    Some(schema) => self.example(schema, 0),
+                    None => json!({}),
+                }))
+            }
+            BodyKind::Urlencoded | BodyKind::FormData => {
+                let fields = self.form_fields(schema, example.as_ref());
+                if kind == BodyKind::Urlencoded {
+                    AddBodyParams::Urlencoded(
+                        fields
+                            .into_iter()
+                            .enumerate()
+                            .map(|(order, (name, value))| AddUrlencodedParamParams {
+                                name,
+                                value,
+                                order: order as isize,
+                                description: None,
+                                options: UrlencodedParamOptions {
+                                    disabled: false,
+                                    propagate: false,
+                                },
+                                id: None,
+                            })
+                            .collect(),
+                    )
+                } else {
+                    AddBodyParams::FormData(
+                        fields
+                            .into_iter()
+                            .enumerate()
+                            .map(|(order, (name, value))| AddFormDataParamParams {
+                                name,
+                                value,
+                                order: order as isize,
+                                description: None,
+                                options: FormDataParamOptions {
+                                    disabled: false,
+                                    propagate: false,
+                                },
+                                id: None,
+                            })
+                            .collect(),
+                    )
+                }
+            }
+            BodyKind::Xml => AddBodyParams::Xml(text_example(example)),
+            BodyKind::Text => AddBodyParams::Text(text_example(example)),
+        };
+
+        Some(body)
+    }
+
+    fn form_fields(
+        &self,
+        schema: Option<&'a JsonValue>,
+        example: Option<&JsonValue>,
+    ) -> Vec<(String, JsonValue)> {
+        let Some(properties) = schema
+            .and_then(|schema| schema.get("properties"))
+            .and_then(JsonValue::as_object)
+        else {
+            return Vec::new();
+        };
+
+        properties
+            .iter()
+            .map(|(name, property)| {
+                let value = example
+                    .and_then(|example| example.get(name))
+                    .cloned()
+                    .or_else(|| {
+                        let property = self.resolve_quietly(property)?;
+                        ["example", "default"]
+                            .iter()
+                            .find_map(|key| property.get(*key).cloned())
+                    })
+                    .unwrap_or_else(|| json!(""));
+
+                (name.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Generates an example value matching `schema`, preferring the examples it declares.
+    fn example(&self, schema: &'a JsonValue, depth: usize) -> JsonValue {
+        if depth > MAX_DEPTH {
+            return JsonValue::Null;
+        }
+        let Some(schema) = self.resolve_quietly(schema).and_then(JsonValue::as_object) else {
+            return JsonValue::Null;
+        };
+
+        if let Some(example) = schema
+            .get("example")
+            .or_else(|| schema.get("examples")?.get(0))
+            .or_else(|| schema.get("default"))
+            .or_else(|| schema.get("const"))
+            .or_else(|| schema.get("enum")?.get(0))
+        {
+            return example.clone();
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(JsonValue::as_array) {
+            let mut merged = Map::new();
+            for schema in all_of {
+                if let JsonValue::Object(properties) = self.example(schema, depth + 1) {
+                    merged.extend(properties);
+                }
+            }
+            return JsonValue::Object(merged);
+        }
+
+        if let Some(schema) = ["oneOf", "anyOf"]
+            .iter()
+            .find_map(|key| schema.get(*key)?.get(0))
+        {
+            return self.example(schema, depth + 1);
+        }
+
+        let types = match schema.get("type") {
+            Some(JsonValue::String(kind)) => vec![kind.as_str()],
+            Some(JsonValue::Array(kinds)) => kinds.iter().filter_map(JsonValue::as_str).collect(),
+            _ => vec![],
+        };
+        let kind = types
+            .iter()
+            .copied()
+            .find(|kind| *kind != "null")
+            .or_else(|| types.first().copied())
+            .unwrap_or(if schema.contains_key("properties") {
+                "object"
+            } else if schema.contains_key("items") {
+                "array"
+            } else {
+                ""
+            });
+
+        match kind {
+            "object" => JsonValue::Object(
+                schema
+                    .get("properties")
+                    .and_then(JsonValue::as_object)
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, property)| (name.clone(), self.example(property, depth + 1)))
+                    .collect(),
+            ),
+            "array" => match schema.get("items") {
+                Some(items) => json!([self.example(items, depth + 1)]),
+                None => json!([]),
+            },
+            "string" => json!(match schema.get("format").and_then(JsonValue::as_str) {
+                Some("date-time") => "1970-01-01T00:00:00Z",
+                Some("date") => "1970-01-01",
+                Some("email") => "user@example.com",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("uri" | "url") => "https://example.com",
+                _ => "string",
+            }),
+            "integer" | "number" => json!(0),
+            "boolean" => json!(false),
+            _ => JsonValue::Null,
+        }
+    }
+
+    fn report_unsupported(&mut self) {
+        if self.document.get("webhooks").is_some() {
+            self.skip("/webhooks".to_string(), "webhooks are not supported");
+        }
+
+        if self
+            .document
+            .pointer("/components/securitySchemes")
+            .is_some()
+        {
+            self.skip(
+                "/components/securitySchemes".to_string(),
+                "security schemes are not imported, set the auth of the resources instead",
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BodyKind {
+    // Ordered by preference, when a request body can be sent as several media types
+    Json,
+    Urlencoded,
+    FormData,
+    Xml,
+    Text,
+}
+
+impl BodyKind {
+    fn of(media_type: &str) -> Option<Self> {
+        let media_type = media_type.split(';').next()?.trim().to_lowercase();
+        match media_type.as_str() {
+            "application/json" => Some(Self::Json),
+            "application/x-www-form-urlencoded" => Some(Self::Urlencoded),
+            "multipart/form-data" => Some(Self::FormData),
+            "application/xml" | "text/xml" => Some(Self::Xml),
+            "text/plain" => Some(Self::Text),
+            _ if media_type.ends_with("+json") => Some(Self::Json),
+            _ if media_type.ends_with("+xml") => Some(Self::Xml),
+            _ => None,
+        }
+    }
+}
+
+/// Turns the OpenAPI 3.0 variants of `nullable` and the exclusive bounds into JSON Schema.
+fn convert_legacy_keywords(schema: &mut Map<String, JsonValue>) {
+    if schema.remove("nullable") == Some(JsonValue::Bool(true))
+        && let Some(JsonValue::String(kind)) = schema.get("type")
+    {
+        let kind = json!([kind, "null"]);
+        schema.insert("type".to_string(), kind);
+    }
+
+    for (exclusive, bound) in [
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(JsonValue::Bool(is_exclusive)) = schema.get(exclusive) {
+            if *is_exclusive && let Some(bound) = schema.remove(bound) {
+                schema.insert(exclusive.to_string(), bound);
+            } else {
+                schema.remove(exclusive);
+            }
+        }
+    }
+}
+
+/// Uses the url of the first server, with its variables set to their default values.
+fn base_url(document: &JsonValue) -> String {
+    let Some(server) = document.pointer("/servers/0") else {
+        return BASE_URL_VARIABLE.to_string();
+    };
+
+    let mut url = server
+        .get("url")
+        .and_then(JsonValue::as_str)
+        .unwrap_or_default()
+        .to_string();
+    if let Some(variables) = server.get("variables").and_then(JsonValue::as_object) {
+        for (name, variable) in variables {
+            if let Some(default) = variable.get("default").and_then(JsonValue::as_str) {
+                url = url.replace(&format!("{{{}}}", name), default);
+            }
+        }
+    }
+
+    let url = url.trim_end_matches('/');
+    if url.contains("://") {
+        url.to_string()
+    } else {
+        // Relative to the location of the document, which the environment has to provide
+        format!("{}/{}", BASE_URL_VARIABLE, url.trim_start_matches('/'))
+            .trim_end_matches('/')
+            .to_string()
+    }
+}
+
+fn path_variables(path: &str) -> impl Iterator<Item = &str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(variable, _)| variable))
+}
+
+/// Path variables of urls can only be made of letters, digits and underscores.
+fn path_variable(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Turns the `{name}` templates of an OpenAPI path into `:name` path variables.
+fn url_path(path: &str) -> String {
+    let mut url = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some((before, after)) = rest.split_once('{') {
+        url.push_str(before);
+        match after.split_once('}') {
+            Some((variable, after)) => {
+                url.push(':');
+                url.push_str(&path_variable(variable));
+                rest = after;
+            }
+            None => {
+                url.push('{');
+                rest = after;
+            }
+        }
+    }
+    url.push_str(rest);
+
+    url
+}
+
+fn text_example(example: Option<JsonValue>) -> String {
+    match example {
+        Some(JsonValue::String(text)) => text,
+        Some(example) => example.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(imported: &ImportedResources) -> Vec<&CreateItemResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect()
+    }
+
+    fn dirs(imported: &ImportedResources) -> Vec<&str> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Dir(dir) => Some(dir.name.as_str()),
+                BatchCreateResourceKind::Item(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rejects_other_documents() {
+        assert!(convert(&json!({ "swagger": "2.0" })).is_err());
+        assert!(convert(&json!({ "openapi": "4.0.0" })).is_err());
+    }
+
+    #[test]
+    fn test_operations_grouped_by_tag_or_segment() {
+        let imported = convert(&json!({
+            "openapi": "3.1.0",
+            "servers": [{ "url": "https://{region}.example.com/v1/", "variables": { "region": { "default": "eu" } } }],
+            "paths": {
+                "/pets/{pet-id}": {
+                    "parameters": [{ "name": "pet-id", "in": "path", "required": true, "example": 7 }],
+                    "get": {
+                        "summary": "Get pet",
+                        "tags": ["animals"],
+                        "parameters": [
+                            { "name": "fields", "in": "query", "schema": { "type": "string", "default": "all" } },
+                            { "name": "X-Trace", "in": "header", "required": true },
+                            { "name": "session", "in": "cookie" }
+                        ]
+                    }
+                },
+                "/users": {
+                    "get": { "operationId": "listUsers" },
+                    "post": {}
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(dirs(&imported), vec!["animals", "users"]);
+
+        let items = items(&imported);
+        assert_eq!(items[0].name, "Get pet");
+        assert_eq!(items[0].path, PathBuf::from("animals"));
+        assert_eq!(
+            items[0].url.as_deref(),
+            Some("https://eu.example.com/v1/pets/:pet_id")
+        );
+        assert_eq!(items[0].path_params[0].name, "pet_id");
+        assert_eq!(items[0].path_params[0].value, json!(7));
+        assert_eq!(items[0].query_params[0].value, json!("all"));
+        assert!(items[0].query_params[0].options.disabled);
+        assert!(!items[0].headers[0].options.disabled);
+
+        assert_eq!(items[1].name, "listUsers");
+        assert_eq!(items[2].name, "POST /users");
+        assert_eq!(items[2].path, PathBuf::from("users"));
+
+        assert_eq!(
+            imported.skipped,
+            vec![ImportSkippedItem {
+                pointer: "/paths/~1pets~1{pet-id}/get/parameters/2".to_string(),
+                reason: "cookie parameters are not supported".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_request_body_examples() {
+        let imported = convert(&json!({
+            "openapi": "3.0.3",
+            "paths": {
+                "/pets": {
+                    "post": {
+                        "requestBody": { "$ref": "#/components/requestBodies/Pet" }
+                    },
+                    "put": {
+                        "requestBody": {
+                            "content": {
+                                "application/x-www-form-urlencoded": {
+                                    "schema": { "properties": { "name": { "example": "Rex" }, "age": {} } }
+                                }
+                            }
+                        }
+                    },
+                    "patch": {
+                        "requestBody": { "content": { "application/octet-stream": {} } }
+                    }
+                }
+            },
+            "components": {
+                "requestBodies": {
+                    "Pet": {
+                        "content": {
+                            "text/plain": { "example": "rex" },
+                            "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } }
+                        }
+                    }
+                },
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "born": { "type": "string", "format": "date" },
+                            "tags": { "type": "array", "items": { "type": "string", "enum": ["good"] } },
+                            "owner": { "allOf": [{ "properties": { "id": { "type": "integer" } } }] }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let items = items(&imported);
+        let endpoints = items
+            .iter()
+            .filter(|item| item.class == ResourceClass::Endpoint)
+            .collect::<Vec<_>>();
+
+        let Some(AddBodyParams::Json(body)) = &endpoints[1].body else {
+            panic!("expected a json body");
+        };
+        assert_eq!(
+            body,
+            &json!({ "name": "string", "born": "1970-01-01", "tags": ["good"], "owner": { "id": 0 } })
+        );
+
+        let Some(AddBodyParams::Urlencoded(fields)) = &endpoints[0].body else {
+            panic!("expected an urlencoded body");
+        };
+        let values = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.value.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![("age", json!("")), ("name", json!("Rex"))]);
+
+        assert!(endpoints[2].body.is_none());
+        assert_eq!(
+            imported.skipped[0].reason,
+            "request bodies of type `application/octet-stream` are not supported"
+        );
+    }
+
+    #[test]
+    fn test_schemas_are_self_contained() {
+        let imported = convert(&json!({
+            "openapi": "3.0.0",
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Owner": { "type": "object", "properties": { "pets": { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } } } },
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string", "nullable": true },
+                            "age": { "type": "integer", "minimum": 0, "exclusiveMinimum": true },
+                            "parent": { "$ref": "#/components/schemas/Pet" },
+                            "other": { "$ref": "other.yaml#/Pet" }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(dirs(&imported), vec!["schemas"]);
+        let items = items(&imported);
+        assert_eq!(items[0].class, ResourceClass::Schema);
+
+        let owner = items[0].schema.as_ref().unwrap();
+        assert_eq!(
+            owner["properties"]["pets"]["items"],
+            json!({ "$ref": "#/$defs/Pet" })
+        );
+        let pet = &owner["$defs"]["Pet"];
+        assert_eq!(pet["properties"]["name"]["type"], json!(["string", "null"]));
+        assert_eq!(pet["properties"]["age"]["exclusiveMinimum"], json!(0));
+        assert!(pet["properties"]["age"].get("minimum").is_none());
+
+        // Schemas referencing themselves carry a copy of themselves
+        let pet = items[1].schema.as_ref().unwrap();
+        assert_eq!(
+            pet["properties"]["parent"],
+            json!({ "$ref": "#/$defs/Pet" })
+        );
+        assert!(pet["$defs"].get("Pet").is_some());
+
+        assert!(
+            imported
+                .skipped
+                .iter()
+                .any(|item| item.pointer == "/components/schemas/Pet/properties/other")
+        );
+    }
+
+    #[test]
+    fn test_base_url() {
+        assert_eq!(base_url(&json!({})), "{{baseUrl}}");
+        assert_eq!(
+            base_url(&json!({ "servers": [{ "url": "/api/" }] })),
+            "{{baseUrl}}/api"
+        );
+        assert_eq!(
+            base_url(&json!({ "servers": [{ "url": "/" }] })),
+            "{{baseUrl}}"
+        );
+    }
+
+    #[test]
+    fn test_url_path() {
+        assert_eq!(url_path("/a/{b}/c/{d.e}"), "/a/:b/c/:d_e");
+        assert_eq!(url_path("/a/{b"), "/a/{b");
+    }
+}
@@ -0,0 +1,1189 @@
+use joinerror::Error;
+use moss_environment::models::types::AddVariableParams;
+use moss_project::models::{
+    operations::BatchCreateResourceKind,
+    types::{
+        ApiKeyLocation, AuthScheme, AwsSigV4Config, CreateDirResourceParams,
+        CreateItemResourceParams, OAuth2Config, OAuth2Grant,
+        http::{
+            AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
+            AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
+            HeaderParamOptions, PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::project::ImportSkippedItem;
+use serde_json::{Map, Value as JsonValue, json};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::import::{
+    ImportedEnvironment, ImportedResources, UniqueNames, convert_path_variables,
+    environment_variable, is_variable_reference, json_pointer, protocol, template,
+    template_variables, variable_name,
+};
+
+const COLLECTION_SCHEMA: &str = "/collection/v2.1";
+
+/// Converts a Postman collection v2.1 into resources.
+///
+/// Folders become directories and requests become endpoints. The collection itself only
+/// becomes a directory when it has an auth, a description or scripts for it to hold.
+/// The variables of the collection, along with the secrets found inline in its auths,
+/// are gathered in an environment named after it.
+pub fn convert(collection: &JsonValue) -> joinerror::Result<ImportedResources> {
+    let schema = collection
+        .pointer("/info/schema")
+        .and_then(JsonValue::as_str)
+        .ok_or_else(|| Error::new::<()>("the document is not a postman collection"))?;
+    if !schema.contains(COLLECTION_SCHEMA) {
+        return Err(Error::new::<()>(format!(
+            "postman collection schema `{}` is not supported, only v2.1 is",
+            schema
+        )));
+    }
+
+    let name = collection
+        .pointer("/info/name")
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Postman");
+
+    let mut converter = Converter::default();
+    converter.convert_variables(collection.get("variable"));
+
+    let root = PathBuf::new();
+    let description = converter.description(
+        collection.pointer("/info/description"),
+        collection.get("event"),
+        "",
+    );
+    let auth = converter.auth(collection.get("auth"), "", name);
+    let headers = converter.headers(collection.get("header"), "", true);
+
+    let dir = if description.is_some() || auth.is_some() || !headers.is_empty() {
+        converter.push_dir(&root, name, description, headers, auth)
+    } else {
+        root
+    };
+    converter.convert_items(collection.get("item"), "", &dir);
+
+    let mut environments = Vec::new();
+    if !converter.variables.is_empty() {
+        environments.push(ImportedEnvironment {
+            name: name.to_string(),
+            variables: converter.variables,
+        });
+    }
+
+    Ok(ImportedResources {
+        resources: converter.resources,
+        skipped: converter.skipped,
+        environments,
+    })
+}
+
+/// Converts a Postman environment file, whose secrets are kept as local values.
+pub fn convert_environment(environment: &JsonValue) -> joinerror::Result<ImportedEnvironment> {
+    let values = environment
+        .get("values")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| Error::new::<()>("the document is not a postman environment"))?;
+    let name = environment
+        .get("name")
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Postman");
+
+    let mut names = HashSet::new();
+    let variables = values
+        .iter()
+        .filter_map(|value| {
+            let key = value.get("key").and_then(JsonValue::as_str)?;
+            let enabled = value
+                .get("enabled")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(true);
+
+            Some(environment_variable(
+                &mut names,
+                key,
+                value.get("value"),
+                value.get("type").and_then(JsonValue::as_str) == Some("secret"),
+                !enabled,
+                None,
+            ))
+        })
+        .enumerate()
+        .map(|(order, variable)| AddVariableParams {
+            order: order as isize,
+            ..variable
+        })
+        .collect();
+
+    Ok(ImportedEnvironment {
+        name: name.to_string(),
+        variables,
+    })
+}
+
+#[derive(Default)]
+struct Converter {
+    names: UniqueNames,
+    orders: HashMap<PathBuf, isize>,
+    resources: Vec<BatchCreateResourceKind>,
+    skipped: Vec<ImportSkippedItem>,
+    variables: Vec<AddVariableParams>,
+    variable_names: HashSet<String>,
+}
+
+impl Converter {
+    fn skip(&mut self, pointer: String, reason: impl Into<String>) {
+        let item = ImportSkippedItem {
+            pointer,
+            reason: reason.into(),
+        };
+        // The same variable is often used several times in a single value
+        if !self.skipped.contains(&item) {
+            self.skipped.push(item);
+        }
+    }
+
+    fn next_order(&mut self, dir: &Path) -> isize {
+        let order = self.orders.entry(dir.to_path_buf()).or_default();
+        *order += 1;
+        *order - 1
+    }
+
+    fn push_dir(
+        &mut self,
+        dir: &Path,
+        name: &str,
+        description: Option<String>,
+        headers: Vec<AddHeaderParams>,
+        auth: Option<AuthScheme>,
+    ) -> PathBuf {
+        let name = self.names.claim(dir, name);
+        let order = self.next_order(dir);
+        self.resources
+            .push(BatchCreateResourceKind::Dir(CreateDirResourceParams {
+                path: dir.to_path_buf(),
+                class: ResourceClass::Endpoint,
+                name: name.clone(),
+                description,
+                order,
+                headers,
+                path_params: vec![],
+                query_params: vec![],
+                auth,
+            }));
+
+        dir.join(name)
+    }
+
+    fn convert_variables(&mut self, variables: Option<&JsonValue>) {
+        for variable in variables
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(key) = variable.get("key").and_then(JsonValue::as_str) else {
+                continue;
+            };
+            let disabled = variable
+                .get("disabled")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false);
+
+            let variable = environment_variable(
+                &mut self.variable_names,
+                key,
+                variable.get("value"),
+                variable.get("type").and_then(JsonValue::as_str) == Some("secret"),
+                disabled,
+                description_text(variable.get("description")),
+            );
+            self.variables.push(AddVariableParams {
+                order: self.variables.len() as isize,
+                ..variable
+            });
+        }
+    }
+
+    fn convert_items(&mut self, items: Option<&JsonValue>, pointer: &str, dir: &Path) {
+        for (index, item) in items
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            let pointer = format!("{}/item/{}", pointer, index);
+            match item.as_object() {
+                Some(folder) if folder.contains_key("item") => {
+                    self.convert_folder(folder, &pointer, dir)
+                }
+                Some(request) if request.contains_key("request") => {
+                    self.convert_request(request, &pointer, dir)
+                }
+                _ => self.skip(pointer, "item is neither a folder nor a request"),
+            }
+        }
+    }
+
+    fn convert_folder(&mut self, folder: &Map<String, JsonValue>, pointer: &str, dir: &Path) {
+        let name = folder
+            .get("name")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default();
+        let description = self.description(folder.get("description"), folder.get("event"), pointer);
+        let auth = self.auth(folder.get("auth"), pointer, name);
+        let headers = self.headers(folder.get("header"), pointer, true);
+        if folder.contains_key("variable") {
+            self.skip(
+                format!("{}/variable", pointer),
+                "variables of folders are not supported, set them in an environment instead",
+            );
+        }
+
+        let path = self.push_dir(dir, name, description, headers, auth);
+        self.convert_items(folder.get("item"), pointer, &path);
+    }
+
+    fn convert_request(&mut self, item: &Map<String, JsonValue>, pointer: &str, dir: &Path) {
+        // A request can be written as nothing more than its url
+        let shorthand;
+        let request = match item.get("request") {
+            Some(JsonValue::String(url)) => {
+                shorthand = Map::from_iter([("url".to_string(), json!(url))]);
+                &shorthand
+            }
+            Some(JsonValue::Object(request)) => request,
+            _ => {
+                self.skip(format!("{}/request", pointer), "request is malformed");
+                return;
+            }
+        };
+        let request_pointer = format!("{}/request", pointer);
+
+        let method = request
+            .get("method")
+            .and_then(JsonValue::as_str)
+            .unwrap_or("GET")
+            .to_uppercase();
+        let Some(protocol) = protocol(&method) else {
+            self.skip(
+                format!("{}/method", request_pointer),
+                format!("invalid http method `{}`", method),
+            );
+            return;
+        };
+
+        let url = request.get("url");
+        let raw_url = url.map(raw_url).unwrap_or_default();
+        let url_pointer = format!("{}/url", request_pointer);
+        let (url, path_variables) = convert_path_variables(&self.template(
+            raw_url.split(['?', '#']).next().unwrap_or_default(),
+            &url_pointer,
+        ));
+
+        let path_params = self.path_params(request.get("url"), &path_variables, &url_pointer);
+        let query_params = match request.get("url").and_then(|url| url.get("query")) {
+            Some(query) => self.query_params(query, &url_pointer),
+            None => self.raw_query_params(&raw_url, &url_pointer),
+        };
+        let headers = self.headers(request.get("header"), &request_pointer, false);
+        let body = self.body(request.get("body"), &request_pointer);
+        let auth = self.auth(
+            request.get("auth"),
+            &request_pointer,
+            item.get("name")
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default(),
+        );
+
+        let description = self.description(
+            request
+                .get("description")
+                .or_else(|| item.get("description")),
+            item.get("event"),
+            pointer,
+        );
+
+        for (object, object_pointer, key, reason) in [
+            (
+                item,
+                pointer,
+                "response",
+                "saved responses are not supported",
+            ),
+            (
+                item,
+                pointer,
+                "variable",
+                "variables of requests are not supported, set them in an environment instead",
+            ),
+            (
+                item,
+                pointer,
+                "protocolProfileBehavior",
+                "request settings are not supported",
+            ),
+            (
+                request,
+                request_pointer.as_str(),
+                "proxy",
+                "proxies of requests are not supported",
+            ),
+            (
+                request,
+                request_pointer.as_str(),
+                "certificate",
+                "client certificates are not supported",
+            ),
+        ] {
+            let is_set = match object.get(key) {
+                None | Some(JsonValue::Null) => false,
+                Some(JsonValue::Array(values)) => !values.is_empty(),
+                Some(JsonValue::Object(values)) => !values.is_empty(),
+                Some(_) => true,
+            };
+            if is_set {
+                self.skip(format!("{}{}", object_pointer, json_pointer([key])), reason);
+            }
+        }
+
+        let name = item
+            .get("name")
+            .and_then(JsonValue::as_str)
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} {}", method, url));
+        let name = self.names.claim(dir, &name);
+        let order = self.next_order(dir);
+
+        self.resources
+            .push(BatchCreateResourceKind::Item(CreateItemResourceParams {
+                path: dir.to_path_buf(),
+                class: ResourceClass::Endpoint,
+                name,
+                description,
+                order,
+                protocol: Some(protocol),
+                url: Some(url),
+                headers,
+                path_params,
+                query_params,
+                body,
+                body_schema: None,
+                components: vec![],
+                auth,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }));
+    }
+
+    /// Rewrites the `{{name}}` variables of a value into ones the template parser accepts.
+    fn template(&mut self, text: &str, pointer: &str) -> String {
+        for name in template_variables(text) {
+            if let Some(dynamic) = name.strip_prefix('$') {
+                self.skip(
+                    pointer.to_string(),
+                    format!(
+                        "dynamic variable `{{{{{}}}}}` is not supported, set `{}` in an environment instead",
+                        name,
+                        variable_name(dynamic)
+                    ),
+                );
+            }
+        }
+
+        template(text)
+    }
+
+    fn template_value(&mut self, value: Option<&JsonValue>, pointer: &str) -> JsonValue {
+        match value {
+            Some(JsonValue::String(text)) => json!(self.template(text, pointer)),
+            Some(JsonValue::Null) | None => json!(""),
+            Some(value) => value.clone(),
+        }
+    }
+
+    /// The description of a folder or a request, followed by its scripts
+    /// so that they aren't lost.
+    fn description(
+        &mut self,
+        description: Option<&JsonValue>,
+        events: Option<&JsonValue>,
+        pointer: &str,
+    ) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(description) = description_text(description) {
+            parts.push(description);
+        }
+
+        for (index, event) in events
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            let script = match event.pointer("/script/exec") {
+                Some(JsonValue::Array(lines)) => lines
+                    .iter()
+                    .filter_map(JsonValue::as_str)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                Some(JsonValue::String(script)) => script.clone(),
+                _ => continue,
+            };
+            if script.trim().is_empty() {
+                continue;
+            }
+
+            let title = match event.get("listen").and_then(JsonValue::as_str) {
+                Some("prerequest") => "Pre-request script",
+                Some("test") => "Test script",
+                _ => "Script",
+            };
+            parts.push(format!("{}:\n```js\n{}\n```", title, script.trim_end()));
+            self.skip(
+                format!("{}/event/{}", pointer, index),
+                "scripts are not supported, the script is kept in the description",
+            );
+        }
+
+        (!parts.is_empty()).then(|| parts.join("\n\n"))
+    }
+
+    fn path_params(
+        &mut self,
+        url: Option<&JsonValue>,
+        path_variables: &[String],
+        pointer: &str,
+    ) -> Vec<AddPathParamParams> {
+        let mut path_params = Vec::<AddPathParamParams>::new();
+        let variables = url
+            .and_then(|url| url.get("variable"))
+            .and_then(JsonValue::as_array);
+        for (index, variable) in variables.into_iter().flatten().enumerate() {
+            let Some(key) = variable.get("key").and_then(JsonValue::as_str) else {
+                continue;
+            };
+            let pointer = format!("{}/variable/{}", pointer, index);
+            let name = variable_name(key);
+            if !path_variables.contains(&name) {
+                self.skip(
+                    pointer,
+                    format!("path variable `{}` isn't used by the url", key),
+                );
+                continue;
+            }
+
+            path_params.push(AddPathParamParams {
+                name,
+                value: self.template_value(variable.get("value"), &pointer),
+                order: path_params.len() as isize,
+                description: description_text(variable.get("description")),
+                options: PathParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            });
+        }
+
+        // Every variable of the path needs a value, even if it isn't described
+        for variable in path_variables {
+            if !path_params.iter().any(|param| &param.name == variable) {
+                path_params.push(AddPathParamParams {
+                    name: variable.clone(),
+                    value: json!(""),
+                    order: path_params.len() as isize,
+                    description: None,
+                    options: PathParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                });
+            }
+        }
+
+        path_params
+    }
+
+    fn query_params(&mut self, query: &JsonValue, pointer: &str) -> Vec<AddQueryParamParams> {
+        key_values(query)
+            .enumerate()
+            .map(|(index, (key, value))| {
+                let pointer = format!("{}/query/{}", pointer, index);
+                AddQueryParamParams {
+                    name: self.template(key, &pointer),
+                    value: self.template_value(value.get("value"), &pointer),
+                    order: index as isize,
+                    description: description_text(value.get("description")),
+                    options: QueryParamOptions {
+                        disabled: is_disabled(value),
+                        propagate: false,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// The query of urls written as strings, which have no list of params.
+    fn raw_query_params(&mut self, url: &str, pointer: &str) -> Vec<AddQueryParamParams> {
+        let Some((_, query)) = url.split('#').next().unwrap_or_default().split_once('?') else {
+            return Vec::new();
+        };
+
+        query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .enumerate()
+            .map(|(order, pair)| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                AddQueryParamParams {
+                    name: self.template(key, pointer),
+                    value: json!(self.template(value, pointer)),
+                    order: order as isize,
+                    description: None,
+                    options: QueryParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn headers(
+        &mut self,
+        headers: Option<&JsonValue>,
+        pointer: &str,
+        propagate: bool,
+    ) -> Vec<AddHeaderParams> {
+        let pointer = format!("{}/header", pointer);
+        match headers {
+            Some(headers @ JsonValue::Array(_)) => key_values(headers)
+                .enumerate()
+                .map(|(index, (key, value))| {
+                    let pointer = format!("{}/{}", pointer, index);
+                    AddHeaderParams {
+                        name: self.template(key, &pointer),
+                        value: self.template_value(value.get("value"), &pointer),
+                        order: index as isize,
+                        description: description_text(value.get("description")),
+                        options: HeaderParamOptions {
+                            disabled: is_disabled(value),
+                            propagate,
+                        },
+                    }
+                })
+                .collect(),
+            // Headers can also be written the way they are sent
+            Some(JsonValue::String(headers)) => headers
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .enumerate()
+                .map(|(order, (key, value))| AddHeaderParams {
+                    name: self.template(key.trim(), &pointer),
+                    value: json!(self.template(value.trim(), &pointer)),
+                    order: order as isize,
+                    description: None,
+                    options: HeaderParamOptions {
+                        disabled: false,
+                        propagate,
+                    },
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn body(&mut self, body: Option<&JsonValue>, pointer: &str) -> Option<AddBodyParams> {
+        let body = body.filter(|body| body.is_object())?;
+        if body.get("disabled").and_then(JsonValue::as_bool) == Some(true) {
+            return None;
+        }
+        let pointer = format!("{}/body", pointer);
+
+        match body.get("mode").and_then(JsonValue::as_str)? {
+            "raw" => {
+                let raw = body
+                    .get("raw")
+                    .and_then(JsonValue::as_str)
+                    .unwrap_or_default();
+                if raw.trim().is_empty() {
+                    return None;
+                }
+                let raw = self.template(raw, &pointer);
+
+                let language = body
+                    .pointer("/options/raw/language")
+                    .and_then(JsonValue::as_str);
+                Some(match language {
+                    // Variables standing for whole values make the body invalid json
+                    Some("json") => serde_json::from_str(&raw)
+                        .map(AddBodyParams::Json)
+                        .unwrap_or(AddBodyParams::Text(raw)),
+                    Some("xml") => AddBodyParams::Xml(raw),
+                    _ => AddBodyParams::Text(raw),
+                })
+            }
+            "urlencoded" => {
+                let fields = body.get("urlencoded")?;
+                Some(AddBodyParams::Urlencoded(
+                    key_values(fields)
+                        .enumerate()
+                        .map(|(index, (key, value))| {
+                            let pointer = format!("{}/urlencoded/{}", pointer, index);
+                            AddUrlencodedParamParams {
+                                name: self.template(key, &pointer),
+                                value: self.template_value(value.get("value"), &pointer),
+                                order: index as isize,
+                                description: description_text(value.get("description")),
+                                options: UrlencodedParamOptions {
+                                    disabled: is_disabled(value),
+                                    propagate: false,
+                                },
+                                id: None,
+                            }
+                        })
+                        .collect(),
+                ))
+            }
+            "formdata" => {
+                let fields = body.get("formdata")?;
+                let mut params = Vec::new();
+                for (index, (key, value)) in key_values(fields).enumerate() {
+                    let pointer = format!("{}/formdata/{}", pointer, index);
+                    if value.get("type").and_then(JsonValue::as_str) == Some("file") {
+                        self.skip(pointer, "files of form data bodies are not supported");
+                        continue;
+                    }
+
+                    params.push(AddFormDataParamParams {
+                        name: self.template(key, &pointer),
+                        value: self.template_value(value.get("value"), &pointer),
+                        order: params.len() as isize,
+                        description: description_text(value.get("description")),
+                        options: FormDataParamOptions {
+                            disabled: is_disabled(value),
+                            propagate: false,
+                        },
+                        id: None,
+                    });
+                }
+                Some(AddBodyParams::FormData(params))
+            }
+            "file" => {
+                let src = body.pointer("/file/src").and_then(JsonValue::as_str)?;
+                Some(AddBodyParams::Binary(PathBuf::from(src)))
+            }
+            // GraphQL requests are sent as json over http
+            "graphql" => {
+                let query = body
+                    .pointer("/graphql/query")
+                    .and_then(JsonValue::as_str)
+                    .unwrap_or_default();
+                let variables = body
+                    .pointer("/graphql/variables")
+                    .and_then(JsonValue::as_str)
+                    .filter(|variables| !variables.trim().is_empty())
+                    .and_then(|variables| serde_json::from_str::<JsonValue>(variables).ok())
+                    .unwrap_or_else(|| json!({}));
+                Some(AddBodyParams::Json(json!({
+                    "query": self.template(query, &pointer),
+                    "variables": variables,
+                })))
+            }
+            mode => {
+                self.skip(pointer, format!("`{}` bodies are not supported", mode));
+                None
+            }
+        }
+    }
+
+    /// An absent auth, or one inherited from the parent, is the same as no auth at all.
+    fn auth(&mut self, auth: Option<&JsonValue>, pointer: &str, owner: &str) -> Option<AuthScheme> {
+        let auth = auth?.as_object()?;
+        let kind = auth.get("type").and_then(JsonValue::as_str)?;
+        let pointer = format!("{}/auth", pointer);
+
+        let attributes = auth
+            .get(kind)
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|attribute| {
+                let key = attribute.get("key").and_then(JsonValue::as_str)?;
+                let value = match attribute.get("value") {
+                    Some(JsonValue::String(value)) => value.clone(),
+                    Some(JsonValue::Null) | None => String::new(),
+                    Some(value) => value.to_string(),
+                };
+                Some((key.to_string(), value))
+            })
+            .collect::<HashMap<_, _>>();
+        let attribute = |converter: &mut Self, key: &str| {
+            let value = attributes.get(key).map(String::as_str).unwrap_or_default();
+            converter.template(value, &pointer)
+        };
+
+        let scheme = match kind {
+            "noauth" => AuthScheme::None,
+            "inherit" => return None,
+            "basic" | "digest" => {
+                let username = attribute(self, "username");
+                let password = attribute(self, "password");
+                let password = self.secret(password, owner, "password");
+                if kind == "basic" {
+                    AuthScheme::Basic { username, password }
+                } else {
+                    AuthScheme::Digest { username, password }
+                }
+            }
+            "bearer" => {
+                let token = attribute(self, "token");
+                AuthScheme::Bearer {
+                    token: self.secret(token, owner, "token"),
+                }
+            }
+            "apikey" => {
+                let key = attribute(self, "key");
+                let value = attribute(self, "value");
+                AuthScheme::ApiKey {
+                    key,
+                    value: self.secret(value, owner, "api key"),
+                    location: if attribute(self, "in") == "query" {
+                        ApiKeyLocation::Query
+                    } else {
+                        ApiKeyLocation::Header
+                    },
+                }
+            }
+            "oauth2" => {
+                let grant = match attribute(self, "grant_type").as_str() {
+                    // The grant Postman uses by default
+                    "" | "authorization_code" | "authorization_code_with_pkce" => {
+                        OAuth2Grant::AuthorizationCode
+                    }
+                    "client_credentials" => OAuth2Grant::ClientCredentials,
+                    grant => {
+                        self.skip(
+                            pointer,
+                            format!("the `{}` oauth2 grant is not supported", grant),
+                        );
+                        return None;
+                    }
+                };
+                let client_secret = Some(attribute(self, "clientSecret"))
+                    .filter(|secret| !secret.is_empty())
+                    .map(|secret| self.secret(secret, owner, "client secret"));
+
+                let config = OAuth2Config {
+                    grant,
+                    auth_url: Some(attribute(self, "authUrl")).filter(|url| !url.is_empty()),
+                    token_url: attribute(self, "accessTokenUrl"),
+                    device_auth_url: None,
+                    client_id: attribute(self, "clientId"),
+                    client_secret,
+                    scopes: attribute(self, "scope")
+                        .split_whitespace()
+                        .map(str::to_string)
+                        .collect(),
+                    refresh_token: None,
+                    callback_port: None,
+                };
+                if let Err(reason) = config.check_grant() {
+                    self.skip(pointer, reason);
+                    return None;
+                }
+
+                AuthScheme::OAuth2(config)
+            }
+            "awsv4" => {
+                let access_key = attribute(self, "accessKey");
+                let secret_key = attribute(self, "secretKey");
+                let session_token =
+                    Some(attribute(self, "sessionToken")).filter(|token| !token.is_empty());
+
+                AuthScheme::AwsSigV4(AwsSigV4Config {
+                    access_key: self.secret(access_key, owner, "access key"),
+                    secret_key: self.secret(secret_key, owner, "secret key"),
+                    session_token: session_token
+                        .map(|token| self.secret(token, owner, "session token")),
+                    region: attribute(self, "region"),
+                    service: attribute(self, "service"),
+                })
+            }
+            kind => {
+                self.skip(pointer, format!("`{}` auth is not supported", kind));
+                return None;
+            }
+        };
+
+        Some(scheme)
+    }
+
+    /// Secrets of auths have to reference variables, so the ones written inline
+    /// are moved to the environment of the collection as local values.
+    fn secret(&mut self, value: String, owner: &str, field: &str) -> String {
+        if is_variable_reference(&value) {
+            return value;
+        }
+
+        let variable = environment_variable(
+            &mut self.variable_names,
+            &format!("{} {}", owner, field),
+            Some(&json!(value)),
+            true,
+            false,
+            Some(format!("The {} of `{}`.", field, owner)),
+        );
+        let reference = format!("{{{{{}}}}}", variable.name);
+        self.variables.push(AddVariableParams {
+            order: self.variables.len() as isize,
+            ..variable
+        });
+
+        reference
+    }
+}
+
+/// The url of a request, which is either a string or an object whose `raw` field is optional.
+fn raw_url(url: &JsonValue) -> String {
+    if let Some(raw) = url.as_str().or_else(|| url.get("raw")?.as_str()) {
+        return raw.trim().to_string();
+    }
+
+    let join = |key: &str, separator: &str| match url.get(key) {
+        Some(JsonValue::Array(parts)) => parts
+            .iter()
+            .filter_map(JsonValue::as_str)
+            .collect::<Vec<_>>()
+            .join(separator),
+        Some(JsonValue::String(part)) => part.clone(),
+        _ => String::new(),
+    };
+
+    let mut raw = String::new();
+    if let Some(protocol) = url.get("protocol").and_then(JsonValue::as_str) {
+        raw.push_str(protocol);
+        raw.push_str("://");
+    }
+    raw.push_str(&join("host", "."));
+    if let Some(port) = url.get("port").and_then(JsonValue::as_str) {
+        raw.push(':');
+        raw.push_str(port);
+    }
+    let path = join("path", "/");
+    if !path.is_empty() {
+        raw.push('/');
+        raw.push_str(path.trim_start_matches('/'));
+    }
+
+    raw
+}
+
+/// Entries of a list of key-value pairs, such as the headers or the query of a request.
+fn key_values(values: &JsonValue) -> impl Iterator<Item = (&str, &JsonValue)> {
+    values
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|value| Some((value.get("key")?.as_str()?, value)))
+}
+
+fn is_disabled(value: &JsonValue) -> bool {
+    value
+        .get("disabled")
+        .and_then(JsonValue::as_bool)
+        .unwrap_or(false)
+}
+
+/// Descriptions are either plain strings or objects holding their content and format.
+fn description_text(description: Option<&JsonValue>) -> Option<String> {
+    let text = match description? {
+        JsonValue::String(text) => text.as_str(),
+        description => description.get("content")?.as_str()?,
+    };
+
+    Some(text.trim())
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    fn collection(items: JsonValue) -> JsonValue {
+        json!({
+            "info": {
+                "name": "Petstore",
+                "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+            },
+            "item": items
+        })
+    }
+
+    fn items(imported: &ImportedResources) -> Vec<&CreateItemResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Item(item) => Some(item),
+                BatchCreateResourceKind::Dir(_) => None,
+            })
+            .collect()
+    }
+
+    fn dirs(imported: &ImportedResources) -> Vec<&CreateDirResourceParams> {
+        imported
+            .resources
+            .iter()
+            .filter_map(|resource| match resource {
+                BatchCreateResourceKind::Dir(dir) => Some(dir),
+                BatchCreateResourceKind::Item(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rejects_other_documents() {
+        assert!(convert(&json!({ "openapi": "3.1.0" })).is_err());
+        assert!(
+            convert(&json!({
+                "info": { "schema": "https://schema.getpostman.com/json/collection/v1.0.0/collection.json" }
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_folders_and_requests() {
+        let imported = convert(&collection(json!([
+            {
+                "name": "Pets",
+                "item": [
+                    {
+                        "name": "Get pet",
+                        "request": {
+                            "method": "GET",
+                            "url": {
+                                "raw": "{{base-url}}/pets/:pet-id?fields=all&limit={{limit}}",
+                                "query": [
+                                    { "key": "fields", "value": "all" },
+                                    { "key": "limit", "value": "{{limit}}", "disabled": true }
+                                ],
+                                "variable": [{ "key": "pet-id", "value": "7", "description": "Id of the pet" }]
+                            },
+                            "header": [{ "key": "X-Trace", "value": "{{$guid}}" }]
+                        }
+                    }
+                ]
+            },
+            { "name": "Health", "request": "https://example.com/health?verbose" }
+        ])))
+        .unwrap();
+
+        let dirs = dirs(&imported);
+        assert_eq!(dirs.len(), 1);
+        assert_eq!(dirs[0].name, "Pets");
+
+        let items = items(&imported);
+        assert_eq!(items[0].path, PathBuf::from("Pets"));
+        assert_eq!(items[0].protocol, Some(ResourceProtocol::Get));
+        assert_eq!(items[0].url.as_deref(), Some("{{base_url}}/pets/:pet_id"));
+        assert_eq!(items[0].path_params[0].name, "pet_id");
+        assert_eq!(items[0].path_params[0].value, json!("7"));
+        assert_eq!(
+            items[0].path_params[0].description.as_deref(),
+            Some("Id of the pet")
+        );
+        assert!(items[0].query_params[1].options.disabled);
+        assert_eq!(items[0].query_params[1].value, json!("{{limit}}"));
+        assert_eq!(items[0].headers[0].value, json!("{{guid}}"));
+
+        assert_eq!(items[1].path, PathBuf::new());
+        assert_eq!(items[1].url.as_deref(), Some("https://example.com/health"));
+        assert_eq!(items[1].query_params[0].name, "verbose");
+
+        assert_eq!(
+            imported.skipped,
+            vec![ImportSkippedItem {
+                pointer: "/item/0/item/0/request/header/0".to_string(),
+                reason: "dynamic variable `{{$guid}}` is not supported, set `guid` in an environment instead".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_bodies() {
+        let request = |body: JsonValue| json!({ "name": "Create", "request": { "method": "POST", "url": "https://example.com", "body": body } });
+        let imported = convert(&collection(json!([
+            request(json!({ "mode": "raw", "raw": "{ \"name\": \"{{name}}\" }", "options": { "raw": { "language": "json" } } })),
+            request(json!({ "mode": "raw", "raw": "{ \"age\": {{age}} }", "options": { "raw": { "language": "json" } } })),
+            request(json!({ "mode": "urlencoded", "urlencoded": [{ "key": "name", "value": "Rex", "disabled": true }] })),
+            request(json!({ "mode": "formdata", "formdata": [
+                { "key": "photo", "type": "file", "src": "/tmp/rex.png" },
+                { "key": "name", "value": "Rex", "type": "text" }
+            ] })),
+            request(json!({ "mode": "graphql", "graphql": { "query": "{ pets { name } }", "variables": "{ \"limit\": 1 }" } })),
+        ])))
+        .unwrap();
+
+        let bodies = items(&imported)
+            .into_iter()
+            .map(|item| item.body.clone())
+            .collect::<Vec<_>>();
+
+        let Some(AddBodyParams::Json(body)) = &bodies[0] else {
+            panic!("expected a json body");
+        };
+        assert_eq!(body, &json!({ "name": "{{name}}" }));
+        assert!(
+            matches!(&bodies[1], Some(AddBodyParams::Text(text)) if text == "{ \"age\": {{age}} }")
+        );
+        let Some(AddBodyParams::Urlencoded(fields)) = &bodies[2] else {
+            panic!("expected an urlencoded body");
+        };
+        assert!(fields[0].options.disabled);
+        let Some(AddBodyParams::FormData(fields)) = &bodies[3] else {
+            panic!("expected a form data body");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, "name");
+        let Some(AddBodyParams::Json(body)) = &bodies[4] else {
+            panic!("expected a json body");
+        };
+        assert_eq!(
+            body,
+            &json!({ "query": "{ pets { name } }", "variables": { "limit": 1 } })
+        );
+
+        assert_eq!(imported.skipped.len(), 1);
+        assert_eq!(
+            imported.skipped[0].pointer,
+            "/item/3/request/body/formdata/0"
+        );
+    }
+
+    #[test]
+    fn test_auth_and_variables() {
+        let mut document = collection(json!([
+            {
+                "name": "Admin",
+                "auth": {
+                    "type": "apikey",
+                    "apikey": [
+                        { "key": "key", "value": "X-Api-Key" },
+                        { "key": "value", "value": "{{api-key}}" },
+                        { "key": "in", "value": "header" }
+                    ]
+                },
+                "item": [
+                    {
+                        "name": "Public",
+                        "request": { "method": "GET", "url": "https://example.com", "auth": { "type": "noauth" } }
+                    }
+                ]
+            }
+        ]));
+        document["auth"] = json!({
+            "type": "basic",
+            "basic": [{ "key": "username", "value": "admin" }, { "key": "password", "value": "hunter2" }]
+        });
+        document["variable"] = json!([{ "key": "api-key", "value": "abc", "type": "secret" }]);
+        document["event"] =
+            json!([{ "listen": "prerequest", "script": { "exec": ["console.log(1);"] } }]);
+
+        let imported = convert(&document).unwrap();
+
+        let dirs = dirs(&imported);
+        assert_eq!(dirs[0].name, "Petstore");
+        assert_eq!(
+            dirs[0].description.as_deref(),
+            Some("Pre-request script:\n```js\nconsole.log(1);\n```")
+        );
+        assert_eq!(
+            dirs[0].auth,
+            Some(AuthScheme::Basic {
+                username: "admin".to_string(),
+                password: "{{Petstore_password}}".to_string(),
+            })
+        );
+        assert_eq!(dirs[1].path, PathBuf::from("Petstore"));
+        assert_eq!(
+            dirs[1].auth,
+            Some(AuthScheme::ApiKey {
+                key: "X-Api-Key".to_string(),
+                value: "{{api_key}}".to_string(),
+                location: ApiKeyLocation::Header,
+            })
+        );
+        assert_eq!(items(&imported)[0].auth, Some(AuthScheme::None));
+
+        let environment = &imported.environments[0];
+        assert_eq!(environment.name, "Petstore");
+        let variables = environment
+            .variables
+            .iter()
+            .map(|variable| {
+                (
+                    variable.name.as_str(),
+                    variable.global_value.clone(),
+                    variable.local_value.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            variables,
+            vec![
+                ("api_key", json!(""), json!("abc")),
+                ("Petstore_password", json!(""), json!("hunter2")),
+            ]
+        );
+
+        assert_eq!(imported.skipped[0].pointer, "/event/0");
+    }
+
+    #[test]
+    fn test_convert_environment() {
+        let environment = convert_environment(&json!({
+            "name": "Staging",
+            "values": [
+                { "key": "base-url", "value": "https://staging.example.com", "enabled": true },
+                { "key": "token", "value": "secret", "type": "secret", "enabled": false }
+            ],
+            "_postman_variable_scope": "environment"
+        }))
+        .unwrap();
+
+        assert_eq!(environment.name, "Staging");
+        assert_eq!(environment.variables[0].name, "base_url");
+        assert_eq!(
+            environment.variables[0].global_value,
+            json!("https://staging.example.com")
+        );
+        assert_eq!(environment.variables[1].order, 1);
+        assert_eq!(environment.variables[1].global_value, json!(""));
+        assert_eq!(environment.variables[1].local_value, json!("secret"));
+        assert!(environment.variables[1].options.disabled);
+
+        assert!(convert_environment(&json!({ "name": "Staging" })).is_err());
+    }
+}
@@ -0,0 +1,515 @@
+pub mod sample;
+
+use http_body_util::Full;
+use hyper::{
+    Method, Request, Response, StatusCode,
+    body::{Bytes, Incoming},
+    header::{CONTENT_TYPE, HeaderName, HeaderValue},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use joinerror::ResultExt;
+use moss_project::models::{primitives::CaseId, types::CaseInfo};
+use sapic_base::resource::types::primitives::ResourceId;
+use sapic_core::context::AnyAsyncContext;
+use sapic_ipc::contracts::main::mock::{MockServerEvent, StartMockServerOutput};
+use serde_json::Value as JsonValue;
+use std::{
+    convert::Infallible,
+    net::Ipv4Addr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use template_parser::{
+    UrlParser,
+    models::types::{ParsedValue, ValueList},
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    task::JoinSet,
+};
+
+use crate::resource::value_to_string;
+
+/// An endpoint resource served by the mock server.
+#[derive(Debug, Clone)]
+pub struct MockRoute {
+    pub resource_id: ResourceId,
+    pub method: Method,
+    /// Path part of the endpoint url, without its leading and trailing slashes.
+    path: ValueList,
+    pub cases: Vec<CaseInfo>,
+    /// The case to respond with. When unset, the case whose path params match the
+    /// request is chosen, falling back to the first case with an expected response.
+    pub case_id: Option<CaseId>,
+    /// Schema the response body is synthesized from, when no case has an expected response.
+    pub schema: Option<JsonValue>,
+    pub status: Option<u16>,
+    pub latency: Option<Duration>,
+}
+
+impl MockRoute {
+    /// Creates a route matching the path of the url, where every `:name` segment
+    /// matches any value. Fails for urls the template parser rejects.
+    pub fn new(resource_id: ResourceId, method: Method, url: &str) -> joinerror::Result<Self> {
+        let parsed = UrlParser::parse_url(url.trim())?;
+
+        let mut path = parsed.path_part;
+        if let Some(ParsedValue::String(last)) = path.last_mut() {
+            last.truncate(last.trim_end_matches('/').len());
+            if last.is_empty() {
+                path.pop();
+            }
+        }
+
+        Ok(Self {
+            resource_id,
+            method,
+            path,
+            cases: Vec::new(),
+            case_id: None,
+            schema: None,
+            status: None,
+            latency: None,
+        })
+    }
+
+    /// Matches a request path against the route, returning the values of its path variables.
+    pub fn match_path(&self, path: &str) -> Option<Vec<(String, String)>> {
+        let path = path.trim_matches('/');
+        let mut params = Vec::new();
+
+        match_values(&self.path, path, &mut params).then_some(params)
+    }
+
+    /// Number of literal characters of the route, so that `/users/me` is preferred
+    /// over `/users/:id`.
+    fn specificity(&self) -> usize {
+        self.path
+            .iter()
+            .map(|value| match value {
+                ParsedValue::String(s) => s.len(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn case(&self, params: &[(String, String)]) -> Option<&CaseInfo> {
+        if let Some(id) = &self.case_id {
+            return self.cases.iter().find(|c| &c.id == id);
+        }
+
+        let candidates = self
+            .cases
+            .iter()
+            .filter(|c| c.expected_response.is_some())
+            .collect::<Vec<_>>();
+        let matching = candidates.iter().find(|c| {
+            let case_params = c
+                .path_params
+                .iter()
+                .filter(|p| !p.disabled)
+                .collect::<Vec<_>>();
+            !case_params.is_empty()
+                && case_params.iter().all(|p| {
+                    params
+                        .iter()
+                        .any(|(name, value)| name == &p.name && value == &value_to_string(&p.value))
+                })
+        });
+
+        matching.or(candidates.first()).copied()
+    }
+}
+
+fn match_values(values: &[ParsedValue], path: &str, params: &mut Vec<(String, String)>) -> bool {
+    match values.split_first() {
+        None => path.is_empty(),
+        Some((ParsedValue::String(literal), rest)) => path
+            .strip_prefix(literal.as_str())
+            .is_some_and(|path| match_values(rest, path, params)),
+        Some((ParsedValue::PathVariable(name), rest)) => {
+            // A variable spans at most one segment, the longest value that lets the rest match wins
+            let segment_end = path.find('/').unwrap_or(path.len());
+            for end in (1..=segment_end)
+                .rev()
+                .filter(|&end| path.is_char_boundary(end))
+            {
+                params.push((name.clone(), path[..end].to_string()));
+                if match_values(rest, &path[end..], params) {
+                    return true;
+                }
+                params.pop();
+            }
+
+            false
+        }
+        // Environment variables cannot appear in the path of a url
+        Some((ParsedValue::Variable(_), _)) => false,
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MockServerOptions {
+    /// Port to listen on, on the loopback interface. A free one is picked when unset.
+    pub port: Option<u16>,
+    pub latency: Duration,
+    pub status: Option<u16>,
+    pub log_requests: bool,
+}
+
+type EventHandler = Box<dyn Fn(MockServerEvent) -> joinerror::Result<()> + Send + Sync>;
+
+struct MockServer {
+    routes: Vec<MockRoute>,
+    options: MockServerOptions,
+    on_event: EventHandler,
+    requests: AtomicU64,
+}
+
+/// A response of the mock server, along with what it was produced from.
+struct MockResponse {
+    status: StatusCode,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    resource_id: Option<ResourceId>,
+    case_id: Option<CaseId>,
+}
+
+impl MockResponse {
+    fn error(status: StatusCode, message: String) -> Self {
+        Self {
+            status,
+            headers: vec![(CONTENT_TYPE.to_string(), "application/json".to_string())],
+            body: serde_json::json!({ "error": message })
+                .to_string()
+                .into_bytes(),
+            resource_id: None,
+            case_id: None,
+        }
+    }
+}
+
+/// Serves the routes on the loopback interface and reports to `on_event` when the server
+/// is listening, then every request it serves if requests are logged.
+///
+/// Runs until the context is canceled or its deadline is exceeded.
+pub async fn serve<F>(
+    ctx: &dyn AnyAsyncContext,
+    mut routes: Vec<MockRoute>,
+    options: MockServerOptions,
+    on_event: F,
+) -> joinerror::Result<StartMockServerOutput>
+where
+    F: Fn(MockServerEvent) -> joinerror::Result<()> + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, options.port.unwrap_or(0)))
+        .await
+        .join_err_with::<()>(|| {
+            format!(
+                "failed to bind the mock server to port {}",
+                options.port.unwrap_or(0)
+            )
+        })?;
+    let port = listener
+        .local_addr()
+        .join_err::<()>("failed to get the address of the mock server")?
+        .port();
+    on_event(MockServerEvent::Started { port })?;
+
+    // Stable, so that routes equally specific keep the order of the project
+    routes.sort_by_key(|route| std::cmp::Reverse(route.specificity()));
+    let server = Arc::new(MockServer {
+        routes,
+        options,
+        on_event: Box::new(on_event),
+        requests: AtomicU64::new(0),
+    });
+
+    let cancellation = ctx.cancellation();
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            _ = cancellation.wait() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(server.clone().serve_connection(stream));
+                }
+                Err(e) => tracing::warn!("failed to accept a mock server connection: {}", e),
+            },
+        }
+
+        while connections.try_join_next().is_some() {}
+    }
+    connections.shutdown().await;
+
+    Ok(StartMockServerOutput {
+        requests: server.requests.load(Ordering::Relaxed),
+    })
+}
+
+impl MockServer {
+    async fn serve_connection(self: Arc<Self>, stream: TcpStream) {
+        let service = service_fn(|request| {
+            let server = self.clone();
+            async move { Ok::<_, Infallible>(server.handle(request).await) }
+        });
+
+        if let Err(e) = http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), service)
+            .await
+        {
+            tracing::debug!("mock server connection closed: {}", e);
+        }
+    }
+
+    async fn handle(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        let started_at = Instant::now();
+        let method = request.method().clone();
+        let path = request
+            .uri()
+            .path_and_query()
+            .map_or("/", |p| p.as_str())
+            .to_string();
+
+        let (response, latency) = self.respond(&method, request.uri().path());
+        tokio::time::sleep(latency).await;
+
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if self.options.log_requests {
+            let event = MockServerEvent::Request {
+                method: method.to_string(),
+                path,
+                status: response.status.as_u16(),
+                resource_id: response.resource_id.clone(),
+                case_id: response.case_id.as_ref().map(|id| id.to_string()),
+                duration_ms: started_at.elapsed().as_millis() as u64,
+            };
+            if let Err(e) = (self.on_event)(event) {
+                tracing::warn!("failed to report a mock server request: {}", e);
+            }
+        }
+
+        let mut http_response = Response::new(Full::new(Bytes::from(response.body)));
+        *http_response.status_mut() = response.status;
+        for (name, value) in &response.headers {
+            match (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(value)) => {
+                    http_response.headers_mut().append(name, value);
+                }
+                _ => tracing::warn!("skipping invalid mock response header `{}`", name),
+            }
+        }
+
+        http_response
+    }
+
+    fn respond(&self, method: &Method, path: &str) -> (MockResponse, Duration) {
+        let path = urlencoding::decode(path).map_or_else(|_| path.to_string(), |p| p.into_owned());
+
+        let mut path_matched = false;
+        let routed = self.routes.iter().find_map(|route| {
+            let params = route.match_path(&path)?;
+            path_matched = true;
+            (route.method == *method).then_some((route, params))
+        });
+
+        let Some((route, params)) = routed else {
+            let response = if path_matched {
+                MockResponse::error(
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    format!("no endpoint accepts `{}` at `{}`", method, path),
+                )
+            } else {
+                MockResponse::error(
+                    StatusCode::NOT_FOUND,
+                    format!("no endpoint matches `{} {}`", method, path),
+                )
+            };
+
+            return (response, self.options.latency);
+        };
+
+        let mut response = match route.case(&params) {
+            Some(case) => case_response(case),
+            None => schema_response(route.schema.as_ref()),
+        };
+        response.resource_id = Some(route.resource_id.clone());
+
+        if let Some(status) = route.status.or(self.options.status) {
+            response.status = status_code(status);
+        }
+
+        (response, route.latency.unwrap_or(self.options.latency))
+    }
+}
+
+fn case_response(case: &CaseInfo) -> MockResponse {
+    let mut response = MockResponse {
+        status: StatusCode::OK,
+        headers: Vec::new(),
+        body: Vec::new(),
+        resource_id: None,
+        case_id: Some(case.id.clone()),
+    };
+    let Some(expected) = &case.expected_response else {
+        return response;
+    };
+
+    if let Some(status) = expected.status {
+        response.status = status_code(status);
+    }
+    if let Some(headers) = &expected.headers {
+        response.headers = headers
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+    }
+
+    let (body, content_type) = match &expected.body {
+        None | Some(JsonValue::Null) => return response,
+        Some(JsonValue::String(text)) => (text.clone().into_bytes(), "text/plain"),
+        Some(json) => (json.to_string().into_bytes(), "application/json"),
+    };
+    response.body = body;
+    if !response
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(CONTENT_TYPE.as_str()))
+    {
+        response
+            .headers
+            .push((CONTENT_TYPE.to_string(), content_type.to_string()));
+    }
+
+    response
+}
+
+fn schema_response(schema: Option<&JsonValue>) -> MockResponse {
+    let Some(schema) = schema else {
+        return MockResponse {
+            status: StatusCode::OK,
+            headers: Vec::new(),
+            body: Vec::new(),
+            resource_id: None,
+            case_id: None,
+        };
+    };
+
+    MockResponse {
+        status: StatusCode::OK,
+        headers: vec![(CONTENT_TYPE.to_string(), "application/json".to_string())],
+        body: sample::sample(schema).to_string().into_bytes(),
+        resource_id: None,
+        case_id: None,
+    }
+}
+
+fn status_code(status: u16) -> StatusCode {
+    StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+#[cfg(test)]
+mod tests {
+    use moss_project::models::{
+        primitives::PathParamId,
+        types::{ExpectedResponse, PathParamInfo},
+    };
+    use serde_json::json;
+
+    use super::*;
+
+    fn route(url: &str) -> MockRoute {
+        MockRoute::new(ResourceId::from("resource".to_string()), Method::GET, url).unwrap()
+    }
+
+    fn case(id: &str, user_id: Option<&str>) -> CaseInfo {
+        CaseInfo {
+            id: CaseId::from(id.to_string()),
+            name: id.to_string(),
+            description: None,
+            headers: vec![],
+            path_params: user_id
+                .map(|value| PathParamInfo {
+                    id: PathParamId::from("p1".to_string()),
+                    name: "id".to_string(),
+                    value: json!(value),
+                    description: None,
+                    disabled: false,
+                    propagate: false,
+                    order: Some(0),
+                    inherited_from: None,
+                    component: None,
+                })
+                .into_iter()
+                .collect(),
+            query_params: vec![],
+            body: None,
+            expected_response: Some(ExpectedResponse {
+                status: Some(200),
+                headers: None,
+                body: Some(json!({ "case": id })),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_match_path() {
+        let route = route("{{baseUrl}}/users/:id/posts/:post/");
+
+        assert_eq!(
+            route.match_path("/users/42/posts/7"),
+            Some(vec![
+                ("id".to_string(), "42".to_string()),
+                ("post".to_string(), "7".to_string()),
+            ])
+        );
+        assert_eq!(
+            route.match_path("/users/42/posts/7/").map(|p| p.len()),
+            Some(2)
+        );
+        assert_eq!(route.match_path("/users/42/posts"), None);
+        assert_eq!(route.match_path("/users/42/7/posts/1"), None);
+    }
+
+    #[test]
+    fn test_match_path_variable_within_segment() {
+        let route = route("http://localhost:8080/files/:name.:ext");
+
+        assert_eq!(
+            route.match_path("/files/report.final.pdf"),
+            Some(vec![
+                ("name".to_string(), "report.final".to_string()),
+                ("ext".to_string(), "pdf".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_specificity() {
+        assert!(
+            route("http://host/users/me").specificity()
+                > route("http://host/users/:id").specificity()
+        );
+    }
+
+    #[test]
+    fn test_case_selection() {
+        let mut route = route("http://host/users/:id");
+        route.cases = vec![case("any", None), case("admin", Some("1"))];
+
+        let params = |id: &str| vec![("id".to_string(), id.to_string())];
+        assert_eq!(route.case(&params("1")).unwrap().name, "admin");
+        assert_eq!(route.case(&params("2")).unwrap().name, "any");
+
+        route.case_id = Some(CaseId::from("admin".to_string()));
+        assert_eq!(route.case(&params("2")).unwrap().name, "admin");
+    }
+}
@@ -0,0 +1,268 @@
+//! Synthesizes a value satisfying a JSON Schema, for endpoints that have no example to respond with.
+//!
+//! Values given by the schema itself (`const`, `examples`, `default`, `enum`) are used as is.
+//! Otherwise the simplest value of the type is built: every property of an object is filled
+//! and arrays hold as many items as they must.
+
+use serde_json::{Map, Value as JsonValue};
+
+// Protects against reference cycles such as `{ "$ref": "#" }`
+const MAX_DEPTH: usize = 32;
+
+/// Returns a sample value for `schema`, resolving references local to it.
+pub fn sample(schema: &JsonValue) -> JsonValue {
+    Sampler { root: schema }.sample(schema, 0)
+}
+
+struct Sampler<'a> {
+    root: &'a JsonValue,
+}
+
+impl<'a> Sampler<'a> {
+    fn sample(&self, schema: &'a JsonValue, depth: usize) -> JsonValue {
+        let JsonValue::Object(schema) = schema else {
+            return JsonValue::Null;
+        };
+        if depth > MAX_DEPTH {
+            return JsonValue::Null;
+        }
+
+        if let Some(value) = given_value(schema) {
+            return value.clone();
+        }
+
+        if let Some(target) = schema
+            .get("$ref")
+            .and_then(JsonValue::as_str)
+            .and_then(|reference| self.resolve(reference))
+        {
+            return self.sample(target, depth + 1);
+        }
+
+        if let Some(JsonValue::Array(schemas)) = schema.get("allOf") {
+            // The samples of the subschemas are merged, which is only meaningful for objects
+            let mut merged = JsonValue::Null;
+            for subschema in schemas {
+                merged = match (merged, self.sample(subschema, depth + 1)) {
+                    (JsonValue::Object(mut fields), JsonValue::Object(other)) => {
+                        fields.extend(other);
+                        JsonValue::Object(fields)
+                    }
+                    (JsonValue::Null, other) => other,
+                    (merged, _) => merged,
+                };
+            }
+
+            return merged;
+        }
+
+        for keyword in ["oneOf", "anyOf"] {
+            if let Some(first) = schema
+                .get(keyword)
+                .and_then(JsonValue::as_array)
+                .and_then(|schemas| schemas.first())
+            {
+                return self.sample(first, depth + 1);
+            }
+        }
+
+        match schema_type(schema) {
+            Some("object") => self.sample_object(schema, depth),
+            Some("array") => self.sample_array(schema, depth),
+            Some("string") => sample_string(schema),
+            Some("integer") => sample_number(schema, true),
+            Some("number") => sample_number(schema, false),
+            Some("boolean") => JsonValue::Bool(true),
+            _ => JsonValue::Null,
+        }
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&'a JsonValue> {
+        let fragment = reference.strip_prefix('#')?;
+        self.root.pointer(fragment)
+    }
+
+    fn sample_object(&self, schema: &'a Map<String, JsonValue>, depth: usize) -> JsonValue {
+        let fields = schema
+            .get("properties")
+            .and_then(JsonValue::as_object)
+            .map(|properties| {
+                properties
+                    .iter()
+                    .map(|(name, property)| (name.clone(), self.sample(property, depth + 1)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        JsonValue::Object(fields)
+    }
+
+    fn sample_array(&self, schema: &'a Map<String, JsonValue>, depth: usize) -> JsonValue {
+        let mut items = schema
+            .get("prefixItems")
+            .and_then(JsonValue::as_array)
+            .map(|schemas| {
+                schemas
+                    .iter()
+                    .map(|item| self.sample(item, depth + 1))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let min_items = schema
+            .get("minItems")
+            .and_then(JsonValue::as_u64)
+            .unwrap_or(1) as usize;
+        if let Some(item) = schema.get("items").filter(|item| item.is_object()) {
+            let sample = self.sample(item, depth + 1);
+            while items.len() < min_items.max(1) {
+                items.push(sample.clone());
+            }
+        }
+
+        JsonValue::Array(items)
+    }
+}
+
+fn given_value(schema: &Map<String, JsonValue>) -> Option<&JsonValue> {
+    schema
+        .get("const")
+        .or_else(|| {
+            schema
+                .get("examples")
+                .and_then(JsonValue::as_array)
+                .and_then(|examples| examples.first())
+        })
+        // OpenAPI documents use the singular form
+        .or_else(|| schema.get("example"))
+        .or_else(|| schema.get("default"))
+        .or_else(|| {
+            schema
+                .get("enum")
+                .and_then(JsonValue::as_array)
+                .and_then(|values| values.first())
+        })
+}
+
+fn schema_type(schema: &Map<String, JsonValue>) -> Option<&str> {
+    match schema.get("type") {
+        Some(JsonValue::String(name)) => Some(name),
+        // The first type that isn't `null`, since a value is more telling than none
+        Some(JsonValue::Array(names)) => names
+            .iter()
+            .filter_map(JsonValue::as_str)
+            .find(|name| *name != "null")
+            .or(Some("null")),
+        _ if schema.contains_key("properties") => Some("object"),
+        _ if schema.contains_key("items") || schema.contains_key("prefixItems") => Some("array"),
+        _ => None,
+    }
+}
+
+fn sample_string(schema: &Map<String, JsonValue>) -> JsonValue {
+    let sample = match schema.get("format").and_then(JsonValue::as_str) {
+        Some("date-time") => "2024-01-01T00:00:00Z",
+        Some("date") => "2024-01-01",
+        Some("time") => "00:00:00Z",
+        Some("email") => "user@example.com",
+        Some("hostname") => "example.com",
+        Some("ipv4") => "127.0.0.1",
+        Some("ipv6") => "::1",
+        Some("uri") | Some("url") => "https://example.com",
+        Some("uuid") => "00000000-0000-0000-0000-000000000000",
+        _ => "string",
+    };
+
+    let mut sample = sample.to_string();
+    if let Some(min_length) = schema.get("minLength").and_then(JsonValue::as_u64) {
+        while sample.chars().count() < min_length as usize {
+            sample.push('x');
+        }
+    }
+    if let Some(max_length) = schema.get("maxLength").and_then(JsonValue::as_u64) {
+        sample = sample.chars().take(max_length as usize).collect();
+    }
+
+    JsonValue::String(sample)
+}
+
+fn sample_number(schema: &Map<String, JsonValue>, integer: bool) -> JsonValue {
+    let minimum = schema.get("minimum").and_then(JsonValue::as_f64);
+    let exclusive_minimum = schema.get("exclusiveMinimum").and_then(JsonValue::as_f64);
+    let maximum = schema.get("maximum").and_then(JsonValue::as_f64);
+
+    let mut value = match (minimum, exclusive_minimum) {
+        (_, Some(bound)) => bound + 1.0,
+        (Some(bound), None) => bound,
+        (None, None) => 0.0,
+    };
+    if let Some(maximum) = maximum {
+        value = value.min(maximum);
+    }
+
+    if integer {
+        JsonValue::from(value.ceil() as i64)
+    } else {
+        serde_json::Number::from_f64(value).map_or(JsonValue::Null, JsonValue::Number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_sample_object() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer", "minimum": 1 },
+                "email": { "type": "string", "format": "email" },
+                "role": { "enum": ["admin", "user"] },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "nickname": { "type": ["null", "string"], "examples": ["neo"] },
+                "score": { "type": "number", "exclusiveMinimum": 0.5 }
+            }
+        });
+
+        assert_eq!(
+            sample(&schema),
+            json!({
+                "id": 1,
+                "email": "user@example.com",
+                "role": "admin",
+                "tags": ["string"],
+                "nickname": "neo",
+                "score": 1.5
+            })
+        );
+    }
+
+    #[test]
+    fn test_sample_refs_and_combinators() {
+        let schema = json!({
+            "$defs": {
+                "named": { "properties": { "name": { "type": "string", "minLength": 8 } } },
+                "node": {
+                    "type": "object",
+                    "properties": { "children": { "type": "array", "items": { "$ref": "#/$defs/node" } } }
+                }
+            },
+            "allOf": [
+                { "$ref": "#/$defs/named" },
+                { "properties": { "kind": { "oneOf": [{ "const": "leaf" }, { "type": "integer" }] } } }
+            ]
+        });
+
+        assert_eq!(
+            sample(&schema),
+            json!({ "name": "stringxx", "kind": "leaf" })
+        );
+
+        // Cyclic references stop at the maximum depth rather than overflowing the stack
+        let tree = sample(&json!({ "$ref": "#/$defs/node", "$defs": schema["$defs"] }));
+        assert!(tree["children"][0]["children"].is_array());
+    }
+}
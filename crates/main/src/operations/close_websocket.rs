@@ -0,0 +1,18 @@
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::websocket::{CloseWebSocketInput, CloseWebSocketOutput};
+
+use crate::MainWindow;
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Closes the session, which is kept for its frames to be listed.
+    pub async fn close_websocket(
+        &self,
+        ctx: &R::AsyncContext,
+        input: CloseWebSocketInput,
+    ) -> joinerror::Result<CloseWebSocketOutput> {
+        let session = self.websocket_sessions.session(&input.session_id).await?;
+        session.close(ctx, input.code, input.reason).await?;
+
+        Ok(CloseWebSocketOutput {})
+    }
+}
@@ -0,0 +1,64 @@
+use joinerror::{Error, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_ipc::contracts::main::websocket::{
+    ConnectWebSocketInput, ConnectWebSocketOutput, WebSocketEvent,
+};
+use tauri::ipc::Channel;
+
+use crate::{
+    MainWindow,
+    resource::{self, EnvironmentVariables},
+    websocket,
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Connects to the WebSocket endpoint. The session outlives the request, until it's closed
+    /// by either side, and reports its frames to the channel.
+    pub async fn connect_websocket(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ConnectWebSocketInput,
+        channel: Channel<WebSocketEvent>,
+    ) -> joinerror::Result<ConnectWebSocketOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id.clone())
+            .await?;
+        if desc.class != ResourceClass::Endpoint
+            || desc.protocol != Some(ResourceProtocol::WebSocket)
+        {
+            return Err(Error::new::<()>(format!(
+                "resource `{}` is not a websocket endpoint",
+                desc.name
+            )));
+        }
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+        let subprotocols = desc
+            .websocket
+            .map(|websocket| websocket.subprotocols)
+            .unwrap_or_default();
+        let request = websocket::handshake_request(&resolved, &subprotocols)?;
+
+        websocket::connect(
+            ctx,
+            &self.websocket_sessions,
+            input.project_id,
+            input.resource_id,
+            request,
+            move |event| {
+                channel
+                    .send(event)
+                    .join_err::<()>("failed to send websocket event")
+            },
+        )
+        .await
+    }
+}
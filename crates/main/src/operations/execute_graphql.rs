@@ -0,0 +1,109 @@
+use async_graphql_parser::types::OperationType;
+use joinerror::{Error, OptionExt, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use reqwest::Method;
+use sapic_ipc::{
+    constants::DEFAULT_OPERATION_TIMEOUT,
+    contracts::main::graphql::{
+        ExecuteGraphqlInput, ExecuteGraphqlOutput, GraphqlEncoding, GraphqlEvent,
+    },
+};
+use std::time::Instant;
+use tauri::ipc::Channel;
+
+use crate::{
+    MainWindow, graphql,
+    resource::{self, EnvironmentVariables},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Executes the operation of the GraphQL endpoint, reporting its result to the channel.
+    /// Subscriptions report every result they receive, until the server completes them or the
+    /// context is canceled.
+    pub async fn execute_graphql(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ExecuteGraphqlInput,
+        channel: Channel<GraphqlEvent>,
+    ) -> joinerror::Result<ExecuteGraphqlOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+        graphql::ensure_graphql_endpoint(&desc)?;
+        let operation = desc
+            .graphql
+            .as_ref()
+            .ok_or_join_err_with::<()>(|| format!("endpoint `{}` has no query", desc.name))?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+        let body = graphql::operation_body(operation, &variables);
+
+        let operation_type =
+            graphql::operation_type(&operation.query, operation.operation_name.as_deref())?;
+        let encoding = input.encoding.unwrap_or_default();
+        if operation_type == OperationType::Mutation && encoding == GraphqlEncoding::Get {
+            return Err(Error::new::<()>(
+                "mutations can only be sent with the post encoding",
+            ));
+        }
+
+        // Subscriptions are sent over a websocket, whose handshake is a get request
+        let method = match (operation_type, encoding) {
+            (OperationType::Subscription, _) | (_, GraphqlEncoding::Get) => Method::GET,
+            (_, GraphqlEncoding::Post) => Method::POST,
+        };
+        let mut request = graphql::endpoint_request(&self.http_client, method, &resolved)?;
+        if operation_type != OperationType::Subscription {
+            request = graphql::add_operation(request, encoding, &body)?;
+        }
+        let mut request = resource::auth::authorize_http_request(
+            ctx,
+            &self.oauth2_client,
+            &self.digest_nonces,
+            &project.workspace_id,
+            &variables,
+            desc.auth.as_ref(),
+            request,
+        )
+        .await?;
+
+        let send_result = |result| {
+            channel
+                .send(GraphqlEvent::Result { result })
+                .join_err::<()>("failed to send graphql event")
+        };
+
+        let started_at = Instant::now();
+        let (status, results) = if operation_type == OperationType::Subscription {
+            let request = request
+                .request
+                .build()
+                .join_err::<()>("failed to build request")?;
+            let request = graphql::handshake_request(&request)?;
+            let results = graphql::subscribe(ctx, request, body, send_result).await?;
+
+            (None, results)
+        } else {
+            // Subscriptions last until they are canceled, but queries and mutations time out
+            // like any other request
+            request.request = request.request.timeout(DEFAULT_OPERATION_TIMEOUT);
+            let (status, result) = graphql::send_request(ctx, request).await?;
+            send_result(result)?;
+
+            (Some(status), 1)
+        };
+
+        Ok(ExecuteGraphqlOutput {
+            status,
+            results,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        })
+    }
+}
@@ -0,0 +1,145 @@
+use joinerror::{OptionExt, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_project::models::{operations::DescribeResourceOutput, types::grpc::GrpcInfo};
+use sapic_core::context::{self, ContextResultExt};
+use sapic_ipc::{
+    constants::DEFAULT_OPERATION_TIMEOUT,
+    contracts::main::grpc::{ExecuteGrpcInput, ExecuteGrpcOutput, GrpcEvent},
+};
+use std::time::{Duration, Instant};
+use tauri::ipc::Channel;
+
+use crate::{
+    MainWindow,
+    grpc::{self, GrpcTarget},
+    project::RuntimeProject,
+    resource::{self, EnvironmentVariables},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Calls the method of the gRPC endpoint, reporting the metadata and the messages the
+    /// server responds with to the channel as they are received.
+    pub async fn execute_grpc(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ExecuteGrpcInput,
+        channel: Channel<GrpcEvent>,
+    ) -> joinerror::Result<ExecuteGrpcOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+        grpc::ensure_grpc_endpoint(&desc)?;
+        let settings = desc.grpc.as_ref().ok_or_join_err_with::<()>(|| {
+            format!("endpoint `{}` has no grpc settings", desc.name)
+        })?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let target = self
+            .grpc_target(ctx, &project, &desc, settings, &variables)
+            .await?;
+
+        let method = grpc::find_method(&target.pool, &settings.service, &settings.method)?;
+        let messages = input
+            .messages
+            .as_ref()
+            .unwrap_or(&settings.messages)
+            .iter()
+            .map(|message| variables.render_json(message))
+            .collect::<Vec<_>>();
+        let messages = grpc::request_messages(&method, &messages)?;
+        // Streaming calls last until they are canceled, unless the endpoint sets a deadline
+        let is_streaming = method.is_client_streaming() || method.is_server_streaming();
+        let timeout = settings
+            .timeout_ms
+            .map(|timeout_ms| Duration::from_millis(timeout_ms as u64))
+            .or((!is_streaming).then_some(DEFAULT_OPERATION_TIMEOUT));
+
+        let started_at = Instant::now();
+        let outcome = grpc::call(
+            ctx,
+            target.channel,
+            &method,
+            messages,
+            target.metadata,
+            timeout,
+            |event| {
+                channel
+                    .send(event)
+                    .join_err::<()>("failed to send grpc event")
+            },
+        )
+        .await?;
+
+        Ok(ExecuteGrpcOutput {
+            code: outcome.code.into(),
+            message: outcome.message,
+            received: outcome.received,
+            trailers: outcome.trailers,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Connects to the gRPC endpoint, with the metadata from its headers and its auth, and
+    /// reads the descriptors of its services from its `.proto` files, or from its server
+    /// reflection when it has none.
+    pub(crate) async fn grpc_target(
+        &self,
+        ctx: &R::AsyncContext,
+        project: &RuntimeProject,
+        desc: &DescribeResourceOutput,
+        settings: &GrpcInfo,
+        variables: &EnvironmentVariables,
+    ) -> joinerror::Result<GrpcTarget> {
+        let resolved = resource::resolve_resource(desc, variables)?;
+        let url = grpc::endpoint_url(&resolved.url)?;
+
+        let mut request = self.http_client.post(&url);
+        for header in &resolved.headers {
+            request = request.header(&header.name, &header.value);
+        }
+        let request = resource::auth::authorize_http_request(
+            ctx,
+            &self.oauth2_client,
+            &self.digest_nonces,
+            &project.workspace_id,
+            variables,
+            desc.auth.as_ref(),
+            request,
+        )
+        .await?
+        .request
+        .build()
+        .join_err::<()>("failed to build request")?;
+        let metadata = grpc::request_metadata(request.headers());
+
+        let channel = context::abortable(ctx, grpc::connect(&url))
+            .await
+            .join_err_bare()?;
+
+        let pool = if settings.proto_files.is_empty() {
+            context::abortable(ctx, grpc::reflect(channel.clone(), &metadata))
+                .await
+                .join_err_bare()?
+        } else {
+            let project_path = project.abs_path();
+            let proto_files = settings.proto_files.clone();
+            let import_paths = settings.import_paths.clone();
+            tokio::task::spawn_blocking(move || {
+                grpc::compile_protos(&project_path, &proto_files, &import_paths)
+            })
+            .await??
+        };
+
+        Ok(GrpcTarget {
+            channel,
+            metadata,
+            pool,
+        })
+    }
+}
@@ -0,0 +1,148 @@
+use chrono::Utc;
+use joinerror::{Error, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_project::models::operations::DescribeResourceOutput;
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::{
+    constants::DEFAULT_OPERATION_TIMEOUT,
+    contracts::main::resource::{
+        ExecuteResourceEvent, ExecuteResourceInput, ExecuteResourceOutput, ResolveResourceOutput,
+    },
+};
+use std::{sync::Mutex, time::Duration};
+use tauri::ipc::Channel;
+
+use crate::{
+    MainWindow,
+    project::RuntimeProject,
+    resource::{
+        self, EnvironmentVariables,
+        history::{Execution, RecordedRequest, RecordedResponse},
+    },
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn execute_resource(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ExecuteResourceInput,
+        channel: Channel<ExecuteResourceEvent>,
+    ) -> joinerror::Result<ExecuteResourceOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+        if desc.class != ResourceClass::Endpoint {
+            return Err(Error::new::<()>(format!(
+                "resource `{}` is not an endpoint",
+                desc.name
+            )));
+        }
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+
+        self.send_endpoint(
+            ctx,
+            &project,
+            &desc,
+            &resolved,
+            &variables,
+            DEFAULT_OPERATION_TIMEOUT,
+            |event| {
+                channel
+                    .send(event)
+                    .join_err::<()>("failed to send response event")
+            },
+        )
+        .await
+    }
+
+    /// Sends the resolved request of the endpoint and records it, as it's sent, in the
+    /// execution history of the project.
+    ///
+    /// The exchange with the server times out after `timeout`. The auth of the request isn't
+    /// covered by it, since interactive OAuth 2.0 grants wait for the user.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn send_endpoint<F>(
+        &self,
+        ctx: &R::AsyncContext,
+        project: &RuntimeProject,
+        desc: &DescribeResourceOutput,
+        resolved: &ResolveResourceOutput,
+        variables: &EnvironmentVariables,
+        timeout: Duration,
+        on_event: F,
+    ) -> joinerror::Result<ExecuteResourceOutput>
+    where
+        F: Fn(ExecuteResourceEvent) -> joinerror::Result<()>,
+    {
+        let request =
+            resource::build_http_request(ctx, project.fs.as_ref(), &self.http_client, resolved)
+                .await?;
+        let authorized = resource::auth::authorize_http_request(
+            ctx,
+            &self.oauth2_client,
+            &self.digest_nonces,
+            &project.workspace_id,
+            variables,
+            desc.auth.as_ref(),
+            request,
+        )
+        .await?;
+
+        // The request is built ahead of sending it, to record it as it's sent
+        let (client, request) = authorized.request.build_split();
+        let mut request = request.join_err::<()>("failed to build request")?;
+        *request.timeout_mut() = Some(timeout);
+        let recorded_request = RecordedRequest::new(
+            &request,
+            resolved.body.as_ref(),
+            &authorized.credential_names,
+        );
+        let recorded_response = Mutex::new(RecordedResponse::default());
+
+        let started_at = Utc::now();
+        let output = resource::send_http_request(
+            ctx,
+            &client,
+            request,
+            authorized.digest.as_ref(),
+            |event| {
+                recorded_response
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .record(&event);
+                on_event(event)
+            },
+        )
+        .await?;
+
+        let execution = Execution {
+            started_at,
+            duration_ms: output.duration_ms,
+            request: recorded_request,
+            response: recorded_response
+                .into_inner()
+                .unwrap_or_else(|e| e.into_inner()),
+        };
+        if let Err(e) = self
+            .execution_history
+            .record(ctx, project, &execution)
+            .await
+        {
+            tracing::warn!(
+                "failed to record execution of project {}: {}",
+                project.id,
+                e
+            );
+        }
+
+        Ok(output)
+    }
+}
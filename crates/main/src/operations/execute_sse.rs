@@ -0,0 +1,102 @@
+use joinerror::ResultExt;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::sse::{ExecuteSseInput, ExecuteSseOutput, SseEvent};
+use std::{collections::VecDeque, sync::Mutex, time::Instant};
+use tauri::ipc::Channel;
+
+use crate::{
+    MainWindow,
+    resource::{self, EnvironmentVariables},
+    sse,
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Sends the request of the HTTP endpoint and streams its response as server-sent
+    /// events, reporting them to the channel as they are dispatched, until the stream ends
+    /// or the request is canceled.
+    ///
+    /// The events received are recorded once the stream stops, when the endpoint is set to
+    /// record them, even if the stream failed.
+    pub async fn execute_sse(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ExecuteSseInput,
+        channel: Channel<SseEvent>,
+    ) -> joinerror::Result<ExecuteSseOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id.clone())
+            .await?;
+        let settings = sse::sse_settings(&desc)?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+        let request =
+            resource::build_http_request(ctx, project.fs.as_ref(), &self.http_client, &resolved)
+                .await?;
+        let authorized = resource::auth::authorize_http_request(
+            ctx,
+            &self.oauth2_client,
+            &self.digest_nonces,
+            &project.workspace_id,
+            &variables,
+            desc.auth.as_ref(),
+            request,
+        )
+        .await?;
+        let (client, request) = authorized.request.build_split();
+        let request = request.join_err::<()>("failed to build request")?;
+
+        let recorded = Mutex::new(VecDeque::new());
+        let started_at = Instant::now();
+        let result = sse::stream(
+            ctx,
+            &client,
+            request,
+            authorized.digest.as_ref(),
+            settings,
+            |event| {
+                if settings.record
+                    && let SseEvent::Event { event } = &event
+                {
+                    let mut recorded = recorded.lock().unwrap_or_else(|e| e.into_inner());
+                    if recorded.len() == sse::MAX_RECORDED_EVENTS {
+                        recorded.pop_front();
+                    }
+                    recorded.push_back(event.clone());
+                }
+
+                channel
+                    .send(event)
+                    .join_err::<()>("failed to send sse event")
+            },
+        )
+        .await;
+        let duration_ms = started_at.elapsed().as_millis() as u64;
+
+        if settings.record {
+            let events =
+                serde_json::to_value(recorded.into_inner().unwrap_or_else(|e| e.into_inner()))
+                    .join_err::<()>("failed to serialize sse events")?;
+
+            // The request context is done once the stream was stopped by canceling it
+            project
+                .put_sse_events(&ArcContext::background(), &input.resource_id, events)
+                .await?;
+        }
+
+        let outcome = result?;
+        Ok(ExecuteSseOutput {
+            received: outcome.received,
+            reconnects: outcome.reconnects,
+            last_event_id: outcome.last_event_id,
+            duration_ms,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use joinerror::{Error, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_base::resource::types::primitives::ResourceKind;
+use sapic_ipc::{
+    ValidationResultExt,
+    contracts::main::resource::{ExportHttpFileInput, ExportHttpFileOutput},
+};
+use validator::Validate;
+
+use crate::{MainWindow, export};
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn export_http_file(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: &ExportHttpFileInput,
+    ) -> joinerror::Result<ExportHttpFileOutput> {
+        input.validate().join_err_bare()?;
+
+        let project = self
+            .workspace
+            .load()
+            .project(ctx, &input.project_id)
+            .await?;
+        let resources = export::describe_resources(ctx, app_delegate, &project).await?;
+
+        let dir = resources
+            .iter()
+            .find(|resource| resource.id == input.resource_id)
+            .ok_or_else(|| Error::new::<()>(format!("resource {} not found", input.resource_id)))?;
+        if dir.description.kind != ResourceKind::Dir {
+            return Err(Error::new::<()>(format!(
+                "resource {} is not a directory",
+                input.resource_id
+            )));
+        }
+
+        let content = export::http_file::convert(dir, &resources);
+        let path = input.destination.join(format!(
+            "{}.http",
+            moss_text::sanitized::sanitize(&dir.description.name)
+        ));
+        tokio::fs::write(&path, content)
+            .await
+            .join_err_with::<()>(|| format!("failed to write {}", path.display()))?;
+
+        Ok(ExportHttpFileOutput { path })
+    }
+}
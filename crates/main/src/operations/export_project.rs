@@ -1,32 +1,105 @@
+use joinerror::{Error, ResultExt};
+use moss_app_delegate::AppDelegate;
 use moss_applib::AppRuntime;
 use sapic_ipc::{
     ValidationResultExt,
-    contracts::main::project::{ExportProjectInput, ExportProjectOutput, ExportProjectParams},
+    contracts::main::project::{
+        ExportProjectFormat, ExportProjectInput, ExportProjectOutput, ExportProjectParams,
+    },
 };
+use std::path::PathBuf;
 use validator::Validate;
 
-use crate::MainWindow;
+use crate::{MainWindow, export};
 
 impl<R: AppRuntime> MainWindow<R> {
     pub async fn export_project(
         &self,
         ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
         input: &ExportProjectInput,
     ) -> joinerror::Result<ExportProjectOutput> {
         input.validate().join_err_bare()?;
 
-        let archive_path = self
-            .workspace
-            .load()
-            .export_project(
-                ctx,
-                ExportProjectParams {
-                    id: input.inner.id.clone(),
-                    destination: input.inner.destination.clone(),
-                },
-            )
-            .await?;
+        let archive_path = match input.inner.format {
+            ExportProjectFormat::Archive => {
+                self.workspace
+                    .load()
+                    .export_project(
+                        ctx,
+                        ExportProjectParams {
+                            id: input.inner.id.clone(),
+                            destination: input.inner.destination.clone(),
+                            format: input.inner.format,
+                        },
+                    )
+                    .await?
+            }
+            ExportProjectFormat::OpenApiYaml | ExportProjectFormat::OpenApiJson => {
+                self.export_openapi_document(ctx, app_delegate, &input.inner)
+                    .await?
+            }
+            ExportProjectFormat::Har => self.export_har_log(ctx, &input.inner).await?,
+        };
 
         Ok(ExportProjectOutput { archive_path })
     }
+
+    async fn export_openapi_document(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        params: &ExportProjectParams,
+    ) -> joinerror::Result<PathBuf> {
+        let workspace = self.workspace.load();
+        let project = workspace.project(ctx, &params.id).await?;
+        let details = project.handle.details(ctx).await?;
+        let resources = export::describe_resources(ctx, app_delegate, &project).await?;
+        let document = export::openapi::convert(&details.name, &resources);
+
+        let (content, extension) = match params.format {
+            ExportProjectFormat::OpenApiJson => (
+                serde_json::to_vec_pretty(&document)
+                    .join_err::<()>("failed to serialize openapi document")?,
+                "json",
+            ),
+            _ => (
+                serde_norway::to_string(&document)
+                    .map_err(|e| {
+                        Error::new::<()>(format!("failed to serialize openapi document: {}", e))
+                    })?
+                    .into_bytes(),
+                "yaml",
+            ),
+        };
+
+        workspace
+            .export_openapi(ctx, &params.id, &params.destination, extension, content)
+            .await
+    }
+
+    /// Writes the last requests sent to the endpoints of the project, along with
+    /// the responses they received.
+    async fn export_har_log(
+        &self,
+        ctx: &R::AsyncContext,
+        params: &ExportProjectParams,
+    ) -> joinerror::Result<PathBuf> {
+        let workspace = self.workspace.load();
+        let project = workspace.project(ctx, &params.id).await?;
+
+        let executions = self.execution_history.executions(ctx, &project).await?;
+        if executions.is_empty() {
+            return Err(Error::new::<()>(
+                "no requests have been sent to the endpoints of the project yet",
+            ));
+        }
+
+        let content = serde_json::to_vec_pretty(&export::har::convert(&executions))
+            .join_err::<()>("failed to serialize har log")?;
+
+        workspace
+            .export_har(ctx, &params.id, &params.destination, content)
+            .await
+    }
 }
@@ -0,0 +1,42 @@
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::resource::{GenerateSnippetInput, GenerateSnippetOutput};
+
+use crate::{
+    MainWindow,
+    resource::{self, EnvironmentVariables},
+    snippet::{self, SnippetRequest},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn generate_snippet(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: &GenerateSnippetInput,
+    ) -> joinerror::Result<GenerateSnippetOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id.clone())
+            .await?;
+        // Without resolving, variables are left as the `{{name}}` placeholders they're written as
+        let variables = if input.resolve_variables {
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?
+        } else {
+            EnvironmentVariables::default()
+        };
+
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+        let request = SnippetRequest::new(
+            &resolved,
+            desc.auth.as_ref().map(|auth| &auth.scheme),
+            &variables,
+        )?;
+
+        Ok(GenerateSnippetOutput {
+            snippet: snippet::generate(&request, input.language),
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use moss_applib::AppRuntime;
+use moss_project::models::operations::CreateResourceInput;
+use sapic_ipc::{
+    ValidationResultExt,
+    contracts::main::resource::{ImportCurlInput, ImportCurlOutput},
+};
+use validator::Validate;
+
+use crate::{MainWindow, import};
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn import_curl(
+        &self,
+        ctx: &R::AsyncContext,
+        input: &ImportCurlInput,
+    ) -> joinerror::Result<ImportCurlOutput> {
+        input.validate().join_err_bare()?;
+
+        let imported = import::curl::convert(
+            &input.command,
+            input.path.clone(),
+            input.name.clone(),
+            input.order,
+        )?;
+        let name = imported.params.name.clone();
+
+        let project = self
+            .workspace
+            .load()
+            .project(ctx, &input.project_id)
+            .await?;
+        let output = project
+            .handle
+            .create_resource::<R>(ctx, CreateResourceInput::Item(imported.params))
+            .await?;
+
+        Ok(ImportCurlOutput {
+            id: output.id,
+            name,
+            skipped: imported.skipped,
+        })
+    }
+}
@@ -1,12 +1,27 @@
+use futures::FutureExt;
+use joinerror::ResultExt;
 use moss_applib::AppRuntime;
-use sapic_base::other::GitProviderKind;
+use moss_project::models::operations::BatchCreateResourceInput;
+use sapic_base::{other::GitProviderKind, project::types::primitives::ProjectId};
 use sapic_ipc::{
     ValidationResultExt,
-    contracts::main::project::{ImportProjectInput, ImportProjectOutput, ImportProjectSource},
+    contracts::main::{
+        environment::CreateEnvironmentInput,
+        project::{
+            CreateProjectParams, ImportHarParams, ImportHttpFileParams, ImportOpenApiParams,
+            ImportPostmanParams, ImportProjectInput, ImportProjectOutput, ImportProjectParams,
+            ImportProjectSource, ImportSkippedItem,
+        },
+    },
 };
+use std::path::Path;
 use validator::Validate;
 
-use crate::MainWindow;
+use crate::{
+    MainWindow,
+    import::{self, ImportedResources},
+    workspace::CreateImportedResources,
+};
 
 impl<R: AppRuntime> MainWindow<R> {
     pub async fn import_project(
@@ -19,6 +34,7 @@ impl<R: AppRuntime> MainWindow<R> {
         let workspace = self.workspace.load();
         let params = &input.inner;
 
+        let mut skipped = Vec::new();
         let project_id = match &params.source {
             ImportProjectSource::GitHub(git_params) => {
                 workspace
@@ -52,6 +68,64 @@ impl<R: AppRuntime> MainWindow<R> {
                     .import_external_project(ctx, external_params)
                     .await?
             }
+            ImportProjectSource::OpenApi(openapi_params) => {
+                let imported = read_openapi_document(openapi_params).await?;
+                let (project_id, openapi_skipped) =
+                    self.create_imported_project(ctx, params, imported).await?;
+                skipped = openapi_skipped;
+
+                project_id
+            }
+            ImportProjectSource::Postman(postman_params) => {
+                let imported = read_postman_collection(postman_params).await?;
+                let (project_id, postman_skipped) =
+                    self.create_imported_project(ctx, params, imported).await?;
+                skipped = postman_skipped;
+
+                project_id
+            }
+            ImportProjectSource::HttpFile(http_file_params) => {
+                let imported = read_http_file(http_file_params).await?;
+                let (project_id, http_file_skipped) =
+                    self.create_imported_project(ctx, params, imported).await?;
+                skipped = http_file_skipped;
+
+                project_id
+            }
+            ImportProjectSource::Har(har_params) => {
+                let imported = read_har_log(har_params).await?;
+                let (project_id, har_skipped) =
+                    self.create_imported_project(ctx, params, imported).await?;
+                skipped = har_skipped;
+
+                project_id
+            }
+            ImportProjectSource::Insomnia(insomnia_params) => {
+                let (project_id, insomnia_skipped) = workspace
+                    .import_insomnia_project(
+                        ctx,
+                        params,
+                        insomnia_params,
+                        create_imported_resources::<R>(ctx),
+                    )
+                    .await?;
+                skipped = insomnia_skipped;
+
+                project_id
+            }
+            ImportProjectSource::Bruno(bruno_params) => {
+                let (project_id, bruno_skipped) = workspace
+                    .import_bruno_project(
+                        ctx,
+                        params,
+                        bruno_params,
+                        create_imported_resources::<R>(ctx),
+                    )
+                    .await?;
+                skipped = bruno_skipped;
+
+                project_id
+            }
         };
 
         let project = workspace.project(ctx, &project_id).await?;
@@ -63,6 +137,129 @@ impl<R: AppRuntime> MainWindow<R> {
             icon_path: project.handle.icon_path(),
             abs_path: project.handle.internal_abs_path().to_owned(),
             external_path: project.handle.external_abs_path().map(|p| p.to_path_buf()),
+            skipped,
         })
     }
+
+    /// Creates a new project holding the imported resources and environments, which is
+    /// deleted again if they can't be created, so that no partial import is left behind.
+    async fn create_imported_project(
+        &self,
+        ctx: &R::AsyncContext,
+        params: &ImportProjectParams,
+        imported: ImportedResources,
+    ) -> joinerror::Result<(ProjectId, Vec<ImportSkippedItem>)> {
+        let workspace = self.workspace.load();
+        let project_id = workspace
+            .create_project(
+                ctx,
+                CreateProjectParams {
+                    name: params.name.clone(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: params.icon_path.clone(),
+                },
+            )
+            .await?;
+
+        let project = workspace.project(ctx, &project_id).await?;
+        let result = async {
+            project
+                .handle
+                .batch_create_resource::<R>(
+                    ctx,
+                    BatchCreateResourceInput {
+                        resources: imported.resources,
+                    },
+                )
+                .await?;
+
+            for environment in imported.environments {
+                project
+                    .create_environment(
+                        ctx,
+                        CreateEnvironmentInput {
+                            project_id: Some(project_id.clone()),
+                            name: environment.name,
+                            color: None,
+                            variables: environment.variables,
+                        },
+                    )
+                    .await?;
+            }
+
+            joinerror::Result::Ok(())
+        }
+        .await;
+        if let Err(e) = result {
+            // The import failure is what the user needs to see, even if the rollback fails too
+            if let Err(delete_err) = workspace.delete_project(ctx, &project_id).await {
+                tracing::warn!(
+                    "failed to delete project {} after its import failed: {}",
+                    project_id,
+                    delete_err
+                );
+            }
+            return Err(e);
+        }
+
+        Ok((project_id, imported.skipped))
+    }
+}
+
+/// Creates the resources converted by an importer in the project being imported.
+fn create_imported_resources<R: AppRuntime>(ctx: &R::AsyncContext) -> CreateImportedResources<'_> {
+    Box::new(move |project, resources| {
+        async move {
+            project
+                .batch_create_resource::<R>(ctx, BatchCreateResourceInput { resources })
+                .await?;
+
+            Ok(())
+        }
+        .boxed()
+    })
+}
+
+async fn read_openapi_document(
+    params: &ImportOpenApiParams,
+) -> joinerror::Result<ImportedResources> {
+    let document = import::read_document(&params.spec_path, "openapi document").await?;
+
+    import::openapi::convert(&document)
+}
+
+async fn read_postman_collection(
+    params: &ImportPostmanParams,
+) -> joinerror::Result<ImportedResources> {
+    let collection = import::read_document(&params.collection_path, "postman collection").await?;
+    let mut imported = import::postman::convert(&collection)?;
+
+    for path in &params.environment_paths {
+        let environment = import::read_document(path, "postman environment").await?;
+        imported
+            .environments
+            .push(import::postman::convert_environment(&environment)?);
+    }
+
+    Ok(imported)
+}
+
+async fn read_http_file(params: &ImportHttpFileParams) -> joinerror::Result<ImportedResources> {
+    let path = &params.file_path;
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .join_err_with::<()>(|| format!("failed to read http file {}", path.display()))?;
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    import::http_file::convert(&content, &name, path.parent().unwrap_or(Path::new("")))
+}
+
+async fn read_har_log(params: &ImportHarParams) -> joinerror::Result<ImportedResources> {
+    let har = import::read_document(&params.file_path, "har log").await?;
+
+    import::har::convert(&har, params.entries.as_deref())
 }
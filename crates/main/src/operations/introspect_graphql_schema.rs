@@ -0,0 +1,60 @@
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use reqwest::Method;
+use sapic_ipc::contracts::main::graphql::{
+    GraphqlEncoding, IntrospectGraphqlSchemaInput, IntrospectGraphqlSchemaOutput,
+};
+use serde_json::json;
+
+use crate::{
+    MainWindow, graphql,
+    resource::{self, EnvironmentVariables},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Fetches the schema of the GraphQL endpoint and caches it for the resource, for its
+    /// operations to be validated against.
+    pub async fn introspect_graphql_schema(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: IntrospectGraphqlSchemaInput,
+    ) -> joinerror::Result<IntrospectGraphqlSchemaOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id.clone())
+            .await?;
+        graphql::ensure_graphql_endpoint(&desc)?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+
+        let request = graphql::endpoint_request(&self.http_client, Method::POST, &resolved)?;
+        let request = graphql::add_operation(
+            request,
+            GraphqlEncoding::Post,
+            &json!({ "query": graphql::INTROSPECTION_QUERY }),
+        )?;
+        let request = resource::auth::authorize_http_request(
+            ctx,
+            &self.oauth2_client,
+            &self.digest_nonces,
+            &project.workspace_id,
+            &variables,
+            desc.auth.as_ref(),
+            request,
+        )
+        .await?;
+
+        let (_, result) = graphql::send_request(ctx, request).await?;
+        let (schema, _) = graphql::schema_from_introspection(&result)?;
+        project
+            .put_graphql_schema(ctx, &input.resource_id, schema.clone())
+            .await?;
+
+        Ok(IntrospectGraphqlSchemaOutput { schema })
+    }
+}
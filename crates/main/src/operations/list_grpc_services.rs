@@ -0,0 +1,38 @@
+use joinerror::OptionExt;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::grpc::{ListGrpcServicesInput, ListGrpcServicesOutput};
+
+use crate::{MainWindow, grpc, resource::EnvironmentVariables};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Lists the services of the gRPC endpoint, as described by its `.proto` files or by its
+    /// server reflection.
+    pub async fn list_grpc_services(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ListGrpcServicesInput,
+    ) -> joinerror::Result<ListGrpcServicesOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+        grpc::ensure_grpc_endpoint(&desc)?;
+        let settings = desc.grpc.as_ref().ok_or_join_err_with::<()>(|| {
+            format!("endpoint `{}` has no grpc settings", desc.name)
+        })?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let target = self
+            .grpc_target(ctx, &project, &desc, settings, &variables)
+            .await?;
+
+        Ok(ListGrpcServicesOutput {
+            services: grpc::services(&target.pool),
+        })
+    }
+}
@@ -0,0 +1,14 @@
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::proxy::ListRecordedExchangesOutput;
+
+use crate::MainWindow;
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn list_recorded_exchanges(&self) -> joinerror::Result<ListRecordedExchangesOutput> {
+        let exchanges = self.proxy_recordings.exchanges().await;
+
+        Ok(ListRecordedExchangesOutput {
+            exchanges: exchanges.iter().map(|exchange| exchange.info()).collect(),
+        })
+    }
+}
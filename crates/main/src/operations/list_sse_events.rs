@@ -0,0 +1,33 @@
+use joinerror::ResultExt;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::sse::{ListSseEventsInput, ListSseEventsOutput};
+
+use crate::{MainWindow, sse};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Lists the server-sent events recorded in the last stream of the HTTP endpoint.
+    pub async fn list_sse_events(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ListSseEventsInput,
+    ) -> joinerror::Result<ListSseEventsOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id.clone())
+            .await?;
+        sse::sse_settings(&desc)?;
+
+        let events = match project.sse_events(ctx, &input.resource_id).await? {
+            Some(events) => {
+                serde_json::from_value(events).join_err::<()>("invalid recorded sse events")?
+            }
+            None => Vec::new(),
+        };
+
+        Ok(ListSseEventsOutput { events })
+    }
+}
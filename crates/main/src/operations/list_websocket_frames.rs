@@ -0,0 +1,18 @@
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::websocket::{ListWebSocketFramesInput, ListWebSocketFramesOutput};
+
+use crate::MainWindow;
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn list_websocket_frames(
+        &self,
+        input: ListWebSocketFramesInput,
+    ) -> joinerror::Result<ListWebSocketFramesOutput> {
+        let session = self.websocket_sessions.session(&input.session_id).await?;
+
+        Ok(ListWebSocketFramesOutput {
+            frames: session.frames(),
+            open: session.is_open(),
+        })
+    }
+}
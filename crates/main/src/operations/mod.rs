@@ -3,6 +3,8 @@ pub mod archive_project;
 pub mod batch_update_environment;
 pub mod batch_update_project;
 pub mod cancel_request;
+pub mod close_websocket;
+pub mod connect_websocket;
 pub mod create_environment;
 pub mod create_project;
 pub mod create_workspace;
@@ -10,14 +12,35 @@ pub mod delete_environment;
 pub mod delete_project;
 mod describe_environment;
 pub mod describe_project;
+pub mod execute_graphql;
+pub mod execute_grpc;
+pub mod execute_resource;
+pub mod execute_sse;
+pub mod export_http_file;
 pub mod export_project;
+pub mod generate_snippet;
+pub mod import_curl;
 pub mod import_project;
+pub mod introspect_graphql_schema;
+pub mod list_grpc_services;
 pub mod list_project_environments;
 pub mod list_project_resources;
 pub mod list_projects;
+pub mod list_recorded_exchanges;
+pub mod list_sse_events;
+pub mod list_websocket_frames;
 pub mod list_workspace_environments;
 pub mod open_workspace;
+pub mod promote_recorded_exchanges;
+pub mod publish_queue_message;
+pub mod resolve_resource;
+pub mod run_collection;
+pub mod send_websocket_message;
+pub mod start_mock_server;
+pub mod start_recording_proxy;
+pub mod subscribe_queue;
 pub mod unarchive_project;
 pub mod update_environment;
 pub mod update_project;
 pub mod update_workspace;
+pub mod validate_graphql_operation;
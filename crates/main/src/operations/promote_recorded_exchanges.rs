@@ -0,0 +1,108 @@
+use joinerror::OptionExt;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_project::models::operations::CreateResourceInput;
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceKind};
+use sapic_ipc::{
+    ValidationResultExt,
+    contracts::main::proxy::{
+        PromoteRecordedExchangesInput, PromoteRecordedExchangesOutput, PromotedExchange,
+    },
+};
+use std::collections::HashMap;
+use validator::Validate;
+
+use crate::{
+    MainWindow,
+    import::UniqueNames,
+    proxy::promote::{EndpointKey, PromotedEndpoint},
+    resource,
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Creates an endpoint in the directory for every exchange, unless one with the same
+    /// method and templated path exists there already or was promoted along with it.
+    pub async fn promote_recorded_exchanges(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: &PromoteRecordedExchangesInput,
+    ) -> joinerror::Result<PromoteRecordedExchangesOutput> {
+        input.validate().join_err_bare()?;
+
+        let recorded = self.proxy_recordings.exchanges().await;
+        let mut exchanges = Vec::with_capacity(input.exchange_ids.len());
+        for id in &input.exchange_ids {
+            let exchange = recorded
+                .iter()
+                .find(|exchange| &exchange.id == id)
+                .ok_or_join_err_with::<()>(|| format!("recorded exchange `{}` not found", id))?;
+            exchanges.push(exchange);
+        }
+
+        let project = self
+            .workspace
+            .load()
+            .project(ctx, &input.project_id)
+            .await?;
+
+        let mut names = UniqueNames::default();
+        let mut order = 0;
+        let mut endpoints = HashMap::new();
+        for summary in project.resources(ctx, vec![input.path.clone()]).await? {
+            if summary.path.parent() == Some(input.path.as_path()) {
+                names.claim(&input.path, &summary.name);
+                order += 1;
+            }
+
+            if summary.class != ResourceClass::Endpoint || summary.kind != ResourceKind::Item {
+                continue;
+            }
+            let desc = project
+                .describe_resource(ctx, app_delegate, summary.id.clone())
+                .await?;
+            let (Some(method), Some(url)) = (
+                desc.protocol.as_ref().and_then(resource::http_method),
+                desc.url.as_deref(),
+            ) else {
+                continue;
+            };
+            if let Some(key) = EndpointKey::new(method.as_str(), url) {
+                endpoints.entry(key).or_insert(summary.id);
+            }
+        }
+
+        let mut promoted = Vec::with_capacity(exchanges.len());
+        for exchange in exchanges {
+            let endpoint = PromotedEndpoint::new(&exchange.execution)?;
+            if let Some(resource_id) = endpoints.get(&endpoint.key) {
+                promoted.push(PromotedExchange {
+                    exchange_id: exchange.id.clone(),
+                    resource_id: resource_id.clone(),
+                    created: false,
+                });
+                continue;
+            }
+
+            let key = endpoint.key.clone();
+            let name = names.claim(&input.path, &endpoint.name());
+            let params = endpoint.into_params(input.path.clone(), name, order);
+            let output = project
+                .handle
+                .create_resource::<R>(ctx, CreateResourceInput::Item(params))
+                .await?;
+            order += 1;
+
+            endpoints.insert(key, output.id.clone());
+            promoted.push(PromotedExchange {
+                exchange_id: exchange.id.clone(),
+                resource_id: output.id,
+                created: true,
+            });
+        }
+
+        Ok(PromoteRecordedExchangesOutput {
+            exchanges: promoted,
+        })
+    }
+}
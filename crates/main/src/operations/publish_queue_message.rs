@@ -0,0 +1,72 @@
+use joinerror::Error;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_base::resource::types::primitives::ResourceProtocol;
+use sapic_ipc::contracts::main::queue::{PublishQueueMessageInput, PublishQueueMessageOutput};
+use std::time::Instant;
+
+use crate::{
+    MainWindow,
+    queue::{OutgoingMessage, amqp, mqtt},
+    resource::{self, EnvironmentVariables},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Publishes the message of the AMQP or MQTT endpoint, or the payload of the input instead,
+    /// and waits for the broker to acknowledge it.
+    pub async fn publish_queue_message(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: PublishQueueMessageInput,
+    ) -> joinerror::Result<PublishQueueMessageOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+
+        let started_at = Instant::now();
+        let (topic, size) = match desc.protocol {
+            Some(ResourceProtocol::Amqp) => {
+                let settings = amqp::render_settings(amqp::amqp_settings(&desc)?, &variables);
+                let payload = input
+                    .payload
+                    .map(|payload| variables.render(&payload))
+                    .unwrap_or_else(|| settings.payload.clone());
+                let message = OutgoingMessage::new(payload, &resolved.headers);
+
+                amqp::publish(ctx, &resolved.url, &settings, &message).await?;
+                (settings.routing_key, message.payload.len())
+            }
+            Some(ResourceProtocol::Mqtt) => {
+                let settings = mqtt::render_settings(mqtt::mqtt_settings(&desc)?, &variables);
+                let payload = input
+                    .payload
+                    .map(|payload| variables.render(&payload))
+                    .unwrap_or_else(|| settings.payload.clone());
+                let message = OutgoingMessage::new(payload, &resolved.headers);
+
+                mqtt::publish(ctx, &resolved.url, &settings, &message).await?;
+                (settings.topic, message.payload.len())
+            }
+            _ => {
+                return Err(Error::new::<()>(format!(
+                    "resource `{}` is not an amqp or mqtt endpoint",
+                    desc.name
+                )));
+            }
+        };
+
+        Ok(PublishQueueMessageOutput {
+            topic,
+            size: size as u64,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::resource::{ResolveResourceInput, ResolveResourceOutput};
+
+use crate::{
+    MainWindow,
+    resource::{self, EnvironmentVariables},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    pub async fn resolve_resource(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ResolveResourceInput,
+    ) -> joinerror::Result<ResolveResourceOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+
+        resource::resolve_resource(&desc, &variables)
+    }
+}
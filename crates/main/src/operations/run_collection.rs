@@ -0,0 +1,213 @@
+use futures::StreamExt;
+use moss_app_delegate::{AppDelegate, broadcast::ToLocation};
+use moss_applib::AppRuntime;
+use moss_project::models::operations::DescribeResourceOutput;
+use sapic_base::{language::i18n::NO_TRANSLATE_KEY, localize, resource::types::ResourceSummary};
+use sapic_core::context::{AnyAsyncContext, Reason};
+use sapic_ipc::{
+    ValidationResultExt,
+    constants::DEFAULT_OPERATION_TIMEOUT,
+    contracts::main::runner::{RunCollectionInput, RunCollectionOutput, RunResultInfo, RunStatus},
+};
+use std::time::{Duration, Instant};
+use validator::Validate;
+
+use crate::{
+    MainWindow,
+    project::RuntimeProject,
+    resource::{self, EnvironmentVariables},
+    runner::{self, DataRow},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Sends the HTTP endpoints below the directory in the order they are shown, once for every
+    /// row of the data file, until they are all sent or the context is done.
+    ///
+    /// A failed endpoint doesn't stop the run. Progress is reported as an activity of the window.
+    pub async fn run_collection(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: &RunCollectionInput,
+    ) -> joinerror::Result<RunCollectionOutput> {
+        input.validate().join_err_bare()?;
+
+        let rows = match &input.data_file {
+            Some(path) => runner::read_data_file(path).await?,
+            None => vec![DataRow::new()],
+        };
+
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let resources = project.resources(ctx, vec![input.path.clone()]).await?;
+        let ids = resources
+            .iter()
+            .map(|resource| resource.id.clone())
+            .collect::<Vec<_>>();
+        let orders = project.handle.resource_orders(ctx, &ids).await?;
+
+        let mut endpoints = Vec::new();
+        for summary in runner::ordered_endpoints(&input.path, resources, &orders) {
+            let desc = project
+                .describe_resource(ctx, app_delegate, summary.id.clone())
+                .await?;
+            endpoints.push((summary, desc));
+        }
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let request_timeout = input
+            .request_timeout_ms
+            .map(|ms| Duration::from_millis(ms as u64))
+            .unwrap_or(DEFAULT_OPERATION_TIMEOUT);
+
+        let activity_id = format!("run_collection_{}", input.project_id);
+        let activity = app_delegate.emit_continual(ToLocation::Window {
+            activity_id: &activity_id,
+            title: localize!("main.run_collection.title", "Running collection"),
+            detail: None,
+        })?;
+
+        let started_at = Instant::now();
+        let total = rows.len() * endpoints.len();
+        let (variables, project, rows, endpoints) =
+            (&variables, project.as_ref(), &rows, &endpoints);
+        // Runs are indexed rather than borrowed, since closures taking references keep the
+        // future from being `Send`
+        let mut runs = futures::stream::iter(
+            (0..rows.len()).flat_map(|iteration| (0..endpoints.len()).map(move |i| (iteration, i))),
+        )
+        .map(|(iteration, i): (usize, usize)| async move {
+            // Endpoints are no longer sent once the run is done
+            if ctx.done().is_some() {
+                return None;
+            }
+
+            let (summary, desc) = &endpoints[i];
+            let mut variables = variables.clone();
+            variables.bind_row(&rows[iteration]);
+
+            let run = EndpointRun {
+                iteration: iteration as u32,
+                summary,
+                desc,
+            };
+            Some(
+                self.run_endpoint(ctx, project, run, &variables, request_timeout)
+                    .await,
+            )
+        })
+        .buffered(input.concurrency.unwrap_or(1) as usize);
+
+        let mut results = Vec::with_capacity(total);
+        let mut skipped = 0;
+        while let Some(result) = runs.next().await {
+            let Some(result) = result else {
+                skipped += 1;
+                continue;
+            };
+
+            let status = match result.status {
+                Some(status) => status.to_string(),
+                None => "failed".to_string(),
+            };
+            activity.emit_progress(Some(localize!(
+                NO_TRANSLATE_KEY,
+                format!(
+                    "{}/{} {}: {}",
+                    results.len() + skipped + 1,
+                    total,
+                    result.name,
+                    status
+                )
+            )))?;
+            results.push(result);
+        }
+        activity.emit_finish()?;
+
+        let passed = results.iter().filter(|result| result.passed).count() as u32;
+        Ok(RunCollectionOutput {
+            status: match ctx.done() {
+                None => RunStatus::Completed,
+                Some(Reason::Canceled) => RunStatus::Canceled,
+                Some(Reason::Timeout) => RunStatus::TimedOut,
+            },
+            iterations: rows.len() as u32,
+            passed,
+            failed: results.len() as u32 - passed,
+            skipped: skipped as u32,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+            results,
+        })
+    }
+
+    /// Sends the endpoint with the variables of the iteration. Failures are reported in the result.
+    async fn run_endpoint(
+        &self,
+        ctx: &R::AsyncContext,
+        project: &RuntimeProject,
+        run: EndpointRun<'_>,
+        variables: &EnvironmentVariables,
+        request_timeout: Duration,
+    ) -> RunResultInfo {
+        let EndpointRun {
+            iteration,
+            summary,
+            desc,
+        } = run;
+        let method = desc
+            .protocol
+            .as_ref()
+            .and_then(resource::http_method)
+            .map(|method| method.to_string())
+            .unwrap_or_default();
+
+        let started_at = Instant::now();
+        let mut url = String::new();
+        let output = match resource::resolve_resource(desc, variables) {
+            Ok(resolved) => {
+                url = resolved.url.clone();
+                self.send_endpoint(
+                    ctx,
+                    project,
+                    desc,
+                    &resolved,
+                    variables,
+                    request_timeout,
+                    |_| Ok(()),
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        let (status, duration_ms, error) = match output {
+            Ok(output) => (Some(output.status), output.duration_ms, None),
+            Err(e) => (
+                None,
+                started_at.elapsed().as_millis() as u64,
+                Some(e.to_string()),
+            ),
+        };
+
+        RunResultInfo {
+            iteration,
+            resource_id: summary.id.clone(),
+            name: summary.name.clone(),
+            path: summary.path.clone(),
+            method,
+            url,
+            status,
+            duration_ms,
+            passed: status.is_some_and(|status| status < 400),
+            error,
+        }
+    }
+}
+
+struct EndpointRun<'a> {
+    iteration: u32,
+    summary: &'a ResourceSummary,
+    desc: &'a DescribeResourceOutput,
+}
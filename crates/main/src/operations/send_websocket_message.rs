@@ -0,0 +1,57 @@
+use joinerror::OptionExt;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_project::models::types::websocket::WebSocketMessagePayload;
+use sapic_ipc::contracts::main::websocket::{
+    OutgoingWebSocketMessage, SendWebSocketMessageInput, SendWebSocketMessageOutput,
+};
+
+use crate::{MainWindow, resource::EnvironmentVariables, websocket};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Sends a message over an open session. Saved messages are read from the endpoint as
+    /// it is when they are sent, and the variables of text and json messages are substituted.
+    pub async fn send_websocket_message(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: SendWebSocketMessageInput,
+    ) -> joinerror::Result<SendWebSocketMessageOutput> {
+        let session = self.websocket_sessions.session(&input.session_id).await?;
+
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &session.project_id).await?;
+
+        let payload = match input.message {
+            OutgoingWebSocketMessage::Saved { message_id } => {
+                let desc = project
+                    .describe_resource(ctx, app_delegate, session.resource_id.clone())
+                    .await?;
+
+                desc.websocket
+                    .and_then(|websocket| {
+                        websocket
+                            .messages
+                            .into_iter()
+                            .find(|message| message.id.as_ref() == message_id)
+                    })
+                    .ok_or_join_err_with::<()>(|| {
+                        format!("websocket message `{message_id}` not found")
+                    })?
+                    .payload
+            }
+            OutgoingWebSocketMessage::Text(text) => WebSocketMessagePayload::Text(text),
+            OutgoingWebSocketMessage::Json(json) => WebSocketMessagePayload::Json(json),
+            OutgoingWebSocketMessage::Binary(path) => WebSocketMessagePayload::Binary(path),
+        };
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let message =
+            websocket::build_message(ctx, project.fs.as_ref(), &variables, &payload).await?;
+
+        Ok(SendWebSocketMessageOutput {
+            frame: session.send(message).await?,
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use joinerror::{Error, OptionExt, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_project::models::{operations::ListResourceCasesInput, primitives::CaseId};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceKind};
+use sapic_ipc::{
+    ValidationResultExt,
+    contracts::main::mock::{MockServerEvent, StartMockServerInput, StartMockServerOutput},
+};
+use std::time::Duration;
+use tauri::ipc::Channel;
+use validator::Validate;
+
+use crate::{
+    MainWindow, export,
+    mock::{self, MockRoute, MockServerOptions},
+    resource,
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Serves the endpoints of the project on a local port until the request is canceled.
+    pub async fn start_mock_server(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: StartMockServerInput,
+        channel: Channel<MockServerEvent>,
+    ) -> joinerror::Result<StartMockServerOutput> {
+        input.validate().join_err_bare()?;
+
+        let project = self
+            .workspace
+            .load()
+            .project(ctx, &input.project_id)
+            .await?;
+        let resources = export::describe_resources(ctx, app_delegate, &project).await?;
+
+        let mut routes = Vec::new();
+        for resource in &resources {
+            let desc = &resource.description;
+            if desc.class != ResourceClass::Endpoint || desc.kind != ResourceKind::Item {
+                continue;
+            }
+            let Some(method) = desc.protocol.as_ref().and_then(resource::http_method) else {
+                continue;
+            };
+
+            let url = desc.url.as_deref().unwrap_or_default();
+            let mut route = match MockRoute::new(resource.id.clone(), method, url) {
+                Ok(route) => route,
+                Err(e) => {
+                    tracing::warn!("endpoint `{}` cannot be mocked: {}", desc.name, e);
+                    continue;
+                }
+            };
+
+            route.cases = project
+                .list_resource_cases(
+                    ctx,
+                    app_delegate,
+                    ListResourceCasesInput {
+                        resource_id: resource.id.clone(),
+                    },
+                )
+                .await?
+                .cases;
+            route.schema = desc.body_schema.as_ref().and_then(|schema_id| {
+                resources
+                    .iter()
+                    .find(|r| &r.id == schema_id)
+                    .and_then(|r| r.description.schema.clone())
+            });
+
+            routes.push(route);
+        }
+
+        for options in &input.endpoints {
+            let route = routes
+                .iter_mut()
+                .find(|r| r.resource_id == options.resource_id)
+                .ok_or_join_err_with::<()>(|| {
+                    format!(
+                        "resource `{}` is not an endpoint that can be mocked",
+                        options.resource_id
+                    )
+                })?;
+
+            if let Some(case_id) = &options.case_id {
+                let case_id = CaseId::from(case_id.clone());
+                if !route.cases.iter().any(|c| c.id == case_id) {
+                    return Err(Error::new::<()>(format!(
+                        "case `{}` not found for resource `{}`",
+                        case_id, options.resource_id
+                    )));
+                }
+                route.case_id = Some(case_id);
+            }
+            route.status = options.status;
+            route.latency = options
+                .latency_ms
+                .map(|ms| Duration::from_millis(ms as u64));
+        }
+
+        mock::serve(
+            ctx,
+            routes,
+            MockServerOptions {
+                port: input.port,
+                latency: Duration::from_millis(input.latency_ms.unwrap_or_default() as u64),
+                status: input.status,
+                log_requests: input.log_requests,
+            },
+            move |event| {
+                channel
+                    .send(event)
+                    .join_err::<()>("failed to send mock server event")
+            },
+        )
+        .await
+    }
+}
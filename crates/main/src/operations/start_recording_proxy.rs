@@ -0,0 +1,60 @@
+use joinerror::ResultExt;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_ipc::{
+    ValidationResultExt,
+    contracts::main::proxy::{
+        RecordingProxyEvent, StartRecordingProxyInput, StartRecordingProxyOutput,
+    },
+};
+use tauri::ipc::Channel;
+use validator::Validate;
+
+use crate::{
+    MainWindow,
+    proxy::{self, ca::CertificateAuthority},
+};
+
+/// Directory of the profile the certificate authority of the proxy is kept in.
+const PROXY_DIR: &str = "proxy";
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Relays and records the requests of the clients pointed at the proxy until the request
+    /// is canceled. The exchanges are kept by the window, to be promoted into endpoints.
+    pub async fn start_recording_proxy(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: StartRecordingProxyInput,
+        channel: Channel<RecordingProxyEvent>,
+    ) -> joinerror::Result<StartRecordingProxyOutput> {
+        input.validate().join_err_bare()?;
+
+        let ca = CertificateAuthority::load_or_generate(
+            &app_delegate.user_dir().join(PROXY_DIR),
+            &input.intercepted_hosts,
+        )
+        .await?;
+        // Proxies configured through the environment would relay the requests back to this one
+        let client = reqwest::Client::builder()
+            .no_proxy()
+            .redirect(reqwest::redirect::Policy::none())
+            .danger_accept_invalid_certs(input.accept_invalid_certs)
+            .build()
+            .join_err::<()>("failed to build http client")?;
+
+        proxy::serve(
+            ctx,
+            ca,
+            client,
+            self.proxy_recordings.clone(),
+            input.port,
+            move |event| {
+                channel
+                    .send(event)
+                    .join_err::<()>("failed to send recording proxy event")
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,71 @@
+use joinerror::{Error, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_base::resource::types::primitives::ResourceProtocol;
+use sapic_ipc::{
+    ValidationResultExt,
+    contracts::main::queue::{QueueEvent, SubscribeQueueInput, SubscribeQueueOutput},
+};
+use std::time::Instant;
+use tauri::ipc::Channel;
+use validator::Validate;
+
+use crate::{
+    MainWindow,
+    queue::{amqp, mqtt},
+    resource::{self, EnvironmentVariables},
+};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Subscribes to the queue of the AMQP endpoint or the topic of the MQTT one, reporting
+    /// the messages to the channel as they are received, until the input's number of them
+    /// were or the request is canceled.
+    pub async fn subscribe_queue(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: SubscribeQueueInput,
+        channel: Channel<QueueEvent>,
+    ) -> joinerror::Result<SubscribeQueueOutput> {
+        input.validate().join_err_bare()?;
+
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id)
+            .await?;
+
+        let variables =
+            EnvironmentVariables::active(ctx, workspace.as_ref(), project.as_ref()).await?;
+        let resolved = resource::resolve_resource(&desc, &variables)?;
+        let on_event = |event| {
+            channel
+                .send(event)
+                .join_err::<()>("failed to send queue event")
+        };
+
+        let started_at = Instant::now();
+        let received = match desc.protocol {
+            Some(ResourceProtocol::Amqp) => {
+                let settings = amqp::render_settings(amqp::amqp_settings(&desc)?, &variables);
+                amqp::subscribe(ctx, &resolved.url, &settings, input.max_messages, on_event).await?
+            }
+            Some(ResourceProtocol::Mqtt) => {
+                let settings = mqtt::render_settings(mqtt::mqtt_settings(&desc)?, &variables);
+                mqtt::subscribe(ctx, &resolved.url, &settings, input.max_messages, on_event).await?
+            }
+            _ => {
+                return Err(Error::new::<()>(format!(
+                    "resource `{}` is not an amqp or mqtt endpoint",
+                    desc.name
+                )));
+            }
+        };
+
+        Ok(SubscribeQueueOutput {
+            received,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use joinerror::{OptionExt, ResultExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use sapic_ipc::contracts::main::graphql::{
+    ValidateGraphqlOperationInput, ValidateGraphqlOperationOutput,
+};
+
+use crate::{MainWindow, graphql};
+
+impl<R: AppRuntime> MainWindow<R> {
+    /// Validates the document of the GraphQL endpoint, or the one given, against the schema
+    /// cached by its last introspection.
+    pub async fn validate_graphql_operation(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ValidateGraphqlOperationInput,
+    ) -> joinerror::Result<ValidateGraphqlOperationOutput> {
+        let workspace = self.workspace.load().get();
+        let project = workspace.project(ctx, &input.project_id).await?;
+
+        let desc = project
+            .describe_resource(ctx, app_delegate, input.resource_id.clone())
+            .await?;
+        graphql::ensure_graphql_endpoint(&desc)?;
+
+        let schema = project
+            .graphql_schema(ctx, &input.resource_id)
+            .await?
+            .ok_or_join_err_with::<()>(|| {
+                format!(
+                    "the schema of `{}` has not been introspected yet",
+                    desc.name
+                )
+            })?;
+        let schema = serde_json::from_value(schema).join_err::<()>("invalid cached schema")?;
+
+        let query = input
+            .query
+            .or_else(|| desc.graphql.map(|graphql| graphql.query))
+            .unwrap_or_default();
+
+        Ok(ValidateGraphqlOperationOutput {
+            errors: graphql::validate(&schema, &query),
+        })
+    }
+}
@@ -194,8 +194,11 @@ impl RuntimeProject {
 
     pub async fn active_environment(
         &self,
-        _ctx: &dyn AnyAsyncContext,
+        ctx: &dyn AnyAsyncContext,
     ) -> joinerror::Result<Option<EnvironmentId>> {
+        // The active environment is restored from the storage along with the environments
+        self.environments_internal(ctx).await?;
+
         let active_environments = self.active_environment.read().await;
 
         Ok(active_environments.clone())
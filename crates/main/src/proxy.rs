@@ -0,0 +1,492 @@
+pub mod ca;
+pub mod promote;
+
+use chrono::Utc;
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    Method, Request, Response, StatusCode,
+    body::{Bytes, Incoming},
+    header::{self, CONTENT_TYPE, HeaderMap, HeaderName},
+    http::uri::Authority,
+    server::conn::http1,
+    service::service_fn,
+    upgrade::Upgraded,
+};
+use hyper_util::rt::TokioIo;
+use joinerror::ResultExt;
+use sapic_core::context::{AnyAsyncContext, Cancellation};
+use sapic_ipc::contracts::main::proxy::{
+    RecordedExchangeInfo, RecordingProxyEvent, StartRecordingProxyOutput,
+};
+use std::{
+    collections::VecDeque,
+    convert::Infallible,
+    net::Ipv4Addr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Instant,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::RwLock,
+    task::JoinSet,
+};
+use tokio_rustls::TlsAcceptor;
+
+use crate::{
+    proxy::ca::CertificateAuthority,
+    resource::history::{Execution, RecordedRequest, RecordedResponse},
+};
+
+/// Exchanges kept per window, the oldest ones being dropped first.
+const MAX_EXCHANGES: usize = 1000;
+
+/// Headers that only concern a single connection, which aren't relayed.
+const HOP_BY_HOP_HEADERS: [HeaderName; 8] = [
+    header::CONNECTION,
+    header::PROXY_AUTHENTICATE,
+    header::PROXY_AUTHORIZATION,
+    header::TE,
+    header::TRAILER,
+    header::TRANSFER_ENCODING,
+    header::UPGRADE,
+    HeaderName::from_static("proxy-connection"),
+];
+
+/// Exchanges relayed by the recording proxy, until they are promoted into resources.
+#[derive(Debug, Default)]
+pub struct ProxyRecordings {
+    inner: RwLock<Recordings>,
+}
+
+#[derive(Debug, Default)]
+struct Recordings {
+    next_id: u64,
+    exchanges: VecDeque<RecordedExchange>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedExchange {
+    pub id: String,
+    pub execution: Execution,
+}
+
+impl RecordedExchange {
+    pub fn info(&self) -> RecordedExchangeInfo {
+        RecordedExchangeInfo {
+            id: self.id.clone(),
+            started_at: self.execution.started_at.to_rfc3339(),
+            method: self.execution.request.method.clone(),
+            url: self.execution.request.url.clone(),
+            status: self.execution.response.status,
+            size: self.execution.response.size as u64,
+            duration_ms: self.execution.duration_ms,
+        }
+    }
+}
+
+impl ProxyRecordings {
+    pub async fn record(&self, execution: Execution) -> RecordedExchange {
+        let mut recordings = self.inner.write().await;
+        recordings.next_id += 1;
+
+        let exchange = RecordedExchange {
+            id: recordings.next_id.to_string(),
+            execution,
+        };
+        if recordings.exchanges.len() == MAX_EXCHANGES {
+            recordings.exchanges.pop_front();
+        }
+        recordings.exchanges.push_back(exchange.clone());
+
+        exchange
+    }
+
+    /// The exchanges recorded so far, from the oldest to the most recent.
+    pub async fn exchanges(&self) -> Vec<RecordedExchange> {
+        let recordings = self.inner.read().await;
+        recordings.exchanges.iter().cloned().collect()
+    }
+}
+
+type EventHandler = Box<dyn Fn(RecordingProxyEvent) -> joinerror::Result<()> + Send + Sync>;
+
+struct RecordingProxy {
+    ca: CertificateAuthority,
+    /// Sends the relayed requests, without following redirects so that they are relayed too.
+    client: reqwest::Client,
+    recordings: Arc<ProxyRecordings>,
+    on_event: EventHandler,
+    /// Ends the connections tunneled through the proxy, which outlive the ones they were opened from.
+    cancellation: Cancellation,
+    exchanges: AtomicU64,
+}
+
+/// Relays the requests of clients pointed at the proxy and records every exchange. Https
+/// requests are intercepted with certificates issued by `ca`, which clients must trust.
+///
+/// Reports to `on_event` when the proxy is listening, then every exchange it records.
+/// Runs until the context is canceled or its deadline is exceeded.
+pub async fn serve<F>(
+    ctx: &dyn AnyAsyncContext,
+    ca: CertificateAuthority,
+    client: reqwest::Client,
+    recordings: Arc<ProxyRecordings>,
+    port: Option<u16>,
+    on_event: F,
+) -> joinerror::Result<StartRecordingProxyOutput>
+where
+    F: Fn(RecordingProxyEvent) -> joinerror::Result<()> + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port.unwrap_or(0)))
+        .await
+        .join_err_with::<()>(|| {
+            format!(
+                "failed to bind the recording proxy to port {}",
+                port.unwrap_or(0)
+            )
+        })?;
+    let port = listener
+        .local_addr()
+        .join_err::<()>("failed to get the address of the recording proxy")?
+        .port();
+    on_event(RecordingProxyEvent::Started {
+        port,
+        ca_certificate_path: ca.certificate_path().to_path_buf(),
+    })?;
+
+    let cancellation = ctx.cancellation();
+    let proxy = Arc::new(RecordingProxy {
+        ca,
+        client,
+        recordings,
+        on_event: Box::new(on_event),
+        cancellation: cancellation.clone(),
+        exchanges: AtomicU64::new(0),
+    });
+
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            _ = cancellation.wait() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(proxy.clone().serve_connection(stream));
+                }
+                Err(e) => tracing::warn!("failed to accept a recording proxy connection: {}", e),
+            },
+        }
+
+        while connections.try_join_next().is_some() {}
+    }
+    connections.shutdown().await;
+
+    Ok(StartRecordingProxyOutput {
+        exchanges: proxy.exchanges.load(Ordering::Relaxed),
+    })
+}
+
+impl RecordingProxy {
+    async fn serve_connection(self: Arc<Self>, stream: TcpStream) {
+        let service = service_fn(|request| {
+            let proxy = self.clone();
+            async move { Ok::<_, Infallible>(proxy.handle(request).await) }
+        });
+
+        if let Err(e) = http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), service)
+            .with_upgrades()
+            .await
+        {
+            tracing::debug!("recording proxy connection closed: {}", e);
+        }
+    }
+
+    async fn handle(self: Arc<Self>, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if request.method() == Method::CONNECT {
+            return self.tunnel(request).await;
+        }
+
+        // Requests meant for a proxy are sent with their absolute url
+        if request.uri().scheme().is_none() || request.uri().authority().is_none() {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "the recording proxy only relays requests sent with an absolute url".to_string(),
+            );
+        }
+        let url = request.uri().to_string();
+
+        self.relay(request, url).await
+    }
+
+    /// Accepts the tunnel a client opens to an https server, then intercepts the requests
+    /// sent through it by impersonating the server. Tunnels to the hosts that aren't
+    /// intercepted are passed through as is.
+    async fn tunnel(self: Arc<Self>, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        let Some(authority) = request.uri().authority().cloned() else {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "the tunnel has no destination".to_string(),
+            );
+        };
+        if !self.ca.intercepts(authority.host()) {
+            return self.pass_through(request, authority).await;
+        }
+        let config = match self.ca.server_config(authority.host()).await {
+            Ok(config) => config,
+            Err(e) => return error_response(StatusCode::BAD_GATEWAY, e.to_string()),
+        };
+
+        // The connection is only handed over once the response is sent
+        let cancellation = self.cancellation.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = cancellation.wait() => {}
+                _ = async {
+                    match hyper::upgrade::on(request).await {
+                        Ok(upgraded) => self.intercept(upgraded, authority, config).await,
+                        Err(e) => tracing::warn!("failed to open a recording proxy tunnel: {}", e),
+                    }
+                } => {}
+            }
+        });
+
+        Response::new(Full::default())
+    }
+
+    /// Connects the tunnel to the server, without recording what's sent through it.
+    async fn pass_through(
+        &self,
+        request: Request<Incoming>,
+        authority: Authority,
+    ) -> Response<Full<Bytes>> {
+        let mut server = match TcpStream::connect(authority.as_str()).await {
+            Ok(server) => server,
+            Err(e) => {
+                return error_response(
+                    StatusCode::BAD_GATEWAY,
+                    format!("failed to connect to `{}`: {}", authority, e),
+                );
+            }
+        };
+
+        let cancellation = self.cancellation.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = cancellation.wait() => {}
+                _ = async {
+                    match hyper::upgrade::on(request).await {
+                        Ok(upgraded) => {
+                            let mut client = TokioIo::new(upgraded);
+                            if let Err(e) =
+                                tokio::io::copy_bidirectional(&mut client, &mut server).await
+                            {
+                                tracing::debug!(
+                                    "recording proxy tunnel to `{}` closed: {}",
+                                    authority,
+                                    e
+                                );
+                            }
+                        }
+                        Err(e) => tracing::warn!("failed to open a recording proxy tunnel: {}", e),
+                    }
+                } => {}
+            }
+        });
+
+        Response::new(Full::default())
+    }
+
+    async fn intercept(
+        self: Arc<Self>,
+        upgraded: Upgraded,
+        authority: Authority,
+        config: Arc<rustls::ServerConfig>,
+    ) {
+        let stream = match TlsAcceptor::from(config)
+            .accept(TokioIo::new(upgraded))
+            .await
+        {
+            Ok(stream) => stream,
+            Err(e) => {
+                // Most likely a client that doesn't trust the certificate authority
+                tracing::warn!("failed to intercept the tunnel to `{}`: {}", authority, e);
+                return;
+            }
+        };
+
+        let service = service_fn(|request: Request<Incoming>| {
+            let proxy = self.clone();
+            let url = format!(
+                "https://{}{}",
+                authority,
+                request.uri().path_and_query().map_or("/", |p| p.as_str())
+            );
+            async move { Ok::<_, Infallible>(proxy.relay(request, url).await) }
+        });
+
+        if let Err(e) = http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), service)
+            .await
+        {
+            tracing::debug!("recording proxy tunnel to `{}` closed: {}", authority, e);
+        }
+    }
+
+    /// Sends the request to `url` and records the exchange once the response is read in full.
+    async fn relay(&self, request: Request<Incoming>, url: String) -> Response<Full<Bytes>> {
+        let started_at = Utc::now();
+        let timer = Instant::now();
+
+        let (parts, body) = request.into_parts();
+        let body = match body.collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(e) => {
+                return error_response(
+                    StatusCode::BAD_REQUEST,
+                    format!("failed to read the request body: {}", e),
+                );
+            }
+        };
+
+        let mut headers = relayed_headers(&parts.headers);
+        // The host is the one of the url, and bodies are recorded as sent rather than compressed
+        headers.remove(header::HOST);
+        headers.remove(header::ACCEPT_ENCODING);
+
+        let mut builder = self
+            .client
+            .request(parts.method.clone(), &url)
+            .headers(headers.clone());
+        if !body.is_empty() {
+            builder = builder.body(body.clone());
+        }
+
+        let response = match builder.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                return error_response(
+                    StatusCode::BAD_GATEWAY,
+                    format!("failed to send request to `{}`: {}", url, e),
+                );
+            }
+        };
+        let status = response.status();
+        let response_headers = relayed_headers(response.headers());
+        let response_body = match response.bytes().await {
+            Ok(body) => body,
+            Err(e) => {
+                return error_response(
+                    StatusCode::BAD_GATEWAY,
+                    format!("failed to read the response of `{}`: {}", url, e),
+                );
+            }
+        };
+
+        let exchange = self
+            .recordings
+            .record(Execution {
+                started_at,
+                duration_ms: timer.elapsed().as_millis() as u64,
+                request: RecordedRequest::relayed(
+                    &parts.method,
+                    &url,
+                    parts.version,
+                    &headers,
+                    &body,
+                ),
+                response: RecordedResponse::relayed(status, &response_headers, &response_body),
+            })
+            .await;
+        self.exchanges.fetch_add(1, Ordering::Relaxed);
+        let event = RecordingProxyEvent::Exchange {
+            exchange: exchange.info(),
+        };
+        if let Err(e) = (self.on_event)(event) {
+            tracing::warn!("failed to report a recorded exchange: {}", e);
+        }
+
+        let mut http_response = Response::new(Full::new(response_body));
+        *http_response.status_mut() = status;
+        *http_response.headers_mut() = response_headers;
+        // Set again from the body, which was decoded if it was chunked
+        http_response.headers_mut().remove(header::CONTENT_LENGTH);
+
+        http_response
+    }
+}
+
+fn relayed_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    for name in &HOP_BY_HOP_HEADERS {
+        headers.remove(name);
+    }
+    headers.remove("keep-alive");
+
+    headers
+}
+
+fn error_response(status: StatusCode, message: String) -> Response<Full<Bytes>> {
+    tracing::warn!("recording proxy error: {}", message);
+
+    let body = serde_json::json!({ "error": message }).to_string();
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution() -> Execution {
+        Execution {
+            started_at: Utc::now(),
+            duration_ms: 0,
+            request: RecordedRequest::relayed(
+                &Method::GET,
+                "http://localhost/",
+                hyper::Version::HTTP_11,
+                &HeaderMap::new(),
+                b"",
+            ),
+            response: RecordedResponse::relayed(StatusCode::OK, &HeaderMap::new(), b""),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recordings_keep_latest_exchanges() {
+        let recordings = ProxyRecordings::default();
+        for _ in 0..MAX_EXCHANGES + 2 {
+            recordings.record(execution()).await;
+        }
+
+        let exchanges = recordings.exchanges().await;
+        assert_eq!(exchanges.len(), MAX_EXCHANGES);
+        assert_eq!(exchanges[0].id, "3");
+        assert_eq!(
+            exchanges.last().unwrap().id,
+            (MAX_EXCHANGES + 2).to_string()
+        );
+    }
+
+    #[test]
+    fn test_relayed_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONNECTION, "keep-alive".parse().unwrap());
+        headers.insert("proxy-connection", "keep-alive".parse().unwrap());
+        headers.insert("keep-alive", "timeout=5".parse().unwrap());
+        headers.insert(header::ACCEPT, "application/json".parse().unwrap());
+
+        let headers = relayed_headers(&headers);
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[header::ACCEPT], "application/json");
+    }
+}
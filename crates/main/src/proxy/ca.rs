@@ -0,0 +1,426 @@
+//! The certificate authority https traffic is intercepted with.
+//!
+//! It's generated the first time the proxy is started and kept in the profile, so that
+//! clients only have to trust it once. Certificates of the hosts the clients connect to
+//! are then issued on the fly, and cached for as long as the proxy runs.
+//!
+//! Since clients trust it for every host, it's constrained to the loopback hosts and the
+//! ones intercepted, and kept short-lived. It's generated again when either changes.
+
+use chrono::{Datelike, Days, NaiveDate, Utc};
+use joinerror::{Error, ResultExt};
+use rcgen::{
+    BasicConstraints, Certificate, CertificateParams, CidrSubnet, DistinguishedName, DnType,
+    ExtendedKeyUsagePurpose, GeneralSubtree, IsCa, KeyPair, KeyUsagePurpose, NameConstraints,
+    SanType,
+};
+use rustls::{
+    ServerConfig,
+    pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer},
+};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+
+const CERTIFICATE_FILE: &str = "ca.pem";
+const KEY_FILE: &str = "ca.key";
+const COMMON_NAME: &str = "Sapic Recording Proxy CA";
+
+const CA_VALIDITY_DAYS: u64 = 90;
+// The authority is renewed before it expires within the validity of the certificates it issues
+const HOST_VALIDITY_DAYS: u64 = 7;
+const LOCALHOST: &str = "localhost";
+
+pub struct CertificateAuthority {
+    certificate_path: PathBuf,
+    certificate: Certificate,
+    key: KeyPair,
+    /// Hosts certificates can be issued for, along with their subdomains and the loopback hosts.
+    hosts: Vec<String>,
+    /// Configs of the hosts intercepted so far, since issuing a certificate is slow.
+    configs: Mutex<HashMap<String, Arc<ServerConfig>>>,
+}
+
+impl CertificateAuthority {
+    /// Loads the authority stored in `dir`, generating it when there is none yet, or when the
+    /// stored one is about to expire or is constrained to other hosts than `hosts`.
+    pub async fn load_or_generate(dir: &Path, hosts: &[String]) -> joinerror::Result<Self> {
+        let certificate_path = dir.join(CERTIFICATE_FILE);
+        let key_path = dir.join(KEY_FILE);
+
+        let mut hosts = hosts
+            .iter()
+            .map(|host| normalize_host(host))
+            .filter(|host| !host.is_empty())
+            .collect::<Vec<_>>();
+        hosts.sort();
+        hosts.dedup();
+        let name_constraints = name_constraints(&hosts);
+
+        let exists = tokio::fs::try_exists(&certificate_path)
+            .await
+            .join_err_with::<()>(|| format!("failed to access `{}`", dir.display()))?;
+        let stored = if exists {
+            let certificate_pem = tokio::fs::read_to_string(&certificate_path)
+                .await
+                .join_err_with::<()>(|| {
+                    format!("failed to read `{}`", certificate_path.display())
+                })?;
+            let key_pem = tokio::fs::read_to_string(&key_path)
+                .await
+                .join_err_with::<()>(|| format!("failed to read `{}`", key_path.display()))?;
+
+            let key = KeyPair::from_pem(&key_pem).map_err(|e| {
+                Error::new::<()>(format!("invalid proxy certificate authority key: {}", e))
+            })?;
+            let params = CertificateParams::from_ca_cert_pem(&certificate_pem).map_err(|e| {
+                Error::new::<()>(format!("invalid proxy certificate authority: {}", e))
+            })?;
+
+            Some((params, key))
+        } else {
+            None
+        };
+
+        let (params, key) = match stored {
+            Some((params, key))
+                if params.name_constraints.as_ref() == Some(&name_constraints)
+                    && !expires_soon(&params) =>
+            {
+                (params, key)
+            }
+            _ => {
+                let (params, key, certificate) = generate(name_constraints)?;
+                write(
+                    dir,
+                    &key_path,
+                    &key.serialize_pem(),
+                    &certificate_path,
+                    &certificate.pem(),
+                )
+                .await?;
+
+                (params, key)
+            }
+        };
+
+        // Certificates are issued by a certificate rather than by its params. Signing the params
+        // again yields one with the same subject and key, which is all the issued ones refer to.
+        let certificate = params.self_signed(&key).map_err(|e| {
+            Error::new::<()>(format!("failed to load proxy certificate authority: {}", e))
+        })?;
+
+        Ok(Self {
+            certificate_path,
+            certificate,
+            key,
+            hosts,
+            configs: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Whether certificates for `host`, a domain name or an ip address, can be issued.
+    pub fn intercepts(&self, host: &str) -> bool {
+        let host = normalize_host(host);
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return ip.is_loopback() || self.hosts.contains(&host);
+        }
+
+        std::iter::once(LOCALHOST)
+            .chain(self.hosts.iter().map(String::as_str))
+            .any(|domain| {
+                host == domain
+                    || host
+                        .strip_suffix(domain)
+                        .is_some_and(|subdomain| subdomain.ends_with('.'))
+            })
+    }
+
+    /// Path of the certificate clients must trust, in PEM format.
+    pub fn certificate_path(&self) -> &Path {
+        &self.certificate_path
+    }
+
+    /// The config of a tls server impersonating `host`, a domain name or an ip address.
+    pub async fn server_config(&self, host: &str) -> joinerror::Result<Arc<ServerConfig>> {
+        if !self.intercepts(host) {
+            return Err(Error::new::<()>(format!(
+                "`{}` is not one of the intercepted hosts",
+                host
+            )));
+        }
+        let host = normalize_host(host);
+
+        let mut configs = self.configs.lock().await;
+        if let Some(config) = configs.get(&host) {
+            return Ok(config.clone());
+        }
+
+        let config = Arc::new(self.issue(&host)?);
+        configs.insert(host, config.clone());
+
+        Ok(config)
+    }
+
+    fn issue(&self, host: &str) -> joinerror::Result<ServerConfig> {
+        let err = |e: &dyn std::fmt::Display| {
+            Error::new::<()>(format!(
+                "failed to issue a certificate for `{}`: {}",
+                host, e
+            ))
+        };
+
+        let mut params = CertificateParams::default();
+        params.distinguished_name = DistinguishedName::new();
+        params.distinguished_name.push(DnType::CommonName, host);
+        params.subject_alt_names = vec![match host.parse::<IpAddr>() {
+            Ok(ip) => SanType::IpAddress(ip),
+            Err(_) => SanType::DnsName(host.try_into().map_err(|e| err(&e))?),
+        }];
+        params.key_usages = vec![
+            KeyUsagePurpose::DigitalSignature,
+            KeyUsagePurpose::KeyEncipherment,
+        ];
+        params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+        set_validity(&mut params, HOST_VALIDITY_DAYS);
+
+        let key = KeyPair::generate().map_err(|e| err(&e))?;
+        let certificate = params
+            .signed_by(&key, &self.certificate, &self.key)
+            .map_err(|e| err(&e))?;
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let mut config = ServerConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|e| err(&e))?
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![certificate.der().clone()],
+                PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.serialize_der())),
+            )
+            .map_err(|e| err(&e))?;
+        // Intercepted connections are only served over http/1.1
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+        Ok(config)
+    }
+}
+
+fn generate(
+    name_constraints: NameConstraints,
+) -> joinerror::Result<(CertificateParams, KeyPair, Certificate)> {
+    let err = |e: rcgen::Error| {
+        Error::new::<()>(format!(
+            "failed to generate proxy certificate authority: {}",
+            e
+        ))
+    };
+
+    let mut params = CertificateParams::default();
+    params.distinguished_name = DistinguishedName::new();
+    params
+        .distinguished_name
+        .push(DnType::CommonName, COMMON_NAME);
+    params
+        .distinguished_name
+        .push(DnType::OrganizationName, "Sapic");
+    params.is_ca = IsCa::Ca(BasicConstraints::Constrained(0));
+    params.name_constraints = Some(name_constraints);
+    params.key_usages = vec![
+        KeyUsagePurpose::KeyCertSign,
+        KeyUsagePurpose::CrlSign,
+        KeyUsagePurpose::DigitalSignature,
+    ];
+    set_validity(&mut params, CA_VALIDITY_DAYS);
+
+    let key = KeyPair::generate().map_err(err)?;
+    let certificate = params.clone().self_signed(&key).map_err(err)?;
+
+    Ok((params, key, certificate))
+}
+
+/// The key is written first, since the authority is considered generated once its certificate exists.
+async fn write(
+    dir: &Path,
+    key_path: &Path,
+    key_pem: &str,
+    certificate_path: &Path,
+    certificate_pem: &str,
+) -> joinerror::Result<()> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .join_err_with::<()>(|| format!("failed to create `{}`", dir.display()))?;
+
+    // Only readable by the user from the start, rather than restricted once written
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut key_file = options
+        .open(key_path)
+        .await
+        .join_err_with::<()>(|| format!("failed to create `{}`", key_path.display()))?;
+    key_file
+        .write_all(key_pem.as_bytes())
+        .await
+        .join_err_with::<()>(|| format!("failed to write `{}`", key_path.display()))?;
+    key_file
+        .flush()
+        .await
+        .join_err_with::<()>(|| format!("failed to write `{}`", key_path.display()))?;
+
+    tokio::fs::write(certificate_path, certificate_pem)
+        .await
+        .join_err_with::<()>(|| format!("failed to write `{}`", certificate_path.display()))
+}
+
+/// Hosts are compared in the form certificates are issued for.
+fn normalize_host(host: &str) -> String {
+    host.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_ascii_lowercase()
+}
+
+/// Restricts the authority to the loopback hosts and `hosts`. A domain name also covers its
+/// subdomains, while an ip address only covers itself.
+fn name_constraints(hosts: &[String]) -> NameConstraints {
+    let mut permitted_subtrees = vec![
+        GeneralSubtree::DnsName(LOCALHOST.to_string()),
+        GeneralSubtree::IpAddress(CidrSubnet::from_addr_prefix(
+            IpAddr::V4(Ipv4Addr::new(127, 0, 0, 0)),
+            8,
+        )),
+        GeneralSubtree::IpAddress(CidrSubnet::from_addr_prefix(
+            IpAddr::V6(Ipv6Addr::LOCALHOST),
+            128,
+        )),
+    ];
+    for host in hosts {
+        permitted_subtrees.push(match host.parse::<IpAddr>() {
+            Ok(ip @ IpAddr::V4(_)) => {
+                GeneralSubtree::IpAddress(CidrSubnet::from_addr_prefix(ip, 32))
+            }
+            Ok(ip @ IpAddr::V6(_)) => {
+                GeneralSubtree::IpAddress(CidrSubnet::from_addr_prefix(ip, 128))
+            }
+            Err(_) => GeneralSubtree::DnsName(host.clone()),
+        });
+    }
+
+    NameConstraints {
+        permitted_subtrees,
+        excluded_subtrees: vec![],
+    }
+}
+
+/// Whether the authority would expire before a certificate issued now.
+fn expires_soon(params: &CertificateParams) -> bool {
+    let renewal = Utc::now() + Days::new(HOST_VALIDITY_DAYS + 1);
+    params.not_after.unix_timestamp() < renewal.timestamp()
+}
+
+/// Valid from the day before, so that clients whose clock is slightly behind accept it.
+fn set_validity(params: &mut CertificateParams, days: u64) {
+    let today = Utc::now().date_naive();
+    let date =
+        |date: NaiveDate| rcgen::date_time_ymd(date.year(), date.month() as u8, date.day() as u8);
+
+    params.not_before = date(today - Days::new(1));
+    params.not_after = date(today + Days::new(days));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_authority_is_generated_once() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("proxy-ca-test-{}", nanos));
+
+        let hosts = vec!["example.com".to_string()];
+
+        let generated = CertificateAuthority::load_or_generate(&dir, &hosts)
+            .await
+            .unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let metadata = std::fs::metadata(dir.join(KEY_FILE)).unwrap();
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        }
+
+        let loaded = CertificateAuthority::load_or_generate(&dir, &hosts)
+            .await
+            .unwrap();
+        assert_eq!(generated.key.serialize_pem(), loaded.key.serialize_pem());
+        assert!(generated.server_config("localhost").await.is_ok());
+
+        // Intercepting other hosts needs an authority constrained to them
+        let regenerated =
+            CertificateAuthority::load_or_generate(&dir, &["example.org".to_string()])
+                .await
+                .unwrap();
+        assert_ne!(
+            generated.key.serialize_pem(),
+            regenerated.key.serialize_pem()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_name_constraints_cover_intercepted_hosts() {
+        let hosts = vec!["example.com".to_string(), "10.0.0.1".to_string()];
+        let constraints = name_constraints(&hosts);
+
+        assert!(
+            constraints
+                .permitted_subtrees
+                .contains(&GeneralSubtree::DnsName("example.com".to_string()))
+        );
+        assert!(
+            constraints
+                .permitted_subtrees
+                .contains(&GeneralSubtree::IpAddress(CidrSubnet::from_addr_prefix(
+                    "10.0.0.1".parse().unwrap(),
+                    32
+                )))
+        );
+        assert!(constraints.excluded_subtrees.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_authority_only_intercepts_constrained_hosts() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("proxy-ca-test-{}", nanos));
+
+        let ca = CertificateAuthority::load_or_generate(&dir, &["Example.com.".to_string()])
+            .await
+            .unwrap();
+
+        assert!(ca.intercepts("localhost"));
+        assert!(ca.intercepts("127.0.0.1"));
+        assert!(ca.intercepts("[::1]"));
+        assert!(ca.intercepts("example.com"));
+        assert!(ca.intercepts("api.example.com"));
+        assert!(!ca.intercepts("notexample.com"));
+        assert!(!ca.intercepts("example.org"));
+        assert!(ca.server_config("example.org").await.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
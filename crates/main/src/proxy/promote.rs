@@ -0,0 +1,436 @@
+//! Endpoints created from the exchanges recorded by the proxy.
+//!
+//! Segments of the recorded paths that look like identifiers become path variables, so that
+//! `/users/42` and `/users/43` are promoted into a single `/users/:user_id` endpoint.
+
+use moss_project::models::types::{
+    CreateItemResourceParams,
+    http::{
+        AddBodyParams, AddHeaderParams, AddPathParamParams, AddQueryParamParams,
+        AddUrlencodedParamParams, HeaderParamOptions, PathParamOptions, QueryParamOptions,
+        UrlencodedParamOptions,
+    },
+};
+use reqwest::Url;
+use sapic_base::resource::types::primitives::ResourceClass;
+use serde_json::json;
+use std::path::PathBuf;
+use template_parser::{UrlParser, models::types::ParsedValue};
+
+use crate::{
+    import::{self, form_pairs, variable_name},
+    resource::history::{self, Execution, RecordedBody},
+};
+
+/// Headers set by the http client when the request is sent.
+const MANAGED_HEADERS: [&str; 4] = ["host", "content-length", "connection", "keep-alive"];
+
+/// What endpoints are deduplicated by: their method and the path of their url,
+/// whatever the names of its path variables.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EndpointKey {
+    method: String,
+    path: String,
+}
+
+impl EndpointKey {
+    /// The key of an endpoint, unless its url is rejected by the template parser.
+    pub fn new(method: &str, url: &str) -> Option<Self> {
+        let parsed = UrlParser::parse_url(url.trim()).ok()?;
+        let path = parsed
+            .path_part
+            .iter()
+            .map(|value| match value {
+                ParsedValue::String(s) => s.clone(),
+                ParsedValue::PathVariable(_) => ":".to_string(),
+                ParsedValue::Variable(name) => format!("{{{{{}}}}}", name),
+            })
+            .collect::<String>();
+
+        Some(Self {
+            method: method.to_uppercase(),
+            path: path.trim_matches('/').to_string(),
+        })
+    }
+}
+
+/// The endpoint a recorded exchange is promoted into.
+#[derive(Debug)]
+pub struct PromotedEndpoint<'a> {
+    pub key: EndpointKey,
+    execution: &'a Execution,
+    url: Url,
+    /// The path of the url, with its identifiers replaced by path variables.
+    path: String,
+    /// The recorded values of the path variables.
+    path_params: Vec<(String, String)>,
+}
+
+impl<'a> PromotedEndpoint<'a> {
+    /// Fails for requests whose url cannot be parsed, which the proxy doesn't relay anyway.
+    pub fn new(execution: &'a Execution) -> joinerror::Result<Self> {
+        let url = Url::parse(&execution.request.url).map_err(|e| {
+            joinerror::Error::new::<()>(format!(
+                "invalid recorded url `{}`: {}",
+                execution.request.url, e
+            ))
+        })?;
+
+        let (path, path_params) = templated_path(url.path());
+        let method = &execution.request.method;
+        let key =
+            EndpointKey::new(method, &url_template(&url, &path)).unwrap_or_else(|| EndpointKey {
+                method: method.to_uppercase(),
+                path: path.trim_matches('/').to_string(),
+            });
+
+        Ok(Self {
+            key,
+            execution,
+            url,
+            path,
+            path_params,
+        })
+    }
+
+    pub fn name(&self) -> String {
+        format!("{} {}", self.execution.request.method, self.path)
+    }
+
+    /// The params of the endpoint, created as `name` in the `dir` of the project.
+    ///
+    /// Headers carrying credentials are left out, so that they aren't written to the project
+    /// in plain text. The auth of the endpoint is configured instead.
+    pub fn into_params(self, dir: PathBuf, name: String, order: isize) -> CreateItemResourceParams {
+        let request = &self.execution.request;
+
+        let path_params = self
+            .path_params
+            .iter()
+            .enumerate()
+            .map(|(order, (name, value))| AddPathParamParams {
+                name: name.clone(),
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: PathParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+        let query_params = self
+            .url
+            .query_pairs()
+            .enumerate()
+            .map(|(order, (name, value))| AddQueryParamParams {
+                name: name.into_owned(),
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        let body = request.body.as_ref().and_then(body);
+        let is_implied_content_type = |value: &str| match &body {
+            Some(AddBodyParams::Json(_)) => value.eq_ignore_ascii_case("application/json"),
+            Some(AddBodyParams::Xml(_)) => value.eq_ignore_ascii_case("application/xml"),
+            Some(AddBodyParams::Text(_)) => value.eq_ignore_ascii_case("text/plain"),
+            // Form bodies are sent with their own content type
+            Some(AddBodyParams::Urlencoded(_)) | Some(AddBodyParams::FormData(_)) => true,
+            // It would describe a body that was left out
+            Some(AddBodyParams::Binary(_)) | None => true,
+        };
+        let headers = request
+            .headers
+            .iter()
+            .filter(|(name, value)| {
+                let is_managed = MANAGED_HEADERS
+                    .iter()
+                    .any(|managed| name.eq_ignore_ascii_case(managed));
+                let is_implied =
+                    name.eq_ignore_ascii_case("content-type") && is_implied_content_type(value);
+                !is_managed && !is_implied && !history::is_sensitive_header(name)
+            })
+            .enumerate()
+            .map(|(order, (name, value))| AddHeaderParams {
+                name: name.clone(),
+                value: json!(value),
+                order: order as isize,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            })
+            .collect();
+
+        CreateItemResourceParams {
+            path: dir,
+            class: ResourceClass::Endpoint,
+            name,
+            description: None,
+            order,
+            protocol: import::protocol(&request.method),
+            url: Some(url_template(&self.url, &self.path)),
+            headers,
+            path_params,
+            query_params,
+            body,
+            body_schema: None,
+            components: vec![],
+            auth: None,
+            schema: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        }
+    }
+}
+
+fn url_template(url: &Url, path: &str) -> String {
+    format!("{}{}", url.origin().ascii_serialization(), path)
+}
+
+/// Replaces the segments of the path that look like identifiers with path variables,
+/// named after the segment before them, returning their recorded values.
+fn templated_path(path: &str) -> (String, Vec<(String, String)>) {
+    let mut params = Vec::<(String, String)>::new();
+    let mut previous = None;
+    let mut segments = Vec::new();
+
+    for segment in path.trim_end_matches('/').split('/') {
+        if !is_identifier(segment) {
+            // Colons would be taken for path variables
+            segments.push(segment.replace(':', "%3A"));
+            previous = Some(segment).filter(|segment| !segment.is_empty());
+            continue;
+        }
+
+        let name = previous.map_or_else(|| "id".to_string(), variable_for);
+        let mut unique = name.clone();
+        let mut suffix = 2;
+        while params.iter().any(|(taken, _)| taken == &unique) {
+            unique = format!("{}_{}", name, suffix);
+            suffix += 1;
+        }
+
+        segments.push(format!(":{}", unique));
+        params.push((unique, import::decode(segment)));
+        previous = None;
+    }
+
+    let path = segments.join("/");
+    if path.is_empty() {
+        ("/".to_string(), params)
+    } else {
+        (path, params)
+    }
+}
+
+fn is_identifier(segment: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+    if segment.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+
+    // Uuids, such as 6f1c2d3e-4b5a-6978-8a9b-0c1d2e3f4a5b
+    let is_uuid = segment.len() == 36
+        && segment.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        });
+    // Hashes and object ids, such as 5f2b8c0e9d1a7b3c4e6f8a90
+    let is_hash = segment.len() >= 16
+        && segment.chars().all(|c| c.is_ascii_hexdigit())
+        && segment.chars().any(|c| c.is_ascii_digit());
+
+    is_uuid || is_hash
+}
+
+/// The name of the variable of an identifier following `segment`, such as `user_id` for `users`.
+fn variable_for(segment: &str) -> String {
+    let name = variable_name(&import::decode(segment)).to_lowercase();
+    let singular = if let Some(stem) = name.strip_suffix("ies").filter(|s| !s.is_empty()) {
+        format!("{}y", stem)
+    } else if name.len() > 1 && name.ends_with('s') && !name.ends_with("ss") {
+        name[..name.len() - 1].to_string()
+    } else {
+        name
+    };
+
+    format!("{}_id", singular)
+}
+
+/// The body of the request, whose kind is told by its mime type.
+/// Bodies that aren't text, such as files, are left out.
+fn body(body: &RecordedBody) -> Option<AddBodyParams> {
+    let text = body.text.as_deref().filter(|text| !text.is_empty())?;
+    let mime_type = body.mime_type.to_lowercase();
+
+    let body = if mime_type.starts_with("application/x-www-form-urlencoded") {
+        AddBodyParams::Urlencoded(
+            form_pairs(text)
+                .enumerate()
+                .map(|(order, (name, value))| AddUrlencodedParamParams {
+                    name,
+                    value: json!(value),
+                    order: order as isize,
+                    description: None,
+                    options: UrlencodedParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                    id: None,
+                })
+                .collect(),
+        )
+    } else if mime_type.starts_with("multipart/") {
+        // Their parts would need to be split from the recorded body
+        return None;
+    } else if mime_type.contains("json") {
+        serde_json::from_str(text)
+            .map(AddBodyParams::Json)
+            .unwrap_or_else(|_| AddBodyParams::Text(text.to_string()))
+    } else if mime_type.contains("xml") {
+        AddBodyParams::Xml(text.to_string())
+    } else {
+        AddBodyParams::Text(text.to_string())
+    };
+
+    Some(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::resource::history::{RecordedRequest, RecordedResponse};
+
+    use super::*;
+
+    fn execution(method: &str, url: &str, headers: &[(&str, &str)], body: &str) -> Execution {
+        let headers = headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        let mime_type = headers
+            .iter()
+            .find(|(name, _)| name == "content-type")
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default();
+
+        Execution {
+            started_at: Utc::now(),
+            duration_ms: 0,
+            request: RecordedRequest {
+                method: method.to_string(),
+                url: url.to_string(),
+                http_version: "HTTP/1.1".to_string(),
+                headers,
+                body: (!body.is_empty()).then(|| RecordedBody {
+                    mime_type,
+                    size: Some(body.len()),
+                    text: Some(body.to_string()),
+                    params: vec![],
+                }),
+            },
+            response: RecordedResponse::default(),
+        }
+    }
+
+    #[test]
+    fn test_templated_path() {
+        assert_eq!(
+            templated_path("/users/42/orders/6f1c2d3e-4b5a-6978-8a9b-0c1d2e3f4a5b/"),
+            (
+                "/users/:user_id/orders/:order_id".to_string(),
+                vec![
+                    ("user_id".to_string(), "42".to_string()),
+                    (
+                        "order_id".to_string(),
+                        "6f1c2d3e-4b5a-6978-8a9b-0c1d2e3f4a5b".to_string()
+                    ),
+                ]
+            )
+        );
+        assert_eq!(
+            templated_path("/1/2/categories/me"),
+            (
+                "/:id/:id_2/categories/me".to_string(),
+                vec![
+                    ("id".to_string(), "1".to_string()),
+                    ("id_2".to_string(), "2".to_string()),
+                ]
+            )
+        );
+        assert_eq!(templated_path("/"), ("/".to_string(), vec![]));
+        assert_eq!(
+            templated_path("/v1:batch"),
+            ("/v1%3Abatch".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn test_endpoint_keys_ignore_variable_names() {
+        let recorded = execution("GET", "http://localhost:8080/users/42/?page=2", &[], "");
+        let endpoint = PromotedEndpoint::new(&recorded).unwrap();
+
+        assert_eq!(
+            Some(endpoint.key.clone()),
+            EndpointKey::new("get", "{{base_url}}/users/:id")
+        );
+        assert_ne!(
+            Some(endpoint.key),
+            EndpointKey::new("GET", "{{base_url}}/users/me")
+        );
+    }
+
+    #[test]
+    fn test_promoted_endpoint_params() {
+        let recorded = execution(
+            "POST",
+            "http://localhost:8080/pets/7/tags?notify=true",
+            &[
+                ("host", "localhost:8080"),
+                ("content-type", "application/json"),
+                ("accept", "application/json"),
+                ("authorization", "Bearer token"),
+                ("x-api-key", "secret"),
+            ],
+            r#"{"tag":"cute"}"#,
+        );
+        let endpoint = PromotedEndpoint::new(&recorded).unwrap();
+        assert_eq!(endpoint.name(), "POST /pets/:pet_id/tags");
+
+        let params = endpoint.into_params(PathBuf::from("pets"), "Tag".to_string(), 3);
+        assert_eq!(
+            params.url.as_deref(),
+            Some("http://localhost:8080/pets/:pet_id/tags")
+        );
+        assert_eq!(params.order, 3);
+        assert_eq!(params.path_params[0].name, "pet_id");
+        assert_eq!(params.path_params[0].value, json!("7"));
+        assert_eq!(params.query_params[0].name, "notify");
+        assert_eq!(
+            params
+                .headers
+                .iter()
+                .map(|header| header.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["accept"]
+        );
+        assert!(
+            matches!(params.body, Some(AddBodyParams::Json(body)) if body == json!({ "tag": "cute" }))
+        );
+    }
+}
@@ -0,0 +1,110 @@
+pub mod amqp;
+pub mod mqtt;
+
+use chrono::Utc;
+use sapic_ipc::contracts::main::{
+    queue::{QueueEvent, QueueMessageInfo, QueueMessageProperty},
+    resource::ResolvedParam,
+};
+
+/// A message to publish, with the variables of the endpoint rendered.
+#[derive(Debug, Clone)]
+pub struct OutgoingMessage {
+    pub payload: Vec<u8>,
+    /// Headers of the endpoint, sent as the headers of AMQP messages and the user properties
+    /// of MQTT 5 ones.
+    pub headers: Vec<(String, String)>,
+}
+
+impl OutgoingMessage {
+    pub fn new(payload: String, headers: &[ResolvedParam]) -> Self {
+        Self {
+            payload: payload.into_bytes(),
+            headers: headers
+                .iter()
+                .map(|header| (header.name.clone(), header.value.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// A message received from the broker.
+#[derive(Debug, Clone, Default)]
+pub struct ReceivedMessage {
+    pub topic: String,
+    pub exchange: Option<String>,
+    pub payload: Vec<u8>,
+    pub properties: Vec<QueueMessageProperty>,
+    pub headers: Vec<QueueMessageProperty>,
+    pub qos: Option<u8>,
+    pub redelivered: bool,
+}
+
+impl ReceivedMessage {
+    pub fn into_info(self, seq: u64) -> QueueMessageInfo {
+        let size = self.payload.len() as u64;
+        let (text, data) = match String::from_utf8(self.payload) {
+            Ok(text) => (Some(text), None),
+            Err(e) => (None, Some(e.into_bytes())),
+        };
+
+        QueueMessageInfo {
+            seq,
+            topic: self.topic,
+            exchange: self.exchange,
+            timestamp: Utc::now().to_rfc3339(),
+            size,
+            text,
+            data,
+            properties: self.properties,
+            headers: self.headers,
+            qos: self.qos,
+            redelivered: self.redelivered,
+        }
+    }
+}
+
+pub(crate) fn property(name: &str, value: impl ToString) -> QueueMessageProperty {
+    QueueMessageProperty {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// Counts the messages of a subscription, and tells when it has received enough of them.
+struct Subscription<'a, F> {
+    received: u64,
+    max_messages: Option<u64>,
+    on_event: &'a F,
+}
+
+impl<'a, F> Subscription<'a, F>
+where
+    F: Fn(QueueEvent) -> joinerror::Result<()>,
+{
+    fn new(max_messages: Option<u64>, on_event: &'a F) -> Self {
+        Self {
+            received: 0,
+            max_messages,
+            on_event,
+        }
+    }
+
+    fn subscribed(&self, source: String) -> joinerror::Result<()> {
+        (self.on_event)(QueueEvent::Subscribed { source })
+    }
+
+    /// Reports the message, returning whether the subscription should go on.
+    fn receive(&mut self, message: ReceivedMessage) -> joinerror::Result<bool> {
+        self.received += 1;
+        (self.on_event)(QueueEvent::Message {
+            message: message.into_info(self.received),
+        })?;
+
+        Ok(self.max_messages.is_none_or(|max| self.received < max))
+    }
+
+    fn received(&self) -> u64 {
+        self.received
+    }
+}
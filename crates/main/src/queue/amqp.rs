@@ -0,0 +1,351 @@
+use futures::StreamExt;
+use joinerror::{Error, OptionExt};
+use lapin::{
+    BasicProperties, Channel, Confirmation, Connection, ConnectionProperties,
+    message::Delivery,
+    options::{
+        BasicAckOptions, BasicConsumeOptions, BasicPublishOptions, ConfirmSelectOptions,
+        QueueBindOptions, QueueDeclareOptions,
+    },
+    types::{AMQPValue, FieldTable, LongString, ShortString},
+};
+use moss_project::models::{operations::DescribeResourceOutput, types::amqp::AmqpInfo};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_core::context::{self, AnyAsyncContext, ContextResultExt};
+use sapic_ipc::contracts::main::queue::{QueueEvent, QueueMessageProperty};
+use url::Url;
+
+use crate::{
+    queue::{OutgoingMessage, ReceivedMessage, Subscription, property},
+    resource::EnvironmentVariables,
+};
+
+const REPLY_SUCCESS: u16 = 200;
+
+/// Returns the AMQP settings of the endpoint, failing when it isn't an AMQP endpoint or has
+/// none.
+pub fn amqp_settings(desc: &DescribeResourceOutput) -> joinerror::Result<&AmqpInfo> {
+    if desc.class != ResourceClass::Endpoint || desc.protocol != Some(ResourceProtocol::Amqp) {
+        return Err(Error::new::<()>(format!(
+            "resource `{}` is not an amqp endpoint",
+            desc.name
+        )));
+    }
+
+    desc.amqp
+        .as_ref()
+        .ok_or_join_err_with::<()>(|| format!("endpoint `{}` has no amqp settings", desc.name))
+}
+
+/// Replaces the variables of the settings that can reference them.
+pub fn render_settings(settings: &AmqpInfo, variables: &EnvironmentVariables) -> AmqpInfo {
+    let render = |value: &Option<String>| value.as_deref().map(|value| variables.render(value));
+
+    AmqpInfo {
+        username: render(&settings.username),
+        password: render(&settings.password),
+        exchange: variables.render(&settings.exchange),
+        routing_key: variables.render(&settings.routing_key),
+        queue: render(&settings.queue),
+        payload: variables.render(&settings.payload),
+        content_type: render(&settings.content_type),
+        correlation_id: render(&settings.correlation_id),
+        reply_to: render(&settings.reply_to),
+        message_id: render(&settings.message_id),
+        ..settings.clone()
+    }
+}
+
+/// Publishes the message to the exchange of the settings, waiting for the broker to confirm
+/// it.
+pub async fn publish(
+    ctx: &dyn AnyAsyncContext,
+    url: &str,
+    settings: &AmqpInfo,
+    message: &OutgoingMessage,
+) -> joinerror::Result<()> {
+    context::abortable(ctx, async {
+        let (connection, channel) = connect(url, settings).await?;
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to enable publisher confirms: {e}")))?;
+
+        let confirmation = channel
+            .basic_publish(
+                short_string("exchange", &settings.exchange)?,
+                short_string("routing key", &settings.routing_key)?,
+                BasicPublishOptions::default(),
+                &message.payload,
+                message_properties(settings, &message.headers)?,
+            )
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to publish message: {e}")))?
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to publish message: {e}")))?;
+        if let Confirmation::Nack(_) = confirmation {
+            return Err(Error::new::<()>("broker rejected the message"));
+        }
+
+        // The message is confirmed, failing to close the connection cleanly doesn't matter
+        let _ = connection.close(REPLY_SUCCESS, "OK".into()).await;
+        Ok(())
+    })
+    .await
+    .join_err_bare()
+}
+
+/// Consumes the messages of the queue of the settings, reporting them as they are delivered,
+/// until enough of them were received or the context is done. Returns the number of messages
+/// received.
+///
+/// Without a queue, the queue named after the routing key is consumed from when the exchange
+/// is the default one. Otherwise a temporary queue is bound to the exchange with the routing
+/// key, and deleted once the subscription stops.
+///
+/// Canceling the context, or exceeding its deadline, is how a subscription without a limit
+/// is stopped, so it isn't an error.
+pub async fn subscribe<F>(
+    ctx: &dyn AnyAsyncContext,
+    url: &str,
+    settings: &AmqpInfo,
+    max_messages: Option<u64>,
+    on_event: F,
+) -> joinerror::Result<u64>
+where
+    F: Fn(QueueEvent) -> joinerror::Result<()>,
+{
+    let mut subscription = Subscription::new(max_messages, &on_event);
+    match context::abortable(ctx, consume(url, settings, &mut subscription)).await {
+        Ok(()) | Err(Ok(_)) => {}
+        Err(Err(e)) => return Err(e),
+    }
+
+    Ok(subscription.received())
+}
+
+async fn consume<F>(
+    url: &str,
+    settings: &AmqpInfo,
+    subscription: &mut Subscription<'_, F>,
+) -> joinerror::Result<()>
+where
+    F: Fn(QueueEvent) -> joinerror::Result<()>,
+{
+    let (connection, channel) = connect(url, settings).await?;
+
+    let queue = match &settings.queue {
+        Some(queue) => short_string("queue", queue)?,
+        None if settings.exchange.is_empty() => {
+            if settings.routing_key.is_empty() {
+                return Err(Error::new::<()>(
+                    "a queue or routing key is required to consume from the default exchange",
+                ));
+            }
+            short_string("routing key", &settings.routing_key)?
+        }
+        None => {
+            let options = QueueDeclareOptions {
+                exclusive: true,
+                auto_delete: true,
+                ..Default::default()
+            };
+            let queue = channel
+                .queue_declare("".into(), options, FieldTable::default())
+                .await
+                .map_err(|e| Error::new::<()>(format!("failed to declare queue: {e}")))?;
+            channel
+                .queue_bind(
+                    queue.name().clone(),
+                    short_string("exchange", &settings.exchange)?,
+                    short_string("routing key", &settings.routing_key)?,
+                    QueueBindOptions::default(),
+                    FieldTable::default(),
+                )
+                .await
+                .map_err(|e| Error::new::<()>(format!("failed to bind queue: {e}")))?;
+
+            queue.name().clone()
+        }
+    };
+
+    // Messages are acknowledged one by one as they are received, so that the ones delivered
+    // after the subscription stopped go back to the queue
+    let mut consumer = channel
+        .basic_consume(
+            queue.clone(),
+            "".into(),
+            BasicConsumeOptions::default(),
+            FieldTable::default(),
+        )
+        .await
+        .map_err(|e| Error::new::<()>(format!("failed to consume from queue `{queue}`: {e}")))?;
+    subscription.subscribed(queue.to_string())?;
+
+    loop {
+        let delivery = consumer
+            .next()
+            .await
+            .ok_or_join_err::<()>("broker canceled the subscription")?
+            .map_err(|e| Error::new::<()>(format!("connection lost: {e}")))?;
+        let message = received_message(&delivery);
+        delivery
+            .acker
+            .ack(BasicAckOptions::default())
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to acknowledge message: {e}")))?;
+
+        if !subscription.receive(message)? {
+            break;
+        }
+    }
+
+    let _ = connection.close(REPLY_SUCCESS, "OK".into()).await;
+    Ok(())
+}
+
+async fn connect(url: &str, settings: &AmqpInfo) -> joinerror::Result<(Connection, Channel)> {
+    let mut url =
+        Url::parse(url).map_err(|e| Error::new::<()>(format!("invalid amqp url `{url}`: {e}")))?;
+    if !matches!(url.scheme(), "amqp" | "amqps") {
+        return Err(Error::new::<()>(format!(
+            "unsupported scheme `{}`, expected `amqp` or `amqps`",
+            url.scheme()
+        )));
+    }
+    if let Some(username) = &settings.username {
+        url.set_username(username)
+            .map_err(|_| Error::new::<()>("the amqp url can't have credentials"))?;
+    }
+    if let Some(password) = &settings.password {
+        url.set_password(Some(password))
+            .map_err(|_| Error::new::<()>("the amqp url can't have credentials"))?;
+    }
+
+    let connection = Connection::connect(url.as_str(), ConnectionProperties::default())
+        .await
+        .map_err(|e| {
+            Error::new::<()>(format!(
+                "failed to connect to `{}`: {e}",
+                url.host_str().unwrap_or_default()
+            ))
+        })?;
+    let channel = connection
+        .create_channel()
+        .await
+        .map_err(|e| Error::new::<()>(format!("failed to open channel: {e}")))?;
+
+    Ok((connection, channel))
+}
+
+fn message_properties(
+    settings: &AmqpInfo,
+    headers: &[(String, String)],
+) -> joinerror::Result<BasicProperties> {
+    let mut properties = BasicProperties::default();
+    if let Some(content_type) = &settings.content_type {
+        properties = properties.with_content_type(short_string("content type", content_type)?);
+    }
+    if let Some(correlation_id) = &settings.correlation_id {
+        properties =
+            properties.with_correlation_id(short_string("correlation id", correlation_id)?);
+    }
+    if let Some(reply_to) = &settings.reply_to {
+        properties = properties.with_reply_to(short_string("reply to", reply_to)?);
+    }
+    if let Some(message_id) = &settings.message_id {
+        properties = properties.with_message_id(short_string("message id", message_id)?);
+    }
+    if let Some(expiration_ms) = settings.expiration_ms {
+        properties = properties.with_expiration(expiration_ms.to_string().into());
+    }
+    if let Some(priority) = settings.priority {
+        properties = properties.with_priority(priority);
+    }
+    if settings.persistent {
+        properties = properties.with_delivery_mode(2);
+    }
+
+    if !headers.is_empty() {
+        let mut table = FieldTable::default();
+        for (name, value) in headers {
+            table.insert(
+                short_string("header name", name)?,
+                AMQPValue::LongString(LongString::from(value.as_bytes())),
+            );
+        }
+        properties = properties.with_headers(table);
+    }
+
+    Ok(properties)
+}
+
+fn received_message(delivery: &Delivery) -> ReceivedMessage {
+    let props = &delivery.properties;
+
+    let mut properties: Vec<QueueMessageProperty> = [
+        ("content-type", props.content_type()),
+        ("content-encoding", props.content_encoding()),
+        ("correlation-id", props.correlation_id()),
+        ("reply-to", props.reply_to()),
+        ("expiration", props.expiration()),
+        ("message-id", props.message_id()),
+        ("type", props.kind()),
+        ("user-id", props.user_id()),
+        ("app-id", props.app_id()),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.as_ref().map(|value| property(name, value)))
+    .collect();
+    if let Some(delivery_mode) = props.delivery_mode() {
+        properties.push(property("delivery-mode", delivery_mode));
+    }
+    if let Some(priority) = props.priority() {
+        properties.push(property("priority", priority));
+    }
+    if let Some(timestamp) = props.timestamp() {
+        properties.push(property("timestamp", timestamp));
+    }
+
+    let headers = props
+        .headers()
+        .iter()
+        .flatten()
+        .map(|(name, value)| property(name.as_str(), amqp_value_to_string(value)))
+        .collect();
+
+    ReceivedMessage {
+        topic: delivery.routing_key.to_string(),
+        exchange: Some(delivery.exchange.to_string()),
+        payload: delivery.data.clone(),
+        properties,
+        headers,
+        qos: None,
+        redelivered: delivery.redelivered,
+    }
+}
+
+fn amqp_value_to_string(value: &AMQPValue) -> String {
+    match value {
+        AMQPValue::Boolean(value) => value.to_string(),
+        AMQPValue::ShortShortInt(value) => value.to_string(),
+        AMQPValue::ShortShortUInt(value) => value.to_string(),
+        AMQPValue::ShortInt(value) => value.to_string(),
+        AMQPValue::ShortUInt(value) => value.to_string(),
+        AMQPValue::LongInt(value) => value.to_string(),
+        AMQPValue::LongUInt(value) => value.to_string(),
+        AMQPValue::LongLongInt(value) => value.to_string(),
+        AMQPValue::Float(value) => value.to_string(),
+        AMQPValue::Double(value) => value.to_string(),
+        AMQPValue::ShortString(value) => value.to_string(),
+        AMQPValue::LongString(value) => value.to_string(),
+        AMQPValue::Timestamp(value) => value.to_string(),
+        AMQPValue::Void => String::new(),
+        other => format!("{other:?}"),
+    }
+}
+
+fn short_string(field: &str, value: &str) -> joinerror::Result<ShortString> {
+    ShortString::try_new(value)
+        .map_err(|_| Error::new::<()>(format!("the {field} is longer than 255 bytes")))
+}
@@ -0,0 +1,454 @@
+use joinerror::{Error, OptionExt};
+use moss_project::models::{
+    operations::DescribeResourceOutput,
+    types::mqtt::{MqttInfo, MqttVersion},
+};
+use nanoid::nanoid;
+use rumqttc::{
+    AsyncClient, Event, EventLoop, MqttOptions, Outgoing, Packet, SubscribeReasonCode,
+    TlsConfiguration, Transport, v5,
+};
+use rustls::{ClientConfig, RootCertStore};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_core::context::{self, AnyAsyncContext, ContextResultExt};
+use sapic_ipc::contracts::main::queue::{QueueEvent, QueueMessageProperty};
+use std::sync::Arc;
+use url::Url;
+
+use crate::{
+    queue::{OutgoingMessage, ReceivedMessage, Subscription, property},
+    resource::EnvironmentVariables,
+};
+
+const DEFAULT_PORT: u16 = 1883;
+const DEFAULT_TLS_PORT: u16 = 8883;
+const REQUEST_CAPACITY: usize = 10;
+
+/// Returns the MQTT settings of the endpoint, failing when it isn't an MQTT endpoint or has
+/// none.
+pub fn mqtt_settings(desc: &DescribeResourceOutput) -> joinerror::Result<&MqttInfo> {
+    if desc.class != ResourceClass::Endpoint || desc.protocol != Some(ResourceProtocol::Mqtt) {
+        return Err(Error::new::<()>(format!(
+            "resource `{}` is not an mqtt endpoint",
+            desc.name
+        )));
+    }
+
+    desc.mqtt
+        .as_ref()
+        .ok_or_join_err_with::<()>(|| format!("endpoint `{}` has no mqtt settings", desc.name))
+}
+
+/// Replaces the variables of the settings that can reference them.
+pub fn render_settings(settings: &MqttInfo, variables: &EnvironmentVariables) -> MqttInfo {
+    let render = |value: &Option<String>| value.as_deref().map(|value| variables.render(value));
+
+    MqttInfo {
+        client_id: render(&settings.client_id),
+        username: render(&settings.username),
+        password: render(&settings.password),
+        topic: variables.render(&settings.topic),
+        topic_filter: render(&settings.topic_filter),
+        payload: variables.render(&settings.payload),
+        content_type: render(&settings.content_type),
+        response_topic: render(&settings.response_topic),
+        correlation_data: render(&settings.correlation_data),
+        ..settings.clone()
+    }
+}
+
+/// Publishes the message to the topic of the settings, waiting for the broker to acknowledge
+/// it as its quality of service requires.
+pub async fn publish(
+    ctx: &dyn AnyAsyncContext,
+    url: &str,
+    settings: &MqttInfo,
+    message: &OutgoingMessage,
+) -> joinerror::Result<()> {
+    context::abortable(ctx, async {
+        let mut client = connect(url, settings)?;
+        client.publish(settings, message).await?;
+
+        loop {
+            let done = match client.next().await? {
+                Activity::Sent => settings.qos == 0,
+                Activity::Acknowledged => settings.qos == 1,
+                Activity::Completed => settings.qos == 2,
+                _ => false,
+            };
+            if done {
+                break;
+            }
+        }
+
+        client.disconnect().await;
+        Ok::<_, joinerror::Error>(())
+    })
+    .await
+    .join_err_bare()
+}
+
+/// Subscribes to the topic filter of the settings, or to its topic without one, reporting the
+/// messages as they are received until enough of them were or the context is done. Returns the
+/// number of messages received.
+///
+/// Canceling the context, or exceeding its deadline, is how a subscription without a limit
+/// is stopped, so it isn't an error.
+pub async fn subscribe<F>(
+    ctx: &dyn AnyAsyncContext,
+    url: &str,
+    settings: &MqttInfo,
+    max_messages: Option<u64>,
+    on_event: F,
+) -> joinerror::Result<u64>
+where
+    F: Fn(QueueEvent) -> joinerror::Result<()>,
+{
+    let mut subscription = Subscription::new(max_messages, &on_event);
+    match context::abortable(ctx, receive(url, settings, &mut subscription)).await {
+        Ok(()) | Err(Ok(_)) => {}
+        Err(Err(e)) => return Err(e),
+    }
+
+    Ok(subscription.received())
+}
+
+async fn receive<F>(
+    url: &str,
+    settings: &MqttInfo,
+    subscription: &mut Subscription<'_, F>,
+) -> joinerror::Result<()>
+where
+    F: Fn(QueueEvent) -> joinerror::Result<()>,
+{
+    let filter = settings
+        .topic_filter
+        .clone()
+        .unwrap_or_else(|| settings.topic.clone());
+
+    let mut client = connect(url, settings)?;
+    client.subscribe(&filter, settings.qos).await?;
+
+    loop {
+        match client.next().await? {
+            Activity::Subscribed { granted: true } => subscription.subscribed(filter.clone())?,
+            Activity::Subscribed { granted: false } => {
+                return Err(Error::new::<()>(format!(
+                    "broker rejected the subscription to `{filter}`"
+                )));
+            }
+            Activity::Message(message) => {
+                if !subscription.receive(message)? {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    client.disconnect().await;
+    Ok(())
+}
+
+/// What happened on the connection to the broker.
+enum Activity {
+    /// A publication was written, which completes it with QoS 0.
+    Sent,
+    /// A publication was acknowledged, which completes it with QoS 1.
+    Acknowledged,
+    /// A publication was completed, with QoS 2.
+    Completed,
+    Subscribed {
+        granted: bool,
+    },
+    Message(ReceivedMessage),
+    Disconnected,
+    Other,
+}
+
+/// A client of either version of the protocol, with its event loop.
+///
+/// The event loop isn't `Sync`, so the client is only ever borrowed mutably across awaits.
+enum Client {
+    V311 {
+        client: AsyncClient,
+        eventloop: Box<EventLoop>,
+    },
+    V5 {
+        client: v5::AsyncClient,
+        eventloop: Box<v5::EventLoop>,
+    },
+}
+
+impl Client {
+    async fn publish(
+        &mut self,
+        settings: &MqttInfo,
+        message: &OutgoingMessage,
+    ) -> joinerror::Result<()> {
+        match self {
+            Client::V311 { client, .. } => client
+                .publish(
+                    settings.topic.clone(),
+                    qos_v311(settings.qos)?,
+                    settings.retain,
+                    message.payload.clone(),
+                )
+                .await
+                .map_err(|e| Error::new::<()>(format!("failed to publish message: {e}"))),
+            Client::V5 { client, .. } => {
+                let properties = v5::mqttbytes::v5::PublishProperties {
+                    content_type: settings.content_type.clone(),
+                    response_topic: settings.response_topic.clone(),
+                    correlation_data: settings.correlation_data.clone().map(Into::into),
+                    message_expiry_interval: settings.message_expiry_secs,
+                    user_properties: message.headers.clone(),
+                    ..Default::default()
+                };
+
+                client
+                    .publish_with_properties(
+                        settings.topic.clone(),
+                        qos_v5(settings.qos)?,
+                        settings.retain,
+                        message.payload.clone(),
+                        properties,
+                    )
+                    .await
+                    .map_err(|e| Error::new::<()>(format!("failed to publish message: {e}")))
+            }
+        }
+    }
+
+    async fn subscribe(&mut self, filter: &str, qos: u8) -> joinerror::Result<()> {
+        match self {
+            Client::V311 { client, .. } => client
+                .subscribe(filter, qos_v311(qos)?)
+                .await
+                .map_err(|e| Error::new::<()>(format!("failed to subscribe to `{filter}`: {e}"))),
+            Client::V5 { client, .. } => client
+                .subscribe(filter, qos_v5(qos)?)
+                .await
+                .map_err(|e| Error::new::<()>(format!("failed to subscribe to `{filter}`: {e}"))),
+        }
+    }
+
+    /// Drives the connection until something happens on it.
+    async fn next(&mut self) -> joinerror::Result<Activity> {
+        match self {
+            Client::V311 { eventloop, .. } => {
+                let event = eventloop
+                    .poll()
+                    .await
+                    .map_err(|e| Error::new::<()>(format!("connection failed: {e}")))?;
+
+                Ok(match event {
+                    Event::Outgoing(Outgoing::Publish(_)) => Activity::Sent,
+                    Event::Outgoing(Outgoing::Disconnect) => Activity::Disconnected,
+                    Event::Incoming(Packet::PubAck(_)) => Activity::Acknowledged,
+                    Event::Incoming(Packet::PubComp(_)) => Activity::Completed,
+                    Event::Incoming(Packet::SubAck(ack)) => Activity::Subscribed {
+                        granted: ack
+                            .return_codes
+                            .iter()
+                            .all(|code| matches!(code, SubscribeReasonCode::Success(_))),
+                    },
+                    Event::Incoming(Packet::Publish(publish)) => {
+                        Activity::Message(ReceivedMessage {
+                            topic: publish.topic,
+                            payload: publish.payload.to_vec(),
+                            qos: Some(publish.qos as u8),
+                            redelivered: publish.retain,
+                            ..Default::default()
+                        })
+                    }
+                    _ => Activity::Other,
+                })
+            }
+            Client::V5 { eventloop, .. } => {
+                use v5::mqttbytes::v5::{Packet, SubscribeReasonCode};
+
+                let event = eventloop
+                    .poll()
+                    .await
+                    .map_err(|e| Error::new::<()>(format!("connection failed: {e}")))?;
+
+                Ok(match event {
+                    v5::Event::Outgoing(Outgoing::Publish(_)) => Activity::Sent,
+                    v5::Event::Outgoing(Outgoing::Disconnect) => Activity::Disconnected,
+                    v5::Event::Incoming(Packet::PubAck(_)) => Activity::Acknowledged,
+                    v5::Event::Incoming(Packet::PubComp(_)) => Activity::Completed,
+                    v5::Event::Incoming(Packet::SubAck(ack)) => Activity::Subscribed {
+                        granted: ack
+                            .return_codes
+                            .iter()
+                            .all(|code| matches!(code, SubscribeReasonCode::Success(_))),
+                    },
+                    v5::Event::Incoming(Packet::Publish(publish)) => {
+                        let mut properties = Vec::new();
+                        let mut headers = Vec::new();
+                        if let Some(props) = publish.properties {
+                            if let Some(content_type) = props.content_type {
+                                properties.push(property("content-type", content_type));
+                            }
+                            if let Some(response_topic) = props.response_topic {
+                                properties.push(property("response-topic", response_topic));
+                            }
+                            if let Some(correlation_data) = props.correlation_data {
+                                properties.push(property(
+                                    "correlation-data",
+                                    String::from_utf8_lossy(&correlation_data),
+                                ));
+                            }
+                            if let Some(expiry) = props.message_expiry_interval {
+                                properties.push(property("message-expiry-interval", expiry));
+                            }
+                            if let Some(indicator) = props.payload_format_indicator {
+                                properties.push(property("payload-format-indicator", indicator));
+                            }
+                            headers = props
+                                .user_properties
+                                .into_iter()
+                                .map(|(name, value)| QueueMessageProperty { name, value })
+                                .collect();
+                        }
+
+                        Activity::Message(ReceivedMessage {
+                            topic: String::from_utf8_lossy(&publish.topic).into_owned(),
+                            exchange: None,
+                            payload: publish.payload.to_vec(),
+                            properties,
+                            headers,
+                            qos: Some(publish.qos as u8),
+                            redelivered: publish.retain,
+                        })
+                    }
+                    _ => Activity::Other,
+                })
+            }
+        }
+    }
+
+    /// Disconnects from the broker, giving up silently when the connection is already broken
+    /// since the broker drops it anyway.
+    async fn disconnect(mut self) {
+        let requested = match &mut self {
+            Client::V311 { client, .. } => client.disconnect().await.is_ok(),
+            Client::V5 { client, .. } => client.disconnect().await.is_ok(),
+        };
+        if !requested {
+            return;
+        }
+
+        while let Ok(activity) = self.next().await {
+            if let Activity::Disconnected = activity {
+                break;
+            }
+        }
+    }
+}
+
+fn connect(url: &str, settings: &MqttInfo) -> joinerror::Result<Client> {
+    let url =
+        Url::parse(url).map_err(|e| Error::new::<()>(format!("invalid mqtt url `{url}`: {e}")))?;
+    let (tls, default_port) = match url.scheme() {
+        "mqtt" | "tcp" => (false, DEFAULT_PORT),
+        "mqtts" | "ssl" => (true, DEFAULT_TLS_PORT),
+        scheme => {
+            return Err(Error::new::<()>(format!(
+                "unsupported scheme `{scheme}`, expected `mqtt` or `mqtts`"
+            )));
+        }
+    };
+    let host = url
+        .host_str()
+        .ok_or_join_err::<()>("the mqtt url has no host")?
+        .to_string();
+    let port = url.port().unwrap_or(default_port);
+
+    let username = settings
+        .username
+        .clone()
+        .or_else(|| (!url.username().is_empty()).then(|| decode(url.username())));
+    let password = settings
+        .password
+        .clone()
+        .or_else(|| url.password().map(decode));
+    if username.is_none() && password.is_some() {
+        return Err(Error::new::<()>("a password requires a username"));
+    }
+
+    let client_id = settings
+        .client_id
+        .clone()
+        .unwrap_or_else(|| format!("sapic-{}", nanoid!(10)));
+    let transport = if tls {
+        Some(Transport::tls_with_config(TlsConfiguration::Rustls(
+            Arc::new(tls_config()?),
+        )))
+    } else {
+        None
+    };
+
+    Ok(match settings.version {
+        MqttVersion::V311 => {
+            let mut options = MqttOptions::new(client_id, host, port);
+            options.set_clean_session(settings.clean_session);
+            if let Some(username) = username {
+                options.set_credentials(username, password.unwrap_or_default());
+            }
+            if let Some(transport) = transport {
+                options.set_transport(transport);
+            }
+
+            let (client, eventloop) = AsyncClient::new(options, REQUEST_CAPACITY);
+            Client::V311 {
+                client,
+                eventloop: Box::new(eventloop),
+            }
+        }
+        MqttVersion::V5 => {
+            let mut options = v5::MqttOptions::new(client_id, host, port);
+            options.set_clean_start(settings.clean_session);
+            if let Some(username) = username {
+                options.set_credentials(username, password.unwrap_or_default());
+            }
+            if let Some(transport) = transport {
+                options.set_transport(transport);
+            }
+
+            let (client, eventloop) = v5::AsyncClient::new(options, REQUEST_CAPACITY);
+            Client::V5 {
+                client,
+                eventloop: Box::new(eventloop),
+            }
+        }
+    })
+}
+
+fn tls_config() -> joinerror::Result<ClientConfig> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.into(),
+    };
+
+    Ok(ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|e| Error::new::<()>(format!("failed to configure tls: {e}")))?
+        .with_root_certificates(roots)
+        .with_no_client_auth())
+}
+
+fn qos_v311(qos: u8) -> joinerror::Result<rumqttc::QoS> {
+    rumqttc::qos(qos).map_err(|_| Error::new::<()>(format!("invalid qos `{qos}`")))
+}
+
+fn qos_v5(qos: u8) -> joinerror::Result<v5::mqttbytes::QoS> {
+    v5::mqttbytes::qos(qos).ok_or_join_err_with::<()>(|| format!("invalid qos `{qos}`"))
+}
+
+fn decode(value: &str) -> String {
+    urlencoding::decode(value)
+        .map(|value| value.into_owned())
+        .unwrap_or_else(|_| value.to_string())
+}
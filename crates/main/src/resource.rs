@@ -1 +1,755 @@
+pub mod auth;
+pub mod history;
 
+use joinerror::{Error, OptionExt, ResultExt};
+use moss_environment::DescribeEnvironment;
+use moss_fs::FileSystem;
+use moss_project::models::{operations::DescribeResourceOutput, types::BodyInfo};
+use reqwest::{Client, Method, Request, RequestBuilder, header::CONTENT_TYPE, multipart};
+use sapic_base::resource::types::primitives::{ResourceKind, ResourceProtocol};
+use sapic_core::context::{self, AnyAsyncContext, ContextResultExt};
+use sapic_ipc::contracts::main::resource::{
+    EnvironmentScope, ExecuteResourceEvent, ExecuteResourceOutput, ResolveResourceOutput,
+    ResolvedBody, ResolvedField, ResolvedParam, ResponseHeader, UnresolvedVariable, VariableKind,
+    VariableLocation, VariableSource, VariableSubstitution, VariableValueKind,
+};
+use serde_json::Value as JsonValue;
+use std::{collections::HashMap, io::Read, time::Instant};
+use template_parser::{
+    UrlParser,
+    models::types::{ParsedValue, ValueList},
+};
+
+use crate::{project::RuntimeProject, resource::auth::DigestAuth, workspace::Workspace};
+
+/// Builds an HTTP request from a resolved endpoint resource.
+pub async fn build_http_request(
+    ctx: &dyn AnyAsyncContext,
+    fs: &dyn FileSystem,
+    client: &Client,
+    resolved: &ResolveResourceOutput,
+) -> joinerror::Result<RequestBuilder> {
+    let method = resolved
+        .protocol
+        .as_ref()
+        .and_then(http_method)
+        .ok_or_join_err::<()>("resource is not an HTTP endpoint")?;
+
+    let url = resolved.url.trim();
+    if url.is_empty() {
+        return Err(Error::new::<()>("resource has no url"));
+    }
+
+    let mut url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("http://{url}")
+    };
+
+    if !resolved.query_params.is_empty() {
+        let query = encode_query(
+            resolved
+                .query_params
+                .iter()
+                .map(|p| (p.name.as_str(), p.value.as_str())),
+        );
+
+        let fragment = url.find('#').map(|i| url.split_off(i));
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(&query);
+        url.extend(fragment);
+    }
+
+    let mut request = client.request(method, &url);
+
+    let has_content_type = resolved
+        .headers
+        .iter()
+        .any(|h| h.name.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+    for header in &resolved.headers {
+        request = request.header(&header.name, &header.value);
+    }
+
+    let Some(body) = &resolved.body else {
+        return Ok(request);
+    };
+
+    let (bytes, content_type) = match body {
+        ResolvedBody::Text(text) => (text.clone().into_bytes(), "text/plain"),
+        ResolvedBody::Json(json) => (
+            serde_json::to_vec(json).join_err::<()>("failed to serialize json body")?,
+            "application/json",
+        ),
+        ResolvedBody::Xml(xml) => (xml.clone().into_bytes(), "application/xml"),
+        ResolvedBody::Binary(path) => {
+            let mut rdr = fs.open_file(ctx, path).await?;
+            let mut bytes = Vec::new();
+            rdr.read_to_end(&mut bytes).join_err_with::<()>(|| {
+                format!("failed to read binary body `{}`", path.display())
+            })?;
+
+            (bytes, "application/octet-stream")
+        }
+        ResolvedBody::Urlencoded(params) => {
+            let pairs = params
+                .iter()
+                .map(|p| (p.name.as_str(), p.value.as_str()))
+                .collect::<Vec<_>>();
+
+            return Ok(request.form(&pairs));
+        }
+        ResolvedBody::FormData(params) => {
+            let form = params.iter().fold(multipart::Form::new(), |form, p| {
+                form.text(p.name.clone(), p.value.clone())
+            });
+
+            return Ok(request.multipart(form));
+        }
+    };
+
+    if !has_content_type {
+        request = request.header(CONTENT_TYPE, content_type);
+    }
+
+    Ok(request.body(bytes))
+}
+
+/// Percent-encodes query params into a query string.
+///
+/// `RequestBuilder::query` form-encodes a space as `+`, which servers following RFC 3986
+/// read as a literal plus sign, so queries are encoded with this instead.
+pub(crate) fn encode_query<'a>(params: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    params
+        .into_iter()
+        .map(|(name, value)| {
+            format!(
+                "{}={}",
+                urlencoding::encode(name),
+                urlencoding::encode(value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Sends the request and reports the response to `on_event` as it arrives: the status first,
+/// then the headers, then the body chunk by chunk. A digest challenge of the server is answered
+/// by sending the request once more, see [`auth::send_authorized`].
+///
+/// Stops as soon as the context is canceled or its deadline is exceeded.
+pub async fn send_http_request<F>(
+    ctx: &dyn AnyAsyncContext,
+    client: &Client,
+    request: Request,
+    digest: Option<&DigestAuth>,
+    on_event: F,
+) -> joinerror::Result<ExecuteResourceOutput>
+where
+    F: Fn(ExecuteResourceEvent) -> joinerror::Result<()>,
+{
+    context::abortable(ctx, async {
+        let started_at = Instant::now();
+        let mut response = auth::send_authorized(client, request, digest).await?;
+
+        let status = response.status();
+        on_event(ExecuteResourceEvent::Status {
+            status: status.as_u16(),
+            status_text: status.canonical_reason().unwrap_or_default().to_string(),
+        })?;
+
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| ResponseHeader {
+                name: name.to_string(),
+                value: String::from_utf8_lossy(value.as_bytes()).to_string(),
+            })
+            .collect();
+        on_event(ExecuteResourceEvent::Headers { headers })?;
+
+        let mut size = 0;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .join_err::<()>("failed to read response body")?
+        {
+            size += chunk.len() as u64;
+            on_event(ExecuteResourceEvent::Body {
+                chunk: chunk.to_vec(),
+            })?;
+        }
+
+        Ok::<_, joinerror::Error>(ExecuteResourceOutput {
+            status: status.as_u16(),
+            size,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        })
+    })
+    .await
+    .join_err_bare()
+}
+
+pub(crate) fn http_method(protocol: &ResourceProtocol) -> Option<Method> {
+    match protocol {
+        ResourceProtocol::Get => Some(Method::GET),
+        ResourceProtocol::Head => Some(Method::HEAD),
+        ResourceProtocol::Post => Some(Method::POST),
+        ResourceProtocol::Put => Some(Method::PUT),
+        ResourceProtocol::Patch => Some(Method::PATCH),
+        ResourceProtocol::Delete => Some(Method::DELETE),
+        ResourceProtocol::Connect => Some(Method::CONNECT),
+        ResourceProtocol::Options => Some(Method::OPTIONS),
+        ResourceProtocol::Trace => Some(Method::TRACE),
+        ResourceProtocol::Custom(method) => Method::from_bytes(method.as_bytes()).ok(),
+        ResourceProtocol::WebSocket
+        | ResourceProtocol::Graphql
+        | ResourceProtocol::Grpc
+        | ResourceProtocol::Amqp
+        | ResourceProtocol::Mqtt => None,
+    }
+}
+
+pub(crate) fn value_to_string(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null => String::new(),
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Values of the variables in the active environments.
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentVariables(HashMap<String, (String, VariableSource)>);
+
+impl EnvironmentVariables {
+    /// Collects the variables of the active workspace and project environments.
+    /// The project environment takes precedence over the workspace one.
+    pub async fn active(
+        ctx: &dyn AnyAsyncContext,
+        workspace: &dyn Workspace,
+        project: &RuntimeProject,
+    ) -> joinerror::Result<Self> {
+        let mut variables = Self::default();
+
+        if let Some(id) = workspace.active_environment(ctx).await? {
+            let desc = workspace.describe_environment(ctx, &id).await?;
+            variables.extend(&desc, EnvironmentScope::Workspace);
+        }
+
+        if let Some(id) = project.active_environment(ctx).await? {
+            let desc = project.describe_environment(ctx, &id).await?;
+            variables.extend(&desc, EnvironmentScope::Project);
+        }
+
+        Ok(variables)
+    }
+
+    /// Replaces the `{{var}}` variables of the text with their values,
+    /// leaving the undefined ones as written.
+    pub fn render(&self, text: &str) -> String {
+        let Ok(values) = UrlParser::parse_template(text) else {
+            return text.to_string();
+        };
+
+        values
+            .iter()
+            .map(|value| match value {
+                ParsedValue::Variable(name) => self.0.get(name).map_or_else(
+                    || render_values(std::slice::from_ref(value)),
+                    |(value, _)| value.clone(),
+                ),
+                other => render_values(std::slice::from_ref(other)),
+            })
+            .collect()
+    }
+
+    /// Replaces the variables of every string of the JSON value, keys excepted.
+    pub fn render_json(&self, value: &JsonValue) -> JsonValue {
+        match value {
+            JsonValue::String(text) => JsonValue::String(self.render(text)),
+            JsonValue::Array(items) => {
+                JsonValue::Array(items.iter().map(|item| self.render_json(item)).collect())
+            }
+            JsonValue::Object(fields) => JsonValue::Object(
+                fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), self.render_json(value)))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// Adds the enabled variables of the environment, overriding the ones with the same name.
+    /// The local value of a variable takes precedence over its global value.
+    pub fn extend(&mut self, desc: &DescribeEnvironment, scope: EnvironmentScope) {
+        for variable in desc.variables.values().filter(|v| !v.disabled) {
+            let local = variable.local_value.as_ref().filter(|v| !v.is_null());
+            let global = variable.global_value.as_ref().filter(|v| !v.is_null());
+            let (value, kind) = match (local, global) {
+                (Some(value), _) => (value, VariableValueKind::Local),
+                (None, Some(value)) => (value, VariableValueKind::Global),
+                (None, None) => continue,
+            };
+
+            self.0.insert(
+                variable.name.clone(),
+                (
+                    value_to_string(value),
+                    VariableSource::Environment {
+                        environment_id: desc.id.clone(),
+                        scope: scope.clone(),
+                        value: kind,
+                    },
+                ),
+            );
+        }
+    }
+
+    /// Binds the columns of a data row as variables, overriding the environment ones.
+    pub fn bind_row(&mut self, row: &[(String, String)]) {
+        for (name, value) in row {
+            self.0
+                .insert(name.clone(), (value.clone(), VariableSource::Data));
+        }
+    }
+}
+
+/// Renders the request of an item resource, replacing every `{{var}}` with its value from
+/// the environments and every `:name` segment of the url path with the matching path param.
+///
+/// Disabled headers and params are skipped, the rest are returned in their user-defined order.
+pub fn resolve_resource(
+    desc: &DescribeResourceOutput,
+    variables: &EnvironmentVariables,
+) -> joinerror::Result<ResolveResourceOutput> {
+    if desc.kind != ResourceKind::Item {
+        return Err(Error::new::<()>(format!(
+            "resource `{}` is not an item",
+            desc.name
+        )));
+    }
+
+    let mut resolver = Resolver {
+        variables,
+        substitutions: Vec::new(),
+        unresolved: Vec::new(),
+    };
+
+    let headers = resolver.resolve_params(
+        desc.headers
+            .iter()
+            .filter(|h| !h.disabled)
+            .map(|h| (h.id.to_string(), &h.name, &h.value, h.order)),
+        ResolvedField::Header,
+    );
+    let path_params = resolver.resolve_params(
+        desc.path_params
+            .iter()
+            .filter(|p| !p.disabled)
+            .map(|p| (p.id.to_string(), &p.name, &p.value, p.order)),
+        ResolvedField::PathParam,
+    );
+    let query_params = resolver.resolve_params(
+        desc.query_params
+            .iter()
+            .filter(|p| !p.disabled)
+            .map(|p| (p.id.to_string(), &p.name, &p.value, p.order)),
+        ResolvedField::QueryParam,
+    );
+
+    let url = resolver.resolve_url(desc.url.as_deref().unwrap_or_default(), &path_params);
+
+    let body = desc.body.as_ref().map(|body| match body {
+        BodyInfo::Text(text) => {
+            ResolvedBody::Text(resolver.resolve_template(text, ResolvedField::Body(String::new())))
+        }
+        BodyInfo::Json(json) => ResolvedBody::Json(resolver.resolve_json(json, String::new())),
+        BodyInfo::Xml(xml) => {
+            ResolvedBody::Xml(resolver.resolve_template(xml, ResolvedField::Body(String::new())))
+        }
+        BodyInfo::Binary(path) => ResolvedBody::Binary(path.clone()),
+        BodyInfo::Urlencoded(params) => ResolvedBody::Urlencoded(
+            resolver.resolve_params(
+                params
+                    .iter()
+                    .filter(|p| !p.disabled)
+                    .map(|p| (p.id.to_string(), &p.name, &p.value, p.order)),
+                ResolvedField::BodyParam,
+            ),
+        ),
+        BodyInfo::FormData(params) => ResolvedBody::FormData(
+            resolver.resolve_params(
+                params
+                    .iter()
+                    .filter(|p| !p.disabled)
+                    .map(|p| (p.id.to_string(), &p.name, &p.value, p.order)),
+                ResolvedField::BodyParam,
+            ),
+        ),
+    });
+
+    Ok(ResolveResourceOutput {
+        protocol: desc.protocol.clone(),
+        url,
+        headers,
+        path_params,
+        query_params,
+        body,
+        substitutions: resolver.substitutions,
+        unresolved: resolver.unresolved,
+    })
+}
+
+struct Resolver<'a> {
+    variables: &'a EnvironmentVariables,
+    substitutions: Vec<VariableSubstitution>,
+    unresolved: Vec<UnresolvedVariable>,
+}
+
+impl Resolver<'_> {
+    fn resolve_params<'p>(
+        &mut self,
+        params: impl Iterator<Item = (String, &'p String, &'p JsonValue, Option<isize>)>,
+        field: impl Fn(String) -> ResolvedField,
+    ) -> Vec<ResolvedParam> {
+        let mut params = params.collect::<Vec<_>>();
+        params.sort_by_key(|(_, _, _, order)| order.unwrap_or(isize::MAX));
+
+        params
+            .into_iter()
+            .map(|(id, name, value, _)| ResolvedParam {
+                value: self.resolve_template(&value_to_string(value), field(id.clone())),
+                id,
+                name: name.clone(),
+            })
+            .collect()
+    }
+
+    fn resolve_json(&mut self, value: &JsonValue, pointer: String) -> JsonValue {
+        match value {
+            JsonValue::String(s) => {
+                JsonValue::String(self.resolve_template(s, ResolvedField::Body(pointer)))
+            }
+            JsonValue::Array(items) => JsonValue::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| self.resolve_json(item, format!("{pointer}/{i}")))
+                    .collect(),
+            ),
+            JsonValue::Object(fields) => JsonValue::Object(
+                fields
+                    .iter()
+                    .map(|(key, item)| {
+                        let escaped = key.replace('~', "~0").replace('/', "~1");
+                        (
+                            key.clone(),
+                            self.resolve_json(item, format!("{pointer}/{escaped}")),
+                        )
+                    })
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    fn resolve_template(&mut self, text: &str, field: ResolvedField) -> String {
+        match UrlParser::parse_template(text) {
+            Ok(values) => self.resolve_values(&values, field, &[]),
+            Err(_) => text.to_string(),
+        }
+    }
+
+    fn resolve_url(&mut self, url: &str, path_params: &[ResolvedParam]) -> String {
+        // Urls the parser cannot reproduce exactly are resolved as plain text,
+        // so that the reported positions always match what the user wrote.
+        match UrlParser::parse_url(url) {
+            Ok(parsed) if render_values(&parsed.raw) == url => {
+                self.resolve_values(&parsed.raw, ResolvedField::Url, path_params)
+            }
+            _ => self.resolve_template(url, ResolvedField::Url),
+        }
+    }
+
+    fn resolve_values(
+        &mut self,
+        values: &ValueList,
+        field: ResolvedField,
+        path_params: &[ResolvedParam],
+    ) -> String {
+        let mut result = String::new();
+        let mut offset = 0;
+
+        for value in values {
+            let (name, kind, len) = match value {
+                ParsedValue::String(s) => {
+                    result.push_str(s);
+                    offset += s.len();
+                    continue;
+                }
+                ParsedValue::Variable(name) => (name, VariableKind::Environment, name.len() + 4),
+                ParsedValue::PathVariable(name) => (name, VariableKind::PathParam, name.len() + 1),
+            };
+
+            let location = VariableLocation {
+                field: field.clone(),
+                start: offset,
+                end: offset + len,
+            };
+            offset += len;
+
+            let resolved = match kind {
+                VariableKind::Environment => self.variables.0.get(name).cloned(),
+                VariableKind::PathParam => path_params.iter().find(|p| &p.name == name).map(|p| {
+                    (
+                        urlencoding::encode(&p.value).into_owned(),
+                        VariableSource::PathParam,
+                    )
+                }),
+            };
+
+            match resolved {
+                Some((value, source)) => {
+                    result.push_str(&value);
+                    self.substitutions.push(VariableSubstitution {
+                        name: name.clone(),
+                        location,
+                        source,
+                    });
+                }
+                None => {
+                    result.push_str(&render_values(std::slice::from_ref(value)));
+                    self.unresolved.push(UnresolvedVariable {
+                        name: name.clone(),
+                        kind,
+                        location,
+                    });
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn render_values(values: &[ParsedValue]) -> String {
+    values
+        .iter()
+        .map(|value| match value {
+            ParsedValue::String(s) => s.clone(),
+            ParsedValue::Variable(name) => format!("{{{{{name}}}}}"),
+            ParsedValue::PathVariable(name) => format!(":{name}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use moss_project::models::{
+        primitives::{HeaderId, PathParamId},
+        types::{HeaderInfo, PathParamInfo},
+    };
+    use sapic_base::{
+        environment::types::{VariableInfo, primitives::EnvironmentId},
+        resource::types::primitives::ResourceClass,
+    };
+    use serde_json::json;
+
+    use super::*;
+
+    fn environment(
+        id: &str,
+        variables: &[(&str, JsonValue, Option<JsonValue>)],
+    ) -> DescribeEnvironment {
+        DescribeEnvironment {
+            id: EnvironmentId::from(id.to_string()),
+            name: id.to_string(),
+            color: None,
+            variables: variables
+                .iter()
+                .map(|(name, global_value, local_value)| {
+                    let id = name.to_string().into();
+                    let info = VariableInfo {
+                        id: name.to_string().into(),
+                        name: name.to_string(),
+                        global_value: Some(global_value.clone()),
+                        local_value: local_value.clone(),
+                        disabled: false,
+                        desc: None,
+                    };
+                    (id, info)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_environment_precedence() {
+        let mut variables = EnvironmentVariables::default();
+        variables.extend(
+            &environment(
+                "workspace",
+                &[
+                    ("host", json!("workspace.host"), None),
+                    ("token", json!("global"), Some(json!("local"))),
+                ],
+            ),
+            EnvironmentScope::Workspace,
+        );
+        variables.extend(
+            &environment("project", &[("host", json!("project.host"), None)]),
+            EnvironmentScope::Project,
+        );
+
+        assert_eq!(
+            variables.0["host"],
+            (
+                "project.host".to_string(),
+                VariableSource::Environment {
+                    environment_id: EnvironmentId::from("project".to_string()),
+                    scope: EnvironmentScope::Project,
+                    value: VariableValueKind::Global,
+                }
+            )
+        );
+        assert_eq!(
+            variables.0["token"],
+            (
+                "local".to_string(),
+                VariableSource::Environment {
+                    environment_id: EnvironmentId::from("workspace".to_string()),
+                    scope: EnvironmentScope::Workspace,
+                    value: VariableValueKind::Local,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_bound_row_precedence() {
+        let mut variables = EnvironmentVariables::default();
+        variables.extend(
+            &environment(
+                "env",
+                &[
+                    ("host", json!("localhost"), None),
+                    ("user", json!("admin"), None),
+                ],
+            ),
+            EnvironmentScope::Workspace,
+        );
+        variables.bind_row(&[("user".to_string(), "neo".to_string())]);
+
+        assert_eq!(
+            variables.0["user"],
+            ("neo".to_string(), VariableSource::Data)
+        );
+        assert_eq!(
+            variables.render("{{host}}/users/{{user}}"),
+            "localhost/users/neo"
+        );
+    }
+
+    #[test]
+    fn test_resolve_resource() {
+        let mut variables = EnvironmentVariables::default();
+        variables.extend(
+            &environment(
+                "env",
+                &[
+                    ("host", json!("localhost:8080"), None),
+                    ("token", json!("secret"), None),
+                    ("user", json!("a b"), None),
+                ],
+            ),
+            EnvironmentScope::Workspace,
+        );
+
+        let desc = DescribeResourceOutput {
+            name: "endpoint".to_string(),
+            description: None,
+            class: ResourceClass::Endpoint,
+            kind: ResourceKind::Item,
+            protocol: Some(ResourceProtocol::Post),
+            url: Some("http://{{host}}/users/:id/:missing".to_string()),
+            headers: vec![HeaderInfo {
+                id: HeaderId::from("h1".to_string()),
+                name: "Authorization".to_string(),
+                value: json!("Bearer {{token}}"),
+                description: None,
+                disabled: false,
+                propagate: false,
+                order: Some(0),
+                inherited_from: None,
+                component: None,
+            }],
+            path_params: vec![PathParamInfo {
+                id: PathParamId::from("p1".to_string()),
+                name: "id".to_string(),
+                value: json!("{{user}}"),
+                description: None,
+                disabled: false,
+                propagate: false,
+                order: Some(0),
+                inherited_from: None,
+                component: None,
+            }],
+            query_params: vec![],
+            body: Some(BodyInfo::Json(json!({ "a/b": ["{{unknown}}"] }))),
+            body_schema: None,
+            schema: None,
+            body_component: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        };
+
+        let output = resolve_resource(&desc, &variables).unwrap();
+
+        assert_eq!(output.url, "http://localhost:8080/users/a%20b/:missing");
+        assert_eq!(output.headers[0].value, "Bearer secret");
+        assert_eq!(output.path_params[0].value, "a b");
+        assert_eq!(
+            output.body,
+            Some(ResolvedBody::Json(json!({ "a/b": ["{{unknown}}"] })))
+        );
+
+        let url_substitutions = output
+            .substitutions
+            .iter()
+            .filter(|s| s.location.field == ResolvedField::Url)
+            .map(|s| (s.name.as_str(), s.location.start, s.location.end))
+            .collect::<Vec<_>>();
+        assert_eq!(url_substitutions, vec![("host", 7, 15), ("id", 22, 25)]);
+
+        assert_eq!(
+            output.unresolved,
+            vec![
+                UnresolvedVariable {
+                    name: "missing".to_string(),
+                    kind: VariableKind::PathParam,
+                    location: VariableLocation {
+                        field: ResolvedField::Url,
+                        start: 26,
+                        end: 34,
+                    },
+                },
+                UnresolvedVariable {
+                    name: "unknown".to_string(),
+                    kind: VariableKind::Environment,
+                    location: VariableLocation {
+                        field: ResolvedField::Body("/a~1b/0".to_string()),
+                        start: 0,
+                        end: 11,
+                    },
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,261 @@
+pub mod digest;
+pub mod sigv4;
+
+use chrono::Utc;
+use http_body_util::BodyExt;
+use joinerror::{Error, ResultExt};
+use moss_project::models::types::{
+    ApiKeyLocation, AuthInfo, AuthScheme, OAuth2Config, OAuth2Grant,
+};
+use reqwest::{Client, Request, RequestBuilder, Response, StatusCode};
+use sapic_base::workspace::types::primitives::WorkspaceId;
+use sapic_core::context::AnyAsyncContext;
+use sapic_platform::oauth::{self, OAuth2Client};
+use std::sync::Arc;
+use template_parser::{UrlParser, models::types::ParsedValue};
+
+use crate::resource::{
+    EnvironmentVariables,
+    auth::{
+        digest::{DigestChallenge, DigestCredentials, DigestNonces},
+        sigv4::AwsCredentials,
+    },
+    encode_query,
+    history::CredentialNames,
+    render_values,
+};
+
+/// A request with the credentials of the auth in effect for its resource.
+pub struct AuthorizedRequest {
+    pub request: RequestBuilder,
+    /// Set for digest auth, to answer a challenge of the server when the request couldn't be
+    /// authorized up front.
+    pub digest: Option<DigestAuth>,
+    /// Where the auth put credentials the history can't tell apart from other headers and
+    /// query params, so that they are masked when the request is recorded.
+    pub credential_names: CredentialNames,
+}
+
+pub struct DigestAuth {
+    credentials: DigestCredentials,
+    nonces: Arc<DigestNonces>,
+}
+
+/// Adds the credentials of the auth in effect for a resource to its request.
+/// Secrets are taken from the environment variables they reference.
+///
+/// Digest auth is only added up front when a nonce of the server is cached, otherwise the
+/// request is authorized once the server challenges it, see [`send_authorized`].
+pub async fn authorize_http_request(
+    ctx: &dyn AnyAsyncContext,
+    oauth2_client: &OAuth2Client,
+    digest_nonces: &Arc<DigestNonces>,
+    workspace_id: &WorkspaceId,
+    variables: &EnvironmentVariables,
+    auth: Option<&AuthInfo>,
+    request: RequestBuilder,
+) -> joinerror::Result<AuthorizedRequest> {
+    let Some(auth) = auth else {
+        return Ok(AuthorizedRequest {
+            request,
+            digest: None,
+            credential_names: CredentialNames::default(),
+        });
+    };
+
+    let render = |value: &str| render_auth_value(variables, value);
+    let mut digest = None;
+    let mut credential_names = CredentialNames::default();
+    let request = match &auth.scheme {
+        AuthScheme::None | AuthScheme::Inherit => request,
+        AuthScheme::Basic { username, password } => {
+            request.basic_auth(render(username)?, Some(render(password)?))
+        }
+        AuthScheme::Bearer { token } => request.bearer_auth(render(token)?),
+        AuthScheme::ApiKey {
+            key,
+            value,
+            location,
+        } => {
+            let (key, value) = (render(key)?, render(value)?);
+            match location {
+                ApiKeyLocation::Header => {
+                    credential_names.headers.push(key.clone());
+                    request.header(key, value)
+                }
+                ApiKeyLocation::Query => {
+                    credential_names.query_params.push(key.clone());
+
+                    let (client, request) = request.build_split();
+                    let mut request = request.join_err::<()>("failed to build request")?;
+                    let pair = encode_query([(key.as_str(), value.as_str())]);
+                    let query = match request.url().query() {
+                        Some(query) if !query.is_empty() => format!("{query}&{pair}"),
+                        _ => pair,
+                    };
+                    request.url_mut().set_query(Some(&query));
+
+                    RequestBuilder::from_parts(client, request)
+                }
+            }
+        }
+        AuthScheme::Digest { username, password } => {
+            let credentials = DigestCredentials {
+                username: render(username)?,
+                password: render(password)?,
+            };
+
+            let (client, request) = request.build_split();
+            let mut request = request.join_err::<()>("failed to build request")?;
+            if let Some((challenge, nonce_count)) = digest_nonces.next(request.url()) {
+                digest::authorize_request(
+                    &mut request,
+                    &challenge,
+                    &credentials,
+                    nonce_count,
+                    &nanoid::nanoid!(),
+                )?;
+            }
+
+            digest = Some(DigestAuth {
+                credentials,
+                nonces: digest_nonces.clone(),
+            });
+            RequestBuilder::from_parts(client, request)
+        }
+        AuthScheme::OAuth2(config) => {
+            let config = resolve_oauth2_config(config, &render)?;
+            let token = oauth2_client
+                .access_token(ctx, workspace_id, &config)
+                .await?;
+
+            request.bearer_auth(token)
+        }
+        AuthScheme::AwsSigV4(config) => {
+            let credentials = AwsCredentials {
+                access_key: render(&config.access_key)?,
+                secret_key: render(&config.secret_key)?,
+                session_token: config.session_token.as_deref().map(render).transpose()?,
+            };
+
+            // The signature covers the final url, headers and body of the request
+            let (client, request) = request.build_split();
+            let mut request = request.join_err::<()>("failed to build request")?;
+            // Multipart forms are streamed, and their hash is part of the signature
+            if let Some(body) = request.body_mut().take_if(|body| body.as_bytes().is_none()) {
+                let bytes = body
+                    .collect()
+                    .await
+                    .join_err::<()>("failed to read request body")?
+                    .to_bytes();
+                *request.body_mut() = Some(bytes.into());
+            }
+            sigv4::sign_request(
+                &mut request,
+                &credentials,
+                &render(&config.region)?,
+                &render(&config.service)?,
+                Utc::now(),
+            )?;
+
+            RequestBuilder::from_parts(client, request)
+        }
+    };
+
+    Ok(AuthorizedRequest {
+        request,
+        digest,
+        credential_names,
+    })
+}
+
+/// Sends the request, and when the server challenges it for digest auth, sends it once more
+/// with the answer to the challenge. The challenge is cached for the server, so that later
+/// requests to it answer its nonce up front.
+///
+/// A request whose body is streamed can't be sent again, so the challenge is returned as is.
+pub async fn send_authorized(
+    client: &Client,
+    request: Request,
+    digest: Option<&DigestAuth>,
+) -> joinerror::Result<Response> {
+    let retry = digest.and_then(|digest| Some((digest, request.try_clone()?)));
+    let response = client
+        .execute(request)
+        .await
+        .join_err::<()>("failed to send request")?;
+
+    let Some((digest, mut request)) = retry else {
+        return Ok(response);
+    };
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+    let Some(challenge) = DigestChallenge::from_headers(response.headers()) else {
+        return Ok(response);
+    };
+
+    let nonce_count = digest.nonces.insert(request.url(), challenge.clone());
+    digest::authorize_request(
+        &mut request,
+        &challenge,
+        &digest.credentials,
+        nonce_count,
+        &nanoid::nanoid!(),
+    )?;
+
+    client
+        .execute(request)
+        .await
+        .join_err::<()>("failed to send request")
+}
+
+fn resolve_oauth2_config(
+    config: &OAuth2Config,
+    render: &impl Fn(&str) -> joinerror::Result<String>,
+) -> joinerror::Result<oauth::OAuth2Config> {
+    let render_option = |value: &Option<String>| value.as_deref().map(render).transpose();
+
+    Ok(oauth::OAuth2Config {
+        grant: match config.grant {
+            OAuth2Grant::AuthorizationCode => oauth::OAuth2Grant::AuthorizationCode,
+            OAuth2Grant::ClientCredentials => oauth::OAuth2Grant::ClientCredentials,
+            OAuth2Grant::DeviceCode => oauth::OAuth2Grant::DeviceCode,
+            OAuth2Grant::RefreshToken => oauth::OAuth2Grant::RefreshToken,
+        },
+        auth_url: render_option(&config.auth_url)?,
+        token_url: render(&config.token_url)?,
+        device_auth_url: render_option(&config.device_auth_url)?,
+        client_id: render(&config.client_id)?,
+        client_secret: render_option(&config.client_secret)?,
+        scopes: config.scopes.clone(),
+        refresh_token: render_option(&config.refresh_token)?,
+        callback_port: config.callback_port,
+    })
+}
+
+/// Credentials sent with variables left unresolved would only be rejected by the server,
+/// so they are reported before sending the request instead.
+fn render_auth_value(variables: &EnvironmentVariables, value: &str) -> joinerror::Result<String> {
+    let Ok(values) = UrlParser::parse_template(value) else {
+        return Ok(value.to_string());
+    };
+
+    let mut result = String::new();
+    for value in &values {
+        match value {
+            ParsedValue::Variable(name) => {
+                let (value, _) = variables.0.get(name).ok_or_else(|| {
+                    Error::new::<()>(format!(
+                        "variable `{}` used by the auth is not defined",
+                        name
+                    ))
+                })?;
+                result.push_str(value);
+            }
+            other => result.push_str(&render_values(std::slice::from_ref(other))),
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,458 @@
+use joinerror::Error;
+use md5::Md5;
+use reqwest::{
+    Request, Url,
+    header::{AUTHORIZATION, HeaderMap, HeaderValue, WWW_AUTHENTICATE},
+};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, sync::Mutex};
+
+pub struct DigestCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Algorithm {
+    Md5,
+    Md5Sess,
+    Sha256,
+    Sha256Sess,
+}
+
+impl Algorithm {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "MD5" => Some(Algorithm::Md5),
+            "MD5-SESS" => Some(Algorithm::Md5Sess),
+            "SHA-256" => Some(Algorithm::Sha256),
+            "SHA-256-SESS" => Some(Algorithm::Sha256Sess),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Md5 => "MD5",
+            Algorithm::Md5Sess => "MD5-sess",
+            Algorithm::Sha256 => "SHA-256",
+            Algorithm::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    fn is_session(&self) -> bool {
+        matches!(self, Algorithm::Md5Sess | Algorithm::Sha256Sess)
+    }
+
+    fn hash(&self, data: &[u8]) -> String {
+        match self {
+            Algorithm::Md5 | Algorithm::Md5Sess => format!("{:x}", Md5::digest(data)),
+            Algorithm::Sha256 | Algorithm::Sha256Sess => format!("{:x}", Sha256::digest(data)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Qop {
+    Auth,
+    AuthInt,
+}
+
+/// The `Digest` challenge of a `WWW-Authenticate` header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigestChallenge {
+    realm: String,
+    nonce: String,
+    opaque: Option<String>,
+    algorithm: Algorithm,
+    /// None for servers that only implement RFC 2069.
+    qop: Option<Qop>,
+}
+
+impl DigestChallenge {
+    /// The first digest challenge of the response headers whose algorithm is supported.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get_all(WWW_AUTHENTICATE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .find_map(Self::parse)
+    }
+
+    fn parse(header: &str) -> Option<Self> {
+        let params = digest_params(header)?;
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.clone())
+        };
+
+        let algorithm = match param("algorithm") {
+            Some(name) => Algorithm::parse(&name)?,
+            None => Algorithm::Md5,
+        };
+        // `auth` is preferred, since `auth-int` also covers the body
+        let qop = match param("qop") {
+            Some(qop) => {
+                let offered = |name: &str| qop.split(',').any(|qop| qop.trim() == name);
+                if offered("auth") {
+                    Some(Qop::Auth)
+                } else if offered("auth-int") {
+                    Some(Qop::AuthInt)
+                } else {
+                    return None;
+                }
+            }
+            None => None,
+        };
+
+        Some(Self {
+            realm: param("realm").unwrap_or_default(),
+            nonce: param("nonce")?,
+            opaque: param("opaque"),
+            algorithm,
+            qop,
+        })
+    }
+}
+
+/// The last challenge answered for each origin, so that later requests to the same server
+/// answer its nonce up front rather than being challenged again.
+#[derive(Default)]
+pub struct DigestNonces {
+    challenges: Mutex<HashMap<String, (DigestChallenge, u32)>>,
+}
+
+impl DigestNonces {
+    /// Caches the challenge of the server of the url, and returns the nonce count of its
+    /// first answer.
+    pub fn insert(&self, url: &Url, challenge: DigestChallenge) -> u32 {
+        self.challenges
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(url.origin().ascii_serialization(), (challenge, 1));
+
+        1
+    }
+
+    /// The cached challenge of the server of the url, with the nonce count of its next answer.
+    pub fn next(&self, url: &Url) -> Option<(DigestChallenge, u32)> {
+        let mut challenges = self.challenges.lock().unwrap_or_else(|e| e.into_inner());
+        let (challenge, nonce_count) = challenges.get_mut(&url.origin().ascii_serialization())?;
+        *nonce_count += 1;
+
+        Some((challenge.clone(), *nonce_count))
+    }
+}
+
+/// Authorizes the request in place with its answer to the challenge of the server, adding the
+/// `Authorization` header. `nonce_count` counts the answers to the nonce of the challenge so far,
+/// this one included, and `cnonce` is the nonce chosen by the client.
+///
+/// See https://www.rfc-editor.org/rfc/rfc7616
+pub fn authorize_request(
+    request: &mut Request,
+    challenge: &DigestChallenge,
+    credentials: &DigestCredentials,
+    nonce_count: u32,
+    cnonce: &str,
+) -> joinerror::Result<()> {
+    let algorithm = challenge.algorithm;
+    let nonce_count = format!("{:08x}", nonce_count);
+    let url = request.url();
+    let uri = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+
+    let mut ha1 = algorithm.hash(
+        format!(
+            "{}:{}:{}",
+            credentials.username, challenge.realm, credentials.password
+        )
+        .as_bytes(),
+    );
+    if algorithm.is_session() {
+        ha1 = algorithm.hash(format!("{}:{}:{}", ha1, challenge.nonce, cnonce).as_bytes());
+    }
+
+    let ha2 = match challenge.qop {
+        Some(Qop::AuthInt) => {
+            let body = match request.body() {
+                None => Some(&[][..]),
+                Some(body) => body.as_bytes(),
+            };
+            // Streamed bodies can't be hashed without consuming them
+            let body = body.ok_or_else(|| {
+                Error::new::<()>("digest auth with auth-int doesn't support streamed bodies")
+            })?;
+            algorithm
+                .hash(format!("{}:{}:{}", request.method(), uri, algorithm.hash(body)).as_bytes())
+        }
+        _ => algorithm.hash(format!("{}:{}", request.method(), uri).as_bytes()),
+    };
+
+    let response = match challenge.qop {
+        Some(qop) => algorithm.hash(
+            format!(
+                "{}:{}:{}:{}:{}:{}",
+                ha1,
+                challenge.nonce,
+                nonce_count,
+                cnonce,
+                qop_name(qop),
+                ha2
+            )
+            .as_bytes(),
+        ),
+        None => algorithm.hash(format!("{}:{}:{}", ha1, challenge.nonce, ha2).as_bytes()),
+    };
+
+    let mut authorization = format!(
+        "Digest username={}, realm={}, nonce={}, uri={}, algorithm={}",
+        quote(&credentials.username),
+        quote(&challenge.realm),
+        quote(&challenge.nonce),
+        quote(&uri),
+        algorithm.name()
+    );
+    if let Some(qop) = challenge.qop {
+        authorization.push_str(&format!(
+            ", qop={}, nc={}, cnonce={}",
+            qop_name(qop),
+            nonce_count,
+            quote(cnonce)
+        ));
+    }
+    authorization.push_str(&format!(", response={}", quote(&response)));
+    if let Some(opaque) = &challenge.opaque {
+        authorization.push_str(&format!(", opaque={}", quote(opaque)));
+    }
+
+    let value = HeaderValue::from_str(&authorization)
+        .map_err(|e| Error::new::<()>(format!("invalid digest auth header value: {}", e)))?;
+    request.headers_mut().insert(AUTHORIZATION, value);
+
+    Ok(())
+}
+
+fn qop_name(qop: Qop) -> &'static str {
+    match qop {
+        Qop::Auth => "auth",
+        Qop::AuthInt => "auth-int",
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The params of the `Digest` challenge of the header, which can hold the challenges of other
+/// schemes before and after it.
+fn digest_params(header: &str) -> Option<Vec<(String, String)>> {
+    let mut rest = header;
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', ',']);
+        let (token, after) = split_token(rest);
+        if token.is_empty() {
+            return None;
+        }
+        if token.eq_ignore_ascii_case("digest") {
+            rest = after;
+            break;
+        }
+        // Skips the params of another scheme, or a param of the previous one
+        rest = after.trim_start();
+        if let Some(after) = rest.strip_prefix('=') {
+            rest = skip_value(after.trim_start());
+        }
+    }
+
+    let mut params = Vec::new();
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', ',']);
+        let (key, after) = split_token(rest);
+        let Some(after) = after.trim_start().strip_prefix('=') else {
+            // The end of the header, or the start of the next challenge
+            break;
+        };
+
+        let after = after.trim_start();
+        let (value, after) = match after.strip_prefix('"') {
+            Some(quoted) => unquote(quoted),
+            None => {
+                let (value, after) = split_token(after);
+                (value.to_string(), after)
+            }
+        };
+        params.push((key.to_string(), value));
+        rest = after;
+    }
+
+    Some(params)
+}
+
+fn split_token(value: &str) -> (&str, &str) {
+    let end = value
+        .find(|c: char| c == '=' || c == ',' || c.is_whitespace())
+        .unwrap_or(value.len());
+    value.split_at(end)
+}
+
+fn skip_value(value: &str) -> &str {
+    match value.strip_prefix('"') {
+        Some(quoted) => unquote(quoted).1,
+        None => split_token(value).1,
+    }
+}
+
+/// The value of a quoted string, whose opening quote was stripped, and what follows it.
+fn unquote(quoted: &str) -> (String, &str) {
+    let mut value = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return (value, &quoted[i + 1..]),
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    value.push(escaped);
+                }
+            }
+            c => value.push(c),
+        }
+    }
+
+    (value, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, Url};
+
+    use super::*;
+
+    // Examples of RFC 7616, section 3.9.1
+
+    const CNONCE: &str = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+
+    fn challenge(algorithm: &str) -> String {
+        format!(
+            r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm={}, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#,
+            algorithm
+        )
+    }
+
+    fn authorize(challenge: &str) -> String {
+        let challenge = DigestChallenge::parse(challenge).unwrap();
+        let mut request = Request::new(
+            Method::GET,
+            Url::parse("http://www.example.org/dir/index.html").unwrap(),
+        );
+        let credentials = DigestCredentials {
+            username: "Mufasa".to_string(),
+            password: "Circle of Life".to_string(),
+        };
+        authorize_request(&mut request, &challenge, &credentials, 1, CNONCE).unwrap();
+
+        request.headers()[AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(
+            authorize(&challenge("MD5")),
+            format!(
+                r#"Digest username="Mufasa", realm="http-auth@example.org", nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", uri="/dir/index.html", algorithm=MD5, qop=auth, nc=00000001, cnonce="{}", response="8ca523f5e9506fed4657c9700eebdbec", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#,
+                CNONCE
+            )
+        );
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert!(authorize(&challenge("SHA-256")).contains(
+            r#"response="753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1""#
+        ));
+    }
+
+    #[test]
+    fn test_rfc_2069_challenge_without_qop() {
+        // Example of RFC 2069, section 2.4
+        let authorization = {
+            let challenge = DigestChallenge::parse(
+                r#"Digest realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", opaque="5ccc069c403ebaf9f0171e9517f40e41""#,
+            )
+            .unwrap();
+            let mut request = Request::new(
+                Method::GET,
+                Url::parse("http://host.com/dir/index.html").unwrap(),
+            );
+            let credentials = DigestCredentials {
+                username: "Mufasa".to_string(),
+                password: "CircleOfLife".to_string(),
+            };
+            authorize_request(&mut request, &challenge, &credentials, 1, CNONCE).unwrap();
+            request.headers()[AUTHORIZATION]
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+
+        assert!(authorization.contains(r#"response="1949323746fe6a43ef61f9606e7febea""#));
+        assert!(!authorization.contains("qop="));
+    }
+
+    #[test]
+    fn test_challenge_among_other_schemes() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            WWW_AUTHENTICATE,
+            HeaderValue::from_static(r#"Basic realm="basic, realm""#),
+        );
+        headers.append(
+            WWW_AUTHENTICATE,
+            HeaderValue::from_static(
+                r#"Bearer realm="api", Digest realm="a \"quoted\" realm", nonce=abc, qop="auth-int", Basic realm="x""#,
+            ),
+        );
+
+        assert_eq!(
+            DigestChallenge::from_headers(&headers),
+            Some(DigestChallenge {
+                realm: r#"a "quoted" realm"#.to_string(),
+                nonce: "abc".to_string(),
+                opaque: None,
+                algorithm: Algorithm::Md5,
+                qop: Some(Qop::AuthInt),
+            })
+        );
+    }
+
+    #[test]
+    fn test_cached_nonce_is_counted_per_origin() {
+        let nonces = DigestNonces::default();
+        let challenge = DigestChallenge::parse(&challenge("MD5")).unwrap();
+        let url = Url::parse("http://www.example.org/dir/index.html").unwrap();
+
+        assert_eq!(nonces.next(&url), None);
+        assert_eq!(nonces.insert(&url, challenge.clone()), 1);
+        assert_eq!(
+            nonces.next(&Url::parse("http://www.example.org/other").unwrap()),
+            Some((challenge.clone(), 2))
+        );
+        assert_eq!(nonces.next(&url), Some((challenge, 3)));
+        assert_eq!(
+            nonces.next(&Url::parse("https://www.example.org/dir/index.html").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unsupported_algorithm() {
+        assert_eq!(DigestChallenge::parse(&challenge("SHA-512-256")), None);
+    }
+}
@@ -0,0 +1,498 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use joinerror::Error;
+use reqwest::{
+    Request,
+    header::{AUTHORIZATION, HeaderName, HeaderValue},
+};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+const X_AMZ_DATE: HeaderName = HeaderName::from_static("x-amz-date");
+const X_AMZ_SECURITY_TOKEN: HeaderName = HeaderName::from_static("x-amz-security-token");
+const X_AMZ_CONTENT_SHA256: HeaderName = HeaderName::from_static("x-amz-content-sha256");
+
+/// Headers that proxies and the http client are known to change, signing them
+/// would make the signature fail to verify.
+const UNSIGNED_HEADERS: [&str; 4] = ["authorization", "user-agent", "expect", "x-amzn-trace-id"];
+
+pub struct AwsCredentials {
+    pub access_key: String,
+    pub secret_key: String,
+    pub session_token: Option<String>,
+}
+
+/// Signs the request in place with AWS Signature Version 4, adding the `Authorization` header
+/// and the `x-amz-*` headers the signature covers.
+///
+/// See https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv.html
+pub fn sign_request(
+    request: &mut Request,
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    time: DateTime<Utc>,
+) -> joinerror::Result<()> {
+    let amz_date = time.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = time.format("%Y%m%d").to_string();
+
+    // Only S3 accepts an unsigned payload, so streamed bodies must be buffered beforehand
+    let payload_hash = match request.body() {
+        None => sha256_hex(b""),
+        Some(body) => sha256_hex(body.as_bytes().ok_or_else(|| {
+            Error::new::<()>("aws sigv4 can't sign a streamed body without buffering it")
+        })?),
+    };
+
+    let headers = request.headers_mut();
+    headers.insert(X_AMZ_DATE, header_value(&amz_date)?);
+    if let Some(token) = &credentials.session_token {
+        headers.insert(X_AMZ_SECURITY_TOKEN, header_value(token)?);
+    }
+    // S3 requires the payload hash to be sent along
+    if service == "s3" {
+        headers.insert(X_AMZ_CONTENT_SHA256, header_value(&payload_hash)?);
+    }
+
+    let (canonical_headers, signed_headers) = canonical_headers(request);
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method().as_str(),
+        canonical_uri(request.url().path(), service),
+        canonical_query(request.url()),
+        canonical_headers,
+        signed_headers,
+        payload_hash
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "{}\n{}\n{}\n{}",
+        ALGORITHM,
+        amz_date,
+        scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+
+    let signing_key = [date.as_str(), region, service, "aws4_request"]
+        .into_iter()
+        .fold(
+            format!("AWS4{}", credentials.secret_key).into_bytes(),
+            |key, part| hmac_sha256(&key, part.as_bytes()),
+        );
+    let signature = hmac_sha256(&signing_key, string_to_sign.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    let authorization = format!(
+        "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+        ALGORITHM, credentials.access_key, scope, signed_headers, signature
+    );
+    request
+        .headers_mut()
+        .insert(AUTHORIZATION, header_value(&authorization)?);
+
+    Ok(())
+}
+
+/// Returns the canonical headers, each followed by a newline, and the list of signed headers.
+fn canonical_headers(request: &Request) -> (String, String) {
+    let mut headers = BTreeMap::<String, Vec<String>>::new();
+    for (name, value) in request.headers() {
+        if UNSIGNED_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+
+        let value = String::from_utf8_lossy(value.as_bytes());
+        headers
+            .entry(name.as_str().to_string())
+            .or_default()
+            .push(value.split_whitespace().collect::<Vec<_>>().join(" "));
+    }
+
+    // The http client sends the host of the url, unless the header is set explicitly
+    if !headers.contains_key("host") {
+        let url = request.url();
+        let host = url.host_str().unwrap_or_default();
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        };
+        headers.insert("host".to_string(), vec![host]);
+    }
+
+    let canonical = headers
+        .iter()
+        .map(|(name, values)| format!("{}:{}\n", name, values.join(",")))
+        .collect();
+    let signed = headers.keys().cloned().collect::<Vec<_>>().join(";");
+
+    (canonical, signed)
+}
+
+/// Every service but S3 expects the path to be encoded once more on top of its encoding in the url.
+fn canonical_uri(path: &str, service: &str) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+
+    if service == "s3" {
+        uri_encode(&urlencoding::decode_binary(path.as_bytes()), false)
+    } else {
+        uri_encode(path.as_bytes(), false)
+    }
+}
+
+/// The query is decoded from the url as is, since form-decoding it would turn a literal `+`
+/// into a space, which is not what the server verifies the signature against.
+fn canonical_query(url: &reqwest::Url) -> String {
+    let mut pairs = url
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (
+                uri_encode(&urlencoding::decode_binary(key.as_bytes()), true),
+                uri_encode(&urlencoding::decode_binary(value.as_bytes()), true),
+            )
+        })
+        .collect::<Vec<_>>();
+    pairs.sort();
+
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-encodes everything but the unreserved characters, and slashes unless `encode_slash` is set.
+fn uri_encode(bytes: &[u8], encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(bytes.len());
+    for &byte in bytes {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+fn header_value(value: &str) -> joinerror::Result<HeaderValue> {
+    HeaderValue::from_str(value)
+        .map_err(|e| Error::new::<()>(format!("invalid aws sigv4 header value: {}", e)))
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use reqwest::{Method, Url};
+
+    use super::*;
+
+    // Requests and expected signatures from the AWS SigV4 test suite
+
+    fn credentials(session_token: Option<&str>) -> AwsCredentials {
+        AwsCredentials {
+            access_key: "AKIDEXAMPLE".to_string(),
+            secret_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: session_token.map(str::to_string),
+        }
+    }
+
+    fn request(method: Method, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Request {
+        let mut request = Request::new(method, Url::parse(url).unwrap());
+        for (name, value) in headers {
+            request.headers_mut().append(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        if let Some(body) = body {
+            *request.body_mut() = Some(body.to_string().into());
+        }
+
+        request
+    }
+
+    fn sign(mut request: Request, session_token: Option<&str>) -> Request {
+        let time = Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap();
+        sign_request(
+            &mut request,
+            &credentials(session_token),
+            "us-east-1",
+            "service",
+            time,
+        )
+        .unwrap();
+
+        request
+    }
+
+    fn authorization(request: &Request) -> &str {
+        request.headers()[AUTHORIZATION].to_str().unwrap()
+    }
+
+    fn expected(signed_headers: &str, signature: &str) -> String {
+        format!(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, SignedHeaders={}, Signature={}",
+            signed_headers, signature
+        )
+    }
+
+    #[test]
+    fn test_get_vanilla() {
+        let request = sign(
+            request(Method::GET, "https://example.amazonaws.com/", &[], None),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+            )
+        );
+        assert_eq!(request.headers()[X_AMZ_DATE], "20150830T123600Z");
+    }
+
+    #[test]
+    fn test_get_vanilla_query_order_key_case() {
+        let request = sign(
+            request(
+                Method::GET,
+                "https://example.amazonaws.com/?Param2=value2&Param1=value1",
+                &[],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"
+            )
+        );
+        assert_eq!(request.url().query(), Some("Param2=value2&Param1=value1"));
+    }
+
+    #[test]
+    fn test_get_vanilla_empty_query_key() {
+        let request = sign(
+            request(
+                Method::GET,
+                "https://example.amazonaws.com/?Param1=value1",
+                &[],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "a67d582fa61cc504c4bae71f336f98b97f1ea3c7a6bfe1b6e45aec72011b9aeb"
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_vanilla_query_unreserved() {
+        let unreserved = "-._~0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        let request = sign(
+            request(
+                Method::GET,
+                &format!("https://example.amazonaws.com/?{unreserved}={unreserved}"),
+                &[],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "9c3e54bfcdf0b19771a7f523ee5669cdf59bc7cc0884027167c21bb143a40197"
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_vanilla_utf8_query() {
+        let request = sign(
+            request(
+                Method::GET,
+                "https://example.amazonaws.com/?ሴ=bar",
+                &[],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "2cdec8eed098649ff3a119c94853b13c643bcf08f8b0a1d91e12c9027818dd04"
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_header_key_duplicate() {
+        let request = sign(
+            request(
+                Method::GET,
+                "https://example.amazonaws.com/",
+                &[
+                    ("My-Header1", "value2"),
+                    ("My-Header1", "value2"),
+                    ("My-Header1", "value1"),
+                ],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;my-header1;x-amz-date",
+                "c9d5ea9f3f72853aea855b47ea873832890dbdd183b4468f858259531a5138ea"
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_header_value_trim() {
+        let request = sign(
+            request(
+                Method::GET,
+                "https://example.amazonaws.com/",
+                &[("My-Header1", "value1"), ("My-Header2", "\"a   b   c\"")],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;my-header1;my-header2;x-amz-date",
+                "acc3ed3afb60bb290fc8d2dd0098b9911fcaa05412b367055dee359757a9c736"
+            )
+        );
+    }
+
+    #[test]
+    fn test_post_vanilla() {
+        let request = sign(
+            request(Method::POST, "https://example.amazonaws.com/", &[], None),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b"
+            )
+        );
+    }
+
+    #[test]
+    fn test_post_vanilla_query() {
+        let request = sign(
+            request(
+                Method::POST,
+                "https://example.amazonaws.com/?Param1=value1",
+                &[],
+                None,
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date",
+                "28038455d6de14eafc1f9222cf5aa6f1a96197d7deb8263271d420d138af7f11"
+            )
+        );
+    }
+
+    #[test]
+    fn test_post_x_www_form_urlencoded() {
+        let request = sign(
+            request(
+                Method::POST,
+                "https://example.amazonaws.com/",
+                &[("Content-Type", "application/x-www-form-urlencoded")],
+                Some("Param1=value1"),
+            ),
+            None,
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "content-type;host;x-amz-date",
+                "ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"
+            )
+        );
+    }
+
+    #[test]
+    fn test_post_sts_header_before() {
+        let token = "AQoDYXdzEPT//////////wEXAMPLEtc764bNrC9SAPBSM22wDOk4x4HIZ8j4FZTwdQWLWsKWHGBuFqwAeMicRXmxfpSPfIeoIYRqTflfKD8YUuwthAx7mSEI/qkPpKPi/kMcGdQrmGdeehM4IC1NtBmUpp2wUE8phUZampKsburEDy0KPkyQDYwT7WZ0wq5VSXDvp75YU9HFvlRd8Tx6q6fE8YQcHNVXAkiY9q6d+xo0rKwT38xVqr7ZD0u0iPPkUL64lIZbqBAz+scqKmlzm8FDrypNC9Yjc8fPOLn9FX9KSYvKTr4rvx3iSIlTJabIQwj2ICCR/oLxBA==";
+        let request = sign(
+            request(Method::POST, "https://example.amazonaws.com/", &[], None),
+            Some(token),
+        );
+
+        assert_eq!(
+            authorization(&request),
+            expected(
+                "host;x-amz-date;x-amz-security-token",
+                "85d96828115b5dc0cfc3bd16ad9e210dd772bbebba041836c64533a82be05ead"
+            )
+        );
+        assert_eq!(request.headers()[X_AMZ_SECURITY_TOKEN], token);
+    }
+
+    #[test]
+    fn test_canonical_query_keeps_plus_signs() {
+        let url = Url::parse("https://example.com/?b=x+y&a=%2F&a=1&c=x%20y&d").unwrap();
+        assert_eq!(canonical_query(&url), "a=%2F&a=1&b=x%2By&c=x%20y&d=");
+    }
+
+    #[test]
+    fn test_canonical_uri() {
+        assert_eq!(canonical_uri("/a%20b/c", "execute-api"), "/a%2520b/c");
+        assert_eq!(canonical_uri("/a%20b/c:d", "s3"), "/a%20b/c%3Ad");
+    }
+}
@@ -0,0 +1,566 @@
+use chrono::{DateTime, Utc};
+use joinerror::ResultExt;
+use moss_storage2::models::primitives::StorageScope;
+use reqwest::{
+    Method, Request, StatusCode, Url, Version,
+    header::{CONTENT_TYPE, HeaderMap},
+};
+use sapic_core::context::AnyAsyncContext;
+use sapic_ipc::contracts::main::resource::{ExecuteResourceEvent, ResolvedBody};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+
+use crate::project::RuntimeProject;
+
+/// Executions kept per project, the oldest ones being dropped first.
+const MAX_EXECUTIONS: usize = 100;
+
+/// Larger bodies are left out of the history, only their size is kept.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Headers carrying credentials, whose values are masked when they're recorded.
+const SENSITIVE_HEADERS: [&str; 9] = [
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+    "api-key",
+    "x-auth-token",
+    "x-amz-security-token",
+    "x-goog-api-key",
+];
+
+/// What the values of sensitive headers are replaced with.
+const MASKED_VALUE: &str = "********";
+
+/// Whether the header carries credentials, such as an authorization, a cookie, an api key
+/// or an aws session token.
+pub fn is_sensitive_header(name: &str) -> bool {
+    SENSITIVE_HEADERS
+        .iter()
+        .any(|sensitive| name.eq_ignore_ascii_case(sensitive))
+}
+
+/// Names of the headers and query params the auth of a request put its credentials in, such as
+/// a custom api key header, whose values are masked along with the sensitive headers.
+#[derive(Debug, Clone, Default)]
+pub struct CredentialNames {
+    pub headers: Vec<String>,
+    pub query_params: Vec<String>,
+}
+
+/// Key of the ids of the executions recorded for a project, from the oldest to the most recent.
+const KEY_EXECUTIONS: &str = "executions";
+
+fn key_execution(id: &str) -> String {
+    format!("{KEY_EXECUTIONS}.{id}")
+}
+
+/// Requests sent to the endpoints of projects along with the responses they received,
+/// kept in the storage of each project so that they can be exported as a HAR log.
+#[derive(Debug, Default)]
+pub struct ExecutionHistory {
+    // Recording an execution rewrites the list of executions of the project
+    lock: Mutex<()>,
+}
+
+impl ExecutionHistory {
+    pub async fn record(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        project: &RuntimeProject,
+        execution: &Execution,
+    ) -> joinerror::Result<()> {
+        let _guard = self.lock.lock().await;
+
+        let scope = StorageScope::Project(project.id.inner());
+        let mut ids = execution_ids(ctx, project).await?;
+        let id = nanoid::nanoid!();
+        ids.push(id.clone());
+        let dropped = ids
+            .drain(..ids.len().saturating_sub(MAX_EXECUTIONS))
+            .map(|id| key_execution(&id))
+            .collect::<Vec<_>>();
+
+        project
+            .storage
+            .put_batch(
+                ctx,
+                scope.clone(),
+                &[
+                    (&key_execution(&id), serde_json::to_value(execution)?),
+                    (KEY_EXECUTIONS, serde_json::to_value(&ids)?),
+                ],
+            )
+            .await
+            .join_err::<()>("failed to record execution")?;
+
+        if !dropped.is_empty() {
+            project
+                .storage
+                .remove_batch(
+                    ctx,
+                    scope,
+                    &dropped.iter().map(String::as_str).collect::<Vec<_>>(),
+                )
+                .await
+                .join_err::<()>("failed to remove old executions")?;
+        }
+
+        Ok(())
+    }
+
+    /// The executions of the project, from the oldest to the most recent.
+    pub async fn executions(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        project: &RuntimeProject,
+    ) -> joinerror::Result<Vec<Execution>> {
+        let keys = execution_ids(ctx, project)
+            .await?
+            .iter()
+            .map(|id| key_execution(id))
+            .collect::<Vec<_>>();
+        let mut values = project
+            .storage
+            .get_batch(
+                ctx,
+                StorageScope::Project(project.id.inner()),
+                &keys.iter().map(String::as_str).collect::<Vec<_>>(),
+            )
+            .await
+            .join_err::<()>("failed to get executions")?
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect::<HashMap<_, _>>();
+
+        Ok(keys
+            .iter()
+            .filter_map(|key| {
+                serde_json::from_value(values.remove(key)?)
+                    .inspect_err(|e| tracing::warn!("failed to parse execution {}: {}", key, e))
+                    .ok()
+            })
+            .collect())
+    }
+}
+
+async fn execution_ids(
+    ctx: &dyn AnyAsyncContext,
+    project: &RuntimeProject,
+) -> joinerror::Result<Vec<String>> {
+    let ids = project
+        .storage
+        .get(
+            ctx,
+            StorageScope::Project(project.id.inner()),
+            KEY_EXECUTIONS,
+        )
+        .await
+        .join_err::<()>("failed to get executions")?;
+
+    Ok(ids
+        .and_then(|ids| serde_json::from_value(ids).ok())
+        .unwrap_or_default())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Execution {
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Values of sensitive headers are masked, so that they aren't exported.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<RecordedBody>,
+}
+
+impl RecordedRequest {
+    /// Records the request as it's about to be sent. Form data bodies are streamed,
+    /// so their fields are taken from the resolved body instead.
+    pub fn new(
+        request: &Request,
+        resolved_body: Option<&ResolvedBody>,
+        credentials: &CredentialNames,
+    ) -> Self {
+        let headers = header_pairs(request.headers(), &credentials.headers);
+        let mime_type = content_type(&headers);
+
+        let body = match (request.body(), resolved_body) {
+            (_, Some(ResolvedBody::FormData(params))) => Some(RecordedBody {
+                mime_type,
+                size: None,
+                text: None,
+                params: params
+                    .iter()
+                    .map(|param| (param.name.clone(), param.value.clone()))
+                    .collect(),
+            }),
+            (Some(body), _) => body
+                .as_bytes()
+                .map(|bytes| RecordedBody::bytes(mime_type, bytes)),
+            (None, _) => None,
+        };
+
+        Self {
+            method: request.method().to_string(),
+            url: masked_url(request.url(), &credentials.query_params),
+            http_version: format!("{:?}", request.version()),
+            headers,
+            body,
+        }
+    }
+
+    /// Records a request relayed by the recording proxy, whose body was read in full.
+    pub fn relayed(
+        method: &Method,
+        url: &str,
+        version: Version,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let headers = header_pairs(headers, &[]);
+        let mime_type = content_type(&headers);
+
+        Self {
+            method: method.to_string(),
+            url: url.to_string(),
+            http_version: format!("{:?}", version),
+            headers,
+            body: (!body.is_empty()).then(|| RecordedBody::bytes(mime_type, body)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedBody {
+    pub mime_type: String,
+    /// Unknown for form data bodies, whose size is only known once they're sent.
+    pub size: Option<usize>,
+    /// Omitted when the body is too large or isn't valid utf-8.
+    pub text: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+impl RecordedBody {
+    fn bytes(mime_type: String, bytes: &[u8]) -> Self {
+        Self {
+            mime_type,
+            size: Some(bytes.len()),
+            text: body_text(bytes),
+            params: vec![],
+        }
+    }
+}
+
+/// Values of sensitive headers are masked, so that they aren't exported.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub headers: Vec<(String, String)>,
+    pub size: usize,
+    // Only the text of the body is exported, so that's all that's stored
+    #[serde(
+        serialize_with = "serialize_body",
+        deserialize_with = "deserialize_body"
+    )]
+    body: Vec<u8>,
+}
+
+impl RecordedResponse {
+    /// Records a response relayed by the recording proxy, whose body was read in full.
+    pub fn relayed(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        Self {
+            status: status.as_u16(),
+            status_text: status.canonical_reason().unwrap_or_default().to_string(),
+            headers: header_pairs(headers, &[]),
+            size: body.len(),
+            body: if body.len() <= MAX_BODY_SIZE {
+                body.to_vec()
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    /// Records an event of the response as it arrives.
+    pub fn record(&mut self, event: &ExecuteResourceEvent) {
+        match event {
+            ExecuteResourceEvent::Status {
+                status,
+                status_text,
+            } => {
+                self.status = *status;
+                self.status_text = status_text.clone();
+            }
+            ExecuteResourceEvent::Headers { headers } => {
+                self.headers = headers
+                    .iter()
+                    .map(|header| recorded_header(&header.name, &header.value, &[]))
+                    .collect();
+            }
+            ExecuteResourceEvent::Body { chunk } => {
+                self.size += chunk.len();
+                if self.size <= MAX_BODY_SIZE {
+                    self.body.extend_from_slice(chunk);
+                } else {
+                    self.body = Vec::new();
+                }
+            }
+        }
+    }
+
+    pub fn mime_type(&self) -> String {
+        content_type(&self.headers)
+    }
+
+    pub fn text(&self) -> Option<String> {
+        if self.size > MAX_BODY_SIZE {
+            return None;
+        }
+        body_text(&self.body)
+    }
+}
+
+/// Values of the sensitive headers and of the `credentials` headers are masked.
+fn header_pairs(headers: &HeaderMap, credentials: &[String]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            recorded_header(
+                name.as_str(),
+                &String::from_utf8_lossy(value.as_bytes()),
+                credentials,
+            )
+        })
+        .collect()
+}
+
+fn recorded_header(name: &str, value: &str, credentials: &[String]) -> (String, String) {
+    let is_credential = credentials
+        .iter()
+        .any(|credential| name.eq_ignore_ascii_case(credential));
+    let value = if is_credential || is_sensitive_header(name) {
+        MASKED_VALUE
+    } else {
+        value
+    };
+    (name.to_string(), value.to_string())
+}
+
+/// The url with the values of the `credentials` query params masked. The rest of the query
+/// is kept as it was encoded.
+fn masked_url(url: &Url, credentials: &[String]) -> String {
+    let Some(query) = url.query().filter(|_| !credentials.is_empty()) else {
+        return url.to_string();
+    };
+
+    let query = query
+        .split('&')
+        .map(|pair| {
+            let is_credential = url::form_urlencoded::parse(pair.as_bytes())
+                .next()
+                .is_some_and(|(name, _)| credentials.iter().any(|credential| *credential == name));
+            if is_credential {
+                let (name, _) = pair.split_once('=').unwrap_or((pair, ""));
+                format!("{}={}", name, MASKED_VALUE)
+            } else {
+                pair.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let mut url = url.clone();
+    url.set_query(Some(&query));
+    url.to_string()
+}
+
+fn content_type(headers: &[(String, String)]) -> String {
+    headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(CONTENT_TYPE.as_str()))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_default()
+}
+
+fn body_text(bytes: &[u8]) -> Option<String> {
+    if bytes.len() > MAX_BODY_SIZE {
+        return None;
+    }
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+fn serialize_body<S: Serializer>(body: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    body_text(body).serialize(serializer)
+}
+
+fn deserialize_body<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?
+        .map(String::into_bytes)
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_ipc::contracts::main::resource::ResponseHeader;
+
+    use super::*;
+
+    fn execution(status: u16) -> Execution {
+        Execution {
+            started_at: Utc::now(),
+            duration_ms: 0,
+            request: RecordedRequest {
+                method: "GET".to_string(),
+                url: "http://localhost/".to_string(),
+                http_version: "HTTP/1.1".to_string(),
+                headers: vec![],
+                body: None,
+            },
+            response: RecordedResponse {
+                status,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_execution_round_trip() {
+        let mut execution = execution(200);
+        execution.response.record(&ExecuteResourceEvent::Body {
+            chunk: b"hello".to_vec(),
+        });
+
+        let value = serde_json::to_value(&execution).unwrap();
+        assert_eq!(value["response"]["body"], serde_json::json!("hello"));
+
+        let execution: Execution = serde_json::from_value(value).unwrap();
+        assert_eq!(execution.response.status, 200);
+        assert_eq!(execution.response.text().as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn test_sensitive_headers_are_masked() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", "Bearer token".parse().unwrap());
+        headers.insert("Cookie", "session=1".parse().unwrap());
+        headers.insert("X-Amz-Security-Token", "session-token".parse().unwrap());
+        headers.insert("X-Api-Key", "secret".parse().unwrap());
+        headers.insert("Accept", "application/json".parse().unwrap());
+
+        let request = RecordedRequest::relayed(
+            &Method::GET,
+            "http://localhost/",
+            Version::HTTP_11,
+            &headers,
+            b"",
+        );
+        let value = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(value("authorization"), Some(MASKED_VALUE));
+        assert_eq!(value("cookie"), Some(MASKED_VALUE));
+        assert_eq!(value("x-amz-security-token"), Some(MASKED_VALUE));
+        assert_eq!(value("x-api-key"), Some(MASKED_VALUE));
+        assert_eq!(value("accept"), Some("application/json"));
+
+        let mut response = RecordedResponse::default();
+        response.record(&ExecuteResourceEvent::Headers {
+            headers: vec![
+                ResponseHeader {
+                    name: "Set-Cookie".to_string(),
+                    value: "session=1".to_string(),
+                },
+                ResponseHeader {
+                    name: "Content-Type".to_string(),
+                    value: "text/plain".to_string(),
+                },
+            ],
+        });
+        assert_eq!(
+            response.headers,
+            vec![
+                ("Set-Cookie".to_string(), MASKED_VALUE.to_string()),
+                ("Content-Type".to_string(), "text/plain".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_api_key_header_is_masked() {
+        let mut request = Request::new(Method::GET, Url::parse("http://localhost/items").unwrap());
+        request
+            .headers_mut()
+            .insert("X-Custom-Key", "secret".parse().unwrap());
+        request
+            .headers_mut()
+            .insert("X-Custom-Other", "visible".parse().unwrap());
+
+        let credentials = CredentialNames {
+            headers: vec!["x-custom-key".to_string()],
+            query_params: vec![],
+        };
+        let recorded = RecordedRequest::new(&request, None, &credentials);
+        assert_eq!(
+            recorded.headers,
+            vec![
+                ("x-custom-key".to_string(), MASKED_VALUE.to_string()),
+                ("x-custom-other".to_string(), "visible".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_api_key_query_param_is_masked() {
+        let request = Request::new(
+            Method::GET,
+            Url::parse("http://localhost/items?q=a%20b&api+key=secret&page=1#top").unwrap(),
+        );
+
+        let credentials = CredentialNames {
+            headers: vec![],
+            query_params: vec!["api key".to_string()],
+        };
+        let recorded = RecordedRequest::new(&request, None, &credentials);
+        assert_eq!(
+            recorded.url,
+            format!("http://localhost/items?q=a%20b&api+key={MASKED_VALUE}&page=1#top")
+        );
+
+        let recorded = RecordedRequest::new(&request, None, &CredentialNames::default());
+        assert_eq!(
+            recorded.url,
+            "http://localhost/items?q=a%20b&api+key=secret&page=1#top"
+        );
+    }
+
+    #[test]
+    fn test_response_drops_large_bodies() {
+        let mut response = RecordedResponse::default();
+        response.record(&ExecuteResourceEvent::Body {
+            chunk: b"hello".to_vec(),
+        });
+        assert_eq!(response.text().as_deref(), Some("hello"));
+
+        response.record(&ExecuteResourceEvent::Body {
+            chunk: vec![b'a'; MAX_BODY_SIZE],
+        });
+        assert_eq!(response.size, MAX_BODY_SIZE + 5);
+        assert_eq!(response.text(), None);
+    }
+}
@@ -0,0 +1,291 @@
+//! Ordering and data of the collection runs.
+//!
+//! A run sends the endpoints of a directory subtree in the order they are shown, once for
+//! every row of an optional data file, the columns of the row being bound as variables.
+
+use joinerror::{Error, ResultExt};
+use sapic_base::resource::types::{
+    ResourceSummary,
+    primitives::{ResourceClass, ResourceId, ResourceKind},
+};
+use serde_json::Value as JsonValue;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::resource::{self, value_to_string};
+
+/// The columns of a row of a data file, as variable names and values.
+pub type DataRow = Vec<(String, String)>;
+
+/// Orders the HTTP endpoints below `dir` the way they are shown: the entries of every
+/// directory by their persisted order then by name, the endpoints of a subdirectory
+/// taking its place.
+pub fn ordered_endpoints(
+    dir: &Path,
+    resources: Vec<ResourceSummary>,
+    orders: &HashMap<ResourceId, isize>,
+) -> Vec<ResourceSummary> {
+    let mut entries: HashMap<PathBuf, Vec<ResourceSummary>> = HashMap::new();
+    for resource in resources {
+        let parent = resource.path.parent().unwrap_or(Path::new(""));
+        entries
+            .entry(parent.to_path_buf())
+            .or_default()
+            .push(resource);
+    }
+
+    // Entries without a persisted order are shown last
+    let order =
+        |resource: &ResourceSummary| orders.get(&resource.id).copied().unwrap_or(isize::MAX);
+    for children in entries.values_mut() {
+        children.sort_by(|a, b| order(a).cmp(&order(b)).then_with(|| a.name.cmp(&b.name)));
+    }
+
+    let mut endpoints = Vec::new();
+    collect_endpoints(dir, &mut entries, &mut endpoints);
+
+    endpoints
+}
+
+fn collect_endpoints(
+    dir: &Path,
+    entries: &mut HashMap<PathBuf, Vec<ResourceSummary>>,
+    endpoints: &mut Vec<ResourceSummary>,
+) {
+    let Some(children) = entries.remove(dir) else {
+        return;
+    };
+
+    for child in children {
+        match child.kind {
+            ResourceKind::Dir => collect_endpoints(&child.path, entries, endpoints),
+            ResourceKind::Item
+                if child.class == ResourceClass::Endpoint
+                    && child
+                        .protocol
+                        .as_ref()
+                        .and_then(resource::http_method)
+                        .is_some() =>
+            {
+                endpoints.push(child)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reads the rows of a CSV or JSON data file, told apart by its extension.
+pub async fn read_data_file(path: &Path) -> joinerror::Result<Vec<DataRow>> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .join_err_with::<()>(|| format!("failed to read data file {}", path.display()))?;
+
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+    let rows = match extension.as_deref() {
+        Some("csv") => parse_csv(&content)?,
+        Some("json") => {
+            let document: JsonValue = serde_json::from_str(&content)
+                .join_err_with::<()>(|| format!("invalid data file {}", path.display()))?;
+            parse_json(&document)?
+        }
+        _ => {
+            return Err(Error::new::<()>(format!(
+                "data file {} is neither a CSV nor a JSON file",
+                path.display()
+            )));
+        }
+    };
+
+    if rows.is_empty() {
+        return Err(Error::new::<()>(format!(
+            "data file {} has no rows",
+            path.display()
+        )));
+    }
+
+    Ok(rows)
+}
+
+/// Parses a CSV document whose first row names the columns.
+pub fn parse_csv(content: &str) -> joinerror::Result<Vec<DataRow>> {
+    let err = |e: csv::Error| Error::new::<()>(format!("invalid csv data: {}", e));
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(content.as_bytes());
+    let headers = reader.headers().map_err(err)?.clone();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(err)?;
+            Ok(headers
+                .iter()
+                .zip(record.iter())
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect())
+        })
+        .collect()
+}
+
+/// Parses a JSON array of objects, whose values are bound as they would be written in
+/// an environment.
+pub fn parse_json(document: &JsonValue) -> joinerror::Result<Vec<DataRow>> {
+    let JsonValue::Array(items) = document else {
+        return Err(Error::new::<()>("json data must be an array of objects"));
+    };
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let JsonValue::Object(object) = item else {
+                return Err(Error::new::<()>(format!(
+                    "row {} of the json data is not an object",
+                    index
+                )));
+            };
+
+            Ok(object
+                .iter()
+                .map(|(name, value)| (name.clone(), value_to_string(value)))
+                .collect())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+    use serde_json::json;
+
+    use super::*;
+
+    fn summary(
+        path: &str,
+        kind: ResourceKind,
+        protocol: Option<ResourceProtocol>,
+    ) -> ResourceSummary {
+        let path = PathBuf::from(path);
+        ResourceSummary {
+            id: ResourceId::new(),
+            name: path.file_name().unwrap().to_string_lossy().to_string(),
+            path,
+            class: ResourceClass::Endpoint,
+            kind,
+            protocol,
+        }
+    }
+
+    #[test]
+    fn test_ordered_endpoints() {
+        let users = summary("users", ResourceKind::Dir, None);
+        let list_users = summary(
+            "users/list",
+            ResourceKind::Item,
+            Some(ResourceProtocol::Get),
+        );
+        let create_user = summary(
+            "users/create",
+            ResourceKind::Item,
+            Some(ResourceProtocol::Post),
+        );
+        let login = summary("login", ResourceKind::Item, Some(ResourceProtocol::Post));
+        let health = summary("health", ResourceKind::Item, Some(ResourceProtocol::Get));
+        let socket = summary(
+            "socket",
+            ResourceKind::Item,
+            Some(ResourceProtocol::WebSocket),
+        );
+
+        let orders = HashMap::from([
+            (login.id.clone(), 0),
+            (users.id.clone(), 1),
+            (socket.id.clone(), 2),
+            (create_user.id.clone(), 0),
+            (list_users.id.clone(), 1),
+        ]);
+        let resources = vec![
+            health.clone(),
+            list_users.clone(),
+            socket,
+            users,
+            create_user.clone(),
+            login.clone(),
+        ];
+
+        let ordered = ordered_endpoints(Path::new(""), resources.clone(), &orders)
+            .into_iter()
+            .map(|endpoint| endpoint.id)
+            .collect::<Vec<_>>();
+        // Non-HTTP endpoints are skipped, unordered ones are run last
+        assert_eq!(
+            ordered,
+            vec![
+                login.id,
+                create_user.id.clone(),
+                list_users.id.clone(),
+                health.id
+            ]
+        );
+
+        let ordered = ordered_endpoints(Path::new("users"), resources, &orders)
+            .into_iter()
+            .map(|endpoint| endpoint.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ordered, vec![create_user.id, list_users.id]);
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let rows = parse_csv("user, password\nneo,\"red, pill\"\ntrinity,\n").unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    ("user".to_string(), "neo".to_string()),
+                    ("password".to_string(), "red, pill".to_string()),
+                ],
+                vec![
+                    ("user".to_string(), "trinity".to_string()),
+                    ("password".to_string(), String::new()),
+                ],
+            ]
+        );
+
+        assert!(parse_csv("user,password\nneo\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let rows = parse_json(&json!([
+            { "user": "neo", "age": 37, "admin": true },
+            { "user": "trinity", "age": null },
+        ]))
+        .unwrap()
+        .into_iter()
+        .map(HashMap::<_, _>::from_iter)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                HashMap::from([
+                    ("user".to_string(), "neo".to_string()),
+                    ("age".to_string(), "37".to_string()),
+                    ("admin".to_string(), "true".to_string()),
+                ]),
+                HashMap::from([
+                    ("user".to_string(), "trinity".to_string()),
+                    ("age".to_string(), String::new()),
+                ]),
+            ]
+        );
+
+        assert!(parse_json(&json!({ "user": "neo" })).is_err());
+        assert!(parse_json(&json!(["neo"])).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+pub mod curl;
+pub mod go;
+pub mod httpie;
+pub mod javascript;
+pub mod python;
+pub mod rust;
+
+use joinerror::OptionExt;
+use moss_project::models::types::{ApiKeyLocation, AuthScheme};
+use sapic_ipc::contracts::main::resource::{
+    ResolveResourceOutput, ResolvedBody, ResolvedParam, SnippetLanguage,
+};
+use std::path::PathBuf;
+
+use crate::{
+    export::http_file::form_pairs,
+    resource::{EnvironmentVariables, http_method},
+};
+
+/// An http request as the snippets send it, with its auth turned into headers
+/// wherever the languages have nothing better for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetRequest {
+    pub method: String,
+    /// The url along with its encoded query.
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub basic_auth: Option<(String, String)>,
+    pub body: Option<SnippetBody>,
+    /// Parts of the request the snippet leaves out, written as comments above it.
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnippetBody {
+    /// Text, json and xml bodies, sent as they are written.
+    Raw(String),
+    Binary(PathBuf),
+    Urlencoded(Vec<(String, String)>),
+    FormData(Vec<(String, String)>),
+}
+
+impl SnippetRequest {
+    /// Builds the request of a resolved endpoint, the same way it's sent from the app.
+    /// The values of the auth are rendered with `variables`, leaving the undefined ones in place.
+    pub fn new(
+        resolved: &ResolveResourceOutput,
+        auth: Option<&AuthScheme>,
+        variables: &EnvironmentVariables,
+    ) -> joinerror::Result<Self> {
+        let method = resolved
+            .protocol
+            .as_ref()
+            .and_then(http_method)
+            .ok_or_join_err::<()>("resource is not an HTTP endpoint")?;
+
+        let url = resolved.url.trim();
+        let mut url = if url.contains("://") {
+            url.to_string()
+        } else {
+            format!("http://{url}")
+        };
+        let mut query = pairs(&resolved.query_params);
+        let mut headers = pairs(&resolved.headers);
+        let mut basic_auth = None;
+        let mut notes = Vec::new();
+
+        match auth {
+            None | Some(AuthScheme::None) | Some(AuthScheme::Inherit) => {}
+            Some(AuthScheme::Basic { username, password }) => {
+                basic_auth = Some((variables.render(username), variables.render(password)))
+            }
+            Some(AuthScheme::Bearer { token }) => headers.push((
+                "Authorization".to_string(),
+                format!("Bearer {}", variables.render(token)),
+            )),
+            Some(AuthScheme::ApiKey {
+                key,
+                value,
+                location,
+            }) => {
+                let pair = (variables.render(key), variables.render(value));
+                match location {
+                    ApiKeyLocation::Header => headers.push(pair),
+                    ApiKeyLocation::Query => query.push(pair),
+                }
+            }
+            Some(AuthScheme::Digest { .. }) => {
+                notes.push("The digest auth of the request isn't included".to_string())
+            }
+            Some(AuthScheme::OAuth2(_)) => {
+                notes.push("The OAuth 2.0 auth of the request isn't included".to_string())
+            }
+            Some(AuthScheme::AwsSigV4(_)) => {
+                notes.push("The AWS Signature V4 auth of the request isn't included".to_string())
+            }
+        }
+
+        if !query.is_empty() {
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&form_pairs(&query));
+        }
+
+        let body = resolved.body.as_ref().map(|body| match body {
+            ResolvedBody::Text(text) => (Some("text/plain"), SnippetBody::Raw(text.clone())),
+            ResolvedBody::Json(json) => (
+                Some("application/json"),
+                SnippetBody::Raw(
+                    serde_json::to_string_pretty(json).unwrap_or_else(|_| json.to_string()),
+                ),
+            ),
+            ResolvedBody::Xml(xml) => (Some("application/xml"), SnippetBody::Raw(xml.clone())),
+            ResolvedBody::Binary(path) => (
+                Some("application/octet-stream"),
+                SnippetBody::Binary(path.clone()),
+            ),
+            // Every language sets the content type of forms by itself
+            ResolvedBody::Urlencoded(params) => (None, SnippetBody::Urlencoded(pairs(params))),
+            ResolvedBody::FormData(params) => (None, SnippetBody::FormData(pairs(params))),
+        });
+
+        let content_type = headers
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+        let body = body.map(|(implied, body)| {
+            match (implied, content_type) {
+                (None, Some(index)) => {
+                    headers.remove(index);
+                }
+                (Some(implied), None) => {
+                    headers.push(("Content-Type".to_string(), implied.to_string()))
+                }
+                _ => {}
+            }
+            body
+        });
+
+        Ok(Self {
+            method: method.to_string(),
+            url,
+            headers,
+            basic_auth,
+            body,
+            notes,
+        })
+    }
+}
+
+/// Renders the request as a snippet of the language.
+pub fn generate(request: &SnippetRequest, language: SnippetLanguage) -> String {
+    match language {
+        SnippetLanguage::Curl => curl::generate(request),
+        SnippetLanguage::Httpie => httpie::generate(request),
+        SnippetLanguage::PythonRequests => python::generate(request),
+        SnippetLanguage::JavascriptFetch => javascript::generate(request),
+        SnippetLanguage::GoNetHttp => go::generate(request),
+        SnippetLanguage::RustReqwest => rust::generate(request),
+    }
+}
+
+fn pairs(params: &[ResolvedParam]) -> Vec<(String, String)> {
+    params
+        .iter()
+        .map(|param| (param.name.clone(), param.value.clone()))
+        .collect()
+}
+
+/// Quotes an argument of a shell command.
+fn shell_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "'\\''"))
+}
+
+/// Leaves words such as methods unquoted, unless the shell would interpret them.
+fn shell_word(text: &str) -> String {
+    if !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        text.to_string()
+    } else {
+        shell_quote(text)
+    }
+}
+
+/// A double-quoted string literal, with the escapes shared by Python, JavaScript and Go.
+fn quote(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| format!("\"{}\"", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+
+    use super::*;
+
+    /// The requests of the golden files, one sending json with a bearer token and
+    /// one sending a form with basic auth.
+    fn requests() -> [(&'static str, SnippetRequest); 2] {
+        [
+            (
+                "json",
+                SnippetRequest {
+                    method: "POST".to_string(),
+                    url: "https://api.example.com/pets?tag=good%20boy".to_string(),
+                    headers: vec![
+                        ("Accept".to_string(), "application/json".to_string()),
+                        ("Authorization".to_string(), "Bearer {{token}}".to_string()),
+                        ("Content-Type".to_string(), "application/json".to_string()),
+                    ],
+                    basic_auth: None,
+                    body: Some(SnippetBody::Raw(
+                        "{\n  \"name\": \"Rex\",\n  \"bio\": \"It's a \\\"good\\\" dog\"\n}"
+                            .to_string(),
+                    )),
+                    notes: vec![],
+                },
+            ),
+            (
+                "form",
+                SnippetRequest {
+                    method: "PUT".to_string(),
+                    url: "http://localhost:8080/login".to_string(),
+                    headers: vec![("X-Request-Id".to_string(), "{{request_id}}".to_string())],
+                    basic_auth: Some(("admin".to_string(), "{{password}}".to_string())),
+                    body: Some(SnippetBody::Urlencoded(vec![
+                        ("user".to_string(), "Rex".to_string()),
+                        ("scope".to_string(), "read write".to_string()),
+                    ])),
+                    notes: vec!["The OAuth 2.0 auth of the request isn't included".to_string()],
+                },
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_snippet_request() {
+        let param = |name: &str, value: &str| ResolvedParam {
+            id: name.to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+        };
+        let resolved = ResolveResourceOutput {
+            protocol: Some(ResourceProtocol::Post),
+            url: "localhost:8080/pets?sort=name".to_string(),
+            headers: vec![param("Content-Type", "text/csv")],
+            path_params: vec![],
+            query_params: vec![param("tag", "good boy")],
+            body: Some(ResolvedBody::Urlencoded(vec![param("name", "{{name}}")])),
+            substitutions: vec![],
+            unresolved: vec![],
+        };
+        let auth = AuthScheme::ApiKey {
+            key: "api_key".to_string(),
+            value: "{{api_key}}".to_string(),
+            location: ApiKeyLocation::Query,
+        };
+
+        let request =
+            SnippetRequest::new(&resolved, Some(&auth), &EnvironmentVariables::default()).unwrap();
+        assert_eq!(
+            request,
+            SnippetRequest {
+                method: "POST".to_string(),
+                url: "http://localhost:8080/pets?sort=name&tag=good%20boy&api_key={{api_key}}"
+                    .to_string(),
+                // The content type of forms is set by the languages
+                headers: vec![],
+                basic_auth: None,
+                body: Some(SnippetBody::Urlencoded(vec![(
+                    "name".to_string(),
+                    "{{name}}".to_string()
+                )])),
+                notes: vec![],
+            }
+        );
+
+        let resolved = ResolveResourceOutput {
+            protocol: Some(ResourceProtocol::WebSocket),
+            ..resolved
+        };
+        assert!(SnippetRequest::new(&resolved, None, &EnvironmentVariables::default()).is_err());
+    }
+
+    fn assert_golden(language: SnippetLanguage, golden: [&str; 2]) {
+        for ((name, request), golden) in requests().into_iter().zip(golden) {
+            assert_eq!(
+                generate(&request, language),
+                golden,
+                "{:?} snippet of the {} request",
+                language,
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_curl() {
+        assert_golden(
+            SnippetLanguage::Curl,
+            [
+                include_str!("../tests/snippets/curl/json.sh"),
+                include_str!("../tests/snippets/curl/form.sh"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_httpie() {
+        assert_golden(
+            SnippetLanguage::Httpie,
+            [
+                include_str!("../tests/snippets/httpie/json.sh"),
+                include_str!("../tests/snippets/httpie/form.sh"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_python() {
+        assert_golden(
+            SnippetLanguage::PythonRequests,
+            [
+                include_str!("../tests/snippets/python/json.py"),
+                include_str!("../tests/snippets/python/form.py"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_javascript() {
+        assert_golden(
+            SnippetLanguage::JavascriptFetch,
+            [
+                include_str!("../tests/snippets/javascript/json.js"),
+                include_str!("../tests/snippets/javascript/form.js"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_go() {
+        assert_golden(
+            SnippetLanguage::GoNetHttp,
+            [
+                include_str!("../tests/snippets/go/json.go"),
+                include_str!("../tests/snippets/go/form.go"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_rust() {
+        assert_golden(
+            SnippetLanguage::RustReqwest,
+            [
+                include_str!("../tests/snippets/rust/json.rs"),
+                include_str!("../tests/snippets/rust/form.rs"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::snippet::{SnippetBody, SnippetRequest, shell_quote, shell_word};
+
+/// Renders the request as a curl command, with one option per line.
+pub fn generate(request: &SnippetRequest) -> String {
+    let mut command = "curl".to_string();
+    match request.method.as_str() {
+        "GET" => {}
+        // Asking for a `HEAD` with `-X` makes curl wait for a body that never comes
+        "HEAD" => command.push_str(" --head"),
+        method => command.push_str(&format!(" -X {}", shell_word(method))),
+    }
+    command.push_str(&format!(" {}", shell_quote(&request.url)));
+
+    let mut arguments = vec![command];
+    arguments.extend(request.headers.iter().map(|(name, value)| {
+        // A header without a value is written as `Name;`, since `Name:` removes it
+        let header = match value.is_empty() {
+            true => format!("{};", name),
+            false => format!("{}: {}", name, value),
+        };
+        format!("-H {}", shell_quote(&header))
+    }));
+    if let Some((username, password)) = &request.basic_auth {
+        arguments.push(format!(
+            "-u {}",
+            shell_quote(&format!("{}:{}", username, password))
+        ));
+    }
+    match &request.body {
+        None => {}
+        Some(SnippetBody::Raw(text)) => arguments.push(format!("--data-raw {}", shell_quote(text))),
+        Some(SnippetBody::Binary(path)) => arguments.push(format!(
+            "--data-binary {}",
+            shell_quote(&format!("@{}", path.display()))
+        )),
+        Some(SnippetBody::Urlencoded(fields)) => {
+            arguments.extend(fields.iter().map(|(name, value)| {
+                format!(
+                    "--data-urlencode {}",
+                    shell_quote(&format!("{}={}", name, value))
+                )
+            }))
+        }
+        // Unlike `--form`, values starting with `@` or `<` aren't read from files
+        Some(SnippetBody::FormData(fields)) => {
+            arguments.extend(fields.iter().map(|(name, value)| {
+                format!(
+                    "--form-string {}",
+                    shell_quote(&format!("{}={}", name, value))
+                )
+            }))
+        }
+    }
+
+    let mut snippet = String::new();
+    for note in &request.notes {
+        snippet.push_str(&format!("# {}\n", note));
+    }
+    snippet.push_str(&arguments.join(" \\\n  "));
+    snippet.push('\n');
+
+    snippet
+}
@@ -0,0 +1,128 @@
+use crate::snippet::{SnippetBody, SnippetRequest, quote};
+
+/// Renders the request as a Go program sending it with `net/http`.
+pub fn generate(request: &SnippetRequest) -> String {
+    let mut imports = vec!["fmt", "io", "net/http"];
+    let mut statements = request
+        .notes
+        .iter()
+        .map(|note| format!("// {}", note))
+        .collect::<Vec<_>>();
+    let mut headers = request
+        .headers
+        .iter()
+        .map(|(name, value)| format!("req.Header.Add({}, {})", quote(name), quote(value)))
+        .collect::<Vec<_>>();
+
+    let body = match &request.body {
+        None => "nil",
+        Some(SnippetBody::Raw(text)) => {
+            imports.push("strings");
+            statements.push(format!("body := strings.NewReader({})", string(text)));
+            statements.push(String::new());
+            "body"
+        }
+        Some(SnippetBody::Binary(path)) => {
+            imports.push("os");
+            statements.push(format!(
+                "body, err := os.Open({})",
+                quote(&path.display().to_string())
+            ));
+            statements.extend(check_error());
+            statements.push("defer body.Close()".to_string());
+            statements.push(String::new());
+            "body"
+        }
+        Some(SnippetBody::Urlencoded(fields)) => {
+            imports.extend(["net/url", "strings"]);
+            statements.push("form := url.Values{}".to_string());
+            statements.extend(
+                fields
+                    .iter()
+                    .map(|(name, value)| format!("form.Add({}, {})", quote(name), quote(value))),
+            );
+            statements.push("body := strings.NewReader(form.Encode())".to_string());
+            statements.push(String::new());
+            headers.push(
+                "req.Header.Set(\"Content-Type\", \"application/x-www-form-urlencoded\")"
+                    .to_string(),
+            );
+            "body"
+        }
+        Some(SnippetBody::FormData(fields)) => {
+            imports.extend(["bytes", "mime/multipart"]);
+            statements.push("body := &bytes.Buffer{}".to_string());
+            statements.push("writer := multipart.NewWriter(body)".to_string());
+            statements.extend(fields.iter().map(|(name, value)| {
+                format!("writer.WriteField({}, {})", quote(name), quote(value))
+            }));
+            statements.push("writer.Close()".to_string());
+            statements.push(String::new());
+            headers
+                .push("req.Header.Set(\"Content-Type\", writer.FormDataContentType())".to_string());
+            "body"
+        }
+    };
+
+    statements.push(format!(
+        "req, err := http.NewRequest({}, {}, {})",
+        quote(&request.method),
+        quote(&request.url),
+        body
+    ));
+    statements.extend(check_error());
+    statements.extend(headers);
+    if let Some((username, password)) = &request.basic_auth {
+        statements.push(format!(
+            "req.SetBasicAuth({}, {})",
+            quote(username),
+            quote(password)
+        ));
+    }
+    statements.push(String::new());
+    statements.push("res, err := http.DefaultClient.Do(req)".to_string());
+    statements.extend(check_error());
+    statements.push("defer res.Body.Close()".to_string());
+    statements.push(String::new());
+    statements.push("data, err := io.ReadAll(res.Body)".to_string());
+    statements.extend(check_error());
+    statements.push("fmt.Println(string(data))".to_string());
+
+    imports.sort();
+    let imports = imports
+        .iter()
+        .map(|import| format!("\t\"{}\"\n", import))
+        .collect::<String>();
+    // Only the first line of a statement is indented, the others belong to a raw string
+    let body = statements
+        .iter()
+        .map(|statement| match statement.is_empty() {
+            true => "\n".to_string(),
+            false => format!("\t{}\n", statement),
+        })
+        .collect::<String>();
+
+    format!(
+        "package main\n\nimport (\n{}\n)\n\nfunc main() {{\n{}}}\n",
+        imports.trim_end(),
+        body
+    )
+}
+
+fn check_error() -> [String; 3] {
+    [
+        "if err != nil {".to_string(),
+        "\tpanic(err)".to_string(),
+        "}".to_string(),
+    ]
+}
+
+/// Text spanning several lines is written as a raw string, to keep it readable,
+/// unless it holds a backtick that would end the string.
+fn string(text: &str) -> String {
+    if text.contains('\n') && !text.contains(['`', '\r']) {
+        format!("`{}`", text)
+    } else {
+        quote(text)
+    }
+}
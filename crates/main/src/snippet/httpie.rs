@@ -0,0 +1,53 @@
+use crate::snippet::{SnippetBody, SnippetRequest, shell_quote, shell_word};
+
+/// Renders the request as an HTTPie command, with one item per line.
+pub fn generate(request: &SnippetRequest) -> String {
+    let mut command = "http".to_string();
+    match &request.body {
+        Some(SnippetBody::Urlencoded(_)) => command.push_str(" --form"),
+        Some(SnippetBody::FormData(_)) => command.push_str(" --multipart"),
+        _ => {}
+    }
+    command.push_str(&format!(
+        " {} {}",
+        shell_word(&request.method),
+        shell_quote(&request.url)
+    ));
+
+    let mut arguments = vec![command];
+    arguments.extend(request.headers.iter().map(|(name, value)| {
+        // A header without a value is written as `Name;`, since `Name:` removes it
+        let header = match value.is_empty() {
+            true => format!("{};", name),
+            false => format!("{}:{}", name, value),
+        };
+        shell_quote(&header)
+    }));
+    if let Some((username, password)) = &request.basic_auth {
+        arguments.push(format!(
+            "-a {}",
+            shell_quote(&format!("{}:{}", username, password))
+        ));
+    }
+    match &request.body {
+        None => {}
+        Some(SnippetBody::Raw(text)) => arguments.push(format!("--raw {}", shell_quote(text))),
+        Some(SnippetBody::Binary(path)) => {
+            arguments.push(format!("< {}", shell_quote(&path.display().to_string())))
+        }
+        Some(SnippetBody::Urlencoded(fields) | SnippetBody::FormData(fields)) => arguments.extend(
+            fields
+                .iter()
+                .map(|(name, value)| shell_quote(&format!("{}={}", name, value))),
+        ),
+    }
+
+    let mut snippet = String::new();
+    for note in &request.notes {
+        snippet.push_str(&format!("# {}\n", note));
+    }
+    snippet.push_str(&arguments.join(" \\\n  "));
+    snippet.push('\n');
+
+    snippet
+}
@@ -0,0 +1,76 @@
+use crate::snippet::{SnippetBody, SnippetRequest, quote};
+
+/// Renders the request as a JavaScript module sending it with `fetch`.
+pub fn generate(request: &SnippetRequest) -> String {
+    let mut lines = Vec::new();
+    if let Some(SnippetBody::Binary(_)) = &request.body {
+        lines.push("import { readFile } from \"node:fs/promises\";".to_string());
+        lines.push(String::new());
+    }
+    lines.extend(request.notes.iter().map(|note| format!("// {}", note)));
+
+    let mut options = vec![format!("  method: {},", quote(&request.method))];
+    let mut headers = request
+        .headers
+        .iter()
+        .map(|(name, value)| format!("    {}: {},", quote(name), quote(value)))
+        .collect::<Vec<_>>();
+    if let Some((username, password)) = &request.basic_auth {
+        headers.push(format!(
+            "    \"Authorization\": \"Basic \" + btoa({}),",
+            quote(&format!("{}:{}", username, password))
+        ));
+    }
+    if !headers.is_empty() {
+        options.push(format!("  headers: {{\n{}\n  }},", headers.join("\n")));
+    }
+
+    let form = match &request.body {
+        Some(SnippetBody::Urlencoded(fields)) => Some(("URLSearchParams", fields)),
+        Some(SnippetBody::FormData(fields)) => Some(("FormData", fields)),
+        _ => None,
+    };
+    if let Some((class, fields)) = form {
+        lines.push(format!("const body = new {}();", class));
+        lines.extend(
+            fields
+                .iter()
+                .map(|(name, value)| format!("body.append({}, {});", quote(name), quote(value))),
+        );
+        lines.push(String::new());
+        options.push("  body,".to_string());
+    }
+    match &request.body {
+        Some(SnippetBody::Raw(text)) => options.push(format!("  body: {},", string(text))),
+        Some(SnippetBody::Binary(path)) => options.push(format!(
+            "  body: await readFile({}),",
+            quote(&path.display().to_string())
+        )),
+        _ => {}
+    }
+
+    lines.push(format!(
+        "const response = await fetch({}, {{\n{}\n}});",
+        quote(&request.url),
+        options.join("\n")
+    ));
+    lines.push(String::new());
+    lines.push("console.log(await response.text());".to_string());
+    lines.push(String::new());
+
+    lines.join("\n")
+}
+
+/// Text spanning several lines is written as a template literal, to keep it readable.
+fn string(text: &str) -> String {
+    if !text.contains('\n') {
+        return quote(text);
+    }
+
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${");
+
+    format!("`{}`", escaped)
+}
@@ -0,0 +1,88 @@
+use crate::snippet::{SnippetBody, SnippetRequest, quote};
+
+/// Renders the request as a Python script sending it with `requests`.
+pub fn generate(request: &SnippetRequest) -> String {
+    let mut lines = vec!["import requests".to_string(), String::new()];
+    lines.extend(request.notes.iter().map(|note| format!("# {}", note)));
+    lines.push(format!("url = {}", quote(&request.url)));
+
+    let mut arguments = vec![quote(&request.method), "url".to_string()];
+    if !request.headers.is_empty() {
+        lines.push(dict("headers", &request.headers));
+        arguments.push("headers=headers".to_string());
+    }
+    if let Some((username, password)) = &request.basic_auth {
+        lines.push(format!("auth = ({}, {})", quote(username), quote(password)));
+        arguments.push("auth=auth".to_string());
+    }
+    match &request.body {
+        None => {}
+        Some(SnippetBody::Raw(text)) => {
+            lines.push(format!("data = {}", string(text)));
+            arguments.push("data=data".to_string());
+        }
+        Some(SnippetBody::Binary(path)) => {
+            lines.push(format!(
+                "data = open({}, \"rb\")",
+                quote(&path.display().to_string())
+            ));
+            arguments.push("data=data".to_string());
+        }
+        Some(SnippetBody::Urlencoded(fields)) => {
+            lines.push(dict("data", fields));
+            arguments.push("data=data".to_string());
+        }
+        // Fields without a file name are sent as plain values of the multipart body
+        Some(SnippetBody::FormData(fields)) => {
+            let fields = fields
+                .iter()
+                .map(|(name, value)| format!("    ({}, (None, {})),", quote(name), quote(value)))
+                .collect::<Vec<_>>();
+            lines.push(format!("files = [\n{}\n]", fields.join("\n")));
+            arguments.push("files=files".to_string());
+        }
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "response = requests.request({})",
+        arguments.join(", ")
+    ));
+    lines.push("print(response.text)".to_string());
+    lines.push(String::new());
+
+    lines.join("\n")
+}
+
+/// Pairs are written as a dict, or as a list of tuples when a name is repeated.
+fn dict(variable: &str, pairs: &[(String, String)]) -> String {
+    let repeated = pairs
+        .iter()
+        .enumerate()
+        .any(|(index, (name, _))| pairs[..index].iter().any(|(other, _)| other == name));
+    let (open, close) = if repeated { ('[', ']') } else { ('{', '}') };
+    let entries = pairs
+        .iter()
+        .map(|(name, value)| match repeated {
+            true => format!("    ({}, {}),", quote(name), quote(value)),
+            false => format!("    {}: {},", quote(name), quote(value)),
+        })
+        .collect::<Vec<_>>();
+
+    format!("{} = {}\n{}\n{}", variable, open, entries.join("\n"), close)
+}
+
+/// Text spanning several lines is written as a triple-quoted string, to keep it readable.
+fn string(text: &str) -> String {
+    if !text.contains('\n') {
+        return quote(text);
+    }
+
+    let mut escaped = text.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+    if escaped.ends_with('"') {
+        escaped.pop();
+        escaped.push_str("\\\"");
+    }
+
+    format!("\"\"\"{}\"\"\"", escaped)
+}
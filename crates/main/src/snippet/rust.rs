@@ -0,0 +1,101 @@
+use crate::snippet::{SnippetBody, SnippetRequest};
+
+/// Renders the request as a Rust program sending it with `reqwest`.
+pub fn generate(request: &SnippetRequest) -> String {
+    let mut lines = request
+        .notes
+        .iter()
+        .map(|note| format!("    // {}", note))
+        .collect::<Vec<_>>();
+    lines.push("    let client = reqwest::Client::new();".to_string());
+    lines.push("    let response = client".to_string());
+
+    let url = string(&request.url);
+    lines.push(match request.method.as_str() {
+        "GET" | "POST" | "PUT" | "PATCH" | "DELETE" | "HEAD" => {
+            format!("        .{}({})", request.method.to_lowercase(), url)
+        }
+        "CONNECT" | "OPTIONS" | "TRACE" => {
+            format!(
+                "        .request(reqwest::Method::{}, {})",
+                request.method, url
+            )
+        }
+        method => format!(
+            "        .request(reqwest::Method::from_bytes(b{})?, {})",
+            string(method),
+            url
+        ),
+    });
+    lines.extend(
+        request
+            .headers
+            .iter()
+            .map(|(name, value)| format!("        .header({}, {})", string(name), string(value))),
+    );
+    if let Some((username, password)) = &request.basic_auth {
+        lines.push(format!(
+            "        .basic_auth({}, Some({}))",
+            string(username),
+            string(password)
+        ));
+    }
+    match &request.body {
+        None => {}
+        Some(SnippetBody::Raw(text)) if text.contains('\n') => lines.push(format!(
+            "        .body(\n            {},\n        )",
+            raw_string(text)
+        )),
+        Some(SnippetBody::Raw(text)) => lines.push(format!("        .body({})", string(text))),
+        Some(SnippetBody::Binary(path)) => lines.push(format!(
+            "        .body(std::fs::read({})?)",
+            string(&path.display().to_string())
+        )),
+        Some(SnippetBody::Urlencoded(fields)) => {
+            let fields = fields
+                .iter()
+                .map(|(name, value)| format!("({}, {})", string(name), string(value)))
+                .collect::<Vec<_>>();
+            lines.push(format!("        .form(&[{}])", fields.join(", ")));
+        }
+        Some(SnippetBody::FormData(fields)) => {
+            let mut form = vec!["        .multipart(".to_string()];
+            form.push("            reqwest::multipart::Form::new()".to_string());
+            form.extend(fields.iter().map(|(name, value)| {
+                format!("                .text({}, {})", string(name), string(value))
+            }));
+            let last = form.len() - 1;
+            form[last].push(',');
+            form.push("        )".to_string());
+            lines.push(form.join("\n"));
+        }
+    }
+    lines.push("        .send()".to_string());
+    lines.push("        .await?;".to_string());
+
+    format!(
+        "#[tokio::main]\n\
+         async fn main() -> Result<(), Box<dyn std::error::Error>> {{\n\
+         {}\n\
+         \n    \
+         println!(\"{{}}\", response.text().await?);\n    \
+         Ok(())\n\
+         }}\n",
+        lines.join("\n")
+    )
+}
+
+fn string(text: &str) -> String {
+    format!("{:?}", text)
+}
+
+/// Text spanning several lines is written as a raw string, to keep it readable,
+/// with enough `#` that no quote of the text ends it.
+fn raw_string(text: &str) -> String {
+    let mut hashes = "#".to_string();
+    while text.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+
+    format!("r{}\"{}\"{}", hashes, text, hashes)
+}
@@ -0,0 +1,487 @@
+use chrono::Utc;
+use joinerror::{Error, OptionExt};
+use moss_project::models::{operations::DescribeResourceOutput, types::sse::SseInfo};
+use reqwest::{
+    Client, Request, StatusCode,
+    header::{ACCEPT, CACHE_CONTROL, CONTENT_TYPE, HeaderMap, HeaderValue},
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_core::context::{self, AnyAsyncContext};
+use sapic_ipc::contracts::main::{
+    resource::ResponseHeader,
+    sse::{SseEvent, SseEventInfo},
+};
+use std::{mem, time::Duration};
+
+use crate::resource::auth::{self, DigestAuth};
+
+/// Delay before reconnecting until the server sets one, as browsers do.
+pub const DEFAULT_RETRY_MS: u64 = 3000;
+
+/// Number of the most recent events recorded from a stream.
+pub const MAX_RECORDED_EVENTS: usize = 1000;
+
+const LAST_EVENT_ID: &str = "last-event-id";
+
+const EVENT_STREAM: &str = "text/event-stream";
+
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Returns the SSE settings of the endpoint, failing when it isn't an HTTP endpoint or has
+/// none.
+pub fn sse_settings(desc: &DescribeResourceOutput) -> joinerror::Result<&SseInfo> {
+    let is_http = desc
+        .protocol
+        .as_ref()
+        .is_some_and(ResourceProtocol::is_http);
+    if desc.class != ResourceClass::Endpoint || !is_http {
+        return Err(Error::new::<()>(format!(
+            "resource `{}` is not an http endpoint",
+            desc.name
+        )));
+    }
+
+    desc.sse
+        .as_ref()
+        .ok_or_join_err_with::<()>(|| format!("endpoint `{}` has no sse settings", desc.name))
+}
+
+/// An event dispatched by the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedEvent {
+    pub event: String,
+    pub data: String,
+    pub id: Option<String>,
+}
+
+/// Incremental parser of `text/event-stream` bodies, fed with the chunks of the body as
+/// they arrive, following the WHATWG specification.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    /// Bytes of the line being received.
+    buffer: Vec<u8>,
+    /// Whether the last chunk ended with a CR, whose LF may start the next one.
+    pending_cr: bool,
+    /// Whether the start of the stream, where a BOM may be, has been received.
+    started: bool,
+    event: String,
+    data: String,
+    id: String,
+    /// Id of the last event dispatched, which is sent back when reconnecting.
+    last_event_id: String,
+    retry: Option<u64>,
+}
+
+impl SseParser {
+    pub fn last_event_id(&self) -> Option<&str> {
+        Some(self.last_event_id.as_str()).filter(|id| !id.is_empty())
+    }
+
+    /// Delay before reconnecting set by the server, in milliseconds.
+    pub fn retry(&self) -> Option<u64> {
+        self.retry
+    }
+
+    /// Discards the event being received, to parse the stream of a new connection. The
+    /// last event id and the reconnection delay are kept.
+    pub fn restart(&mut self) {
+        self.buffer.clear();
+        self.pending_cr = false;
+        self.started = false;
+        self.event.clear();
+        self.data.clear();
+        self.id = self.last_event_id.clone();
+    }
+
+    /// Parses the chunk, returning the events it completes.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<ParsedEvent> {
+        let mut events = Vec::new();
+        let mut chunk = chunk;
+        if chunk.is_empty() {
+            return events;
+        }
+        if mem::take(&mut self.pending_cr) && chunk[0] == b'\n' {
+            chunk = &chunk[1..];
+        }
+
+        self.buffer.extend_from_slice(chunk);
+        if !self.started {
+            if self.buffer.len() < BOM.len() && BOM.starts_with(&self.buffer) {
+                return events;
+            }
+            if self.buffer.starts_with(BOM) {
+                self.buffer.drain(..BOM.len());
+            }
+            self.started = true;
+        }
+
+        let buffer = mem::take(&mut self.buffer);
+        let mut start = 0;
+        let mut i = 0;
+        while i < buffer.len() {
+            let next = match buffer[i] {
+                b'\n' => i + 1,
+                b'\r' if i + 1 == buffer.len() => {
+                    self.pending_cr = true;
+                    i + 1
+                }
+                b'\r' if buffer[i + 1] == b'\n' => i + 2,
+                b'\r' => i + 1,
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+
+            let line = String::from_utf8_lossy(&buffer[start..i]);
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+            start = next;
+            i = next;
+        }
+        self.buffer = buffer[start..].to_vec();
+
+        events
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<ParsedEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = value.to_string(),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => self.id = value.to_string(),
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                self.retry = value.parse().ok().or(self.retry);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<ParsedEvent> {
+        self.last_event_id = self.id.clone();
+
+        let event = mem::take(&mut self.event);
+        let mut data = mem::take(&mut self.data);
+        if data.is_empty() {
+            return None;
+        }
+        data.pop();
+
+        Some(ParsedEvent {
+            event: if event.is_empty() {
+                "message".to_string()
+            } else {
+                event
+            },
+            data,
+            id: self.last_event_id().map(str::to_string),
+        })
+    }
+}
+
+/// How a stream ended.
+#[derive(Debug, Default)]
+pub struct StreamOutcome {
+    pub received: u64,
+    pub reconnects: u64,
+    pub last_event_id: Option<String>,
+}
+
+#[derive(Default)]
+struct StreamState {
+    parser: SseParser,
+    outcome: StreamOutcome,
+}
+
+/// Sends the request and reads its response as a stream of server-sent events, reporting
+/// the events as they are dispatched.
+///
+/// Once the stream ends or the connection is lost, reconnects when the settings allow it,
+/// resuming from the last event received through the `Last-Event-ID` header. A connection
+/// that yields no event counts as a failed attempt to reconnect. The server stops the
+/// stream by responding with `204 No Content`.
+///
+/// Canceling the context, or exceeding its deadline, is how the stream is stopped, so it
+/// isn't an error.
+pub async fn stream<F>(
+    ctx: &dyn AnyAsyncContext,
+    client: &Client,
+    mut request: Request,
+    digest: Option<&DigestAuth>,
+    settings: &SseInfo,
+    on_event: F,
+) -> joinerror::Result<StreamOutcome>
+where
+    F: Fn(SseEvent) -> joinerror::Result<()>,
+{
+    let headers = request.headers_mut();
+    headers
+        .entry(ACCEPT)
+        .or_insert(HeaderValue::from_static(EVENT_STREAM));
+    headers
+        .entry(CACHE_CONTROL)
+        .or_insert(HeaderValue::from_static("no-cache"));
+
+    let mut state = StreamState::default();
+    match context::abortable(
+        ctx,
+        read_stream(client, &request, digest, settings, &mut state, &on_event),
+    )
+    .await
+    {
+        Ok(()) | Err(Ok(_)) => {}
+        Err(Err(e)) => return Err(e),
+    }
+
+    let mut outcome = state.outcome;
+    outcome.last_event_id = state.parser.last_event_id().map(str::to_string);
+    Ok(outcome)
+}
+
+async fn read_stream<F>(
+    client: &Client,
+    request: &Request,
+    digest: Option<&DigestAuth>,
+    settings: &SseInfo,
+    state: &mut StreamState,
+    on_event: &F,
+) -> joinerror::Result<()>
+where
+    F: Fn(SseEvent) -> joinerror::Result<()>,
+{
+    let mut attempt = 0;
+    loop {
+        let mut request = request
+            .try_clone()
+            .ok_or_join_err::<()>("request body can't be sent again")?;
+        if let Some(value) = state
+            .parser
+            .last_event_id()
+            .and_then(|id| HeaderValue::from_str(id).ok())
+        {
+            request.headers_mut().insert(LAST_EVENT_ID, value);
+        }
+
+        match auth::send_authorized(client, request, digest).await {
+            Ok(mut response) => {
+                let status = response.status();
+                if status == StatusCode::NO_CONTENT {
+                    return Ok(());
+                }
+                if !status.is_success() {
+                    return Err(Error::new::<()>(format!(
+                        "server responded with status {status}"
+                    )));
+                }
+                if !is_event_stream(response.headers()) {
+                    return Err(Error::new::<()>("response is not an event stream"));
+                }
+
+                let headers = response
+                    .headers()
+                    .iter()
+                    .map(|(name, value)| ResponseHeader {
+                        name: name.to_string(),
+                        value: String::from_utf8_lossy(value.as_bytes()).to_string(),
+                    })
+                    .collect();
+                on_event(SseEvent::Connected {
+                    status: status.as_u16(),
+                    headers,
+                })?;
+
+                state.parser.restart();
+                loop {
+                    match response.chunk().await {
+                        Ok(Some(chunk)) => {
+                            for parsed in state.parser.feed(&chunk) {
+                                attempt = 0;
+                                state.outcome.received += 1;
+                                on_event(SseEvent::Event {
+                                    event: SseEventInfo {
+                                        seq: state.outcome.received,
+                                        event: parsed.event,
+                                        data: parsed.data,
+                                        id: parsed.id,
+                                        timestamp: Utc::now().to_rfc3339(),
+                                    },
+                                })?;
+                            }
+                        }
+                        Ok(None) => break,
+                        Err(_) if settings.reconnect => break,
+                        Err(e) => return Err(Error::new::<()>(format!("connection lost: {e}"))),
+                    }
+                }
+            }
+            // Only the first connection has to succeed, reconnecting keeps trying
+            Err(_) if state.outcome.reconnects > 0 => {}
+            Err(e) => return Err(e),
+        }
+
+        if !settings.reconnect || settings.max_reconnects.is_some_and(|max| attempt >= max) {
+            return Ok(());
+        }
+
+        attempt += 1;
+        state.outcome.reconnects += 1;
+        let delay_ms = state.parser.retry().unwrap_or(
+            settings
+                .retry_ms
+                .map_or(DEFAULT_RETRY_MS, |retry_ms| retry_ms as u64),
+        );
+        on_event(SseEvent::Reconnecting {
+            attempt,
+            delay_ms,
+            last_event_id: state.parser.last_event_id().map(str::to_string),
+        })?;
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    }
+}
+
+fn is_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case(EVENT_STREAM))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event: &str, data: &str, id: Option<&str>) -> ParsedEvent {
+        ParsedEvent {
+            event: event.to_string(),
+            data: data.to_string(),
+            id: id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let mut parser = SseParser::default();
+        let events = parser.feed(
+            b": comment\n\
+              data: first\n\n\
+              event: update\n\
+              id: 1\n\
+              data: second\n\
+              data:  line\n\
+              data\n\n",
+        );
+        assert_eq!(
+            events,
+            vec![
+                event("message", "first", None),
+                event("update", "second\n line\n", Some("1")),
+            ]
+        );
+        assert_eq!(parser.last_event_id(), Some("1"));
+    }
+
+    #[test]
+    fn test_parse_split_chunks() {
+        let mut parser = SseParser::default();
+        let stream = b"\xEF\xBB\xBFdata: h\xC3\xA9llo\r\n\r\nevent: a\rdata: b\r\rdata: c\n\n";
+
+        // Every split, including ones within the BOM, a CRLF and a character
+        for split in 0..stream.len() {
+            let mut parser = SseParser::default();
+            let mut events = parser.feed(&stream[..split]);
+            events.extend(parser.feed(&stream[split..]));
+            assert_eq!(
+                events,
+                vec![
+                    event("message", "héllo", None),
+                    event("a", "b", None),
+                    event("message", "c", None),
+                ],
+                "split at {split}"
+            );
+        }
+
+        // An event is only dispatched once its blank line is received
+        assert_eq!(parser.feed(b"data: d\n"), vec![]);
+        assert_eq!(parser.feed(b"\n"), vec![event("message", "d", None)]);
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let mut parser = SseParser::default();
+
+        // Events without data aren't dispatched, but their id is kept
+        assert_eq!(parser.feed(b"event: empty\nid: 7\n\n"), vec![]);
+        assert_eq!(parser.last_event_id(), Some("7"));
+        assert_eq!(
+            parser.feed(b"data: a\n\n"),
+            vec![event("message", "a", Some("7"))]
+        );
+
+        // Ids with NUL are ignored, empty ones reset the last event id
+        assert_eq!(
+            parser.feed(b"id: 8\0\ndata: b\n\n"),
+            vec![event("message", "b", Some("7"))]
+        );
+        assert_eq!(
+            parser.feed(b"id\ndata: c\n\n"),
+            vec![event("message", "c", None)]
+        );
+
+        // Retries that aren't only digits are ignored
+        parser.feed(b"retry: 1500\n\nretry: 1s\n\nretry: -1\n\n");
+        assert_eq!(parser.retry(), Some(1500));
+
+        // Unknown fields are ignored
+        assert_eq!(
+            parser.feed(b"foo: bar\ndata:no space\n\n"),
+            vec![event("message", "no space", None)]
+        );
+    }
+
+    #[test]
+    fn test_parse_restart() {
+        let mut parser = SseParser::default();
+        parser.feed(b"id: 1\ndata: a\n\nretry: 10\nid: 2\ndata: partial\n");
+
+        parser.restart();
+        assert_eq!(parser.last_event_id(), Some("1"));
+        assert_eq!(parser.retry(), Some(10));
+        assert_eq!(
+            parser.feed(b"\xEF\xBB\xBFdata: b\n\n"),
+            vec![event("message", "b", Some("1"))]
+        );
+    }
+
+    #[test]
+    fn test_is_event_stream() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_event_stream(&headers));
+
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("Text/Event-Stream; charset=utf-8"),
+        );
+        assert!(is_event_stream(&headers));
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        assert!(!is_event_stream(&headers));
+    }
+}
@@ -0,0 +1,503 @@
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use joinerror::{Error, OptionExt, ResultExt};
+use moss_fs::FileSystem;
+use moss_project::models::types::websocket::WebSocketMessagePayload;
+use reqwest::Url;
+use sapic_base::{project::types::primitives::ProjectId, resource::types::primitives::ResourceId};
+use sapic_core::context::{self, AnyAsyncContext, CancelToken, ContextResultExt};
+use sapic_ipc::contracts::main::{
+    resource::ResolveResourceOutput,
+    websocket::{
+        ConnectWebSocketOutput, WebSocketEvent, WebSocketFrameDirection, WebSocketFrameInfo,
+        WebSocketFrameKind,
+    },
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    io::Read,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    net::TcpStream,
+    sync::{RwLock, mpsc, oneshot, watch},
+};
+use tokio_tungstenite::{
+    MaybeTlsStream, WebSocketStream,
+    tungstenite::{
+        self, Message,
+        client::IntoClientRequest,
+        handshake::client::Request,
+        http::{HeaderName, HeaderValue, header::SEC_WEBSOCKET_PROTOCOL},
+        protocol::{CloseFrame, frame::coding::CloseCode},
+    },
+};
+
+use crate::resource::EnvironmentVariables;
+
+/// Frames kept per session, the oldest ones being dropped first.
+const MAX_FRAMES: usize = 1000;
+
+/// Time given to the server to acknowledge a close frame before the connection is dropped.
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// WebSocket sessions connected from the window. Closed sessions are kept, so that their
+/// frames can still be listed, until their endpoint is connected again.
+#[derive(Default)]
+pub struct WebSocketSessions {
+    inner: RwLock<Sessions>,
+}
+
+#[derive(Default)]
+struct Sessions {
+    next_id: u64,
+    sessions: HashMap<String, Arc<WebSocketSession>>,
+}
+
+impl WebSocketSessions {
+    pub async fn session(&self, id: &str) -> joinerror::Result<Arc<WebSocketSession>> {
+        self.inner
+            .read()
+            .await
+            .sessions
+            .get(id)
+            .cloned()
+            .ok_or_join_err_with::<()>(|| format!("websocket session `{id}` not found"))
+    }
+
+    async fn insert(
+        &self,
+        project_id: ProjectId,
+        resource_id: ResourceId,
+        sender: mpsc::UnboundedSender<Outgoing>,
+        closed: watch::Receiver<bool>,
+    ) -> Arc<WebSocketSession> {
+        let mut sessions = self.inner.write().await;
+        sessions
+            .sessions
+            .retain(|_, session| session.resource_id != resource_id || session.is_open());
+        sessions.next_id += 1;
+
+        let session = Arc::new(WebSocketSession {
+            id: sessions.next_id.to_string(),
+            project_id,
+            resource_id,
+            commands: sender,
+            log: Mutex::new(FrameLog::default()),
+            cancel: CancelToken::root(),
+            closed,
+        });
+        sessions
+            .sessions
+            .insert(session.id.clone(), session.clone());
+
+        session
+    }
+}
+
+struct Outgoing {
+    message: Message,
+    reply: oneshot::Sender<joinerror::Result<WebSocketFrameInfo>>,
+}
+
+#[derive(Default)]
+struct FrameLog {
+    next_id: u64,
+    frames: VecDeque<WebSocketFrameInfo>,
+}
+
+pub struct WebSocketSession {
+    pub id: String,
+    pub project_id: ProjectId,
+    pub resource_id: ResourceId,
+    commands: mpsc::UnboundedSender<Outgoing>,
+    log: Mutex<FrameLog>,
+    /// Drops the connection without waiting for the close handshake.
+    cancel: Arc<CancelToken>,
+    closed: watch::Receiver<bool>,
+}
+
+impl WebSocketSession {
+    pub fn is_open(&self) -> bool {
+        !*self.closed.borrow()
+    }
+
+    /// The most recent frames of the session, from the oldest to the most recent.
+    pub fn frames(&self) -> Vec<WebSocketFrameInfo> {
+        let log = self.log.lock().unwrap_or_else(|e| e.into_inner());
+        log.frames.iter().cloned().collect()
+    }
+
+    /// Sends the message, returning its frame once it's written to the connection.
+    pub async fn send(&self, message: Message) -> joinerror::Result<WebSocketFrameInfo> {
+        if !self.is_open() {
+            return Err(self.closed_error());
+        }
+
+        let (reply, receiver) = oneshot::channel();
+        self.commands
+            .send(Outgoing { message, reply })
+            .map_err(|_| self.closed_error())?;
+
+        receiver.await.map_err(|_| self.closed_error())?
+    }
+
+    /// Starts the close handshake and waits for the server to acknowledge it. The connection
+    /// is dropped if it doesn't before the context is done or `CLOSE_TIMEOUT` is elapsed.
+    pub async fn close(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        code: Option<u16>,
+        reason: Option<String>,
+    ) -> joinerror::Result<()> {
+        let code = CloseCode::from(code.unwrap_or(1000));
+        if !code.is_allowed() {
+            return Err(Error::new::<()>(format!(
+                "close code {code} cannot be sent"
+            )));
+        }
+        if !self.is_open() {
+            return Ok(());
+        }
+
+        let frame = CloseFrame {
+            code,
+            reason: reason.unwrap_or_default().into(),
+        };
+        if self.send(Message::Close(Some(frame))).await.is_err() {
+            self.cancel.cancel();
+            return Ok(());
+        }
+
+        let mut closed = self.closed.clone();
+        let cancellation = ctx.cancellation();
+        tokio::select! {
+            _ = closed.wait_for(|closed| *closed) => {}
+            _ = cancellation.wait() => self.cancel.cancel(),
+            _ = tokio::time::sleep(CLOSE_TIMEOUT) => self.cancel.cancel(),
+        }
+
+        Ok(())
+    }
+
+    fn record(
+        &self,
+        direction: WebSocketFrameDirection,
+        message: &Message,
+    ) -> Option<WebSocketFrameInfo> {
+        // Raw frames are never read, nor sent
+        if let Message::Frame(_) = message {
+            return None;
+        }
+
+        let mut log = self.log.lock().unwrap_or_else(|e| e.into_inner());
+        log.next_id += 1;
+
+        let mut frame = WebSocketFrameInfo {
+            id: log.next_id,
+            direction,
+            kind: WebSocketFrameKind::Text,
+            timestamp: Utc::now().to_rfc3339(),
+            size: message.len() as u64,
+            text: None,
+            data: None,
+            close_code: None,
+        };
+        match message {
+            Message::Text(text) => frame.text = Some(text.to_string()),
+            Message::Binary(data) => {
+                frame.kind = WebSocketFrameKind::Binary;
+                frame.data = Some(data.to_vec());
+            }
+            Message::Ping(data) => {
+                frame.kind = WebSocketFrameKind::Ping;
+                frame.data = Some(data.to_vec());
+            }
+            Message::Pong(data) => {
+                frame.kind = WebSocketFrameKind::Pong;
+                frame.data = Some(data.to_vec());
+            }
+            Message::Close(close) => {
+                frame.kind = WebSocketFrameKind::Close;
+                frame.text = close.as_ref().map(|close| close.reason.to_string());
+                frame.close_code = close.as_ref().map(|close| close.code.into());
+            }
+            Message::Frame(_) => {}
+        }
+
+        if log.frames.len() == MAX_FRAMES {
+            log.frames.pop_front();
+        }
+        log.frames.push_back(frame.clone());
+
+        Some(frame)
+    }
+
+    fn closed_error(&self) -> Error {
+        Error::new::<()>(format!("websocket session `{}` is closed", self.id))
+    }
+}
+
+/// Builds the handshake request of a resolved WebSocket endpoint, offering the subprotocols
+/// in order of preference. Http urls are upgraded to their WebSocket counterpart.
+pub fn handshake_request(
+    resolved: &ResolveResourceOutput,
+    subprotocols: &[String],
+) -> joinerror::Result<Request> {
+    let url = resolved.url.trim();
+    if url.is_empty() {
+        return Err(Error::new::<()>("resource has no url"));
+    }
+
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("ws://{url}")
+    };
+    let mut url = Url::parse(&url)
+        .map_err(|e| Error::new::<()>(format!("invalid websocket url `{url}`: {e}")))?;
+
+    let scheme = match url.scheme() {
+        "ws" | "http" => "ws",
+        "wss" | "https" => "wss",
+        other => {
+            return Err(Error::new::<()>(format!(
+                "unsupported websocket url scheme `{other}`"
+            )));
+        }
+    };
+    if url.scheme() != scheme {
+        url.set_scheme(scheme)
+            .map_err(|_| Error::new::<()>(format!("invalid websocket url `{url}`")))?;
+    }
+
+    if !resolved.query_params.is_empty() {
+        let mut query = url.query_pairs_mut();
+        for param in &resolved.query_params {
+            query.append_pair(&param.name, &param.value);
+        }
+    }
+
+    let mut request = url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| Error::new::<()>(format!("invalid websocket url `{url}`: {e}")))?;
+    let headers = request.headers_mut();
+    for header in &resolved.headers {
+        let name = HeaderName::from_bytes(header.name.as_bytes())
+            .map_err(|e| Error::new::<()>(format!("invalid header `{}`: {e}", header.name)))?;
+        let value = HeaderValue::from_str(&header.value)
+            .map_err(|e| Error::new::<()>(format!("invalid header `{}`: {e}", header.name)))?;
+        headers.append(name, value);
+    }
+    if !subprotocols.is_empty() {
+        let value = HeaderValue::from_str(&subprotocols.join(", "))
+            .map_err(|e| Error::new::<()>(format!("invalid subprotocols: {e}")))?;
+        headers.insert(SEC_WEBSOCKET_PROTOCOL, value);
+    }
+
+    Ok(request)
+}
+
+/// Builds the message of a payload, substituting the variables of text and json payloads.
+pub async fn build_message(
+    ctx: &dyn AnyAsyncContext,
+    fs: &dyn FileSystem,
+    variables: &EnvironmentVariables,
+    payload: &WebSocketMessagePayload,
+) -> joinerror::Result<Message> {
+    match payload {
+        WebSocketMessagePayload::Text(text) => Ok(Message::text(variables.render(text))),
+        WebSocketMessagePayload::Json(json) => {
+            let json =
+                serde_json::to_string(json).join_err::<()>("failed to serialize json message")?;
+            Ok(Message::text(variables.render(&json)))
+        }
+        WebSocketMessagePayload::Binary(path) => {
+            let mut rdr = fs.open_file(ctx, path).await?;
+            let mut bytes = Vec::new();
+            rdr.read_to_end(&mut bytes).join_err_with::<()>(|| {
+                format!("failed to read binary message `{}`", path.display())
+            })?;
+
+            Ok(Message::binary(bytes))
+        }
+    }
+}
+
+/// Opens a session with the server the handshake request is sent to, which stays open until
+/// either side closes it, independently of the context it was connected with.
+///
+/// Reports to `on_event` every frame sent or received over the session, then its closure.
+/// The handshake stops as soon as the context is canceled or its deadline is exceeded.
+pub async fn connect<F>(
+    ctx: &dyn AnyAsyncContext,
+    sessions: &WebSocketSessions,
+    project_id: ProjectId,
+    resource_id: ResourceId,
+    request: Request,
+    on_event: F,
+) -> joinerror::Result<ConnectWebSocketOutput>
+where
+    F: Fn(WebSocketEvent) -> joinerror::Result<()> + Send + Sync + 'static,
+{
+    let url = request.uri().to_string();
+    let (stream, response) = context::abortable(ctx, async {
+        tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to connect to `{url}`: {e}")))
+    })
+    .await
+    .join_err_bare()?;
+
+    let protocol = response
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let (sender, commands) = mpsc::unbounded_channel();
+    let (closed_tx, closed) = watch::channel(false);
+    let session = sessions
+        .insert(project_id, resource_id, sender, closed)
+        .await;
+
+    let output = ConnectWebSocketOutput {
+        session_id: session.id.clone(),
+        protocol,
+    };
+    tokio::spawn(async move {
+        let event = run_session(&session, stream, commands, &on_event).await;
+        closed_tx.send_replace(true);
+
+        if let Err(e) = on_event(event) {
+            tracing::warn!("failed to report closure of websocket session: {}", e);
+        }
+    });
+
+    Ok(output)
+}
+
+/// Relays the messages sent through the session and the frames received, until the connection
+/// is closed, lost or dropped through the cancel token of the session.
+async fn run_session<F>(
+    session: &WebSocketSession,
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    mut commands: mpsc::UnboundedReceiver<Outgoing>,
+    on_event: &F,
+) -> WebSocketEvent
+where
+    F: Fn(WebSocketEvent) -> joinerror::Result<()>,
+{
+    let report = |frame: &WebSocketFrameInfo| {
+        if let Err(e) = on_event(WebSocketEvent::Frame {
+            frame: frame.clone(),
+        }) {
+            tracing::warn!("failed to report websocket frame: {}", e);
+        }
+    };
+
+    let (mut sink, mut stream) = stream.split();
+    let mut close_frame = None;
+    let error = loop {
+        tokio::select! {
+            _ = session.cancel.cancelled() => break None,
+            Some(Outgoing { message, reply }) = commands.recv() => {
+                let result = match sink.send(message.clone()).await {
+                    Ok(()) => session
+                        .record(WebSocketFrameDirection::Sent, &message)
+                        .ok_or_join_err::<()>("raw frames cannot be sent"),
+                    Err(e) => Err(Error::new::<()>(format!(
+                        "failed to send websocket message: {e}"
+                    ))),
+                };
+                if let Ok(frame) = &result {
+                    report(frame);
+                }
+                let _ = reply.send(result);
+            }
+            message = stream.next() => match message {
+                Some(Ok(message)) => {
+                    if let Message::Close(frame) = &message {
+                        close_frame = frame.clone();
+                    }
+                    let frame = session.record(WebSocketFrameDirection::Received, &message);
+                    if let Some(frame) = &frame {
+                        report(frame);
+                    }
+                }
+                Some(Err(tungstenite::Error::ConnectionClosed)) | None => break None,
+                Some(Err(e)) => break Some(e.to_string()),
+            },
+        }
+    };
+
+    WebSocketEvent::Closed {
+        code: close_frame.as_ref().map(|frame| frame.code.into()),
+        reason: close_frame.map(|frame| frame.reason.to_string()),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_base::resource::types::primitives::ResourceProtocol;
+    use sapic_ipc::contracts::main::resource::ResolvedParam;
+
+    use super::*;
+
+    fn param(name: &str, value: &str) -> ResolvedParam {
+        ResolvedParam {
+            id: name.to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn resolved(url: &str) -> ResolveResourceOutput {
+        ResolveResourceOutput {
+            protocol: Some(ResourceProtocol::WebSocket),
+            url: url.to_string(),
+            headers: vec![param("Authorization", "Bearer token")],
+            path_params: vec![],
+            query_params: vec![param("room", "general chat")],
+            body: None,
+            substitutions: vec![],
+            unresolved: vec![],
+        }
+    }
+
+    #[test]
+    fn test_handshake_request() {
+        let request = handshake_request(
+            &resolved("example.com/socket?v=1"),
+            &["chat".to_string(), "superchat".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            request.uri().to_string(),
+            "ws://example.com/socket?v=1&room=general+chat"
+        );
+        assert_eq!(request.headers()["authorization"], "Bearer token");
+        assert_eq!(request.headers()[SEC_WEBSOCKET_PROTOCOL], "chat, superchat");
+    }
+
+    #[test]
+    fn test_handshake_request_upgrades_http_urls() {
+        let request = handshake_request(&resolved("https://example.com/socket"), &[]).unwrap();
+
+        assert_eq!(
+            request.uri().to_string(),
+            "wss://example.com/socket?room=general+chat"
+        );
+        assert!(!request.headers().contains_key(SEC_WEBSOCKET_PROTOCOL));
+    }
+
+    #[test]
+    fn test_handshake_request_invalid_scheme() {
+        assert!(handshake_request(&resolved("ftp://example.com/socket"), &[]).is_err());
+        assert!(handshake_request(&resolved("  "), &[]).is_err());
+    }
+}
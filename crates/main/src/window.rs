@@ -1,8 +1,19 @@
 pub mod environment;
 pub mod environment_ops;
+pub mod export;
+pub mod graphql;
+pub mod grpc;
+pub mod import;
+pub mod mock;
 pub mod operations;
 pub mod project;
+pub mod proxy;
+pub mod queue;
 pub mod resource;
+pub mod runner;
+pub mod snippet;
+pub mod sse;
+pub mod websocket;
 pub mod workspace;
 pub mod workspace_ops;
 
@@ -12,6 +23,7 @@ use derive_more::Deref;
 use moss_app_delegate::AppDelegate;
 use moss_applib::{AppRuntime, TauriResultExt};
 use sapic_core::context::Canceller;
+use sapic_platform::oauth::OAuth2Client;
 use sapic_window2::{
     AppWindowApi, WindowHandle,
     constants::{MIN_WINDOW_HEIGHT, MIN_WINDOW_WIDTH},
@@ -20,7 +32,11 @@ use std::{collections::HashMap, sync::Arc};
 use tokio::sync::RwLock;
 
 use crate::{
-    environment_ops::MainWindowEnvironmentOps, workspace::Workspace,
+    environment_ops::MainWindowEnvironmentOps,
+    proxy::ProxyRecordings,
+    resource::{auth::digest::DigestNonces, history::ExecutionHistory},
+    websocket::WebSocketSessions,
+    workspace::Workspace,
     workspace_ops::MainWindowWorkspaceOps,
 };
 
@@ -63,6 +79,24 @@ pub struct MainWindow<R: AppRuntime> {
 
     // Store cancellers by the id of API requests
     pub(crate) tracked_cancellations: Arc<RwLock<HashMap<String, Canceller>>>,
+
+    // Shared by all requests sent from this window, so that connections are pooled
+    pub(crate) http_client: reqwest::Client,
+
+    // Shared by all windows, the tokens it obtains are cached per workspace
+    pub(crate) oauth2_client: Arc<OAuth2Client>,
+
+    // Digest challenges answered by requests sent from this window, by the server that sent them
+    pub(crate) digest_nonces: Arc<DigestNonces>,
+
+    // Keeps the requests sent to endpoints in the storage of their project, for HAR exports
+    pub(crate) execution_history: Arc<ExecutionHistory>,
+
+    // Exchanges relayed by the recording proxies started from this window
+    pub(crate) proxy_recordings: Arc<ProxyRecordings>,
+
+    // WebSocket sessions connected from this window, kept after they are closed
+    pub(crate) websocket_sessions: Arc<WebSocketSessions>,
 }
 
 impl<R: AppRuntime> Clone for MainWindow<R> {
@@ -74,6 +108,12 @@ impl<R: AppRuntime> Clone for MainWindow<R> {
             workspace_ops: self.workspace_ops.clone(),
             environment_ops: self.environment_ops.clone(),
             tracked_cancellations: self.tracked_cancellations.clone(),
+            http_client: self.http_client.clone(),
+            oauth2_client: self.oauth2_client.clone(),
+            digest_nonces: self.digest_nonces.clone(),
+            execution_history: self.execution_history.clone(),
+            proxy_recordings: self.proxy_recordings.clone(),
+            websocket_sessions: self.websocket_sessions.clone(),
         }
     }
 }
@@ -86,6 +126,7 @@ impl<R: AppRuntime> MainWindow<R> {
         workspace: Arc<dyn Workspace>,
         workspace_ops: MainWindowWorkspaceOps,
         environment_ops: MainWindowEnvironmentOps,
+        oauth2_client: Arc<OAuth2Client>,
     ) -> joinerror::Result<Self> {
         let tao_handle = delegate.handle();
         let label = format!("{MAIN_WINDOW_LABEL_PREFIX}{}", window_id);
@@ -127,6 +168,11 @@ impl<R: AppRuntime> MainWindow<R> {
         #[cfg(target_os = "windows")]
         webview_window.set_decorations(false)?;
 
+        let http_client = joinerror::ResultExt::join_err::<()>(
+            reqwest::Client::builder().user_agent("SAPIC/1.0").build(),
+            "failed to build http client",
+        )?;
+
         Ok(Self {
             handle: WindowHandle::new(webview_window),
             w: ArcSwap::from_pointee(old_window).into(),
@@ -134,6 +180,12 @@ impl<R: AppRuntime> MainWindow<R> {
             workspace_ops,
             environment_ops,
             tracked_cancellations: Arc::new(RwLock::new(HashMap::new())),
+            http_client,
+            oauth2_client,
+            digest_nonces: Arc::new(DigestNonces::default()),
+            execution_history: Arc::new(ExecutionHistory::default()),
+            proxy_recordings: Arc::new(ProxyRecordings::default()),
+            websocket_sessions: Arc::new(WebSocketSessions::default()),
         })
     }
 
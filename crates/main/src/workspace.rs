@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use atomic_fs::Rollback;
+use futures::future::BoxFuture;
 use joinerror::{OptionExt, ResultExt};
 use moss_bindingutils::primitives::ChangeJsonValue;
 use moss_common::continue_if_err;
@@ -10,12 +12,13 @@ use moss_environment::{
 use moss_fs::FileSystem;
 use moss_git::url::GitUrl;
 use moss_project::{
-    ProjectBuilder,
+    Project, ProjectBuilder,
     builder::{
         ProjectCloneParams, ProjectCreateParams, ProjectImportArchiveParams,
         ProjectImportExternalParams, ProjectLoadParams,
     },
     git::GitClient,
+    models::operations::BatchCreateResourceKind,
 };
 use moss_storage2::{KvStorage, models::primitives::StorageScope};
 use moss_workspace::storage::{KEY_ACTIVE_ENVIRONMENT, key_project};
@@ -31,7 +34,8 @@ use sapic_core::context::AnyAsyncContext;
 use sapic_ipc::contracts::main::{
     environment::{CreateEnvironmentInput, UpdateEnvironmentParams},
     project::{
-        CreateProjectParams, ExportProjectParams, ImportArchiveParams, ImportDiskParams,
+        CreateProjectParams, ExportProjectParams, ImportArchiveParams, ImportBrunoParams,
+        ImportDiskParams, ImportInsomniaParams, ImportProjectParams, ImportSkippedItem,
         UpdateProjectParams,
     },
 };
@@ -56,10 +60,25 @@ use sapic_system::{
     user::User,
     workspace::{WorkspaceEditOp, WorkspaceEditParams},
 };
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use tokio::sync::{OnceCell, RwLock};
 
-use crate::{environment::RuntimeEnvironment, project::RuntimeProject};
+use crate::{
+    environment::RuntimeEnvironment,
+    import::{self, ImportedEnvironment, ImportedResources},
+    project::RuntimeProject,
+};
+
+/// Creates the converted resources in a project being imported. It's left to the caller,
+/// since the resources can only be created with the runtime of the app.
+pub type CreateImportedResources<'a> = Box<
+    dyn FnOnce(Arc<Project>, Vec<BatchCreateResourceKind>) -> BoxFuture<'a, joinerror::Result<()>>
+        + Send
+        + 'a,
+>;
 
 #[async_trait]
 pub trait Workspace: Send + Sync {
@@ -101,6 +120,22 @@ pub trait Workspace: Send + Sync {
         params: &ImportDiskParams,
     ) -> joinerror::Result<ProjectId>;
 
+    async fn import_insomnia_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        params: &ImportProjectParams,
+        insomnia_params: &ImportInsomniaParams,
+        create_resources: CreateImportedResources<'_>,
+    ) -> joinerror::Result<(ProjectId, Vec<ImportSkippedItem>)>;
+
+    async fn import_bruno_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        params: &ImportProjectParams,
+        bruno_params: &ImportBrunoParams,
+        create_resources: CreateImportedResources<'_>,
+    ) -> joinerror::Result<(ProjectId, Vec<ImportSkippedItem>)>;
+
     async fn delete_project(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -131,6 +166,23 @@ pub trait Workspace: Send + Sync {
         params: ExportProjectParams,
     ) -> joinerror::Result<PathBuf>;
 
+    async fn export_har(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        destination: &Path,
+        content: Vec<u8>,
+    ) -> joinerror::Result<PathBuf>;
+
+    async fn export_openapi(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        destination: &Path,
+        extension: &str,
+        content: Vec<u8>,
+    ) -> joinerror::Result<PathBuf>;
+
     async fn project(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -250,6 +302,153 @@ impl RuntimeWorkspace {
         }
     }
 
+    /// Creates a project holding the converted resources and environments. The project files
+    /// are written under a rollback, so that nothing is left behind if any of them can't be created.
+    async fn import_converted_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        params: &ImportProjectParams,
+        imported: ImportedResources,
+        create_resources: CreateImportedResources<'_>,
+    ) -> joinerror::Result<(ProjectId, Vec<ImportSkippedItem>)> {
+        let projects = self.projects_internal(ctx).await?;
+
+        let mut rb = self.fs.start_rollback(ctx).await?;
+        let project = match self
+            .import_converted_project_internal(
+                ctx,
+                &mut rb,
+                params,
+                imported.resources,
+                imported.environments,
+                create_resources,
+            )
+            .await
+        {
+            Ok(project) => project,
+            Err(e) => {
+                let _ = rb.rollback().await.map_err(|e| {
+                    tracing::error!("failed to rollback fs changes: {}", e.to_string());
+                });
+                return Err(e);
+            }
+        };
+
+        let id = project.id.clone();
+        projects.write().await.insert(id.clone(), project.into());
+
+        Ok((id, imported.skipped))
+    }
+
+    async fn import_converted_project_internal(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        rb: &mut Rollback,
+        params: &ImportProjectParams,
+        resources: Vec<BatchCreateResourceKind>,
+        environments: Vec<ImportedEnvironment>,
+        create_resources: CreateImportedResources<'_>,
+    ) -> joinerror::Result<RuntimeProject> {
+        let project_item = self
+            .project_service
+            .create_imported_project(ctx, rb, params.name.clone(), params.icon_path.clone())
+            .await?;
+
+        let builder = ProjectBuilder::new(
+            self.fs.clone(),
+            self.storage.clone(),
+            project_item.id.clone(),
+        )
+        .await;
+
+        let handle = builder
+            .create(
+                ctx,
+                ProjectCreateParams {
+                    name: Some(params.name.clone()),
+                    abs_path: project_item.internal_abs_path.clone(),
+                    config: project_item.config.clone(),
+                    icon_path: params.icon_path.clone(),
+                },
+            )
+            .await?;
+
+        let environments_path = handle.abs_path().join("environments");
+        let environment_service = EnvironmentService::new(
+            Some(self.id.clone()),
+            Some(project_item.id.clone()),
+            Arc::new(EnvironmentServiceFs::new(
+                environments_path,
+                self.fs.clone(),
+            )),
+            self.storage.clone(),
+        )
+        .into();
+
+        let project = RuntimeProject {
+            id: project_item.id.clone(),
+            workspace_id: self.id.clone(),
+            handle: handle.into(),
+            edit: ProjectEditService::new(ProjectFsEditBackend::new(
+                self.fs.clone(),
+                self.abs_path.join("projects"),
+            )),
+            storage: self.storage.clone(),
+            fs: self.fs.clone(),
+            environment_service,
+            environments: Default::default(),
+            active_environment: Default::default(),
+        };
+
+        if let Err(e) = self
+            .storage
+            .add_project(self.id.inner(), project_item.id.inner())
+            .await
+        {
+            return Err(joinerror::Error::new::<()>(format!(
+                "failed to add project storage: {}",
+                e
+            )));
+        }
+
+        let result = async {
+            create_resources(project.handle.clone(), resources).await?;
+
+            for environment in environments {
+                project
+                    .create_environment(
+                        ctx,
+                        CreateEnvironmentInput {
+                            project_id: Some(project.id.clone()),
+                            name: environment.name,
+                            color: None,
+                            variables: environment.variables,
+                        },
+                    )
+                    .await?;
+            }
+
+            joinerror::Result::Ok(())
+        }
+        .await;
+        if let Err(e) = result {
+            // Dropping repo and database handle to prevent lock when rolling back the folder
+            if let Err(e) = project.handle.dispose(ctx).await {
+                tracing::warn!("failed to dispose project {}: {}", project.id, e);
+            }
+            if let Err(e) = self
+                .storage
+                .remove_project(self.id.inner(), project.id.inner())
+                .await
+            {
+                tracing::warn!("failed to remove project {} storage: {}", project.id, e);
+            }
+            return Err(e);
+        }
+
+        Ok(project)
+    }
+
     async fn projects_internal(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -825,6 +1024,32 @@ impl Workspace for RuntimeWorkspace {
         Ok(project_item.id)
     }
 
+    async fn import_insomnia_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        params: &ImportProjectParams,
+        insomnia_params: &ImportInsomniaParams,
+        create_resources: CreateImportedResources<'_>,
+    ) -> joinerror::Result<(ProjectId, Vec<ImportSkippedItem>)> {
+        let imported = import::insomnia::read_export(insomnia_params).await?;
+
+        self.import_converted_project(ctx, params, imported, create_resources)
+            .await
+    }
+
+    async fn import_bruno_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        params: &ImportProjectParams,
+        bruno_params: &ImportBrunoParams,
+        create_resources: CreateImportedResources<'_>,
+    ) -> joinerror::Result<(ProjectId, Vec<ImportSkippedItem>)> {
+        let imported = import::bruno::read_collection(bruno_params).await?;
+
+        self.import_converted_project(ctx, params, imported, create_resources)
+            .await
+    }
+
     async fn delete_project(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -939,6 +1164,31 @@ impl Workspace for RuntimeWorkspace {
         Ok(archive_path)
     }
 
+    async fn export_har(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        destination: &Path,
+        content: Vec<u8>,
+    ) -> joinerror::Result<PathBuf> {
+        self.project_service
+            .export_har(ctx, id, destination, content)
+            .await
+    }
+
+    async fn export_openapi(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        destination: &Path,
+        extension: &str,
+        content: Vec<u8>,
+    ) -> joinerror::Result<PathBuf> {
+        self.project_service
+            .export_openapi(ctx, id, destination, extension, content)
+            .await
+    }
+
     async fn projects(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -1242,8 +1492,11 @@ impl Workspace for RuntimeWorkspace {
 
     async fn active_environment(
         &self,
-        _ctx: &dyn AnyAsyncContext,
+        ctx: &dyn AnyAsyncContext,
     ) -> joinerror::Result<Option<EnvironmentId>> {
+        // The active environment is restored from the storage along with the environments
+        self.environments_internal(ctx).await?;
+
         let active_environments = self.active_environment.read().await;
 
         Ok(active_environments.clone())
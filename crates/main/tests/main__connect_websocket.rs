@@ -0,0 +1,361 @@
+#![cfg(feature = "integration-tests")]
+
+use futures::{SinkExt, StreamExt};
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, websocket::WebSocketParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::{
+        project::{CreateProjectInput, CreateProjectParams},
+        websocket::{ConnectWebSocketInput, WebSocketEvent},
+    },
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::Value as JsonValue;
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::{
+    Message,
+    handshake::server::{ErrorResponse, Request, Response},
+    http::{HeaderValue, header::SEC_WEBSOCKET_PROTOCOL},
+    protocol::{CloseFrame, frame::coding::CloseCode},
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+/// Subprotocol picked by the echo server, when offered.
+const ECHO_SUBPROTOCOL: &str = "superchat";
+
+#[tokio::test]
+async fn connect_websocket_negotiates_subprotocol() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let port = spawn_echo_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            ResourceProtocol::WebSocket,
+            &format!("ws://127.0.0.1:{port}/echo"),
+            Some(WebSocketParams {
+                subprotocols: vec!["chat".to_string(), ECHO_SUBPROTOCOL.to_string()],
+                messages: vec![],
+            }),
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    let output = main_window
+        .connect_websocket(
+            &ctx,
+            &delegate,
+            ConnectWebSocketInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.protocol.as_deref(), Some(ECHO_SUBPROTOCOL));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn connect_websocket_without_subprotocol() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let port = spawn_echo_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            ResourceProtocol::WebSocket,
+            &format!("127.0.0.1:{port}/echo"),
+            None,
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    let output = main_window
+        .connect_websocket(
+            &ctx,
+            &delegate,
+            ConnectWebSocketInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    // No subprotocol was offered
+    assert_eq!(output.protocol, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn connect_websocket_http_endpoint() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(ResourceProtocol::Get, "http://127.0.0.1/echo", None),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    let result = main_window
+        .connect_websocket(
+            &ctx,
+            &delegate,
+            ConnectWebSocketInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn connect_websocket_canceled() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+
+    // Accepts connections without ever completing the handshake
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        let mut connections = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            connections.push(stream);
+        }
+    });
+
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            ResourceProtocol::WebSocket,
+            &format!("ws://127.0.0.1:{port}/echo"),
+            None,
+        ),
+    )
+    .await;
+
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("websocket", request_ctx.get_canceller())
+        .await;
+
+    let (channel, _) = test_channel();
+    let window_clone = main_window.clone();
+    let delegate_clone = delegate.clone();
+    let connection = tokio::spawn(async move {
+        window_clone
+            .connect_websocket(
+                &request_ctx,
+                &delegate_clone,
+                ConnectWebSocketInput {
+                    project_id,
+                    resource_id,
+                },
+                channel,
+            )
+            .await
+    });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "websocket".to_string(),
+        })
+        .await
+        .unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(5), connection)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+/// Echoes text and binary messages, closing the connection with code 4000 on `bye`.
+async fn spawn_echo_server() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let Ok(mut ws) =
+                    tokio_tungstenite::accept_hdr_async(stream, select_subprotocol).await
+                else {
+                    return;
+                };
+
+                while let Some(Ok(message)) = ws.next().await {
+                    let result = match message {
+                        Message::Text(text) if text.as_str() == "bye" => {
+                            ws.close(Some(CloseFrame {
+                                code: CloseCode::from(4000),
+                                reason: "bye".into(),
+                            }))
+                            .await
+                        }
+                        Message::Text(_) | Message::Binary(_) => ws.send(message).await,
+                        _ => Ok(()),
+                    };
+                    if result.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    port
+}
+
+// The signature is the handshake callback of tungstenite
+#[allow(clippy::result_large_err)]
+fn select_subprotocol(
+    request: &Request,
+    mut response: Response,
+) -> Result<Response, ErrorResponse> {
+    let offered = request
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if offered
+        .split(',')
+        .any(|protocol| protocol.trim() == ECHO_SUBPROTOCOL)
+    {
+        response.headers_mut().insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static(ECHO_SUBPROTOCOL),
+        );
+    }
+
+    Ok(response)
+}
+
+fn test_channel() -> (Channel<WebSocketEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+fn endpoint(
+    protocol: ResourceProtocol,
+    url: &str,
+    websocket: Option<WebSocketParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(protocol),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
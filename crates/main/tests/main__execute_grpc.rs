@@ -0,0 +1,714 @@
+#![cfg(feature = "integration-tests")]
+
+use futures::{StreamExt, stream};
+use main::grpc::{self, DynamicCodec};
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{
+        CreateItemResourceParams,
+        grpc::GrpcParams,
+        http::{AddHeaderParams, HeaderParamOptions},
+    },
+};
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor, Value};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    grpc::{ExecuteGrpcInput, GrpcEvent},
+    project::{CreateProjectInput, CreateProjectParams},
+};
+use serde_json::{Value as JsonValue, json};
+use std::{
+    convert::Infallible,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::net::TcpListener;
+use tonic::{
+    Status, Streaming,
+    codegen::{Body, BoxFuture, BoxStream, Context, Poll, Service, StdError, http},
+    server::{NamedService, StreamingService},
+    transport::{Server, server::TcpIncoming},
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+const SERVICE: &str = "test.Greeter";
+
+const GREETER_PROTO: &str = r#"
+syntax = "proto3";
+
+package test;
+
+import "common.proto";
+
+service Greeter {
+  rpc SayHello (HelloRequest) returns (HelloReply);
+  rpc SayHellos (HelloRequest) returns (stream HelloReply);
+  rpc Collect (stream HelloRequest) returns (HelloReply);
+  rpc Chat (stream HelloRequest) returns (stream HelloReply);
+  rpc Slow (HelloRequest) returns (stream HelloReply);
+  rpc Fail (HelloRequest) returns (HelloReply);
+}
+
+message HelloRequest {
+  string name = 1;
+}
+
+message HelloReply {
+  string message = 1;
+  common.Stats stats = 2;
+}
+"#;
+
+const COMMON_PROTO: &str = r#"
+syntax = "proto3";
+
+package common;
+
+message Stats {
+  int64 count = 1;
+}
+"#;
+
+#[tokio::test]
+async fn execute_grpc_unary() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(&url, "SayHello", vec![json!({ "name": "Ann" })], true),
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let output = main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+                messages: None,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.code, 0);
+    assert_eq!(output.message, None);
+    assert_eq!(output.received, 1);
+    assert_eq!(
+        messages(&events),
+        vec![json!({ "message": "Hello, Ann!", "stats": { "count": "1" } })]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_streaming_methods() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+
+    let cases = [
+        (
+            "SayHellos",
+            vec![json!({ "name": "Ann" })],
+            vec!["Hello, Ann #1!", "Hello, Ann #2!", "Hello, Ann #3!"],
+        ),
+        (
+            "Collect",
+            vec![json!({ "name": "Ann" }), json!({ "name": "Bob" })],
+            vec!["Hello, Ann and Bob!"],
+        ),
+        (
+            "Chat",
+            vec![json!({ "name": "Ann" }), json!({ "name": "Bob" })],
+            vec!["Hello, Ann!", "Hello, Bob!"],
+        ),
+    ];
+    for (method, requests, replies) in cases {
+        let resource_id = create_test_item(
+            &main_window,
+            &ctx,
+            &project_id,
+            endpoint(&url, method, vec![json!({ "name": "Saved" })], true),
+        )
+        .await;
+
+        // The messages given replace the saved ones
+        let (channel, events) = test_channel();
+        let output = main_window
+            .execute_grpc(
+                &ctx,
+                &delegate,
+                ExecuteGrpcInput {
+                    project_id: project_id.clone(),
+                    resource_id,
+                    messages: Some(requests),
+                },
+                channel,
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.code, 0, "{method}");
+        assert_eq!(output.received, replies.len() as u64, "{method}");
+
+        let received = messages(&events)
+            .into_iter()
+            .map(|message| message["message"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(received, replies, "{method}");
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_with_too_many_messages() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            &url,
+            "SayHello",
+            vec![json!({ "name": "Ann" }), json!({ "name": "Bob" })],
+            true,
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    let result = main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id,
+                resource_id,
+                messages: None,
+            },
+            channel,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_with_reflection() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(&url, "SayHello", vec![json!({ "name": "Ann" })], false),
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let output = main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id,
+                resource_id,
+                messages: None,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.code, 0);
+    assert_eq!(
+        messages(&events),
+        vec![json!({ "message": "Hello, Ann!", "stats": { "count": "1" } })]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_sends_headers_as_metadata() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+
+    let mut params = endpoint(&url, "SayHello", vec![json!({ "name": "Ann" })], true);
+    params.headers = vec![AddHeaderParams {
+        name: "x-token".to_string(),
+        value: json!("abc"),
+        order: 0,
+        description: None,
+        options: HeaderParamOptions {
+            disabled: false,
+            propagate: false,
+        },
+    }];
+    let resource_id = create_test_item(&main_window, &ctx, &project_id, params).await;
+
+    let (channel, events) = test_channel();
+    main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id,
+                resource_id,
+                messages: None,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    let events = events.lock().unwrap().clone();
+    let metadata = events[0]["headers"]["metadata"].as_array().unwrap();
+    assert!(metadata.contains(&json!({ "name": "x-echo", "value": "abc" })));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_error_status() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(&url, "Fail", vec![json!({ "name": "Ann" })], true),
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let output = main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id,
+                resource_id,
+                messages: None,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.code, tonic::Code::NotFound as i32);
+    assert_eq!(output.message.as_deref(), Some("no user named Ann"));
+    assert_eq!(output.received, 0);
+    assert!(
+        output
+            .trailers
+            .iter()
+            .any(|entry| entry.name == "x-reason" && entry.value == "missing")
+    );
+    assert!(messages(&events).is_empty());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_deadline_exceeded() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+
+    let mut params = endpoint(&url, "Slow", vec![json!({ "name": "Ann" })], true);
+    params.grpc.as_mut().unwrap().timeout_ms = Some(200);
+    let resource_id = create_test_item(&main_window, &ctx, &project_id, params).await;
+
+    let (channel, _) = test_channel();
+    let output = main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id,
+                resource_id,
+                messages: None,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.code, tonic::Code::DeadlineExceeded as i32);
+    assert_eq!(output.received, 0);
+    assert!(output.duration_ms < 5000);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_grpc_http_endpoint() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, _) = create_test_project(&main_window, &ctx).await;
+
+    let mut params = endpoint("http://127.0.0.1:1", "SayHello", vec![], true);
+    params.protocol = Some(ResourceProtocol::Post);
+    params.grpc = None;
+    let resource_id = create_test_item(&main_window, &ctx, &project_id, params).await;
+
+    let (channel, _) = test_channel();
+    let result = main_window
+        .execute_grpc(
+            &ctx,
+            &delegate,
+            ExecuteGrpcInput {
+                project_id,
+                resource_id,
+                messages: None,
+            },
+            channel,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+/// Serves the greeter service described by the pool, along with its server reflection.
+async fn spawn_grpc_server(pool: DescriptorPool) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let file_descriptor_set = pool.encode_to_vec();
+    let reflection = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(&file_descriptor_set)
+        .build_v1()
+        .unwrap();
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(GreeterServer { pool })
+            .add_service(reflection)
+            .serve_with_incoming(TcpIncoming::from(listener)),
+    );
+
+    url
+}
+
+/// Greeter service handling messages through their descriptors, like the client does.
+#[derive(Clone)]
+struct GreeterServer {
+    pool: DescriptorPool,
+}
+
+impl NamedService for GreeterServer {
+    const NAME: &'static str = SERVICE;
+}
+
+impl<B> Service<http::Request<B>> for GreeterServer
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request
+            .uri()
+            .path()
+            .rsplit('/')
+            .next()
+            .and_then(|name| grpc::find_method(&self.pool, SERVICE, name).ok());
+
+        Box::pin(async move {
+            let Some(method) = method else {
+                return Ok(Status::unimplemented("unknown method").into_http());
+            };
+
+            // Every kind of method is a bidi stream on the wire
+            let mut server = tonic::server::Grpc::new(DynamicCodec::new(method.input()));
+            Ok(server.streaming(GreeterMethod(method), request).await)
+        })
+    }
+}
+
+struct GreeterMethod(MethodDescriptor);
+
+impl StreamingService<DynamicMessage> for GreeterMethod {
+    type Response = DynamicMessage;
+    type ResponseStream = BoxStream<DynamicMessage>;
+    type Future = BoxFuture<tonic::Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: tonic::Request<Streaming<DynamicMessage>>) -> Self::Future {
+        Box::pin(respond(self.0.clone(), request))
+    }
+}
+
+async fn respond(
+    method: MethodDescriptor,
+    request: tonic::Request<Streaming<DynamicMessage>>,
+) -> Result<tonic::Response<BoxStream<DynamicMessage>>, Status> {
+    let token = request.metadata().get("x-token").cloned();
+    let mut requests = request.into_inner();
+    let output = method.output();
+
+    let replies: BoxStream<DynamicMessage> = match method.name() {
+        "SayHello" => {
+            let name = next_name(&mut requests).await?;
+            let reply = reply(&output, format!("Hello, {name}!"), 1);
+            Box::pin(stream::iter([Ok(reply)]))
+        }
+        "SayHellos" => {
+            let name = next_name(&mut requests).await?;
+            let replies = (1..=3)
+                .map(|i| Ok(reply(&output, format!("Hello, {name} #{i}!"), i)))
+                .collect::<Vec<_>>();
+            Box::pin(stream::iter(replies))
+        }
+        "Collect" => {
+            let mut names = Vec::new();
+            while let Some(message) = requests.message().await? {
+                names.push(name_of(&message));
+            }
+            let reply = reply(
+                &output,
+                format!("Hello, {}!", names.join(" and ")),
+                names.len() as i64,
+            );
+            Box::pin(stream::iter([Ok(reply)]))
+        }
+        "Chat" => Box::pin(requests.map(move |message| {
+            message.map(|message| reply(&output, format!("Hello, {}!", name_of(&message)), 1))
+        })),
+        "Slow" => {
+            let name = next_name(&mut requests).await?;
+            Box::pin(stream::once(async move {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                Ok(reply(&output, format!("Hello, {name}!"), 1))
+            }))
+        }
+        "Fail" => {
+            let name = next_name(&mut requests).await?;
+            let mut status = Status::not_found(format!("no user named {name}"));
+            status
+                .metadata_mut()
+                .insert("x-reason", "missing".parse().unwrap());
+            return Err(status);
+        }
+        _ => return Err(Status::unimplemented("unknown method")),
+    };
+
+    let mut response = tonic::Response::new(replies);
+    if let Some(token) = token {
+        response.metadata_mut().insert("x-echo", token);
+    }
+
+    Ok(response)
+}
+
+async fn next_name(requests: &mut Streaming<DynamicMessage>) -> Result<String, Status> {
+    let message = requests
+        .message()
+        .await?
+        .ok_or_else(|| Status::invalid_argument("missing request"))?;
+
+    Ok(name_of(&message))
+}
+
+fn name_of(message: &DynamicMessage) -> String {
+    message
+        .get_field_by_name("name")
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn reply(output: &MessageDescriptor, message: String, count: i64) -> DynamicMessage {
+    let stats = output
+        .get_field_by_name("stats")
+        .and_then(|field| field.kind().as_message().cloned())
+        .unwrap();
+    let mut stats = DynamicMessage::new(stats);
+    stats.set_field_by_name("count", Value::I64(count));
+
+    let mut reply = DynamicMessage::new(output.clone());
+    reply.set_field_by_name("message", Value::String(message));
+    reply.set_field_by_name("stats", Value::Message(stats));
+
+    reply
+}
+
+fn test_channel() -> (Channel<GrpcEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+/// Response messages among the events received, in order.
+fn messages(events: &Arc<Mutex<Vec<JsonValue>>>) -> Vec<JsonValue> {
+    events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|event| event.get("message"))
+        .map(|event| event["message"].clone())
+        .collect()
+}
+
+/// Endpoint calling a method of the greeter, described by the proto files of the project or
+/// by the server reflection.
+fn endpoint(
+    url: &str,
+    method: &str,
+    messages: Vec<JsonValue>,
+    proto_files: bool,
+) -> CreateItemResourceParams {
+    let (proto_files, import_paths) = if proto_files {
+        (
+            vec![PathBuf::from("protos/greeter.proto")],
+            vec![PathBuf::from("protos/shared")],
+        )
+    } else {
+        (vec![], vec![])
+    };
+
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(ResourceProtocol::Grpc),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: Some(GrpcParams {
+            proto_files,
+            import_paths,
+            service: SERVICE.to_string(),
+            method: method.to_string(),
+            messages,
+            timeout_ms: None,
+        }),
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+/// Creates a project holding the proto files of the greeter, returning the descriptors they
+/// compile to.
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> (ProjectId, DescriptorPool) {
+    let project_id = main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, &project_id)
+        .await
+        .unwrap();
+    let project_path = project.abs_path();
+    write_protos(&project_path).await;
+
+    let pool = grpc::compile_protos(
+        &project_path,
+        &[PathBuf::from("protos/greeter.proto")],
+        &[PathBuf::from("protos/shared")],
+    )
+    .unwrap();
+
+    (project_id, pool)
+}
+
+async fn write_protos(project_path: &Path) {
+    let shared_path = project_path.join("protos").join("shared");
+    tokio::fs::create_dir_all(&shared_path).await.unwrap();
+    tokio::fs::write(
+        project_path.join("protos").join("greeter.proto"),
+        GREETER_PROTO,
+    )
+    .await
+    .unwrap();
+    tokio::fs::write(shared_path.join("common.proto"), COMMON_PROTO)
+        .await
+        .unwrap();
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,1241 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_environment::models::types::{AddVariableParams, VariableOptions};
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{
+        ApiKeyLocation, AuthScheme, AwsSigV4Config, CreateItemResourceParams, OAuth2Config,
+        OAuth2Grant,
+        http::{
+            AddBodyParams, AddHeaderParams, AddPathParamParams, AddQueryParamParams,
+            HeaderParamOptions, PathParamOptions, QueryParamOptions,
+        },
+    },
+};
+use reqwest::Url;
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::{
+        environment::{ActivateEnvironmentInput, CreateEnvironmentInput},
+        project::{CreateProjectInput, CreateProjectParams},
+        resource::{ExecuteResourceEvent, ExecuteResourceInput},
+    },
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn execute_resource_get_streams_response() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let (addr, request_rx) = spawn_test_server(
+        "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nX-Test: yes\r\nConnection: close\r\n\r\nhello",
+    )
+    .await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some(format!("http://{addr}/users/:id")),
+            headers: vec![
+                AddHeaderParams {
+                    name: "x-token".to_string(),
+                    value: json!("abc"),
+                    order: 0,
+                    description: None,
+                    options: HeaderParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                },
+                AddHeaderParams {
+                    name: "x-disabled".to_string(),
+                    value: json!("nope"),
+                    order: 1,
+                    description: None,
+                    options: HeaderParamOptions {
+                        disabled: true,
+                        propagate: false,
+                    },
+                },
+            ],
+            path_params: vec![AddPathParamParams {
+                name: "id".to_string(),
+                value: json!(42),
+                order: 0,
+                description: None,
+                options: PathParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            query_params: vec![AddQueryParamParams {
+                name: "page".to_string(),
+                value: json!("1"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let output = main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.status, 200);
+    assert_eq!(output.size, 5);
+
+    let request = request_rx.await.unwrap();
+    assert!(request.starts_with("GET /users/42?page=1 HTTP/1.1\r\n"));
+    assert!(request.contains("x-token: abc\r\n"));
+    assert!(!request.contains("x-disabled"));
+
+    let events = events.lock().unwrap().clone();
+    assert_eq!(
+        events[0],
+        json!({ "status": { "status": 200, "statusText": "OK" } })
+    );
+    assert!(
+        events[1]["headers"]["headers"]
+            .as_array()
+            .unwrap()
+            .contains(&json!({ "name": "x-test", "value": "yes" }))
+    );
+
+    let body = events[2..]
+        .iter()
+        .flat_map(|event| event["body"]["chunk"].as_array().unwrap().clone())
+        .map(|byte| byte.as_u64().unwrap() as u8)
+        .collect::<Vec<_>>();
+    assert_eq!(body, b"hello");
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_post_json_body() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let (addr, request_rx) =
+        spawn_test_server("HTTP/1.1 201 Created\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Post),
+            url: Some(format!("{addr}/items")),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: Some(AddBodyParams::Json(json!({ "name": "item" }))),
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let (channel, _events) = test_channel();
+    let output = main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.status, 201);
+    assert_eq!(output.size, 0);
+
+    let request = request_rx.await.unwrap();
+    assert!(request.starts_with("POST /items HTTP/1.1\r\n"));
+    assert!(request.contains("content-type: application/json\r\n"));
+    assert!(request.ends_with(r#"{"name":"item"}"#));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_signs_aws_sigv4_request() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let environment_id = main_window
+        .create_environment(
+            &ctx,
+            CreateEnvironmentInput {
+                project_id: None,
+                name: random_entry_name(),
+                color: None,
+                variables: [
+                    ("aws_access_key", "AKIDEXAMPLE"),
+                    ("aws_secret_key", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+                    ("aws_session_token", "session"),
+                ]
+                .into_iter()
+                .map(|(name, value)| AddVariableParams {
+                    name: name.to_string(),
+                    global_value: json!(value),
+                    local_value: JsonValue::Null,
+                    order: 0,
+                    desc: None,
+                    options: VariableOptions { disabled: false },
+                })
+                .collect(),
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+    main_window
+        .activate_environment(
+            &ctx,
+            ActivateEnvironmentInput {
+                project_id: None,
+                environment_id,
+            },
+        )
+        .await
+        .unwrap();
+
+    let (addr, request_rx) =
+        spawn_test_server("HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Post),
+            url: Some(format!("http://{addr}/items?b=2")),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![AddQueryParamParams {
+                name: "a".to_string(),
+                value: json!("x y"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: Some(AddBodyParams::Json(json!({ "name": "item" }))),
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: Some(AuthScheme::AwsSigV4(AwsSigV4Config {
+                access_key: "{{aws_access_key}}".to_string(),
+                secret_key: "{{aws_secret_key}}".to_string(),
+                session_token: Some("{{aws_session_token}}".to_string()),
+                region: "us-east-1".to_string(),
+                service: "execute-api".to_string(),
+            })),
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let (channel, _events) = test_channel();
+    main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    let request = request_rx.await.unwrap();
+    // The query is sent as entered, with the space percent-encoded rather than form-encoded
+    assert!(request.starts_with("POST /items?b=2&a=x%20y HTTP/1.1\r\n"));
+    assert!(request.contains("x-amz-security-token: session\r\n"));
+    assert!(request.contains("x-amz-date: "));
+
+    let authorization = request
+        .lines()
+        .find_map(|line| line.strip_prefix("authorization: "))
+        .unwrap();
+    assert!(authorization.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+    assert!(authorization.contains(
+        "/us-east-1/execute-api/aws4_request, \
+         SignedHeaders=content-type;host;x-amz-date;x-amz-security-token, Signature="
+    ));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_adds_api_key_to_query() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let (addr, request_rx) =
+        spawn_test_server("HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some(format!("http://{addr}/items?b=2")),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![AddQueryParamParams {
+                name: "a".to_string(),
+                value: json!("x y"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: Some(AuthScheme::ApiKey {
+                key: "api key".to_string(),
+                value: "s e+c".to_string(),
+                location: ApiKeyLocation::Query,
+            }),
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let (channel, _events) = test_channel();
+    main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    // The key is percent-encoded like the rest of the query
+    let request = request_rx.await.unwrap();
+    assert!(request.starts_with("GET /items?b=2&a=x%20y&api%20key=s%20e%2Bc HTTP/1.1\r\n"));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_answers_digest_challenge() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let (addr, requests_rx) = spawn_test_server_with_responses(vec![
+        "HTTP/1.1 401 Unauthorized\r\n\
+         WWW-Authenticate: Basic realm=\"test\", Digest realm=\"test\", qop=\"auth\", nonce=\"abc\", opaque=\"xyz\"\r\n\
+         Content-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ])
+    .await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Post),
+            url: Some(format!("http://{addr}/protected?x=1")),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: Some(AddBodyParams::Json(json!({ "name": "item" }))),
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: Some(AuthScheme::Digest {
+                username: "Mufasa".to_string(),
+                password: "Circle of Life".to_string(),
+            }),
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    for _ in 0..2 {
+        let (channel, _events) = test_channel();
+        let output = main_window
+            .execute_resource(
+                &ctx,
+                &delegate,
+                ExecuteResourceInput {
+                    project_id: project_id.clone(),
+                    resource_id: resource_id.clone(),
+                },
+                channel,
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.status, 200);
+    }
+
+    let requests = requests_rx.await.unwrap();
+    let authorization = |request: &str| {
+        request
+            .lines()
+            .find_map(|line| line.strip_prefix("authorization: "))
+            .map(str::to_string)
+    };
+
+    // The request is sent as is until the server challenges it
+    assert!(requests[0].starts_with("POST /protected?x=1 HTTP/1.1\r\n"));
+    assert!(requests[0].ends_with(r#"{"name":"item"}"#));
+    assert_eq!(authorization(&requests[0]), None);
+
+    assert!(requests[1].ends_with(r#"{"name":"item"}"#));
+    let answer = authorization(&requests[1]).unwrap();
+    assert!(answer.starts_with(
+        r#"Digest username="Mufasa", realm="test", nonce="abc", uri="/protected?x=1", algorithm=MD5, qop=auth, nc=00000001, cnonce=""#
+    ));
+    assert!(answer.ends_with(r#"", opaque="xyz""#));
+
+    // The next request answers the cached nonce up front
+    assert!(requests[2].ends_with(r#"{"name":"item"}"#));
+    assert!(
+        authorization(&requests[2])
+            .unwrap()
+            .contains(r#"nonce="abc", uri="/protected?x=1", algorithm=MD5, qop=auth, nc=00000002"#)
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_canceled() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    // The server accepts the connection but never responds
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (_socket, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(30)).await;
+    });
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some(format!("http://{addr}/slow")),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("execute", request_ctx.get_canceller())
+        .await;
+
+    let window_clone = main_window.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        window_clone
+            .cancel_request(CancelRequestInput {
+                request_id: "execute".to_string(),
+            })
+            .await
+            .unwrap();
+    });
+
+    let (channel, events) = test_channel();
+    let result = main_window
+        .execute_resource(
+            &request_ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(events.lock().unwrap().is_empty());
+
+    main_window.release_cancellation("execute").await;
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_not_http_endpoint() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::WebSocket),
+            url: Some("ws://localhost/socket".to_string()),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let (channel, _events) = test_channel();
+    let result = main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_oauth2_client_credentials_reuses_cached_token() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server = AuthServer::spawn(3600).await;
+
+    create_active_environment(&main_window, &ctx, "secret").await;
+    let resource_id = create_oauth2_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        &server,
+        oauth2_config(&server, OAuth2Grant::ClientCredentials),
+    )
+    .await;
+
+    for _ in 0..2 {
+        execute_oauth2_endpoint(&main_window, &delegate, &ctx, &project_id, &resource_id).await;
+    }
+
+    {
+        let state = server.state.lock().unwrap();
+        assert_eq!(state.grants, vec!["client_credentials"]);
+        // The secret is resolved from the environment and sent with basic auth
+        assert_eq!(
+            state.token_authorizations,
+            vec!["Basic Y2xpZW50OnNlY3JldA=="]
+        );
+        assert_eq!(
+            state.api_authorizations,
+            vec!["Bearer token-1", "Bearer token-1"]
+        );
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_oauth2_refreshes_expiring_token() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    // Tokens expiring within the refresh margin are refreshed before they are used again
+    let server = AuthServer::spawn(30).await;
+
+    create_active_environment(&main_window, &ctx, "secret").await;
+    let resource_id = create_oauth2_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        &server,
+        oauth2_config(&server, OAuth2Grant::ClientCredentials),
+    )
+    .await;
+
+    for _ in 0..2 {
+        execute_oauth2_endpoint(&main_window, &delegate, &ctx, &project_id, &resource_id).await;
+    }
+
+    {
+        let state = server.state.lock().unwrap();
+        assert_eq!(state.grants, vec!["client_credentials", "refresh_token"]);
+        assert_eq!(state.refresh_tokens, vec!["refresh-1"]);
+        assert_eq!(
+            state.api_authorizations,
+            vec!["Bearer token-1", "Bearer token-2"]
+        );
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_oauth2_authorization_code_with_pkce() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server = AuthServer::spawn(3600).await;
+
+    let resource_id = create_oauth2_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        &server,
+        OAuth2Config {
+            client_secret: None,
+            ..oauth2_config(&server, OAuth2Grant::AuthorizationCode)
+        },
+    )
+    .await;
+
+    execute_oauth2_endpoint(&main_window, &delegate, &ctx, &project_id, &resource_id).await;
+
+    {
+        let state = server.state.lock().unwrap();
+        assert_eq!(state.grants, vec!["authorization_code"]);
+        assert_eq!(state.code_challenge_methods, vec!["S256"]);
+        assert!(state.code_verified);
+        assert_eq!(state.api_authorizations, vec!["Bearer token-1"]);
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_oauth2_device_code_polls_until_approved() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server = AuthServer::spawn(3600).await;
+
+    let resource_id = create_oauth2_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        &server,
+        OAuth2Config {
+            client_secret: None,
+            ..oauth2_config(&server, OAuth2Grant::DeviceCode)
+        },
+    )
+    .await;
+
+    execute_oauth2_endpoint(&main_window, &delegate, &ctx, &project_id, &resource_id).await;
+
+    {
+        let state = server.state.lock().unwrap();
+        // The first poll is answered with `authorization_pending`
+        assert_eq!(
+            state.grants,
+            vec![
+                "urn:ietf:params:oauth:grant-type:device_code",
+                "urn:ietf:params:oauth:grant-type:device_code"
+            ]
+        );
+        assert_eq!(state.api_authorizations, vec!["Bearer token-1"]);
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_resource_oauth2_undefined_secret_variable() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server = AuthServer::spawn(3600).await;
+
+    let resource_id = create_oauth2_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        &server,
+        oauth2_config(&server, OAuth2Grant::ClientCredentials),
+    )
+    .await;
+
+    let result = main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id,
+                resource_id,
+            },
+            Channel::new(|_| Ok(())),
+        )
+        .await;
+    assert!(result.is_err());
+
+    {
+        let state = server.state.lock().unwrap();
+        assert!(state.grants.is_empty());
+        assert!(state.api_authorizations.is_empty());
+    }
+
+    cleanup().await;
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_endpoint(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
+
+fn test_channel() -> (Channel<ExecuteResourceEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+/// Serves a single connection with the given raw response and reports the raw request it received.
+async fn spawn_test_server(response: &'static str) -> (String, oneshot::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let request = read_request(&mut socket).await;
+
+        socket.write_all(response.as_bytes()).await.unwrap();
+        socket.shutdown().await.unwrap();
+        let _ = tx.send(request);
+    });
+
+    (addr, rx)
+}
+
+/// Answers a connection with each of the responses in turn, reporting the requests once
+/// they were all answered.
+async fn spawn_test_server_with_responses(
+    responses: Vec<&'static str>,
+) -> (String, oneshot::Receiver<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            requests.push(read_request(&mut socket).await);
+
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+        let _ = tx.send(requests);
+    });
+
+    (addr, rx)
+}
+
+async fn read_request(socket: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+
+    loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+
+        let text = String::from_utf8_lossy(&request).to_string();
+        if let Some(head_end) = text.find("\r\n\r\n") {
+            let content_length = text[..head_end]
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length: "))
+                .map(|len| len.trim().parse::<usize>().unwrap())
+                .unwrap_or(0);
+
+            if n == 0 || request.len() >= head_end + 4 + content_length {
+                break;
+            }
+        } else if n == 0 {
+            break;
+        }
+    }
+
+    String::from_utf8_lossy(&request).to_string()
+}
+
+#[derive(Default)]
+struct AuthServerState {
+    /// The `grant_type` of every token request.
+    grants: Vec<String>,
+    token_authorizations: Vec<String>,
+    refresh_tokens: Vec<String>,
+    code_challenge_methods: Vec<String>,
+    code_verified: bool,
+    device_polls: usize,
+    issued: usize,
+    api_authorizations: Vec<String>,
+}
+
+/// Stand-in authorization server, which also serves the api the tokens are issued for.
+struct AuthServer {
+    addr: String,
+    state: Arc<Mutex<AuthServerState>>,
+}
+
+impl AuthServer {
+    async fn spawn(expires_in: u64) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let state = Arc::new(Mutex::new(AuthServerState::default()));
+
+        let state_clone = state.clone();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(handle_connection(socket, state_clone.clone(), expires_in));
+            }
+        });
+
+        Self { addr, state }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+}
+
+async fn handle_connection(
+    mut socket: TcpStream,
+    state: Arc<Mutex<AuthServerState>>,
+    expires_in: u64,
+) {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    let (head, body) = loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+
+        let text = String::from_utf8_lossy(&request).to_string();
+        if let Some(head_end) = text.find("\r\n\r\n") {
+            let head = text[..head_end].to_string();
+            let content_length = request_header(&head, "content-length")
+                .map(|len| len.parse::<usize>().unwrap())
+                .unwrap_or(0);
+
+            if n == 0 || request.len() >= head_end + 4 + content_length {
+                break (head, text[head_end + 4..].to_string());
+            }
+        } else if n == 0 {
+            return;
+        }
+    };
+
+    let target = head.split(' ').nth(1).unwrap().to_string();
+    let url = Url::parse(&format!("http://localhost{}", target)).unwrap();
+    let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    let form: HashMap<String, String> = Url::parse(&format!("http://localhost/?{}", body))
+        .unwrap()
+        .query_pairs()
+        .into_owned()
+        .collect();
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        match url.path() {
+            "/authorize" => {
+                state
+                    .code_challenge_methods
+                    .push(query["code_challenge_method"].clone());
+                format!(
+                    "HTTP/1.1 302 Found\r\nLocation: {}?code=code-1&state={}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    query["redirect_uri"], query["state"]
+                )
+            }
+            "/device" => json_response(
+                "200 OK",
+                json!({
+                    "device_code": "device-1",
+                    "user_code": "ABCD-1234",
+                    "verification_uri": "http://localhost/verify",
+                    "expires_in": 600,
+                    "interval": 1,
+                }),
+            ),
+            "/token" => {
+                let grant = form["grant_type"].clone();
+                state.grants.push(grant.clone());
+                if let Some(authorization) = request_header(&head, "authorization") {
+                    state.token_authorizations.push(authorization);
+                }
+
+                match grant.as_str() {
+                    "authorization_code" => {
+                        state.code_verified =
+                            form["code"] == "code-1" && form.contains_key("code_verifier");
+                    }
+                    "refresh_token" => state.refresh_tokens.push(form["refresh_token"].clone()),
+                    "urn:ietf:params:oauth:grant-type:device_code" => state.device_polls += 1,
+                    _ => {}
+                }
+
+                if state.device_polls == 1 {
+                    json_response(
+                        "400 Bad Request",
+                        json!({ "error": "authorization_pending" }),
+                    )
+                } else {
+                    state.issued += 1;
+                    json_response(
+                        "200 OK",
+                        json!({
+                            "access_token": format!("token-{}", state.issued),
+                            "token_type": "bearer",
+                            "expires_in": expires_in,
+                            "refresh_token": format!("refresh-{}", state.issued),
+                        }),
+                    )
+                }
+            }
+            "/api" => {
+                state
+                    .api_authorizations
+                    .push(request_header(&head, "authorization").unwrap_or_default());
+                "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok".to_string()
+            }
+            _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                .to_string(),
+        }
+    };
+
+    socket.write_all(response.as_bytes()).await.unwrap();
+    socket.shutdown().await.unwrap();
+}
+
+fn request_header(head: &str, name: &str) -> Option<String> {
+    head.lines().skip(1).find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.eq_ignore_ascii_case(name)
+            .then(|| value.trim().to_string())
+    })
+}
+
+fn json_response(status: &str, body: JsonValue) -> String {
+    let body = body.to_string();
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+fn oauth2_config(server: &AuthServer, grant: OAuth2Grant) -> OAuth2Config {
+    OAuth2Config {
+        grant,
+        auth_url: Some(server.url("/authorize")),
+        token_url: server.url("/token"),
+        device_auth_url: Some(server.url("/device")),
+        client_id: "client".to_string(),
+        client_secret: Some("{{client_secret}}".to_string()),
+        scopes: vec!["read".to_string()],
+        refresh_token: None,
+        callback_port: None,
+    }
+}
+
+async fn execute_oauth2_endpoint(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    delegate: &moss_app_delegate::AppDelegate<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    resource_id: &ResourceId,
+) {
+    let output = main_window
+        .execute_resource(
+            ctx,
+            delegate,
+            ExecuteResourceInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+            },
+            Channel::new(|_| Ok(())),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.status, 200);
+}
+
+async fn create_active_environment(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    client_secret: &str,
+) {
+    let id = main_window
+        .create_environment(
+            ctx,
+            CreateEnvironmentInput {
+                project_id: None,
+                name: random_entry_name(),
+                color: None,
+                variables: vec![AddVariableParams {
+                    name: "client_secret".to_string(),
+                    global_value: json!(client_secret),
+                    local_value: JsonValue::Null,
+                    order: 0,
+                    desc: None,
+                    options: VariableOptions { disabled: false },
+                }],
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    main_window
+        .activate_environment(
+            ctx,
+            ActivateEnvironmentInput {
+                project_id: None,
+                environment_id: id,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+async fn create_oauth2_endpoint(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    server: &AuthServer,
+    config: OAuth2Config,
+) -> ResourceId {
+    create_test_endpoint(
+        main_window,
+        ctx,
+        project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some(server.url("/api")),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: Some(AuthScheme::OAuth2(config)),
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await
+}
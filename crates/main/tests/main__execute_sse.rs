@@ -0,0 +1,471 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, sse::SseParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::{
+        project::{CreateProjectInput, CreateProjectParams},
+        sse::{ExecuteSseInput, ListSseEventsInput, SseEvent},
+    },
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+const EVENT_STREAM_HEADERS: &str =
+    "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n";
+
+/// `Last-Event-ID` headers of the requests received by the test server, in order.
+type LastEventIds = Arc<Mutex<Vec<Option<String>>>>;
+
+#[tokio::test]
+async fn execute_sse_reconnects_from_last_event() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (port, last_event_ids) = spawn_sse_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            &format!("http://127.0.0.1:{port}/events"),
+            Some(SseParams {
+                reconnect: true,
+                retry_ms: Some(5000),
+                max_reconnects: Some(3),
+                record: true,
+            }),
+        ),
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let output = main_window
+        .execute_sse(
+            &ctx,
+            &delegate,
+            ExecuteSseInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.received, 3);
+    assert_eq!(output.reconnects, 2);
+    assert_eq!(output.last_event_id.as_deref(), Some("3"));
+
+    // The server stops the stream with a 204 once reconnected a second time
+    assert_eq!(
+        *last_event_ids.lock().unwrap(),
+        vec![None, Some("2".to_string()), Some("3".to_string())]
+    );
+
+    let events = events.lock().unwrap().clone();
+    let kinds = events
+        .iter()
+        .map(|event| event.as_object().unwrap().keys().next().unwrap().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            "connected",
+            "event",
+            "event",
+            "reconnecting",
+            "connected",
+            "event",
+            "reconnecting",
+        ]
+    );
+    assert_eq!(events[0]["connected"]["status"], 200);
+
+    // The delay set by the server takes precedence over the one of the endpoint
+    assert_eq!(
+        events[3]["reconnecting"],
+        json!({ "attempt": 1, "delayMs": 20, "lastEventId": "2" })
+    );
+
+    let recorded = main_window
+        .list_sse_events(
+            &ctx,
+            &delegate,
+            ListSseEventsInput {
+                project_id,
+                resource_id,
+            },
+        )
+        .await
+        .unwrap()
+        .events;
+    let recorded = recorded
+        .iter()
+        .map(|event| {
+            (
+                event.seq,
+                event.event.as_str(),
+                event.data.as_str(),
+                event.id.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        recorded,
+        vec![
+            (1, "message", "one", Some("1")),
+            (2, "update", "two\nlines", Some("2")),
+            (3, "message", "three", Some("3")),
+        ]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_sse_without_reconnecting() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (port, last_event_ids) = spawn_sse_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            &format!("http://127.0.0.1:{port}/events"),
+            Some(SseParams {
+                reconnect: false,
+                retry_ms: None,
+                max_reconnects: None,
+                record: false,
+            }),
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    let output = main_window
+        .execute_sse(
+            &ctx,
+            &delegate,
+            ExecuteSseInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.received, 2);
+    assert_eq!(output.reconnects, 0);
+    assert_eq!(last_event_ids.lock().unwrap().len(), 1);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_sse_canceled() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (port, _) = spawn_sse_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            &format!("http://127.0.0.1:{port}/forever"),
+            Some(SseParams {
+                reconnect: true,
+                retry_ms: None,
+                max_reconnects: None,
+                record: true,
+            }),
+        ),
+    )
+    .await;
+
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("sse", request_ctx.get_canceller())
+        .await;
+
+    let (channel, events) = test_channel();
+    let window_clone = main_window.clone();
+    let delegate_clone = delegate.clone();
+    let input = ExecuteSseInput {
+        project_id: project_id.clone(),
+        resource_id: resource_id.clone(),
+    };
+    let stream = tokio::spawn(async move {
+        window_clone
+            .execute_sse(&request_ctx, &delegate_clone, input, channel)
+            .await
+    });
+
+    wait_for_event(&events).await;
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "sse".to_string(),
+        })
+        .await
+        .unwrap();
+
+    // Stopping the stream isn't an error, and the events received so far are recorded
+    let output = tokio::time::timeout(Duration::from_secs(5), stream)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(output.received, 1);
+
+    let recorded = main_window
+        .list_sse_events(
+            &ctx,
+            &delegate,
+            ListSseEventsInput {
+                project_id,
+                resource_id,
+            },
+        )
+        .await
+        .unwrap()
+        .events;
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].data, "hello");
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn execute_sse_invalid() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (port, _) = spawn_sse_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let cases = [
+        // The response isn't an event stream
+        endpoint(
+            &format!("http://127.0.0.1:{port}/json"),
+            Some(SseParams {
+                reconnect: true,
+                retry_ms: None,
+                max_reconnects: None,
+                record: false,
+            }),
+        ),
+        // The endpoint has no sse settings
+        endpoint(&format!("http://127.0.0.1:{port}/events"), None),
+    ];
+    for params in cases {
+        let resource_id = create_test_item(&main_window, &ctx, &project_id, params).await;
+
+        let (channel, _) = test_channel();
+        let result = main_window
+            .execute_sse(
+                &ctx,
+                &delegate,
+                ExecuteSseInput {
+                    project_id: project_id.clone(),
+                    resource_id,
+                },
+                channel,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    cleanup().await;
+}
+
+/// Serves every request on its own connection:
+/// - `/events` streams two events, one more once reconnected, then stops the stream with
+///   `204 No Content`
+/// - `/forever` streams one event and keeps the connection open
+/// - anything else responds with JSON
+async fn spawn_sse_server() -> (u16, LastEventIds) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let last_event_ids = LastEventIds::default();
+
+    let last_event_ids_clone = last_event_ids.clone();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve_connection(stream, last_event_ids_clone.clone()));
+        }
+    });
+
+    (port, last_event_ids)
+}
+
+async fn serve_connection(mut stream: TcpStream, last_event_ids: LastEventIds) {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => request.extend_from_slice(&buf[..n]),
+        }
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+    let last_event_id = request.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("last-event-id")
+            .then(|| value.trim().to_string())
+    });
+
+    let response = match path {
+        "/events" => {
+            let connection = {
+                let mut last_event_ids = last_event_ids.lock().unwrap();
+                last_event_ids.push(last_event_id);
+                last_event_ids.len()
+            };
+            match connection {
+                1 => format!(
+                    "{EVENT_STREAM_HEADERS}retry: 20\n\n\
+                     id: 1\ndata: one\n\n\
+                     id: 2\nevent: update\ndata: two\ndata: lines\n\n"
+                ),
+                2 => format!("{EVENT_STREAM_HEADERS}: keep-alive\n\nid: 3\ndata: three\n\n"),
+                _ => "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_string(),
+            }
+        }
+        "/forever" => {
+            let _ = stream
+                .write_all(format!("{EVENT_STREAM_HEADERS}data: hello\n\n").as_bytes())
+                .await;
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            return;
+        }
+        _ => "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}"
+            .to_string(),
+    };
+
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+async fn wait_for_event(events: &Arc<Mutex<Vec<JsonValue>>>) {
+    for _ in 0..100 {
+        if events
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|event| event.get("event").is_some())
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("sse event was not received");
+}
+
+fn test_channel() -> (Channel<SseEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+fn endpoint(url: &str, sse: Option<SseParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(ResourceProtocol::Get),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
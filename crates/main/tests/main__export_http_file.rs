@@ -0,0 +1,140 @@
+#![cfg(feature = "integration-tests")]
+
+use sapic_base::resource::types::primitives::ResourceKind;
+use sapic_ipc::contracts::main::{
+    project::{ImportOpenApiParams, ImportProjectInput, ImportProjectParams, ImportProjectSource},
+    resource::{ExportHttpFileInput, ListProjectResourcesInput, ListProjectResourcesMode},
+};
+
+use crate::shared::{PETSTORE_OPENAPI_SPEC, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn export_http_file_success() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let spec_path = test_path.join("petstore.yaml");
+    tokio::fs::write(&spec_path, PETSTORE_OPENAPI_SPEC)
+        .await
+        .unwrap();
+    let project_id = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::OpenApi(ImportOpenApiParams { spec_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: project_id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let dir = resources
+        .items
+        .iter()
+        .find(|item| item.kind == ResourceKind::Dir && item.name == "pets")
+        .unwrap();
+
+    let path = main_window
+        .export_http_file(
+            &ctx,
+            &delegate,
+            &ExportHttpFileInput {
+                project_id,
+                resource_id: dir.id.clone(),
+                destination: test_path.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .path;
+    assert_eq!(path, test_path.join("pets.http"));
+
+    let content = tokio::fs::read_to_string(&path).await.unwrap();
+    // The optional `limit` query param is imported disabled, and so isn't written
+    assert_eq!(
+        content,
+        "### Create pet\n\
+         POST https://petstore.example.com/v1/pets\n\
+         Content-Type: application/json\n\
+         \n\
+         {\n  \"name\": \"string\"\n}\n\
+         \n\
+         ### Get pet\n\
+         GET https://petstore.example.com/v1/pets/{{petId}}\n\
+         \n\
+         ### List pets\n\
+         GET https://petstore.example.com/v1/pets\n"
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn export_http_file_not_a_dir() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let spec_path = test_path.join("petstore.yaml");
+    tokio::fs::write(&spec_path, PETSTORE_OPENAPI_SPEC)
+        .await
+        .unwrap();
+    let project_id = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::OpenApi(ImportOpenApiParams { spec_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: project_id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let item = resources
+        .items
+        .iter()
+        .find(|item| item.kind == ResourceKind::Item)
+        .unwrap();
+
+    let result = main_window
+        .export_http_file(
+            &ctx,
+            &delegate,
+            &ExportHttpFileInput {
+                project_id,
+                resource_id: item.id.clone(),
+                destination: test_path.clone(),
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
@@ -1,19 +1,42 @@
 #![cfg(feature = "integration-tests")]
 
+use main::{MainWindow, export::describe_resources};
+use moss_app_delegate::AppDelegate;
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, http::AddBodyParams},
+};
 use moss_testutils::random_name::random_project_name;
-use sapic_ipc::contracts::main::project::{
-    CreateProjectInput, CreateProjectParams, ExportProjectInput, ExportProjectParams,
-    ImportArchiveParams, ImportProjectInput, ImportProjectParams, ImportProjectSource,
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    project::{
+        CreateProjectInput, CreateProjectParams, ExportProjectFormat, ExportProjectInput,
+        ExportProjectParams, ImportArchiveParams, ImportHarParams, ImportOpenApiParams,
+        ImportProjectInput, ImportProjectParams, ImportProjectSource,
+    },
+    resource::ExecuteResourceInput,
+};
+use serde_json::{Value as JsonValue, json};
+use std::path::{Path, PathBuf};
+use tauri::ipc::Channel;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
 };
 
-use crate::shared::{set_up_test_main_window, test_list_projects};
+use crate::shared::{PETSTORE_OPENAPI_SPEC, set_up_test_main_window, test_list_projects};
 
 mod shared;
 
 // Create an archive file from a project and import it back
 #[tokio::test]
 async fn export_project_success() {
-    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
 
     let project_name = random_project_name();
     let id = main_window
@@ -41,10 +64,12 @@ async fn export_project_success() {
     let archive_path = main_window
         .export_project(
             &ctx,
+            &delegate,
             &ExportProjectInput {
                 inner: ExportProjectParams {
                     id,
                     destination: archive_destination.clone(),
+                    format: ExportProjectFormat::Archive,
                 },
             },
         )
@@ -72,3 +97,379 @@ async fn export_project_success() {
 
     cleanup().await;
 }
+
+async fn import_openapi_project(
+    main_window: &MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    name: &str,
+    spec_path: PathBuf,
+) -> ProjectId {
+    let output = main_window
+        .import_project(
+            ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: name.to_string(),
+                    source: ImportProjectSource::OpenApi(ImportOpenApiParams { spec_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    output.id
+}
+
+async fn export_openapi_document(
+    main_window: &MainWindow<MockAppRuntime>,
+    delegate: &AppDelegate<MockAppRuntime>,
+    ctx: &ArcContext,
+    id: ProjectId,
+    destination: &Path,
+    format: ExportProjectFormat,
+) -> PathBuf {
+    main_window
+        .export_project(
+            ctx,
+            delegate,
+            &ExportProjectInput {
+                inner: ExportProjectParams {
+                    id,
+                    destination: destination.to_path_buf(),
+                    format,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .archive_path
+}
+
+/// The parts of the resources an openapi document describes, without their ids.
+async fn openapi_snapshot(
+    main_window: &MainWindow<MockAppRuntime>,
+    delegate: &AppDelegate<MockAppRuntime>,
+    ctx: &ArcContext,
+    id: &ProjectId,
+) -> Vec<JsonValue> {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, id)
+        .await
+        .unwrap();
+
+    let entries = |values: Vec<(String, JsonValue, bool)>| {
+        values
+            .into_iter()
+            .map(|(name, value, disabled)| json!([name, value, disabled]))
+            .collect::<Vec<_>>()
+    };
+
+    describe_resources(ctx, delegate, &project)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|resource| {
+            let description = resource.description;
+            json!({
+                "path": resource.path,
+                "name": description.name,
+                "class": description.class,
+                "protocol": description.protocol,
+                "url": description.url,
+                "headers": entries(description.headers.into_iter().map(|h| (h.name, h.value, h.disabled)).collect()),
+                "pathParams": entries(description.path_params.into_iter().map(|p| (p.name, p.value, p.disabled)).collect()),
+                "queryParams": entries(description.query_params.into_iter().map(|p| (p.name, p.value, p.disabled)).collect()),
+                "body": description.body,
+                "schema": description.schema,
+            })
+        })
+        .collect()
+}
+
+// Import the importer fixture, export it and import the export again
+#[tokio::test]
+async fn export_project_openapi_round_trip() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let spec_path = test_path.join("petstore.yaml");
+    tokio::fs::write(&spec_path, PETSTORE_OPENAPI_SPEC)
+        .await
+        .unwrap();
+    let original_id = import_openapi_project(&main_window, &ctx, "Petstore", spec_path).await;
+
+    let destination = test_path.join("export");
+    tokio::fs::create_dir_all(&destination).await.unwrap();
+    let document_path = export_openapi_document(
+        &main_window,
+        &delegate,
+        &ctx,
+        original_id.clone(),
+        &destination,
+        ExportProjectFormat::OpenApiYaml,
+    )
+    .await;
+    assert_eq!(document_path.extension().unwrap(), "yaml");
+
+    let round_trip_id =
+        import_openapi_project(&main_window, &ctx, "Petstore Round Trip", document_path).await;
+
+    let original = openapi_snapshot(&main_window, &delegate, &ctx, &original_id).await;
+    let round_trip = openapi_snapshot(&main_window, &delegate, &ctx, &round_trip_id).await;
+    assert!(!original.is_empty());
+    assert_eq!(original, round_trip);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn export_project_openapi_json() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let spec_path = test_path.join("petstore.yaml");
+    tokio::fs::write(&spec_path, PETSTORE_OPENAPI_SPEC)
+        .await
+        .unwrap();
+    let id = import_openapi_project(&main_window, &ctx, "Petstore", spec_path).await;
+
+    let document_path = export_openapi_document(
+        &main_window,
+        &delegate,
+        &ctx,
+        id,
+        &test_path,
+        ExportProjectFormat::OpenApiJson,
+    )
+    .await;
+
+    let content = tokio::fs::read_to_string(&document_path).await.unwrap();
+    let document: JsonValue = serde_json::from_str(&content).unwrap();
+    assert_eq!(document["openapi"], json!("3.1.0"));
+    assert_eq!(document["info"]["title"], json!("Petstore"));
+    assert_eq!(
+        document["servers"],
+        json!([{ "url": "https://petstore.example.com" }])
+    );
+    assert_eq!(document["tags"], json!([{ "name": "pets" }]));
+    assert_eq!(
+        document["paths"]["/v1/pets/{petId}"]["get"]["parameters"][0]["in"],
+        json!("path")
+    );
+    assert_eq!(
+        document["components"]["schemas"]["Pet"]["properties"]["name"]["type"],
+        json!(["string", "null"])
+    );
+
+    cleanup().await;
+}
+
+// Export the requests sent to the project as a HAR log and import it back
+#[tokio::test]
+async fn export_project_har_round_trip() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let id = main_window
+        .create_project(
+            &ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: random_project_name(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let addr = spawn_test_server(
+        "HTTP/1.1 201 Created\r\nContent-Type: application/json\r\nContent-Length: 8\r\nConnection: close\r\n\r\n{\"id\":1}",
+    )
+    .await;
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(&ctx, &id)
+        .await
+        .unwrap();
+    let resource_id = project
+        .handle
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                path: PathBuf::from(""),
+                class: ResourceClass::Endpoint,
+                name: "Create pet".to_string(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Post),
+                url: Some(format!("http://{addr}/pets")),
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: Some(AddBodyParams::Json(json!({ "name": "Rex" }))),
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    main_window
+        .execute_resource(
+            &ctx,
+            &delegate,
+            ExecuteResourceInput {
+                project_id: id.clone(),
+                resource_id,
+            },
+            Channel::new(|_| Ok(())),
+        )
+        .await
+        .unwrap();
+
+    let har_path = main_window
+        .export_project(
+            &ctx,
+            &delegate,
+            &ExportProjectInput {
+                inner: ExportProjectParams {
+                    id: id.clone(),
+                    destination: test_path.clone(),
+                    format: ExportProjectFormat::Har,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .archive_path;
+    assert_eq!(har_path, test_path.join(format!("{}.har", id)));
+
+    let content = tokio::fs::read_to_string(&har_path).await.unwrap();
+    let har: JsonValue = serde_json::from_str(&content).unwrap();
+    let entry = &har["log"]["entries"][0];
+    assert_eq!(har["log"]["version"], json!("1.2"));
+    assert_eq!(entry["request"]["method"], json!("POST"));
+    assert_eq!(
+        entry["request"]["url"],
+        json!(format!("http://{addr}/pets"))
+    );
+    assert_eq!(
+        entry["request"]["postData"]["text"],
+        json!(r#"{"name":"Rex"}"#)
+    );
+    assert_eq!(entry["response"]["status"], json!(201));
+    assert_eq!(entry["response"]["content"]["text"], json!(r#"{"id":1}"#));
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Imported".to_string(),
+                    source: ImportProjectSource::Har(ImportHarParams {
+                        file_path: har_path,
+                        entries: None,
+                    }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+    assert!(output.skipped.is_empty());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn export_project_har_without_executions() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let id = main_window
+        .create_project(
+            &ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: random_project_name(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let result = main_window
+        .export_project(
+            &ctx,
+            &delegate,
+            &ExportProjectInput {
+                inner: ExportProjectParams {
+                    id: id.clone(),
+                    destination: test_path.clone(),
+                    format: ExportProjectFormat::Har,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+    assert!(!test_path.join(format!("{}.har", id)).exists());
+
+    cleanup().await;
+}
+
+/// Serves a single connection with the given raw response, once the whole request is read.
+async fn spawn_test_server(response: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+
+        loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+
+            let text = String::from_utf8_lossy(&request).to_string();
+            let Some(head_end) = text.find("\r\n\r\n") else {
+                if n == 0 {
+                    break;
+                }
+                continue;
+            };
+            let content_length = text[..head_end]
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length: "))
+                .map(|len| len.trim().parse::<usize>().unwrap())
+                .unwrap_or(0);
+            if n == 0 || request.len() >= head_end + 4 + content_length {
+                break;
+            }
+        }
+
+        socket.write_all(response.as_bytes()).await.unwrap();
+        socket.shutdown().await.unwrap();
+    });
+
+    addr
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_environment::models::types::{AddVariableParams, VariableOptions};
+use sapic_base::project::types::primitives::ProjectId;
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    environment::{ActivateEnvironmentInput, CreateEnvironmentInput},
+    project::{CreateProjectInput, CreateProjectParams},
+    resource::{GenerateSnippetInput, ImportCurlInput, SnippetLanguage},
+};
+use serde_json::{Value as JsonValue, json};
+use std::path::PathBuf;
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn generate_snippet_success() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    create_active_environment(
+        &main_window,
+        &ctx,
+        Some(project_id.clone()),
+        vec![
+            variable("host", json!("localhost:8080")),
+            variable("token", json!("secret")),
+        ],
+    )
+    .await;
+
+    let resource_id = main_window
+        .import_curl(
+            &ctx,
+            &ImportCurlInput {
+                project_id: project_id.clone(),
+                path: PathBuf::new(),
+                order: 0,
+                name: Some(random_entry_name()),
+                command: "curl 'https://{{host}}/pets?limit=10' --oauth2-bearer '{{token}}'"
+                    .to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let mut input = GenerateSnippetInput {
+        project_id,
+        resource_id,
+        language: SnippetLanguage::Curl,
+        resolve_variables: false,
+    };
+
+    let snippet = main_window
+        .generate_snippet(&ctx, &delegate, &input)
+        .await
+        .unwrap()
+        .snippet;
+    assert_eq!(
+        snippet,
+        "curl 'https://{{host}}/pets?limit=10' \\\n  -H 'Authorization: Bearer {{token}}'\n"
+    );
+
+    input.resolve_variables = true;
+    let snippet = main_window
+        .generate_snippet(&ctx, &delegate, &input)
+        .await
+        .unwrap()
+        .snippet;
+    assert_eq!(
+        snippet,
+        "curl 'https://localhost:8080/pets?limit=10' \\\n  -H 'Authorization: Bearer secret'\n"
+    );
+
+    cleanup().await;
+}
+
+async fn create_active_environment(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: Option<ProjectId>,
+    variables: Vec<AddVariableParams>,
+) {
+    let id = main_window
+        .create_environment(
+            ctx,
+            CreateEnvironmentInput {
+                project_id: project_id.clone(),
+                name: random_entry_name(),
+                color: None,
+                variables,
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    main_window
+        .activate_environment(
+            ctx,
+            ActivateEnvironmentInput {
+                project_id,
+                environment_id: id,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+fn variable(name: &str, global_value: JsonValue) -> AddVariableParams {
+    AddVariableParams {
+        name: name.to_string(),
+        global_value,
+        local_value: JsonValue::Null,
+        order: 0,
+        desc: None,
+        options: VariableOptions { disabled: false },
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::types::{AuthScheme, BodyInfo};
+use sapic_base::{
+    project::types::primitives::ProjectId, resource::types::primitives::ResourceProtocol,
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    project::{CreateProjectInput, CreateProjectParams},
+    resource::ImportCurlInput,
+};
+use serde_json::json;
+use std::path::PathBuf;
+
+use crate::shared::set_up_test_main_window;
+
+mod shared;
+
+#[tokio::test]
+async fn import_curl_success() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let output = main_window
+        .import_curl(
+            &ctx,
+            &ImportCurlInput {
+                project_id: project_id.clone(),
+                path: PathBuf::new(),
+                order: 0,
+                name: Some("Create pet".to_string()),
+                command: "curl 'https://petstore.example.com/v1/pets?verbose=true' \\\n  \
+                          -H 'Accept: application/json' \\\n  \
+                          -H 'Content-Type: application/json' \\\n  \
+                          -u admin:hunter2 \\\n  \
+                          --data-raw '{\"name\":\"Rex\"}' \\\n  \
+                          --compressed"
+                    .to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.name, "Create pet");
+    // The password can't be stored inline
+    assert_eq!(output.skipped.len(), 1);
+    assert_eq!(output.skipped[0].pointer, "/6");
+
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(&ctx, &project_id)
+        .await
+        .unwrap();
+    let desc = project
+        .describe_resource(&ctx, &delegate, output.id)
+        .await
+        .unwrap();
+
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Post));
+    assert_eq!(
+        desc.url.as_deref(),
+        Some("https://petstore.example.com/v1/pets")
+    );
+    assert_eq!(desc.query_params.len(), 1);
+    assert_eq!(desc.query_params[0].name, "verbose");
+    assert_eq!(desc.headers.len(), 1);
+    assert_eq!(desc.headers[0].name, "Accept");
+    assert_eq!(desc.body, Some(BodyInfo::Json(json!({ "name": "Rex" }))));
+    assert_eq!(
+        desc.auth.map(|auth| auth.scheme),
+        Some(AuthScheme::Basic {
+            username: "admin".to_string(),
+            password: "{{password}}".to_string(),
+        })
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_curl_invalid_command() {
+    let (main_window, _, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let result = main_window
+        .import_curl(
+            &ctx,
+            &ImportCurlInput {
+                project_id,
+                path: PathBuf::new(),
+                order: 0,
+                name: None,
+                command: "curl -H 'Accept: application/json'".to_string(),
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
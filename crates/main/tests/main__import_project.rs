@@ -1,12 +1,19 @@
 #![cfg(feature = "integration-tests")]
 
 use moss_testutils::random_name::random_project_name;
-use sapic_ipc::contracts::main::project::{
-    CreateProjectInput, CreateProjectParams, ImportDiskParams, ImportProjectInput,
-    ImportProjectParams, ImportProjectSource,
+use sapic_base::resource::types::primitives::ResourceClass;
+use sapic_ipc::contracts::main::{
+    project::{
+        CreateProjectInput, CreateProjectParams, ImportBrunoParams, ImportDiskParams,
+        ImportHarParams, ImportHttpFileParams, ImportInsomniaParams, ImportOpenApiParams,
+        ImportPostmanParams, ImportProjectInput, ImportProjectParams, ImportProjectSource,
+    },
+    resource::{ListProjectResourcesInput, ListProjectResourcesMode},
 };
 
-use crate::shared::{set_up_test_main_window, test_list_projects};
+use crate::shared::{
+    PETSTORE_OPENAPI_SPEC, set_up_test_main_window, test_list_environments, test_list_projects,
+};
 
 mod shared;
 
@@ -52,3 +59,759 @@ async fn import_external_project_success() {
     assert_eq!(output.items.len(), 2);
     cleanup().await;
 }
+
+#[tokio::test]
+async fn import_openapi_project_success() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let spec_path = test_path.join("petstore.yaml");
+    tokio::fs::write(&spec_path, PETSTORE_OPENAPI_SPEC)
+        .await
+        .unwrap();
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::OpenApi(ImportOpenApiParams { spec_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.skipped.len(), 1);
+    assert_eq!(output.skipped[0].pointer, "/paths/~1pets/post/callbacks");
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: output.id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+
+    let endpoints = resources
+        .items
+        .iter()
+        .filter(|item| item.class == ResourceClass::Endpoint)
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    assert!(endpoints.contains(&"pets"));
+    assert!(endpoints.contains(&"List pets"));
+    assert!(endpoints.contains(&"Create pet"));
+    assert!(endpoints.contains(&"Get pet"));
+    assert!(
+        resources
+            .items
+            .iter()
+            .any(|item| item.class == ResourceClass::Schema && item.name == "Pet")
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_openapi_project_unsupported_version() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let spec_path = test_path.join("swagger.json");
+    tokio::fs::write(&spec_path, r#"{ "swagger": "2.0", "paths": {} }"#)
+        .await
+        .unwrap();
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Swagger".to_string(),
+                    source: ImportProjectSource::OpenApi(ImportOpenApiParams { spec_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    cleanup().await;
+}
+
+const PETSTORE_POSTMAN_COLLECTION: &str = r#"{
+  "info": {
+    "name": "Petstore",
+    "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+  },
+  "auth": {
+    "type": "bearer",
+    "bearer": [{ "key": "token", "value": "{{token}}", "type": "string" }]
+  },
+  "variable": [{ "key": "baseUrl", "value": "https://petstore.example.com" }],
+  "item": [
+    {
+      "name": "Pets",
+      "item": [
+        {
+          "name": "List pets",
+          "event": [{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }],
+          "request": {
+            "method": "GET",
+            "url": {
+              "raw": "{{baseUrl}}/pets?limit=10",
+              "host": ["{{baseUrl}}"],
+              "path": ["pets"],
+              "query": [{ "key": "limit", "value": "10" }]
+            }
+          }
+        },
+        {
+          "name": "Get pet",
+          "request": {
+            "method": "GET",
+            "url": {
+              "raw": "{{baseUrl}}/pets/:petId",
+              "variable": [{ "key": "petId", "value": "1" }]
+            }
+          }
+        }
+      ]
+    }
+  ]
+}"#;
+
+#[tokio::test]
+async fn import_postman_project_success() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let collection_path = test_path.join("petstore.postman_collection.json");
+    tokio::fs::write(&collection_path, PETSTORE_POSTMAN_COLLECTION)
+        .await
+        .unwrap();
+    let environment_path = test_path.join("staging.postman_environment.json");
+    tokio::fs::write(
+        &environment_path,
+        r#"{
+          "name": "Staging",
+          "values": [{ "key": "token", "value": "secret", "type": "secret", "enabled": true }]
+        }"#,
+    )
+    .await
+    .unwrap();
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::Postman(ImportPostmanParams {
+                        collection_path,
+                        environment_paths: vec![environment_path],
+                    }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.skipped.len(), 1);
+    assert_eq!(output.skipped[0].pointer, "/item/0/item/0/event/0");
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: output.id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let names = resources
+        .items
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    for name in ["Petstore", "Pets", "List pets", "Get pet"] {
+        assert!(names.contains(&name));
+    }
+
+    let mut environments = test_list_environments(&ctx, &main_window, Some(output.id.clone()))
+        .await
+        .into_values()
+        .map(|environment| (environment.name, environment.total_variables))
+        .collect::<Vec<_>>();
+    environments.sort();
+    assert_eq!(
+        environments,
+        vec![("Petstore".to_string(), 1), ("Staging".to_string(), 1)]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_postman_project_invalid_environment() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let collection_path = test_path.join("petstore.postman_collection.json");
+    tokio::fs::write(&collection_path, PETSTORE_POSTMAN_COLLECTION)
+        .await
+        .unwrap();
+    let environment_path = test_path.join("staging.postman_environment.json");
+    tokio::fs::write(&environment_path, r#"{ "name": "Staging" }"#)
+        .await
+        .unwrap();
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::Postman(ImportPostmanParams {
+                        collection_path,
+                        environment_paths: vec![environment_path],
+                    }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    cleanup().await;
+}
+
+const PETSTORE_HTTP_FILE: &str = r#"@base-url = https://petstore.example.com/v1
+
+### List pets
+GET {{base-url}}/pets?limit=20
+Accept: application/json
+
+> {% client.test("ok", () => client.assert(response.status === 200)); %}
+
+### Upload photo
+PUT {{base-url}}/pets/1/photo
+Content-Type: image/png
+
+< ./photo.png
+"#;
+
+#[tokio::test]
+async fn import_http_file_project_success() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let file_path = test_path.join("petstore.http");
+    tokio::fs::write(&file_path, PETSTORE_HTTP_FILE)
+        .await
+        .unwrap();
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::HttpFile(ImportHttpFileParams { file_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.skipped.len(), 1);
+    assert_eq!(output.skipped[0].pointer, "/0");
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: output.id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let names = resources
+        .items
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    for name in ["List pets", "Upload photo"] {
+        assert!(names.contains(&name));
+    }
+
+    let environments = test_list_environments(&ctx, &main_window, Some(output.id.clone()))
+        .await
+        .into_values()
+        .map(|environment| (environment.name, environment.total_variables))
+        .collect::<Vec<_>>();
+    assert_eq!(environments, vec![("petstore".to_string(), 1)]);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_http_file_project_without_requests() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let file_path = test_path.join("variables.http");
+    tokio::fs::write(&file_path, "@base-url = https://petstore.example.com\n")
+        .await
+        .unwrap();
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Variables".to_string(),
+                    source: ImportProjectSource::HttpFile(ImportHttpFileParams { file_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_http_file_project_rolls_back_failed_resources() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    // The second request is named beyond what the file system allows, so it fails
+    // once the first one is already created
+    let file_path = test_path.join("petstore.http");
+    tokio::fs::write(
+        &file_path,
+        format!(
+            "### List pets\nGET https://petstore.example.com/v1/pets\n\n### {}\nGET https://petstore.example.com/v1/pets/1\n",
+            "x".repeat(300)
+        ),
+    )
+    .await
+    .unwrap();
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::HttpFile(ImportHttpFileParams { file_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    // Nothing of the project is left in the workspace
+    for workspace in std::fs::read_dir(delegate.workspaces_dir()).unwrap() {
+        let projects_path = workspace.unwrap().path().join("projects");
+        if projects_path.exists() {
+            assert_eq!(std::fs::read_dir(&projects_path).unwrap().count(), 0);
+        }
+    }
+
+    cleanup().await;
+}
+
+const PETSTORE_HAR: &str = r#"{
+  "log": {
+    "version": "1.2",
+    "creator": { "name": "WebInspector", "version": "537.36" },
+    "entries": [
+      {
+        "startedDateTime": "2025-01-02T03:04:05.000Z",
+        "request": {
+          "method": "GET",
+          "url": "https://petstore.example.com/v1/pets?limit=10",
+          "httpVersion": "http/2.0",
+          "headers": [
+            { "name": ":authority", "value": "petstore.example.com" },
+            { "name": "accept", "value": "application/json" }
+          ],
+          "queryString": [{ "name": "limit", "value": "10" }]
+        }
+      },
+      {
+        "startedDateTime": "2025-01-02T03:04:06.000Z",
+        "request": {
+          "method": "GET",
+          "url": "https://petstore.example.com/v1/pets/?limit=20",
+          "httpVersion": "http/2.0",
+          "headers": []
+        }
+      },
+      {
+        "startedDateTime": "2025-01-02T03:04:07.000Z",
+        "request": {
+          "method": "POST",
+          "url": "https://petstore.example.com/v1/pets",
+          "httpVersion": "http/2.0",
+          "headers": [{ "name": "content-type", "value": "application/json" }],
+          "postData": { "mimeType": "application/json", "text": "{\"name\":\"Rex\"}" }
+        }
+      }
+    ]
+  }
+}"#;
+
+#[tokio::test]
+async fn import_har_project_success() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let file_path = test_path.join("petstore.har");
+    tokio::fs::write(&file_path, PETSTORE_HAR).await.unwrap();
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::Har(ImportHarParams {
+                        file_path,
+                        entries: None,
+                    }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.skipped.len(), 1);
+    assert_eq!(output.skipped[0].pointer, "/log/entries/1");
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: output.id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let mut endpoints = resources
+        .items
+        .iter()
+        .filter(|item| item.class == ResourceClass::Endpoint)
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    endpoints.sort();
+    assert_eq!(
+        endpoints,
+        vec!["GET /v1/pets", "POST /v1/pets", "petstore.example.com"]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_har_project_unknown_entry() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let file_path = test_path.join("petstore.har");
+    tokio::fs::write(&file_path, PETSTORE_HAR).await.unwrap();
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::Har(ImportHarParams {
+                        file_path,
+                        entries: Some(vec![0, 3]),
+                    }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    cleanup().await;
+}
+
+const PETSTORE_INSOMNIA_EXPORT: &str = r#"
+_type: export
+__export_format: 4
+__export_source: insomnia.desktop.app:v2023.5.8
+resources:
+  - _id: wrk_1
+    _type: workspace
+    parentId: null
+    name: Petstore
+  - _id: fld_1
+    _type: request_group
+    parentId: wrk_1
+    name: Pets
+    metaSortKey: -1
+  - _id: req_1
+    _type: request
+    parentId: fld_1
+    name: List pets
+    method: GET
+    url: "{{ _.base_url }}/pets"
+    metaSortKey: 1
+  - _id: req_2
+    _type: request
+    parentId: fld_1
+    name: Create pet
+    method: POST
+    url: "{{ _.base_url }}/pets"
+    metaSortKey: 2
+    body:
+      mimeType: application/json
+      text: '{"name": "Rex"}'
+    authentication:
+      type: bearer
+      token: "{{ _.token }}"
+  - _id: greq_1
+    _type: grpc_request
+    parentId: wrk_1
+    name: Watch pets
+  - _id: env_1
+    _type: environment
+    parentId: wrk_1
+    name: Base Environment
+    data:
+      base_url: https://petstore.example.com
+  - _id: env_2
+    _type: environment
+    parentId: env_1
+    name: Staging
+    data:
+      token: secret
+"#;
+
+#[tokio::test]
+async fn import_insomnia_project_success() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let file_path = test_path.join("petstore.yaml");
+    tokio::fs::write(&file_path, PETSTORE_INSOMNIA_EXPORT)
+        .await
+        .unwrap();
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::Insomnia(ImportInsomniaParams { file_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.skipped.len(), 1);
+    assert_eq!(output.skipped[0].pointer, "/resources/4");
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: output.id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let mut names = resources
+        .items
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["Create pet", "List pets", "Pets"]);
+
+    let environments = test_list_environments(&ctx, &main_window, Some(output.id.clone()))
+        .await
+        .into_values()
+        .map(|environment| (environment.name, environment.total_variables))
+        .collect::<Vec<_>>();
+    assert_eq!(environments, vec![("Staging".to_string(), 2)]);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_insomnia_project_rolls_back_failed_resources() {
+    let (main_window, delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    // The second request is named beyond what the file system allows, so it fails
+    // once the project and the first request are already created
+    let file_path = test_path.join("petstore.yaml");
+    let export = PETSTORE_INSOMNIA_EXPORT.replace("Create pet", &"x".repeat(300));
+    tokio::fs::write(&file_path, export).await.unwrap();
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Petstore".to_string(),
+                    source: ImportProjectSource::Insomnia(ImportInsomniaParams { file_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    // The project files are rolled back along with the resources
+    for workspace in std::fs::read_dir(delegate.workspaces_dir()).unwrap() {
+        let projects_path = workspace.unwrap().path().join("projects");
+        if projects_path.exists() {
+            assert_eq!(std::fs::read_dir(&projects_path).unwrap().count(), 0);
+        }
+    }
+
+    cleanup().await;
+}
+
+async fn write_bruno_collection(collection_path: &std::path::Path, request: &str) {
+    for (path, content) in [
+        (
+            "bruno.json",
+            r#"{ "version": "1", "name": "Users", "type": "collection" }"#,
+        ),
+        (
+            "users/folder.bru",
+            "meta {\n  name: User accounts\n  seq: 1\n}\n",
+        ),
+        ("users/Get user.bru", request),
+        (
+            "environments/Local.bru",
+            "vars {\n  baseUrl: http://localhost:3000\n}\n\nvars:secret [\n  token\n]\n",
+        ),
+        ("node_modules/lib/ignored.bru", "not a bru file"),
+    ] {
+        let path = collection_path.join(path);
+        tokio::fs::create_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(path, content).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn import_bruno_project_success() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let collection_path = test_path.join("users");
+    write_bruno_collection(
+        &collection_path,
+        "meta {\n  name: Get user\n  type: http\n  seq: 1\n}\n\n\
+         get {\n  url: {{baseUrl}}/users/:id\n  body: none\n  auth: bearer\n}\n\n\
+         params:path {\n  id: 42\n}\n\nauth:bearer {\n  token: {{token}}\n}\n",
+    )
+    .await;
+
+    let output = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Users".to_string(),
+                    source: ImportProjectSource::Bruno(ImportBrunoParams { collection_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(output.skipped.is_empty());
+
+    let resources = main_window
+        .list_project_resources(
+            &ctx,
+            ListProjectResourcesInput {
+                project_id: output.id.clone(),
+                mode: ListProjectResourcesMode::LoadRoot,
+            },
+        )
+        .await
+        .unwrap();
+    let mut names = resources
+        .items
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["Get user", "User accounts"]);
+
+    let environments = test_list_environments(&ctx, &main_window, Some(output.id.clone()))
+        .await
+        .into_values()
+        .map(|environment| (environment.name, environment.total_variables))
+        .collect::<Vec<_>>();
+    assert_eq!(environments, vec![("Local".to_string(), 2)]);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn import_bruno_project_invalid_file() {
+    let (main_window, _delegate, ctx, cleanup, test_path) = set_up_test_main_window().await;
+
+    let collection_path = test_path.join("users");
+    write_bruno_collection(&collection_path, "meta {\n  name: Get user\n").await;
+
+    let result = main_window
+        .import_project(
+            &ctx,
+            &ImportProjectInput {
+                inner: ImportProjectParams {
+                    name: "Users".to_string(),
+                    source: ImportProjectSource::Bruno(ImportBrunoParams { collection_path }),
+                    icon_path: None,
+                },
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    let output = test_list_projects(&main_window, &ctx).await;
+    assert!(output.items.is_empty());
+
+    cleanup().await;
+}
@@ -0,0 +1,239 @@
+#![cfg(feature = "integration-tests")]
+
+use async_graphql::{EmptyMutation, Object, Schema, SimpleObject, Subscription};
+use futures::Stream;
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    Method, Request, Response,
+    body::{Bytes, Incoming},
+    header::CONTENT_TYPE,
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, graphql::GraphqlParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    graphql::IntrospectGraphqlSchemaInput,
+    project::{CreateProjectInput, CreateProjectParams},
+};
+use serde_json::json;
+use std::{convert::Infallible, path::PathBuf};
+use tokio::net::TcpListener;
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+type TestSchema = Schema<Query, EmptyMutation, SubscriptionRoot>;
+
+#[derive(SimpleObject)]
+struct User {
+    id: String,
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self, id: String) -> Option<User> {
+        (id == "1").then(|| User {
+            id,
+            name: "Ann".to_string(),
+        })
+    }
+
+    async fn hello(&self, name: Option<String>) -> String {
+        format!("Hello, {}!", name.as_deref().unwrap_or("world"))
+    }
+}
+
+struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    async fn count_to(&self, to: i32) -> impl Stream<Item = i32> {
+        futures::stream::iter(1..=to)
+    }
+}
+
+fn test_schema() -> TestSchema {
+    Schema::new(Query, EmptyMutation, SubscriptionRoot)
+}
+
+#[tokio::test]
+async fn introspect_graphql_schema_caches_schema() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let url = spawn_graphql_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(ResourceProtocol::Graphql, &url, "{ hello }\n", None),
+    )
+    .await;
+
+    let schema = main_window
+        .introspect_graphql_schema(
+            &ctx,
+            &delegate,
+            IntrospectGraphqlSchemaInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .schema;
+    assert_eq!(schema["queryType"]["name"], "Query");
+    assert_eq!(schema["subscriptionType"]["name"], "SubscriptionRoot");
+
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(&ctx, &project_id)
+        .await
+        .unwrap();
+    let cached = project
+        .handle
+        .graphql_schema(&ctx, &resource_id)
+        .await
+        .unwrap();
+    assert_eq!(cached, Some(schema));
+
+    cleanup().await;
+}
+
+/// Serves queries sent as a json body or in the query string.
+async fn spawn_graphql_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+    let schema = test_schema();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let schema = schema.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let schema = schema.clone();
+                    async move { Ok::<_, Infallible>(handle_graphql(&schema, request).await) }
+                });
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+            });
+        }
+    });
+
+    url
+}
+
+async fn handle_graphql(schema: &TestSchema, request: Request<Incoming>) -> Response<Full<Bytes>> {
+    let graphql_request = if request.method() == Method::GET {
+        async_graphql::http::parse_query_string(request.uri().query().unwrap_or_default()).unwrap()
+    } else {
+        let body = request.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    };
+
+    let response = schema.execute(graphql_request).await;
+    let mut http_response = Response::new(Full::new(Bytes::from(
+        serde_json::to_vec(&response).unwrap(),
+    )));
+    http_response
+        .headers_mut()
+        .insert(CONTENT_TYPE, "application/json".parse().unwrap());
+
+    http_response
+}
+
+fn endpoint(
+    protocol: ResourceProtocol,
+    url: &str,
+    query: &str,
+    operation_name: Option<&str>,
+) -> CreateItemResourceParams {
+    let graphql = (protocol == ResourceProtocol::Graphql).then(|| GraphqlParams {
+        query: query.to_string(),
+        operation_name: operation_name.map(str::to_string),
+        variables: operation_name.map(|_| json!({ "id": "1" })),
+    });
+
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(protocol),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,424 @@
+#![cfg(feature = "integration-tests")]
+
+use futures::{StreamExt, stream};
+use main::grpc::{self, DynamicCodec};
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, grpc::GrpcParams},
+};
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor, Value};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    grpc::{GrpcMethodKind, ListGrpcServicesInput},
+    project::{CreateProjectInput, CreateProjectParams},
+};
+use serde_json::Value as JsonValue;
+use std::{
+    convert::Infallible,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::net::TcpListener;
+use tonic::{
+    Status, Streaming,
+    codegen::{Body, BoxFuture, BoxStream, Context, Poll, Service, StdError, http},
+    server::{NamedService, StreamingService},
+    transport::{Server, server::TcpIncoming},
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+const SERVICE: &str = "test.Greeter";
+
+const GREETER_PROTO: &str = r#"
+syntax = "proto3";
+
+package test;
+
+import "common.proto";
+
+service Greeter {
+  rpc SayHello (HelloRequest) returns (HelloReply);
+  rpc SayHellos (HelloRequest) returns (stream HelloReply);
+  rpc Collect (stream HelloRequest) returns (HelloReply);
+  rpc Chat (stream HelloRequest) returns (stream HelloReply);
+  rpc Slow (HelloRequest) returns (stream HelloReply);
+  rpc Fail (HelloRequest) returns (HelloReply);
+}
+
+message HelloRequest {
+  string name = 1;
+}
+
+message HelloReply {
+  string message = 1;
+  common.Stats stats = 2;
+}
+"#;
+
+const COMMON_PROTO: &str = r#"
+syntax = "proto3";
+
+package common;
+
+message Stats {
+  int64 count = 1;
+}
+"#;
+
+#[tokio::test]
+async fn list_grpc_services() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (project_id, pool) = create_test_project(&main_window, &ctx).await;
+    let url = spawn_grpc_server(pool).await;
+
+    // Services are the same whether they come from the proto files or the server reflection
+    for proto_files in [true, false] {
+        let resource_id = create_test_item(
+            &main_window,
+            &ctx,
+            &project_id,
+            endpoint(&url, "SayHello", vec![], proto_files),
+        )
+        .await;
+
+        let services = main_window
+            .list_grpc_services(
+                &ctx,
+                &delegate,
+                ListGrpcServicesInput {
+                    project_id: project_id.clone(),
+                    resource_id,
+                },
+            )
+            .await
+            .unwrap()
+            .services;
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].name, SERVICE);
+
+        let methods = services[0]
+            .methods
+            .iter()
+            .map(|method| (method.name.as_str(), method.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            methods,
+            vec![
+                ("SayHello", GrpcMethodKind::Unary),
+                ("SayHellos", GrpcMethodKind::ServerStreaming),
+                ("Collect", GrpcMethodKind::ClientStreaming),
+                ("Chat", GrpcMethodKind::BidiStreaming),
+                ("Slow", GrpcMethodKind::ServerStreaming),
+                ("Fail", GrpcMethodKind::Unary),
+            ]
+        );
+        assert_eq!(services[0].methods[0].input_type, "test.HelloRequest");
+        assert_eq!(services[0].methods[0].output_type, "test.HelloReply");
+    }
+
+    cleanup().await;
+}
+
+/// Serves the greeter service described by the pool, along with its server reflection.
+async fn spawn_grpc_server(pool: DescriptorPool) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let file_descriptor_set = pool.encode_to_vec();
+    let reflection = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(&file_descriptor_set)
+        .build_v1()
+        .unwrap();
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(GreeterServer { pool })
+            .add_service(reflection)
+            .serve_with_incoming(TcpIncoming::from(listener)),
+    );
+
+    url
+}
+
+/// Greeter service handling messages through their descriptors, like the client does.
+#[derive(Clone)]
+struct GreeterServer {
+    pool: DescriptorPool,
+}
+
+impl NamedService for GreeterServer {
+    const NAME: &'static str = SERVICE;
+}
+
+impl<B> Service<http::Request<B>> for GreeterServer
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request
+            .uri()
+            .path()
+            .rsplit('/')
+            .next()
+            .and_then(|name| grpc::find_method(&self.pool, SERVICE, name).ok());
+
+        Box::pin(async move {
+            let Some(method) = method else {
+                return Ok(Status::unimplemented("unknown method").into_http());
+            };
+
+            // Every kind of method is a bidi stream on the wire
+            let mut server = tonic::server::Grpc::new(DynamicCodec::new(method.input()));
+            Ok(server.streaming(GreeterMethod(method), request).await)
+        })
+    }
+}
+
+struct GreeterMethod(MethodDescriptor);
+
+impl StreamingService<DynamicMessage> for GreeterMethod {
+    type Response = DynamicMessage;
+    type ResponseStream = BoxStream<DynamicMessage>;
+    type Future = BoxFuture<tonic::Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: tonic::Request<Streaming<DynamicMessage>>) -> Self::Future {
+        Box::pin(respond(self.0.clone(), request))
+    }
+}
+
+async fn respond(
+    method: MethodDescriptor,
+    request: tonic::Request<Streaming<DynamicMessage>>,
+) -> Result<tonic::Response<BoxStream<DynamicMessage>>, Status> {
+    let token = request.metadata().get("x-token").cloned();
+    let mut requests = request.into_inner();
+    let output = method.output();
+
+    let replies: BoxStream<DynamicMessage> = match method.name() {
+        "SayHello" => {
+            let name = next_name(&mut requests).await?;
+            let reply = reply(&output, format!("Hello, {name}!"), 1);
+            Box::pin(stream::iter([Ok(reply)]))
+        }
+        "SayHellos" => {
+            let name = next_name(&mut requests).await?;
+            let replies = (1..=3)
+                .map(|i| Ok(reply(&output, format!("Hello, {name} #{i}!"), i)))
+                .collect::<Vec<_>>();
+            Box::pin(stream::iter(replies))
+        }
+        "Collect" => {
+            let mut names = Vec::new();
+            while let Some(message) = requests.message().await? {
+                names.push(name_of(&message));
+            }
+            let reply = reply(
+                &output,
+                format!("Hello, {}!", names.join(" and ")),
+                names.len() as i64,
+            );
+            Box::pin(stream::iter([Ok(reply)]))
+        }
+        "Chat" => Box::pin(requests.map(move |message| {
+            message.map(|message| reply(&output, format!("Hello, {}!", name_of(&message)), 1))
+        })),
+        "Slow" => {
+            let name = next_name(&mut requests).await?;
+            Box::pin(stream::once(async move {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                Ok(reply(&output, format!("Hello, {name}!"), 1))
+            }))
+        }
+        "Fail" => {
+            let name = next_name(&mut requests).await?;
+            let mut status = Status::not_found(format!("no user named {name}"));
+            status
+                .metadata_mut()
+                .insert("x-reason", "missing".parse().unwrap());
+            return Err(status);
+        }
+        _ => return Err(Status::unimplemented("unknown method")),
+    };
+
+    let mut response = tonic::Response::new(replies);
+    if let Some(token) = token {
+        response.metadata_mut().insert("x-echo", token);
+    }
+
+    Ok(response)
+}
+
+async fn next_name(requests: &mut Streaming<DynamicMessage>) -> Result<String, Status> {
+    let message = requests
+        .message()
+        .await?
+        .ok_or_else(|| Status::invalid_argument("missing request"))?;
+
+    Ok(name_of(&message))
+}
+
+fn name_of(message: &DynamicMessage) -> String {
+    message
+        .get_field_by_name("name")
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn reply(output: &MessageDescriptor, message: String, count: i64) -> DynamicMessage {
+    let stats = output
+        .get_field_by_name("stats")
+        .and_then(|field| field.kind().as_message().cloned())
+        .unwrap();
+    let mut stats = DynamicMessage::new(stats);
+    stats.set_field_by_name("count", Value::I64(count));
+
+    let mut reply = DynamicMessage::new(output.clone());
+    reply.set_field_by_name("message", Value::String(message));
+    reply.set_field_by_name("stats", Value::Message(stats));
+
+    reply
+}
+
+/// Endpoint calling a method of the greeter, described by the proto files of the project or
+/// by the server reflection.
+fn endpoint(
+    url: &str,
+    method: &str,
+    messages: Vec<JsonValue>,
+    proto_files: bool,
+) -> CreateItemResourceParams {
+    let (proto_files, import_paths) = if proto_files {
+        (
+            vec![PathBuf::from("protos/greeter.proto")],
+            vec![PathBuf::from("protos/shared")],
+        )
+    } else {
+        (vec![], vec![])
+    };
+
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(ResourceProtocol::Grpc),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: Some(GrpcParams {
+            proto_files,
+            import_paths,
+            service: SERVICE.to_string(),
+            method: method.to_string(),
+            messages,
+            timeout_ms: None,
+        }),
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+/// Creates a project holding the proto files of the greeter, returning the descriptors they
+/// compile to.
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> (ProjectId, DescriptorPool) {
+    let project_id = main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, &project_id)
+        .await
+        .unwrap();
+    let project_path = project.abs_path();
+    write_protos(&project_path).await;
+
+    let pool = grpc::compile_protos(
+        &project_path,
+        &[PathBuf::from("protos/greeter.proto")],
+        &[PathBuf::from("protos/shared")],
+    )
+    .unwrap();
+
+    (project_id, pool)
+}
+
+async fn write_protos(project_path: &Path) {
+    let shared_path = project_path.join("protos").join("shared");
+    tokio::fs::create_dir_all(&shared_path).await.unwrap();
+    tokio::fs::write(
+        project_path.join("protos").join("greeter.proto"),
+        GREETER_PROTO,
+    )
+    .await
+    .unwrap();
+    tokio::fs::write(shared_path.join("common.proto"), COMMON_PROTO)
+        .await
+        .unwrap();
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,314 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, sse::SseParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    project::{CreateProjectInput, CreateProjectParams},
+    sse::{ExecuteSseInput, ListSseEventsInput, SseEvent},
+};
+use serde_json::Value as JsonValue;
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+const EVENT_STREAM_HEADERS: &str =
+    "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n";
+
+/// `Last-Event-ID` headers of the requests received by the test server, in order.
+type LastEventIds = Arc<Mutex<Vec<Option<String>>>>;
+
+#[tokio::test]
+async fn list_sse_events_recorded() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (port, _) = spawn_sse_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            &format!("http://127.0.0.1:{port}/events"),
+            Some(SseParams {
+                reconnect: false,
+                retry_ms: None,
+                max_reconnects: None,
+                record: true,
+            }),
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    main_window
+        .execute_sse(
+            &ctx,
+            &delegate,
+            ExecuteSseInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    let recorded = main_window
+        .list_sse_events(
+            &ctx,
+            &delegate,
+            ListSseEventsInput {
+                project_id,
+                resource_id,
+            },
+        )
+        .await
+        .unwrap()
+        .events;
+    let recorded = recorded
+        .iter()
+        .map(|event| {
+            (
+                event.seq,
+                event.event.as_str(),
+                event.data.as_str(),
+                event.id.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        recorded,
+        vec![
+            (1, "message", "one", Some("1")),
+            (2, "update", "two\nlines", Some("2")),
+        ]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn list_sse_events_not_recorded() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let (port, _) = spawn_sse_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            &format!("http://127.0.0.1:{port}/events"),
+            Some(SseParams {
+                reconnect: false,
+                retry_ms: None,
+                max_reconnects: None,
+                record: false,
+            }),
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    main_window
+        .execute_sse(
+            &ctx,
+            &delegate,
+            ExecuteSseInput {
+                project_id: project_id.clone(),
+                resource_id: resource_id.clone(),
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+
+    // Nothing is recorded unless the endpoint is set to
+    let recorded = main_window
+        .list_sse_events(
+            &ctx,
+            &delegate,
+            ListSseEventsInput {
+                project_id,
+                resource_id,
+            },
+        )
+        .await
+        .unwrap()
+        .events;
+    assert!(recorded.is_empty());
+
+    cleanup().await;
+}
+
+/// Serves every request on its own connection:
+/// - `/events` streams two events, one more once reconnected, then stops the stream with
+///   `204 No Content`
+/// - `/forever` streams one event and keeps the connection open
+/// - anything else responds with JSON
+async fn spawn_sse_server() -> (u16, LastEventIds) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let last_event_ids = LastEventIds::default();
+
+    let last_event_ids_clone = last_event_ids.clone();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve_connection(stream, last_event_ids_clone.clone()));
+        }
+    });
+
+    (port, last_event_ids)
+}
+
+async fn serve_connection(mut stream: TcpStream, last_event_ids: LastEventIds) {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => request.extend_from_slice(&buf[..n]),
+        }
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+    let last_event_id = request.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("last-event-id")
+            .then(|| value.trim().to_string())
+    });
+
+    let response = match path {
+        "/events" => {
+            let connection = {
+                let mut last_event_ids = last_event_ids.lock().unwrap();
+                last_event_ids.push(last_event_id);
+                last_event_ids.len()
+            };
+            match connection {
+                1 => format!(
+                    "{EVENT_STREAM_HEADERS}retry: 20\n\n\
+                     id: 1\ndata: one\n\n\
+                     id: 2\nevent: update\ndata: two\ndata: lines\n\n"
+                ),
+                2 => format!("{EVENT_STREAM_HEADERS}: keep-alive\n\nid: 3\ndata: three\n\n"),
+                _ => "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".to_string(),
+            }
+        }
+        "/forever" => {
+            let _ = stream
+                .write_all(format!("{EVENT_STREAM_HEADERS}data: hello\n\n").as_bytes())
+                .await;
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            return;
+        }
+        _ => "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}"
+            .to_string(),
+    };
+
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+fn test_channel() -> (Channel<SseEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+fn endpoint(url: &str, sse: Option<SseParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(ResourceProtocol::Get),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,360 @@
+#![cfg(feature = "integration-tests")]
+
+use futures::{SinkExt, StreamExt};
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateItemResourceParams, websocket::WebSocketParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    project::{CreateProjectInput, CreateProjectParams},
+    websocket::{
+        CloseWebSocketInput, ConnectWebSocketInput, ListWebSocketFramesInput,
+        OutgoingWebSocketMessage, SendWebSocketMessageInput, WebSocketEvent,
+        WebSocketFrameDirection, WebSocketFrameKind,
+    },
+};
+use serde_json::{Value as JsonValue, json};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::{
+    Message,
+    handshake::server::{ErrorResponse, Request, Response},
+    http::{HeaderValue, header::SEC_WEBSOCKET_PROTOCOL},
+    protocol::{CloseFrame, frame::coding::CloseCode},
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+/// Subprotocol picked by the echo server, when offered.
+const ECHO_SUBPROTOCOL: &str = "superchat";
+
+#[tokio::test]
+async fn list_websocket_frames_open_session() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let port = spawn_echo_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            ResourceProtocol::WebSocket,
+            &format!("ws://127.0.0.1:{port}/echo"),
+            None,
+        ),
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let output = main_window
+        .connect_websocket(
+            &ctx,
+            &delegate,
+            ConnectWebSocketInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    for message in [
+        OutgoingWebSocketMessage::Text("hello".to_string()),
+        OutgoingWebSocketMessage::Json(json!({ "type": "ping" })),
+    ] {
+        main_window
+            .send_websocket_message(
+                &ctx,
+                &delegate,
+                SendWebSocketMessageInput {
+                    session_id: output.session_id.clone(),
+                    message,
+                },
+            )
+            .await
+            .unwrap();
+    }
+    wait_for_received(&events, 2).await;
+
+    let frames = main_window
+        .list_websocket_frames(ListWebSocketFramesInput {
+            session_id: output.session_id,
+        })
+        .await
+        .unwrap();
+    assert!(frames.open);
+    let log = frames
+        .frames
+        .iter()
+        .map(|frame| (frame.direction, frame.text.clone().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        log,
+        vec![
+            (WebSocketFrameDirection::Sent, "hello".to_string()),
+            (WebSocketFrameDirection::Received, "hello".to_string()),
+            (
+                WebSocketFrameDirection::Sent,
+                r#"{"type":"ping"}"#.to_string()
+            ),
+            (
+                WebSocketFrameDirection::Received,
+                r#"{"type":"ping"}"#.to_string()
+            ),
+        ]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn list_websocket_frames_closed_session() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let port = spawn_echo_server().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(
+            ResourceProtocol::WebSocket,
+            &format!("ws://127.0.0.1:{port}/echo"),
+            None,
+        ),
+    )
+    .await;
+
+    let (channel, _) = test_channel();
+    let output = main_window
+        .connect_websocket(
+            &ctx,
+            &delegate,
+            ConnectWebSocketInput {
+                project_id,
+                resource_id,
+            },
+            channel,
+        )
+        .await
+        .unwrap();
+    main_window
+        .close_websocket(
+            &ctx,
+            CloseWebSocketInput {
+                session_id: output.session_id.clone(),
+                code: None,
+                reason: Some("done".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    // The frames of closed sessions can still be listed
+    let frames = main_window
+        .list_websocket_frames(ListWebSocketFramesInput {
+            session_id: output.session_id,
+        })
+        .await
+        .unwrap();
+    assert!(!frames.open);
+    let close = frames.frames.last().unwrap();
+    assert_eq!(close.direction, WebSocketFrameDirection::Received);
+    assert_eq!(close.kind, WebSocketFrameKind::Close);
+    assert_eq!(close.close_code, Some(1000));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn list_websocket_frames_unknown_session() {
+    let (main_window, _, _, cleanup, _) = set_up_test_main_window().await;
+
+    let result = main_window
+        .list_websocket_frames(ListWebSocketFramesInput {
+            session_id: "unknown".to_string(),
+        })
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+/// Echoes text and binary messages, closing the connection with code 4000 on `bye`.
+async fn spawn_echo_server() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let Ok(mut ws) =
+                    tokio_tungstenite::accept_hdr_async(stream, select_subprotocol).await
+                else {
+                    return;
+                };
+
+                while let Some(Ok(message)) = ws.next().await {
+                    let result = match message {
+                        Message::Text(text) if text.as_str() == "bye" => {
+                            ws.close(Some(CloseFrame {
+                                code: CloseCode::from(4000),
+                                reason: "bye".into(),
+                            }))
+                            .await
+                        }
+                        Message::Text(_) | Message::Binary(_) => ws.send(message).await,
+                        _ => Ok(()),
+                    };
+                    if result.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    port
+}
+
+// The signature is the handshake callback of tungstenite
+#[allow(clippy::result_large_err)]
+fn select_subprotocol(
+    request: &Request,
+    mut response: Response,
+) -> Result<Response, ErrorResponse> {
+    let offered = request
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if offered
+        .split(',')
+        .any(|protocol| protocol.trim() == ECHO_SUBPROTOCOL)
+    {
+        response.headers_mut().insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static(ECHO_SUBPROTOCOL),
+        );
+    }
+
+    Ok(response)
+}
+
+async fn wait_for_received(events: &Arc<Mutex<Vec<JsonValue>>>, count: usize) {
+    for _ in 0..100 {
+        let received = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event["frame"]["frame"]["direction"] == "received")
+            .count();
+        if received >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("websocket frames were not received");
+}
+
+fn test_channel() -> (Channel<WebSocketEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+fn endpoint(
+    protocol: ResourceProtocol,
+    url: &str,
+    websocket: Option<WebSocketParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(protocol),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
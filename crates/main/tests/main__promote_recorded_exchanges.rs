@@ -0,0 +1,351 @@
+#![cfg(feature = "integration-tests")]
+
+use http_body_util::Full;
+use hyper::{
+    Request, Response,
+    body::{Bytes, Incoming},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use moss_app_delegate::AppDelegate;
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{BodyInfo, CreateItemResourceParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::{
+        project::{CreateProjectInput, CreateProjectParams},
+        proxy::{PromoteRecordedExchangesInput, RecordingProxyEvent, StartRecordingProxyInput},
+    },
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    convert::Infallible,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::net::TcpListener;
+
+use crate::shared::{create_test_endpoint_dir_entry, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn promote_recorded_exchanges_deduplicates_endpoints() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    create_test_endpoint_dir_entry(&main_window, &ctx, &project_id, "Legacy").await;
+    let orders_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from("Legacy"),
+            class: ResourceClass::Endpoint,
+            name: "Get order".to_string(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some("{{baseUrl}}/orders/:id".to_string()),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let server_port = spawn_test_server().await;
+    record(
+        &main_window,
+        &ctx,
+        &delegate,
+        vec![
+            ("GET", format!("http://127.0.0.1:{server_port}/users/1")),
+            ("GET", format!("http://127.0.0.1:{server_port}/users/2")),
+            ("GET", format!("http://127.0.0.1:{server_port}/users/me")),
+            ("POST", format!("http://127.0.0.1:{server_port}/users")),
+            ("GET", format!("http://127.0.0.1:{server_port}/orders/9")),
+        ],
+    )
+    .await;
+    let exchange_ids = main_window
+        .list_recorded_exchanges()
+        .await
+        .unwrap()
+        .exchanges
+        .into_iter()
+        .map(|exchange| exchange.id)
+        .collect::<Vec<_>>();
+    assert_eq!(exchange_ids.len(), 5);
+
+    let promoted = main_window
+        .promote_recorded_exchanges(
+            &ctx,
+            &delegate,
+            &PromoteRecordedExchangesInput {
+                project_id: project_id.clone(),
+                path: PathBuf::from("Legacy"),
+                exchange_ids: exchange_ids.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .exchanges;
+    assert_eq!(promoted.len(), 5);
+    assert_eq!(
+        promoted
+            .iter()
+            .map(|exchange| exchange.created)
+            .collect::<Vec<_>>(),
+        vec![true, false, true, true, false]
+    );
+    // Requests to the same templated path share an endpoint
+    assert_eq!(promoted[1].resource_id, promoted[0].resource_id);
+    assert_eq!(promoted[4].resource_id, orders_id);
+
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(&ctx, &project_id)
+        .await
+        .unwrap();
+    let desc = project
+        .describe_resource(&ctx, &delegate, promoted[0].resource_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.name, "GET /users/:user_id");
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Get));
+    assert_eq!(
+        desc.url.as_deref(),
+        Some(format!("http://127.0.0.1:{server_port}/users/:user_id").as_str())
+    );
+    assert_eq!(desc.path_params.len(), 1);
+    assert_eq!(desc.path_params[0].name, "user_id");
+    assert_eq!(desc.path_params[0].value, json!("1"));
+
+    let desc = project
+        .describe_resource(&ctx, &delegate, promoted[3].resource_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Post));
+    assert_eq!(desc.body, Some(BodyInfo::Json(json!({ "name": "Neo" }))));
+    assert!(
+        desc.headers
+            .iter()
+            .all(|header| !header.name.eq_ignore_ascii_case("content-type"))
+    );
+
+    // Promoting the exchanges again doesn't duplicate their endpoints
+    let promoted_again = main_window
+        .promote_recorded_exchanges(
+            &ctx,
+            &delegate,
+            &PromoteRecordedExchangesInput {
+                project_id: project_id.clone(),
+                path: PathBuf::from("Legacy"),
+                exchange_ids: vec![exchange_ids[0].clone()],
+            },
+        )
+        .await
+        .unwrap()
+        .exchanges;
+    assert!(!promoted_again[0].created);
+    assert_eq!(promoted_again[0].resource_id, promoted[0].resource_id);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn promote_recorded_exchanges_unknown_exchange() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let result = main_window
+        .promote_recorded_exchanges(
+            &ctx,
+            &delegate,
+            &PromoteRecordedExchangesInput {
+                project_id,
+                path: PathBuf::new(),
+                exchange_ids: vec!["missing".to_string()],
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+/// Sends the requests through a recording proxy, stopped once they are all recorded.
+async fn record(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    delegate: &AppDelegate<MockAppRuntime>,
+    requests: Vec<(&str, String)>,
+) {
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("proxy", request_ctx.get_canceller())
+        .await;
+
+    let (channel, events) = test_channel();
+    let window_clone = main_window.clone();
+    let delegate_clone = delegate.clone();
+    let ctx_clone = request_ctx.clone();
+    let proxy = tokio::spawn(async move {
+        window_clone
+            .start_recording_proxy(
+                &ctx_clone,
+                &delegate_clone,
+                StartRecordingProxyInput {
+                    port: None,
+                    accept_invalid_certs: false,
+                    intercepted_hosts: vec![],
+                },
+                channel,
+            )
+            .await
+    });
+    let proxy_port = wait_for_port(&events).await;
+
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{proxy_port}")).unwrap())
+        .build()
+        .unwrap();
+    for (method, url) in requests {
+        let request = match method {
+            "POST" => client.post(url).json(&json!({ "name": "Neo" })),
+            _ => client.get(url),
+        };
+        assert!(request.send().await.unwrap().status().is_success());
+    }
+
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "proxy".to_string(),
+        })
+        .await
+        .unwrap();
+    proxy.await.unwrap().unwrap();
+    main_window.release_cancellation("proxy").await;
+}
+
+async fn wait_for_port(events: &Arc<Mutex<Vec<JsonValue>>>) -> u64 {
+    for _ in 0..100 {
+        let port = events
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|event| event["started"]["port"].as_u64());
+        if let Some(port) = port {
+            return port;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("recording proxy did not start");
+}
+
+async fn spawn_test_server() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service_fn(respond))
+                    .await;
+            });
+        }
+    });
+
+    port
+}
+
+async fn respond(_: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    Ok(Response::new(Full::new(Bytes::from_static(b"{}"))))
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
+
+fn test_channel() -> (Channel<RecordingProxyEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
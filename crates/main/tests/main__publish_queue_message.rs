@@ -0,0 +1,347 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{
+        CreateItemResourceParams,
+        amqp::AmqpParams,
+        mqtt::{MqttParams, MqttVersion},
+    },
+};
+use rumqttd::{Broker, Config, ConnectionSettings, RouterConfig, ServerSettings};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    project::{CreateProjectInput, CreateProjectParams},
+    queue::PublishQueueMessageInput,
+};
+use std::{
+    collections::HashMap,
+    net::{SocketAddr, TcpListener},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn publish_queue_message_mqtt() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let broker = spawn_mqtt_broker().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    for (version, port) in [
+        (MqttVersion::V311, broker.v4_port),
+        (MqttVersion::V5, broker.v5_port),
+    ] {
+        let resource_id = create_test_item(
+            &main_window,
+            &ctx,
+            &project_id,
+            mqtt_endpoint(
+                &format!("mqtt://127.0.0.1:{port}"),
+                MqttParams {
+                    qos: 1,
+                    ..mqtt_params(version)
+                },
+            ),
+        )
+        .await;
+
+        // QoS 1 messages are done with once acknowledged by the broker
+        let output = main_window
+            .publish_queue_message(
+                &ctx,
+                &delegate,
+                PublishQueueMessageInput {
+                    project_id: project_id.clone(),
+                    resource_id,
+                    payload: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(output.topic, "sensors/kitchen");
+        assert_eq!(output.size, 14);
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn publish_mqtt_payload_of_input() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let broker = spawn_mqtt_broker().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        mqtt_endpoint(
+            &format!("mqtt://127.0.0.1:{}", broker.v4_port),
+            mqtt_params(MqttVersion::V311),
+        ),
+    )
+    .await;
+
+    // QoS 0 messages are done with once written
+    let output = main_window
+        .publish_queue_message(
+            &ctx,
+            &delegate,
+            PublishQueueMessageInput {
+                project_id,
+                resource_id,
+                payload: Some("off".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.topic, "sensors/kitchen");
+    assert_eq!(output.size, 3);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn publish_queue_message_invalid() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let closed_port = closed_port();
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let cases = [
+        // The endpoint isn't a queue endpoint
+        CreateItemResourceParams {
+            protocol: Some(ResourceProtocol::Get),
+            ..endpoint("http://127.0.0.1")
+        },
+        // The scheme doesn't match the protocol
+        mqtt_endpoint("http://127.0.0.1", mqtt_params(MqttVersion::V311)),
+        amqp_endpoint(&format!("mqtt://127.0.0.1:{closed_port}")),
+        // No broker is listening
+        mqtt_endpoint(
+            &format!("mqtt://127.0.0.1:{closed_port}"),
+            mqtt_params(MqttVersion::V5),
+        ),
+        amqp_endpoint(&format!("amqp://127.0.0.1:{closed_port}")),
+    ];
+    for params in cases {
+        let resource_id = create_test_item(&main_window, &ctx, &project_id, params).await;
+
+        let result = main_window
+            .publish_queue_message(
+                &ctx,
+                &delegate,
+                PublishQueueMessageInput {
+                    project_id: project_id.clone(),
+                    resource_id,
+                    payload: None,
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    cleanup().await;
+}
+
+struct MqttBroker {
+    v4_port: u16,
+    v5_port: u16,
+}
+
+/// Starts an in-process broker accepting MQTT 3.1.1 and MQTT 5 connections on their own
+/// ports, without authentication.
+async fn spawn_mqtt_broker() -> MqttBroker {
+    let broker = MqttBroker {
+        v4_port: closed_port(),
+        v5_port: closed_port(),
+    };
+
+    let server = |name: &str, port: u16| {
+        HashMap::from([(
+            name.to_string(),
+            ServerSettings {
+                name: name.to_string(),
+                listen: SocketAddr::from(([127, 0, 0, 1], port)),
+                tls: None,
+                next_connection_delay_ms: 1,
+                connections: ConnectionSettings {
+                    connection_timeout_ms: 5000,
+                    max_payload_size: 1024 * 1024,
+                    max_inflight_count: 100,
+                    auth: None,
+                    external_auth: None,
+                    dynamic_filters: true,
+                },
+            },
+        )])
+    };
+    let config = Config {
+        router: RouterConfig {
+            max_connections: 100,
+            max_outgoing_packet_count: 200,
+            max_segment_size: 1024 * 1024,
+            max_segment_count: 10,
+            ..Default::default()
+        },
+        v4: Some(server("v4", broker.v4_port)),
+        v5: Some(server("v5", broker.v5_port)),
+        ..Default::default()
+    };
+
+    // The broker runs its servers on threads of their own, until the test process exits
+    std::thread::spawn(move || Broker::new(config).start());
+
+    for port in [broker.v4_port, broker.v5_port] {
+        wait_for_listener(port).await;
+    }
+    broker
+}
+
+async fn wait_for_listener(port: u16) {
+    for _ in 0..100 {
+        if tokio::net::TcpStream::connect(("127.0.0.1", port))
+            .await
+            .is_ok()
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("mqtt broker is not listening on port {port}");
+}
+
+/// Returns a port nothing listens on, at least until something binds it again.
+fn closed_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+fn mqtt_params(version: MqttVersion) -> MqttParams {
+    MqttParams {
+        version,
+        client_id: None,
+        username: None,
+        password: None,
+        topic: "sensors/kitchen".to_string(),
+        topic_filter: None,
+        qos: 0,
+        retain: false,
+        clean_session: true,
+        payload: r#"{"celsius":21}"#.to_string(),
+        content_type: None,
+        response_topic: None,
+        correlation_data: None,
+        message_expiry_secs: None,
+    }
+}
+
+fn mqtt_endpoint(url: &str, mqtt: MqttParams) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(ResourceProtocol::Mqtt),
+        mqtt: Some(mqtt),
+        ..endpoint(url)
+    }
+}
+
+fn amqp_endpoint(url: &str) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(ResourceProtocol::Amqp),
+        amqp: Some(AmqpParams {
+            username: None,
+            password: None,
+            exchange: String::new(),
+            routing_key: "orders".to_string(),
+            queue: None,
+            payload: "created".to_string(),
+            content_type: None,
+            correlation_id: None,
+            reply_to: None,
+            message_id: None,
+            expiration_ms: None,
+            priority: None,
+            persistent: false,
+        }),
+        ..endpoint(url)
+    }
+}
+
+fn endpoint(url: &str) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: None,
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,305 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_environment::models::types::{AddVariableParams, VariableOptions};
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{
+        CreateItemResourceParams,
+        http::{AddHeaderParams, AddQueryParamParams, HeaderParamOptions, QueryParamOptions},
+    },
+};
+use sapic_base::{
+    environment::types::primitives::EnvironmentId,
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::ArcContext;
+use sapic_ipc::contracts::main::{
+    environment::{ActivateEnvironmentInput, CreateEnvironmentInput},
+    project::{CreateProjectInput, CreateProjectParams},
+    resource::{
+        EnvironmentScope, ResolveResourceInput, ResolvedField, VariableKind, VariableSource,
+        VariableValueKind,
+    },
+};
+use serde_json::{Value as JsonValue, json};
+use std::path::PathBuf;
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn resolve_resource_success() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let workspace_env_id = create_active_environment(
+        &main_window,
+        &ctx,
+        None,
+        vec![
+            variable("host", json!("workspace.host"), JsonValue::Null),
+            variable("token", json!("global"), json!("local")),
+        ],
+    )
+    .await;
+    let project_env_id = create_active_environment(
+        &main_window,
+        &ctx,
+        Some(project_id.clone()),
+        vec![variable("host", json!("project.host"), JsonValue::Null)],
+    )
+    .await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some("https://{{host}}/users".to_string()),
+            headers: vec![AddHeaderParams {
+                name: "Authorization".to_string(),
+                value: json!("Bearer {{token}}"),
+                order: 0,
+                description: None,
+                options: HeaderParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            path_params: vec![],
+            query_params: vec![AddQueryParamParams {
+                name: "page".to_string(),
+                value: json!("{{page}}"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let output = main_window
+        .resolve_resource(
+            &ctx,
+            &delegate,
+            ResolveResourceInput {
+                project_id,
+                resource_id,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.url, "https://project.host/users");
+    assert_eq!(output.headers[0].value, "Bearer local");
+    assert_eq!(output.query_params[0].value, "{{page}}");
+
+    let host = output
+        .substitutions
+        .iter()
+        .find(|s| s.name == "host")
+        .unwrap();
+    assert_eq!(host.location.field, ResolvedField::Url);
+    assert_eq!((host.location.start, host.location.end), (8, 16));
+    assert_eq!(
+        host.source,
+        VariableSource::Environment {
+            environment_id: project_env_id,
+            scope: EnvironmentScope::Project,
+            value: VariableValueKind::Global,
+        }
+    );
+
+    let token = output
+        .substitutions
+        .iter()
+        .find(|s| s.name == "token")
+        .unwrap();
+    assert_eq!(
+        token.source,
+        VariableSource::Environment {
+            environment_id: workspace_env_id,
+            scope: EnvironmentScope::Workspace,
+            value: VariableValueKind::Local,
+        }
+    );
+
+    assert_eq!(output.unresolved.len(), 1);
+    assert_eq!(output.unresolved[0].name, "page");
+    assert_eq!(output.unresolved[0].kind, VariableKind::Environment);
+    assert_eq!(
+        (
+            output.unresolved[0].location.start,
+            output.unresolved[0].location.end
+        ),
+        (0, 8)
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn resolve_resource_without_active_environment() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let resource_id = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            path: PathBuf::from(""),
+            class: ResourceClass::Endpoint,
+            name: random_entry_name(),
+            description: None,
+            order: 0,
+            protocol: Some(ResourceProtocol::Get),
+            url: Some("{{host}}/users".to_string()),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        },
+    )
+    .await;
+
+    let output = main_window
+        .resolve_resource(
+            &ctx,
+            &delegate,
+            ResolveResourceInput {
+                project_id,
+                resource_id,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.url, "{{host}}/users");
+    assert!(output.substitutions.is_empty());
+    assert_eq!(output.unresolved.len(), 1);
+    assert_eq!(output.unresolved[0].name, "host");
+
+    cleanup().await;
+}
+
+fn variable(name: &str, global_value: JsonValue, local_value: JsonValue) -> AddVariableParams {
+    AddVariableParams {
+        name: name.to_string(),
+        global_value,
+        local_value,
+        order: 0,
+        desc: None,
+        options: VariableOptions { disabled: false },
+    }
+}
+
+async fn create_active_environment(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: Option<ProjectId>,
+    variables: Vec<AddVariableParams>,
+) -> EnvironmentId {
+    let id = main_window
+        .create_environment(
+            ctx,
+            CreateEnvironmentInput {
+                project_id: project_id.clone(),
+                name: random_entry_name(),
+                color: None,
+                variables,
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    main_window
+        .activate_environment(
+            ctx,
+            ActivateEnvironmentInput {
+                project_id,
+                environment_id: id.clone(),
+            },
+        )
+        .await
+        .unwrap();
+
+    id
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_endpoint(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,420 @@
+#![cfg(feature = "integration-tests")]
+
+use http_body_util::Full;
+use hyper::{
+    Request, Response, StatusCode,
+    body::{Bytes, Incoming},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{CreateDirResourceParams, CreateItemResourceParams},
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::main::{
+    project::{CreateProjectInput, CreateProjectParams},
+    runner::{RunCollectionInput, RunStatus},
+};
+use std::{convert::Infallible, path::PathBuf, time::Duration};
+use tokio::net::TcpListener;
+
+use crate::shared::set_up_test_main_window;
+
+mod shared;
+
+#[tokio::test]
+async fn run_collection_runs_endpoints_in_order_for_every_row() {
+    let (main_window, delegate, ctx, cleanup, test_dir_path) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server_port = spawn_test_server().await;
+
+    create_test_dir(&main_window, &ctx, &project_id, "", "Smoke", 0).await;
+    create_test_dir(&main_window, &ctx, &project_id, "Smoke", "Users", 1).await;
+    let login = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        "Smoke",
+        "Login",
+        0,
+        ResourceProtocol::Post,
+        format!("http://127.0.0.1:{server_port}/login/{{{{user}}}}"),
+    )
+    .await;
+    let profile = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        "Smoke/Users",
+        "Profile",
+        0,
+        ResourceProtocol::Get,
+        format!("http://127.0.0.1:{server_port}/users/{{{{user}}}}"),
+    )
+    .await;
+    let missing = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        "Smoke",
+        "A missing page",
+        2,
+        ResourceProtocol::Get,
+        format!("http://127.0.0.1:{server_port}/missing"),
+    )
+    .await;
+    // Outside of the directory run
+    create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        "",
+        "Health",
+        1,
+        ResourceProtocol::Get,
+        format!("http://127.0.0.1:{server_port}/health"),
+    )
+    .await;
+
+    let data_file = test_dir_path.join("users.csv");
+    tokio::fs::write(&data_file, "user\nneo\ntrinity\n")
+        .await
+        .unwrap();
+
+    let output = main_window
+        .run_collection(
+            &ctx,
+            &delegate,
+            &RunCollectionInput {
+                project_id: project_id.clone(),
+                path: PathBuf::from("Smoke"),
+                data_file: Some(data_file),
+                concurrency: None,
+                request_timeout_ms: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.status, RunStatus::Completed);
+    assert_eq!(output.iterations, 2);
+    assert_eq!(output.passed, 4);
+    assert_eq!(output.failed, 2);
+    assert_eq!(output.skipped, 0);
+
+    let runs = output
+        .results
+        .iter()
+        .map(|result| (result.iteration, result.resource_id.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        runs,
+        vec![
+            (0, login.clone()),
+            (0, profile.clone()),
+            (0, missing.clone()),
+            (1, login),
+            (1, profile),
+            (1, missing),
+        ]
+    );
+
+    // The columns of the row are bound as variables
+    assert_eq!(output.results[0].method, "POST");
+    assert_eq!(
+        output.results[0].url,
+        format!("http://127.0.0.1:{server_port}/login/neo")
+    );
+    assert_eq!(
+        output.results[4].url,
+        format!("http://127.0.0.1:{server_port}/users/trinity")
+    );
+    assert_eq!(output.results[1].status, Some(200));
+    assert!(output.results[1].passed);
+    assert_eq!(output.results[2].status, Some(404));
+    assert!(!output.results[2].passed);
+    assert!(output.results[2].error.is_none());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn run_collection_request_timeout() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server_port = spawn_test_server().await;
+
+    let slow = create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        "",
+        "Slow",
+        0,
+        ResourceProtocol::Get,
+        format!("http://127.0.0.1:{server_port}/slow"),
+    )
+    .await;
+    create_test_endpoint(
+        &main_window,
+        &ctx,
+        &project_id,
+        "",
+        "Health",
+        1,
+        ResourceProtocol::Get,
+        format!("http://127.0.0.1:{server_port}/health"),
+    )
+    .await;
+
+    let output = main_window
+        .run_collection(
+            &ctx,
+            &delegate,
+            &RunCollectionInput {
+                project_id,
+                path: PathBuf::new(),
+                data_file: None,
+                concurrency: Some(2),
+                request_timeout_ms: Some(200),
+            },
+        )
+        .await
+        .unwrap();
+
+    // A failed endpoint doesn't stop the run
+    assert_eq!(output.status, RunStatus::Completed);
+    assert_eq!(output.iterations, 1);
+    assert_eq!(output.results.len(), 2);
+    assert_eq!(output.results[0].resource_id, slow);
+    assert_eq!(output.results[0].status, None);
+    assert!(output.results[0].error.is_some());
+    assert!(!output.results[0].passed);
+    assert!(output.results[1].passed);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn run_collection_canceled() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let server_port = spawn_test_server().await;
+
+    for (order, path) in ["slow", "health"].into_iter().enumerate() {
+        create_test_endpoint(
+            &main_window,
+            &ctx,
+            &project_id,
+            "",
+            path,
+            order as isize,
+            ResourceProtocol::Get,
+            format!("http://127.0.0.1:{server_port}/{path}"),
+        )
+        .await;
+    }
+
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    let canceller = request_ctx.get_canceller();
+    let input = RunCollectionInput {
+        project_id,
+        path: PathBuf::new(),
+        data_file: None,
+        concurrency: None,
+        request_timeout_ms: None,
+    };
+    let (output, _) = tokio::join!(
+        main_window.run_collection(&request_ctx, &delegate, &input),
+        async {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            canceller.cancel();
+        }
+    );
+    let output = output.unwrap();
+
+    // The request in flight is aborted, the next ones aren't sent
+    assert_eq!(output.status, RunStatus::Canceled);
+    assert_eq!(output.results.len(), 1);
+    assert!(output.results[0].error.is_some());
+    assert_eq!(output.skipped, 1);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn run_collection_invalid_data_file() {
+    let (main_window, delegate, ctx, cleanup, test_dir_path) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let data_file = test_dir_path.join("users.json");
+    tokio::fs::write(&data_file, r#"{ "user": "neo" }"#)
+        .await
+        .unwrap();
+
+    let result = main_window
+        .run_collection(
+            &ctx,
+            &delegate,
+            &RunCollectionInput {
+                project_id,
+                path: PathBuf::new(),
+                data_file: Some(data_file),
+                concurrency: None,
+                request_timeout_ms: None,
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+/// Answers `/missing` with a 404, `/slow` after a second, and everything else with a 200.
+async fn spawn_test_server() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service_fn(respond))
+                    .await;
+            });
+        }
+    });
+
+    port
+}
+
+async fn respond(request: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let mut response = Response::new(Full::new(Bytes::from_static(b"{}")));
+    match request.uri().path() {
+        "/missing" => *response.status_mut() = StatusCode::NOT_FOUND,
+        "/slow" => tokio::time::sleep(Duration::from_secs(1)).await,
+        _ => {}
+    }
+
+    Ok(response)
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_dir(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    path: &str,
+    name: &str,
+    order: isize,
+) -> ResourceId {
+    create_test_resource(
+        main_window,
+        ctx,
+        project_id,
+        CreateResourceInput::Dir(CreateDirResourceParams {
+            class: ResourceClass::Endpoint,
+            path: PathBuf::from(path),
+            name: name.to_string(),
+            description: None,
+            order,
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            auth: None,
+        }),
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn create_test_endpoint(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    path: &str,
+    name: &str,
+    order: isize,
+    protocol: ResourceProtocol,
+    url: String,
+) -> ResourceId {
+    create_test_resource(
+        main_window,
+        ctx,
+        project_id,
+        CreateResourceInput::Item(CreateItemResourceParams {
+            path: PathBuf::from(path),
+            class: ResourceClass::Endpoint,
+            name: name.to_string(),
+            description: None,
+            order,
+            protocol: Some(protocol),
+            url: Some(url),
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        }),
+    )
+    .await
+}
+
+async fn create_test_resource(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    input: CreateResourceInput,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, input)
+        .await
+        .unwrap()
+        .id
+}
@@ -0,0 +1,486 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_app_delegate::AppDelegate;
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::{CreateResourceCaseInput, CreateResourceInput},
+    types::{
+        CreateItemResourceParams, ExpectedResponse,
+        http::{AddPathParamParams, PathParamOptions},
+    },
+};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::{
+        mock::{MockEndpointOptions, MockServerEvent, StartMockServerInput, StartMockServerOutput},
+        project::{CreateProjectInput, CreateProjectParams},
+    },
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::task::JoinHandle;
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn start_mock_server_serves_cases() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let users_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(ResourceProtocol::Get, "{{baseUrl}}/users/:id"),
+    )
+    .await;
+    create_test_case(
+        &main_window,
+        &ctx,
+        &project_id,
+        &users_id,
+        "Any user",
+        None,
+        ExpectedResponse {
+            status: Some(200),
+            headers: Some(
+                [("x-case".to_string(), "any".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            body: Some(json!({ "id": 0, "name": "Anyone" })),
+        },
+    )
+    .await;
+    create_test_case(
+        &main_window,
+        &ctx,
+        &project_id,
+        &users_id,
+        "Missing user",
+        Some("404"),
+        ExpectedResponse {
+            status: Some(404),
+            headers: None,
+            body: Some(json!("not found")),
+        },
+    )
+    .await;
+    let me_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(ResourceProtocol::Get, "{{baseUrl}}/users/me"),
+    )
+    .await;
+
+    let (_request_ctx, events, server) = spawn_mock_server(
+        &main_window,
+        &delegate,
+        &ctx,
+        StartMockServerInput {
+            project_id,
+            port: None,
+            latency_ms: None,
+            status: None,
+            endpoints: vec![],
+            log_requests: true,
+        },
+    )
+    .await;
+    let port = wait_for_port(&events).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://127.0.0.1:{port}/users/42"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-case"], "any");
+    assert_eq!(response.headers()["content-type"], "application/json");
+    assert_eq!(
+        response.json::<JsonValue>().await.unwrap(),
+        json!({ "id": 0, "name": "Anyone" })
+    );
+
+    // The case whose path params match the request is preferred
+    let response = client
+        .get(format!("http://127.0.0.1:{port}/users/404"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+    assert_eq!(response.text().await.unwrap(), "not found");
+
+    // Literal paths take precedence over path variables
+    let response = client
+        .get(format!("http://127.0.0.1:{port}/users/me"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.text().await.unwrap(), "");
+
+    let response = client
+        .post(format!("http://127.0.0.1:{port}/users/42"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 405);
+
+    let response = client
+        .get(format!("http://127.0.0.1:{port}/unknown"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "mock".to_string(),
+        })
+        .await
+        .unwrap();
+    let output = server.await.unwrap().unwrap();
+    assert_eq!(output.requests, 5);
+
+    let events = events.lock().unwrap().clone();
+    let requests = events
+        .iter()
+        .filter_map(|event| event.get("request"))
+        .collect::<Vec<_>>();
+    assert_eq!(requests.len(), 5);
+    assert_eq!(requests[0]["method"], "GET");
+    assert_eq!(requests[0]["path"], "/users/42");
+    assert_eq!(requests[0]["resourceId"], json!(users_id.to_string()));
+    assert!(requests[0]["caseId"].is_string());
+    assert_eq!(requests[2]["resourceId"], json!(me_id.to_string()));
+    assert!(requests[4]["resourceId"].is_null());
+
+    // The server no longer accepts connections once stopped
+    assert!(
+        client
+            .get(format!("http://127.0.0.1:{port}/users/42"))
+            .send()
+            .await
+            .is_err()
+    );
+
+    main_window.release_cancellation("mock").await;
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn start_mock_server_synthesizes_from_schema() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let schema_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            class: ResourceClass::Schema,
+            protocol: None,
+            url: None,
+            schema: Some(json!({
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer", "minimum": 1 },
+                    "email": { "type": "string", "format": "email" }
+                }
+            })),
+            ..endpoint(ResourceProtocol::Get, "")
+        },
+    )
+    .await;
+    let endpoint_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        CreateItemResourceParams {
+            body_schema: Some(schema_id),
+            ..endpoint(ResourceProtocol::Post, "http://localhost:8080/api/users")
+        },
+    )
+    .await;
+
+    let (_request_ctx, events, server) = spawn_mock_server(
+        &main_window,
+        &delegate,
+        &ctx,
+        StartMockServerInput {
+            project_id,
+            port: None,
+            latency_ms: None,
+            status: Some(500),
+            endpoints: vec![MockEndpointOptions {
+                resource_id: endpoint_id,
+                case_id: None,
+                status: Some(201),
+                latency_ms: Some(300),
+            }],
+            log_requests: false,
+        },
+    )
+    .await;
+    let port = wait_for_port(&events).await;
+
+    let started_at = std::time::Instant::now();
+    let response = reqwest::Client::new()
+        .post(format!("http://127.0.0.1:{port}/api/users"))
+        .send()
+        .await
+        .unwrap();
+    assert!(started_at.elapsed() >= Duration::from_millis(300));
+    assert_eq!(response.status(), 201);
+    assert_eq!(
+        response.json::<JsonValue>().await.unwrap(),
+        json!({ "id": 1, "email": "user@example.com" })
+    );
+
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "mock".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(server.await.unwrap().unwrap().requests, 1);
+
+    // Only the started event is sent when requests aren't logged
+    assert_eq!(events.lock().unwrap().len(), 1);
+
+    main_window.release_cancellation("mock").await;
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn start_mock_server_unknown_case() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        endpoint(ResourceProtocol::Get, "{{baseUrl}}/users"),
+    )
+    .await;
+
+    let (channel, events) = test_channel();
+    let result = main_window
+        .start_mock_server(
+            &ctx,
+            &delegate,
+            StartMockServerInput {
+                project_id,
+                port: None,
+                latency_ms: None,
+                status: None,
+                endpoints: vec![MockEndpointOptions {
+                    resource_id,
+                    case_id: Some("missing".to_string()),
+                    status: None,
+                    latency_ms: None,
+                }],
+                log_requests: false,
+            },
+            channel,
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(events.lock().unwrap().is_empty());
+
+    cleanup().await;
+}
+
+type MockServerTask = JoinHandle<joinerror::Result<StartMockServerOutput>>;
+
+/// Starts the mock server in the background, cancelable with the `mock` request id.
+async fn spawn_mock_server(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    delegate: &AppDelegate<MockAppRuntime>,
+    ctx: &ArcContext,
+    input: StartMockServerInput,
+) -> (ArcContext, Arc<Mutex<Vec<JsonValue>>>, MockServerTask) {
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("mock", request_ctx.get_canceller())
+        .await;
+
+    let (channel, events) = test_channel();
+    let window_clone = main_window.clone();
+    let delegate_clone = delegate.clone();
+    let ctx_clone = request_ctx.clone();
+    let server = tokio::spawn(async move {
+        window_clone
+            .start_mock_server(&ctx_clone, &delegate_clone, input, channel)
+            .await
+    });
+
+    (request_ctx, events, server)
+}
+
+async fn wait_for_port(events: &Arc<Mutex<Vec<JsonValue>>>) -> u64 {
+    for _ in 0..100 {
+        let port = events
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|event| event["started"]["port"].as_u64());
+        if let Some(port) = port {
+            return port;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("mock server did not start");
+}
+
+fn endpoint(protocol: ResourceProtocol, url: &str) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(protocol),
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_case(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    resource_id: &ResourceId,
+    name: &str,
+    user_id: Option<&str>,
+    expected_response: ExpectedResponse,
+) {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource_case::<MockAppRuntime>(
+            ctx,
+            CreateResourceCaseInput {
+                resource_id: resource_id.clone(),
+                name: name.to_string(),
+                description: None,
+                headers: vec![],
+                path_params: user_id
+                    .map(|value| AddPathParamParams {
+                        name: "id".to_string(),
+                        value: json!(value),
+                        order: 0,
+                        description: None,
+                        options: PathParamOptions {
+                            disabled: false,
+                            propagate: false,
+                        },
+                    })
+                    .into_iter()
+                    .collect(),
+                query_params: vec![],
+                body: None,
+                expected_response: Some(expected_response),
+            },
+        )
+        .await
+        .unwrap();
+}
+
+fn test_channel() -> (Channel<MockServerEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
@@ -0,0 +1,363 @@
+#![cfg(feature = "integration-tests")]
+
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    Request, Response,
+    body::{Bytes, Incoming},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use moss_app_delegate::AppDelegate;
+use moss_applib::mock::MockAppRuntime;
+use rustls::{
+    ServerConfig,
+    pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::proxy::{RecordingProxyEvent, StartRecordingProxyInput, StartRecordingProxyOutput},
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    convert::Infallible,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tokio::{net::TcpListener, task::JoinHandle};
+use tokio_rustls::TlsAcceptor;
+
+use crate::shared::set_up_test_main_window;
+
+mod shared;
+
+#[tokio::test]
+async fn start_recording_proxy_records_exchanges() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let server_port = spawn_test_server(None).await;
+
+    let (_request_ctx, events, proxy) = spawn_recording_proxy(
+        &main_window,
+        &delegate,
+        &ctx,
+        StartRecordingProxyInput {
+            port: None,
+            accept_invalid_certs: false,
+            intercepted_hosts: vec![],
+        },
+    )
+    .await;
+    let (proxy_port, _) = wait_for_start(&events).await;
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{proxy_port}")).unwrap())
+        .build()
+        .unwrap();
+
+    let response = client
+        .get(format!(
+            "http://127.0.0.1:{server_port}/users/42?verbose=true"
+        ))
+        .header("x-api-key", "secret")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-test-server"], "true");
+    assert_eq!(
+        response.json::<JsonValue>().await.unwrap(),
+        json!({ "method": "GET", "path": "/users/42?verbose=true", "body": "" })
+    );
+
+    let response = client
+        .post(format!("http://127.0.0.1:{server_port}/users"))
+        .json(&json!({ "name": "Neo" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    assert_eq!(
+        response.json::<JsonValue>().await.unwrap()["body"],
+        r#"{"name":"Neo"}"#
+    );
+
+    let exchanges = main_window
+        .list_recorded_exchanges()
+        .await
+        .unwrap()
+        .exchanges;
+    assert_eq!(exchanges.len(), 2);
+    assert_eq!(exchanges[0].method, "GET");
+    assert_eq!(
+        exchanges[0].url,
+        format!("http://127.0.0.1:{server_port}/users/42?verbose=true")
+    );
+    assert_eq!(exchanges[0].status, 200);
+    assert_eq!(exchanges[1].method, "POST");
+    assert_eq!(exchanges[1].status, 201);
+    assert_ne!(exchanges[0].id, exchanges[1].id);
+
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "proxy".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(proxy.await.unwrap().unwrap().exchanges, 2);
+
+    let events = events.lock().unwrap().clone();
+    let recorded = events
+        .iter()
+        .filter_map(|event| event["exchange"].get("exchange"))
+        .collect::<Vec<_>>();
+    assert_eq!(recorded.len(), 2);
+    assert_eq!(recorded[0]["id"], json!(exchanges[0].id));
+    assert_eq!(recorded[1]["method"], "POST");
+
+    main_window.release_cancellation("proxy").await;
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn start_recording_proxy_intercepts_https() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let server_port = spawn_test_server(Some(self_signed_config())).await;
+
+    let (_request_ctx, events, proxy) = spawn_recording_proxy(
+        &main_window,
+        &delegate,
+        &ctx,
+        StartRecordingProxyInput {
+            port: None,
+            accept_invalid_certs: true,
+            intercepted_hosts: vec![],
+        },
+    )
+    .await;
+    let (proxy_port, ca_certificate_path) = wait_for_start(&events).await;
+    let ca_certificate = std::fs::read(&ca_certificate_path).unwrap();
+
+    // Clients that don't trust the certificate authority cannot be intercepted
+    let untrusting_client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{proxy_port}")).unwrap())
+        .build()
+        .unwrap();
+    assert!(
+        untrusting_client
+            .get(format!("https://127.0.0.1:{server_port}/secure"))
+            .send()
+            .await
+            .is_err()
+    );
+
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{proxy_port}")).unwrap())
+        .add_root_certificate(reqwest::Certificate::from_pem(&ca_certificate).unwrap())
+        .build()
+        .unwrap();
+    let response = client
+        .get(format!("https://127.0.0.1:{server_port}/secure"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.json::<JsonValue>().await.unwrap()["path"],
+        "/secure"
+    );
+
+    let exchanges = main_window
+        .list_recorded_exchanges()
+        .await
+        .unwrap()
+        .exchanges;
+    assert_eq!(exchanges.len(), 1);
+    assert_eq!(
+        exchanges[0].url,
+        format!("https://127.0.0.1:{server_port}/secure")
+    );
+
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "proxy".to_string(),
+        })
+        .await
+        .unwrap();
+    proxy.await.unwrap().unwrap();
+    main_window.release_cancellation("proxy").await;
+
+    // The certificate authority is kept in the profile, so clients only trust it once
+    let (_request_ctx, events, proxy) = spawn_recording_proxy(
+        &main_window,
+        &delegate,
+        &ctx,
+        StartRecordingProxyInput {
+            port: None,
+            accept_invalid_certs: true,
+            intercepted_hosts: vec![],
+        },
+    )
+    .await;
+    let (proxy_port, restarted_ca_certificate_path) = wait_for_start(&events).await;
+    assert_eq!(restarted_ca_certificate_path, ca_certificate_path);
+    assert_eq!(
+        std::fs::read(&restarted_ca_certificate_path).unwrap(),
+        ca_certificate
+    );
+
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{proxy_port}")).unwrap())
+        .add_root_certificate(reqwest::Certificate::from_pem(&ca_certificate).unwrap())
+        .build()
+        .unwrap();
+    let response = client
+        .get(format!("https://127.0.0.1:{server_port}/secure"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "proxy".to_string(),
+        })
+        .await
+        .unwrap();
+    proxy.await.unwrap().unwrap();
+
+    main_window.release_cancellation("proxy").await;
+    cleanup().await;
+}
+
+type RecordingProxyTask = JoinHandle<joinerror::Result<StartRecordingProxyOutput>>;
+
+/// Starts the recording proxy in the background, cancelable with the `proxy` request id.
+async fn spawn_recording_proxy(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    delegate: &AppDelegate<MockAppRuntime>,
+    ctx: &ArcContext,
+    input: StartRecordingProxyInput,
+) -> (ArcContext, Arc<Mutex<Vec<JsonValue>>>, RecordingProxyTask) {
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("proxy", request_ctx.get_canceller())
+        .await;
+
+    let (channel, events) = test_channel();
+    let window_clone = main_window.clone();
+    let delegate_clone = delegate.clone();
+    let ctx_clone = request_ctx.clone();
+    let proxy = tokio::spawn(async move {
+        window_clone
+            .start_recording_proxy(&ctx_clone, &delegate_clone, input, channel)
+            .await
+    });
+
+    (request_ctx, events, proxy)
+}
+
+async fn wait_for_start(events: &Arc<Mutex<Vec<JsonValue>>>) -> (u64, PathBuf) {
+    for _ in 0..100 {
+        let started = events.lock().unwrap().iter().find_map(|event| {
+            let started = event.get("started")?;
+            Some((
+                started["port"].as_u64()?,
+                PathBuf::from(started["caCertificatePath"].as_str()?),
+            ))
+        });
+        if let Some(started) = started {
+            return started;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("recording proxy did not start");
+}
+
+/// Serves every request with its method, path and body, over tls when given a config.
+async fn spawn_test_server(tls: Option<ServerConfig>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let acceptor = tls.map(|config| TlsAcceptor::from(Arc::new(config)));
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                let service = service_fn(respond);
+                match acceptor {
+                    Some(acceptor) => {
+                        let stream = acceptor.accept(stream).await.unwrap();
+                        let _ = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service)
+                            .await;
+                    }
+                    None => {
+                        let _ = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service)
+                            .await;
+                    }
+                }
+            });
+        }
+    });
+
+    port
+}
+
+async fn respond(request: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let method = request.method().to_string();
+    let path = request.uri().path_and_query().unwrap().to_string();
+    let body = request.into_body().collect().await.unwrap().to_bytes();
+
+    let body = json!({
+        "method": method,
+        "path": path,
+        "body": String::from_utf8_lossy(&body),
+    });
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    if method == "POST" {
+        *response.status_mut() = hyper::StatusCode::CREATED;
+    }
+    response
+        .headers_mut()
+        .insert("content-type", "application/json".parse().unwrap());
+    response
+        .headers_mut()
+        .insert("x-test-server", "true".parse().unwrap());
+
+    Ok(response)
+}
+
+/// A certificate no client trusts, which the proxy only relays to when told to.
+fn self_signed_config() -> ServerConfig {
+    let certified = rcgen::generate_simple_self_signed(vec!["127.0.0.1".to_string()]).unwrap();
+    let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(certified.key_pair.serialize_der()));
+
+    ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![certified.cert.der().clone()], key)
+        .unwrap()
+}
+
+fn test_channel() -> (Channel<RecordingProxyEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
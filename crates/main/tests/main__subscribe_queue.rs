@@ -0,0 +1,483 @@
+#![cfg(feature = "integration-tests")]
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::models::{
+    operations::CreateResourceInput,
+    types::{
+        CreateItemResourceParams,
+        amqp::AmqpParams,
+        http::{AddHeaderParams, HeaderParamOptions},
+        mqtt::{MqttParams, MqttVersion},
+    },
+};
+use rumqttd::{Broker, Config, ConnectionSettings, RouterConfig, ServerSettings};
+use sapic_base::{
+    project::types::primitives::ProjectId,
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol},
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext, ContextBuilder};
+use sapic_ipc::contracts::{
+    main::{
+        project::{CreateProjectInput, CreateProjectParams},
+        queue::{PublishQueueMessageInput, QueueEvent, SubscribeQueueInput},
+    },
+    other::CancelRequestInput,
+};
+use sapic_window2::AppWindowApi;
+use serde_json::{Value as JsonValue, json};
+use std::{
+    collections::HashMap,
+    net::{SocketAddr, TcpListener},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tauri::ipc::{Channel, InvokeResponseBody};
+
+use crate::shared::{random_entry_name, set_up_test_main_window};
+
+mod shared;
+
+#[tokio::test]
+async fn subscribe_queue_mqtt() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let broker = spawn_mqtt_broker().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    for version in [MqttVersion::V311, MqttVersion::V5] {
+        let port = match version {
+            MqttVersion::V311 => broker.v4_port,
+            MqttVersion::V5 => broker.v5_port,
+        };
+        let url = format!("mqtt://127.0.0.1:{port}");
+
+        let subscriber_id = create_test_item(
+            &main_window,
+            &ctx,
+            &project_id,
+            mqtt_endpoint(
+                &url,
+                MqttParams {
+                    topic_filter: Some("sensors/+".to_string()),
+                    qos: 1,
+                    ..mqtt_params(version)
+                },
+            ),
+        )
+        .await;
+        let mut publisher = mqtt_endpoint(
+            &url,
+            MqttParams {
+                qos: 1,
+                content_type: Some("application/json".to_string()),
+                ..mqtt_params(version)
+            },
+        );
+        publisher.headers = vec![AddHeaderParams {
+            name: "x-sensor".to_string(),
+            value: json!("kitchen"),
+            order: 0,
+            description: None,
+            options: HeaderParamOptions {
+                disabled: false,
+                propagate: false,
+            },
+        }];
+        let publisher_id = create_test_item(&main_window, &ctx, &project_id, publisher).await;
+
+        let (channel, events) = test_channel();
+        let window_clone = main_window.clone();
+        let delegate_clone = delegate.clone();
+        let ctx_clone = ctx.clone();
+        let input = SubscribeQueueInput {
+            project_id: project_id.clone(),
+            resource_id: subscriber_id,
+            max_messages: Some(1),
+        };
+        let subscription = tokio::spawn(async move {
+            window_clone
+                .subscribe_queue(&ctx_clone, &delegate_clone, input, channel)
+                .await
+        });
+        wait_for_event(&events, "subscribed").await;
+
+        let published = main_window
+            .publish_queue_message(
+                &ctx,
+                &delegate,
+                PublishQueueMessageInput {
+                    project_id: project_id.clone(),
+                    resource_id: publisher_id,
+                    payload: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(published.topic, "sensors/kitchen");
+        assert_eq!(published.size, 14);
+
+        // The subscription stops by itself once the message was received
+        let output = tokio::time::timeout(Duration::from_secs(5), subscription)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.received, 1);
+
+        let events = events.lock().unwrap().clone();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["subscribed"]["source"], "sensors/+");
+
+        let message = &events[1]["message"]["message"];
+        assert_eq!(message["seq"], 1);
+        assert_eq!(message["topic"], "sensors/kitchen");
+        assert_eq!(message["text"], r#"{"celsius":21}"#);
+        assert_eq!(message["qos"], 1);
+
+        // Properties only exist as of MQTT 5
+        match version {
+            MqttVersion::V311 => {
+                assert_eq!(message["properties"], json!([]));
+                assert_eq!(message["headers"], json!([]));
+            }
+            MqttVersion::V5 => {
+                assert_eq!(
+                    message["properties"],
+                    json!([{ "name": "content-type", "value": "application/json" }])
+                );
+                assert_eq!(
+                    message["headers"],
+                    json!([{ "name": "x-sensor", "value": "kitchen" }])
+                );
+            }
+        }
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn subscribe_mqtt_canceled() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let broker = spawn_mqtt_broker().await;
+    let project_id = create_test_project(&main_window, &ctx).await;
+    let resource_id = create_test_item(
+        &main_window,
+        &ctx,
+        &project_id,
+        mqtt_endpoint(
+            &format!("mqtt://127.0.0.1:{}", broker.v5_port),
+            mqtt_params(MqttVersion::V5),
+        ),
+    )
+    .await;
+
+    let request_ctx = ContextBuilder::new().with_parent(ctx.clone()).freeze();
+    main_window
+        .track_cancellation("subscription", request_ctx.get_canceller())
+        .await;
+
+    let (channel, events) = test_channel();
+    let window_clone = main_window.clone();
+    let delegate_clone = delegate.clone();
+    let input = SubscribeQueueInput {
+        project_id,
+        resource_id,
+        max_messages: None,
+    };
+    let subscription = tokio::spawn(async move {
+        window_clone
+            .subscribe_queue(&request_ctx, &delegate_clone, input, channel)
+            .await
+    });
+
+    wait_for_event(&events, "subscribed").await;
+    main_window
+        .cancel_request(CancelRequestInput {
+            request_id: "subscription".to_string(),
+        })
+        .await
+        .unwrap();
+
+    // Stopping a subscription without a limit isn't an error
+    let output = tokio::time::timeout(Duration::from_secs(5), subscription)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(output.received, 0);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn subscribe_queue_invalid() {
+    let (main_window, delegate, ctx, cleanup, _) = set_up_test_main_window().await;
+    let broker = spawn_mqtt_broker().await;
+    let closed_port = closed_port();
+    let project_id = create_test_project(&main_window, &ctx).await;
+
+    let cases = [
+        // A subscription can't stop before receiving any message
+        (
+            mqtt_endpoint(
+                &format!("mqtt://127.0.0.1:{}", broker.v4_port),
+                mqtt_params(MqttVersion::V311),
+            ),
+            Some(0),
+        ),
+        // No broker is listening
+        (
+            amqp_endpoint(&format!("amqp://127.0.0.1:{closed_port}")),
+            None,
+        ),
+    ];
+    for (params, max_messages) in cases {
+        let resource_id = create_test_item(&main_window, &ctx, &project_id, params).await;
+
+        let (channel, events) = test_channel();
+        let result = main_window
+            .subscribe_queue(
+                &ctx,
+                &delegate,
+                SubscribeQueueInput {
+                    project_id: project_id.clone(),
+                    resource_id,
+                    max_messages,
+                },
+                channel,
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    cleanup().await;
+}
+
+struct MqttBroker {
+    v4_port: u16,
+    v5_port: u16,
+}
+
+/// Starts an in-process broker accepting MQTT 3.1.1 and MQTT 5 connections on their own
+/// ports, without authentication.
+async fn spawn_mqtt_broker() -> MqttBroker {
+    let broker = MqttBroker {
+        v4_port: closed_port(),
+        v5_port: closed_port(),
+    };
+
+    let server = |name: &str, port: u16| {
+        HashMap::from([(
+            name.to_string(),
+            ServerSettings {
+                name: name.to_string(),
+                listen: SocketAddr::from(([127, 0, 0, 1], port)),
+                tls: None,
+                next_connection_delay_ms: 1,
+                connections: ConnectionSettings {
+                    connection_timeout_ms: 5000,
+                    max_payload_size: 1024 * 1024,
+                    max_inflight_count: 100,
+                    auth: None,
+                    external_auth: None,
+                    dynamic_filters: true,
+                },
+            },
+        )])
+    };
+    let config = Config {
+        router: RouterConfig {
+            max_connections: 100,
+            max_outgoing_packet_count: 200,
+            max_segment_size: 1024 * 1024,
+            max_segment_count: 10,
+            ..Default::default()
+        },
+        v4: Some(server("v4", broker.v4_port)),
+        v5: Some(server("v5", broker.v5_port)),
+        ..Default::default()
+    };
+
+    // The broker runs its servers on threads of their own, until the test process exits
+    std::thread::spawn(move || Broker::new(config).start());
+
+    for port in [broker.v4_port, broker.v5_port] {
+        wait_for_listener(port).await;
+    }
+    broker
+}
+
+async fn wait_for_listener(port: u16) {
+    for _ in 0..100 {
+        if tokio::net::TcpStream::connect(("127.0.0.1", port))
+            .await
+            .is_ok()
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("mqtt broker is not listening on port {port}");
+}
+
+/// Returns a port nothing listens on, at least until something binds it again.
+fn closed_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+async fn wait_for_event(events: &Arc<Mutex<Vec<JsonValue>>>, kind: &str) {
+    for _ in 0..100 {
+        if events
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|event| event.get(kind).is_some())
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    panic!("`{kind}` queue event was not received");
+}
+
+fn test_channel() -> (Channel<QueueEvent>, Arc<Mutex<Vec<JsonValue>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let channel = Channel::new(move |body| {
+        if let InvokeResponseBody::Json(json) = body {
+            events_clone
+                .lock()
+                .unwrap()
+                .push(serde_json::from_str(&json).unwrap());
+        }
+        Ok(())
+    });
+
+    (channel, events)
+}
+
+fn mqtt_params(version: MqttVersion) -> MqttParams {
+    MqttParams {
+        version,
+        client_id: None,
+        username: None,
+        password: None,
+        topic: "sensors/kitchen".to_string(),
+        topic_filter: None,
+        qos: 0,
+        retain: false,
+        clean_session: true,
+        payload: r#"{"celsius":21}"#.to_string(),
+        content_type: None,
+        response_topic: None,
+        correlation_data: None,
+        message_expiry_secs: None,
+    }
+}
+
+fn mqtt_endpoint(url: &str, mqtt: MqttParams) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(ResourceProtocol::Mqtt),
+        mqtt: Some(mqtt),
+        ..endpoint(url)
+    }
+}
+
+fn amqp_endpoint(url: &str) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(ResourceProtocol::Amqp),
+        amqp: Some(AmqpParams {
+            username: None,
+            password: None,
+            exchange: String::new(),
+            routing_key: "orders".to_string(),
+            queue: None,
+            payload: "created".to_string(),
+            content_type: None,
+            correlation_id: None,
+            reply_to: None,
+            message_id: None,
+            expiration_ms: None,
+            priority: None,
+            persistent: false,
+        }),
+        ..endpoint(url)
+    }
+}
+
+fn endpoint(url: &str) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(""),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: None,
+        url: Some(url.to_string()),
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_test_project(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+) -> ProjectId {
+    main_window
+        .create_project(
+            ctx,
+            &CreateProjectInput {
+                inner: CreateProjectParams {
+                    name: "Test Project".to_string(),
+                    external_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_test_item(
+    main_window: &main::MainWindow<MockAppRuntime>,
+    ctx: &ArcContext,
+    project_id: &ProjectId,
+    params: CreateItemResourceParams,
+) -> ResourceId {
+    let project = main_window
+        .workspace
+        .load()
+        .get()
+        .project(ctx, project_id)
+        .await
+        .unwrap();
+
+    project
+        .handle
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(params))
+        .await
+        .unwrap()
+        .id
+}
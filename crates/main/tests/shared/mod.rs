@@ -29,6 +29,7 @@ use sapic_platform::{
     environment::environment_service_fs::EnvironmentServiceFs,
     github::{AppGitHubApiClient, auth::AppGitHubAuthAdapter},
     gitlab::{AppGitLabApiClient, auth::AppGitLabAuthAdapter},
+    oauth::{OAuth2Client, OAuth2Prompt},
     project::project_service_fs::ProjectServiceFs,
     server::HttpServerApiClient,
     workspace::{
@@ -48,6 +49,22 @@ use sapic_window::OldSapicWindowBuilder;
 use std::{collections::HashMap, path::PathBuf, pin::Pin, sync::Arc, time::Duration};
 
 pub type CleanupFn = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+/// Removes the test directory once dropped, so that it's also removed when a test fails
+/// before calling its cleanup.
+struct TestDirGuard(PathBuf);
+
+impl Drop for TestDirGuard {
+    fn drop(&mut self) {
+        match std::fs::remove_dir_all(&self.0) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                eprintln!("Failed to clean up test directory: {}", e);
+            }
+            _ => {}
+        }
+    }
+}
+
 pub fn random_test_dir_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .join("tests")
@@ -218,6 +235,16 @@ pub async fn set_up_test_main_window() -> (
         storage.clone(),
     ));
 
+    let oauth2_client = OAuth2Client::new(
+        HttpClientBuilder::new()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("failed to build oauth2 http client"),
+        Arc::new(moss_keyring::test::MockKeyringClient::new()),
+        Arc::new(HeadlessPrompt),
+    )
+    .into();
+
     let main_window = MainWindow::new(
         &delegate,
         0,
@@ -225,20 +252,19 @@ pub async fn set_up_test_main_window() -> (
         workspace.clone(),
         workspace_ops,
         MainWindowEnvironmentOps::new(environment_service),
+        oauth2_client,
     )
     .await
     .unwrap();
 
     let storage_clone = storage.clone();
     let cleanup_fn = Box::new({
-        let path = test_dir_path.clone();
+        let guard = TestDirGuard(test_dir_path.clone());
         let storage_clone = storage_clone.clone();
         move || {
             Box::pin(async move {
                 storage_clone.close().await.unwrap();
-                if let Err(e) = tokio::fs::remove_dir_all(&path).await {
-                    eprintln!("Failed to clean up test directory: {}", e);
-                }
+                drop(guard);
             }) as Pin<Box<dyn Future<Output = ()> + Send>>
         }
     });
@@ -246,6 +272,22 @@ pub async fn set_up_test_main_window() -> (
     (main_window, delegate, ctx, cleanup_fn, test_dir_path)
 }
 
+/// Stands in for the user in the oauth2 grants that need their consent.
+/// Stand-in authorization servers approve the grant without any input.
+pub struct HeadlessPrompt;
+
+impl OAuth2Prompt for HeadlessPrompt {
+    fn open_authorization_url(&self, url: &str) {
+        // Following the redirect of the server delivers the code to the loopback listener
+        let url = url.to_string();
+        tokio::spawn(async move {
+            let _ = reqwest::get(url).await;
+        });
+    }
+
+    fn show_device_code(&self, _verification_uri: &str, _user_code: &str) {}
+}
+
 #[allow(unused)]
 pub async fn test_list_projects(
     window: &MainWindow<MockAppRuntime>,
@@ -297,7 +339,12 @@ pub async fn create_test_endpoint_dir_entry(
                 class: ResourceClass::Endpoint,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
             }),
         )
         .await
@@ -327,7 +374,12 @@ pub async fn create_test_component_dir_entry(
                 class: ResourceClass::Component,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
             }),
         )
         .await
@@ -357,7 +409,12 @@ pub async fn create_test_schema_dir_entry(
                 class: ResourceClass::Schema,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
             }),
         )
         .await
@@ -369,3 +426,53 @@ pub async fn create_test_schema_dir_entry(
 pub fn random_entry_name() -> String {
     format!("Test_{}_Entry", random_string(10))
 }
+
+/// Covers the constructs the openapi importer converts, and one it skips.
+#[allow(unused)]
+pub const PETSTORE_OPENAPI_SPEC: &str = r##"
+openapi: 3.0.3
+info:
+  title: Petstore
+  version: 1.0.0
+servers:
+  - url: https://petstore.example.com/v1
+paths:
+  /pets:
+    get:
+      summary: List pets
+      tags: [pets]
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+            default: 20
+    post:
+      summary: Create pet
+      tags: [pets]
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/Pet"
+      callbacks:
+        onCreated: {}
+  /pets/{petId}:
+    get:
+      summary: Get pet
+      tags: [pets]
+      parameters:
+        - name: petId
+          in: path
+          required: true
+          schema:
+            type: string
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name:
+          type: string
+          nullable: true
+"##;
@@ -0,0 +1,6 @@
+# The OAuth 2.0 auth of the request isn't included
+curl -X PUT 'http://localhost:8080/login' \
+  -H 'X-Request-Id: {{request_id}}' \
+  -u 'admin:{{password}}' \
+  --data-urlencode 'user=Rex' \
+  --data-urlencode 'scope=read write'
@@ -0,0 +1,8 @@
+curl -X POST 'https://api.example.com/pets?tag=good%20boy' \
+  -H 'Accept: application/json' \
+  -H 'Authorization: Bearer {{token}}' \
+  -H 'Content-Type: application/json' \
+  --data-raw '{
+  "name": "Rex",
+  "bio": "It'\''s a \"good\" dog"
+}'
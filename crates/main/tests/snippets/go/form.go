@@ -0,0 +1,37 @@
+package main
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+)
+
+func main() {
+	// The OAuth 2.0 auth of the request isn't included
+	form := url.Values{}
+	form.Add("user", "Rex")
+	form.Add("scope", "read write")
+	body := strings.NewReader(form.Encode())
+
+	req, err := http.NewRequest("PUT", "http://localhost:8080/login", body)
+	if err != nil {
+		panic(err)
+	}
+	req.Header.Add("X-Request-Id", "{{request_id}}")
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	req.SetBasicAuth("admin", "{{password}}")
+
+	res, err := http.DefaultClient.Do(req)
+	if err != nil {
+		panic(err)
+	}
+	defer res.Body.Close()
+
+	data, err := io.ReadAll(res.Body)
+	if err != nil {
+		panic(err)
+	}
+	fmt.Println(string(data))
+}
@@ -0,0 +1,6 @@
+# The OAuth 2.0 auth of the request isn't included
+http --form PUT 'http://localhost:8080/login' \
+  'X-Request-Id:{{request_id}}' \
+  -a 'admin:{{password}}' \
+  'user=Rex' \
+  'scope=read write'
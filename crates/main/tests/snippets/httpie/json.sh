@@ -0,0 +1,8 @@
+http POST 'https://api.example.com/pets?tag=good%20boy' \
+  'Accept:application/json' \
+  'Authorization:Bearer {{token}}' \
+  'Content-Type:application/json' \
+  --raw '{
+  "name": "Rex",
+  "bio": "It'\''s a \"good\" dog"
+}'
@@ -0,0 +1,15 @@
+// The OAuth 2.0 auth of the request isn't included
+const body = new URLSearchParams();
+body.append("user", "Rex");
+body.append("scope", "read write");
+
+const response = await fetch("http://localhost:8080/login", {
+  method: "PUT",
+  headers: {
+    "X-Request-Id": "{{request_id}}",
+    "Authorization": "Basic " + btoa("admin:{{password}}"),
+  },
+  body,
+});
+
+console.log(await response.text());
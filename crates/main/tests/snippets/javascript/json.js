@@ -0,0 +1,14 @@
+const response = await fetch("https://api.example.com/pets?tag=good%20boy", {
+  method: "POST",
+  headers: {
+    "Accept": "application/json",
+    "Authorization": "Bearer {{token}}",
+    "Content-Type": "application/json",
+  },
+  body: `{
+  "name": "Rex",
+  "bio": "It's a \\"good\\" dog"
+}`,
+});
+
+console.log(await response.text());
@@ -0,0 +1,15 @@
+import requests
+
+# The OAuth 2.0 auth of the request isn't included
+url = "http://localhost:8080/login"
+headers = {
+    "X-Request-Id": "{{request_id}}",
+}
+auth = ("admin", "{{password}}")
+data = {
+    "user": "Rex",
+    "scope": "read write",
+}
+
+response = requests.request("PUT", url, headers=headers, auth=auth, data=data)
+print(response.text)
@@ -0,0 +1,15 @@
+import requests
+
+url = "https://api.example.com/pets?tag=good%20boy"
+headers = {
+    "Accept": "application/json",
+    "Authorization": "Bearer {{token}}",
+    "Content-Type": "application/json",
+}
+data = """{
+  "name": "Rex",
+  "bio": "It's a \\"good\\" dog"
+}"""
+
+response = requests.request("POST", url, headers=headers, data=data)
+print(response.text)
@@ -0,0 +1,15 @@
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The OAuth 2.0 auth of the request isn't included
+    let client = reqwest::Client::new();
+    let response = client
+        .put("http://localhost:8080/login")
+        .header("X-Request-Id", "{{request_id}}")
+        .basic_auth("admin", Some("{{password}}"))
+        .form(&[("user", "Rex"), ("scope", "read write")])
+        .send()
+        .await?;
+
+    println!("{}", response.text().await?);
+    Ok(())
+}
@@ -0,0 +1,20 @@
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post("https://api.example.com/pets?tag=good%20boy")
+        .header("Accept", "application/json")
+        .header("Authorization", "Bearer {{token}}")
+        .header("Content-Type", "application/json")
+        .body(
+            r#"{
+  "name": "Rex",
+  "bio": "It's a \"good\" dog"
+}"#,
+        )
+        .send()
+        .await?;
+
+    println!("{}", response.text().await?);
+    Ok(())
+}
@@ -1,18 +1,38 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeString, JsonValue } from "@repo/moss-bindingutils";
 import type { ResourceClass, ResourceKind, ResourceProtocol } from "./resource/primitives";
 import type {
+  AddBodyParams,
+  AddHeaderParams,
+  AddPathParamParams,
+  AddQueryParamParams,
   AfterCreateResourceDescription,
   AfterUpdateDirResourceDescription,
   AfterUpdateItemResourceDescription,
+  AmqpInfo,
+  AuthInfo,
   BodyInfo,
+  CaseInfo,
+  ChangeCaseBody,
+  ChangeExpectedResponse,
   CreateDirResourceParams,
   CreateItemResourceParams,
+  ExpectedResponse,
+  GraphqlInfo,
+  GrpcInfo,
   HeaderInfo,
+  MqttInfo,
   PathParamInfo,
   QueryParamInfo,
+  SchemaViolation,
+  SseInfo,
   UpdateDirResourceParams,
+  UpdateHeaderParams,
   UpdateItemResourceParams,
+  UpdatePathParamParams,
+  UpdateQueryParamParams,
   VcsOperation,
+  WebSocketInfo,
 } from "./types";
 
 /**
@@ -52,6 +72,25 @@ export type BatchUpdateResourceOutputKind =
   | { "ITEM": AfterUpdateItemResourceDescription }
   | { "DIR": AfterUpdateDirResourceDescription };
 
+/**
+ * @category Operation
+ */
+export type CreateResourceCaseInput = {
+  resourceId: string;
+  name: string;
+  description?: string;
+  headers?: Array<AddHeaderParams>;
+  pathParams?: Array<AddPathParamParams>;
+  queryParams?: Array<AddQueryParamParams>;
+  body?: AddBodyParams;
+  expectedResponse?: ExpectedResponse;
+};
+
+/**
+ * @category Operation
+ */
+export type CreateResourceCaseOutput = { id: string };
+
 /**
  * @category Operation
  */
@@ -62,6 +101,16 @@ export type CreateResourceInput = { "ITEM": CreateItemResourceParams } | { "DIR"
  */
 export type CreateResourceOutput = { id: string };
 
+/**
+ * @category Operation
+ */
+export type DeleteResourceCaseInput = { resourceId: string; id: string };
+
+/**
+ * @category Operation
+ */
+export type DeleteResourceCaseOutput = { id: string };
+
 /**
  * @category Operation
  */
@@ -74,6 +123,7 @@ export type DeleteResourceOutput = { id: string };
 
 export type DescribeResourceOutput = {
   name: string;
+  description?: string;
   class: ResourceClass;
   kind: ResourceKind;
   protocol?: ResourceProtocol;
@@ -82,6 +132,44 @@ export type DescribeResourceOutput = {
   pathParams: Array<PathParamInfo>;
   queryParams: Array<QueryParamInfo>;
   body?: BodyInfo;
+  /**
+   * The component the body is expanded from, if the resource doesn't define its own.
+   */
+  bodyComponent?: string;
+  bodySchema?: string;
+  /**
+   * Component resources referenced by the resource.
+   */
+  components: Array<string>;
+  /**
+   * The auth in effect for the resource, after inheritance.
+   */
+  auth?: AuthInfo;
+  schema?: JsonValue;
+  /**
+   * Subprotocols and saved messages of a WebSocket endpoint.
+   */
+  websocket?: WebSocketInfo;
+  /**
+   * Query document, operation name and variables of a GraphQL endpoint.
+   */
+  graphql?: GraphqlInfo;
+  /**
+   * Service descriptors, method and request messages of a gRPC endpoint.
+   */
+  grpc?: GrpcInfo;
+  /**
+   * Reconnection and recording settings of an HTTP endpoint streaming server-sent events.
+   */
+  sse?: SseInfo;
+  /**
+   * Exchange, routing key and message to publish of an AMQP endpoint.
+   */
+  amqp?: AmqpInfo;
+  /**
+   * Topic and message to publish of an MQTT endpoint.
+   */
+  mqtt?: MqttInfo;
 };
 
 /**
@@ -94,6 +182,42 @@ export type ExecuteVcsOperationInput = { operation: VcsOperation };
  */
 export type ExecuteVcsOperationOutput = Record<string, never>;
 
+/**
+ * @category Operation
+ */
+export type ListResourceCasesInput = { resourceId: string };
+
+/**
+ * @category Operation
+ */
+export type ListResourceCasesOutput = { cases: Array<CaseInfo> };
+
+/**
+ * @category Operation
+ */
+export type UpdateResourceCaseInput = {
+  resourceId: string;
+  id: string;
+  name?: string;
+  description?: ChangeString;
+  headersToAdd?: Array<AddHeaderParams>;
+  headersToUpdate?: Array<UpdateHeaderParams>;
+  headersToRemove?: Array<string>;
+  pathParamsToAdd?: Array<AddPathParamParams>;
+  pathParamsToUpdate?: Array<UpdatePathParamParams>;
+  pathParamsToRemove?: Array<string>;
+  queryParamsToAdd?: Array<AddQueryParamParams>;
+  queryParamsToUpdate?: Array<UpdateQueryParamParams>;
+  queryParamsToRemove?: Array<string>;
+  body?: ChangeCaseBody;
+  expectedResponse?: ChangeExpectedResponse;
+};
+
+/**
+ * @category Operation
+ */
+export type UpdateResourceCaseOutput = { id: string };
+
 /**
  * @category Operation
  */
@@ -105,3 +229,19 @@ export type UpdateResourceInput = { "ITEM": UpdateItemResourceParams } | { "DIR"
 export type UpdateResourceOutput =
   | { "ITEM": AfterUpdateItemResourceDescription }
   | { "DIR": AfterUpdateDirResourceDescription };
+
+/**
+ * @category Operation
+ */
+export type ValidateResourceBodyInput = {
+  id: string;
+  /**
+   * Validated in place of the stored json body, e.g. for unsaved changes.
+   */
+  body?: JsonValue;
+};
+
+/**
+ * @category Operation
+ */
+export type ValidateResourceBodyOutput = { violations: Array<SchemaViolation> };
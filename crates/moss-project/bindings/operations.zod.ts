@@ -1,27 +1,60 @@
 // Generated by ts-to-zod
+import { changeStringSchema, jsonValueSchema } from "@repo/moss-bindingutils";
 import { z } from "zod";
 import { resourceClassSchema, resourceKindSchema, resourceProtocolSchema } from "./resource/primitives.zod";
 import {
+  addBodyParamsSchema,
+  addHeaderParamsSchema,
+  addPathParamParamsSchema,
+  addQueryParamParamsSchema,
   afterCreateResourceDescriptionSchema,
   afterUpdateDirResourceDescriptionSchema,
   afterUpdateItemResourceDescriptionSchema,
+  amqpInfoSchema,
+  authInfoSchema,
   bodyInfoSchema,
+  caseInfoSchema,
+  changeCaseBodySchema,
+  changeExpectedResponseSchema,
   createDirResourceParamsSchema,
   createItemResourceParamsSchema,
+  expectedResponseSchema,
+  graphqlInfoSchema,
+  grpcInfoSchema,
   headerInfoSchema,
+  mqttInfoSchema,
   pathParamInfoSchema,
   queryParamInfoSchema,
+  schemaViolationSchema,
+  sseInfoSchema,
   updateDirResourceParamsSchema,
+  updateHeaderParamsSchema,
   updateItemResourceParamsSchema,
+  updatePathParamParamsSchema,
+  updateQueryParamParamsSchema,
   vcsOperationSchema,
+  webSocketInfoSchema,
 } from "./types.zod";
 
 export const batchUpdateResourceOutputSchema = z.record(z.string(), z.never());
 
+export const createResourceCaseOutputSchema = z.object({
+  id: z.string(),
+});
+
 export const createResourceOutputSchema = z.object({
   id: z.string(),
 });
 
+export const deleteResourceCaseInputSchema = z.object({
+  resourceId: z.string(),
+  id: z.string(),
+});
+
+export const deleteResourceCaseOutputSchema = z.object({
+  id: z.string(),
+});
+
 export const deleteResourceInputSchema = z.object({
   id: z.string(),
 });
@@ -31,6 +64,20 @@ export const deleteResourceOutputSchema = z.object({
 });
 
 export const executeVcsOperationOutputSchema = z.record(z.string(), z.never());
+
+export const listResourceCasesInputSchema = z.object({
+  resourceId: z.string(),
+});
+
+export const updateResourceCaseOutputSchema = z.object({
+  id: z.string(),
+});
+
+export const validateResourceBodyInputSchema = z.object({
+  id: z.string(),
+  body: jsonValueSchema.optional(),
+});
+
 export const batchCreateResourceKindSchema = z.union([
   z.object({
     "ITEM": createItemResourceParamsSchema,
@@ -62,6 +109,17 @@ export const batchUpdateResourceOutputKindSchema = z.union([
   }),
 ]);
 
+export const createResourceCaseInputSchema = z.object({
+  resourceId: z.string(),
+  name: z.string(),
+  description: z.string().optional(),
+  headers: z.array(addHeaderParamsSchema).optional(),
+  pathParams: z.array(addPathParamParamsSchema).optional(),
+  queryParams: z.array(addQueryParamParamsSchema).optional(),
+  body: addBodyParamsSchema.optional(),
+  expectedResponse: expectedResponseSchema.optional(),
+});
+
 export const createResourceInputSchema = z.union([
   z.object({
     "ITEM": createItemResourceParamsSchema,
@@ -73,6 +131,7 @@ export const createResourceInputSchema = z.union([
 
 export const describeResourceOutputSchema = z.object({
   name: z.string(),
+  description: z.string().optional(),
   class: resourceClassSchema,
   kind: resourceKindSchema,
   protocol: resourceProtocolSchema.optional(),
@@ -81,12 +140,45 @@ export const describeResourceOutputSchema = z.object({
   pathParams: z.array(pathParamInfoSchema),
   queryParams: z.array(queryParamInfoSchema),
   body: bodyInfoSchema.optional(),
+  bodyComponent: z.string().optional(),
+  bodySchema: z.string().optional(),
+  components: z.array(z.string()),
+  auth: authInfoSchema.optional(),
+  schema: jsonValueSchema.optional(),
+  websocket: webSocketInfoSchema.optional(),
+  graphql: graphqlInfoSchema.optional(),
+  grpc: grpcInfoSchema.optional(),
+  sse: sseInfoSchema.optional(),
+  amqp: amqpInfoSchema.optional(),
+  mqtt: mqttInfoSchema.optional(),
 });
 
 export const executeVcsOperationInputSchema = z.object({
   operation: vcsOperationSchema,
 });
 
+export const listResourceCasesOutputSchema = z.object({
+  cases: z.array(caseInfoSchema),
+});
+
+export const updateResourceCaseInputSchema = z.object({
+  resourceId: z.string(),
+  id: z.string(),
+  name: z.string().optional(),
+  description: changeStringSchema.optional(),
+  headersToAdd: z.array(addHeaderParamsSchema).optional(),
+  headersToUpdate: z.array(updateHeaderParamsSchema).optional(),
+  headersToRemove: z.array(z.string()).optional(),
+  pathParamsToAdd: z.array(addPathParamParamsSchema).optional(),
+  pathParamsToUpdate: z.array(updatePathParamParamsSchema).optional(),
+  pathParamsToRemove: z.array(z.string()).optional(),
+  queryParamsToAdd: z.array(addQueryParamParamsSchema).optional(),
+  queryParamsToUpdate: z.array(updateQueryParamParamsSchema).optional(),
+  queryParamsToRemove: z.array(z.string()).optional(),
+  body: changeCaseBodySchema.optional(),
+  expectedResponse: changeExpectedResponseSchema.optional(),
+});
+
 export const updateResourceInputSchema = z.union([
   z.object({
     "ITEM": updateItemResourceParamsSchema,
@@ -105,6 +197,10 @@ export const updateResourceOutputSchema = z.union([
   }),
 ]);
 
+export const validateResourceBodyOutputSchema = z.object({
+  violations: z.array(schemaViolationSchema),
+});
+
 export const batchCreateResourceInputSchema = z.object({
   resources: z.array(batchCreateResourceKindSchema),
 });
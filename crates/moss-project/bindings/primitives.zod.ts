@@ -1,4 +1,17 @@
 // Generated by ts-to-zod
 import { z } from "zod";
 
-export const httpMethodSchema = z.union([z.literal("GET"), z.literal("POST"), z.literal("PUT"), z.literal("DELETE")]);
+export const httpMethodSchema = z.union([
+  z.literal("GET"),
+  z.literal("HEAD"),
+  z.literal("POST"),
+  z.literal("PUT"),
+  z.literal("PATCH"),
+  z.literal("DELETE"),
+  z.literal("CONNECT"),
+  z.literal("OPTIONS"),
+  z.literal("TRACE"),
+  z.object({
+    "CUSTOM": z.string(),
+  }),
+]);
@@ -1,5 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
-import { ChangeJsonValue, ChangeString, JsonValue } from "@repo/moss-bindingutils";
+import { ChangeJsonValue, ChangeString, ChangeUsize, JsonValue } from "@repo/moss-bindingutils";
 import type { ResourcePath } from "./main/types";
 import type { ResourceClass, ResourceProtocol } from "./resource/primitives";
 
@@ -79,6 +79,11 @@ export type AddUrlencodedParamParams = {
   id?: string;
 };
 
+/**
+ * @category Type
+ */
+export type AddWebSocketMessageParams = { name: string; payload: WebSocketMessagePayload };
+
 /**
  * @category Type
  */
@@ -92,7 +97,133 @@ export type AfterUpdateDirResourceDescription = { id: string; path: ResourcePath
 /**
  * @category Type
  */
-export type AfterUpdateItemResourceDescription = { id: string; path: ResourcePath };
+export type AfterUpdateItemResourceDescription = {
+  id: string;
+  path: ResourcePath;
+  /**
+   * Resources referencing the updated component, whose descriptions have changed along with it.
+   */
+  dependents: Array<string>;
+};
+
+/**
+ * @category Type
+ */
+export type AmqpInfo = {
+  username?: string;
+  password?: string;
+  exchange: string;
+  routingKey: string;
+  queue?: string;
+  payload: string;
+  contentType?: string;
+  correlationId?: string;
+  replyTo?: string;
+  messageId?: string;
+  expirationMs?: number;
+  priority?: number;
+  persistent: boolean;
+};
+
+/**
+ * @category Type
+ */
+export type AmqpParams = {
+  /**
+   * Credentials to connect with, overriding the ones of the `amqp://` url. The password
+   * must reference an environment variable, e.g. `{{password}}`.
+   */
+  username?: string;
+  password?: string;
+  /**
+   * Exchange messages are published to, the default exchange when empty.
+   */
+  exchange?: string;
+  /**
+   * Routing key messages are published with. Subscriptions without a queue bind to the
+   * exchange with it.
+   */
+  routingKey?: string;
+  /**
+   * Queue subscriptions consume from. When not set, they consume from the queue named after
+   * the routing key with the default exchange, and from a temporary queue bound to the
+   * exchange for the time of the subscription otherwise.
+   */
+  queue?: string;
+  /**
+   * Body of the published messages. Can reference environment variables.
+   */
+  payload?: string;
+  contentType?: string;
+  correlationId?: string;
+  replyTo?: string;
+  messageId?: string;
+  /**
+   * Time the published messages live in a queue before expiring, in milliseconds.
+   */
+  expirationMs?: number;
+  priority?: number;
+  /**
+   * Publishes the messages with the persistent delivery mode, so that they survive a
+   * restart of the broker.
+   */
+  persistent?: boolean;
+};
+
+/**
+ * @category Type
+ */
+export type ApiKeyLocation = "header" | "query";
+
+/**
+ * @category Type
+ */
+export type AuthInfo = {
+  scheme: AuthScheme;
+  /**
+   * The directory the auth is inherited from, if it isn't defined on the resource itself.
+   */
+  inheritedFrom?: string;
+};
+
+/**
+ * Authentication scheme of a resource.
+ * Secrets must reference an environment variable, e.g. `{{token}}`, instead of being stored inline.
+ *
+ * @category Type
+ */
+export type AuthScheme =
+  | "none"
+  | "inherit"
+  | { "basic": { username: string; password: string } }
+  | { "bearer": { token: string } }
+  | { "apiKey": { key: string; value: string; location: ApiKeyLocation } }
+  | { "digest": { username: string; password: string } }
+  | { "oauth2": OAuth2Config }
+  | { "awsSigV4": AwsSigV4Config };
+
+/**
+ * AWS credentials and scope used to sign requests.
+ * The keys and the session token must reference environment variables.
+ *
+ * @category Type
+ */
+export type AwsSigV4Config = {
+  accessKey: string;
+  secretKey: string;
+  /**
+   * Required for temporary credentials.
+   */
+  sessionToken?: string;
+  /**
+   * For example `us-east-1`.
+   */
+  region: string;
+  /**
+   * Signing name of the service, for example `execute-api`, `lambda` or `s3`.
+   */
+  service: string;
+};
 
 /**
  * @category Type
@@ -108,7 +239,81 @@ export type BodyInfo =
 /**
  * @category Type
  */
-export type CreateDirResourceParams = { path: string; class: ResourceClass; name: string; order: number };
+export type CaseInfo = {
+  id: string;
+  name: string;
+  description?: string;
+  /**
+   * Entries overriding the ones of the endpoint with the same name.
+   */
+  headers: Array<HeaderInfo>;
+  pathParams: Array<PathParamInfo>;
+  queryParams: Array<QueryParamInfo>;
+  /**
+   * Replaces the body of the endpoint when set.
+   */
+  body?: BodyInfo;
+  expectedResponse?: ExpectedResponse;
+};
+
+/**
+ * @category Type
+ */
+export type ChangeAmqp = { "UPDATE": AmqpParams } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type ChangeAuth = { "UPDATE": AuthScheme } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type ChangeBodySchema = { "UPDATE": string } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type ChangeCaseBody = { "UPDATE": AddBodyParams } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type ChangeExpectedResponse = { "UPDATE": ExpectedResponse } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type ChangeMqtt = { "UPDATE": MqttParams } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type ChangeSse = { "UPDATE": SseParams } | "REMOVE";
+
+/**
+ * @category Type
+ */
+export type CreateDirResourceParams = {
+  path: string;
+  class: ResourceClass;
+  name: string;
+  /**
+   * Notes about the resource, such as what it is used for.
+   */
+  description?: string;
+  order: number;
+  /**
+   * Entries marked as `propagate` are inherited by every resource inside the directory.
+   */
+  headers?: Array<AddHeaderParams>;
+  pathParams?: Array<AddPathParamParams>;
+  queryParams?: Array<AddQueryParamParams>;
+  /**
+   * Used by every resource inside the directory that inherits its auth.
+   */
+  auth?: AuthScheme;
+};
 
 /**
  * @category Type
@@ -117,6 +322,10 @@ export type CreateItemResourceParams = {
   path: string;
   class: ResourceClass;
   name: string;
+  /**
+   * Notes about the resource, such as what it is used for.
+   */
+  description?: string;
   order: number;
   protocol?: ResourceProtocol;
   url?: string;
@@ -124,8 +333,50 @@ export type CreateItemResourceParams = {
   pathParams: Array<AddPathParamParams>;
   queryParams: Array<AddQueryParamParams>;
   body?: AddBodyParams;
+  /**
+   * Schema resource the json body is validated against.
+   */
+  bodySchema?: string;
+  /**
+   * Component resources expanded into the endpoint, in order of precedence.
+   */
+  components?: Array<string>;
+  auth?: AuthScheme;
+  /**
+   * JSON Schema document of a schema resource.
+   */
+  schema?: JsonValue;
+  /**
+   * Subprotocols and saved messages of a WebSocket endpoint.
+   */
+  websocket?: WebSocketParams;
+  /**
+   * Query document, operation name and variables of a GraphQL endpoint.
+   */
+  graphql?: GraphqlParams;
+  /**
+   * Service descriptors, method and request messages of a gRPC endpoint.
+   */
+  grpc?: GrpcParams;
+  /**
+   * Treats the response of an HTTP endpoint as a stream of server-sent events.
+   */
+  sse?: SseParams;
+  /**
+   * Exchange, routing key and message to publish of an AMQP endpoint.
+   */
+  amqp?: AmqpParams;
+  /**
+   * Topic and message to publish of an MQTT endpoint.
+   */
+  mqtt?: MqttParams;
 };
 
+/**
+ * @category Type
+ */
+export type ExpectedResponse = { status?: number; headers?: Record<string, string>; body?: JsonValue };
+
 /**
  * @category Type
  */
@@ -137,6 +388,10 @@ export type FormDataParamInfo = {
   disabled: boolean;
   propagate: boolean;
   order?: number;
+  /**
+   * The directory the entry is inherited from, if it isn't defined on the resource itself.
+   */
+  inheritedFrom?: string;
 };
 
 /**
@@ -144,6 +399,72 @@ export type FormDataParamInfo = {
  */
 export type FormDataParamOptions = { disabled: boolean; propagate: boolean };
 
+/**
+ * @category Type
+ */
+export type GraphqlInfo = { query: string; operationName?: string; variables?: JsonValue };
+
+/**
+ * @category Type
+ */
+export type GraphqlParams = {
+  /**
+   * The query document, which may define several operations.
+   */
+  query: string;
+  /**
+   * Operation of the document to execute, required when it defines more than one.
+   */
+  operationName?: string;
+  /**
+   * Values of the operation variables, as a JSON object.
+   */
+  variables?: JsonValue;
+};
+
+/**
+ * @category Type
+ */
+export type GrpcInfo = {
+  protoFiles: Array<string>;
+  importPaths: Array<string>;
+  service: string;
+  method: string;
+  messages: Array<JsonValue>;
+  timeoutMs?: number;
+};
+
+/**
+ * @category Type
+ */
+export type GrpcParams = {
+  /**
+   * `.proto` files describing the service. When empty, the service is discovered through
+   * the server reflection of the endpoint.
+   */
+  protoFiles?: Array<string>;
+  /**
+   * Directories the imports of the `.proto` files are resolved against, besides the
+   * directories of the files themselves.
+   */
+  importPaths?: Array<string>;
+  /**
+   * Fully qualified name of the service, such as `helloworld.Greeter`.
+   */
+  service: string;
+  method: string;
+  /**
+   * Request messages as JSON objects, sent in order. Unary and server-streaming methods
+   * take exactly one.
+   */
+  messages?: Array<JsonValue>;
+  /**
+   * Deadline of the call, in milliseconds. Unary calls are given 30 seconds when omitted,
+   * streaming calls have no deadline.
+   */
+  timeoutMs?: number;
+};
+
 /**
  * @category Type
  */
@@ -155,6 +476,14 @@ export type HeaderInfo = {
   disabled: boolean;
   propagate: boolean;
   order?: number;
+  /**
+   * The directory the entry is inherited from, if it isn't defined on the resource itself.
+   */
+  inheritedFrom?: string;
+  /**
+   * The component the entry is expanded from, if it isn't defined on the resource itself.
+   */
+  component?: string;
 };
 
 /**
@@ -162,6 +491,120 @@ export type HeaderInfo = {
  */
 export type HeaderParamOptions = { disabled: boolean; propagate: boolean };
 
+/**
+ * @category Type
+ */
+export type MqttInfo = {
+  version: MqttVersion;
+  clientId?: string;
+  username?: string;
+  password?: string;
+  topic: string;
+  topicFilter?: string;
+  qos: number;
+  retain: boolean;
+  cleanSession: boolean;
+  payload: string;
+  contentType?: string;
+  responseTopic?: string;
+  correlationData?: string;
+  messageExpirySecs?: number;
+};
+
+/**
+ * @category Type
+ */
+export type MqttParams = {
+  /**
+   * Version of the protocol spoken with the broker. Defaults to MQTT 3.1.1.
+   */
+  version?: MqttVersion;
+  /**
+   * Identifier of the client, a random one being generated when not set.
+   * Can reference environment variables.
+   */
+  clientId?: string;
+  /**
+   * Credentials to connect with, overriding the ones of the `mqtt://` url. The password
+   * must reference an environment variable, e.g. `{{password}}`.
+   */
+  username?: string;
+  password?: string;
+  /**
+   * Topic messages are published to. Can reference environment variables.
+   */
+  topic: string;
+  /**
+   * Topic filter of subscriptions, which may contain the `+` and `#` wildcards.
+   * Subscriptions are made to the topic when not set.
+   */
+  topicFilter?: string;
+  /**
+   * Quality of service level messages are published and subscribed with, from 0 to 2.
+   */
+  qos?: number;
+  /**
+   * Asks the broker to retain the published messages for future subscribers of the topic.
+   */
+  retain?: boolean;
+  /**
+   * Starts a session without the subscriptions and messages pending from a previous one.
+   */
+  cleanSession?: boolean;
+  /**
+   * Body of the published messages. Can reference environment variables.
+   */
+  payload?: string;
+  /**
+   * Properties of the published messages, sent with MQTT 5 only.
+   */
+  contentType?: string;
+  responseTopic?: string;
+  correlationData?: string;
+  /**
+   * Time the broker keeps the published messages for subscribers, in seconds.
+   */
+  messageExpirySecs?: number;
+};
+
+/**
+ * @category Type
+ */
+export type MqttVersion = "3.1.1" | "5";
+
+/**
+ * @category Type
+ */
+export type OAuth2Config = {
+  grant: OAuth2Grant;
+  /**
+   * Required by the authorization code grant.
+   */
+  authUrl?: string;
+  tokenUrl: string;
+  /**
+   * Required by the device code grant.
+   */
+  deviceAuthUrl?: string;
+  clientId: string;
+  clientSecret?: string;
+  scopes?: Array<string>;
+  /**
+   * Required by the refresh token grant.
+   */
+  refreshToken?: string;
+  /**
+   * Port of the loopback redirect uri of the authorization code grant.
+   * A random port is used if not set.
+   */
+  callbackPort?: number;
+};
+
+/**
+ * @category Type
+ */
+export type OAuth2Grant = "authorizationCode" | "clientCredentials" | "deviceCode" | "refreshToken";
+
 /**
  * @category Type
  */
@@ -173,6 +616,14 @@ export type PathParamInfo = {
   disabled: boolean;
   propagate: boolean;
   order?: number;
+  /**
+   * The directory the entry is inherited from, if it isn't defined on the resource itself.
+   */
+  inheritedFrom?: string;
+  /**
+   * The component the entry is expanded from, if it isn't defined on the resource itself.
+   */
+  component?: string;
 };
 
 /**
@@ -191,6 +642,14 @@ export type QueryParamInfo = {
   disabled: boolean;
   propagate: boolean;
   order?: number;
+  /**
+   * The directory the entry is inherited from, if it isn't defined on the resource itself.
+   */
+  inheritedFrom?: string;
+  /**
+   * The component the entry is expanded from, if it isn't defined on the resource itself.
+   */
+  component?: string;
 };
 
 /**
@@ -198,6 +657,48 @@ export type QueryParamInfo = {
  */
 export type QueryParamOptions = { disabled: boolean; propagate: boolean };
 
+/**
+ * @category Type
+ */
+export type SchemaViolation = {
+  /**
+   * JSON pointer to the offending value of the body, empty for the body itself.
+   */
+  pointer: string;
+  message: string;
+};
+
+/**
+ * @category Type
+ */
+export type SseInfo = { reconnect: boolean; retryMs?: number; maxReconnects?: number; record: boolean };
+
+/**
+ * @category Type
+ */
+export type SseParams = {
+  /**
+   * Reconnects once the stream ends or the connection is lost, resuming from the last
+   * event received through the `Last-Event-ID` header.
+   */
+  reconnect?: boolean;
+  /**
+   * Delay before reconnecting, in milliseconds, until the server sets one with a `retry`
+   * field. Defaults to 3 seconds.
+   */
+  retryMs?: number;
+  /**
+   * Attempts to reconnect in a row before giving up, reconnecting until the stream is
+   * stopped when not set.
+   */
+  maxReconnects?: number;
+  /**
+   * Records the events received to the project storage, replacing the ones of the previous
+   * stream.
+   */
+  record?: boolean;
+};
+
 /**
  * @category Type
  */
@@ -234,8 +735,19 @@ export type UpdateDirResourceParams = {
    */
   path?: string;
   name?: string;
+  description?: ChangeString;
   order?: number;
   expanded?: boolean;
+  headersToAdd?: Array<AddHeaderParams>;
+  headersToUpdate?: Array<UpdateHeaderParams>;
+  headersToRemove?: Array<string>;
+  pathParamsToAdd?: Array<AddPathParamParams>;
+  pathParamsToUpdate?: Array<UpdatePathParamParams>;
+  pathParamsToRemove?: Array<string>;
+  queryParamsToAdd?: Array<AddQueryParamParams>;
+  queryParamsToUpdate?: Array<UpdateQueryParamParams>;
+  queryParamsToRemove?: Array<string>;
+  auth?: ChangeAuth;
 };
 
 /**
@@ -250,6 +762,32 @@ export type UpdateFormDataParamParams = {
   options?: FormDataParamOptions;
 };
 
+/**
+ * @category Type
+ */
+export type UpdateGraphqlParams = { query?: string; operationName?: ChangeString; variables?: ChangeJsonValue };
+
+/**
+ * @category Type
+ */
+export type UpdateGrpcParams = {
+  /**
+   * Replaces the `.proto` files describing the service.
+   */
+  protoFiles?: Array<string>;
+  /**
+   * Replaces the directories imports are resolved against.
+   */
+  importPaths?: Array<string>;
+  service?: string;
+  method?: string;
+  /**
+   * Replaces the request messages.
+   */
+  messages?: Array<JsonValue>;
+  timeoutMs?: ChangeUsize;
+};
+
 /**
  * @category Type
  */
@@ -279,6 +817,7 @@ export type UpdateItemResourceParams = {
    */
   path?: string;
   name?: string;
+  description?: ChangeString;
   protocol?: ResourceProtocol;
   url?: string;
   headersToAdd: Array<AddHeaderParams>;
@@ -291,6 +830,20 @@ export type UpdateItemResourceParams = {
   queryParamsToUpdate: Array<UpdateQueryParamParams>;
   queryParamsToRemove: Array<string>;
   body?: UpdateBodyParams;
+  bodySchema?: ChangeBodySchema;
+  componentsToAdd?: Array<string>;
+  componentsToRemove?: Array<string>;
+  auth?: ChangeAuth;
+  /**
+   * Replaces the JSON Schema document of a schema resource.
+   */
+  schema?: JsonValue;
+  websocket?: UpdateWebSocketParams;
+  graphql?: UpdateGraphqlParams;
+  grpc?: UpdateGrpcParams;
+  sse?: ChangeSse;
+  amqp?: ChangeAmqp;
+  mqtt?: ChangeMqtt;
 };
 
 /**
@@ -339,6 +892,24 @@ export type UpdateUrlencodedParamParams = {
   options?: UrlencodedParamOptions;
 };
 
+/**
+ * @category Type
+ */
+export type UpdateWebSocketMessageParams = { id: string; name?: string; payload?: WebSocketMessagePayload };
+
+/**
+ * @category Type
+ */
+export type UpdateWebSocketParams = {
+  /**
+   * Replaces the subprotocols offered during the handshake.
+   */
+  subprotocols?: Array<string>;
+  messagesToAdd?: Array<AddWebSocketMessageParams>;
+  messagesToUpdate?: Array<UpdateWebSocketMessageParams>;
+  messagesToRemove?: Array<string>;
+};
+
 /**
  * @category Type
  */
@@ -350,6 +921,10 @@ export type UrlencodedParamInfo = {
   disabled: boolean;
   propagate: boolean;
   order?: number;
+  /**
+   * The directory the entry is inherited from, if it isn't defined on the resource itself.
+   */
+  inheritedFrom?: string;
 };
 
 /**
@@ -366,3 +941,40 @@ export type VcsOperation =
   | "PUSH"
   | "PULL"
   | "FETCH";
+
+/**
+ * @category Type
+ */
+export type WebSocketInfo = { subprotocols: Array<string>; messages: Array<WebSocketMessageInfo> };
+
+/**
+ * @category Type
+ */
+export type WebSocketMessageInfo = { id: string; name: string; payload: WebSocketMessagePayload };
+
+/**
+ * @category Type
+ */
+export type WebSocketMessagePayload =
+  | { "text": string }
+  | { "json": JsonValue }
+  | {
+    /**
+     * Path of the file whose content is sent, the same as a binary body.
+     */
+    "binary": string;
+  };
+
+/**
+ * @category Type
+ */
+export type WebSocketParams = {
+  /**
+   * Offered to the server during the handshake, in order of preference.
+   */
+  subprotocols?: Array<string>;
+  /**
+   * Messages saved to be sent once connected, in the order they are shown.
+   */
+  messages?: Array<AddWebSocketMessageParams>;
+};
@@ -1,6 +1,11 @@
 // @ts-nocheck - File uses recursive schemas that may cause "Type instantiation is excessively deep" errors
 // Generated by ts-to-zod
-import { changeJsonValueSchema, changeStringSchema, jsonValueSchema } from "@repo/moss-bindingutils";
+import {
+  changeJsonValueSchema,
+  changeStringSchema,
+  changeUsizeSchema,
+  jsonValueSchema,
+} from "@repo/moss-bindingutils";
 import { z } from "zod";
 import { resourcePathSchema } from "./main/types.zod";
 import { resourceClassSchema, resourceProtocolSchema } from "./resource/primitives.zod";
@@ -30,14 +35,6 @@ export const urlencodedParamOptionsSchema = z.object({
   propagate: z.boolean(),
 });
 
-export const updateDirResourceParamsSchema = z.object({
-  id: z.string(),
-  path: z.string().optional(),
-  name: z.string().optional(),
-  order: z.number().optional(),
-  expanded: z.boolean().optional(),
-});
-
 export const updateHeaderParamOptionsSchema = z.object({
   disabled: z.boolean().optional(),
   propagate: z.boolean().optional(),
@@ -53,6 +50,35 @@ export const updateQueryParamOptionsSchema = z.object({
   propagate: z.boolean().optional(),
 });
 
+export const apiKeyLocationSchema = z.union([z.literal("header"), z.literal("query")]);
+
+export const oAuth2GrantSchema = z.union([
+  z.literal("authorizationCode"),
+  z.literal("clientCredentials"),
+  z.literal("deviceCode"),
+  z.literal("refreshToken"),
+]);
+
+export const oAuth2ConfigSchema = z.object({
+  grant: oAuth2GrantSchema,
+  authUrl: z.string().optional(),
+  tokenUrl: z.string(),
+  deviceAuthUrl: z.string().optional(),
+  clientId: z.string(),
+  clientSecret: z.string().optional(),
+  scopes: z.array(z.string()).optional(),
+  refreshToken: z.string().optional(),
+  callbackPort: z.number().optional(),
+});
+
+export const awsSigV4ConfigSchema = z.object({
+  accessKey: z.string(),
+  secretKey: z.string(),
+  sessionToken: z.string().optional(),
+  region: z.string(),
+  service: z.string(),
+});
+
 export const vcsOperationSchema = z.union([
   z.object({
     "COMMIT": z.object({
@@ -70,6 +96,65 @@ export const vcsOperationSchema = z.union([
   z.literal("PULL"),
   z.literal("FETCH"),
 ]);
+export const authSchemeSchema = z.union([
+  z.literal("none"),
+  z.literal("inherit"),
+  z.object({
+    "basic": z.object({
+      username: z.string(),
+      password: z.string(),
+    }),
+  }),
+  z.object({
+    "bearer": z.object({
+      token: z.string(),
+    }),
+  }),
+  z.object({
+    "apiKey": z.object({
+      key: z.string(),
+      value: z.string(),
+      location: apiKeyLocationSchema,
+    }),
+  }),
+  z.object({
+    "digest": z.object({
+      username: z.string(),
+      password: z.string(),
+    }),
+  }),
+  z.object({
+    "oauth2": oAuth2ConfigSchema,
+  }),
+  z.object({
+    "awsSigV4": awsSigV4ConfigSchema,
+  }),
+]);
+
+export const authInfoSchema = z.object({
+  scheme: authSchemeSchema,
+  inheritedFrom: z.string().optional(),
+});
+
+export const changeAuthSchema = z.union([
+  z.object({
+    "UPDATE": authSchemeSchema,
+  }),
+  z.literal("REMOVE"),
+]);
+
+export const changeBodySchemaSchema = z.union([
+  z.object({
+    "UPDATE": z.string(),
+  }),
+  z.literal("REMOVE"),
+]);
+
+export const schemaViolationSchema = z.object({
+  pointer: z.string(),
+  message: z.string(),
+});
+
 export const addUrlencodedParamParamsSchema = z.object({
   name: z.string(),
   value: jsonValueSchema,
@@ -126,6 +211,7 @@ export const afterUpdateDirResourceDescriptionSchema = z.object({
 export const afterUpdateItemResourceDescriptionSchema = z.object({
   id: z.string(),
   path: resourcePathSchema,
+  dependents: z.array(z.string()),
 });
 
 export const urlencodedParamInfoSchema = z.object({
@@ -136,6 +222,7 @@ export const urlencodedParamInfoSchema = z.object({
   disabled: z.boolean(),
   propagate: z.boolean(),
   order: z.number().optional(),
+  inheritedFrom: z.string().optional(),
 });
 
 export const formDataParamInfoSchema = z.object({
@@ -146,15 +233,34 @@ export const formDataParamInfoSchema = z.object({
   disabled: z.boolean(),
   propagate: z.boolean(),
   order: z.number().optional(),
+  inheritedFrom: z.string().optional(),
 });
 
 export const createDirResourceParamsSchema = z.object({
   path: z.string(),
   class: resourceClassSchema,
   name: z.string(),
+  description: z.string().optional(),
   order: z.number(),
+  headers: z.array(addHeaderParamsSchema).optional(),
+  pathParams: z.array(addPathParamParamsSchema).optional(),
+  queryParams: z.array(addQueryParamParamsSchema).optional(),
+  auth: authSchemeSchema.optional(),
+});
+
+export const expectedResponseSchema = z.object({
+  status: z.number().optional(),
+  headers: z.record(z.string(), z.string()).optional(),
+  body: jsonValueSchema.optional(),
 });
 
+export const changeExpectedResponseSchema = z.union([
+  z.object({
+    "UPDATE": expectedResponseSchema,
+  }),
+  z.literal("REMOVE"),
+]);
+
 export const addBodyParamsSchema = z.union([
   z.object({
     "text": z.string(),
@@ -176,6 +282,13 @@ export const addBodyParamsSchema = z.union([
   }),
 ]);
 
+export const changeCaseBodySchema = z.union([
+  z.object({
+    "UPDATE": addBodyParamsSchema,
+  }),
+  z.literal("REMOVE"),
+]);
+
 export const headerInfoSchema = z.object({
   id: z.string(),
   name: z.string(),
@@ -184,6 +297,8 @@ export const headerInfoSchema = z.object({
   disabled: z.boolean(),
   propagate: z.boolean(),
   order: z.number().optional(),
+  inheritedFrom: z.string().optional(),
+  component: z.string().optional(),
 });
 
 export const pathParamInfoSchema = z.object({
@@ -194,6 +309,8 @@ export const pathParamInfoSchema = z.object({
   disabled: z.boolean(),
   propagate: z.boolean(),
   order: z.number().optional(),
+  inheritedFrom: z.string().optional(),
+  component: z.string().optional(),
 });
 
 export const queryParamInfoSchema = z.object({
@@ -204,6 +321,8 @@ export const queryParamInfoSchema = z.object({
   disabled: z.boolean(),
   propagate: z.boolean(),
   order: z.number().optional(),
+  inheritedFrom: z.string().optional(),
+  component: z.string().optional(),
 });
 
 export const updateUrlencodedParamParamsSchema = z.object({
@@ -251,6 +370,25 @@ export const updateQueryParamParamsSchema = z.object({
   options: queryParamOptionsSchema.optional(),
 });
 
+export const updateDirResourceParamsSchema = z.object({
+  id: z.string(),
+  path: z.string().optional(),
+  name: z.string().optional(),
+  description: changeStringSchema.optional(),
+  order: z.number().optional(),
+  expanded: z.boolean().optional(),
+  headersToAdd: z.array(addHeaderParamsSchema).optional(),
+  headersToUpdate: z.array(updateHeaderParamsSchema).optional(),
+  headersToRemove: z.array(z.string()).optional(),
+  pathParamsToAdd: z.array(addPathParamParamsSchema).optional(),
+  pathParamsToUpdate: z.array(updatePathParamParamsSchema).optional(),
+  pathParamsToRemove: z.array(z.string()).optional(),
+  queryParamsToAdd: z.array(addQueryParamParamsSchema).optional(),
+  queryParamsToUpdate: z.array(updateQueryParamParamsSchema).optional(),
+  queryParamsToRemove: z.array(z.string()).optional(),
+  auth: changeAuthSchema.optional(),
+});
+
 export const updateBodyParamsSchema = z.union([
   z.literal("remove"),
   z.object({
@@ -302,10 +440,216 @@ export const bodyInfoSchema = z.union([
   }),
 ]);
 
+export const caseInfoSchema = z.object({
+  id: z.string(),
+  name: z.string(),
+  description: z.string().optional(),
+  headers: z.array(headerInfoSchema),
+  pathParams: z.array(pathParamInfoSchema),
+  queryParams: z.array(queryParamInfoSchema),
+  body: bodyInfoSchema.optional(),
+  expectedResponse: expectedResponseSchema.optional(),
+});
+
+export const webSocketMessagePayloadSchema = z.union([
+  z.object({
+    "text": z.string(),
+  }),
+  z.object({
+    "json": jsonValueSchema,
+  }),
+  z.object({
+    "binary": z.string(),
+  }),
+]);
+
+export const addWebSocketMessageParamsSchema = z.object({
+  name: z.string(),
+  payload: webSocketMessagePayloadSchema,
+});
+
+export const updateWebSocketMessageParamsSchema = z.object({
+  id: z.string(),
+  name: z.string().optional(),
+  payload: webSocketMessagePayloadSchema.optional(),
+});
+
+export const webSocketParamsSchema = z.object({
+  subprotocols: z.array(z.string()).optional(),
+  messages: z.array(addWebSocketMessageParamsSchema).optional(),
+});
+
+export const updateWebSocketParamsSchema = z.object({
+  subprotocols: z.array(z.string()).optional(),
+  messagesToAdd: z.array(addWebSocketMessageParamsSchema).optional(),
+  messagesToUpdate: z.array(updateWebSocketMessageParamsSchema).optional(),
+  messagesToRemove: z.array(z.string()).optional(),
+});
+
+export const webSocketMessageInfoSchema = z.object({
+  id: z.string(),
+  name: z.string(),
+  payload: webSocketMessagePayloadSchema,
+});
+
+export const webSocketInfoSchema = z.object({
+  subprotocols: z.array(z.string()),
+  messages: z.array(webSocketMessageInfoSchema),
+});
+
+export const graphqlParamsSchema = z.object({
+  query: z.string(),
+  operationName: z.string().optional(),
+  variables: jsonValueSchema.optional(),
+});
+
+export const updateGraphqlParamsSchema = z.object({
+  query: z.string().optional(),
+  operationName: changeStringSchema.optional(),
+  variables: changeJsonValueSchema.optional(),
+});
+
+export const graphqlInfoSchema = z.object({
+  query: z.string(),
+  operationName: z.string().optional(),
+  variables: jsonValueSchema.optional(),
+});
+
+export const grpcParamsSchema = z.object({
+  protoFiles: z.array(z.string()).optional(),
+  importPaths: z.array(z.string()).optional(),
+  service: z.string(),
+  method: z.string(),
+  messages: z.array(jsonValueSchema).optional(),
+  timeoutMs: z.number().optional(),
+});
+
+export const updateGrpcParamsSchema = z.object({
+  protoFiles: z.array(z.string()).optional(),
+  importPaths: z.array(z.string()).optional(),
+  service: z.string().optional(),
+  method: z.string().optional(),
+  messages: z.array(jsonValueSchema).optional(),
+  timeoutMs: changeUsizeSchema.optional(),
+});
+
+export const grpcInfoSchema = z.object({
+  protoFiles: z.array(z.string()),
+  importPaths: z.array(z.string()),
+  service: z.string(),
+  method: z.string(),
+  messages: z.array(jsonValueSchema),
+  timeoutMs: z.number().optional(),
+});
+
+export const sseParamsSchema = z.object({
+  reconnect: z.boolean().optional(),
+  retryMs: z.number().optional(),
+  maxReconnects: z.number().optional(),
+  record: z.boolean().optional(),
+});
+
+export const changeSseSchema = z.union([
+  z.object({
+    "UPDATE": sseParamsSchema,
+  }),
+  z.literal("REMOVE"),
+]);
+
+export const sseInfoSchema = z.object({
+  reconnect: z.boolean(),
+  retryMs: z.number().optional(),
+  maxReconnects: z.number().optional(),
+  record: z.boolean(),
+});
+
+export const amqpParamsSchema = z.object({
+  username: z.string().optional(),
+  password: z.string().optional(),
+  exchange: z.string().optional(),
+  routingKey: z.string().optional(),
+  queue: z.string().optional(),
+  payload: z.string().optional(),
+  contentType: z.string().optional(),
+  correlationId: z.string().optional(),
+  replyTo: z.string().optional(),
+  messageId: z.string().optional(),
+  expirationMs: z.number().optional(),
+  priority: z.number().optional(),
+  persistent: z.boolean().optional(),
+});
+
+export const changeAmqpSchema = z.union([
+  z.object({
+    "UPDATE": amqpParamsSchema,
+  }),
+  z.literal("REMOVE"),
+]);
+
+export const amqpInfoSchema = z.object({
+  username: z.string().optional(),
+  password: z.string().optional(),
+  exchange: z.string(),
+  routingKey: z.string(),
+  queue: z.string().optional(),
+  payload: z.string(),
+  contentType: z.string().optional(),
+  correlationId: z.string().optional(),
+  replyTo: z.string().optional(),
+  messageId: z.string().optional(),
+  expirationMs: z.number().optional(),
+  priority: z.number().optional(),
+  persistent: z.boolean(),
+});
+
+export const mqttVersionSchema = z.union([z.literal("3.1.1"), z.literal("5")]);
+
+export const mqttParamsSchema = z.object({
+  version: mqttVersionSchema.optional(),
+  clientId: z.string().optional(),
+  username: z.string().optional(),
+  password: z.string().optional(),
+  topic: z.string(),
+  topicFilter: z.string().optional(),
+  qos: z.number().optional(),
+  retain: z.boolean().optional(),
+  cleanSession: z.boolean().optional(),
+  payload: z.string().optional(),
+  contentType: z.string().optional(),
+  responseTopic: z.string().optional(),
+  correlationData: z.string().optional(),
+  messageExpirySecs: z.number().optional(),
+});
+
+export const changeMqttSchema = z.union([
+  z.object({
+    "UPDATE": mqttParamsSchema,
+  }),
+  z.literal("REMOVE"),
+]);
+
+export const mqttInfoSchema = z.object({
+  version: mqttVersionSchema,
+  clientId: z.string().optional(),
+  username: z.string().optional(),
+  password: z.string().optional(),
+  topic: z.string(),
+  topicFilter: z.string().optional(),
+  qos: z.number(),
+  retain: z.boolean(),
+  cleanSession: z.boolean(),
+  payload: z.string(),
+  contentType: z.string().optional(),
+  responseTopic: z.string().optional(),
+  correlationData: z.string().optional(),
+  messageExpirySecs: z.number().optional(),
+});
+
 export const createItemResourceParamsSchema = z.object({
   path: z.string(),
   class: resourceClassSchema,
   name: z.string(),
+  description: z.string().optional(),
   order: z.number(),
   protocol: resourceProtocolSchema.optional(),
   url: z.string().optional(),
@@ -313,12 +657,23 @@ export const createItemResourceParamsSchema = z.object({
   pathParams: z.array(addPathParamParamsSchema),
   queryParams: z.array(addQueryParamParamsSchema),
   body: addBodyParamsSchema.optional(),
+  bodySchema: z.string().optional(),
+  components: z.array(z.string()).optional(),
+  auth: authSchemeSchema.optional(),
+  schema: jsonValueSchema.optional(),
+  websocket: webSocketParamsSchema.optional(),
+  graphql: graphqlParamsSchema.optional(),
+  grpc: grpcParamsSchema.optional(),
+  sse: sseParamsSchema.optional(),
+  amqp: amqpParamsSchema.optional(),
+  mqtt: mqttParamsSchema.optional(),
 });
 
 export const updateItemResourceParamsSchema = z.object({
   id: z.string(),
   path: z.string().optional(),
   name: z.string().optional(),
+  description: changeStringSchema.optional(),
   protocol: resourceProtocolSchema.optional(),
   url: z.string().optional(),
   headersToAdd: z.array(addHeaderParamsSchema),
@@ -331,4 +686,15 @@ export const updateItemResourceParamsSchema = z.object({
   queryParamsToUpdate: z.array(updateQueryParamParamsSchema),
   queryParamsToRemove: z.array(z.string()),
   body: updateBodyParamsSchema.optional(),
+  bodySchema: changeBodySchemaSchema.optional(),
+  componentsToAdd: z.array(z.string()).optional(),
+  componentsToRemove: z.array(z.string()).optional(),
+  auth: changeAuthSchema.optional(),
+  schema: jsonValueSchema.optional(),
+  websocket: updateWebSocketParamsSchema.optional(),
+  graphql: updateGraphqlParamsSchema.optional(),
+  grpc: updateGrpcParamsSchema.optional(),
+  sse: changeSseSchema.optional(),
+  amqp: changeAmqpSchema.optional(),
+  mqtt: changeMqttSchema.optional(),
 });
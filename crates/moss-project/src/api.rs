@@ -2,7 +2,12 @@ pub mod batch_create_resource;
 pub mod batch_update_resource;
 mod common;
 pub mod create_resource;
+pub mod create_resource_case;
 pub mod delete_resource;
+pub mod delete_resource_case;
 pub mod describe_resource;
 mod execute_vcs_operation;
+pub mod list_resource_cases;
 pub mod update_resource;
+pub mod update_resource_case;
+pub mod validate_resource_body;
@@ -1,4 +1,6 @@
 use moss_applib::AppRuntime;
+use moss_logging::session;
+use sapic_core::context::AnyAsyncContext;
 use sapic_ipc::contracts::main::resource::FrontendResourcePath;
 
 use crate::{
@@ -12,6 +14,8 @@ use crate::{
 };
 
 impl Project {
+    /// Creates all the resources or none of them. If one of them can't be created,
+    /// the ones already created are removed again before the error is returned.
     pub async fn batch_create_resource<R: AppRuntime>(
         &self,
         ctx: &R::AsyncContext,
@@ -45,7 +49,13 @@ impl Project {
         for dir in dirs {
             let path = dir.path.clone();
             let name = dir.name.clone();
-            let output = self.create_dir_resource::<R>(ctx, dir).await?;
+            let output = match self.create_dir_resource::<R>(ctx, dir).await {
+                Ok(output) => output,
+                Err(e) => {
+                    self.rollback_created_resources(ctx, &resources).await;
+                    return Err(e);
+                }
+            };
             resources.push(AfterCreateResourceDescription {
                 id: output.id,
                 path: FrontendResourcePath::new(path.to_path_buf()),
@@ -56,7 +66,13 @@ impl Project {
         for item in items {
             let path = item.path.clone();
             let name = item.name.clone();
-            let output = self.create_item_resource::<R>(ctx, item).await?;
+            let output = match self.create_item_resource::<R>(ctx, item).await {
+                Ok(output) => output,
+                Err(e) => {
+                    self.rollback_created_resources(ctx, &resources).await;
+                    return Err(e);
+                }
+            };
             resources.push(AfterCreateResourceDescription {
                 id: output.id,
                 path: FrontendResourcePath::new(path.to_path_buf()),
@@ -66,4 +82,19 @@ impl Project {
 
         Ok(BatchCreateResourceOutput { resources })
     }
+
+    async fn rollback_created_resources(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        resources: &[AfterCreateResourceDescription],
+    ) {
+        // Removing in reverse order removes the contents of a directory before the directory
+        // itself, and the resources referencing a component or a schema before it
+        let worktree = self.worktree().await;
+        for id in resources.iter().rev().map(|resource| &resource.id) {
+            if let Err(e) = worktree.remove_entry(ctx, id).await {
+                session::warn!(format!("failed to roll back resource {}: {}", id, e));
+            }
+        }
+    }
 }
@@ -35,7 +35,7 @@ impl Project {
                         })?;
                 }
                 BatchUpdateResourceKind::Dir(input) => {
-                    let output = self.update_dir_resource::<R>(ctx, input).await?;
+                    let output = self.update_dir_resource(ctx, app_delegate, input).await?;
                     channel
                         .send(BatchUpdateResourceEvent::Dir(output))
                         .map_err(|e| {
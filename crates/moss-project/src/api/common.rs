@@ -1,5 +1,6 @@
 use hcl::ser::LabeledBlock;
 use indexmap::{IndexMap, indexmap};
+use joinerror::{Error, OptionExt};
 use moss_app_delegate::AppDelegate;
 use moss_applib::AppRuntime;
 use moss_common::continue_if_err;
@@ -9,18 +10,25 @@ use moss_storage2::models::primitives::StorageScope;
 use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
 use sapic_core::context::AnyAsyncContext;
 use sapic_ipc::{ValidationResultExt, contracts::main::resource::FrontendResourcePath};
+use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use validator::Validate;
 
 use crate::{
     Project,
+    errors::ErrorInvalidInput,
+    json_schema,
     models::{
         operations::CreateResourceOutput,
-        primitives::{FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId},
+        primitives::{
+            FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId,
+            WebSocketMessageId,
+        },
         types::{
             AfterUpdateDirResourceDescription, AfterUpdateItemResourceDescription,
             CreateDirResourceParams, CreateItemResourceParams, UpdateDirResourceParams,
-            UpdateItemResourceParams, http::AddBodyParams,
+            UpdateItemResourceParams,
+            http::{AddBodyParams, AddHeaderParams, AddPathParamParams, AddQueryParamParams},
         },
     },
     storage::{
@@ -31,9 +39,10 @@ use crate::{
         ModifyParams,
         entry::model::{
             BodyKind, BodySpec, EntryMetadataSpec, EntryModel, FormDataParamSpec,
-            FormDataParamSpecOptions, HeaderParamSpec, HeaderParamSpecOptions, PathParamSpec,
-            PathParamSpecOptions, QueryParamSpec, QueryParamSpecOptions, UrlDetails,
-            UrlencodedParamSpec, UrlencodedParamSpecOptions,
+            FormDataParamSpecOptions, GraphqlSpec, GrpcSpec, HeaderParamSpec,
+            HeaderParamSpecOptions, PathParamSpec, PathParamSpecOptions, QueryParamSpec,
+            QueryParamSpecOptions, UrlDetails, UrlencodedParamSpec, UrlencodedParamSpecOptions,
+            WebSocketMessageSpec, WebSocketSpec,
         },
     },
 };
@@ -47,7 +56,21 @@ impl Project {
         input.validate().join_err_bare()?;
 
         let id = ResourceId::new();
-        let model = EntryModel::from((id.clone(), input.class));
+        let params =
+            create_param_specs(&id, &input.headers, &input.path_params, &input.query_params)?;
+
+        let mut model = EntryModel::from((id.clone(), input.class));
+        if !params.headers.is_empty() {
+            model.headers = Some(LabeledBlock::new(params.headers));
+        }
+        if !params.path_params.is_empty() {
+            model.path_params = Some(LabeledBlock::new(params.path_params));
+        }
+        if !params.query_params.is_empty() {
+            model.query_params = Some(LabeledBlock::new(params.query_params));
+        }
+        model.auth = input.auth.map(|auth| Block::new(auth.into()));
+        model.description = input.description;
 
         self.worktree()
             .await
@@ -61,6 +84,8 @@ impl Project {
             )
             .await?;
 
+        self.put_param_orders(ctx, params.orders).await;
+
         Ok(CreateResourceOutput { id: id })
     }
 
@@ -73,19 +98,94 @@ impl Project {
 
         let id = ResourceId::new();
 
+        if let Some(schema) = &input.schema {
+            if input.class != ResourceClass::Schema {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only schema resources can hold a schema document",
+                ));
+            }
+            json_schema::check_document(schema).map_err(Error::new::<ErrorInvalidInput>)?;
+        }
+
+        if input.websocket.is_some() && input.protocol != Some(ResourceProtocol::WebSocket) {
+            return Err(Error::new::<ErrorInvalidInput>(
+                "only websocket endpoints can have websocket settings",
+            ));
+        }
+
+        if input.graphql.is_some() && input.protocol != Some(ResourceProtocol::Graphql) {
+            return Err(Error::new::<ErrorInvalidInput>(
+                "only graphql endpoints can have graphql settings",
+            ));
+        }
+
+        if input.grpc.is_some() && input.protocol != Some(ResourceProtocol::Grpc) {
+            return Err(Error::new::<ErrorInvalidInput>(
+                "only grpc endpoints can have grpc settings",
+            ));
+        }
+
+        if input.sse.is_some()
+            && (input.class != ResourceClass::Endpoint
+                || !input
+                    .protocol
+                    .as_ref()
+                    .is_some_and(ResourceProtocol::is_http))
+        {
+            return Err(Error::new::<ErrorInvalidInput>(
+                "only http endpoints can have sse settings",
+            ));
+        }
+
+        if input.amqp.is_some() && input.protocol != Some(ResourceProtocol::Amqp) {
+            return Err(Error::new::<ErrorInvalidInput>(
+                "only amqp endpoints can have amqp settings",
+            ));
+        }
+
+        if input.mqtt.is_some() && input.protocol != Some(ResourceProtocol::Mqtt) {
+            return Err(Error::new::<ErrorInvalidInput>(
+                "only mqtt endpoints can have mqtt settings",
+            ));
+        }
+
+        if !input.components.is_empty() {
+            if input.class != ResourceClass::Endpoint {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only endpoints can reference components",
+                ));
+            }
+            self.worktree()
+                .await
+                .check_component_resources(&input.components)
+                .await?;
+        }
+
         match &input.class {
-            ResourceClass::Endpoint => self.create_endpoint_resource(id, ctx, input).await,
+            ResourceClass::Endpoint | ResourceClass::Component => {
+                self.create_endpoint_resource(id, ctx, input).await
+            }
             _ => {
                 let model = EntryModel {
                     metadata: Block::new(EntryMetadataSpec {
                         id: id.clone(),
                         class: input.class,
                     }),
+                    description: input.description,
                     url: None,
                     headers: None, // Hardcoded for now
                     path_params: None,
                     query_params: None,
                     body: None,
+                    components: None,
+                    auth: input.auth.map(|auth| Block::new(auth.into())),
+                    schema: input.schema,
+                    websocket: None,
+                    graphql: None,
+                    grpc: None,
+                    sse: None,
+                    amqp: None,
+                    mqtt: None,
                 };
                 self.worktree()
                     .await
@@ -113,6 +213,7 @@ impl Project {
                 &input.id,
                 ModifyParams {
                     name: input.name,
+                    description: input.description,
                     protocol: input.protocol,
                     url: input.url,
                     path: input.path,
@@ -130,18 +231,39 @@ impl Project {
                     headers_to_remove: input.headers_to_remove,
 
                     body: input.body,
+                    body_schema: input.body_schema,
+
+                    components_to_add: input.components_to_add,
+                    components_to_remove: input.components_to_remove,
+
+                    auth: input.auth,
+
+                    schema: input.schema,
+
+                    websocket: input.websocket,
+                    graphql: input.graphql,
+                    grpc: input.grpc,
+                    sse: input.sse,
+                    amqp: input.amqp,
+                    mqtt: input.mqtt,
                 },
             )
             .await?;
 
         let path = FrontendResourcePath::new(path.to_path_buf());
+        let dependents = self.worktree().await.dependents(&input.id).await;
 
-        Ok(AfterUpdateItemResourceDescription { id: input.id, path })
+        Ok(AfterUpdateItemResourceDescription {
+            id: input.id,
+            path,
+            dependents,
+        })
     }
 
     pub(super) async fn update_dir_resource<R: AppRuntime>(
         &self,
         ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
         input: UpdateDirResourceParams,
     ) -> joinerror::Result<AfterUpdateDirResourceDescription> {
         input.validate().join_err_bare()?;
@@ -151,26 +273,43 @@ impl Project {
             .await
             .update_dir_entry(
                 ctx,
+                app_delegate,
                 &input.id,
                 ModifyParams {
                     name: input.name,
+                    description: input.description,
                     protocol: None,
                     url: None,
                     path: input.path,
 
-                    headers_to_add: vec![],
-                    headers_to_update: vec![],
-                    headers_to_remove: vec![],
+                    headers_to_add: input.headers_to_add,
+                    headers_to_update: input.headers_to_update,
+                    headers_to_remove: input.headers_to_remove,
 
-                    path_params_to_add: vec![],
-                    path_params_to_update: vec![],
-                    path_params_to_remove: vec![],
+                    path_params_to_add: input.path_params_to_add,
+                    path_params_to_update: input.path_params_to_update,
+                    path_params_to_remove: input.path_params_to_remove,
 
-                    query_params_to_add: vec![],
-                    query_params_to_update: vec![],
-                    query_params_to_remove: vec![],
+                    query_params_to_add: input.query_params_to_add,
+                    query_params_to_update: input.query_params_to_update,
+                    query_params_to_remove: input.query_params_to_remove,
 
                     body: None,
+                    body_schema: None,
+
+                    components_to_add: vec![],
+                    components_to_remove: vec![],
+
+                    auth: input.auth,
+
+                    schema: None,
+
+                    websocket: None,
+                    graphql: None,
+                    grpc: None,
+                    sse: None,
+                    amqp: None,
+                    mqtt: None,
                 },
             )
             .await?;
@@ -188,58 +327,12 @@ impl Project {
         ctx: &dyn AnyAsyncContext,
         input: CreateItemResourceParams,
     ) -> joinerror::Result<CreateResourceOutput> {
-        let mut header_map = IndexMap::new();
-        let mut header_orders = HashMap::new();
-        let mut path_param_map = IndexMap::new();
-        let mut path_param_orders = HashMap::new();
-        let mut query_param_map = IndexMap::new();
-        let mut query_param_orders = HashMap::new();
+        let params =
+            create_param_specs(&id, &input.headers, &input.path_params, &input.query_params)?;
         let mut urlencoded_param_orders = HashMap::new();
         let mut formdata_param_orders = HashMap::new();
 
-        for param in &input.headers {
-            let id = HeaderId::new();
-            let value = continue_if_err!(json_to_hcl(&param.value), |err| {
-                session::error!("failed to convert value expression: {}", err)
-            });
-
-            header_map.insert(
-                id.clone(),
-                HeaderParamSpec {
-                    name: param.name.clone(),
-                    value,
-                    description: param.description.clone(),
-                    options: HeaderParamSpecOptions {
-                        disabled: param.options.disabled,
-                        propagate: param.options.propagate,
-                    },
-                },
-            );
-            header_orders.insert(id, param.order);
-        }
-
-        for param in &input.path_params {
-            let id = PathParamId::new();
-            let value = continue_if_err!(json_to_hcl(&param.value), |err| {
-                session::error!("failed to convert value expression: {}", err)
-            });
-
-            path_param_map.insert(
-                id.clone(),
-                PathParamSpec {
-                    name: param.name.clone(),
-                    value,
-                    description: param.description.clone(),
-                    options: PathParamSpecOptions {
-                        disabled: param.options.disabled,
-                        propagate: param.options.propagate,
-                    },
-                },
-            );
-            path_param_orders.insert(id.clone(), param.order.clone());
-        }
-
-        let body = if let Some(body_params) = input.body {
+        let mut body = if let Some(body_params) = input.body {
             Some(
                 create_body_block(
                     body_params,
@@ -252,25 +345,19 @@ impl Project {
             None
         };
 
-        for param in &input.query_params {
-            let id = QueryParamId::new();
-            let value = continue_if_err!(json_to_hcl(&param.value), |err| {
-                session::error!("failed to convert value expression: {}", err)
-            });
-
-            query_param_map.insert(
-                id.clone(),
-                QueryParamSpec {
-                    name: param.name.clone(),
-                    value,
-                    description: param.description.clone(),
-                    options: QueryParamSpecOptions {
-                        disabled: param.options.disabled,
-                        propagate: param.options.propagate,
-                    },
-                },
-            );
-            query_param_orders.insert(id.clone(), param.order.clone());
+        if let Some(schema_id) = input.body_schema {
+            let spec = body
+                .as_mut()
+                .and_then(|body| body.get_mut(&BodyKind::Json))
+                .ok_or_join_err::<ErrorInvalidInput>(
+                    "a schema can only be attached to a json body",
+                )?;
+
+            self.worktree()
+                .await
+                .check_schema_resource(&schema_id)
+                .await?;
+            spec.schema = Some(schema_id);
         }
 
         let model = EntryModel {
@@ -278,18 +365,66 @@ impl Project {
                 id: id.clone(),
                 class: input.class.clone(),
             }),
-            url: Some(Block::new(UrlDetails {
-                protocol: input.protocol.clone().unwrap_or(ResourceProtocol::Get),
-                raw: input.url.unwrap_or_default(),
-            })),
-            headers: if header_map.is_empty() {
+            description: input.description,
+            // Components are fragments expanded into endpoints, they aren't sent on their own
+            url: (input.class == ResourceClass::Endpoint).then(|| {
+                Block::new(UrlDetails {
+                    protocol: input.protocol.clone().unwrap_or(ResourceProtocol::Get),
+                    raw: input.url.unwrap_or_default(),
+                })
+            }),
+            headers: if params.headers.is_empty() {
                 None
             } else {
-                Some(LabeledBlock::new(header_map))
+                Some(LabeledBlock::new(params.headers))
             },
-            path_params: Some(LabeledBlock::new(path_param_map)),
-            query_params: Some(LabeledBlock::new(query_param_map)),
+            path_params: Some(LabeledBlock::new(params.path_params)),
+            query_params: Some(LabeledBlock::new(params.query_params)),
             body,
+            components: if input.components.is_empty() {
+                None
+            } else {
+                Some(input.components)
+            },
+            auth: input.auth.map(|auth| Block::new(auth.into())),
+            schema: None,
+            websocket: input.websocket.map(|params| {
+                Block::new(WebSocketSpec {
+                    subprotocols: params.subprotocols,
+                    messages: Some(LabeledBlock::new(
+                        params
+                            .messages
+                            .into_iter()
+                            .map(|message| {
+                                (
+                                    WebSocketMessageId::new(),
+                                    WebSocketMessageSpec::new(message.name, message.payload),
+                                )
+                            })
+                            .collect(),
+                    )),
+                })
+            }),
+            graphql: input.graphql.map(|params| {
+                Block::new(GraphqlSpec {
+                    query: params.query,
+                    operation_name: params.operation_name,
+                    variables: params.variables,
+                })
+            }),
+            grpc: input.grpc.map(|params| {
+                Block::new(GrpcSpec {
+                    proto_files: params.proto_files,
+                    import_paths: params.import_paths,
+                    service: params.service,
+                    method: params.method,
+                    messages: params.messages,
+                    timeout_ms: params.timeout_ms,
+                })
+            }),
+            sse: input.sse.map(|params| Block::new(params.into())),
+            amqp: input.amqp.map(|params| Block::new(params.into())),
+            mqtt: input.mqtt.map(|params| Block::new(params.into())),
         };
 
         self.worktree()
@@ -297,59 +432,33 @@ impl Project {
             .create_item_entry(ctx, &input.name, &input.path, model, input.order, false)
             .await?;
 
-        let output = CreateResourceOutput { id: id.clone() };
-
-        if header_orders.is_empty()
-            && path_param_orders.is_empty()
-            && query_param_orders.is_empty()
-            && urlencoded_param_orders.is_empty()
-            && formdata_param_orders.is_empty()
-        {
-            return Ok(output);
-        }
-
         // FIXME: Find a better way to convert to &[(&str, JsonValue)]
 
-        let mut key_values = vec![];
-        for (header_id, order) in header_orders {
-            key_values.push((
-                key_resource_header_order(&id, &header_id),
-                serde_json::to_value(&order)?,
-            ));
-        }
-
-        for (path_param_id, order) in path_param_orders {
-            key_values.push((
-                key_resource_path_param_order(&id, &path_param_id),
-                serde_json::to_value(&order)?,
-            ));
-        }
+        let mut key_values = params.orders;
+        key_values.extend(body_param_orders(
+            &id,
+            urlencoded_param_orders,
+            formdata_param_orders,
+        )?);
 
-        for (query_param_id, order) in query_param_orders {
-            key_values.push((
-                key_resource_query_param_order(&id, &query_param_id),
-                serde_json::to_value(&order)?,
-            ));
-        }
+        self.put_param_orders(ctx, key_values).await;
 
-        for (urlencoded_param_id, order) in urlencoded_param_orders {
-            key_values.push((
-                key_resource_body_urlencoded_param_order(&id, &urlencoded_param_id),
-                serde_json::to_value(&order)?,
-            ));
-        }
+        Ok(CreateResourceOutput { id })
+    }
 
-        for (formdata_param_id, order) in formdata_param_orders {
-            key_values.push((
-                key_resource_body_formdata_param_order(&id, &formdata_param_id),
-                serde_json::to_value(&order)?,
-            ));
+    pub(super) async fn put_param_orders(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        orders: Vec<(String, JsonValue)>,
+    ) {
+        if orders.is_empty() {
+            return;
         }
 
-        let mut batch_input = Vec::new();
-        for (key, value) in key_values.iter() {
-            batch_input.push((key.as_str(), value.clone()));
-        }
+        let batch_input = orders
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect::<Vec<_>>();
 
         let storage_scope = StorageScope::Project(self.id.inner());
 
@@ -359,16 +468,149 @@ impl Project {
             .await
         {
             session::warn!(format!(
-                "failed to update database after creating endpoint resource: {}",
+                "failed to update database after creating resource: {}",
                 e
             ));
         }
+    }
 
-        Ok(output)
+    pub(super) async fn remove_param_orders(&self, ctx: &dyn AnyAsyncContext, keys: Vec<String>) {
+        if keys.is_empty() {
+            return;
+        }
+
+        let keys = keys.iter().map(|key| key.as_str()).collect::<Vec<_>>();
+        let storage_scope = StorageScope::Project(self.id.inner());
+
+        if let Err(e) = self.storage.remove_batch(ctx, storage_scope, &keys).await {
+            session::warn!(format!(
+                "failed to update database after removing params: {}",
+                e
+            ));
+        }
     }
 }
 
-async fn create_body_block(
+pub(super) struct ParamSpecs {
+    pub headers: IndexMap<HeaderId, HeaderParamSpec>,
+    pub path_params: IndexMap<PathParamId, PathParamSpec>,
+    pub query_params: IndexMap<QueryParamId, QueryParamSpec>,
+    /// Storage keys and values of the user-defined order of each param.
+    pub orders: Vec<(String, JsonValue)>,
+}
+
+pub(super) fn create_param_specs(
+    resource_id: &ResourceId,
+    headers: &[AddHeaderParams],
+    path_params: &[AddPathParamParams],
+    query_params: &[AddQueryParamParams],
+) -> joinerror::Result<ParamSpecs> {
+    let mut specs = ParamSpecs {
+        headers: IndexMap::new(),
+        path_params: IndexMap::new(),
+        query_params: IndexMap::new(),
+        orders: Vec::new(),
+    };
+
+    for param in headers {
+        let id = HeaderId::new();
+        let value = continue_if_err!(json_to_hcl(&param.value), |err| {
+            session::error!("failed to convert value expression: {}", err)
+        });
+
+        specs.headers.insert(
+            id.clone(),
+            HeaderParamSpec {
+                name: param.name.clone(),
+                value,
+                description: param.description.clone(),
+                options: HeaderParamSpecOptions {
+                    disabled: param.options.disabled,
+                    propagate: param.options.propagate,
+                },
+            },
+        );
+        specs.orders.push((
+            key_resource_header_order(resource_id, &id),
+            serde_json::to_value(param.order)?,
+        ));
+    }
+
+    for param in path_params {
+        let id = PathParamId::new();
+        let value = continue_if_err!(json_to_hcl(&param.value), |err| {
+            session::error!("failed to convert value expression: {}", err)
+        });
+
+        specs.path_params.insert(
+            id.clone(),
+            PathParamSpec {
+                name: param.name.clone(),
+                value,
+                description: param.description.clone(),
+                options: PathParamSpecOptions {
+                    disabled: param.options.disabled,
+                    propagate: param.options.propagate,
+                },
+            },
+        );
+        specs.orders.push((
+            key_resource_path_param_order(resource_id, &id),
+            serde_json::to_value(param.order)?,
+        ));
+    }
+
+    for param in query_params {
+        let id = QueryParamId::new();
+        let value = continue_if_err!(json_to_hcl(&param.value), |err| {
+            session::error!("failed to convert value expression: {}", err)
+        });
+
+        specs.query_params.insert(
+            id.clone(),
+            QueryParamSpec {
+                name: param.name.clone(),
+                value,
+                description: param.description.clone(),
+                options: QueryParamSpecOptions {
+                    disabled: param.options.disabled,
+                    propagate: param.options.propagate,
+                },
+            },
+        );
+        specs.orders.push((
+            key_resource_query_param_order(resource_id, &id),
+            serde_json::to_value(param.order)?,
+        ));
+    }
+
+    Ok(specs)
+}
+
+pub(super) fn body_param_orders(
+    resource_id: &ResourceId,
+    urlencoded_param_orders: HashMap<UrlencodedParamId, isize>,
+    formdata_param_orders: HashMap<FormDataParamId, isize>,
+) -> joinerror::Result<Vec<(String, JsonValue)>> {
+    let mut orders = Vec::new();
+    for (urlencoded_param_id, order) in urlencoded_param_orders {
+        orders.push((
+            key_resource_body_urlencoded_param_order(resource_id, &urlencoded_param_id),
+            serde_json::to_value(order)?,
+        ));
+    }
+
+    for (formdata_param_id, order) in formdata_param_orders {
+        orders.push((
+            key_resource_body_formdata_param_order(resource_id, &formdata_param_id),
+            serde_json::to_value(order)?,
+        ));
+    }
+
+    Ok(orders)
+}
+
+pub(super) async fn create_body_block(
     params: AddBodyParams,
     urlencoded_param_orders: &mut HashMap<UrlencodedParamId, isize>,
     formdata_param_orders: &mut HashMap<FormDataParamId, isize>,
@@ -0,0 +1,90 @@
+use hcl::ser::LabeledBlock;
+use joinerror::Error;
+use moss_applib::AppRuntime;
+use moss_hcl::Block;
+use sapic_ipc::ValidationResultExt;
+use std::collections::HashMap;
+use validator::Validate;
+
+use crate::{
+    Project,
+    api::common::{body_param_orders, create_body_block, create_param_specs},
+    errors::ErrorAlreadyExists,
+    models::{
+        operations::{CreateResourceCaseInput, CreateResourceCaseOutput},
+        primitives::CaseId,
+    },
+    worktree::entry::model::CaseSpec,
+};
+
+impl Project {
+    pub async fn create_resource_case<R: AppRuntime>(
+        &self,
+        ctx: &R::AsyncContext,
+        input: CreateResourceCaseInput,
+    ) -> joinerror::Result<CreateResourceCaseOutput> {
+        input.validate().join_err_bare()?;
+
+        let id = CaseId::new();
+        let params = create_param_specs(
+            &input.resource_id,
+            &input.headers,
+            &input.path_params,
+            &input.query_params,
+        )?;
+
+        let mut urlencoded_param_orders = HashMap::new();
+        let mut formdata_param_orders = HashMap::new();
+        let body = if let Some(body_params) = input.body {
+            Some(
+                create_body_block(
+                    body_params,
+                    &mut urlencoded_param_orders,
+                    &mut formdata_param_orders,
+                )
+                .await,
+            )
+        } else {
+            None
+        };
+
+        let spec = CaseSpec {
+            name: input.name,
+            description: input.description,
+            headers: (!params.headers.is_empty()).then(|| LabeledBlock::new(params.headers)),
+            path_params: (!params.path_params.is_empty())
+                .then(|| LabeledBlock::new(params.path_params)),
+            query_params: (!params.query_params.is_empty())
+                .then(|| LabeledBlock::new(params.query_params)),
+            body,
+            expected_response: input
+                .expected_response
+                .map(|expected| Block::new(expected.into())),
+        };
+
+        self.worktree()
+            .await
+            .edit_cases(ctx, &input.resource_id, |cases| {
+                if cases.values().any(|case| case.name == spec.name) {
+                    return Err(Error::new::<ErrorAlreadyExists>(format!(
+                        "case `{}` already exists",
+                        spec.name
+                    )));
+                }
+
+                cases.insert(id.clone(), spec);
+                Ok(())
+            })
+            .await?;
+
+        let mut orders = params.orders;
+        orders.extend(body_param_orders(
+            &input.resource_id,
+            urlencoded_param_orders,
+            formdata_param_orders,
+        )?);
+        self.put_param_orders(ctx, orders).await;
+
+        Ok(CreateResourceCaseOutput { id })
+    }
+}
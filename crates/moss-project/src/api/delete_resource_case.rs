@@ -0,0 +1,53 @@
+use joinerror::OptionExt;
+use moss_applib::AppRuntime;
+
+use crate::{
+    Project,
+    errors::ErrorNotFound,
+    models::operations::{DeleteResourceCaseInput, DeleteResourceCaseOutput},
+    storage::{
+        key_resource_body_formdata_param_order, key_resource_body_urlencoded_param_order,
+        key_resource_header_order, key_resource_path_param_order, key_resource_query_param_order,
+    },
+};
+
+impl Project {
+    pub async fn delete_resource_case<R: AppRuntime>(
+        &self,
+        ctx: &R::AsyncContext,
+        input: DeleteResourceCaseInput,
+    ) -> joinerror::Result<DeleteResourceCaseOutput> {
+        let spec = self
+            .worktree()
+            .await
+            .edit_cases(ctx, &input.resource_id, |cases| {
+                cases
+                    .shift_remove(&input.id)
+                    .ok_or_join_err_with::<ErrorNotFound>(|| format!("case {} not found", input.id))
+            })
+            .await?;
+
+        let resource_id = &input.resource_id;
+        let mut keys = Vec::new();
+        for id in spec.headers.iter().flat_map(|block| block.keys()) {
+            keys.push(key_resource_header_order(resource_id, id));
+        }
+        for id in spec.path_params.iter().flat_map(|block| block.keys()) {
+            keys.push(key_resource_path_param_order(resource_id, id));
+        }
+        for id in spec.query_params.iter().flat_map(|block| block.keys()) {
+            keys.push(key_resource_query_param_order(resource_id, id));
+        }
+        for body in spec.body.iter().flat_map(|block| block.values()) {
+            for id in body.urlencoded.iter().flat_map(|block| block.keys()) {
+                keys.push(key_resource_body_urlencoded_param_order(resource_id, id));
+            }
+            for id in body.form_data.iter().flat_map(|block| block.keys()) {
+                keys.push(key_resource_body_formdata_param_order(resource_id, id));
+            }
+        }
+        self.remove_param_orders(ctx, keys).await;
+
+        Ok(DeleteResourceCaseOutput { id: input.id })
+    }
+}
@@ -0,0 +1,24 @@
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+
+use crate::{
+    Project,
+    models::operations::{ListResourceCasesInput, ListResourceCasesOutput},
+};
+
+impl Project {
+    pub async fn list_resource_cases<R: AppRuntime>(
+        &self,
+        ctx: &R::AsyncContext,
+        app_delegate: &AppDelegate<R>,
+        input: ListResourceCasesInput,
+    ) -> joinerror::Result<ListResourceCasesOutput> {
+        let cases = self
+            .worktree()
+            .await
+            .describe_cases(ctx, app_delegate, &input.resource_id)
+            .await?;
+
+        Ok(ListResourceCasesOutput { cases })
+    }
+}
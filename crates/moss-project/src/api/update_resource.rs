@@ -19,7 +19,7 @@ impl Project {
                 Ok(UpdateResourceOutput::Item(output))
             }
             UpdateResourceInput::Dir(input) => {
-                let output = self.update_dir_resource::<R>(ctx, input).await?;
+                let output = self.update_dir_resource(ctx, app_delegate, input).await?;
                 Ok(UpdateResourceOutput::Dir(output))
             }
         }
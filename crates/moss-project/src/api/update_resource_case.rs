@@ -0,0 +1,206 @@
+use hcl::{Expression, ser::LabeledBlock};
+use indexmap::IndexMap;
+use joinerror::{Error, OptionExt};
+use moss_applib::AppRuntime;
+use moss_bindingutils::primitives::{ChangeJsonValue, ChangeString};
+use moss_hcl::{Block, json_to_hcl};
+use sapic_ipc::ValidationResultExt;
+use std::collections::HashMap;
+use validator::Validate;
+
+use crate::{
+    Project,
+    api::common::{body_param_orders, create_body_block, create_param_specs},
+    errors::{ErrorAlreadyExists, ErrorInvalidInput, ErrorNotFound},
+    models::{
+        operations::{UpdateResourceCaseInput, UpdateResourceCaseOutput},
+        types::{ChangeCaseBody, ChangeExpectedResponse},
+    },
+    storage::{
+        key_resource_header_order, key_resource_path_param_order, key_resource_query_param_order,
+    },
+};
+
+/// Applies the added, updated and removed entries to a header or param block of a case.
+macro_rules! patch_params {
+    ($block:expr, $to_add:expr, $to_update:expr, $to_remove:expr) => {{
+        let params = $block.get_or_insert_with(|| LabeledBlock::new(IndexMap::new()));
+        params.extend($to_add);
+
+        for param in $to_update {
+            let spec = params
+                .get_mut(&param.id)
+                .ok_or_join_err_with::<ErrorNotFound>(|| format!("param {} not found", param.id))?;
+
+            if let Some(name) = param.name {
+                spec.name = name;
+            }
+            match param.value {
+                Some(ChangeJsonValue::Update(value)) => {
+                    spec.value = json_to_hcl(&value).map_err(Error::new::<ErrorInvalidInput>)?;
+                }
+                Some(ChangeJsonValue::Remove) => spec.value = Expression::Null,
+                None => {}
+            }
+            match param.description {
+                Some(ChangeString::Update(description)) => spec.description = Some(description),
+                Some(ChangeString::Remove) => spec.description = None,
+                None => {}
+            }
+            if let Some(options) = param.options {
+                spec.options.disabled = options.disabled;
+                spec.options.propagate = options.propagate;
+            }
+        }
+
+        for id in $to_remove {
+            params.shift_remove(id);
+        }
+
+        if params.is_empty() {
+            $block = None;
+        }
+    }};
+}
+
+impl Project {
+    pub async fn update_resource_case<R: AppRuntime>(
+        &self,
+        ctx: &R::AsyncContext,
+        input: UpdateResourceCaseInput,
+    ) -> joinerror::Result<UpdateResourceCaseOutput> {
+        input.validate().join_err_bare()?;
+
+        let resource_id = input.resource_id.clone();
+        let added = create_param_specs(
+            &resource_id,
+            &input.headers_to_add,
+            &input.path_params_to_add,
+            &input.query_params_to_add,
+        )?;
+
+        let mut orders = added.orders;
+        for param in &input.headers_to_update {
+            if let Some(order) = param.order {
+                orders.push((
+                    key_resource_header_order(&resource_id, &param.id),
+                    serde_json::to_value(order)?,
+                ));
+            }
+        }
+        for param in &input.path_params_to_update {
+            if let Some(order) = param.order {
+                orders.push((
+                    key_resource_path_param_order(&resource_id, &param.id),
+                    serde_json::to_value(order)?,
+                ));
+            }
+        }
+        for param in &input.query_params_to_update {
+            if let Some(order) = param.order {
+                orders.push((
+                    key_resource_query_param_order(&resource_id, &param.id),
+                    serde_json::to_value(order)?,
+                ));
+            }
+        }
+
+        let mut removed_keys = Vec::new();
+        for id in &input.headers_to_remove {
+            removed_keys.push(key_resource_header_order(&resource_id, id));
+        }
+        for id in &input.path_params_to_remove {
+            removed_keys.push(key_resource_path_param_order(&resource_id, id));
+        }
+        for id in &input.query_params_to_remove {
+            removed_keys.push(key_resource_query_param_order(&resource_id, id));
+        }
+
+        let mut urlencoded_param_orders = HashMap::new();
+        let mut formdata_param_orders = HashMap::new();
+        let body = match input.body {
+            Some(ChangeCaseBody::Update(body_params)) => Some(Some(
+                create_body_block(
+                    body_params,
+                    &mut urlencoded_param_orders,
+                    &mut formdata_param_orders,
+                )
+                .await,
+            )),
+            Some(ChangeCaseBody::Remove) => Some(None),
+            None => None,
+        };
+        orders.extend(body_param_orders(
+            &resource_id,
+            urlencoded_param_orders,
+            formdata_param_orders,
+        )?);
+
+        let id = input.id;
+        self.worktree()
+            .await
+            .edit_cases(ctx, &resource_id, |cases| {
+                if let Some(name) = &input.name
+                    && cases
+                        .iter()
+                        .any(|(case_id, case)| case_id != &id && &case.name == name)
+                {
+                    return Err(Error::new::<ErrorAlreadyExists>(format!(
+                        "case `{}` already exists",
+                        name
+                    )));
+                }
+
+                let case = cases
+                    .get_mut(&id)
+                    .ok_or_join_err_with::<ErrorNotFound>(|| format!("case {} not found", id))?;
+
+                if let Some(name) = input.name {
+                    case.name = name;
+                }
+                match input.description {
+                    Some(ChangeString::Update(description)) => case.description = Some(description),
+                    Some(ChangeString::Remove) => case.description = None,
+                    None => {}
+                }
+
+                patch_params!(
+                    case.headers,
+                    added.headers,
+                    input.headers_to_update,
+                    &input.headers_to_remove
+                );
+                patch_params!(
+                    case.path_params,
+                    added.path_params,
+                    input.path_params_to_update,
+                    &input.path_params_to_remove
+                );
+                patch_params!(
+                    case.query_params,
+                    added.query_params,
+                    input.query_params_to_update,
+                    &input.query_params_to_remove
+                );
+
+                if let Some(body) = body {
+                    case.body = body;
+                }
+                match input.expected_response {
+                    Some(ChangeExpectedResponse::Update(expected)) => {
+                        case.expected_response = Some(Block::new(expected.into()));
+                    }
+                    Some(ChangeExpectedResponse::Remove) => case.expected_response = None,
+                    None => {}
+                }
+
+                Ok(())
+            })
+            .await?;
+
+        self.put_param_orders(ctx, orders).await;
+        self.remove_param_orders(ctx, removed_keys).await;
+
+        Ok(UpdateResourceCaseOutput { id })
+    }
+}
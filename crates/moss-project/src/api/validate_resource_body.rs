@@ -0,0 +1,22 @@
+use moss_applib::AppRuntime;
+
+use crate::{
+    Project,
+    models::operations::{ValidateResourceBodyInput, ValidateResourceBodyOutput},
+};
+
+impl Project {
+    pub async fn validate_resource_body<R: AppRuntime>(
+        &self,
+        ctx: &R::AsyncContext,
+        input: ValidateResourceBodyInput,
+    ) -> joinerror::Result<ValidateResourceBodyOutput> {
+        let violations = self
+            .worktree()
+            .await
+            .validate_body(ctx, &input.id, input.body)
+            .await?;
+
+        Ok(ValidateResourceBodyOutput { violations })
+    }
+}
@@ -0,0 +1,149 @@
+//! Validation of request bodies against the JSON Schema documents of schema resources.
+//!
+//! The draft is picked from the `$schema` of the document, and defaults to 2020-12.
+//! Schema resources are self-contained, so only references local to the document are
+//! resolved.
+
+use serde_json::Value as JsonValue;
+
+use crate::models::types::SchemaViolation;
+
+/// Validates `instance` against `schema` and returns the violations found, if any.
+pub(crate) fn validate(schema: &JsonValue, instance: &JsonValue) -> Vec<SchemaViolation> {
+    // Documents are checked when they're saved, but the adjacent file can be edited by hand
+    let validator = match compile(schema) {
+        Ok(validator) => validator,
+        Err(message) => {
+            return vec![SchemaViolation {
+                pointer: String::new(),
+                message,
+            }];
+        }
+    };
+
+    validator
+        .iter_errors(instance)
+        .map(|error| SchemaViolation {
+            pointer: error.instance_path().as_str().to_string(),
+            message: error.to_string(),
+        })
+        .collect()
+}
+
+/// Checks that `schema` can be used as a schema document.
+pub(crate) fn check_document(schema: &JsonValue) -> Result<(), String> {
+    match schema {
+        JsonValue::Object(_) | JsonValue::Bool(_) => compile(schema).map(|_| ()),
+        _ => Err("a schema document must be an object or a boolean".to_string()),
+    }
+}
+
+fn compile(schema: &JsonValue) -> Result<jsonschema::Validator, String> {
+    jsonschema::validator_for(schema).map_err(|e| format!("invalid schema document: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn pointers(violations: &[SchemaViolation]) -> Vec<&str> {
+        let mut pointers = violations
+            .iter()
+            .map(|v| v.pointer.as_str())
+            .collect::<Vec<_>>();
+        pointers.sort();
+        pointers
+    }
+
+    #[test]
+    fn test_validate_object() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name", "age"],
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "age": { "type": "integer", "minimum": 0 },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "a/b": { "const": 1 }
+            },
+            "additionalProperties": false
+        });
+
+        assert!(validate(&schema, &json!({ "name": "John", "age": 42 })).is_empty());
+
+        let violations = validate(
+            &schema,
+            &json!({
+                "name": "",
+                "age": 1.5,
+                "tags": ["a", 1],
+                "a/b": 2
+            }),
+        );
+        assert_eq!(
+            pointers(&violations),
+            vec!["/age", "/a~1b", "/name", "/tags/1"]
+        );
+
+        let violations = validate(&schema, &json!({ "name": "John" }));
+        assert_eq!(pointers(&violations), vec![""]);
+        assert_eq!(violations[0].message, "\"age\" is a required property");
+    }
+
+    #[test]
+    fn test_validate_references() {
+        let schema = json!({
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "pattern": "^[a-z]+$" },
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } }
+                    }
+                }
+            },
+            "$ref": "#/$defs/node"
+        });
+
+        assert!(validate(&schema, &json!({ "id": "root" })).is_empty());
+
+        let violations = validate(
+            &schema,
+            &json!({ "id": "root", "children": [{ "id": "A" }, { "id": true }] }),
+        );
+        assert_eq!(
+            pointers(&violations),
+            vec!["/children/0/id", "/children/1/id"]
+        );
+    }
+
+    #[test]
+    fn test_validate_ecma_patterns() {
+        // Lookarounds and backreferences are part of ECMA-262
+        let schema = json!({ "type": "string", "pattern": "^(?=.*[0-9])(?!.*(.)\\1).{6,}$" });
+
+        assert!(validate(&schema, &json!("abc123")).is_empty());
+        assert_eq!(validate(&schema, &json!("abcdef")).len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_documents_are_schema_errors() {
+        assert!(check_document(&json!({ "type": 5 })).is_err());
+        assert!(check_document(&json!([])).is_err());
+        assert!(check_document(&json!(true)).is_ok());
+
+        let violations = validate(&json!({ "type": 5 }), &json!(null));
+        assert_eq!(pointers(&violations), vec![""]);
+        assert!(violations[0].message.starts_with("invalid schema document"));
+    }
+
+    #[test]
+    fn test_remote_references_are_not_resolved() {
+        let schema = json!({ "$ref": "https://example.com/user.json" });
+        assert!(check_document(&schema).is_err());
+
+        assert!(check_document(&json!({ "$ref": "#/missing" })).is_err());
+    }
+}
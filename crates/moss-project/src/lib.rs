@@ -3,6 +3,7 @@ pub mod builder;
 pub mod contribution;
 mod edit;
 pub mod git;
+mod json_schema;
 pub mod models;
 pub mod project;
 pub mod registries;
@@ -31,6 +32,8 @@ inventory::submit! {
 pub mod constants {
     pub const ITEM_CONFIG_FILENAME: &str = "config.sap";
     pub const DIR_CONFIG_FILENAME: &str = "config-folder.sap";
+    pub const CASES_CONFIG_FILENAME: &str = "cases.sap";
+    pub const SCHEMA_FILENAME: &str = "schema.json";
 }
 
 pub mod dirs {
@@ -1,13 +1,30 @@
+use moss_bindingutils::primitives::ChangeString;
 use sapic_base::resource::types::primitives::*;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use ts_rs::TS;
 use validator::Validate;
 
-use crate::models::types::{
-    AfterCreateResourceDescription, AfterUpdateDirResourceDescription,
-    AfterUpdateItemResourceDescription, BodyInfo, CreateDirResourceParams,
-    CreateItemResourceParams, HeaderInfo, PathParamInfo, QueryParamInfo, UpdateDirResourceParams,
-    UpdateItemResourceParams, VcsOperation,
+use crate::models::{
+    primitives::{CaseId, HeaderId, PathParamId, QueryParamId},
+    types::{
+        AfterCreateResourceDescription, AfterUpdateDirResourceDescription,
+        AfterUpdateItemResourceDescription, AuthInfo, BodyInfo, CaseInfo, ChangeCaseBody,
+        ChangeExpectedResponse, CreateDirResourceParams, CreateItemResourceParams,
+        ExpectedResponse, HeaderInfo, PathParamInfo, QueryParamInfo, SchemaViolation,
+        UpdateDirResourceParams, UpdateItemResourceParams, VcsOperation,
+        amqp::AmqpInfo,
+        graphql::GraphqlInfo,
+        grpc::GrpcInfo,
+        http::{
+            AddBodyParams, AddHeaderParams, AddPathParamParams, AddQueryParamParams,
+            UpdateHeaderParams, UpdatePathParamParams, UpdateQueryParamParams,
+        },
+        mqtt::MqttInfo,
+        sse::SseInfo,
+        validate_case_name,
+        websocket::WebSocketInfo,
+    },
 };
 // ########################################################
 // ###                Create Resource                   ###
@@ -146,6 +163,7 @@ pub struct BatchUpdateResourceOutput {}
 #[ts(export, export_to = "operations.ts")]
 pub struct DescribeResourceOutput {
     pub name: String,
+    pub description: Option<String>,
     pub class: ResourceClass,
     pub kind: ResourceKind,
     pub protocol: Option<ResourceProtocol>,
@@ -154,6 +172,189 @@ pub struct DescribeResourceOutput {
     pub path_params: Vec<PathParamInfo>,
     pub query_params: Vec<QueryParamInfo>,
     pub body: Option<BodyInfo>,
+    /// The component the body is expanded from, if the resource doesn't define its own.
+    pub body_component: Option<ResourceId>,
+    pub body_schema: Option<ResourceId>,
+    /// Component resources referenced by the resource.
+    pub components: Vec<ResourceId>,
+    /// The auth in effect for the resource, after inheritance.
+    pub auth: Option<AuthInfo>,
+    #[ts(optional, type = "JsonValue")]
+    pub schema: Option<JsonValue>,
+    /// Subprotocols and saved messages of a WebSocket endpoint.
+    pub websocket: Option<WebSocketInfo>,
+    /// Query document, operation name and variables of a GraphQL endpoint.
+    pub graphql: Option<GraphqlInfo>,
+    /// Service descriptors, method and request messages of a gRPC endpoint.
+    pub grpc: Option<GrpcInfo>,
+    /// Reconnection and recording settings of an HTTP endpoint streaming server-sent events.
+    pub sse: Option<SseInfo>,
+    /// Exchange, routing key and message to publish of an AMQP endpoint.
+    pub amqp: Option<AmqpInfo>,
+    /// Topic and message to publish of an MQTT endpoint.
+    pub mqtt: Option<MqttInfo>,
+}
+
+// ########################################################
+// ###             Validate Resource Body               ###
+// ########################################################
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "operations.ts")]
+pub struct ValidateResourceBodyInput {
+    pub id: ResourceId,
+    /// Validated in place of the stored json body, e.g. for unsaved changes.
+    #[ts(optional, type = "JsonValue")]
+    pub body: Option<JsonValue>,
+}
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct ValidateResourceBodyOutput {
+    pub violations: Vec<SchemaViolation>,
+}
+
+// ########################################################
+// ###             Create Resource Case                 ###
+// ########################################################
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "operations.ts")]
+pub struct CreateResourceCaseInput {
+    pub resource_id: ResourceId,
+
+    #[validate(length(min = 1), custom(function = "validate_case_name"))]
+    pub name: String,
+    pub description: Option<String>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddHeaderParams>>", optional)]
+    pub headers: Vec<AddHeaderParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddPathParamParams>>", optional)]
+    pub path_params: Vec<AddPathParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddQueryParamParams>>", optional)]
+    pub query_params: Vec<AddQueryParamParams>,
+    pub body: Option<AddBodyParams>,
+
+    pub expected_response: Option<ExpectedResponse>,
+}
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct CreateResourceCaseOutput {
+    pub id: CaseId,
+}
+
+// ########################################################
+// ###             Update Resource Case                 ###
+// ########################################################
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, Deserialize, TS, Validate)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "operations.ts")]
+pub struct UpdateResourceCaseInput {
+    pub resource_id: ResourceId,
+    pub id: CaseId,
+
+    #[validate(length(min = 1), custom(function = "validate_case_name"))]
+    pub name: Option<String>,
+    pub description: Option<ChangeString>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddHeaderParams>>", optional)]
+    pub headers_to_add: Vec<AddHeaderParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdateHeaderParams>>", optional)]
+    pub headers_to_update: Vec<UpdateHeaderParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<HeaderId>>", optional)]
+    pub headers_to_remove: Vec<HeaderId>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddPathParamParams>>", optional)]
+    pub path_params_to_add: Vec<AddPathParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdatePathParamParams>>", optional)]
+    pub path_params_to_update: Vec<UpdatePathParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<PathParamId>>", optional)]
+    pub path_params_to_remove: Vec<PathParamId>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddQueryParamParams>>", optional)]
+    pub query_params_to_add: Vec<AddQueryParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdateQueryParamParams>>", optional)]
+    pub query_params_to_update: Vec<UpdateQueryParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<QueryParamId>>", optional)]
+    pub query_params_to_remove: Vec<QueryParamId>,
+
+    pub body: Option<ChangeCaseBody>,
+    pub expected_response: Option<ChangeExpectedResponse>,
+}
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct UpdateResourceCaseOutput {
+    pub id: CaseId,
+}
+
+// ########################################################
+// ###             Delete Resource Case                 ###
+// ########################################################
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct DeleteResourceCaseInput {
+    pub resource_id: ResourceId,
+    pub id: CaseId,
+}
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct DeleteResourceCaseOutput {
+    pub id: CaseId,
+}
+
+// ########################################################
+// ###              List Resource Cases                 ###
+// ########################################################
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct ListResourceCasesInput {
+    pub resource_id: ResourceId,
+}
+
+/// @category Operation
+#[derive(Clone, Debug, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "operations.ts")]
+pub struct ListResourceCasesOutput {
+    pub cases: Vec<CaseInfo>,
 }
 
 /// @category Operation
@@ -9,15 +9,23 @@ ids!([
     HeaderId,
     FormDataParamId,
     UrlencodedParamId,
+    CaseId,
+    WebSocketMessageId,
 ]);
 
 impl From<&HttpMethod> for ResourceProtocol {
     fn from(method: &HttpMethod) -> Self {
         match method {
             HttpMethod::Get => ResourceProtocol::Get,
+            HttpMethod::Head => ResourceProtocol::Head,
             HttpMethod::Post => ResourceProtocol::Post,
             HttpMethod::Put => ResourceProtocol::Put,
+            HttpMethod::Patch => ResourceProtocol::Patch,
             HttpMethod::Delete => ResourceProtocol::Delete,
+            HttpMethod::Connect => ResourceProtocol::Connect,
+            HttpMethod::Options => ResourceProtocol::Options,
+            HttpMethod::Trace => ResourceProtocol::Trace,
+            HttpMethod::Custom(method) => ResourceProtocol::Custom(method.clone()),
         }
     }
 }
@@ -28,10 +36,23 @@ impl From<&HttpMethod> for ResourceProtocol {
 pub enum HttpMethod {
     #[serde(rename = "GET")]
     Get,
+    #[serde(rename = "HEAD")]
+    Head,
     #[serde(rename = "POST")]
     Post,
     #[serde(rename = "PUT")]
     Put,
+    #[serde(rename = "PATCH")]
+    Patch,
     #[serde(rename = "DELETE")]
     Delete,
+    #[serde(rename = "CONNECT")]
+    Connect,
+    #[serde(rename = "OPTIONS")]
+    Options,
+    #[serde(rename = "TRACE")]
+    Trace,
+    /// Any other method, such as the WebDAV ones.
+    #[serde(rename = "CUSTOM")]
+    Custom(String),
 }
@@ -1,16 +1,30 @@
+pub mod amqp;
+pub mod graphql;
+pub mod grpc;
 pub mod http;
+pub mod mqtt;
+pub mod sse;
+pub mod websocket;
 
+use amqp::*;
+use graphql::*;
+use grpc::*;
 use http::*;
+use indexmap::IndexMap;
+use moss_bindingutils::primitives::ChangeString;
+use mqtt::*;
 use sapic_base::resource::types::primitives::{ResourceId, *};
 use sapic_ipc::contracts::main::resource::FrontendResourcePath;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use sse::*;
 use std::path::{Path, PathBuf};
 use ts_rs::TS;
 use validator::{Validate, ValidationError};
+use websocket::*;
 
 use crate::models::primitives::{
-    FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId,
+    CaseId, FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId,
 };
 
 /// @category Type
@@ -25,9 +39,12 @@ pub struct CreateItemResourceParams {
 
     #[validate(length(min = 1))]
     pub name: String,
+    /// Notes about the resource, such as what it is used for.
+    pub description: Option<String>,
     pub order: isize,
 
     // TODO: url
+    #[validate(custom(function = "validate_resource_protocol"))]
     pub protocol: Option<ResourceProtocol>,
     pub url: Option<String>,
 
@@ -35,6 +52,42 @@ pub struct CreateItemResourceParams {
     pub path_params: Vec<AddPathParamParams>,
     pub query_params: Vec<AddQueryParamParams>,
     pub body: Option<AddBodyParams>,
+    /// Schema resource the json body is validated against.
+    pub body_schema: Option<ResourceId>,
+    /// Component resources expanded into the endpoint, in order of precedence.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<ResourceId>>", optional)]
+    pub components: Vec<ResourceId>,
+    #[validate(custom(function = "validate_auth"))]
+    pub auth: Option<AuthScheme>,
+
+    /// JSON Schema document of a schema resource.
+    #[ts(optional, type = "JsonValue")]
+    pub schema: Option<JsonValue>,
+
+    /// Subprotocols and saved messages of a WebSocket endpoint.
+    #[validate(nested)]
+    pub websocket: Option<WebSocketParams>,
+
+    /// Query document, operation name and variables of a GraphQL endpoint.
+    #[validate(nested)]
+    pub graphql: Option<GraphqlParams>,
+
+    /// Service descriptors, method and request messages of a gRPC endpoint.
+    #[validate(nested)]
+    pub grpc: Option<GrpcParams>,
+
+    /// Treats the response of an HTTP endpoint as a stream of server-sent events.
+    #[validate(nested)]
+    pub sse: Option<SseParams>,
+
+    /// Exchange, routing key and message to publish of an AMQP endpoint.
+    #[validate(nested)]
+    pub amqp: Option<AmqpParams>,
+
+    /// Topic and message to publish of an MQTT endpoint.
+    #[validate(nested)]
+    pub mqtt: Option<MqttParams>,
 }
 
 /// @category Type
@@ -49,7 +102,24 @@ pub struct CreateDirResourceParams {
 
     #[validate(length(min = 1))]
     pub name: String,
+    /// Notes about the resource, such as what it is used for.
+    pub description: Option<String>,
     pub order: isize,
+
+    /// Entries marked as `propagate` are inherited by every resource inside the directory.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddHeaderParams>>", optional)]
+    pub headers: Vec<AddHeaderParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddPathParamParams>>", optional)]
+    pub path_params: Vec<AddPathParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddQueryParamParams>>", optional)]
+    pub query_params: Vec<AddQueryParamParams>,
+
+    /// Used by every resource inside the directory that inherits its auth.
+    #[validate(custom(function = "validate_auth"))]
+    pub auth: Option<AuthScheme>,
 }
 
 /// @category Type
@@ -67,7 +137,10 @@ pub struct UpdateItemResourceParams {
 
     #[validate(length(min = 1))]
     pub name: Option<String>,
+    #[ts(optional, type = "ChangeString")]
+    pub description: Option<ChangeString>,
 
+    #[validate(custom(function = "validate_resource_protocol"))]
     pub protocol: Option<ResourceProtocol>,
     pub url: Option<String>,
 
@@ -84,6 +157,38 @@ pub struct UpdateItemResourceParams {
     pub query_params_to_remove: Vec<QueryParamId>,
 
     pub body: Option<UpdateBodyParams>,
+    pub body_schema: Option<ChangeBodySchema>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<ResourceId>>", optional)]
+    pub components_to_add: Vec<ResourceId>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<ResourceId>>", optional)]
+    pub components_to_remove: Vec<ResourceId>,
+
+    #[validate(custom(function = "validate_change_auth"))]
+    pub auth: Option<ChangeAuth>,
+
+    /// Replaces the JSON Schema document of a schema resource.
+    #[ts(optional, type = "JsonValue")]
+    pub schema: Option<JsonValue>,
+
+    #[validate(nested)]
+    pub websocket: Option<UpdateWebSocketParams>,
+
+    #[validate(nested)]
+    pub graphql: Option<UpdateGraphqlParams>,
+
+    #[validate(nested)]
+    pub grpc: Option<UpdateGrpcParams>,
+
+    pub sse: Option<ChangeSse>,
+
+    #[validate(custom(function = "validate_change_amqp"))]
+    pub amqp: Option<ChangeAmqp>,
+
+    #[validate(custom(function = "validate_change_mqtt"))]
+    pub mqtt: Option<ChangeMqtt>,
 }
 
 /// @category Type
@@ -123,8 +228,43 @@ pub struct UpdateDirResourceParams {
 
     #[validate(length(min = 1))]
     pub name: Option<String>,
+    #[ts(optional, type = "ChangeString")]
+    pub description: Option<ChangeString>,
     pub order: Option<isize>,
     pub expanded: Option<bool>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddHeaderParams>>", optional)]
+    pub headers_to_add: Vec<AddHeaderParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdateHeaderParams>>", optional)]
+    pub headers_to_update: Vec<UpdateHeaderParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<HeaderId>>", optional)]
+    pub headers_to_remove: Vec<HeaderId>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddPathParamParams>>", optional)]
+    pub path_params_to_add: Vec<AddPathParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdatePathParamParams>>", optional)]
+    pub path_params_to_update: Vec<UpdatePathParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<PathParamId>>", optional)]
+    pub path_params_to_remove: Vec<PathParamId>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddQueryParamParams>>", optional)]
+    pub query_params_to_add: Vec<AddQueryParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdateQueryParamParams>>", optional)]
+    pub query_params_to_update: Vec<UpdateQueryParamParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<QueryParamId>>", optional)]
+    pub query_params_to_remove: Vec<QueryParamId>,
+
+    #[validate(custom(function = "validate_change_auth"))]
+    pub auth: Option<ChangeAuth>,
 }
 
 /// @category Type
@@ -145,6 +285,8 @@ pub struct AfterUpdateItemResourceDescription {
     pub id: ResourceId,
 
     pub path: FrontendResourcePath,
+    /// Resources referencing the updated component, whose descriptions have changed along with it.
+    pub dependents: Vec<ResourceId>,
 }
 /// @category Type
 #[derive(Clone, Debug, Serialize, TS)]
@@ -188,6 +330,10 @@ pub struct HeaderInfo {
     pub disabled: bool,
     pub propagate: bool,
     pub order: Option<isize>,
+    /// The directory the entry is inherited from, if it isn't defined on the resource itself.
+    pub inherited_from: Option<ResourceId>,
+    /// The component the entry is expanded from, if it isn't defined on the resource itself.
+    pub component: Option<ResourceId>,
 }
 
 /// @category Type
@@ -204,6 +350,10 @@ pub struct PathParamInfo {
     pub disabled: bool,
     pub propagate: bool,
     pub order: Option<isize>,
+    /// The directory the entry is inherited from, if it isn't defined on the resource itself.
+    pub inherited_from: Option<ResourceId>,
+    /// The component the entry is expanded from, if it isn't defined on the resource itself.
+    pub component: Option<ResourceId>,
 }
 
 /// @category Type
@@ -220,6 +370,10 @@ pub struct QueryParamInfo {
     pub disabled: bool,
     pub propagate: bool,
     pub order: Option<isize>,
+    /// The directory the entry is inherited from, if it isn't defined on the resource itself.
+    pub inherited_from: Option<ResourceId>,
+    /// The component the entry is expanded from, if it isn't defined on the resource itself.
+    pub component: Option<ResourceId>,
 }
 
 /// @category Type
@@ -236,6 +390,8 @@ pub struct UrlencodedParamInfo {
     pub disabled: bool,
     pub propagate: bool,
     pub order: Option<isize>,
+    /// The directory the entry is inherited from, if it isn't defined on the resource itself.
+    pub inherited_from: Option<ResourceId>,
 }
 
 /// @category Type
@@ -252,6 +408,8 @@ pub struct FormDataParamInfo {
     pub disabled: bool,
     pub propagate: bool,
     pub order: Option<isize>,
+    /// The directory the entry is inherited from, if it isn't defined on the resource itself.
+    pub inherited_from: Option<ResourceId>,
 }
 
 /// @category Type
@@ -267,6 +425,280 @@ pub enum BodyInfo {
     FormData(Vec<FormDataParamInfo>),
 }
 
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct ExpectedResponse {
+    pub status: Option<u16>,
+    #[ts(optional, type = "Record<string, string>")]
+    pub headers: Option<IndexMap<String, String>>,
+    #[ts(optional, type = "JsonValue")]
+    pub body: Option<JsonValue>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeCaseBody {
+    Update(AddBodyParams),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeExpectedResponse {
+    Update(ExpectedResponse),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct CaseInfo {
+    pub id: CaseId,
+    pub name: String,
+    pub description: Option<String>,
+    /// Entries overriding the ones of the endpoint with the same name.
+    pub headers: Vec<HeaderInfo>,
+    pub path_params: Vec<PathParamInfo>,
+    pub query_params: Vec<QueryParamInfo>,
+    /// Replaces the body of the endpoint when set.
+    pub body: Option<BodyInfo>,
+    pub expected_response: Option<ExpectedResponse>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeBodySchema {
+    Update(ResourceId),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct SchemaViolation {
+    /// JSON pointer to the offending value of the body, empty for the body itself.
+    pub pointer: String,
+    pub message: String,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub enum ApiKeyLocation {
+    Header,
+    Query,
+}
+
+/// Authentication scheme of a resource.
+/// Secrets must reference an environment variable, e.g. `{{token}}`, instead of being stored inline.
+///
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub enum AuthScheme {
+    /// Sends no credentials, even if a parent directory defines some.
+    None,
+    /// Uses the auth of the closest parent directory defining one, the same as no auth at all.
+    Inherit,
+    Basic {
+        username: String,
+        password: String,
+    },
+    Bearer {
+        token: String,
+    },
+    ApiKey {
+        key: String,
+        value: String,
+        location: ApiKeyLocation,
+    },
+    /// Answers the digest challenge of the server, which is requested by sending the request
+    /// once without its body.
+    Digest {
+        username: String,
+        password: String,
+    },
+    /// Sends the access token obtained from the authorization server as a bearer token.
+    #[serde(rename = "oauth2")]
+    OAuth2(OAuth2Config),
+    /// Signs the request with AWS Signature Version 4 right before it is sent.
+    AwsSigV4(AwsSigV4Config),
+}
+
+impl AuthScheme {
+    /// Fails if a secret of the scheme is stored inline.
+    pub fn check_secrets(&self) -> Result<(), String> {
+        let secrets = match self {
+            AuthScheme::None | AuthScheme::Inherit => vec![],
+            AuthScheme::Basic { password, .. } | AuthScheme::Digest { password, .. } => {
+                vec![("password", password)]
+            }
+            AuthScheme::Bearer { token } => vec![("token", token)],
+            AuthScheme::ApiKey { value, .. } => vec![("value", value)],
+            AuthScheme::OAuth2(config) => config
+                .client_secret
+                .iter()
+                .map(|secret| ("client_secret", secret))
+                .chain(
+                    config
+                        .refresh_token
+                        .iter()
+                        .map(|token| ("refresh_token", token)),
+                )
+                .collect(),
+            AuthScheme::AwsSigV4(config) => [
+                ("access_key", Some(&config.access_key)),
+                ("secret_key", Some(&config.secret_key)),
+                ("session_token", config.session_token.as_ref()),
+            ]
+            .into_iter()
+            .filter_map(|(field, secret)| secret.map(|secret| (field, secret)))
+            .collect(),
+        };
+
+        for (field, secret) in secrets {
+            if !is_variable_reference(secret) {
+                return Err(format!(
+                    "the auth {} must reference an environment variable, e.g. `{{{{{}}}}}`",
+                    field, field
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub enum OAuth2Grant {
+    /// Authorization code with PKCE, redirected to a loopback address.
+    AuthorizationCode,
+    ClientCredentials,
+    DeviceCode,
+    RefreshToken,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct OAuth2Config {
+    pub grant: OAuth2Grant,
+    /// Required by the authorization code grant.
+    pub auth_url: Option<String>,
+    pub token_url: String,
+    /// Required by the device code grant.
+    pub device_auth_url: Option<String>,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<String>>", optional)]
+    pub scopes: Vec<String>,
+    /// Required by the refresh token grant.
+    pub refresh_token: Option<String>,
+    /// Port of the loopback redirect uri of the authorization code grant.
+    /// A random port is used if not set.
+    pub callback_port: Option<u16>,
+}
+
+impl OAuth2Config {
+    /// Fails if an endpoint or a token required by the grant is missing.
+    pub fn check_grant(&self) -> Result<(), String> {
+        let missing = match self.grant {
+            OAuth2Grant::AuthorizationCode if self.auth_url.is_none() => Some("auth url"),
+            OAuth2Grant::DeviceCode if self.device_auth_url.is_none() => Some("device auth url"),
+            OAuth2Grant::RefreshToken if self.refresh_token.is_none() => Some("refresh token"),
+            _ => None,
+        };
+
+        match missing {
+            Some(field) => Err(format!("the {} is required by the oauth2 grant", field)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// AWS credentials and scope used to sign requests.
+/// The keys and the session token must reference environment variables.
+///
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct AwsSigV4Config {
+    pub access_key: String,
+    pub secret_key: String,
+    /// Required for temporary credentials.
+    pub session_token: Option<String>,
+    /// For example `us-east-1`.
+    pub region: String,
+    /// Signing name of the service, for example `execute-api`, `lambda` or `s3`.
+    pub service: String,
+}
+
+fn is_variable_reference(value: &str) -> bool {
+    let Some(name) = value
+        .trim()
+        .strip_prefix("{{")
+        .and_then(|value| value.strip_suffix("}}"))
+    else {
+        return false;
+    };
+    let name = name.trim();
+
+    !name.is_empty() && !name.contains(|c: char| c == '{' || c == '}' || c.is_whitespace())
+}
+
+/// Fails if the password of a message queue endpoint is stored inline.
+fn validate_password_reference(password: &str) -> Result<(), ValidationError> {
+    if !is_variable_reference(password) {
+        return Err(ValidationError::new("inline_secret").with_message(
+            "the password must reference an environment variable, e.g. `{{password}}`".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeAuth {
+    Update(AuthScheme),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct AuthInfo {
+    pub scheme: AuthScheme,
+    /// The directory the auth is inherited from, if it isn't defined on the resource itself.
+    pub inherited_from: Option<ResourceId>,
+}
+
 // Check that input path begins with a valid top folder
 // such as requests, endpoints, etc.
 pub(super) fn validate_create_resource_input_path(path: &Path) -> Result<(), ValidationError> {
@@ -276,3 +708,57 @@ pub(super) fn validate_create_resource_input_path(path: &Path) -> Result<(), Val
 
     Ok(())
 }
+
+/// Fails if the case name contains a path separator, since cases are named like resources.
+pub(super) fn validate_case_name(name: &str) -> Result<(), ValidationError> {
+    if name.contains(['/', '\\']) {
+        return Err(ValidationError::new("invalid_name")
+            .with_message("the case name must not contain `/` or `\\`".into()));
+    }
+
+    Ok(())
+}
+
+fn validate_resource_protocol(protocol: &ResourceProtocol) -> Result<(), ValidationError> {
+    protocol
+        .validate()
+        .map_err(|err| ValidationError::new("invalid_protocol").with_message(err.into()))
+}
+
+fn validate_auth(auth: &AuthScheme) -> Result<(), ValidationError> {
+    auth.check_secrets()
+        .map_err(|err| ValidationError::new("inline_secret").with_message(err.into()))?;
+
+    if let AuthScheme::OAuth2(config) = auth {
+        config
+            .check_grant()
+            .map_err(|err| ValidationError::new("invalid_oauth2").with_message(err.into()))?;
+    }
+
+    Ok(())
+}
+
+fn validate_change_auth(auth: &ChangeAuth) -> Result<(), ValidationError> {
+    match auth {
+        ChangeAuth::Update(auth) => validate_auth(auth),
+        ChangeAuth::Remove => Ok(()),
+    }
+}
+
+fn validate_change_amqp(amqp: &ChangeAmqp) -> Result<(), ValidationError> {
+    match amqp {
+        ChangeAmqp::Update(params) => params.validate().map_err(|err| {
+            ValidationError::new("invalid_amqp_settings").with_message(err.to_string().into())
+        }),
+        ChangeAmqp::Remove => Ok(()),
+    }
+}
+
+fn validate_change_mqtt(mqtt: &ChangeMqtt) -> Result<(), ValidationError> {
+    match mqtt {
+        ChangeMqtt::Update(params) => params.validate().map_err(|err| {
+            ValidationError::new("invalid_mqtt_settings").with_message(err.to_string().into())
+        }),
+        ChangeMqtt::Remove => Ok(()),
+    }
+}
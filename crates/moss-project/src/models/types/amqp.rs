@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use super::validate_password_reference;
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct AmqpParams {
+    /// Credentials to connect with, overriding the ones of the `amqp://` url. The password
+    /// must reference an environment variable, e.g. `{{password}}`.
+    pub username: Option<String>,
+    #[validate(custom(function = "validate_password_reference"))]
+    pub password: Option<String>,
+    /// Exchange messages are published to, the default exchange when empty.
+    #[serde(default)]
+    #[ts(as = "Option<String>", optional)]
+    pub exchange: String,
+    /// Routing key messages are published with. Subscriptions without a queue bind to the
+    /// exchange with it.
+    #[serde(default)]
+    #[ts(as = "Option<String>", optional)]
+    pub routing_key: String,
+    /// Queue subscriptions consume from. When not set, they consume from the queue named after
+    /// the routing key with the default exchange, and from a temporary queue bound to the
+    /// exchange for the time of the subscription otherwise.
+    pub queue: Option<String>,
+    /// Body of the published messages. Can reference environment variables.
+    #[serde(default)]
+    #[ts(as = "Option<String>", optional)]
+    pub payload: String,
+    pub content_type: Option<String>,
+    pub correlation_id: Option<String>,
+    pub reply_to: Option<String>,
+    pub message_id: Option<String>,
+    /// Time the published messages live in a queue before expiring, in milliseconds.
+    pub expiration_ms: Option<u64>,
+    #[validate(range(max = 9))]
+    pub priority: Option<u8>,
+    /// Publishes the messages with the persistent delivery mode, so that they survive a
+    /// restart of the broker.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub persistent: bool,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeAmqp {
+    Update(Box<AmqpParams>),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct AmqpInfo {
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub exchange: String,
+    pub routing_key: String,
+    pub queue: Option<String>,
+    pub payload: String,
+    pub content_type: Option<String>,
+    pub correlation_id: Option<String>,
+    pub reply_to: Option<String>,
+    pub message_id: Option<String>,
+    pub expiration_ms: Option<u64>,
+    pub priority: Option<u8>,
+    pub persistent: bool,
+}
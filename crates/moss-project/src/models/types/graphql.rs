@@ -0,0 +1,63 @@
+use moss_bindingutils::primitives::{ChangeJsonValue, ChangeString};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use ts_rs::TS;
+use validator::{Validate, ValidationError};
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphqlParams {
+    /// The query document, which may define several operations.
+    pub query: String,
+    /// Operation of the document to execute, required when it defines more than one.
+    pub operation_name: Option<String>,
+    /// Values of the operation variables, as a JSON object.
+    #[ts(optional, type = "JsonValue")]
+    #[validate(custom(function = "validate_graphql_variables"))]
+    pub variables: Option<JsonValue>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGraphqlParams {
+    pub query: Option<String>,
+    #[ts(optional, type = "ChangeString")]
+    pub operation_name: Option<ChangeString>,
+    #[ts(optional, type = "ChangeJsonValue")]
+    #[validate(custom(function = "validate_change_graphql_variables"))]
+    pub variables: Option<ChangeJsonValue>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct GraphqlInfo {
+    pub query: String,
+    pub operation_name: Option<String>,
+    #[ts(optional, type = "JsonValue")]
+    pub variables: Option<JsonValue>,
+}
+
+fn validate_graphql_variables(variables: &JsonValue) -> Result<(), ValidationError> {
+    if !variables.is_object() {
+        return Err(ValidationError::new("invalid_graphql_variables")
+            .with_message("graphql variables must be a JSON object".into()));
+    }
+
+    Ok(())
+}
+
+fn validate_change_graphql_variables(variables: &ChangeJsonValue) -> Result<(), ValidationError> {
+    match variables {
+        ChangeJsonValue::Update(variables) => validate_graphql_variables(variables),
+        ChangeJsonValue::Remove => Ok(()),
+    }
+}
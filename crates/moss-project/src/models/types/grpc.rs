@@ -0,0 +1,84 @@
+use moss_bindingutils::primitives::ChangeUsize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::path::PathBuf;
+use ts_rs::TS;
+use validator::{Validate, ValidationError};
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcParams {
+    /// `.proto` files describing the service. When empty, the service is discovered through
+    /// the server reflection of the endpoint.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<PathBuf>>", optional)]
+    pub proto_files: Vec<PathBuf>,
+    /// Directories the imports of the `.proto` files are resolved against, besides the
+    /// directories of the files themselves.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<PathBuf>>", optional)]
+    pub import_paths: Vec<PathBuf>,
+    /// Fully qualified name of the service, such as `helloworld.Greeter`.
+    #[validate(length(min = 1))]
+    pub service: String,
+    #[validate(length(min = 1))]
+    pub method: String,
+    /// Request messages as JSON objects, sent in order. Unary and server-streaming methods
+    /// take exactly one.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<JsonValue>>", optional)]
+    #[validate(custom(function = "validate_grpc_messages"))]
+    pub messages: Vec<JsonValue>,
+    /// Deadline of the call, in milliseconds. Unary calls are given 30 seconds when omitted,
+    /// streaming calls have no deadline.
+    pub timeout_ms: Option<usize>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGrpcParams {
+    /// Replaces the `.proto` files describing the service.
+    pub proto_files: Option<Vec<PathBuf>>,
+    /// Replaces the directories imports are resolved against.
+    pub import_paths: Option<Vec<PathBuf>>,
+    #[validate(length(min = 1))]
+    pub service: Option<String>,
+    #[validate(length(min = 1))]
+    pub method: Option<String>,
+    /// Replaces the request messages.
+    #[ts(optional, type = "Array<JsonValue>")]
+    #[validate(custom(function = "validate_grpc_messages"))]
+    pub messages: Option<Vec<JsonValue>>,
+    #[ts(optional, type = "ChangeUsize")]
+    pub timeout_ms: Option<ChangeUsize>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct GrpcInfo {
+    pub proto_files: Vec<PathBuf>,
+    pub import_paths: Vec<PathBuf>,
+    pub service: String,
+    pub method: String,
+    #[ts(type = "Array<JsonValue>")]
+    pub messages: Vec<JsonValue>,
+    pub timeout_ms: Option<usize>,
+}
+
+fn validate_grpc_messages(messages: &[JsonValue]) -> Result<(), ValidationError> {
+    if messages.iter().any(|message| !message.is_object()) {
+        return Err(ValidationError::new("invalid_grpc_message")
+            .with_message("grpc messages must be JSON objects".into()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use super::validate_password_reference;
+
+/// @category Type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, TS)]
+#[ts(export, export_to = "types.ts")]
+pub enum MqttVersion {
+    #[default]
+    #[serde(rename = "3.1.1")]
+    V311,
+    #[serde(rename = "5")]
+    V5,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttParams {
+    /// Version of the protocol spoken with the broker. Defaults to MQTT 3.1.1.
+    #[serde(default)]
+    #[ts(as = "Option<MqttVersion>", optional)]
+    pub version: MqttVersion,
+    /// Identifier of the client, a random one being generated when not set.
+    /// Can reference environment variables.
+    pub client_id: Option<String>,
+    /// Credentials to connect with, overriding the ones of the `mqtt://` url. The password
+    /// must reference an environment variable, e.g. `{{password}}`.
+    pub username: Option<String>,
+    #[validate(custom(function = "validate_password_reference"))]
+    pub password: Option<String>,
+    /// Topic messages are published to. Can reference environment variables.
+    #[validate(length(min = 1))]
+    pub topic: String,
+    /// Topic filter of subscriptions, which may contain the `+` and `#` wildcards.
+    /// Subscriptions are made to the topic when not set.
+    pub topic_filter: Option<String>,
+    /// Quality of service level messages are published and subscribed with, from 0 to 2.
+    #[serde(default)]
+    #[ts(as = "Option<u8>", optional)]
+    #[validate(range(max = 2))]
+    pub qos: u8,
+    /// Asks the broker to retain the published messages for future subscribers of the topic.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub retain: bool,
+    /// Starts a session without the subscriptions and messages pending from a previous one.
+    #[serde(default = "default_clean_session")]
+    #[ts(as = "Option<bool>", optional)]
+    pub clean_session: bool,
+    /// Body of the published messages. Can reference environment variables.
+    #[serde(default)]
+    #[ts(as = "Option<String>", optional)]
+    pub payload: String,
+    /// Properties of the published messages, sent with MQTT 5 only.
+    pub content_type: Option<String>,
+    pub response_topic: Option<String>,
+    pub correlation_data: Option<String>,
+    /// Time the broker keeps the published messages for subscribers, in seconds.
+    pub message_expiry_secs: Option<u32>,
+}
+
+fn default_clean_session() -> bool {
+    true
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeMqtt {
+    Update(Box<MqttParams>),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct MqttInfo {
+    pub version: MqttVersion,
+    pub client_id: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub topic: String,
+    pub topic_filter: Option<String>,
+    pub qos: u8,
+    pub retain: bool,
+    pub clean_session: bool,
+    pub payload: String,
+    pub content_type: Option<String>,
+    pub response_topic: Option<String>,
+    pub correlation_data: Option<String>,
+    pub message_expiry_secs: Option<u32>,
+}
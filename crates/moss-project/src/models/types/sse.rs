@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct SseParams {
+    /// Reconnects once the stream ends or the connection is lost, resuming from the last
+    /// event received through the `Last-Event-ID` header.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub reconnect: bool,
+    /// Delay before reconnecting, in milliseconds, until the server sets one with a `retry`
+    /// field. Defaults to 3 seconds.
+    pub retry_ms: Option<usize>,
+    /// Attempts to reconnect in a row before giving up, reconnecting until the stream is
+    /// stopped when not set.
+    pub max_reconnects: Option<usize>,
+    /// Records the events received to the project storage, replacing the ones of the previous
+    /// stream.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub record: bool,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export, export_to = "types.ts")]
+pub enum ChangeSse {
+    Update(SseParams),
+    Remove,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(optional_fields)]
+#[ts(export, export_to = "types.ts")]
+pub struct SseInfo {
+    pub reconnect: bool,
+    pub retry_ms: Option<usize>,
+    pub max_reconnects: Option<usize>,
+    pub record: bool,
+}
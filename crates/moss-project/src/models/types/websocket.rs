@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::path::PathBuf;
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::models::primitives::WebSocketMessageId;
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub enum WebSocketMessagePayload {
+    Text(String),
+    Json(#[ts(type = "JsonValue")] JsonValue),
+    /// Path of the file whose content is sent, the same as a binary body.
+    Binary(PathBuf),
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[serde(rename_all = "camelCase")]
+pub struct AddWebSocketMessageParams {
+    #[validate(length(min = 1))]
+    pub name: String,
+    pub payload: WebSocketMessagePayload,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebSocketMessageParams {
+    pub id: WebSocketMessageId,
+    #[validate(length(min = 1))]
+    pub name: Option<String>,
+    pub payload: Option<WebSocketMessagePayload>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct WebSocketParams {
+    /// Offered to the server during the handshake, in order of preference.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<String>>", optional)]
+    pub subprotocols: Vec<String>,
+    /// Messages saved to be sent once connected, in the order they are shown.
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddWebSocketMessageParams>>", optional)]
+    #[validate(nested)]
+    pub messages: Vec<AddWebSocketMessageParams>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, Deserialize, Serialize, Validate, TS)]
+#[ts(export, export_to = "types.ts")]
+#[ts(optional_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebSocketParams {
+    /// Replaces the subprotocols offered during the handshake.
+    pub subprotocols: Option<Vec<String>>,
+
+    #[serde(default)]
+    #[ts(as = "Option<Vec<AddWebSocketMessageParams>>", optional)]
+    #[validate(nested)]
+    pub messages_to_add: Vec<AddWebSocketMessageParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<UpdateWebSocketMessageParams>>", optional)]
+    #[validate(nested)]
+    pub messages_to_update: Vec<UpdateWebSocketMessageParams>,
+    #[serde(default)]
+    #[ts(as = "Option<Vec<WebSocketMessageId>>", optional)]
+    pub messages_to_remove: Vec<WebSocketMessageId>,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct WebSocketMessageInfo {
+    pub id: WebSocketMessageId,
+    pub name: String,
+    pub payload: WebSocketMessagePayload,
+}
+
+/// @category Type
+#[derive(Clone, Debug, PartialEq, Serialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "types.ts")]
+pub struct WebSocketInfo {
+    pub subprotocols: Vec<String>,
+    pub messages: Vec<WebSocketMessageInfo>,
+}
@@ -5,7 +5,7 @@ use moss_applib::AppRuntime;
 use moss_bindingutils::primitives::{ChangePath, ChangeString};
 use moss_fs::{CreateOptions, FileSystem};
 use moss_git::{repository::Repository, url::GitUrl};
-use moss_storage2::KvStorage;
+use moss_storage2::{KvStorage, models::primitives::StorageScope};
 
 use sapic_base::{
     other::GitProviderKind,
@@ -14,13 +14,16 @@ use sapic_base::{
         manifest::{MANIFEST_FILE_NAME, ManifestVcs, ProjectManifest},
         types::primitives::ProjectId,
     },
+    resource::types::primitives::ResourceId,
     user::types::primitives::AccountId,
 };
 use sapic_core::{
     context::AnyAsyncContext,
     subscription::{Event, EventEmitter, EventMarker},
 };
+use serde_json::Value as JsonValue;
 use std::{
+    collections::HashMap,
     path::{Path, PathBuf},
     sync::{
         Arc,
@@ -33,6 +36,7 @@ use crate::{
     dirs,
     git::GitClient,
     set_icon::SetIconService,
+    storage::{key_resource_graphql_schema, key_resource_order, key_resource_sse_events},
     vcs::{ProjectVcs, Vcs},
     worktree::Worktree,
 };
@@ -381,6 +385,107 @@ impl Project {
         Ok(())
     }
 
+    /// Returns the persisted order of the resources within their directories.
+    /// Resources whose order was never persisted are left out.
+    pub async fn resource_orders(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        ids: &[ResourceId],
+    ) -> joinerror::Result<HashMap<ResourceId, isize>> {
+        let keys = ids
+            .iter()
+            .map(|id| (key_resource_order(id), id))
+            .collect::<HashMap<_, _>>();
+        let values = self
+            .storage
+            .get_batch(
+                ctx,
+                StorageScope::Project(self.id.inner()),
+                &keys.keys().map(String::as_str).collect::<Vec<_>>(),
+            )
+            .await
+            .join_err::<()>("failed to get resource orders")?;
+
+        Ok(values
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let id = keys.get(&key)?;
+                let order = serde_json::from_value(value?).ok()?;
+                Some(((*id).clone(), order))
+            })
+            .collect())
+    }
+
+    /// Returns the schema last introspected from a GraphQL endpoint, if any.
+    pub async fn graphql_schema(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ResourceId,
+    ) -> joinerror::Result<Option<JsonValue>> {
+        self.storage
+            .get(
+                ctx,
+                StorageScope::Project(self.id.inner()),
+                &key_resource_graphql_schema(id),
+            )
+            .await
+            .join_err::<()>("failed to get graphql schema")
+    }
+
+    /// Caches the schema introspected from a GraphQL endpoint, replacing the previous one.
+    /// It's removed along with the other storage entries of the resource.
+    pub async fn put_graphql_schema(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ResourceId,
+        schema: JsonValue,
+    ) -> joinerror::Result<()> {
+        self.storage
+            .put(
+                ctx,
+                StorageScope::Project(self.id.inner()),
+                &key_resource_graphql_schema(id),
+                schema,
+            )
+            .await
+            .join_err::<()>("failed to put graphql schema")
+    }
+
+    /// Returns the server-sent events recorded in the last stream of an HTTP endpoint, if any.
+    pub async fn sse_events(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ResourceId,
+    ) -> joinerror::Result<Option<JsonValue>> {
+        self.storage
+            .get(
+                ctx,
+                StorageScope::Project(self.id.inner()),
+                &key_resource_sse_events(id),
+            )
+            .await
+            .join_err::<()>("failed to get sse events")
+    }
+
+    /// Records the server-sent events received in a stream of an HTTP endpoint, replacing the
+    /// ones of the previous stream.
+    pub async fn put_sse_events(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ResourceId,
+        events: JsonValue,
+    ) -> joinerror::Result<()> {
+        self.storage
+            .put(
+                ctx,
+                StorageScope::Project(self.id.inner()),
+                &key_resource_sse_events(id),
+                events,
+            )
+            .await
+            .join_err::<()>("failed to put sse events")
+    }
+
     pub async fn archive(&self, _ctx: &dyn AnyAsyncContext) -> joinerror::Result<()> {
         let updated = self
             .archived
@@ -16,6 +16,14 @@ pub fn key_resource_order(resource_id: &ResourceId) -> String {
     format!("{KEY_RESOURCE_PREFIX}.{resource_id}.order")
 }
 
+pub fn key_resource_graphql_schema(resource_id: &ResourceId) -> String {
+    format!("{KEY_RESOURCE_PREFIX}.{resource_id}.graphqlSchema")
+}
+
+pub fn key_resource_sse_events(resource_id: &ResourceId) -> String {
+    format!("{KEY_RESOURCE_PREFIX}.{resource_id}.sseEvents")
+}
+
 // Header
 pub fn key_resource_header(resource_id: &ResourceId, header_id: &HeaderId) -> String {
     format!("{KEY_RESOURCE_PREFIX}.{resource_id}.header.{header_id}")
@@ -1,4 +1,8 @@
+mod auth;
+mod cases;
+mod component;
 pub mod entry;
+mod schema;
 
 use anyhow::anyhow;
 use hcl::ser::LabeledBlock;
@@ -9,11 +13,11 @@ use json_patch::{
 };
 use moss_app_delegate::{AppDelegate, broadcast::ToLocation};
 use moss_applib::AppRuntime;
-use moss_bindingutils::primitives::{ChangeJsonValue, ChangeString};
+use moss_bindingutils::primitives::{ChangeJsonValue, ChangeString, ChangeUsize};
 use moss_common::{continue_if_err, continue_if_none};
 use moss_edit::json::EditOptions;
 use moss_fs::{CreateOptions, FileSystem, RemoveOptions, desanitize_path, utils::SanitizedPath};
-use moss_hcl::{HclResultExt, hcl_to_json, json_to_hcl};
+use moss_hcl::{Block, HclResultExt, hcl_to_json, json_to_hcl};
 use moss_logging::session;
 use moss_storage2::{KvStorage, models::primitives::StorageScope};
 use moss_text::sanitized::{desanitize, sanitize};
@@ -22,7 +26,7 @@ use sapic_base::{
     language::i18n::NO_TRANSLATE_KEY,
     localize,
     project::types::primitives::ProjectId,
-    resource::types::primitives::{ResourceId, ResourceKind, ResourceProtocol},
+    resource::types::primitives::{ResourceClass, ResourceId, ResourceKind, ResourceProtocol},
 };
 use sapic_core::context::AnyAsyncContext;
 use serde_json::{Value as JsonValue, json};
@@ -42,16 +46,28 @@ use crate::{
     dirs,
     dirs::RESOURCES_DIR,
     errors::{ErrorAlreadyExists, ErrorInvalidInput, ErrorNotFound},
+    json_schema,
     models::{
         operations::DescribeResourceOutput,
-        primitives::{FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId},
+        primitives::{
+            FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId,
+            WebSocketMessageId,
+        },
         types::{
-            BodyInfo, FormDataParamInfo, HeaderInfo, PathParamInfo, QueryParamInfo,
-            UpdateBodyParams, UrlencodedParamInfo,
+            BodyInfo, ChangeAuth, ChangeBodySchema, FormDataParamInfo, HeaderInfo, PathParamInfo,
+            QueryParamInfo, UpdateBodyParams, UrlencodedParamInfo,
+            amqp::ChangeAmqp,
+            graphql::{GraphqlInfo, UpdateGraphqlParams},
+            grpc::{GrpcInfo, UpdateGrpcParams},
             http::{
                 AddHeaderParams, AddPathParamParams, AddQueryParamParams, UpdateHeaderParams,
                 UpdatePathParamParams, UpdateQueryParamParams,
             },
+            mqtt::ChangeMqtt,
+            sse::ChangeSse,
+            websocket::{
+                UpdateWebSocketParams, WebSocketInfo, WebSocketMessageInfo, WebSocketMessagePayload,
+            },
         },
     },
     storage::{
@@ -65,9 +81,10 @@ use crate::{
         Entry, EntryMetadata, ScannedEntry,
         edit::EntryEditing,
         model::{
-            BodyKind, BodySpec, EntryModel, FormDataParamSpec, FormDataParamSpecOptions,
-            HeaderParamSpec, HeaderParamSpecOptions, PathParamSpec, PathParamSpecOptions,
-            QueryParamSpec, QueryParamSpecOptions, UrlencodedParamSpec, UrlencodedParamSpecOptions,
+            AmqpSpec, AuthSpec, BodyKind, BodySpec, EntryModel, FormDataParamSpec,
+            FormDataParamSpecOptions, HeaderParamSpec, HeaderParamSpecOptions, MqttSpec,
+            PathParamSpec, PathParamSpecOptions, QueryParamSpec, QueryParamSpecOptions, SseSpec,
+            UrlencodedParamSpec, UrlencodedParamSpecOptions, WebSocketMessageSpec, WebSocketSpec,
         },
     },
 };
@@ -97,6 +114,7 @@ struct ScanJob {
 
 pub struct ModifyParams {
     pub name: Option<String>,
+    pub description: Option<ChangeString>,
     pub path: Option<PathBuf>,
 
     pub protocol: Option<ResourceProtocol>,
@@ -115,6 +133,26 @@ pub struct ModifyParams {
     pub query_params_to_remove: Vec<QueryParamId>,
 
     pub body: Option<UpdateBodyParams>,
+    pub body_schema: Option<ChangeBodySchema>,
+
+    pub components_to_add: Vec<ResourceId>,
+    pub components_to_remove: Vec<ResourceId>,
+
+    pub auth: Option<ChangeAuth>,
+
+    pub schema: Option<JsonValue>,
+
+    pub websocket: Option<UpdateWebSocketParams>,
+
+    pub graphql: Option<UpdateGraphqlParams>,
+
+    pub grpc: Option<UpdateGrpcParams>,
+
+    pub sse: Option<ChangeSse>,
+
+    pub amqp: Option<ChangeAmqp>,
+
+    pub mqtt: Option<ChangeMqtt>,
 }
 
 #[derive(Default)]
@@ -168,6 +206,8 @@ impl Worktree {
         id: &ResourceId,
     ) -> joinerror::Result<()> {
         let mut state_lock = self.state.write().await;
+        self.check_not_referenced(&state_lock, id)?;
+
         let entry = state_lock
             .entries
             .remove(&id)
@@ -443,6 +483,7 @@ impl Worktree {
                 url: model.url(),
                 metadata: EntryMetadata {
                     body_kind: model.body_kind(),
+                    components: model.components(),
                 },
             },
         );
@@ -509,7 +550,10 @@ impl Worktree {
                 class: model.metadata.class.clone(),
                 protocol: None,
                 url: None,
-                metadata: EntryMetadata { body_kind: None },
+                metadata: EntryMetadata {
+                    body_kind: None,
+                    components: vec![],
+                },
             },
         );
 
@@ -543,9 +587,10 @@ impl Worktree {
         Ok(())
     }
 
-    pub async fn update_dir_entry(
+    pub async fn update_dir_entry<R: AppRuntime>(
         &self,
         ctx: &dyn AnyAsyncContext,
+        app_delegate: &AppDelegate<R>,
         id: &ResourceId,
         params: ModifyParams,
     ) -> joinerror::Result<Arc<Path>> {
@@ -556,7 +601,7 @@ impl Worktree {
             .get_mut(&id)
             .ok_or_join_err_with::<ErrorNotFound>(|| format!("entry {} not found", id))?;
 
-        if let Some(new_parent) = params.path {
+        if let Some(new_parent) = &params.path {
             if !new_parent.is_root() {
                 // For now, we can only move entries into a directory entry
                 // Check if the destination path has dir config file
@@ -582,9 +627,9 @@ impl Worktree {
                 .await?;
         }
 
-        if let Some(name) = params.name {
+        if let Some(name) = &params.name {
             let old_path = entry.path_rx.borrow().clone();
-            let new_path = rename_path(&old_path, &name);
+            let new_path = rename_path(&old_path, name);
 
             entry
                 .edit
@@ -597,7 +642,9 @@ impl Worktree {
                 .await?;
         }
 
-        // TODO: patch the dir entry
+        // Dir configs share the item model, so only the headers and params are ever patched here
+        self.patch_item_entry(ctx, app_delegate, entry, &params)
+            .await?;
 
         let path = entry.path_rx.borrow().clone();
 
@@ -612,6 +659,13 @@ impl Worktree {
         params: ModifyParams,
     ) -> joinerror::Result<Arc<Path>> {
         let mut state_lock = self.state.write().await;
+        if let Some(ChangeBodySchema::Update(schema_id)) = &params.body_schema {
+            self.schema_item_path(&state_lock, schema_id)?;
+        }
+        for component_id in &params.components_to_add {
+            self.component_item_path(&state_lock, component_id)?;
+        }
+
         let entry = state_lock
             .entries
             .get_mut(&id)
@@ -677,10 +731,11 @@ impl Worktree {
             .entries
             .get(&id)
             .ok_or_join_err_with::<ErrorNotFound>(|| format!("entry {} not found", id))?;
+        let entry_rel_path = entry.path_rx.borrow().clone();
         let entry_path = self
             .abs_path
             .join(RESOURCES_DIR)
-            .join(entry.path_rx.borrow().as_ref());
+            .join(entry_rel_path.as_ref());
         let dir_config_path = entry_path.join(constants::DIR_CONFIG_FILENAME);
         let item_config_path = entry_path.join(constants::ITEM_CONFIG_FILENAME);
 
@@ -689,175 +744,288 @@ impl Worktree {
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_else(|| entry_path.to_string_lossy().to_string());
 
-        if dir_config_path.exists() {
+        let mut model: EntryModel = if dir_config_path.exists() {
             let mut rdr = self.fs.open_file(ctx, &dir_config_path).await?;
-            let model: EntryModel =
-                hcl::from_reader(&mut rdr).join_err::<()>("failed to parse dir configuration")?;
+            hcl::from_reader(&mut rdr).join_err::<()>("failed to parse dir configuration")?
+        } else if item_config_path.exists() {
+            let mut rdr = self.fs.open_file(ctx, &item_config_path).await?;
+            hcl::from_reader(&mut rdr).join_err::<()>("failed to parse item configuration")?
+        } else {
+            return Err(Error::new::<()>("cannot find entry config"));
+        };
+        let description = model.description.take();
 
+        let entry_keys = self.entry_keys(ctx, id).await;
+        let components = model.components();
+        let (component_params, component_body) = self
+            .describe_components(ctx, app_delegate, &state_lock, &components)
+            .await?;
+
+        let mut params = self
+            .describe_inherited_params(ctx, app_delegate, &entry_rel_path)
+            .await?;
+        params.merge(component_params);
+        params.merge(describe_params(app_delegate, id, &mut model, &entry_keys));
+
+        let auth = self
+            .describe_auth(
+                ctx,
+                &entry_rel_path,
+                model.auth.take().map(Block::into_inner),
+            )
+            .await?;
+
+        if dir_config_path.exists() {
             return Ok(DescribeResourceOutput {
                 name: desanitize(&name),
+                description,
                 class: model.class(),
                 kind: ResourceKind::Dir,
                 protocol: None,
                 url: None,
-                headers: vec![],
-                path_params: vec![],
-                query_params: vec![],
+                headers: params.headers,
+                path_params: params.path_params,
+                query_params: params.query_params,
                 body: None,
+                body_component: None,
+                body_schema: None,
+                components,
+                auth,
+                schema: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             });
-        } else if item_config_path.exists() {
-            let entry_keys = self
-                .storage
-                .get_batch_by_prefix(
-                    ctx,
-                    StorageScope::Project(self.project_id.inner()),
-                    &key_resource(&id),
-                )
-                .await
-                .unwrap_or_else(|e| {
-                    session::error!(format!("failed to get entry cache: {}", e));
-                    Vec::new()
-                })
-                .into_iter()
-                .collect::<HashMap<_, _>>();
+        }
 
-            let mut rdr = self.fs.open_file(ctx, &item_config_path).await?;
-            let model: EntryModel =
-                hcl::from_reader(&mut rdr).join_err::<()>("failed to parse item configuration")?;
-            let class = model.class();
-            let protocol = model.protocol();
-            let url = model.url.map(|url| url.raw.clone());
-
-            let mut header_infos = Vec::new();
-            let mut path_param_infos = Vec::new();
-            let mut query_param_infos = Vec::new();
-
-            if let Some(header_block) = model.headers {
-                for (header_id, header_spec) in header_block.into_inner() {
-                    let value = match hcl_to_json(&header_spec.value) {
-                        Ok(value) => value,
-                        Err(err) => {
-                            session::error!(format!(
-                                "failed to convert value expression `{}`, {}",
-                                &header_spec.value,
-                                err.to_string()
-                            ));
-                            let _ = app_delegate.emit_oneshot(ToLocation::Toast {
-                                activity_id: "expression_conversion_error",
-                                title: localize!(
-                                    "workbench.activity.failed_to_convert_value_expression",
-                                    "Failed to convert value expression"
-                                ),
-                                detail: Some(localize!(NO_TRANSLATE_KEY, err)),
-                            });
-                            JsonValue::Null
-                        }
-                    };
-                    header_infos.push(HeaderInfo {
-                        id: header_id.clone(),
-                        name: header_spec.name,
-                        value,
-                        description: header_spec.description,
-                        disabled: header_spec.options.disabled,
-                        propagate: header_spec.options.propagate,
-                        order: entry_keys
-                            .get(&key_resource_header_order(id, &header_id))
-                            .and_then(|value| serde_json::from_value(value.clone()).ok()),
-                    })
-                }
+        let class = model.class();
+        let protocol = model.protocol();
+        let url = model.url();
+        let body_schema = model.body_schema();
+        let websocket = model
+            .websocket
+            .take()
+            .map(|websocket| describe_websocket(websocket.into_inner()));
+        let graphql = model.graphql.take().map(|graphql| {
+            let spec = graphql.into_inner();
+            GraphqlInfo {
+                query: spec.query,
+                operation_name: spec.operation_name,
+                variables: spec.variables,
             }
+        });
+        let sse = model.sse.take().map(|sse| sse.into_inner().into());
+        let amqp = model.amqp.take().map(|amqp| amqp.into_inner().into());
+        let mqtt = model.mqtt.take().map(|mqtt| mqtt.into_inner().into());
+        let grpc = model.grpc.take().map(|grpc| {
+            let spec = grpc.into_inner();
+            GrpcInfo {
+                proto_files: spec.proto_files,
+                import_paths: spec.import_paths,
+                service: spec.service,
+                method: spec.method,
+                messages: spec.messages,
+                timeout_ms: spec.timeout_ms,
+            }
+        });
 
-            if let Some(path_param_block) = model.path_params {
-                for (path_param_id, path_param_spec) in path_param_block.into_inner() {
-                    let value = match hcl_to_json(&path_param_spec.value) {
-                        Ok(value) => value,
-                        Err(err) => {
-                            session::error!(format!(
-                                "failed to convert value expression `{}`, {}",
-                                &path_param_spec.value,
-                                err.to_string()
-                            ));
-                            let _ = app_delegate.emit_oneshot(ToLocation::Toast {
-                                activity_id: "expression_conversion_error",
-                                title: localize!(
-                                    "workbench.activity.failed_to_convert_value_expression",
-                                    "Failed to convert value expression"
-                                ),
-                                detail: Some(localize!(NO_TRANSLATE_KEY, err)),
-                            });
-                            JsonValue::Null
-                        }
-                    };
-
-                    path_param_infos.push(PathParamInfo {
-                        id: path_param_id.clone(),
-                        name: path_param_spec.name,
-                        value,
-                        description: path_param_spec.description,
-                        disabled: path_param_spec.options.disabled,
-                        propagate: path_param_spec.options.propagate,
-                        order: entry_keys
-                            .get(&key_resource_path_param_order(id, &path_param_id))
-                            .and_then(|value| serde_json::from_value(value.clone()).ok()),
-                    })
-                }
+        let body_info = if let Some(body) = model.body.take() {
+            describe_body(app_delegate, id, body, &entry_keys).await
+        } else {
+            None
+        };
+        let (body_info, body_component) = match (body_info, component_body) {
+            (None, Some((component_id, component_body))) => {
+                (Some(component_body), Some(component_id))
             }
+            (body_info, _) => (body_info, None),
+        };
+        let body_info = body_info.map(|body| params.merge_body(body));
 
-            if let Some(query_param_block) = model.query_params {
-                for (query_param_id, query_param_spec) in query_param_block.into_inner() {
-                    let value = match hcl_to_json(&query_param_spec.value) {
-                        Ok(value) => value,
-                        Err(err) => {
-                            session::error!(format!(
-                                "failed to convert value expression `{}`, {}",
-                                &query_param_spec.value,
-                                err.to_string()
-                            ));
-                            let _ = app_delegate.emit_oneshot(ToLocation::Toast {
-                                activity_id: "expression_conversion_error",
-                                title: localize!(
-                                    "workbench.activity.failed_to_convert_value_expression",
-                                    "Failed to convert value expression"
-                                ),
-                                detail: Some(localize!(NO_TRANSLATE_KEY, err)),
-                            });
-                            JsonValue::Null
-                        }
-                    };
+        let schema = if class == ResourceClass::Schema {
+            self.schema_document(ctx, &entry_path, model).await?
+        } else {
+            None
+        };
 
-                    query_param_infos.push(QueryParamInfo {
-                        id: query_param_id.clone(),
-                        name: query_param_spec.name,
-                        value,
-                        description: query_param_spec.description,
-                        disabled: query_param_spec.options.disabled,
-                        propagate: query_param_spec.options.propagate,
-                        order: entry_keys
-                            .get(&key_resource_query_param_order(id, &query_param_id))
-                            .and_then(|value| serde_json::from_value(value.clone()).ok()),
-                    })
+        Ok(DescribeResourceOutput {
+            name: desanitize(&name),
+            description,
+            class,
+            kind: ResourceKind::Item,
+            protocol,
+            url,
+            headers: params.headers,
+            path_params: params.path_params,
+            query_params: params.query_params,
+            body: body_info,
+            body_component,
+            body_schema,
+            components,
+            auth,
+            schema,
+            websocket,
+            graphql,
+            grpc,
+            sse,
+            amqp,
+            mqtt,
+        })
+    }
+
+    /// Collects the headers and params, including the body ones, marked as `propagate`
+    /// in the dirs containing the entry.
+    /// Entries of a nested dir override the ones with the same name from its parents.
+    async fn describe_inherited_params<R: AppRuntime>(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        app_delegate: &AppDelegate<R>,
+        path: &Path,
+    ) -> joinerror::Result<ParamInfos> {
+        let mut ancestors = path
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| !ancestor.is_root())
+            .collect::<Vec<_>>();
+        ancestors.reverse();
+
+        let mut inherited = ParamInfos::default();
+        for ancestor in ancestors {
+            let config_path = self.absolutize(&ancestor.join(DIR_CONFIG_FILENAME))?;
+            if !config_path.exists() {
+                continue;
+            }
+
+            let mut rdr = self.fs.open_file(ctx, &config_path).await?;
+            let mut model: EntryModel =
+                hcl::from_reader(&mut rdr).join_err::<()>("failed to parse dir configuration")?;
+            let dir_id = model.id();
+            let entry_keys = self.entry_keys(ctx, &dir_id).await;
+
+            let mut params = describe_params(app_delegate, &dir_id, &mut model, &entry_keys);
+            params.headers.retain(|h| h.propagate);
+            params.path_params.retain(|p| p.propagate);
+            params.query_params.retain(|p| p.propagate);
+
+            // A dir can keep params for several body kinds, each is merged into bodies of its kind
+            for (kind, spec) in model
+                .body
+                .take()
+                .map(LabeledBlock::into_inner)
+                .unwrap_or_default()
+            {
+                let body = LabeledBlock::new(IndexMap::from([(kind, spec)]));
+                match describe_body(app_delegate, &dir_id, body, &entry_keys).await {
+                    Some(BodyInfo::Urlencoded(body_params)) => params
+                        .urlencoded_params
+                        .extend(body_params.into_iter().filter(|p| p.propagate)),
+                    Some(BodyInfo::FormData(body_params)) => params
+                        .form_data_params
+                        .extend(body_params.into_iter().filter(|p| p.propagate)),
+                    _ => {}
                 }
             }
 
-            let body_info = if let Some(body) = model.body {
-                describe_body(app_delegate, id, body, &entry_keys).await
-            } else {
-                None
-            };
+            for header in params.headers.iter_mut() {
+                header.inherited_from = Some(dir_id.clone());
+            }
+            for path_param in params.path_params.iter_mut() {
+                path_param.inherited_from = Some(dir_id.clone());
+            }
+            for query_param in params.query_params.iter_mut() {
+                query_param.inherited_from = Some(dir_id.clone());
+            }
+            for urlencoded_param in params.urlencoded_params.iter_mut() {
+                urlencoded_param.inherited_from = Some(dir_id.clone());
+            }
+            for form_data_param in params.form_data_params.iter_mut() {
+                form_data_param.inherited_from = Some(dir_id.clone());
+            }
 
-            return Ok(DescribeResourceOutput {
-                name: desanitize(&name),
-                class,
-                kind: ResourceKind::Item,
-                protocol,
-                url,
-                headers: header_infos,
-                path_params: path_param_infos,
-                query_params: query_param_infos,
-                body: body_info,
-            });
-        } else {
-            return Err(Error::new::<()>("cannot find entry config"));
+            inherited.merge(params);
+        }
+
+        Ok(inherited)
+    }
+
+    async fn entry_keys(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ResourceId,
+    ) -> HashMap<String, JsonValue> {
+        self.storage
+            .get_batch_by_prefix(
+                ctx,
+                StorageScope::Project(self.project_id.inner()),
+                &key_resource(id),
+            )
+            .await
+            .unwrap_or_else(|e| {
+                session::error!(format!("failed to get entry cache: {}", e));
+                Vec::new()
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct ParamInfos {
+    headers: Vec<HeaderInfo>,
+    path_params: Vec<PathParamInfo>,
+    query_params: Vec<QueryParamInfo>,
+    urlencoded_params: Vec<UrlencodedParamInfo>,
+    form_data_params: Vec<FormDataParamInfo>,
+}
+
+impl ParamInfos {
+    /// Adds the entries of `other`, replacing the existing entries with the same names.
+    fn merge(&mut self, other: ParamInfos) {
+        self.headers.retain(|h| {
+            !other
+                .headers
+                .iter()
+                .any(|o| o.name.eq_ignore_ascii_case(&h.name))
+        });
+        self.headers.extend(other.headers);
+
+        self.path_params
+            .retain(|p| !other.path_params.iter().any(|o| o.name == p.name));
+        self.path_params.extend(other.path_params);
+
+        self.query_params
+            .retain(|p| !other.query_params.iter().any(|o| o.name == p.name));
+        self.query_params.extend(other.query_params);
+
+        self.urlencoded_params
+            .retain(|p| !other.urlencoded_params.iter().any(|o| o.name == p.name));
+        self.urlencoded_params.extend(other.urlencoded_params);
+
+        self.form_data_params
+            .retain(|p| !other.form_data_params.iter().any(|o| o.name == p.name));
+        self.form_data_params.extend(other.form_data_params);
+    }
+
+    /// Adds the body params to a body of the same kind, the params of the body itself
+    /// replace the ones with the same names. Bodies of other kinds are left as they are.
+    fn merge_body(&mut self, body: BodyInfo) -> BodyInfo {
+        match body {
+            BodyInfo::Urlencoded(params) => {
+                let mut merged = std::mem::take(&mut self.urlencoded_params);
+                merged.retain(|p| !params.iter().any(|o| o.name == p.name));
+                merged.extend(params);
+                BodyInfo::Urlencoded(merged)
+            }
+            BodyInfo::FormData(params) => {
+                let mut merged = std::mem::take(&mut self.form_data_params);
+                merged.retain(|p| !params.iter().any(|o| o.name == p.name));
+                merged.extend(params);
+                BodyInfo::FormData(merged)
+            }
+            body => body,
         }
     }
 }
@@ -1693,6 +1861,301 @@ impl Worktree {
             }
         }
 
+        match &params.body_schema {
+            Some(ChangeBodySchema::Update(schema_id)) => {
+                let body_kind = match &params.body {
+                    Some(UpdateBodyParams::Json(_)) => Some(BodyKind::Json),
+                    Some(_) => None,
+                    None => entry.metadata.body_kind,
+                };
+                if body_kind != Some(BodyKind::Json) {
+                    return Err(Error::new::<ErrorInvalidInput>(
+                        "a schema can only be attached to a json body",
+                    ));
+                }
+
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/body/json/schema") },
+                        value: JsonValue::String(schema_id.to_string()),
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+            Some(ChangeBodySchema::Remove) => {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/body/json/schema") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            }
+            None => {}
+        }
+
+        if !params.components_to_add.is_empty() || !params.components_to_remove.is_empty() {
+            if !params.components_to_add.is_empty() && entry.class != ResourceClass::Endpoint {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only endpoints can reference components",
+                ));
+            }
+
+            let mut components = entry.metadata.components.clone();
+            components.retain(|id| !params.components_to_remove.contains(id));
+            for id in &params.components_to_add {
+                if !components.contains(id) {
+                    components.push(id.clone());
+                }
+            }
+
+            if components.is_empty() {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/components") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            } else {
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/components") },
+                        value: serde_json::to_value(&components)?,
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+
+            on_edit_success.push(Box::new(move |entry: &mut Entry| {
+                entry.metadata.components = components;
+            }));
+        }
+
+        match &params.description {
+            Some(ChangeString::Update(description)) => {
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/description") },
+                        value: JsonValue::String(description.clone()),
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+            Some(ChangeString::Remove) => {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/description") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            }
+            None => {}
+        }
+
+        match &params.auth {
+            Some(ChangeAuth::Update(scheme)) => {
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/auth") },
+                        value: serde_json::to_value(AuthSpec::from(scheme.clone()))?,
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+            Some(ChangeAuth::Remove) => {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/auth") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            }
+            None => {}
+        }
+
+        if let Some(websocket) = &params.websocket {
+            let protocol = params.protocol.as_ref().or(entry.protocol.as_ref());
+            if protocol != Some(&ResourceProtocol::WebSocket) {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only websocket endpoints can have websocket settings",
+                ));
+            }
+
+            patch_item_websocket(&mut patches, websocket)?;
+        }
+
+        if let Some(graphql) = &params.graphql {
+            let protocol = params.protocol.as_ref().or(entry.protocol.as_ref());
+            if protocol != Some(&ResourceProtocol::Graphql) {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only graphql endpoints can have graphql settings",
+                ));
+            }
+
+            patch_item_graphql(&mut patches, graphql);
+        }
+
+        if let Some(grpc) = &params.grpc {
+            let protocol = params.protocol.as_ref().or(entry.protocol.as_ref());
+            if protocol != Some(&ResourceProtocol::Grpc) {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only grpc endpoints can have grpc settings",
+                ));
+            }
+
+            patch_item_grpc(&mut patches, grpc)?;
+        }
+
+        match &params.sse {
+            Some(ChangeSse::Update(sse)) => {
+                let protocol = params.protocol.as_ref().or(entry.protocol.as_ref());
+                if entry.class != ResourceClass::Endpoint
+                    || !protocol.is_some_and(ResourceProtocol::is_http)
+                {
+                    return Err(Error::new::<ErrorInvalidInput>(
+                        "only http endpoints can have sse settings",
+                    ));
+                }
+
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/sse") },
+                        value: serde_json::to_value(SseSpec::from(sse.clone()))?,
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+            Some(ChangeSse::Remove) => {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/sse") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            }
+            None => {}
+        }
+
+        match &params.amqp {
+            Some(ChangeAmqp::Update(amqp)) => {
+                let protocol = params.protocol.as_ref().or(entry.protocol.as_ref());
+                if protocol != Some(&ResourceProtocol::Amqp) {
+                    return Err(Error::new::<ErrorInvalidInput>(
+                        "only amqp endpoints can have amqp settings",
+                    ));
+                }
+
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/amqp") },
+                        value: serde_json::to_value(AmqpSpec::from(amqp.as_ref().clone()))?,
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+            Some(ChangeAmqp::Remove) => {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/amqp") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            }
+            None => {}
+        }
+
+        match &params.mqtt {
+            Some(ChangeMqtt::Update(mqtt)) => {
+                let protocol = params.protocol.as_ref().or(entry.protocol.as_ref());
+                if protocol != Some(&ResourceProtocol::Mqtt) {
+                    return Err(Error::new::<ErrorInvalidInput>(
+                        "only mqtt endpoints can have mqtt settings",
+                    ));
+                }
+
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/mqtt") },
+                        value: serde_json::to_value(MqttSpec::from(mqtt.as_ref().clone()))?,
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+            Some(ChangeMqtt::Remove) => {
+                patches.push((
+                    PatchOperation::Remove(RemoveOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/mqtt") },
+                    }),
+                    EditOptions {
+                        create_missing_segments: false,
+                        ignore_if_not_exists: true,
+                    },
+                ));
+            }
+            None => {}
+        }
+
+        if let Some(schema) = &params.schema {
+            if entry.class != ResourceClass::Schema {
+                return Err(Error::new::<ErrorInvalidInput>(
+                    "only schema resources can hold a schema document",
+                ));
+            }
+            json_schema::check_document(schema).map_err(Error::new::<ErrorInvalidInput>)?;
+
+            let abs_path = self.absolutize(&entry.path_rx.borrow())?;
+            if !self.write_schema_file(ctx, &abs_path, schema).await? {
+                patches.push((
+                    PatchOperation::Replace(ReplaceOperation {
+                        path: unsafe { PointerBuf::new_unchecked("/schema") },
+                        value: schema.clone(),
+                    }),
+                    EditOptions {
+                        create_missing_segments: true,
+                        ignore_if_not_exists: false,
+                    },
+                ));
+            }
+        }
+
         if patches.is_empty() {
             return Ok(());
         }
@@ -1710,6 +2173,245 @@ impl Worktree {
     }
 }
 
+fn patch_item_websocket(
+    patches: &mut Vec<(PatchOperation, EditOptions)>,
+    params: &UpdateWebSocketParams,
+) -> joinerror::Result<()> {
+    if let Some(subprotocols) = &params.subprotocols {
+        patches.push((
+            PatchOperation::Replace(ReplaceOperation {
+                path: unsafe { PointerBuf::new_unchecked("/websocket/subprotocols") },
+                value: serde_json::to_value(subprotocols)?,
+            }),
+            EditOptions {
+                create_missing_segments: true,
+                ignore_if_not_exists: false,
+            },
+        ));
+    }
+
+    for message in &params.messages_to_add {
+        let spec = WebSocketMessageSpec::new(message.name.clone(), message.payload.clone());
+        patches.push((
+            PatchOperation::Add(AddOperation {
+                path: unsafe {
+                    PointerBuf::new_unchecked(format!(
+                        "/websocket/message/{}",
+                        WebSocketMessageId::new()
+                    ))
+                },
+                value: serde_json::to_value(&spec)?,
+            }),
+            EditOptions {
+                create_missing_segments: true,
+                ignore_if_not_exists: false,
+            },
+        ));
+    }
+
+    for message in &params.messages_to_update {
+        if let Some(name) = &message.name {
+            patches.push((
+                PatchOperation::Replace(ReplaceOperation {
+                    path: unsafe {
+                        PointerBuf::new_unchecked(format!("/websocket/message/{}/name", message.id))
+                    },
+                    value: JsonValue::String(name.clone()),
+                }),
+                EditOptions {
+                    create_missing_segments: false,
+                    ignore_if_not_exists: false,
+                },
+            ));
+        }
+
+        let Some(payload) = &message.payload else {
+            continue;
+        };
+
+        // The payload is kept in the field named after its kind, the others are cleared
+        for kind in ["text", "json", "binary"] {
+            patches.push((
+                PatchOperation::Remove(RemoveOperation {
+                    path: unsafe {
+                        PointerBuf::new_unchecked(format!(
+                            "/websocket/message/{}/{}",
+                            message.id, kind
+                        ))
+                    },
+                }),
+                EditOptions {
+                    create_missing_segments: false,
+                    ignore_if_not_exists: true,
+                },
+            ));
+        }
+
+        let (kind, value) = match payload {
+            WebSocketMessagePayload::Text(text) => ("text", JsonValue::String(text.clone())),
+            WebSocketMessagePayload::Json(json) => ("json", json.clone()),
+            WebSocketMessagePayload::Binary(path) => ("binary", serde_json::to_value(path)?),
+        };
+        patches.push((
+            PatchOperation::Add(AddOperation {
+                path: unsafe {
+                    PointerBuf::new_unchecked(format!("/websocket/message/{}/{}", message.id, kind))
+                },
+                value,
+            }),
+            EditOptions {
+                create_missing_segments: false,
+                ignore_if_not_exists: false,
+            },
+        ));
+    }
+
+    for id in &params.messages_to_remove {
+        patches.push((
+            PatchOperation::Remove(RemoveOperation {
+                path: unsafe { PointerBuf::new_unchecked(format!("/websocket/message/{}", id)) },
+            }),
+            EditOptions {
+                create_missing_segments: false,
+                ignore_if_not_exists: false,
+            },
+        ));
+    }
+
+    Ok(())
+}
+
+fn patch_item_graphql(
+    patches: &mut Vec<(PatchOperation, EditOptions)>,
+    params: &UpdateGraphqlParams,
+) {
+    if let Some(query) = &params.query {
+        patches.push((
+            PatchOperation::Replace(ReplaceOperation {
+                path: unsafe { PointerBuf::new_unchecked("/graphql/query") },
+                value: JsonValue::String(query.clone()),
+            }),
+            EditOptions {
+                create_missing_segments: true,
+                ignore_if_not_exists: false,
+            },
+        ));
+    }
+
+    let changes = [
+        (
+            "/graphql/operation_name",
+            params.operation_name.as_ref().map(|change| match change {
+                ChangeString::Update(name) => Some(JsonValue::String(name.clone())),
+                ChangeString::Remove => None,
+            }),
+        ),
+        (
+            "/graphql/variables",
+            params.variables.as_ref().map(|change| match change {
+                ChangeJsonValue::Update(variables) => Some(variables.clone()),
+                ChangeJsonValue::Remove => None,
+            }),
+        ),
+    ];
+    for (path, change) in changes {
+        match change {
+            Some(Some(value)) => patches.push((
+                PatchOperation::Replace(ReplaceOperation {
+                    path: unsafe { PointerBuf::new_unchecked(path) },
+                    value,
+                }),
+                EditOptions {
+                    create_missing_segments: true,
+                    ignore_if_not_exists: false,
+                },
+            )),
+            Some(None) => patches.push((
+                PatchOperation::Remove(RemoveOperation {
+                    path: unsafe { PointerBuf::new_unchecked(path) },
+                }),
+                EditOptions {
+                    create_missing_segments: false,
+                    ignore_if_not_exists: true,
+                },
+            )),
+            None => {}
+        }
+    }
+}
+
+fn patch_item_grpc(
+    patches: &mut Vec<(PatchOperation, EditOptions)>,
+    params: &UpdateGrpcParams,
+) -> joinerror::Result<()> {
+    let replacements = [
+        (
+            "/grpc/proto_files",
+            params
+                .proto_files
+                .as_ref()
+                .map(serde_json::to_value)
+                .transpose()?,
+        ),
+        (
+            "/grpc/import_paths",
+            params
+                .import_paths
+                .as_ref()
+                .map(serde_json::to_value)
+                .transpose()?,
+        ),
+        (
+            "/grpc/service",
+            params.service.clone().map(JsonValue::String),
+        ),
+        ("/grpc/method", params.method.clone().map(JsonValue::String)),
+        (
+            "/grpc/messages",
+            params.messages.clone().map(JsonValue::Array),
+        ),
+    ];
+    for (path, value) in replacements {
+        if let Some(value) = value {
+            patches.push((
+                PatchOperation::Replace(ReplaceOperation {
+                    path: unsafe { PointerBuf::new_unchecked(path) },
+                    value,
+                }),
+                EditOptions {
+                    create_missing_segments: true,
+                    ignore_if_not_exists: false,
+                },
+            ));
+        }
+    }
+
+    match &params.timeout_ms {
+        Some(ChangeUsize::Update(timeout_ms)) => patches.push((
+            PatchOperation::Replace(ReplaceOperation {
+                path: unsafe { PointerBuf::new_unchecked("/grpc/timeout_ms") },
+                value: JsonValue::from(*timeout_ms),
+            }),
+            EditOptions {
+                create_missing_segments: true,
+                ignore_if_not_exists: false,
+            },
+        )),
+        Some(ChangeUsize::Remove) => patches.push((
+            PatchOperation::Remove(RemoveOperation {
+                path: unsafe { PointerBuf::new_unchecked("/grpc/timeout_ms") },
+            }),
+            EditOptions {
+                create_missing_segments: false,
+                ignore_if_not_exists: true,
+            },
+        )),
+        None => {}
+    }
+
+    Ok(())
+}
+
 async fn patch_item_body<R: AppRuntime>(
     worktree: &Worktree,
     ctx: &dyn AnyAsyncContext,
@@ -2434,7 +3136,10 @@ async fn process_entry(
                 class: model.class(),
                 protocol: None,
                 url: None,
-                metadata: EntryMetadata { body_kind: None },
+                metadata: EntryMetadata {
+                    body_kind: None,
+                    components: vec![],
+                },
             },
             desc,
         )));
@@ -2469,6 +3174,7 @@ async fn process_entry(
                 url: model.url(),
                 metadata: EntryMetadata {
                     body_kind: model.body_kind(),
+                    components: model.components(),
                 },
             },
             desc,
@@ -2488,6 +3194,160 @@ async fn process_file(
     Ok(None)
 }
 
+/// Describes the headers and params defined in the configuration of the resource itself.
+fn describe_params<R: AppRuntime>(
+    app_delegate: &AppDelegate<R>,
+    id: &ResourceId,
+    model: &mut EntryModel,
+    entry_keys: &HashMap<String, JsonValue>,
+) -> ParamInfos {
+    let mut header_infos = Vec::new();
+    let mut path_param_infos = Vec::new();
+    let mut query_param_infos = Vec::new();
+
+    if let Some(header_block) = model.headers.take() {
+        for (header_id, header_spec) in header_block.into_inner() {
+            let value = match hcl_to_json(&header_spec.value) {
+                Ok(value) => value,
+                Err(err) => {
+                    session::error!(format!(
+                        "failed to convert value expression `{}`, {}",
+                        &header_spec.value,
+                        err.to_string()
+                    ));
+                    let _ = app_delegate.emit_oneshot(ToLocation::Toast {
+                        activity_id: "expression_conversion_error",
+                        title: localize!(
+                            "workbench.activity.failed_to_convert_value_expression",
+                            "Failed to convert value expression"
+                        ),
+                        detail: Some(localize!(NO_TRANSLATE_KEY, err)),
+                    });
+                    JsonValue::Null
+                }
+            };
+            header_infos.push(HeaderInfo {
+                id: header_id.clone(),
+                name: header_spec.name,
+                value,
+                description: header_spec.description,
+                disabled: header_spec.options.disabled,
+                propagate: header_spec.options.propagate,
+                order: entry_keys
+                    .get(&key_resource_header_order(id, &header_id))
+                    .and_then(|value| serde_json::from_value(value.clone()).ok()),
+                inherited_from: None,
+                component: None,
+            })
+        }
+    }
+
+    if let Some(path_param_block) = model.path_params.take() {
+        for (path_param_id, path_param_spec) in path_param_block.into_inner() {
+            let value = match hcl_to_json(&path_param_spec.value) {
+                Ok(value) => value,
+                Err(err) => {
+                    session::error!(format!(
+                        "failed to convert value expression `{}`, {}",
+                        &path_param_spec.value,
+                        err.to_string()
+                    ));
+                    let _ = app_delegate.emit_oneshot(ToLocation::Toast {
+                        activity_id: "expression_conversion_error",
+                        title: localize!(
+                            "workbench.activity.failed_to_convert_value_expression",
+                            "Failed to convert value expression"
+                        ),
+                        detail: Some(localize!(NO_TRANSLATE_KEY, err)),
+                    });
+                    JsonValue::Null
+                }
+            };
+
+            path_param_infos.push(PathParamInfo {
+                id: path_param_id.clone(),
+                name: path_param_spec.name,
+                value,
+                description: path_param_spec.description,
+                disabled: path_param_spec.options.disabled,
+                propagate: path_param_spec.options.propagate,
+                order: entry_keys
+                    .get(&key_resource_path_param_order(id, &path_param_id))
+                    .and_then(|value| serde_json::from_value(value.clone()).ok()),
+                inherited_from: None,
+                component: None,
+            })
+        }
+    }
+
+    if let Some(query_param_block) = model.query_params.take() {
+        for (query_param_id, query_param_spec) in query_param_block.into_inner() {
+            let value = match hcl_to_json(&query_param_spec.value) {
+                Ok(value) => value,
+                Err(err) => {
+                    session::error!(format!(
+                        "failed to convert value expression `{}`, {}",
+                        &query_param_spec.value,
+                        err.to_string()
+                    ));
+                    let _ = app_delegate.emit_oneshot(ToLocation::Toast {
+                        activity_id: "expression_conversion_error",
+                        title: localize!(
+                            "workbench.activity.failed_to_convert_value_expression",
+                            "Failed to convert value expression"
+                        ),
+                        detail: Some(localize!(NO_TRANSLATE_KEY, err)),
+                    });
+                    JsonValue::Null
+                }
+            };
+
+            query_param_infos.push(QueryParamInfo {
+                id: query_param_id.clone(),
+                name: query_param_spec.name,
+                value,
+                description: query_param_spec.description,
+                disabled: query_param_spec.options.disabled,
+                propagate: query_param_spec.options.propagate,
+                order: entry_keys
+                    .get(&key_resource_query_param_order(id, &query_param_id))
+                    .and_then(|value| serde_json::from_value(value.clone()).ok()),
+                inherited_from: None,
+                component: None,
+            })
+        }
+    }
+
+    ParamInfos {
+        headers: header_infos,
+        path_params: path_param_infos,
+        query_params: query_param_infos,
+        ..Default::default()
+    }
+}
+
+/// Describes the saved messages in the order they are kept, skipping the ones without a payload.
+fn describe_websocket(spec: WebSocketSpec) -> WebSocketInfo {
+    let messages = spec
+        .messages
+        .map(|messages| messages.into_inner())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(id, message)| {
+            Some(WebSocketMessageInfo {
+                payload: message.payload()?,
+                id,
+                name: message.name,
+            })
+        })
+        .collect();
+
+    WebSocketInfo {
+        subprotocols: spec.subprotocols,
+        messages,
+    }
+}
+
 async fn describe_body<R: AppRuntime>(
     app_delegate: &AppDelegate<R>,
     entry_id: &ResourceId,
@@ -2542,6 +3402,7 @@ async fn describe_body<R: AppRuntime>(
                             entry_id, &param_id,
                         ))
                         .and_then(|value| serde_json::from_value(value.clone()).ok()),
+                    inherited_from: None,
                 });
             }
             BodyInfo::Urlencoded(param_infos)
@@ -2583,6 +3444,7 @@ async fn describe_body<R: AppRuntime>(
                     order: entry_keys
                         .get(&key_resource_body_formdata_param_order(entry_id, &param_id))
                         .and_then(|value| serde_json::from_value(value.clone()).ok()),
+                    inherited_from: None,
                 });
             }
             BodyInfo::FormData(param_infos)
@@ -0,0 +1,59 @@
+use moss_hcl::HclResultExt;
+use sapic_core::context::AnyAsyncContext;
+use std::path::Path;
+
+use crate::{
+    constants::DIR_CONFIG_FILENAME,
+    models::types::AuthInfo,
+    worktree::{
+        IsRoot, Worktree,
+        entry::model::{AuthSpec, EntryModel},
+    },
+};
+
+impl Worktree {
+    /// Resolves the auth in effect for the entry at `path`. Entries without their own auth,
+    /// or set to inherit it, use the auth of the nearest dir that sets one.
+    pub(super) async fn describe_auth(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        path: &Path,
+        own: Option<AuthSpec>,
+    ) -> joinerror::Result<Option<AuthInfo>> {
+        if let Some(auth) = own
+            && auth != AuthSpec::Inherit
+        {
+            return Ok(Some(AuthInfo {
+                scheme: auth.into(),
+                inherited_from: None,
+            }));
+        }
+
+        for ancestor in path
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| !ancestor.is_root())
+        {
+            let config_path = self.absolutize(&ancestor.join(DIR_CONFIG_FILENAME))?;
+            if !config_path.exists() {
+                continue;
+            }
+
+            let mut rdr = self.fs.open_file(ctx, &config_path).await?;
+            let model: EntryModel =
+                hcl::from_reader(&mut rdr).join_err::<()>("failed to parse dir configuration")?;
+            let dir_id = model.id();
+            match model.auth.map(|auth| auth.into_inner()) {
+                None | Some(AuthSpec::Inherit) => continue,
+                Some(auth) => {
+                    return Ok(Some(AuthInfo {
+                        scheme: auth.into(),
+                        inherited_from: Some(dir_id),
+                    }));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
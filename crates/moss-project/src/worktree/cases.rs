@@ -0,0 +1,159 @@
+use hcl::ser::LabeledBlock;
+use indexmap::IndexMap;
+use joinerror::{Error, OptionExt};
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_fs::{CreateOptions, RemoveOptions};
+use moss_hcl::HclResultExt;
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId};
+use sapic_core::context::AnyAsyncContext;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    constants::{CASES_CONFIG_FILENAME, ITEM_CONFIG_FILENAME},
+    errors::{ErrorInvalidKind, ErrorNotFound},
+    models::{primitives::CaseId, types::CaseInfo},
+    worktree::{
+        Worktree, WorktreeState, describe_body, describe_params,
+        entry::model::{CaseSpec, CasesModel, EntryModel},
+    },
+};
+
+impl Worktree {
+    /// Applies `f` to the cases of the endpoint and persists the result.
+    /// Nothing is written if `f` fails.
+    pub async fn edit_cases<T>(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        resource_id: &ResourceId,
+        f: impl FnOnce(&mut IndexMap<CaseId, CaseSpec>) -> joinerror::Result<T>,
+    ) -> joinerror::Result<T> {
+        // Held until the file is written so the endpoint can't be moved or removed meanwhile
+        let state_lock = self.state.write().await;
+        let path = self.cases_config_path(&state_lock, resource_id)?;
+
+        let mut cases = self.read_cases(ctx, &path).await?;
+        let output = f(&mut cases)?;
+
+        if cases.is_empty() {
+            self.fs
+                .remove_file(
+                    ctx,
+                    &path,
+                    RemoveOptions {
+                        recursive: false,
+                        ignore_if_not_exists: true,
+                    },
+                )
+                .await?;
+
+            return Ok(output);
+        }
+
+        let model = CasesModel {
+            cases: Some(LabeledBlock::new(cases)),
+        };
+        let content =
+            hcl::to_string(&model).join_err::<()>("failed to serialize cases into hcl string")?;
+
+        self.fs
+            .create_file_with(
+                ctx,
+                &path,
+                content.as_bytes(),
+                CreateOptions {
+                    overwrite: true,
+                    ignore_if_exists: false,
+                },
+            )
+            .await?;
+
+        Ok(output)
+    }
+
+    pub async fn describe_cases<R: AppRuntime>(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        app_delegate: &AppDelegate<R>,
+        resource_id: &ResourceId,
+    ) -> joinerror::Result<Vec<CaseInfo>> {
+        let path = {
+            let state_lock = self.state.read().await;
+            self.cases_config_path(&state_lock, resource_id)?
+        };
+
+        let cases = self.read_cases(ctx, &path).await?;
+        let entry_keys = self.entry_keys(ctx, resource_id).await;
+
+        let mut infos = Vec::with_capacity(cases.len());
+        for (id, spec) in cases {
+            // Case blocks share the layout of the endpoint ones
+            let mut model = EntryModel::from((resource_id.clone(), ResourceClass::Endpoint));
+            model.headers = spec.headers;
+            model.path_params = spec.path_params;
+            model.query_params = spec.query_params;
+
+            let params = describe_params(app_delegate, resource_id, &mut model, &entry_keys);
+            let body = if let Some(body) = spec.body {
+                describe_body(app_delegate, resource_id, body, &entry_keys).await
+            } else {
+                None
+            };
+
+            infos.push(CaseInfo {
+                id,
+                name: spec.name,
+                description: spec.description,
+                headers: params.headers,
+                path_params: params.path_params,
+                query_params: params.query_params,
+                body,
+                expected_response: spec
+                    .expected_response
+                    .map(|expected| expected.into_inner().into()),
+            });
+        }
+
+        Ok(infos)
+    }
+
+    fn cases_config_path(
+        &self,
+        state: &WorktreeState,
+        resource_id: &ResourceId,
+    ) -> joinerror::Result<PathBuf> {
+        let entry = state
+            .entries
+            .get(resource_id)
+            .ok_or_join_err_with::<ErrorNotFound>(|| format!("entry {} not found", resource_id))?;
+        let abs_path = self.absolutize(&entry.path_rx.borrow())?;
+
+        if entry.class != ResourceClass::Endpoint || !abs_path.join(ITEM_CONFIG_FILENAME).exists() {
+            return Err(Error::new::<ErrorInvalidKind>(format!(
+                "cases can only be attached to endpoints, {} is not one",
+                resource_id
+            )));
+        }
+
+        Ok(abs_path.join(CASES_CONFIG_FILENAME))
+    }
+
+    async fn read_cases(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        path: &Path,
+    ) -> joinerror::Result<IndexMap<CaseId, CaseSpec>> {
+        if !path.exists() {
+            return Ok(IndexMap::new());
+        }
+
+        let mut rdr = self.fs.open_file(ctx, path).await?;
+        let model: CasesModel =
+            hcl::from_reader(&mut rdr).join_err::<()>("failed to parse cases configuration")?;
+
+        Ok(model
+            .cases
+            .map(|cases| cases.into_inner())
+            .unwrap_or_default())
+    }
+}
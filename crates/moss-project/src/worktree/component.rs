@@ -0,0 +1,144 @@
+use joinerror::Error;
+use moss_app_delegate::AppDelegate;
+use moss_applib::AppRuntime;
+use moss_logging::session;
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId};
+use sapic_core::context::AnyAsyncContext;
+use std::{collections::HashSet, path::PathBuf};
+
+use crate::{
+    errors::{ErrorInvalidInput, ErrorInvalidKind},
+    models::types::BodyInfo,
+    worktree::{ParamInfos, Worktree, WorktreeState, describe_body, describe_params},
+};
+
+impl Worktree {
+    /// Fails unless every id refers to a component item that endpoints can reference.
+    pub async fn check_component_resources(&self, ids: &[ResourceId]) -> joinerror::Result<()> {
+        let state_lock = self.state.read().await;
+        for id in ids {
+            self.component_item_path(&state_lock, id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the resources referencing the component `id`.
+    pub async fn dependents(&self, id: &ResourceId) -> Vec<ResourceId> {
+        let state_lock = self.state.read().await;
+        state_lock
+            .entries
+            .values()
+            .filter(|entry| entry.metadata.components.contains(id))
+            .map(|entry| entry.id.clone())
+            .collect()
+    }
+
+    /// Fails if the entry, or a component inside it, is still referenced by a resource
+    /// that isn't removed along with it.
+    pub(super) fn check_not_referenced(
+        &self,
+        state: &WorktreeState,
+        id: &ResourceId,
+    ) -> joinerror::Result<()> {
+        let Some(entry) = state.entries.get(id) else {
+            return Ok(());
+        };
+        let path = entry.path_rx.borrow().clone();
+        let removed = state
+            .entries
+            .values()
+            .filter(|entry| entry.path_rx.borrow().starts_with(&path))
+            .map(|entry| &entry.id)
+            .collect::<HashSet<_>>();
+
+        for entry in state.entries.values() {
+            if removed.contains(&entry.id) {
+                continue;
+            }
+
+            if let Some(component_id) = entry
+                .metadata
+                .components
+                .iter()
+                .find(|component_id| removed.contains(component_id))
+            {
+                return Err(Error::new::<ErrorInvalidInput>(format!(
+                    "component {} is still referenced by resource {}",
+                    component_id, entry.id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Expands the headers, params and body of the components, in order of precedence.
+    /// The body is taken from the last component that defines one.
+    pub(super) async fn describe_components<R: AppRuntime>(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        app_delegate: &AppDelegate<R>,
+        state: &WorktreeState,
+        ids: &[ResourceId],
+    ) -> joinerror::Result<(ParamInfos, Option<(ResourceId, BodyInfo)>)> {
+        let mut expanded = ParamInfos::default();
+        let mut body = None;
+
+        for id in ids {
+            // A component removed outside of the app shouldn't prevent its dependents from loading
+            let abs_path = match self.component_item_path(state, id) {
+                Ok(abs_path) => abs_path,
+                Err(e) => {
+                    session::warn!(format!("failed to expand component {}: {}", id, e));
+                    continue;
+                }
+            };
+
+            let mut model = self.read_item_model(ctx, &abs_path).await?;
+            let entry_keys = self.entry_keys(ctx, id).await;
+
+            let mut params = describe_params(app_delegate, id, &mut model, &entry_keys);
+            for header in params.headers.iter_mut() {
+                header.component = Some(id.clone());
+            }
+            for path_param in params.path_params.iter_mut() {
+                path_param.component = Some(id.clone());
+            }
+            for query_param in params.query_params.iter_mut() {
+                query_param.component = Some(id.clone());
+            }
+            expanded.merge(params);
+
+            if let Some(component_body) = model.body.take()
+                && let Some(info) =
+                    describe_body(app_delegate, id, component_body, &entry_keys).await
+            {
+                body = Some((id.clone(), info));
+            }
+        }
+
+        Ok((expanded, body))
+    }
+
+    pub(super) fn component_item_path(
+        &self,
+        state: &WorktreeState,
+        id: &ResourceId,
+    ) -> joinerror::Result<PathBuf> {
+        let abs_path = self.item_path(state, id)?;
+        let is_component = state
+            .entries
+            .get(id)
+            .is_some_and(|entry| entry.class == ResourceClass::Component);
+
+        if !is_component {
+            return Err(Error::new::<ErrorInvalidKind>(format!(
+                "resource {} is not a component",
+                id
+            )));
+        }
+
+        Ok(abs_path)
+    }
+}
@@ -12,7 +12,6 @@ use crate::worktree::entry::{edit::EntryEditing, model::BodyKind};
 pub(crate) struct Entry {
     pub id: ResourceId,
     pub path_rx: watch::Receiver<Arc<Path>>,
-    #[allow(unused)]
     pub class: ResourceClass,
     pub protocol: Option<ResourceProtocol>,
     pub url: Option<String>,
@@ -24,6 +23,7 @@ pub(crate) struct Entry {
 
 pub(crate) struct EntryMetadata {
     pub body_kind: Option<BodyKind>,
+    pub components: Vec<ResourceId>,
 }
 
 #[derive(Debug)]
@@ -2,15 +2,26 @@ use hcl::Expression;
 use indexmap::IndexMap;
 use moss_hcl::{
     Block, LabeledBlock, deserialize_expression, expression,
-    heredoc::serialize_option_string_as_heredoc, serialize_expression,
+    heredoc::{serialize_option_string_as_heredoc, serialize_string_as_heredoc},
+    serialize_expression,
 };
 use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value as JsonValue;
 use std::path::PathBuf;
 
-use crate::models::primitives::{
-    FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId,
+use crate::models::{
+    primitives::{
+        CaseId, FormDataParamId, HeaderId, PathParamId, QueryParamId, UrlencodedParamId,
+        WebSocketMessageId,
+    },
+    types::{
+        ApiKeyLocation, AuthScheme, AwsSigV4Config, ExpectedResponse, OAuth2Config, OAuth2Grant,
+        amqp::{AmqpInfo, AmqpParams},
+        mqtt::{MqttInfo, MqttParams, MqttVersion},
+        sse::{SseInfo, SseParams},
+        websocket::WebSocketMessagePayload,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,10 +33,28 @@ pub struct EntryMetadataSpec {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UrlDetails {
+    #[serde(serialize_with = "serialize_protocol")]
+    #[serde(deserialize_with = "deserialize_protocol")]
     pub protocol: ResourceProtocol,
     pub raw: String,
 }
 
+/// Keeps the protocol a plain string in the configuration, custom methods included.
+fn serialize_protocol<S>(protocol: &ResourceProtocol, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&protocol.to_string())
+}
+
+fn deserialize_protocol<'de, D>(deserializer: D) -> Result<ResourceProtocol, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let protocol = String::deserialize(deserializer)?;
+    protocol.parse().map_err(serde::de::Error::custom)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeaderParamSpecOptions {
     pub disabled: bool,
@@ -132,6 +161,10 @@ pub struct BodySpec {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub json: Option<JsonValue>,
 
+    /// Schema resource the json body is validated against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<ResourceId>,
+
     // TODO: Find a way to fully support xml
     // Currently there isn't a good counterpart to serde_json::Value for xml
     // `xmltree::Element` will silently discard extra root nodes instead of raising an error
@@ -154,6 +187,7 @@ impl Default for BodySpec {
         Self {
             text: None,
             json: None,
+            schema: None,
             xml: None,
             binary: None,
             urlencoded: None,
@@ -213,6 +247,9 @@ impl<'de> Deserialize<'de> for BodyKind {
 pub struct EntryModel {
     pub metadata: Block<EntryMetadataSpec>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<Block<UrlDetails>>,
 
@@ -230,17 +267,56 @@ pub struct EntryModel {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<LabeledBlock<IndexMap<BodyKind, BodySpec>>>,
+
+    /// Component resources expanded into an endpoint, in order of precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ResourceId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Block<AuthSpec>>,
+
+    /// JSON Schema document of a schema resource, unless it's kept in an adjacent file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<JsonValue>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub websocket: Option<Block<WebSocketSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graphql: Option<Block<GraphqlSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc: Option<Block<GrpcSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sse: Option<Block<SseSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amqp: Option<Block<AmqpSpec>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mqtt: Option<Block<MqttSpec>>,
 }
 
 impl From<(ResourceId, ResourceClass)> for EntryModel {
     fn from((id, class): (ResourceId, ResourceClass)) -> Self {
         Self {
             metadata: Block::new(EntryMetadataSpec { id, class }),
+            description: None,
             url: None,
             headers: None,
             query_params: None,
             path_params: None,
             body: None,
+            components: None,
+            auth: None,
+            schema: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
         }
     }
 }
@@ -262,6 +338,17 @@ impl EntryModel {
         self.url.as_ref().map(|url| url.raw.clone())
     }
 
+    pub fn body_schema(&self) -> Option<ResourceId> {
+        self.body
+            .as_ref()
+            .and_then(|body| body.get(&BodyKind::Json))
+            .and_then(|spec| spec.schema.clone())
+    }
+
+    pub fn components(&self) -> Vec<ResourceId> {
+        self.components.clone().unwrap_or_default()
+    }
+
     pub fn body_kind(&self) -> Option<BodyKind> {
         if let Some(body) = self.body.as_ref() {
             body.iter().map(|(kind, _)| *kind).next()
@@ -271,6 +358,524 @@ impl EntryModel {
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebSocketSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subprotocols: Vec<String>,
+
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<LabeledBlock<IndexMap<WebSocketMessageId, WebSocketMessageSpec>>>,
+}
+
+/// A saved message, whose payload is kept in the field named after its kind.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebSocketMessageSpec {
+    pub name: String,
+
+    // Kept as a quoted string, since a heredoc always adds a trailing newline to the
+    // message that's sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json: Option<JsonValue>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary: Option<PathBuf>,
+}
+
+impl WebSocketMessageSpec {
+    pub fn new(name: String, payload: WebSocketMessagePayload) -> Self {
+        let mut spec = Self {
+            name,
+            text: None,
+            json: None,
+            binary: None,
+        };
+        match payload {
+            WebSocketMessagePayload::Text(text) => spec.text = Some(text),
+            WebSocketMessagePayload::Json(json) => spec.json = Some(json),
+            WebSocketMessagePayload::Binary(path) => spec.binary = Some(path),
+        }
+
+        spec
+    }
+
+    pub fn payload(&self) -> Option<WebSocketMessagePayload> {
+        if let Some(text) = &self.text {
+            Some(WebSocketMessagePayload::Text(text.clone()))
+        } else if let Some(json) = &self.json {
+            Some(WebSocketMessagePayload::Json(json.clone()))
+        } else {
+            self.binary.clone().map(WebSocketMessagePayload::Binary)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphqlSpec {
+    #[serde(default)]
+    #[serde(serialize_with = "serialize_string_as_heredoc")]
+    pub query: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<JsonValue>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GrpcSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proto_files: Vec<PathBuf>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub import_paths: Vec<PathBuf>,
+
+    #[serde(default)]
+    pub service: String,
+
+    #[serde(default)]
+    pub method: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<JsonValue>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SseSpec {
+    #[serde(default)]
+    pub reconnect: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_ms: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_reconnects: Option<usize>,
+
+    #[serde(default)]
+    pub record: bool,
+}
+
+impl From<SseParams> for SseSpec {
+    fn from(params: SseParams) -> Self {
+        Self {
+            reconnect: params.reconnect,
+            retry_ms: params.retry_ms,
+            max_reconnects: params.max_reconnects,
+            record: params.record,
+        }
+    }
+}
+
+impl From<SseSpec> for SseInfo {
+    fn from(spec: SseSpec) -> Self {
+        Self {
+            reconnect: spec.reconnect,
+            retry_ms: spec.retry_ms,
+            max_reconnects: spec.max_reconnects,
+            record: spec.record,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AmqpSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub exchange: String,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub routing_key: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<String>,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub payload: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_ms: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+
+    #[serde(default)]
+    pub persistent: bool,
+}
+
+impl From<AmqpParams> for AmqpSpec {
+    fn from(params: AmqpParams) -> Self {
+        Self {
+            username: params.username,
+            password: params.password,
+            exchange: params.exchange,
+            routing_key: params.routing_key,
+            queue: params.queue,
+            payload: params.payload,
+            content_type: params.content_type,
+            correlation_id: params.correlation_id,
+            reply_to: params.reply_to,
+            message_id: params.message_id,
+            expiration_ms: params.expiration_ms,
+            priority: params.priority,
+            persistent: params.persistent,
+        }
+    }
+}
+
+impl From<AmqpSpec> for AmqpInfo {
+    fn from(spec: AmqpSpec) -> Self {
+        Self {
+            username: spec.username,
+            password: spec.password,
+            exchange: spec.exchange,
+            routing_key: spec.routing_key,
+            queue: spec.queue,
+            payload: spec.payload,
+            content_type: spec.content_type,
+            correlation_id: spec.correlation_id,
+            reply_to: spec.reply_to,
+            message_id: spec.message_id,
+            expiration_ms: spec.expiration_ms,
+            priority: spec.priority,
+            persistent: spec.persistent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MqttSpec {
+    #[serde(default)]
+    pub version: MqttVersion,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    #[serde(default)]
+    pub topic: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic_filter: Option<String>,
+
+    #[serde(default)]
+    pub qos: u8,
+
+    #[serde(default)]
+    pub retain: bool,
+
+    #[serde(default)]
+    pub clean_session: bool,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub payload: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_topic: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_data: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_expiry_secs: Option<u32>,
+}
+
+impl From<MqttParams> for MqttSpec {
+    fn from(params: MqttParams) -> Self {
+        Self {
+            version: params.version,
+            client_id: params.client_id,
+            username: params.username,
+            password: params.password,
+            topic: params.topic,
+            topic_filter: params.topic_filter,
+            qos: params.qos,
+            retain: params.retain,
+            clean_session: params.clean_session,
+            payload: params.payload,
+            content_type: params.content_type,
+            response_topic: params.response_topic,
+            correlation_data: params.correlation_data,
+            message_expiry_secs: params.message_expiry_secs,
+        }
+    }
+}
+
+impl From<MqttSpec> for MqttInfo {
+    fn from(spec: MqttSpec) -> Self {
+        Self {
+            version: spec.version,
+            client_id: spec.client_id,
+            username: spec.username,
+            password: spec.password,
+            topic: spec.topic,
+            topic_filter: spec.topic_filter,
+            qos: spec.qos,
+            retain: spec.retain,
+            clean_session: spec.clean_session,
+            payload: spec.payload,
+            content_type: spec.content_type,
+            response_topic: spec.response_topic,
+            correlation_data: spec.correlation_data,
+            message_expiry_secs: spec.message_expiry_secs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum AuthSpec {
+    None,
+    Inherit,
+    Basic {
+        username: String,
+        password: String,
+    },
+    Bearer {
+        token: String,
+    },
+    ApiKey {
+        key: String,
+        value: String,
+        #[serde(rename = "in")]
+        location: ApiKeyLocation,
+    },
+    Digest {
+        username: String,
+        password: String,
+    },
+    #[serde(rename = "oauth2")]
+    OAuth2(OAuth2Spec),
+    #[serde(rename = "aws-sigv4")]
+    AwsSigV4(AwsSigV4Spec),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuth2Spec {
+    pub grant: OAuth2Grant,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_url: Option<String>,
+    pub token_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_auth_url: Option<String>,
+    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_port: Option<u16>,
+}
+
+impl From<OAuth2Config> for OAuth2Spec {
+    fn from(value: OAuth2Config) -> Self {
+        Self {
+            grant: value.grant,
+            auth_url: value.auth_url,
+            token_url: value.token_url,
+            device_auth_url: value.device_auth_url,
+            client_id: value.client_id,
+            client_secret: value.client_secret,
+            scopes: value.scopes,
+            refresh_token: value.refresh_token,
+            callback_port: value.callback_port,
+        }
+    }
+}
+
+impl From<OAuth2Spec> for OAuth2Config {
+    fn from(value: OAuth2Spec) -> Self {
+        Self {
+            grant: value.grant,
+            auth_url: value.auth_url,
+            token_url: value.token_url,
+            device_auth_url: value.device_auth_url,
+            client_id: value.client_id,
+            client_secret: value.client_secret,
+            scopes: value.scopes,
+            refresh_token: value.refresh_token,
+            callback_port: value.callback_port,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwsSigV4Spec {
+    pub access_key: String,
+    pub secret_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_token: Option<String>,
+    pub region: String,
+    pub service: String,
+}
+
+impl From<AwsSigV4Config> for AwsSigV4Spec {
+    fn from(value: AwsSigV4Config) -> Self {
+        Self {
+            access_key: value.access_key,
+            secret_key: value.secret_key,
+            session_token: value.session_token,
+            region: value.region,
+            service: value.service,
+        }
+    }
+}
+
+impl From<AwsSigV4Spec> for AwsSigV4Config {
+    fn from(value: AwsSigV4Spec) -> Self {
+        Self {
+            access_key: value.access_key,
+            secret_key: value.secret_key,
+            session_token: value.session_token,
+            region: value.region,
+            service: value.service,
+        }
+    }
+}
+
+impl From<AuthScheme> for AuthSpec {
+    fn from(value: AuthScheme) -> Self {
+        match value {
+            AuthScheme::None => AuthSpec::None,
+            AuthScheme::Inherit => AuthSpec::Inherit,
+            AuthScheme::Basic { username, password } => AuthSpec::Basic { username, password },
+            AuthScheme::Bearer { token } => AuthSpec::Bearer { token },
+            AuthScheme::ApiKey {
+                key,
+                value,
+                location,
+            } => AuthSpec::ApiKey {
+                key,
+                value,
+                location,
+            },
+            AuthScheme::Digest { username, password } => AuthSpec::Digest { username, password },
+            AuthScheme::OAuth2(config) => AuthSpec::OAuth2(config.into()),
+            AuthScheme::AwsSigV4(config) => AuthSpec::AwsSigV4(config.into()),
+        }
+    }
+}
+
+impl From<AuthSpec> for AuthScheme {
+    fn from(value: AuthSpec) -> Self {
+        match value {
+            AuthSpec::None => AuthScheme::None,
+            AuthSpec::Inherit => AuthScheme::Inherit,
+            AuthSpec::Basic { username, password } => AuthScheme::Basic { username, password },
+            AuthSpec::Bearer { token } => AuthScheme::Bearer { token },
+            AuthSpec::ApiKey {
+                key,
+                value,
+                location,
+            } => AuthScheme::ApiKey {
+                key,
+                value,
+                location,
+            },
+            AuthSpec::Digest { username, password } => AuthScheme::Digest { username, password },
+            AuthSpec::OAuth2(spec) => AuthScheme::OAuth2(spec.into()),
+            AuthSpec::AwsSigV4(spec) => AuthScheme::AwsSigV4(spec.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectedResponseSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<IndexMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<JsonValue>,
+}
+
+impl From<ExpectedResponse> for ExpectedResponseSpec {
+    fn from(value: ExpectedResponse) -> Self {
+        Self {
+            status: value.status,
+            headers: value.headers,
+            body: value.body,
+        }
+    }
+}
+
+impl From<ExpectedResponseSpec> for ExpectedResponse {
+    fn from(value: ExpectedResponseSpec) -> Self {
+        Self {
+            status: value.status,
+            headers: value.headers,
+            body: value.body,
+        }
+    }
+}
+
+/// A named set of overrides applied on top of the endpoint it belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaseSpec {
+    pub name: String,
+    pub description: Option<String>,
+
+    #[serde(rename = "header")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<LabeledBlock<IndexMap<HeaderId, HeaderParamSpec>>>,
+
+    #[serde(rename = "path_param")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_params: Option<LabeledBlock<IndexMap<PathParamId, PathParamSpec>>>,
+
+    #[serde(rename = "query_param")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_params: Option<LabeledBlock<IndexMap<QueryParamId, QueryParamSpec>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<LabeledBlock<IndexMap<BodyKind, BodySpec>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_response: Option<Block<ExpectedResponseSpec>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CasesModel {
+    #[serde(rename = "case")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cases: Option<LabeledBlock<IndexMap<CaseId, CaseSpec>>>,
+}
+
 #[cfg(test)]
 mod tests {
     use hcl::{Expression as HclExpression, ser::LabeledBlock};
@@ -285,6 +890,7 @@ mod tests {
                 id: ResourceId::new(),
                 class: ResourceClass::Endpoint,
             }),
+            description: None,
             url: Some(Block::new(UrlDetails {
                 protocol: ResourceProtocol::Get,
                 raw: "https://example.com".to_string(),
@@ -381,6 +987,19 @@ mod tests {
                     ..Default::default()
                 }
             })),
+            components: Some(vec![ResourceId::new()]),
+            auth: Some(Block::new(AuthSpec::ApiKey {
+                key: "X-Api-Key".to_string(),
+                value: "{{api_key}}".to_string(),
+                location: ApiKeyLocation::Header,
+            })),
+            schema: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
         };
 
         let str = hcl::to_string(&model).unwrap();
@@ -402,4 +1021,360 @@ mod tests {
         let model = hcl::from_str::<EntryModel>(&model_string).unwrap();
         dbg!(&model);
     }
+
+    #[test]
+    fn test_cases_roundtrip() {
+        let header_id = HeaderId::new();
+        let case_id = CaseId::new();
+        let model = CasesModel {
+            cases: Some(LabeledBlock::new(indexmap! {
+                case_id.clone() => CaseSpec {
+                    name: "Second page".to_string(),
+                    description: None,
+                    headers: Some(LabeledBlock::new(indexmap! {
+                        header_id.clone() => HeaderParamSpec {
+                            name: "Accept".to_string(),
+                            value: HclExpression::String("application/xml".to_string()),
+                            description: None,
+                            options: HeaderParamSpecOptions {
+                                disabled: false,
+                                propagate: false,
+                            },
+                        }
+                    })),
+                    path_params: None,
+                    query_params: None,
+                    body: Some(LabeledBlock::new(indexmap! {
+                        BodyKind::Json => BodySpec {
+                            json: Some(json!({ "page": 2 })),
+                            ..Default::default()
+                        }
+                    })),
+                    expected_response: Some(Block::new(ExpectedResponseSpec {
+                        status: Some(200),
+                        headers: Some(indexmap! {
+                            "Content-Type".to_string() => "application/xml".to_string()
+                        }),
+                        body: None,
+                    })),
+                }
+            })),
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<CasesModel>(&str).unwrap();
+
+        let case = &model.cases.unwrap()[&case_id];
+        assert_eq!(case.name, "Second page");
+        assert_eq!(case.headers.as_ref().unwrap()[&header_id].name, "Accept");
+        assert_eq!(
+            case.body.as_ref().unwrap()[&BodyKind::Json].json,
+            Some(json!({ "page": 2 }))
+        );
+        let expected_response = case.expected_response.as_ref().unwrap();
+        assert_eq!(expected_response.status, Some(200));
+        assert_eq!(
+            expected_response.headers.as_ref().unwrap()["Content-Type"],
+            "application/xml"
+        );
+    }
+
+    #[test]
+    fn test_protocol_roundtrip() {
+        for protocol in [
+            ResourceProtocol::Patch,
+            ResourceProtocol::Custom("PROPFIND".to_string()),
+        ] {
+            let mut model = EntryModel::from((ResourceId::new(), ResourceClass::Endpoint));
+            model.url = Some(Block::new(UrlDetails {
+                protocol: protocol.clone(),
+                raw: "https://example.com".to_string(),
+            }));
+
+            let str = hcl::to_string(&model).unwrap();
+            assert!(str.contains(&format!("protocol = \"{}\"", protocol.to_string())));
+
+            let model = hcl::from_str::<EntryModel>(&str).unwrap();
+            assert_eq!(model.protocol(), Some(protocol));
+        }
+
+        let str = hcl::to_string(&EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::Custom("NOT A METHOD".to_string()),
+                raw: "https://example.com".to_string(),
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        })
+        .unwrap();
+        assert!(hcl::from_str::<EntryModel>(&str).is_err());
+    }
+
+    #[test]
+    fn test_auth_roundtrip() {
+        for auth in [
+            AuthSpec::None,
+            AuthSpec::Basic {
+                username: "admin".to_string(),
+                password: "{{password}}".to_string(),
+            },
+            AuthSpec::ApiKey {
+                key: "api_key".to_string(),
+                value: "{{api_key}}".to_string(),
+                location: ApiKeyLocation::Query,
+            },
+            AuthSpec::OAuth2(OAuth2Spec {
+                grant: OAuth2Grant::ClientCredentials,
+                auth_url: None,
+                token_url: "https://auth.example.com/token".to_string(),
+                device_auth_url: None,
+                client_id: "sapic".to_string(),
+                client_secret: Some("{{client_secret}}".to_string()),
+                scopes: vec!["read".to_string(), "write".to_string()],
+                refresh_token: None,
+                callback_port: None,
+            }),
+            AuthSpec::AwsSigV4(AwsSigV4Spec {
+                access_key: "{{aws_access_key}}".to_string(),
+                secret_key: "{{aws_secret_key}}".to_string(),
+                session_token: None,
+                region: "us-east-1".to_string(),
+                service: "execute-api".to_string(),
+            }),
+        ] {
+            let model = EntryModel {
+                auth: Some(Block::new(auth.clone())),
+                ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+            };
+
+            let str = hcl::to_string(&model).unwrap();
+            let model = hcl::from_str::<EntryModel>(&str).unwrap();
+            assert_eq!(model.auth.map(|auth| auth.into_inner()), Some(auth));
+        }
+    }
+
+    #[test]
+    fn test_websocket_roundtrip() {
+        let text_id = WebSocketMessageId::new();
+        let json_id = WebSocketMessageId::new();
+        let binary_id = WebSocketMessageId::new();
+        let model = EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::WebSocket,
+                raw: "wss://example.com/socket".to_string(),
+            })),
+            websocket: Some(Block::new(WebSocketSpec {
+                subprotocols: vec!["graphql-ws".to_string()],
+                messages: Some(LabeledBlock::new(indexmap! {
+                    text_id.clone() => WebSocketMessageSpec::new(
+                        "Ping".to_string(),
+                        WebSocketMessagePayload::Text("ping\n{{user}}\n".to_string()),
+                    ),
+                    json_id.clone() => WebSocketMessageSpec::new(
+                        "Subscribe".to_string(),
+                        WebSocketMessagePayload::Json(json!({ "type": "subscribe" })),
+                    ),
+                    binary_id.clone() => WebSocketMessageSpec::new(
+                        "Frame".to_string(),
+                        WebSocketMessagePayload::Binary(PathBuf::from("frames/hello.bin")),
+                    ),
+                })),
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<EntryModel>(&str).unwrap();
+        assert_eq!(model.protocol(), Some(ResourceProtocol::WebSocket));
+
+        let websocket = model.websocket.unwrap().into_inner();
+        assert_eq!(websocket.subprotocols, vec!["graphql-ws".to_string()]);
+
+        // Messages keep the order they were saved in
+        let messages = websocket.messages.unwrap();
+        let ids = messages.keys().cloned().collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![text_id.clone(), json_id.clone(), binary_id.clone()]
+        );
+        assert_eq!(
+            messages[&text_id].payload(),
+            Some(WebSocketMessagePayload::Text(
+                "ping\n{{user}}\n".to_string()
+            ))
+        );
+        assert_eq!(
+            messages[&json_id].payload(),
+            Some(WebSocketMessagePayload::Json(
+                json!({ "type": "subscribe" })
+            ))
+        );
+        assert_eq!(
+            messages[&binary_id].payload(),
+            Some(WebSocketMessagePayload::Binary(PathBuf::from(
+                "frames/hello.bin"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_graphql_roundtrip() {
+        let query = "query User($id: ID!) {\n  user(id: $id) {\n    name\n  }\n}\n";
+        let model = EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::Graphql,
+                raw: "{{baseUrl}}/graphql".to_string(),
+            })),
+            graphql: Some(Block::new(GraphqlSpec {
+                query: query.to_string(),
+                operation_name: Some("User".to_string()),
+                variables: Some(json!({ "id": "{{userId}}", "first": 10 })),
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<EntryModel>(&str).unwrap();
+        assert_eq!(model.protocol(), Some(ResourceProtocol::Graphql));
+
+        let graphql = model.graphql.unwrap().into_inner();
+        assert_eq!(graphql.query, query);
+        assert_eq!(graphql.operation_name, Some("User".to_string()));
+        assert_eq!(
+            graphql.variables,
+            Some(json!({ "id": "{{userId}}", "first": 10 }))
+        );
+    }
+
+    #[test]
+    fn test_grpc_roundtrip() {
+        let model = EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::Grpc,
+                raw: "http://localhost:50051".to_string(),
+            })),
+            grpc: Some(Block::new(GrpcSpec {
+                proto_files: vec![PathBuf::from("protos/greeter.proto")],
+                import_paths: vec![PathBuf::from("protos")],
+                service: "helloworld.Greeter".to_string(),
+                method: "SayHello".to_string(),
+                messages: vec![json!({ "name": "{{name}}", "tags": ["a", "b"] })],
+                timeout_ms: Some(5000),
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<EntryModel>(&str).unwrap();
+        assert_eq!(model.protocol(), Some(ResourceProtocol::Grpc));
+
+        let grpc = model.grpc.unwrap().into_inner();
+        assert_eq!(
+            grpc.proto_files,
+            vec![PathBuf::from("protos/greeter.proto")]
+        );
+        assert_eq!(grpc.import_paths, vec![PathBuf::from("protos")]);
+        assert_eq!(grpc.service, "helloworld.Greeter");
+        assert_eq!(grpc.method, "SayHello");
+        assert_eq!(
+            grpc.messages,
+            vec![json!({ "name": "{{name}}", "tags": ["a", "b"] })]
+        );
+        assert_eq!(grpc.timeout_ms, Some(5000));
+    }
+
+    #[test]
+    fn test_sse_roundtrip() {
+        let model = EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::Get,
+                raw: "{{baseUrl}}/events".to_string(),
+            })),
+            sse: Some(Block::new(SseSpec {
+                reconnect: true,
+                retry_ms: Some(1000),
+                max_reconnects: None,
+                record: true,
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<EntryModel>(&str).unwrap();
+        assert_eq!(model.protocol(), Some(ResourceProtocol::Get));
+
+        let sse = model.sse.unwrap().into_inner();
+        assert!(sse.reconnect);
+        assert_eq!(sse.retry_ms, Some(1000));
+        assert_eq!(sse.max_reconnects, None);
+        assert!(sse.record);
+    }
+
+    #[test]
+    fn test_amqp_roundtrip() {
+        let model = EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::Amqp,
+                raw: "amqp://{{host}}:5672/%2f".to_string(),
+            })),
+            amqp: Some(Block::new(AmqpSpec {
+                password: Some("{{password}}".to_string()),
+                exchange: "orders".to_string(),
+                routing_key: "orders.created".to_string(),
+                payload: "{\n  \"id\": 1\n}".to_string(),
+                content_type: Some("application/json".to_string()),
+                priority: Some(5),
+                persistent: true,
+                ..Default::default()
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<EntryModel>(&str).unwrap();
+        assert_eq!(model.protocol(), Some(ResourceProtocol::Amqp));
+
+        let amqp = model.amqp.unwrap().into_inner();
+        assert_eq!(amqp.password.as_deref(), Some("{{password}}"));
+        assert_eq!(amqp.exchange, "orders");
+        assert_eq!(amqp.routing_key, "orders.created");
+        assert_eq!(amqp.queue, None);
+        assert_eq!(amqp.payload, "{\n  \"id\": 1\n}");
+        assert_eq!(amqp.priority, Some(5));
+        assert!(amqp.persistent);
+    }
+
+    #[test]
+    fn test_mqtt_roundtrip() {
+        let model = EntryModel {
+            url: Some(Block::new(UrlDetails {
+                protocol: ResourceProtocol::Mqtt,
+                raw: "mqtt://{{host}}:1883".to_string(),
+            })),
+            mqtt: Some(Block::new(MqttSpec {
+                version: MqttVersion::V5,
+                topic: "sensors/kitchen".to_string(),
+                topic_filter: Some("sensors/+".to_string()),
+                qos: 1,
+                clean_session: true,
+                payload: "21.5".to_string(),
+                message_expiry_secs: Some(60),
+                ..Default::default()
+            })),
+            ..EntryModel::from((ResourceId::new(), ResourceClass::Endpoint))
+        };
+
+        let str = hcl::to_string(&model).unwrap();
+        let model = hcl::from_str::<EntryModel>(&str).unwrap();
+        assert_eq!(model.protocol(), Some(ResourceProtocol::Mqtt));
+
+        let mqtt = model.mqtt.unwrap().into_inner();
+        assert_eq!(mqtt.version, MqttVersion::V5);
+        assert_eq!(mqtt.topic, "sensors/kitchen");
+        assert_eq!(mqtt.topic_filter.as_deref(), Some("sensors/+"));
+        assert_eq!(mqtt.qos, 1);
+        assert!(!mqtt.retain);
+        assert!(mqtt.clean_session);
+        assert_eq!(mqtt.payload, "21.5");
+        assert_eq!(mqtt.message_expiry_secs, Some(60));
+    }
 }
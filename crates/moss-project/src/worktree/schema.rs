@@ -0,0 +1,180 @@
+use joinerror::{Error, OptionExt};
+use moss_fs::CreateOptions;
+use moss_hcl::HclResultExt;
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId};
+use sapic_core::context::AnyAsyncContext;
+use serde_json::Value as JsonValue;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    constants::{ITEM_CONFIG_FILENAME, SCHEMA_FILENAME},
+    errors::{ErrorInvalidInput, ErrorInvalidKind, ErrorNotFound},
+    json_schema,
+    models::types::SchemaViolation,
+    worktree::{
+        Worktree, WorktreeState,
+        entry::model::{BodyKind, EntryModel},
+    },
+};
+
+impl Worktree {
+    /// Fails unless `id` refers to a schema item that bodies can be validated against.
+    pub async fn check_schema_resource(&self, id: &ResourceId) -> joinerror::Result<()> {
+        let state_lock = self.state.read().await;
+        self.schema_item_path(&state_lock, id).map(|_| ())
+    }
+
+    /// Validates the json body of an endpoint against the schema resource it references.
+    /// `body` is validated in place of the stored body when provided.
+    pub async fn validate_body(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ResourceId,
+        body: Option<JsonValue>,
+    ) -> joinerror::Result<Vec<SchemaViolation>> {
+        let (model, schema_id, schema_path) = {
+            let state_lock = self.state.read().await;
+            let path = self.item_path(&state_lock, id)?;
+            let model = self.read_item_model(ctx, &path).await?;
+            let schema_id = model
+                .body_schema()
+                .ok_or_join_err_with::<ErrorInvalidInput>(|| {
+                    format!("resource {} has no schema attached to its body", id)
+                })?;
+            let schema_path = self.schema_item_path(&state_lock, &schema_id)?;
+
+            (model, schema_id, schema_path)
+        };
+
+        let schema_model = self.read_item_model(ctx, &schema_path).await?;
+        let schema = self
+            .schema_document(ctx, &schema_path, schema_model)
+            .await?
+            .ok_or_join_err_with::<ErrorNotFound>(|| {
+                format!("schema resource {} has no document", schema_id)
+            })?;
+
+        let body = body
+            .or_else(|| {
+                model
+                    .body
+                    .and_then(|mut body| body.shift_remove(&BodyKind::Json))
+                    .and_then(|spec| spec.json)
+            })
+            .unwrap_or(JsonValue::Null);
+
+        Ok(json_schema::validate(&schema, &body))
+    }
+
+    /// Returns the document of a schema item, kept either in its config or in an adjacent file.
+    pub(super) async fn schema_document(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        abs_path: &Path,
+        model: EntryModel,
+    ) -> joinerror::Result<Option<JsonValue>> {
+        if model.schema.is_some() {
+            return Ok(model.schema);
+        }
+
+        let schema_path = abs_path.join(SCHEMA_FILENAME);
+        if !schema_path.exists() {
+            return Ok(None);
+        }
+
+        let rdr = self.fs.open_file(ctx, &schema_path).await?;
+        let schema = serde_json::from_reader(rdr).map_err(|err| {
+            Error::new::<ErrorInvalidInput>(format!(
+                "failed to parse {}: {}",
+                schema_path.display(),
+                err
+            ))
+        })?;
+
+        Ok(Some(schema))
+    }
+
+    /// Writes the document back to the adjacent file of a schema item.
+    /// Returns false when the document lives in the config instead.
+    pub(super) async fn write_schema_file(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        abs_path: &Path,
+        schema: &JsonValue,
+    ) -> joinerror::Result<bool> {
+        let schema_path = abs_path.join(SCHEMA_FILENAME);
+        if !schema_path.exists() {
+            return Ok(false);
+        }
+
+        let content = serde_json::to_string_pretty(schema)?;
+        self.fs
+            .create_file_with(
+                ctx,
+                &schema_path,
+                content.as_bytes(),
+                CreateOptions {
+                    overwrite: true,
+                    ignore_if_exists: false,
+                },
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    pub(super) fn item_path(
+        &self,
+        state: &WorktreeState,
+        id: &ResourceId,
+    ) -> joinerror::Result<PathBuf> {
+        let entry = state
+            .entries
+            .get(id)
+            .ok_or_join_err_with::<ErrorNotFound>(|| format!("entry {} not found", id))?;
+        let abs_path = self.absolutize(&entry.path_rx.borrow())?;
+
+        if !abs_path.join(ITEM_CONFIG_FILENAME).exists() {
+            return Err(Error::new::<ErrorInvalidKind>(format!(
+                "entry {} is not an item",
+                id
+            )));
+        }
+
+        Ok(abs_path)
+    }
+
+    pub(super) fn schema_item_path(
+        &self,
+        state: &WorktreeState,
+        id: &ResourceId,
+    ) -> joinerror::Result<PathBuf> {
+        let abs_path = self.item_path(state, id)?;
+        let is_schema = state
+            .entries
+            .get(id)
+            .is_some_and(|entry| entry.class == ResourceClass::Schema);
+
+        if !is_schema {
+            return Err(Error::new::<ErrorInvalidKind>(format!(
+                "resource {} is not a schema",
+                id
+            )));
+        }
+
+        Ok(abs_path)
+    }
+
+    pub(super) async fn read_item_model(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        abs_path: &Path,
+    ) -> joinerror::Result<EntryModel> {
+        let mut rdr = self
+            .fs
+            .open_file(ctx, &abs_path.join(ITEM_CONFIG_FILENAME))
+            .await?;
+
+        hcl::from_reader(&mut rdr).join_err::<()>("failed to parse item configuration")
+    }
+}
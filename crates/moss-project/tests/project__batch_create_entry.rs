@@ -31,12 +31,18 @@ async fn batch_create_entry_success() {
         class: ResourceClass::Endpoint,
         path: entry_base_path.clone(),
         name: outer_name.clone(),
+        description: None,
         order: 0,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        auth: None,
     });
     let inner_input = BatchCreateResourceKind::Item(CreateItemResourceParams {
         class: ResourceClass::Endpoint,
         path: entry_base_path.join(&outer_name),
         name: inner_name.clone(),
+        description: None,
         order: 0,
         protocol: None,
         query_params: vec![],
@@ -44,6 +50,16 @@ async fn batch_create_entry_success() {
         headers: vec![],
         body: None,
         url: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
     let input = BatchCreateResourceInput {
         // Make sure that the order is correctly sorted
@@ -87,6 +103,7 @@ async fn batch_create_entry_missing_parent() {
         class: ResourceClass::Endpoint,
         path: entry_base_path.join("parent"),
         name: inner_name.clone(),
+        description: None,
         order: 0,
         protocol: None,
         query_params: vec![],
@@ -94,6 +111,16 @@ async fn batch_create_entry_missing_parent() {
         headers: vec![],
         body: None,
         url: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
     let input = BatchCreateResourceInput {
         resources: vec![inner_input],
@@ -108,6 +135,76 @@ async fn batch_create_entry_missing_parent() {
     cleanup().await;
 }
 
+#[tokio::test]
+async fn batch_create_entry_rolls_back_on_failure() {
+    let (ctx, _, project_path, project, cleanup) = create_test_project().await;
+    let resources_dir = project_path.join(dirs::RESOURCES_DIR);
+
+    let entry_base_path = PathBuf::from(RESOURCES_ROOT_DIR);
+    let dir_name = random_entry_name();
+    let item_name = random_entry_name();
+
+    let item_input = |path: PathBuf, name: String| {
+        BatchCreateResourceKind::Item(CreateItemResourceParams {
+            class: ResourceClass::Endpoint,
+            path,
+            name,
+            description: None,
+            order: 0,
+            protocol: None,
+            query_params: vec![],
+            path_params: vec![],
+            headers: vec![],
+            body: None,
+            url: None,
+            body_schema: None,
+            schema: None,
+            components: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        })
+    };
+    let input = BatchCreateResourceInput {
+        resources: vec![
+            BatchCreateResourceKind::Dir(CreateDirResourceParams {
+                class: ResourceClass::Endpoint,
+                path: entry_base_path.clone(),
+                name: dir_name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
+            }),
+            item_input(entry_base_path.join(&dir_name), item_name.clone()),
+            // Fails, since the parent doesn't exist
+            item_input(entry_base_path.join("parent"), random_entry_name()),
+        ],
+    };
+
+    let result = project
+        .batch_create_resource::<MockAppRuntime>(&ctx, input)
+        .await;
+    assert!(result.is_err());
+
+    // The resources created before the failure were removed again
+    assert!(
+        !resources_dir
+            .join(&entry_base_path)
+            .join(&dir_name)
+            .exists()
+    );
+
+    // Cleanup
+    cleanup().await;
+}
+
 #[tokio::test]
 async fn batch_create_entry_empty_input() {
     let (ctx, _, _, project, cleanup) = create_test_project().await;
@@ -3,17 +3,24 @@ pub mod shared;
 
 use moss_applib::mock::MockAppRuntime;
 use moss_project::{
-    constants, dirs,
-    errors::ErrorAlreadyExists,
+    Project, constants, dirs,
+    errors::{ErrorAlreadyExists, ErrorInvalidInput, ErrorInvalidKind},
     models::{
         operations::CreateResourceInput,
         types::{
-            BodyInfo, CreateDirResourceParams, CreateItemResourceParams,
+            ApiKeyLocation, AuthInfo, AuthScheme, BodyInfo, CreateDirResourceParams,
+            CreateItemResourceParams, OAuth2Config, OAuth2Grant,
+            amqp::{AmqpInfo, AmqpParams},
+            graphql::GraphqlParams,
+            grpc::GrpcParams,
             http::{
                 AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
                 AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
                 HeaderParamOptions, PathParamOptions, QueryParamOptions, UrlencodedParamOptions,
             },
+            mqtt::{MqttInfo, MqttParams, MqttVersion},
+            sse::{SseInfo, SseParams},
+            websocket::{AddWebSocketMessageParams, WebSocketMessagePayload, WebSocketParams},
         },
     },
     storage::key_resource_order,
@@ -21,12 +28,233 @@ use moss_project::{
 use moss_storage2::models::primitives::StorageScope;
 use moss_testutils::fs_specific::FILENAME_SPECIAL_CHARS;
 use moss_text::sanitized::sanitize;
-use sapic_base::resource::types::primitives::{ResourceClass, ResourceKind, ResourceProtocol};
+use sapic_base::resource::types::primitives::{
+    ResourceClass, ResourceId, ResourceKind, ResourceProtocol,
+};
+use sapic_core::context::ArcContext;
 use sapic_runtime::globals::GlobalKvStorage;
 use serde_json::{Value as JsonValue, json};
 use std::path::PathBuf;
 
-use crate::shared::{RESOURCES_ROOT_DIR, create_test_project, random_entry_name};
+use crate::shared::{
+    RESOURCES_ROOT_DIR, create_test_item, create_test_project, random_entry_name, test_item_input,
+};
+
+fn header(name: &str, value: &str) -> AddHeaderParams {
+    AddHeaderParams {
+        name: name.to_string(),
+        value: json!(value),
+        order: 0,
+        description: None,
+        options: HeaderParamOptions {
+            disabled: false,
+            propagate: false,
+        },
+    }
+}
+
+async fn create_test_component(ctx: &ArcContext, project: &Project) -> ResourceId {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            headers: vec![
+                header("Authorization", "Bearer {{token}}"),
+                header("Accept", "application/json"),
+            ],
+            query_params: vec![AddQueryParamParams {
+                name: "version".to_string(),
+                value: json!("2"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: Some(AddBodyParams::Json(json!({ "client": "sapic" }))),
+            ..test_item_input(ResourceClass::Component)
+        },
+    )
+    .await
+    .unwrap()
+}
+
+async fn create_endpoint_with_components(
+    ctx: &ArcContext,
+    project: &Project,
+    components: Vec<ResourceId>,
+) -> joinerror::Result<ResourceId> {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            protocol: Some(ResourceProtocol::Post),
+            url: Some("https://example.com/users".to_string()),
+            headers: vec![header("Accept", "application/xml")],
+            components,
+            ..test_item_input(ResourceClass::Endpoint)
+        },
+    )
+    .await
+}
+
+fn bearer(token: &str) -> AuthScheme {
+    AuthScheme::Bearer {
+        token: token.to_string(),
+    }
+}
+
+fn oauth2(grant: OAuth2Grant) -> OAuth2Config {
+    OAuth2Config {
+        grant,
+        auth_url: None,
+        token_url: "https://auth.example.com/token".to_string(),
+        device_auth_url: None,
+        client_id: "client".to_string(),
+        client_secret: Some("{{client_secret}}".to_string()),
+        scopes: vec!["read".to_string(), "write".to_string()],
+        refresh_token: None,
+        callback_port: None,
+    }
+}
+
+fn endpoint_input(path: PathBuf, auth: Option<AuthScheme>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path,
+        protocol: Some(ResourceProtocol::Get),
+        url: Some("https://example.com/users".to_string()),
+        auth,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn message(name: &str, payload: WebSocketMessagePayload) -> AddWebSocketMessageParams {
+    AddWebSocketMessageParams {
+        name: name.to_string(),
+        payload,
+    }
+}
+
+fn websocket_input(
+    protocol: ResourceProtocol,
+    websocket: Option<WebSocketParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("wss://example.com/socket".to_string()),
+        websocket,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+const GRAPHQL_QUERY: &str = "query User($id: ID!) {\n  user(id: $id) {\n    name\n  }\n}\n";
+
+fn graphql_input(
+    protocol: ResourceProtocol,
+    graphql: Option<GraphqlParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("https://example.com/graphql".to_string()),
+        graphql,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn grpc_params() -> GrpcParams {
+    GrpcParams {
+        proto_files: vec![PathBuf::from("/protos/greeter.proto")],
+        import_paths: vec![],
+        service: "helloworld.Greeter".to_string(),
+        method: "SayHello".to_string(),
+        messages: vec![json!({ "name": "{{name}}" })],
+        timeout_ms: Some(5000),
+    }
+}
+
+fn grpc_input(protocol: ResourceProtocol, grpc: Option<GrpcParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("http://localhost:50051".to_string()),
+        grpc,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn sse_params() -> SseParams {
+    SseParams {
+        reconnect: true,
+        retry_ms: Some(1000),
+        max_reconnects: Some(5),
+        record: true,
+    }
+}
+
+fn sse_input(protocol: ResourceProtocol, sse: Option<SseParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("http://localhost:8080/events".to_string()),
+        sse,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn amqp_params() -> AmqpParams {
+    AmqpParams {
+        username: Some("guest".to_string()),
+        password: Some("{{amqpPassword}}".to_string()),
+        exchange: "orders".to_string(),
+        routing_key: "orders.created".to_string(),
+        queue: None,
+        payload: "{\"id\": {{orderId}}}".to_string(),
+        content_type: Some("application/json".to_string()),
+        correlation_id: None,
+        reply_to: None,
+        message_id: None,
+        expiration_ms: Some(60000),
+        priority: Some(5),
+        persistent: true,
+    }
+}
+
+fn mqtt_params() -> MqttParams {
+    MqttParams {
+        version: MqttVersion::V5,
+        client_id: Some("sapic-{{device}}".to_string()),
+        username: None,
+        password: None,
+        topic: "sensors/kitchen".to_string(),
+        topic_filter: Some("sensors/+".to_string()),
+        qos: 1,
+        retain: false,
+        clean_session: true,
+        payload: "21.5".to_string(),
+        content_type: Some("text/plain".to_string()),
+        response_topic: None,
+        correlation_data: None,
+        message_expiry_secs: Some(60),
+    }
+}
+
+fn queue_input(
+    protocol: ResourceProtocol,
+    amqp: Option<AmqpParams>,
+    mqtt: Option<MqttParams>,
+) -> CreateItemResourceParams {
+    let url = match protocol {
+        ResourceProtocol::Mqtt => "mqtt://localhost:1883",
+        _ => "amqp://localhost:5672/%2f",
+    };
+
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some(url.to_string()),
+        amqp,
+        mqtt,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
 
 #[tokio::test]
 async fn create_dir_entry_success() {
@@ -39,7 +267,12 @@ async fn create_dir_entry_success() {
         class: ResourceClass::Endpoint,
         path: entry_path.clone(),
         name: entry_name.clone(),
+        description: None,
         order: 0,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        auth: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -76,7 +309,12 @@ async fn create_dir_entry_with_order() {
         class: ResourceClass::Endpoint,
         path: entry_path.clone(),
         name: entry_name.clone(),
+        description: None,
         order: order_value,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        auth: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -115,7 +353,12 @@ async fn create_dir_entry_already_exists() {
         class: ResourceClass::Endpoint,
         path: entry_path.clone(),
         name: entry_name.clone(),
+        description: None,
         order: 0,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        auth: None,
     });
 
     // Create the entry first time - should succeed
@@ -151,7 +394,12 @@ async fn create_dir_entry_special_chars_in_name() {
             class: ResourceClass::Endpoint,
             path: entry_path.clone(),
             name: entry_name.clone(),
+            description: None,
             order: 0,
+            headers: vec![],
+            path_params: vec![],
+            query_params: vec![],
+            auth: None,
         });
 
         let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -190,6 +438,7 @@ async fn create_item_entry_endpoint() {
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -224,6 +473,16 @@ async fn create_item_entry_endpoint() {
             },
         }],
         body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -297,6 +556,7 @@ String"#;
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -304,6 +564,16 @@ String"#;
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Text(text.to_string())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -336,6 +606,7 @@ async fn create_item_entry_body_json() {
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -343,6 +614,16 @@ async fn create_item_entry_body_json() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Json(json.clone())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -371,6 +652,7 @@ async fn create_item_entry_body_xml() {
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -378,6 +660,16 @@ async fn create_item_entry_body_xml() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Xml(xml.to_string())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -406,6 +698,7 @@ async fn create_item_entry_body_binary() {
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -413,6 +706,16 @@ async fn create_item_entry_body_binary() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Binary(binary.clone())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
@@ -464,6 +767,7 @@ async fn create_item_entry_body_urlencoded() {
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -471,6 +775,16 @@ async fn create_item_entry_body_urlencoded() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Urlencoded(params)),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
     let id = result.unwrap().id;
@@ -542,6 +856,7 @@ async fn create_item_entry_body_formdata() {
         path: entry_path.clone(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -549,6 +864,16 @@ async fn create_item_entry_body_formdata() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::FormData(params.clone())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
     let result = project.create_resource::<MockAppRuntime>(&ctx, input).await;
     let id = result.unwrap().id;
@@ -585,3 +910,593 @@ async fn create_item_entry_body_formdata() {
     // Cleanup
     cleanup().await;
 }
+
+#[tokio::test]
+async fn create_resource_component_not_a_component() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let schema_id = create_test_item(&ctx, &project, test_item_input(ResourceClass::Schema))
+        .await
+        .unwrap();
+
+    let result = create_endpoint_with_components(&ctx, &project, vec![schema_id]).await;
+    assert!(result.unwrap_err().is::<ErrorInvalidKind>());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_component_referencing_components() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let component_id = create_test_component(&ctx, &project).await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        CreateItemResourceParams {
+            components: vec![component_id],
+            ..test_item_input(ResourceClass::Component)
+        },
+    )
+    .await;
+    assert!(result.unwrap_err().is::<ErrorInvalidInput>());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_item_entry_with_auth() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(PathBuf::from(RESOURCES_ROOT_DIR), Some(bearer("{{token}}"))),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.auth,
+        Some(AuthInfo {
+            scheme: bearer("{{token}}"),
+            inherited_from: None,
+        })
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_item_entry_with_api_key_in_query() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let scheme = AuthScheme::ApiKey {
+        key: "api_key".to_string(),
+        value: "{{api_key}}".to_string(),
+        location: ApiKeyLocation::Query,
+    };
+    let id = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(PathBuf::from(RESOURCES_ROOT_DIR), Some(scheme.clone())),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.auth.map(|auth| auth.scheme), Some(scheme));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_item_entry_with_inline_secret() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(
+            PathBuf::from(RESOURCES_ROOT_DIR),
+            Some(AuthScheme::Basic {
+                username: "admin".to_string(),
+                password: "hunter2".to_string(),
+            }),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_item_entry_with_oauth2() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let scheme = AuthScheme::OAuth2(OAuth2Config {
+        auth_url: Some("https://auth.example.com/authorize".to_string()),
+        callback_port: Some(8765),
+        ..oauth2(OAuth2Grant::AuthorizationCode)
+    });
+    let id = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(PathBuf::from(RESOURCES_ROOT_DIR), Some(scheme.clone())),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.auth.map(|auth| auth.scheme), Some(scheme));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_item_entry_with_incomplete_oauth2_grant() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    // The authorization code grant needs the auth url to send the user to
+    let result = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(
+            PathBuf::from(RESOURCES_ROOT_DIR),
+            Some(AuthScheme::OAuth2(oauth2(OAuth2Grant::AuthorizationCode))),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    // Client secrets must not be stored inline either
+    let result = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(
+            PathBuf::from(RESOURCES_ROOT_DIR),
+            Some(AuthScheme::OAuth2(OAuth2Config {
+                client_secret: Some("s3cr3t".to_string()),
+                ..oauth2(OAuth2Grant::ClientCredentials)
+            })),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_websocket_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        websocket_input(
+            ResourceProtocol::WebSocket,
+            Some(WebSocketParams {
+                subprotocols: vec!["chat".to_string(), "superchat".to_string()],
+                messages: vec![
+                    message(
+                        "Hello",
+                        WebSocketMessagePayload::Text("hello {{user}}".to_string()),
+                    ),
+                    message(
+                        "Subscribe",
+                        WebSocketMessagePayload::Json(json!({ "type": "subscribe" })),
+                    ),
+                    message(
+                        "Frame",
+                        WebSocketMessagePayload::Binary(PathBuf::from("frame.bin")),
+                    ),
+                ],
+            }),
+        ),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::WebSocket));
+
+    let websocket = desc.websocket.unwrap();
+    assert_eq!(websocket.subprotocols, vec!["chat", "superchat"]);
+    let messages = websocket
+        .messages
+        .into_iter()
+        .map(|message| (message.name, message.payload))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            (
+                "Hello".to_string(),
+                WebSocketMessagePayload::Text("hello {{user}}".to_string())
+            ),
+            (
+                "Subscribe".to_string(),
+                WebSocketMessagePayload::Json(json!({ "type": "subscribe" }))
+            ),
+            (
+                "Frame".to_string(),
+                WebSocketMessagePayload::Binary(PathBuf::from("frame.bin"))
+            ),
+        ]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_http_endpoint_with_websocket_settings() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        websocket_input(
+            ResourceProtocol::Get,
+            Some(WebSocketParams {
+                subprotocols: vec!["chat".to_string()],
+                messages: vec![],
+            }),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_graphql_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        graphql_input(
+            ResourceProtocol::Graphql,
+            Some(GraphqlParams {
+                query: GRAPHQL_QUERY.to_string(),
+                operation_name: Some("User".to_string()),
+                variables: Some(json!({ "id": "{{userId}}" })),
+            }),
+        ),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Graphql));
+
+    let graphql = desc.graphql.unwrap();
+    assert_eq!(graphql.query, GRAPHQL_QUERY);
+    assert_eq!(graphql.operation_name, Some("User".to_string()));
+    assert_eq!(graphql.variables, Some(json!({ "id": "{{userId}}" })));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_graphql_endpoint_with_invalid_variables() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        graphql_input(
+            ResourceProtocol::Graphql,
+            Some(GraphqlParams {
+                query: GRAPHQL_QUERY.to_string(),
+                operation_name: None,
+                variables: Some(json!(["not", "an", "object"])),
+            }),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_http_endpoint_with_graphql_settings() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        graphql_input(
+            ResourceProtocol::Post,
+            Some(GraphqlParams {
+                query: GRAPHQL_QUERY.to_string(),
+                operation_name: None,
+                variables: None,
+            }),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_grpc_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        grpc_input(ResourceProtocol::Grpc, Some(grpc_params())),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Grpc));
+
+    let grpc = desc.grpc.unwrap();
+    assert_eq!(
+        grpc.proto_files,
+        vec![PathBuf::from("/protos/greeter.proto")]
+    );
+    assert!(grpc.import_paths.is_empty());
+    assert_eq!(grpc.service, "helloworld.Greeter");
+    assert_eq!(grpc.method, "SayHello");
+    assert_eq!(grpc.messages, vec![json!({ "name": "{{name}}" })]);
+    assert_eq!(grpc.timeout_ms, Some(5000));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_grpc_endpoint_with_invalid_message() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        grpc_input(
+            ResourceProtocol::Grpc,
+            Some(GrpcParams {
+                messages: vec![json!("not an object")],
+                ..grpc_params()
+            }),
+        ),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_http_endpoint_with_grpc_settings() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_test_item(
+        &ctx,
+        &project,
+        grpc_input(ResourceProtocol::Post, Some(grpc_params())),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_sse_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        sse_input(ResourceProtocol::Get, Some(sse_params())),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.sse,
+        Some(SseInfo {
+            reconnect: true,
+            retry_ms: Some(1000),
+            max_reconnects: Some(5),
+            record: true,
+        })
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_non_http_endpoint_with_sse_settings() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    for protocol in [ResourceProtocol::WebSocket, ResourceProtocol::Grpc] {
+        let result =
+            create_test_item(&ctx, &project, sse_input(protocol, Some(sse_params()))).await;
+        assert!(result.is_err());
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_amqp_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Amqp, Some(amqp_params()), None),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Amqp));
+    assert_eq!(
+        desc.amqp,
+        Some(AmqpInfo {
+            username: Some("guest".to_string()),
+            password: Some("{{amqpPassword}}".to_string()),
+            exchange: "orders".to_string(),
+            routing_key: "orders.created".to_string(),
+            queue: None,
+            payload: "{\"id\": {{orderId}}}".to_string(),
+            content_type: Some("application/json".to_string()),
+            correlation_id: None,
+            reply_to: None,
+            message_id: None,
+            expiration_ms: Some(60000),
+            priority: Some(5),
+            persistent: true,
+        })
+    );
+    assert_eq!(desc.mqtt, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_mqtt_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Mqtt, None, Some(mqtt_params())),
+    )
+    .await
+    .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Mqtt));
+    assert_eq!(
+        desc.mqtt,
+        Some(MqttInfo {
+            version: MqttVersion::V5,
+            client_id: Some("sapic-{{device}}".to_string()),
+            username: None,
+            password: None,
+            topic: "sensors/kitchen".to_string(),
+            topic_filter: Some("sensors/+".to_string()),
+            qos: 1,
+            retain: false,
+            clean_session: true,
+            payload: "21.5".to_string(),
+            content_type: Some("text/plain".to_string()),
+            response_topic: None,
+            correlation_data: None,
+            message_expiry_secs: Some(60),
+        })
+    );
+    assert_eq!(desc.amqp, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_endpoint_with_queue_settings_of_another_protocol() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    for input in [
+        queue_input(ResourceProtocol::Mqtt, Some(amqp_params()), None),
+        queue_input(ResourceProtocol::Get, Some(amqp_params()), None),
+        queue_input(ResourceProtocol::Amqp, None, Some(mqtt_params())),
+        queue_input(ResourceProtocol::WebSocket, None, Some(mqtt_params())),
+    ] {
+        let result = create_test_item(&ctx, &project, input).await;
+        assert!(result.is_err());
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_queue_endpoint_with_inline_password() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let amqp = AmqpParams {
+        password: Some("guest".to_string()),
+        ..amqp_params()
+    };
+    let result = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Amqp, Some(amqp), None),
+    )
+    .await;
+    assert!(result.is_err());
+
+    let mqtt = MqttParams {
+        username: Some("device".to_string()),
+        password: Some("secret".to_string()),
+        ..mqtt_params()
+    };
+    let result = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Mqtt, None, Some(mqtt)),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_mqtt_endpoint_with_invalid_qos() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let mqtt = MqttParams {
+        qos: 3,
+        ..mqtt_params()
+    };
+    let result = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Mqtt, None, Some(mqtt)),
+    )
+    .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
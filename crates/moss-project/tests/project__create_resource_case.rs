@@ -0,0 +1,209 @@
+#![cfg(feature = "integration-tests")]
+
+mod shared;
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::{
+    Project,
+    constants::CASES_CONFIG_FILENAME,
+    dirs::RESOURCES_DIR,
+    errors::{ErrorAlreadyExists, ErrorInvalidKind},
+    models::{
+        operations::{CreateResourceCaseInput, CreateResourceInput, ListResourceCasesInput},
+        types::{
+            BodyInfo, CreateItemResourceParams, ExpectedResponse,
+            http::{AddBodyParams, AddQueryParamParams, QueryParamOptions},
+        },
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
+use serde_json::json;
+use std::path::PathBuf;
+
+use crate::shared::{
+    RESOURCES_ROOT_DIR, create_test_endpoint_dir_entry, create_test_project, random_entry_name,
+};
+
+fn query_param(name: &str, value: &str) -> AddQueryParamParams {
+    AddQueryParamParams {
+        name: name.to_string(),
+        value: json!(value),
+        order: 0,
+        description: None,
+        options: QueryParamOptions {
+            disabled: false,
+            propagate: false,
+        },
+    }
+}
+
+fn case_input(resource_id: &ResourceId, name: &str) -> CreateResourceCaseInput {
+    CreateResourceCaseInput {
+        resource_id: resource_id.clone(),
+        name: name.to_string(),
+        description: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        expected_response: None,
+    }
+}
+
+async fn create_test_endpoint(ctx: &ArcContext, project: &Project, name: &str) -> ResourceId {
+    project
+        .create_resource::<MockAppRuntime>(
+            ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                name: name.to_string(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Get),
+                url: Some("https://example.com/users".to_string()),
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![query_param("page", "1")],
+                body: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn create_resource_case_success() {
+    let (ctx, app_delegate, project_path, project, cleanup) = create_test_project().await;
+
+    let endpoint_name = random_entry_name();
+    let resource_id = create_test_endpoint(&ctx, &project, &endpoint_name).await;
+
+    let id = project
+        .create_resource_case::<MockAppRuntime>(
+            &ctx,
+            CreateResourceCaseInput {
+                query_params: vec![AddQueryParamParams {
+                    order: 3,
+                    ..query_param("page", "2")
+                }],
+                body: Some(AddBodyParams::Json(json!({ "filter": "active" }))),
+                expected_response: Some(ExpectedResponse {
+                    status: Some(200),
+                    headers: None,
+                    body: None,
+                }),
+                ..case_input(&resource_id, "Second page")
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    // Cases are stored next to the config of the endpoint
+    assert!(
+        project_path
+            .join(RESOURCES_DIR)
+            .join(&endpoint_name)
+            .join(CASES_CONFIG_FILENAME)
+            .exists()
+    );
+
+    let cases = project
+        .list_resource_cases(
+            &ctx,
+            &app_delegate,
+            ListResourceCasesInput {
+                resource_id: resource_id.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .cases;
+
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].id, id);
+    assert_eq!(cases[0].name, "Second page");
+    assert_eq!(cases[0].query_params.len(), 1);
+    assert_eq!(cases[0].query_params[0].value, json!("2"));
+    assert_eq!(cases[0].query_params[0].order, Some(3));
+    assert_eq!(
+        cases[0].body,
+        Some(BodyInfo::Json(json!({ "filter": "active" })))
+    );
+    assert_eq!(
+        cases[0].expected_response.as_ref().unwrap().status,
+        Some(200)
+    );
+
+    // The endpoint itself is left untouched
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, resource_id)
+        .await
+        .unwrap();
+    assert_eq!(desc.query_params[0].value, json!("1"));
+    assert_eq!(desc.body, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_resource_case_already_exists() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let resource_id = create_test_endpoint(&ctx, &project, &random_entry_name()).await;
+
+    project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, "Case"))
+        .await
+        .unwrap();
+
+    let result = project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, "Case"))
+        .await;
+    assert!(result.unwrap_err().is::<ErrorAlreadyExists>());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_resource_case_not_an_endpoint() {
+    let (ctx, _, _, mut project, cleanup) = create_test_project().await;
+
+    let dir_id = create_test_endpoint_dir_entry(&ctx, &mut project, &random_entry_name()).await;
+
+    let result = project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&dir_id, "Case"))
+        .await;
+    assert!(result.unwrap_err().is::<ErrorInvalidKind>());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_resource_case_invalid_name() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let resource_id = create_test_endpoint(&ctx, &project, &random_entry_name()).await;
+
+    for name in ["", "Second/page", "Second\\page"] {
+        let result = project
+            .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, name))
+            .await;
+        assert!(result.is_err(), "case name {name:?} should be rejected");
+    }
+
+    cleanup().await;
+}
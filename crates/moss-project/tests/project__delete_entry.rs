@@ -2,15 +2,122 @@
 pub mod shared;
 
 use moss_applib::mock::MockAppRuntime;
-use moss_project::{dirs, errors::ErrorNotFound, models::operations::DeleteResourceInput};
-use sapic_base::resource::types::primitives::ResourceId;
+use moss_project::{
+    Project, dirs,
+    errors::{ErrorInvalidInput, ErrorNotFound},
+    models::{
+        operations::DeleteResourceInput,
+        types::{
+            CreateItemResourceParams,
+            graphql::GraphqlParams,
+            http::{
+                AddBodyParams, AddHeaderParams, AddQueryParamParams, HeaderParamOptions,
+                QueryParamOptions,
+            },
+            sse::SseParams,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
+use serde_json::json;
 use std::path::PathBuf;
 
 use crate::shared::{
     RESOURCES_ROOT_DIR, create_test_component_dir_entry, create_test_endpoint_dir_entry,
-    create_test_project, create_test_schema_dir_entry, random_entry_name,
+    create_test_item, create_test_project, create_test_schema_dir_entry, random_entry_name,
+    test_item_input,
 };
 
+fn header(name: &str, value: &str) -> AddHeaderParams {
+    AddHeaderParams {
+        name: name.to_string(),
+        value: json!(value),
+        order: 0,
+        description: None,
+        options: HeaderParamOptions {
+            disabled: false,
+            propagate: false,
+        },
+    }
+}
+
+async fn create_test_component(ctx: &ArcContext, project: &Project) -> ResourceId {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            headers: vec![
+                header("Authorization", "Bearer {{token}}"),
+                header("Accept", "application/json"),
+            ],
+            query_params: vec![AddQueryParamParams {
+                name: "version".to_string(),
+                value: json!("2"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: Some(AddBodyParams::Json(json!({ "client": "sapic" }))),
+            ..test_item_input(ResourceClass::Component)
+        },
+    )
+    .await
+    .unwrap()
+}
+
+async fn create_endpoint_with_components(
+    ctx: &ArcContext,
+    project: &Project,
+    components: Vec<ResourceId>,
+) -> joinerror::Result<ResourceId> {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            protocol: Some(ResourceProtocol::Post),
+            url: Some("https://example.com/users".to_string()),
+            headers: vec![header("Accept", "application/xml")],
+            components,
+            ..test_item_input(ResourceClass::Endpoint)
+        },
+    )
+    .await
+}
+
+fn graphql_input(
+    protocol: ResourceProtocol,
+    graphql: Option<GraphqlParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("https://example.com/graphql".to_string()),
+        graphql,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn sse_params() -> SseParams {
+    SseParams {
+        reconnect: true,
+        retry_ms: Some(1000),
+        max_reconnects: Some(5),
+        record: true,
+    }
+}
+
+fn sse_input(protocol: ResourceProtocol, sse: Option<SseParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("http://localhost:8080/events".to_string()),
+        sse,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
 #[tokio::test]
 async fn delete_entry_success() {
     let (ctx, _, project_path, mut project, cleanup) = create_test_project().await;
@@ -232,3 +339,111 @@ async fn delete_entries_from_different_directories() {
     // Cleanup
     cleanup().await;
 }
+
+#[tokio::test]
+async fn delete_referenced_component() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let component_id = create_test_component(&ctx, &project).await;
+    let endpoint_id = create_endpoint_with_components(&ctx, &project, vec![component_id.clone()])
+        .await
+        .unwrap();
+
+    let result = project
+        .delete_resource::<MockAppRuntime>(
+            &ctx,
+            DeleteResourceInput {
+                id: component_id.clone(),
+            },
+        )
+        .await;
+    assert!(result.unwrap_err().is::<ErrorInvalidInput>());
+
+    // The component can be deleted once nothing references it anymore
+    project
+        .delete_resource::<MockAppRuntime>(&ctx, DeleteResourceInput { id: endpoint_id })
+        .await
+        .unwrap();
+    project
+        .delete_resource::<MockAppRuntime>(
+            &ctx,
+            DeleteResourceInput {
+                id: component_id.clone(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = project
+        .describe_resource(&ctx, &app_delegate, component_id)
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn delete_graphql_endpoint_removes_cached_schema() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        graphql_input(ResourceProtocol::Graphql, None),
+    )
+    .await
+    .unwrap();
+    assert_eq!(project.graphql_schema(&ctx, &id).await.unwrap(), None);
+
+    let schema = json!({ "queryType": { "name": "Query" }, "types": [] });
+    project
+        .put_graphql_schema(&ctx, &id, schema.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        project.graphql_schema(&ctx, &id).await.unwrap(),
+        Some(schema)
+    );
+
+    project
+        .delete_resource::<MockAppRuntime>(&ctx, DeleteResourceInput { id: id.clone() })
+        .await
+        .unwrap();
+    assert_eq!(project.graphql_schema(&ctx, &id).await.unwrap(), None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn delete_sse_endpoint_removes_recorded_events() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        sse_input(ResourceProtocol::Get, Some(sse_params())),
+    )
+    .await
+    .unwrap();
+    assert_eq!(project.sse_events(&ctx, &id).await.unwrap(), None);
+
+    let events = json!([{
+        "seq": 1,
+        "event": "message",
+        "data": "hello",
+        "timestamp": "2026-01-01T00:00:00+00:00",
+    }]);
+    project
+        .put_sse_events(&ctx, &id, events.clone())
+        .await
+        .unwrap();
+    assert_eq!(project.sse_events(&ctx, &id).await.unwrap(), Some(events));
+
+    project
+        .delete_resource::<MockAppRuntime>(&ctx, DeleteResourceInput { id: id.clone() })
+        .await
+        .unwrap();
+    assert_eq!(project.sse_events(&ctx, &id).await.unwrap(), None);
+
+    cleanup().await;
+}
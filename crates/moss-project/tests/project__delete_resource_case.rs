@@ -0,0 +1,134 @@
+#![cfg(feature = "integration-tests")]
+
+mod shared;
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::{
+    Project,
+    constants::CASES_CONFIG_FILENAME,
+    dirs::RESOURCES_DIR,
+    models::{
+        operations::{
+            CreateResourceCaseInput, CreateResourceInput, DeleteResourceCaseInput,
+            ListResourceCasesInput,
+        },
+        types::CreateItemResourceParams,
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
+use std::path::PathBuf;
+
+use crate::shared::{RESOURCES_ROOT_DIR, create_test_project, random_entry_name};
+
+fn case_input(resource_id: &ResourceId, name: &str) -> CreateResourceCaseInput {
+    CreateResourceCaseInput {
+        resource_id: resource_id.clone(),
+        name: name.to_string(),
+        description: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        expected_response: None,
+    }
+}
+
+async fn create_test_endpoint(ctx: &ArcContext, project: &Project, name: &str) -> ResourceId {
+    project
+        .create_resource::<MockAppRuntime>(
+            ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                name: name.to_string(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Get),
+                url: Some("https://example.com/users".to_string()),
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn delete_resource_case_success() {
+    let (ctx, app_delegate, project_path, project, cleanup) = create_test_project().await;
+
+    let endpoint_name = random_entry_name();
+    let resource_id = create_test_endpoint(&ctx, &project, &endpoint_name).await;
+
+    let first_id = project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, "First"))
+        .await
+        .unwrap()
+        .id;
+    let second_id = project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, "Second"))
+        .await
+        .unwrap()
+        .id;
+
+    project
+        .delete_resource_case::<MockAppRuntime>(
+            &ctx,
+            DeleteResourceCaseInput {
+                resource_id: resource_id.clone(),
+                id: first_id,
+            },
+        )
+        .await
+        .unwrap();
+
+    let cases = project
+        .list_resource_cases(
+            &ctx,
+            &app_delegate,
+            ListResourceCasesInput {
+                resource_id: resource_id.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .cases;
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].id, second_id);
+
+    project
+        .delete_resource_case::<MockAppRuntime>(
+            &ctx,
+            DeleteResourceCaseInput {
+                resource_id: resource_id.clone(),
+                id: second_id,
+            },
+        )
+        .await
+        .unwrap();
+
+    // The file is removed along with the last case
+    assert!(
+        !project_path
+            .join(RESOURCES_DIR)
+            .join(&endpoint_name)
+            .join(CASES_CONFIG_FILENAME)
+            .exists()
+    );
+
+    cleanup().await;
+}
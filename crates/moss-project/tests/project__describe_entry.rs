@@ -0,0 +1,581 @@
+#![cfg(feature = "integration-tests")]
+
+mod shared;
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::{
+    Project, constants, dirs,
+    models::{
+        operations::{CreateResourceInput, UpdateResourceInput},
+        types::{
+            AuthInfo, AuthScheme, BodyInfo, CreateDirResourceParams, CreateItemResourceParams,
+            UpdateDirResourceParams,
+            http::{
+                AddBodyParams, AddHeaderParams, AddQueryParamParams, AddUrlencodedParamParams,
+                HeaderParamOptions, QueryParamOptions, UrlencodedParamOptions,
+            },
+        },
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
+use serde_json::json;
+use std::{io::Write, path::PathBuf};
+
+use crate::shared::{
+    RESOURCES_ROOT_DIR, create_test_item, create_test_project, random_entry_name, test_item_input,
+};
+
+fn header(name: &str, value: &str, propagate: bool) -> AddHeaderParams {
+    AddHeaderParams {
+        name: name.to_string(),
+        value: json!(value),
+        order: 0,
+        description: None,
+        options: HeaderParamOptions {
+            disabled: false,
+            propagate,
+        },
+    }
+}
+
+async fn create_test_component(ctx: &ArcContext, project: &Project) -> ResourceId {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            headers: vec![
+                header("Authorization", "Bearer {{token}}", false),
+                header("Accept", "application/json", false),
+            ],
+            query_params: vec![AddQueryParamParams {
+                name: "version".to_string(),
+                value: json!("2"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: Some(AddBodyParams::Json(json!({ "client": "sapic" }))),
+            ..test_item_input(ResourceClass::Component)
+        },
+    )
+    .await
+    .unwrap()
+}
+
+async fn create_endpoint_with_components(
+    ctx: &ArcContext,
+    project: &Project,
+    components: Vec<ResourceId>,
+) -> joinerror::Result<ResourceId> {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            protocol: Some(ResourceProtocol::Post),
+            url: Some("https://example.com/users".to_string()),
+            headers: vec![header("Accept", "application/xml", false)],
+            components,
+            ..test_item_input(ResourceClass::Endpoint)
+        },
+    )
+    .await
+}
+
+fn bearer(token: &str) -> AuthScheme {
+    AuthScheme::Bearer {
+        token: token.to_string(),
+    }
+}
+
+async fn create_test_dir(
+    ctx: &ArcContext,
+    project: &Project,
+    auth: Option<AuthScheme>,
+) -> (ResourceId, PathBuf) {
+    let name = random_entry_name();
+    let id = project
+        .create_resource::<MockAppRuntime>(
+            ctx,
+            CreateResourceInput::Dir(CreateDirResourceParams {
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                class: ResourceClass::Endpoint,
+                name: name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    (id, PathBuf::from(RESOURCES_ROOT_DIR).join(name))
+}
+
+fn endpoint_input(path: PathBuf, auth: Option<AuthScheme>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path,
+        protocol: Some(ResourceProtocol::Get),
+        url: Some("https://example.com/users".to_string()),
+        auth,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+#[tokio::test]
+async fn describe_entry_inherits_propagated_params() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    // {outer}/{inner}/{item}
+    let outer_name = random_entry_name();
+    let inner_name = random_entry_name();
+    let outer_path = PathBuf::from(RESOURCES_ROOT_DIR);
+    let inner_path = outer_path.join(&outer_name);
+
+    let outer_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Dir(CreateDirResourceParams {
+                class: ResourceClass::Endpoint,
+                path: outer_path,
+                name: outer_name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![
+                    header("Authorization", "outer", true),
+                    header("X-Outer-Only", "outer", false),
+                ],
+                path_params: vec![],
+                query_params: vec![AddQueryParamParams {
+                    name: "trace".to_string(),
+                    value: json!("1"),
+                    order: 0,
+                    description: None,
+                    options: QueryParamOptions {
+                        disabled: false,
+                        propagate: true,
+                    },
+                }],
+                auth: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let inner_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Dir(CreateDirResourceParams {
+                class: ResourceClass::Endpoint,
+                path: inner_path.clone(),
+                name: inner_name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![header("authorization", "inner", true)],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let item_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: inner_path.join(&inner_name),
+                name: random_entry_name(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Get),
+                url: None,
+                headers: vec![header("X-Item", "item", false)],
+                path_params: vec![],
+                query_params: vec![],
+                body: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, item_id.clone())
+        .await
+        .unwrap();
+
+    let headers = desc
+        .headers
+        .iter()
+        .map(|h| (h.name.as_str(), h.value.clone(), h.inherited_from.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        headers,
+        vec![
+            ("authorization", json!("inner"), Some(inner_id.clone())),
+            ("X-Item", json!("item"), None),
+        ]
+    );
+
+    assert_eq!(desc.query_params.len(), 1);
+    assert_eq!(desc.query_params[0].name, "trace");
+    assert_eq!(desc.query_params[0].inherited_from, Some(outer_id.clone()));
+
+    // Dirs expose their own entries along with the inherited ones
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, outer_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.headers.len(), 2);
+    assert!(desc.headers.iter().all(|h| h.inherited_from.is_none()));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn describe_entry_inherits_updated_dir_params() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let dir_name = random_entry_name();
+    let dir_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Dir(CreateDirResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                name: dir_name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let item_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR).join(&dir_name),
+                name: random_entry_name(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Get),
+                url: None,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    project
+        .update_resource(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Dir(UpdateDirResourceParams {
+                id: dir_id.clone(),
+                path: None,
+                name: None,
+                description: None,
+                order: None,
+                expanded: None,
+                headers_to_add: vec![header("X-Trace", "1", true)],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, item_id)
+        .await
+        .unwrap();
+    assert_eq!(desc.headers.len(), 1);
+    assert_eq!(desc.headers[0].name, "X-Trace");
+    assert_eq!(desc.headers[0].inherited_from, Some(dir_id));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn describe_entry_inherits_propagated_body_params() {
+    let (ctx, app_delegate, project_path, project, cleanup) = create_test_project().await;
+
+    let dir_name = random_entry_name();
+    let dir_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Dir(CreateDirResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                name: dir_name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    // Dirs can't be given a body through the api, so it's written to the config directly
+    let dir_config_path = project_path
+        .join(dirs::RESOURCES_DIR)
+        .join(RESOURCES_ROOT_DIR)
+        .join(&dir_name)
+        .join(constants::DIR_CONFIG_FILENAME);
+    let mut dir_config = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&dir_config_path)
+        .unwrap();
+    write!(
+        dir_config,
+        r#"
+body "x-www-form-urlencoded" {{
+  urlencoded "GrantType1" {{
+    name = "grant_type"
+    value = "client_credentials"
+    description = null
+    options = {{
+      "disabled" = false
+      "propagate" = true
+    }}
+  }}
+  urlencoded "ClientId01" {{
+    name = "client_id"
+    value = "dir"
+    description = null
+    options = {{
+      "disabled" = false
+      "propagate" = true
+    }}
+  }}
+  urlencoded "DirSecret1" {{
+    name = "secret"
+    value = "dir"
+    description = null
+    options = {{
+      "disabled" = false
+      "propagate" = false
+    }}
+  }}
+}}
+"#
+    )
+    .unwrap();
+
+    let item_id = project
+        .create_resource::<MockAppRuntime>(
+            &ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR).join(&dir_name),
+                name: random_entry_name(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Post),
+                url: None,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: Some(AddBodyParams::Urlencoded(vec![AddUrlencodedParamParams {
+                    name: "client_id".to_string(),
+                    value: json!("item"),
+                    order: 0,
+                    description: None,
+                    options: UrlencodedParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                    id: None,
+                }])),
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, item_id)
+        .await
+        .unwrap();
+    let Some(BodyInfo::Urlencoded(params)) = desc.body else {
+        panic!("Incorrect body type");
+    };
+    let params = params
+        .iter()
+        .map(|p| (p.name.as_str(), p.value.clone(), p.inherited_from.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        vec![
+            ("grant_type", json!("client_credentials"), Some(dir_id)),
+            ("client_id", json!("item"), None),
+        ]
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn describe_resource_expands_components() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let component_id = create_test_component(&ctx, &project).await;
+    let endpoint_id = create_endpoint_with_components(&ctx, &project, vec![component_id.clone()])
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, endpoint_id)
+        .await
+        .unwrap();
+
+    assert_eq!(desc.components, vec![component_id.clone()]);
+    assert_eq!(desc.headers.len(), 2);
+
+    let authorization = desc
+        .headers
+        .iter()
+        .find(|h| h.name == "Authorization")
+        .unwrap();
+    assert_eq!(authorization.component, Some(component_id.clone()));
+
+    // Entries of the endpoint override the ones from its components
+    let accept = desc.headers.iter().find(|h| h.name == "Accept").unwrap();
+    assert_eq!(accept.value, json!("application/xml"));
+    assert_eq!(accept.component, None);
+
+    assert_eq!(desc.query_params.len(), 1);
+    assert_eq!(desc.query_params[0].component, Some(component_id.clone()));
+
+    assert_eq!(
+        desc.body,
+        Some(BodyInfo::Json(json!({ "client": "sapic" })))
+    );
+    assert_eq!(desc.body_component, Some(component_id.clone()));
+
+    // Components aren't sent on their own
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, component_id)
+        .await
+        .unwrap();
+    assert_eq!(desc.url, None);
+    assert_eq!(desc.headers.len(), 2);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn describe_item_entry_inherits_dir_auth() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let (dir_id, dir_path) = create_test_dir(&ctx, &project, Some(bearer("{{token}}"))).await;
+    let inheriting_id = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(dir_path.clone(), Some(AuthScheme::Inherit)),
+    )
+    .await
+    .unwrap();
+    let unset_id = create_test_item(&ctx, &project, endpoint_input(dir_path.clone(), None))
+        .await
+        .unwrap();
+    let overriding_id = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(dir_path, Some(AuthScheme::None)),
+    )
+    .await
+    .unwrap();
+
+    for id in [inheriting_id, unset_id] {
+        let desc = project
+            .describe_resource(&ctx, &app_delegate, id)
+            .await
+            .unwrap();
+        assert_eq!(
+            desc.auth,
+            Some(AuthInfo {
+                scheme: bearer("{{token}}"),
+                inherited_from: Some(dir_id.clone()),
+            })
+        );
+    }
+
+    // An explicit scheme on the item overrides the one of its dir
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, overriding_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.auth,
+        Some(AuthInfo {
+            scheme: AuthScheme::None,
+            inherited_from: None,
+        })
+    );
+
+    cleanup().await;
+}
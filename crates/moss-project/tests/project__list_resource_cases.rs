@@ -0,0 +1,111 @@
+#![cfg(feature = "integration-tests")]
+
+mod shared;
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::{
+    Project,
+    models::{
+        operations::{CreateResourceCaseInput, CreateResourceInput, ListResourceCasesInput},
+        types::CreateItemResourceParams,
+    },
+};
+use sapic_base::resource::types::primitives::{
+    ResourceClass, ResourceId, ResourceKind, ResourceProtocol,
+};
+use sapic_core::context::{AnyAsyncContext, ArcContext};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+use crate::shared::{RESOURCES_ROOT_DIR, create_test_project, random_entry_name};
+
+fn case_input(resource_id: &ResourceId, name: &str) -> CreateResourceCaseInput {
+    CreateResourceCaseInput {
+        resource_id: resource_id.clone(),
+        name: name.to_string(),
+        description: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        expected_response: None,
+    }
+}
+
+async fn create_test_endpoint(ctx: &ArcContext, project: &Project, name: &str) -> ResourceId {
+    project
+        .create_resource::<MockAppRuntime>(
+            ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                name: name.to_string(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Get),
+                url: Some("https://example.com/users".to_string()),
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                body: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn scan_does_not_list_resource_cases() {
+    let (ctx, app_delegate, _project_path, project, cleanup) = create_test_project().await;
+
+    let endpoint_name = random_entry_name();
+    let resource_id = create_test_endpoint(&ctx, &project, &endpoint_name).await;
+
+    let id = project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, "Second page"))
+        .await
+        .unwrap()
+        .id;
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    project
+        .worktree()
+        .await
+        .scan(ctx.clone_arc(), Path::new(RESOURCES_ROOT_DIR), tx)
+        .await
+        .unwrap();
+
+    let mut entries = vec![];
+    while let Some(entry) = rx.recv().await {
+        entries.push(entry);
+    }
+
+    assert!(entries.iter().all(|entry| entry.kind != ResourceKind::Case));
+    assert!(
+        entries
+            .iter()
+            .any(|entry| entry.id == resource_id && entry.kind == ResourceKind::Item)
+    );
+
+    // Cases are only available through their endpoint
+    let cases = project
+        .list_resource_cases(&ctx, &app_delegate, ListResourceCasesInput { resource_id })
+        .await
+        .unwrap()
+        .cases;
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].id, id);
+
+    cleanup().await;
+}
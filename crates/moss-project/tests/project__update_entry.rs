@@ -3,14 +3,18 @@
 mod shared;
 
 use moss_applib::mock::MockAppRuntime;
-use moss_bindingutils::primitives::{ChangeJsonValue, ChangeString};
+use moss_bindingutils::primitives::{ChangeJsonValue, ChangeString, ChangeUsize};
 use moss_project::{
-    dirs,
+    Project, dirs,
     models::{
-        operations::{CreateResourceInput, UpdateResourceInput},
+        operations::{CreateResourceInput, UpdateResourceInput, UpdateResourceOutput},
         types::{
-            BodyInfo, CreateItemResourceParams, UpdateBodyParams, UpdateDirResourceParams,
+            AuthInfo, AuthScheme, BodyInfo, ChangeAuth, CreateDirResourceParams,
+            CreateItemResourceParams, UpdateBodyParams, UpdateDirResourceParams,
             UpdateItemResourceParams,
+            amqp::{AmqpParams, ChangeAmqp},
+            graphql::{GraphqlParams, UpdateGraphqlParams},
+            grpc::{GrpcParams, UpdateGrpcParams},
             http::{
                 AddBodyParams, AddFormDataParamParams, AddHeaderParams, AddPathParamParams,
                 AddQueryParamParams, AddUrlencodedParamParams, FormDataParamOptions,
@@ -18,20 +22,301 @@ use moss_project::{
                 UpdateHeaderParams, UpdatePathParamParams, UpdateQueryParamParams,
                 UpdateUrlencodedParamParams, UrlencodedParamOptions,
             },
+            mqtt::{ChangeMqtt, MqttParams, MqttVersion},
+            sse::{ChangeSse, SseInfo, SseParams},
+            websocket::{
+                AddWebSocketMessageParams, UpdateWebSocketMessageParams, UpdateWebSocketParams,
+                WebSocketMessagePayload, WebSocketParams,
+            },
         },
     },
 };
 use moss_testutils::fs_specific::FILENAME_SPECIAL_CHARS;
 use moss_text::sanitized::sanitize;
-use sapic_base::resource::types::primitives::{ResourceClass, ResourceProtocol};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
 use serde_json::{Value as JsonValue, json};
 use std::path::{Path, PathBuf};
 
 use crate::shared::{
     RESOURCES_ROOT_DIR, create_test_component_dir_entry, create_test_endpoint_dir_entry,
-    create_test_project, random_entry_name,
+    create_test_item, create_test_project, random_entry_name, test_item_input,
+    test_update_item_input,
 };
 
+fn header(name: &str, value: &str) -> AddHeaderParams {
+    AddHeaderParams {
+        name: name.to_string(),
+        value: json!(value),
+        order: 0,
+        description: None,
+        options: HeaderParamOptions {
+            disabled: false,
+            propagate: false,
+        },
+    }
+}
+
+async fn create_test_component(ctx: &ArcContext, project: &Project) -> ResourceId {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            headers: vec![
+                header("Authorization", "Bearer {{token}}"),
+                header("Accept", "application/json"),
+            ],
+            query_params: vec![AddQueryParamParams {
+                name: "version".to_string(),
+                value: json!("2"),
+                order: 0,
+                description: None,
+                options: QueryParamOptions {
+                    disabled: false,
+                    propagate: false,
+                },
+            }],
+            body: Some(AddBodyParams::Json(json!({ "client": "sapic" }))),
+            ..test_item_input(ResourceClass::Component)
+        },
+    )
+    .await
+    .unwrap()
+}
+
+async fn create_endpoint_with_components(
+    ctx: &ArcContext,
+    project: &Project,
+    components: Vec<ResourceId>,
+) -> joinerror::Result<ResourceId> {
+    create_test_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            protocol: Some(ResourceProtocol::Post),
+            url: Some("https://example.com/users".to_string()),
+            headers: vec![header("Accept", "application/xml")],
+            components,
+            ..test_item_input(ResourceClass::Endpoint)
+        },
+    )
+    .await
+}
+
+fn bearer(token: &str) -> AuthScheme {
+    AuthScheme::Bearer {
+        token: token.to_string(),
+    }
+}
+
+async fn create_test_dir(
+    ctx: &ArcContext,
+    project: &Project,
+    auth: Option<AuthScheme>,
+) -> (ResourceId, PathBuf) {
+    let name = random_entry_name();
+    let id = project
+        .create_resource::<MockAppRuntime>(
+            ctx,
+            CreateResourceInput::Dir(CreateDirResourceParams {
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                class: ResourceClass::Endpoint,
+                name: name.clone(),
+                description: None,
+                order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth,
+            }),
+        )
+        .await
+        .unwrap()
+        .id;
+
+    (id, PathBuf::from(RESOURCES_ROOT_DIR).join(name))
+}
+
+fn endpoint_input(path: PathBuf, auth: Option<AuthScheme>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path,
+        protocol: Some(ResourceProtocol::Get),
+        url: Some("https://example.com/users".to_string()),
+        auth,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn message(name: &str, payload: WebSocketMessagePayload) -> AddWebSocketMessageParams {
+    AddWebSocketMessageParams {
+        name: name.to_string(),
+        payload,
+    }
+}
+
+fn websocket_input(
+    protocol: ResourceProtocol,
+    websocket: Option<WebSocketParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("wss://example.com/socket".to_string()),
+        websocket,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn update_websocket_input(
+    id: &ResourceId,
+    websocket: UpdateWebSocketParams,
+) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        websocket: Some(websocket),
+        ..test_update_item_input(id)
+    }
+}
+
+const GRAPHQL_QUERY: &str = "query User($id: ID!) {\n  user(id: $id) {\n    name\n  }\n}\n";
+
+fn graphql_input(
+    protocol: ResourceProtocol,
+    graphql: Option<GraphqlParams>,
+) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("https://example.com/graphql".to_string()),
+        graphql,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn update_graphql_input(id: &ResourceId, graphql: UpdateGraphqlParams) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        graphql: Some(graphql),
+        ..test_update_item_input(id)
+    }
+}
+
+fn grpc_params() -> GrpcParams {
+    GrpcParams {
+        proto_files: vec![PathBuf::from("/protos/greeter.proto")],
+        import_paths: vec![],
+        service: "helloworld.Greeter".to_string(),
+        method: "SayHello".to_string(),
+        messages: vec![json!({ "name": "{{name}}" })],
+        timeout_ms: Some(5000),
+    }
+}
+
+fn grpc_input(protocol: ResourceProtocol, grpc: Option<GrpcParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("http://localhost:50051".to_string()),
+        grpc,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn update_grpc_input(id: &ResourceId, grpc: UpdateGrpcParams) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        grpc: Some(grpc),
+        ..test_update_item_input(id)
+    }
+}
+
+fn sse_params() -> SseParams {
+    SseParams {
+        reconnect: true,
+        retry_ms: Some(1000),
+        max_reconnects: Some(5),
+        record: true,
+    }
+}
+
+fn sse_input(protocol: ResourceProtocol, sse: Option<SseParams>) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some("http://localhost:8080/events".to_string()),
+        sse,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn update_sse_input(id: &ResourceId, sse: ChangeSse) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        sse: Some(sse),
+        ..test_update_item_input(id)
+    }
+}
+
+fn amqp_params() -> AmqpParams {
+    AmqpParams {
+        username: Some("guest".to_string()),
+        password: Some("{{amqpPassword}}".to_string()),
+        exchange: "orders".to_string(),
+        routing_key: "orders.created".to_string(),
+        queue: None,
+        payload: "{\"id\": {{orderId}}}".to_string(),
+        content_type: Some("application/json".to_string()),
+        correlation_id: None,
+        reply_to: None,
+        message_id: None,
+        expiration_ms: Some(60000),
+        priority: Some(5),
+        persistent: true,
+    }
+}
+
+fn mqtt_params() -> MqttParams {
+    MqttParams {
+        version: MqttVersion::V5,
+        client_id: Some("sapic-{{device}}".to_string()),
+        username: None,
+        password: None,
+        topic: "sensors/kitchen".to_string(),
+        topic_filter: Some("sensors/+".to_string()),
+        qos: 1,
+        retain: false,
+        clean_session: true,
+        payload: "21.5".to_string(),
+        content_type: Some("text/plain".to_string()),
+        response_topic: None,
+        correlation_data: None,
+        message_expiry_secs: Some(60),
+    }
+}
+
+fn queue_input(
+    protocol: ResourceProtocol,
+    amqp: Option<AmqpParams>,
+    mqtt: Option<MqttParams>,
+) -> CreateItemResourceParams {
+    let url = match protocol {
+        ResourceProtocol::Mqtt => "mqtt://localhost:1883",
+        _ => "amqp://localhost:5672/%2f",
+    };
+
+    CreateItemResourceParams {
+        protocol: Some(protocol),
+        url: Some(url.to_string()),
+        amqp,
+        mqtt,
+        ..test_item_input(ResourceClass::Endpoint)
+    }
+}
+
+fn update_queue_input(
+    id: &ResourceId,
+    amqp: Option<ChangeAmqp>,
+    mqtt: Option<ChangeMqtt>,
+) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        amqp,
+        mqtt,
+        ..test_update_item_input(id)
+    }
+}
+
 #[tokio::test]
 async fn rename_dir_entry_success() {
     let (ctx, app_delegate, project_path, mut project, cleanup) = create_test_project().await;
@@ -50,8 +335,19 @@ async fn rename_dir_entry_success() {
                 id,
                 path: None,
                 name: Some(new_entry_name.clone()),
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await
@@ -84,8 +380,19 @@ async fn rename_dir_entry_empty_name() {
                 id,
                 path: None,
                 name: Some(new_entry_name.clone()),
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await;
@@ -115,8 +422,19 @@ async fn rename_dir_entry_already_exists() {
                 id: first_id,
                 path: None,
                 name: Some(second_entry_name.clone()),
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await;
@@ -148,8 +466,19 @@ async fn rename_dir_entry_special_chars_in_name() {
                     id,
                     path: None,
                     name: Some(new_entry_name.clone()),
+                    description: None,
                     order: None,
                     expanded: None,
+                    headers_to_add: vec![],
+                    headers_to_update: vec![],
+                    headers_to_remove: vec![],
+                    path_params_to_add: vec![],
+                    path_params_to_update: vec![],
+                    path_params_to_remove: vec![],
+                    query_params_to_add: vec![],
+                    query_params_to_update: vec![],
+                    query_params_to_remove: vec![],
+                    auth: None,
                 }),
             )
             .await;
@@ -200,8 +529,19 @@ async fn move_dir_entry_success() {
                 id,
                 path: Some(new_dest.clone()),
                 name: None,
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await
@@ -236,8 +576,19 @@ async fn move_dir_entry_nonexistent_destination() {
                 id,
                 path: Some(new_dest.clone()),
                 name: None,
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await;
@@ -268,8 +619,19 @@ async fn move_dir_entry_already_exists() {
                 id: existing_id,
                 path: Some(dest.clone()),
                 name: None,
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await
@@ -285,8 +647,19 @@ async fn move_dir_entry_already_exists() {
                 id: new_id,
                 path: Some(dest.clone()),
                 name: None,
+                description: None,
                 order: None,
                 expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: None,
             }),
         )
         .await;
@@ -306,6 +679,7 @@ async fn update_item_entry_endpoint_headers() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -322,6 +696,16 @@ async fn update_item_entry_endpoint_headers() {
         path_params: vec![],
         query_params: vec![],
         body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -345,6 +729,7 @@ async fn update_item_entry_endpoint_headers() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -367,6 +752,17 @@ async fn update_item_entry_endpoint_headers() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -395,6 +791,7 @@ async fn update_item_entry_endpoint_headers() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -407,6 +804,17 @@ async fn update_item_entry_endpoint_headers() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -427,6 +835,7 @@ async fn update_item_entry_endpoint_headers() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![AddHeaderParams {
@@ -448,6 +857,17 @@ async fn update_item_entry_endpoint_headers() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -478,6 +898,7 @@ async fn update_item_entry_endpoint_path_params() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -494,6 +915,16 @@ async fn update_item_entry_endpoint_path_params() {
         }],
         query_params: vec![],
         body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -517,6 +948,7 @@ async fn update_item_entry_endpoint_path_params() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -539,6 +971,17 @@ async fn update_item_entry_endpoint_path_params() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -566,6 +1009,7 @@ async fn update_item_entry_endpoint_path_params() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -578,6 +1022,17 @@ async fn update_item_entry_endpoint_path_params() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -598,6 +1053,7 @@ async fn update_item_entry_endpoint_path_params() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -619,6 +1075,17 @@ async fn update_item_entry_endpoint_path_params() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -649,6 +1116,7 @@ async fn update_item_entry_endpoint_query_params() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -665,6 +1133,16 @@ async fn update_item_entry_endpoint_query_params() {
             },
         }],
         body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -688,6 +1166,7 @@ async fn update_item_entry_endpoint_query_params() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -710,6 +1189,17 @@ async fn update_item_entry_endpoint_query_params() {
                 }],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -737,6 +1227,7 @@ async fn update_item_entry_endpoint_query_params() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -749,6 +1240,17 @@ async fn update_item_entry_endpoint_query_params() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![query_param_id.clone()],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -769,6 +1271,7 @@ async fn update_item_entry_endpoint_query_params() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -790,6 +1293,17 @@ async fn update_item_entry_endpoint_query_params() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: None,
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -820,6 +1334,7 @@ async fn test_item_entry_endpoint_remove_body() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -837,6 +1352,16 @@ async fn test_item_entry_endpoint_remove_body() {
             },
             id: None,
         }])),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -854,6 +1379,7 @@ async fn test_item_entry_endpoint_remove_body() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -866,6 +1392,17 @@ async fn test_item_entry_endpoint_remove_body() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: Some(UpdateBodyParams::Remove),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -889,6 +1426,7 @@ async fn test_item_entry_endpoint_update_text() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -896,6 +1434,16 @@ async fn test_item_entry_endpoint_update_text() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Text("Before".to_string())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -913,6 +1461,7 @@ async fn test_item_entry_endpoint_update_text() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -925,6 +1474,17 @@ async fn test_item_entry_endpoint_update_text() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: Some(UpdateBodyParams::Text("After".to_string())),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -949,6 +1509,7 @@ async fn test_item_entry_endpoint_update_json() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -956,6 +1517,16 @@ async fn test_item_entry_endpoint_update_json() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Json(json!( {"before": "true"} ))),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let new_json = json!( {"after": "true"} );
@@ -974,6 +1545,7 @@ async fn test_item_entry_endpoint_update_json() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -986,6 +1558,17 @@ async fn test_item_entry_endpoint_update_json() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: Some(UpdateBodyParams::Json(new_json.clone())),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1009,6 +1592,7 @@ async fn test_item_entry_endpoint_update_xml() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -1016,6 +1600,16 @@ async fn test_item_entry_endpoint_update_xml() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Xml("<before></before>".to_string())),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -1032,6 +1626,7 @@ async fn test_item_entry_endpoint_update_xml() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1044,6 +1639,17 @@ async fn test_item_entry_endpoint_update_xml() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: Some(UpdateBodyParams::Xml("<after></after>".to_string())),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1071,6 +1677,7 @@ async fn test_item_entry_endpoint_update_binary() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -1078,6 +1685,16 @@ async fn test_item_entry_endpoint_update_binary() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Binary(PathBuf::from("/before"))),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -1095,6 +1712,7 @@ async fn test_item_entry_endpoint_update_binary() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1107,6 +1725,17 @@ async fn test_item_entry_endpoint_update_binary() {
                 query_params_to_update: vec![],
                 query_params_to_remove: vec![],
                 body: Some(UpdateBodyParams::Binary(PathBuf::from("/after"))),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1130,6 +1759,7 @@ async fn test_item_entry_endpoint_update_urlencoded() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -1137,6 +1767,16 @@ async fn test_item_entry_endpoint_update_urlencoded() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::Urlencoded(vec![])),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -1166,6 +1806,7 @@ async fn test_item_entry_endpoint_update_urlencoded() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1182,6 +1823,17 @@ async fn test_item_entry_endpoint_update_urlencoded() {
                     params_to_update: vec![],
                     params_to_remove: vec![],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1229,6 +1881,7 @@ async fn test_item_entry_endpoint_update_urlencoded() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1245,6 +1898,17 @@ async fn test_item_entry_endpoint_update_urlencoded() {
                     params_to_update: vec![after.clone()],
                     params_to_remove: vec![],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1278,6 +1942,7 @@ async fn test_item_entry_endpoint_update_urlencoded() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1294,6 +1959,17 @@ async fn test_item_entry_endpoint_update_urlencoded() {
                     params_to_update: vec![],
                     params_to_remove: vec![param_id.clone()],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1323,6 +1999,7 @@ async fn test_item_entry_endpoint_update_formdata() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -1330,6 +2007,16 @@ async fn test_item_entry_endpoint_update_formdata() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::FormData(vec![])),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -1359,6 +2046,7 @@ async fn test_item_entry_endpoint_update_formdata() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1375,6 +2063,17 @@ async fn test_item_entry_endpoint_update_formdata() {
                     params_to_update: vec![],
                     params_to_remove: vec![],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1422,6 +2121,7 @@ async fn test_item_entry_endpoint_update_formdata() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1438,6 +2138,17 @@ async fn test_item_entry_endpoint_update_formdata() {
                     params_to_update: vec![after.clone()],
                     params_to_remove: vec![],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1471,6 +2182,7 @@ async fn test_item_entry_endpoint_update_formdata() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1487,6 +2199,17 @@ async fn test_item_entry_endpoint_update_formdata() {
                     params_to_update: vec![],
                     params_to_remove: vec![param_id.clone()],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1516,6 +2239,7 @@ async fn test_item_entry_endpoint_update_change_body_type() {
         path: Default::default(),
         class: ResourceClass::Endpoint,
         name: entry_name.clone(),
+        description: None,
         order: 0,
         protocol: Some(ResourceProtocol::Get),
         url: None,
@@ -1523,6 +2247,16 @@ async fn test_item_entry_endpoint_update_change_body_type() {
         path_params: vec![],
         query_params: vec![],
         body: Some(AddBodyParams::FormData(vec![])),
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
     });
 
     let id = project
@@ -1539,6 +2273,7 @@ async fn test_item_entry_endpoint_update_change_body_type() {
                 id: id.clone(),
                 path: None,
                 name: None,
+                description: None,
                 protocol: None,
                 url: None,
                 headers_to_add: vec![],
@@ -1555,6 +2290,17 @@ async fn test_item_entry_endpoint_update_change_body_type() {
                     params_to_update: vec![],
                     params_to_remove: vec![],
                 }),
+                body_schema: None,
+                schema: None,
+                components_to_add: vec![],
+                components_to_remove: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -1573,3 +2319,919 @@ async fn test_item_entry_endpoint_update_change_body_type() {
 
     cleanup().await;
 }
+
+#[tokio::test]
+async fn test_item_entry_endpoint_update_protocol() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let input = CreateResourceInput::Item(CreateItemResourceParams {
+        path: Default::default(),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: Some(ResourceProtocol::Patch),
+        url: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    });
+
+    let id = project
+        .create_resource::<MockAppRuntime>(&ctx, input)
+        .await
+        .unwrap()
+        .id;
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.protocol, Some(ResourceProtocol::Patch));
+
+    let update_input = |protocol: ResourceProtocol| {
+        UpdateResourceInput::Item(UpdateItemResourceParams {
+            id: id.clone(),
+            path: None,
+            name: None,
+            description: None,
+            protocol: Some(protocol),
+            url: None,
+            headers_to_add: vec![],
+            headers_to_update: vec![],
+            headers_to_remove: vec![],
+            path_params_to_add: vec![],
+            path_params_to_update: vec![],
+            path_params_to_remove: vec![],
+            query_params_to_add: vec![],
+            query_params_to_update: vec![],
+            query_params_to_remove: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components_to_add: vec![],
+            components_to_remove: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        })
+    };
+
+    project
+        .update_resource(
+            &ctx,
+            &app_delegate,
+            update_input(ResourceProtocol::Custom("PURGE".to_string())),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.protocol,
+        Some(ResourceProtocol::Custom("PURGE".to_string()))
+    );
+
+    // Custom methods must be valid tokens distinct from the built-in ones
+    for method in ["get", "NOT A METHOD", ""] {
+        let result = project
+            .update_resource(
+                &ctx,
+                &app_delegate,
+                update_input(ResourceProtocol::Custom(method.to_string())),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn test_item_entry_update_description() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let input = CreateResourceInput::Item(CreateItemResourceParams {
+        path: Default::default(),
+        class: ResourceClass::Endpoint,
+        name: random_entry_name(),
+        description: Some("Lists the pets".to_string()),
+        order: 0,
+        protocol: Some(ResourceProtocol::Get),
+        url: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    });
+
+    let id = project
+        .create_resource::<MockAppRuntime>(&ctx, input)
+        .await
+        .unwrap()
+        .id;
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.description.as_deref(), Some("Lists the pets"));
+
+    let update_input = |description: ChangeString| {
+        UpdateResourceInput::Item(UpdateItemResourceParams {
+            id: id.clone(),
+            path: None,
+            name: None,
+            description: Some(description),
+            protocol: None,
+            url: None,
+            headers_to_add: vec![],
+            headers_to_update: vec![],
+            headers_to_remove: vec![],
+            path_params_to_add: vec![],
+            path_params_to_update: vec![],
+            path_params_to_remove: vec![],
+            query_params_to_add: vec![],
+            query_params_to_update: vec![],
+            query_params_to_remove: vec![],
+            body: None,
+            body_schema: None,
+            schema: None,
+            components_to_add: vec![],
+            components_to_remove: vec![],
+            auth: None,
+            websocket: None,
+            graphql: None,
+            grpc: None,
+            sse: None,
+            amqp: None,
+            mqtt: None,
+        })
+    };
+
+    project
+        .update_resource(
+            &ctx,
+            &app_delegate,
+            update_input(ChangeString::Update("Lists\nthe pets".to_string())),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.description.as_deref(), Some("Lists\nthe pets"));
+
+    project
+        .update_resource(&ctx, &app_delegate, update_input(ChangeString::Remove))
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.description, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_component_returns_dependents() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let component_id = create_test_component(&ctx, &project).await;
+    let endpoint_id = create_endpoint_with_components(&ctx, &project, vec![])
+        .await
+        .unwrap();
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                components_to_add: vec![component_id.clone()],
+                ..test_update_item_input(&endpoint_id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, component_id.clone())
+        .await
+        .unwrap();
+    let authorization_id = desc
+        .headers
+        .iter()
+        .find(|h| h.name == "Authorization")
+        .unwrap()
+        .id
+        .clone();
+
+    let output = project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                headers_to_update: vec![UpdateHeaderParams {
+                    id: authorization_id,
+                    name: None,
+                    value: Some(ChangeJsonValue::Update(json!("Basic {{credentials}}"))),
+                    order: None,
+                    description: None,
+                    options: None,
+                }],
+                ..test_update_item_input(&component_id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let UpdateResourceOutput::Item(output) = output else {
+        panic!("expected an item update");
+    };
+    assert_eq!(output.dependents, vec![endpoint_id.clone()]);
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, endpoint_id.clone())
+        .await
+        .unwrap();
+    let authorization = desc
+        .headers
+        .iter()
+        .find(|h| h.name == "Authorization")
+        .unwrap();
+    assert_eq!(authorization.value, json!("Basic {{credentials}}"));
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                components_to_remove: vec![component_id],
+                ..test_update_item_input(&endpoint_id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, endpoint_id)
+        .await
+        .unwrap();
+    assert!(desc.components.is_empty());
+    assert_eq!(desc.headers.len(), 1);
+    assert_eq!(desc.body, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_item_entry_auth() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        endpoint_input(PathBuf::from(RESOURCES_ROOT_DIR), None),
+    )
+    .await
+    .unwrap();
+
+    let scheme = AuthScheme::Digest {
+        username: "admin".to_string(),
+        password: "{{password}}".to_string(),
+    };
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                auth: Some(ChangeAuth::Update(scheme.clone())),
+                ..test_update_item_input(&id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(desc.auth.map(|auth| auth.scheme), Some(scheme));
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                auth: Some(ChangeAuth::Remove),
+                ..test_update_item_input(&id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.auth, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_dir_entry_auth() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let (dir_id, dir_path) = create_test_dir(&ctx, &project, None).await;
+    let item_id = create_test_item(&ctx, &project, endpoint_input(dir_path, None))
+        .await
+        .unwrap();
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Dir(UpdateDirResourceParams {
+                id: dir_id.clone(),
+                path: None,
+                name: None,
+                description: None,
+                order: None,
+                expanded: None,
+                headers_to_add: vec![],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![],
+                query_params_to_remove: vec![],
+                auth: Some(ChangeAuth::Update(bearer("{{token}}"))),
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, dir_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.auth,
+        Some(AuthInfo {
+            scheme: bearer("{{token}}"),
+            inherited_from: None,
+        })
+    );
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, item_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.auth,
+        Some(AuthInfo {
+            scheme: bearer("{{token}}"),
+            inherited_from: Some(dir_id),
+        })
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_websocket_messages() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        websocket_input(ResourceProtocol::WebSocket, None),
+    )
+    .await
+    .unwrap();
+
+    // Messages can be saved to an endpoint created without any
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_websocket_input(
+                &id,
+                UpdateWebSocketParams {
+                    subprotocols: Some(vec!["chat".to_string()]),
+                    messages_to_add: vec![
+                        message("Ping", WebSocketMessagePayload::Text("ping".to_string())),
+                        message("Bye", WebSocketMessagePayload::Text("bye".to_string())),
+                    ],
+                    messages_to_update: vec![],
+                    messages_to_remove: vec![],
+                },
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let websocket = desc.websocket.unwrap();
+    assert_eq!(websocket.subprotocols, vec!["chat"]);
+    assert_eq!(websocket.messages.len(), 2);
+    let ping_id = websocket.messages[0].id.clone();
+    let bye_id = websocket.messages[1].id.clone();
+
+    // Changing the kind of the payload replaces the previous one
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_websocket_input(
+                &id,
+                UpdateWebSocketParams {
+                    subprotocols: None,
+                    messages_to_add: vec![],
+                    messages_to_update: vec![UpdateWebSocketMessageParams {
+                        id: ping_id.clone(),
+                        name: Some("Ping as json".to_string()),
+                        payload: Some(WebSocketMessagePayload::Json(json!({ "type": "ping" }))),
+                    }],
+                    messages_to_remove: vec![bye_id],
+                },
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let websocket = desc.websocket.unwrap();
+    assert_eq!(websocket.subprotocols, vec!["chat"]);
+    assert_eq!(websocket.messages.len(), 1);
+    assert_eq!(websocket.messages[0].id, ping_id);
+    assert_eq!(websocket.messages[0].name, "Ping as json");
+    assert_eq!(
+        websocket.messages[0].payload,
+        WebSocketMessagePayload::Json(json!({ "type": "ping" }))
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_websocket_messages_of_http_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(&ctx, &project, websocket_input(ResourceProtocol::Get, None))
+        .await
+        .unwrap();
+
+    let result = project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_websocket_input(
+                &id,
+                UpdateWebSocketParams {
+                    subprotocols: None,
+                    messages_to_add: vec![message(
+                        "Ping",
+                        WebSocketMessagePayload::Text("ping".to_string()),
+                    )],
+                    messages_to_update: vec![],
+                    messages_to_remove: vec![],
+                },
+            )),
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_graphql_settings() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        graphql_input(ResourceProtocol::Graphql, None),
+    )
+    .await
+    .unwrap();
+
+    // The settings can be saved to an endpoint created without any
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_graphql_input(
+                &id,
+                UpdateGraphqlParams {
+                    query: Some(GRAPHQL_QUERY.to_string()),
+                    operation_name: Some(ChangeString::Update("User".to_string())),
+                    variables: Some(ChangeJsonValue::Update(json!({ "id": "1" }))),
+                },
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let graphql = desc.graphql.unwrap();
+    assert_eq!(graphql.query, GRAPHQL_QUERY);
+    assert_eq!(graphql.operation_name, Some("User".to_string()));
+    assert_eq!(graphql.variables, Some(json!({ "id": "1" })));
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_graphql_input(
+                &id,
+                UpdateGraphqlParams {
+                    query: None,
+                    operation_name: Some(ChangeString::Remove),
+                    variables: Some(ChangeJsonValue::Remove),
+                },
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let graphql = desc.graphql.unwrap();
+    assert_eq!(graphql.query, GRAPHQL_QUERY);
+    assert_eq!(graphql.operation_name, None);
+    assert_eq!(graphql.variables, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_graphql_settings_of_http_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(&ctx, &project, graphql_input(ResourceProtocol::Post, None))
+        .await
+        .unwrap();
+
+    let result = project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_graphql_input(
+                &id,
+                UpdateGraphqlParams {
+                    query: Some(GRAPHQL_QUERY.to_string()),
+                    operation_name: None,
+                    variables: None,
+                },
+            )),
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_grpc_settings() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        grpc_input(ResourceProtocol::Grpc, Some(grpc_params())),
+    )
+    .await
+    .unwrap();
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_grpc_input(
+                &id,
+                UpdateGrpcParams {
+                    proto_files: Some(vec![]),
+                    import_paths: None,
+                    service: Some("routeguide.RouteGuide".to_string()),
+                    method: Some("RouteChat".to_string()),
+                    messages: Some(vec![json!({ "message": "a" }), json!({ "message": "b" })]),
+                    timeout_ms: Some(ChangeUsize::Remove),
+                },
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let grpc = desc.grpc.unwrap();
+    assert!(grpc.proto_files.is_empty());
+    assert_eq!(grpc.service, "routeguide.RouteGuide");
+    assert_eq!(grpc.method, "RouteChat");
+    assert_eq!(
+        grpc.messages,
+        vec![json!({ "message": "a" }), json!({ "message": "b" })]
+    );
+    assert_eq!(grpc.timeout_ms, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_grpc_settings_of_http_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(&ctx, &project, grpc_input(ResourceProtocol::Post, None))
+        .await
+        .unwrap();
+
+    let result = project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_grpc_input(
+                &id,
+                UpdateGrpcParams {
+                    proto_files: None,
+                    import_paths: None,
+                    service: None,
+                    method: None,
+                    messages: None,
+                    timeout_ms: Some(ChangeUsize::Update(1000)),
+                },
+            )),
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_sse_settings() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(&ctx, &project, sse_input(ResourceProtocol::Post, None))
+        .await
+        .unwrap();
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_sse_input(
+                &id,
+                ChangeSse::Update(SseParams {
+                    reconnect: false,
+                    retry_ms: None,
+                    max_reconnects: None,
+                    record: true,
+                }),
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        desc.sse,
+        Some(SseInfo {
+            reconnect: false,
+            retry_ms: None,
+            max_reconnects: None,
+            record: true,
+        })
+    );
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_sse_input(&id, ChangeSse::Remove)),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.sse, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_sse_settings_of_non_http_endpoint() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(&ctx, &project, sse_input(ResourceProtocol::WebSocket, None))
+        .await
+        .unwrap();
+
+    let result = project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_sse_input(&id, ChangeSse::Update(sse_params()))),
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_amqp_settings() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Amqp, None, None),
+    )
+    .await
+    .unwrap();
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_queue_input(
+                &id,
+                Some(ChangeAmqp::Update(Box::new(AmqpParams {
+                    queue: Some("orders".to_string()),
+                    ..amqp_params()
+                }))),
+                None,
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let amqp = desc.amqp.unwrap();
+    assert_eq!(amqp.queue.as_deref(), Some("orders"));
+    assert_eq!(amqp.routing_key, "orders.created");
+    assert!(amqp.persistent);
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_queue_input(&id, Some(ChangeAmqp::Remove), None)),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.amqp, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_mqtt_settings() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Mqtt, None, Some(mqtt_params())),
+    )
+    .await
+    .unwrap();
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_queue_input(
+                &id,
+                None,
+                Some(ChangeMqtt::Update(Box::new(MqttParams {
+                    version: MqttVersion::V311,
+                    qos: 2,
+                    retain: true,
+                    ..mqtt_params()
+                }))),
+            )),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id.clone())
+        .await
+        .unwrap();
+    let mqtt = desc.mqtt.unwrap();
+    assert_eq!(mqtt.version, MqttVersion::V311);
+    assert_eq!(mqtt.qos, 2);
+    assert!(mqtt.retain);
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(update_queue_input(&id, None, Some(ChangeMqtt::Remove))),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, id)
+        .await
+        .unwrap();
+    assert_eq!(desc.mqtt, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_queue_settings_of_another_protocol() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let id = create_test_item(
+        &ctx,
+        &project,
+        queue_input(ResourceProtocol::Post, None, None),
+    )
+    .await
+    .unwrap();
+
+    for (amqp, mqtt) in [
+        (Some(ChangeAmqp::Update(Box::new(amqp_params()))), None),
+        (None, Some(ChangeMqtt::Update(Box::new(mqtt_params())))),
+    ] {
+        let result = project
+            .update_resource::<MockAppRuntime>(
+                &ctx,
+                &app_delegate,
+                UpdateResourceInput::Item(update_queue_input(&id, amqp, mqtt)),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    cleanup().await;
+}
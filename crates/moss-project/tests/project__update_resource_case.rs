@@ -0,0 +1,231 @@
+#![cfg(feature = "integration-tests")]
+
+mod shared;
+
+use moss_applib::mock::MockAppRuntime;
+use moss_bindingutils::primitives::ChangeJsonValue;
+use moss_project::{
+    Project,
+    models::{
+        operations::{
+            CreateResourceCaseInput, CreateResourceInput, ListResourceCasesInput,
+            UpdateResourceCaseInput,
+        },
+        types::{
+            ChangeCaseBody, ChangeExpectedResponse, CreateItemResourceParams, ExpectedResponse,
+            http::{
+                AddBodyParams, AddHeaderParams, AddQueryParamParams, HeaderParamOptions,
+                QueryParamOptions, UpdateQueryParamParams,
+            },
+        },
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
+use serde_json::json;
+use std::path::PathBuf;
+
+use crate::shared::{RESOURCES_ROOT_DIR, create_test_project, random_entry_name};
+
+fn query_param(name: &str, value: &str) -> AddQueryParamParams {
+    AddQueryParamParams {
+        name: name.to_string(),
+        value: json!(value),
+        order: 0,
+        description: None,
+        options: QueryParamOptions {
+            disabled: false,
+            propagate: false,
+        },
+    }
+}
+
+fn case_input(resource_id: &ResourceId, name: &str) -> CreateResourceCaseInput {
+    CreateResourceCaseInput {
+        resource_id: resource_id.clone(),
+        name: name.to_string(),
+        description: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        expected_response: None,
+    }
+}
+
+async fn create_test_endpoint(ctx: &ArcContext, project: &Project, name: &str) -> ResourceId {
+    project
+        .create_resource::<MockAppRuntime>(
+            ctx,
+            CreateResourceInput::Item(CreateItemResourceParams {
+                class: ResourceClass::Endpoint,
+                path: PathBuf::from(RESOURCES_ROOT_DIR),
+                name: name.to_string(),
+                description: None,
+                order: 0,
+                protocol: Some(ResourceProtocol::Get),
+                url: Some("https://example.com/users".to_string()),
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![query_param("page", "1")],
+                body: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
+            }),
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn update_resource_case_success() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let resource_id = create_test_endpoint(&ctx, &project, &random_entry_name()).await;
+
+    let id = project
+        .create_resource_case::<MockAppRuntime>(
+            &ctx,
+            CreateResourceCaseInput {
+                query_params: vec![query_param("page", "2")],
+                body: Some(AddBodyParams::Text("text".to_string())),
+                ..case_input(&resource_id, "Second page")
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+
+    let cases = project
+        .list_resource_cases(
+            &ctx,
+            &app_delegate,
+            ListResourceCasesInput {
+                resource_id: resource_id.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .cases;
+    let query_param_id = cases[0].query_params[0].id.clone();
+
+    project
+        .update_resource_case::<MockAppRuntime>(
+            &ctx,
+            UpdateResourceCaseInput {
+                resource_id: resource_id.clone(),
+                id: id.clone(),
+                name: Some("Third page".to_string()),
+                description: None,
+                headers_to_add: vec![AddHeaderParams {
+                    name: "Accept".to_string(),
+                    value: json!("application/xml"),
+                    order: 0,
+                    description: None,
+                    options: HeaderParamOptions {
+                        disabled: false,
+                        propagate: false,
+                    },
+                }],
+                headers_to_update: vec![],
+                headers_to_remove: vec![],
+                path_params_to_add: vec![],
+                path_params_to_update: vec![],
+                path_params_to_remove: vec![],
+                query_params_to_add: vec![],
+                query_params_to_update: vec![UpdateQueryParamParams {
+                    id: query_param_id.clone(),
+                    name: None,
+                    value: Some(ChangeJsonValue::Update(json!("3"))),
+                    order: None,
+                    description: None,
+                    options: None,
+                }],
+                query_params_to_remove: vec![],
+                body: Some(ChangeCaseBody::Remove),
+                expected_response: Some(ChangeExpectedResponse::Update(ExpectedResponse {
+                    status: Some(404),
+                    headers: None,
+                    body: None,
+                })),
+            },
+        )
+        .await
+        .unwrap();
+
+    let cases = project
+        .list_resource_cases(
+            &ctx,
+            &app_delegate,
+            ListResourceCasesInput {
+                resource_id: resource_id.clone(),
+            },
+        )
+        .await
+        .unwrap()
+        .cases;
+
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].name, "Third page");
+    assert_eq!(cases[0].headers.len(), 1);
+    assert_eq!(cases[0].headers[0].name, "Accept");
+    assert_eq!(cases[0].query_params[0].id, query_param_id);
+    assert_eq!(cases[0].query_params[0].value, json!("3"));
+    assert_eq!(cases[0].body, None);
+    assert_eq!(
+        cases[0].expected_response.as_ref().unwrap().status,
+        Some(404)
+    );
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_resource_case_invalid_name() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let resource_id = create_test_endpoint(&ctx, &project, &random_entry_name()).await;
+
+    let id = project
+        .create_resource_case::<MockAppRuntime>(&ctx, case_input(&resource_id, "Second page"))
+        .await
+        .unwrap()
+        .id;
+
+    for name in ["", "Second/page", "Second\\page"] {
+        let result = project
+            .update_resource_case::<MockAppRuntime>(
+                &ctx,
+                UpdateResourceCaseInput {
+                    resource_id: resource_id.clone(),
+                    id: id.clone(),
+                    name: Some(name.to_string()),
+                    description: None,
+                    headers_to_add: vec![],
+                    headers_to_update: vec![],
+                    headers_to_remove: vec![],
+                    path_params_to_add: vec![],
+                    path_params_to_update: vec![],
+                    path_params_to_remove: vec![],
+                    query_params_to_add: vec![],
+                    query_params_to_update: vec![],
+                    query_params_to_remove: vec![],
+                    body: None,
+                    expected_response: None,
+                },
+            )
+            .await;
+        assert!(result.is_err(), "case name {name:?} should be rejected");
+    }
+
+    cleanup().await;
+}
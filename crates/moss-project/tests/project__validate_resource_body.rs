@@ -0,0 +1,365 @@
+#![cfg(feature = "integration-tests")]
+
+mod shared;
+
+use moss_applib::mock::MockAppRuntime;
+use moss_project::{
+    Project,
+    constants::SCHEMA_FILENAME,
+    dirs::RESOURCES_DIR,
+    errors::{ErrorInvalidInput, ErrorInvalidKind},
+    models::{
+        operations::{CreateResourceInput, UpdateResourceInput, ValidateResourceBodyInput},
+        types::{
+            ChangeBodySchema, CreateItemResourceParams, UpdateItemResourceParams,
+            http::AddBodyParams,
+        },
+    },
+};
+use sapic_base::resource::types::primitives::{ResourceClass, ResourceId, ResourceProtocol};
+use sapic_core::context::ArcContext;
+use serde_json::{Value as JsonValue, json};
+use std::path::PathBuf;
+
+use crate::shared::{RESOURCES_ROOT_DIR, create_test_project, random_entry_name};
+
+fn user_schema() -> JsonValue {
+    json!({
+        "type": "object",
+        "required": ["name"],
+        "properties": {
+            "name": { "type": "string" },
+            "age": { "type": "integer", "minimum": 0 }
+        }
+    })
+}
+
+fn item_input(class: ResourceClass, name: &str) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(RESOURCES_ROOT_DIR),
+        class,
+        name: name.to_string(),
+        description: None,
+        order: 0,
+        protocol: None,
+        url: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+fn update_input(id: &ResourceId) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        id: id.clone(),
+        path: None,
+        name: None,
+        description: None,
+        protocol: None,
+        url: None,
+        headers_to_add: vec![],
+        headers_to_update: vec![],
+        headers_to_remove: vec![],
+        path_params_to_add: vec![],
+        path_params_to_update: vec![],
+        path_params_to_remove: vec![],
+        query_params_to_add: vec![],
+        query_params_to_update: vec![],
+        query_params_to_remove: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components_to_add: vec![],
+        components_to_remove: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+async fn create_item(
+    ctx: &ArcContext,
+    project: &Project,
+    input: CreateItemResourceParams,
+) -> joinerror::Result<ResourceId> {
+    project
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(input))
+        .await
+        .map(|output| output.id)
+}
+
+async fn create_test_endpoint(
+    ctx: &ArcContext,
+    project: &Project,
+    body: JsonValue,
+    body_schema: Option<ResourceId>,
+) -> joinerror::Result<ResourceId> {
+    create_item(
+        ctx,
+        project,
+        CreateItemResourceParams {
+            protocol: Some(ResourceProtocol::Post),
+            url: Some("https://example.com/users".to_string()),
+            body: Some(AddBodyParams::Json(body)),
+            body_schema,
+            ..item_input(ResourceClass::Endpoint, &random_entry_name())
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn validate_resource_body_success() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let schema_id = create_item(
+        &ctx,
+        &project,
+        CreateItemResourceParams {
+            schema: Some(user_schema()),
+            ..item_input(ResourceClass::Schema, &random_entry_name())
+        },
+    )
+    .await
+    .unwrap();
+    let endpoint_id = create_test_endpoint(
+        &ctx,
+        &project,
+        json!({ "age": -1, "extra": true }),
+        Some(schema_id.clone()),
+    )
+    .await
+    .unwrap();
+
+    let violations = project
+        .validate_resource_body::<MockAppRuntime>(
+            &ctx,
+            ValidateResourceBodyInput {
+                id: endpoint_id.clone(),
+                body: None,
+            },
+        )
+        .await
+        .unwrap()
+        .violations;
+
+    let mut pointers = violations
+        .iter()
+        .map(|violation| violation.pointer.as_str())
+        .collect::<Vec<_>>();
+    pointers.sort();
+    assert_eq!(pointers, vec!["", "/age"]);
+
+    // The body under edition takes precedence over the stored one
+    let violations = project
+        .validate_resource_body::<MockAppRuntime>(
+            &ctx,
+            ValidateResourceBodyInput {
+                id: endpoint_id.clone(),
+                body: Some(json!({ "name": "John", "age": 42 })),
+            },
+        )
+        .await
+        .unwrap()
+        .violations;
+    assert!(violations.is_empty());
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, endpoint_id)
+        .await
+        .unwrap();
+    assert_eq!(desc.body_schema, Some(schema_id.clone()));
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, schema_id)
+        .await
+        .unwrap();
+    assert_eq!(desc.schema, Some(user_schema()));
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn validate_resource_body_adjacent_schema_file() {
+    let (ctx, app_delegate, project_path, project, cleanup) = create_test_project().await;
+
+    let schema_name = random_entry_name();
+    let schema_id = create_item(
+        &ctx,
+        &project,
+        item_input(ResourceClass::Schema, &schema_name),
+    )
+    .await
+    .unwrap();
+    let schema_path = project_path
+        .join(RESOURCES_DIR)
+        .join(&schema_name)
+        .join(SCHEMA_FILENAME);
+    tokio::fs::write(&schema_path, user_schema().to_string())
+        .await
+        .unwrap();
+
+    let endpoint_id = create_test_endpoint(&ctx, &project, json!({}), Some(schema_id.clone()))
+        .await
+        .unwrap();
+
+    let violations = project
+        .validate_resource_body::<MockAppRuntime>(
+            &ctx,
+            ValidateResourceBodyInput {
+                id: endpoint_id,
+                body: None,
+            },
+        )
+        .await
+        .unwrap()
+        .violations;
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].message, "\"name\" is a required property");
+
+    // Updates are written back to the file instead of the config
+    let schema = json!({ "type": "object" });
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                schema: Some(schema.clone()),
+                ..update_input(&schema_id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let content = tokio::fs::read_to_string(&schema_path).await.unwrap();
+    assert_eq!(serde_json::from_str::<JsonValue>(&content).unwrap(), schema);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn update_resource_body_schema() {
+    let (ctx, app_delegate, _, project, cleanup) = create_test_project().await;
+
+    let schema_id = create_item(
+        &ctx,
+        &project,
+        CreateItemResourceParams {
+            schema: Some(user_schema()),
+            ..item_input(ResourceClass::Schema, &random_entry_name())
+        },
+    )
+    .await
+    .unwrap();
+    let endpoint_id = create_test_endpoint(&ctx, &project, json!({ "name": 1 }), None)
+        .await
+        .unwrap();
+
+    let result = project
+        .validate_resource_body::<MockAppRuntime>(
+            &ctx,
+            ValidateResourceBodyInput {
+                id: endpoint_id.clone(),
+                body: None,
+            },
+        )
+        .await;
+    assert!(result.unwrap_err().is::<ErrorInvalidInput>());
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                body_schema: Some(ChangeBodySchema::Update(schema_id.clone())),
+                ..update_input(&endpoint_id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let violations = project
+        .validate_resource_body::<MockAppRuntime>(
+            &ctx,
+            ValidateResourceBodyInput {
+                id: endpoint_id.clone(),
+                body: None,
+            },
+        )
+        .await
+        .unwrap()
+        .violations;
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].pointer, "/name");
+
+    project
+        .update_resource::<MockAppRuntime>(
+            &ctx,
+            &app_delegate,
+            UpdateResourceInput::Item(UpdateItemResourceParams {
+                body_schema: Some(ChangeBodySchema::Remove),
+                ..update_input(&endpoint_id)
+            }),
+        )
+        .await
+        .unwrap();
+
+    let desc = project
+        .describe_resource(&ctx, &app_delegate, endpoint_id)
+        .await
+        .unwrap();
+    assert_eq!(desc.body_schema, None);
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_resource_body_schema_not_a_schema() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let component_id = create_item(
+        &ctx,
+        &project,
+        item_input(ResourceClass::Component, &random_entry_name()),
+    )
+    .await
+    .unwrap();
+
+    let result = create_test_endpoint(&ctx, &project, json!({}), Some(component_id)).await;
+    assert!(result.unwrap_err().is::<ErrorInvalidKind>());
+
+    cleanup().await;
+}
+
+#[tokio::test]
+async fn create_resource_schema_not_a_schema_resource() {
+    let (ctx, _, _, project, cleanup) = create_test_project().await;
+
+    let result = create_item(
+        &ctx,
+        &project,
+        CreateItemResourceParams {
+            schema: Some(user_schema()),
+            ..item_input(ResourceClass::Component, &random_entry_name())
+        },
+    )
+    .await;
+    assert!(result.unwrap_err().is::<ErrorInvalidInput>());
+
+    cleanup().await;
+}
@@ -9,7 +9,7 @@ use moss_project::{
     builder::ProjectCreateParams,
     models::{
         operations::CreateResourceInput,
-        types::{CreateDirResourceParams, CreateItemResourceParams},
+        types::{CreateDirResourceParams, CreateItemResourceParams, UpdateItemResourceParams},
     },
     project::Project,
 };
@@ -159,7 +159,12 @@ pub async fn create_test_endpoint_dir_entry(
                 class: ResourceClass::Endpoint,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
             }),
         )
         .await
@@ -180,7 +185,12 @@ pub async fn create_test_component_dir_entry(
                 class: ResourceClass::Component,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
             }),
         )
         .await
@@ -201,6 +211,7 @@ pub async fn create_test_component_item_entry(
                 class: ResourceClass::Component,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
                 protocol: None,
                 query_params: vec![],
@@ -208,6 +219,16 @@ pub async fn create_test_component_item_entry(
                 headers: vec![],
                 body: None,
                 url: None,
+                body_schema: None,
+                schema: None,
+                components: vec![],
+                auth: None,
+                websocket: None,
+                graphql: None,
+                grpc: None,
+                sse: None,
+                amqp: None,
+                mqtt: None,
             }),
         )
         .await
@@ -228,10 +249,89 @@ pub async fn create_test_schema_dir_entry(
                 class: ResourceClass::Schema,
                 path: PathBuf::from(""),
                 name: name.to_string(),
+                description: None,
                 order: 0,
+                headers: vec![],
+                path_params: vec![],
+                query_params: vec![],
+                auth: None,
             }),
         )
         .await
         .unwrap()
         .id
 }
+
+/// Input of an item with only its class set, to be completed with the fields under test.
+#[allow(dead_code)]
+pub fn test_item_input(class: ResourceClass) -> CreateItemResourceParams {
+    CreateItemResourceParams {
+        path: PathBuf::from(RESOURCES_ROOT_DIR),
+        class,
+        name: random_entry_name(),
+        description: None,
+        order: 0,
+        protocol: None,
+        url: None,
+        headers: vec![],
+        path_params: vec![],
+        query_params: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+/// Input of an item update that changes nothing, to be completed with the fields under test.
+#[allow(dead_code)]
+pub fn test_update_item_input(id: &ResourceId) -> UpdateItemResourceParams {
+    UpdateItemResourceParams {
+        id: id.clone(),
+        path: None,
+        name: None,
+        description: None,
+        protocol: None,
+        url: None,
+        headers_to_add: vec![],
+        headers_to_update: vec![],
+        headers_to_remove: vec![],
+        path_params_to_add: vec![],
+        path_params_to_update: vec![],
+        path_params_to_remove: vec![],
+        query_params_to_add: vec![],
+        query_params_to_update: vec![],
+        query_params_to_remove: vec![],
+        body: None,
+        body_schema: None,
+        schema: None,
+        components_to_add: vec![],
+        components_to_remove: vec![],
+        auth: None,
+        websocket: None,
+        graphql: None,
+        grpc: None,
+        sse: None,
+        amqp: None,
+        mqtt: None,
+    }
+}
+
+#[allow(dead_code)]
+pub async fn create_test_item(
+    ctx: &AsyncContext,
+    project: &Project,
+    input: CreateItemResourceParams,
+) -> joinerror::Result<ResourceId> {
+    project
+        .create_resource::<MockAppRuntime>(ctx, CreateResourceInput::Item(input))
+        .await
+        .map(|output| output.id)
+}
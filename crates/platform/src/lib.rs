@@ -9,3 +9,4 @@ pub mod workspace;
 pub mod environment;
 pub mod github;
 pub mod gitlab;
+pub mod oauth;
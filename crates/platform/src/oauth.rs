@@ -0,0 +1,313 @@
+pub mod loopback;
+
+use chrono::Utc;
+use joinerror::Error;
+use moss_keyring::KeyringClient;
+use oauth2::{
+    AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, DeviceAuthorizationUrl,
+    EndpointMaybeSet, EndpointNotSet, EndpointSet, PkceCodeChallenge, RedirectUrl, RefreshToken,
+    Scope, StandardDeviceAuthorizationResponse, TokenResponse, TokenUrl,
+    basic::{BasicClient, BasicTokenResponse},
+};
+use sapic_base::workspace::types::primitives::WorkspaceId;
+use sapic_core::context::{self, AnyAsyncContext, ContextResultExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{borrow::Cow, sync::Arc};
+
+use crate::oauth::loopback::LoopbackListener;
+
+/// Cached tokens are refreshed this many seconds before they expire,
+/// so that they don't expire while a request is in flight.
+const REFRESH_MARGIN_SECS: i64 = 60;
+
+type Client =
+    BasicClient<EndpointMaybeSet, EndpointMaybeSet, EndpointNotSet, EndpointNotSet, EndpointSet>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OAuth2Grant {
+    AuthorizationCode,
+    ClientCredentials,
+    DeviceCode,
+    RefreshToken,
+}
+
+/// OAuth 2.0 client of an API under test, with its secrets already resolved.
+#[derive(Debug, Clone)]
+pub struct OAuth2Config {
+    pub grant: OAuth2Grant,
+    pub auth_url: Option<String>,
+    pub token_url: String,
+    pub device_auth_url: Option<String>,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scopes: Vec<String>,
+    pub refresh_token: Option<String>,
+    pub callback_port: Option<u16>,
+}
+
+impl OAuth2Config {
+    /// Identifies the tokens issued for the config in the workspace. Secrets are left out,
+    /// so rotating them doesn't discard the tokens already issued.
+    fn cache_key(&self, workspace_id: &WorkspaceId) -> String {
+        let grant = match self.grant {
+            OAuth2Grant::AuthorizationCode => "authorization_code",
+            OAuth2Grant::ClientCredentials => "client_credentials",
+            OAuth2Grant::DeviceCode => "device_code",
+            OAuth2Grant::RefreshToken => "refresh_token",
+        };
+
+        let mut hasher = Sha256::new();
+        for part in [
+            grant,
+            &self.token_url,
+            self.auth_url.as_deref().unwrap_or_default(),
+            self.device_auth_url.as_deref().unwrap_or_default(),
+            &self.client_id,
+            &self.scopes.join(" "),
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+
+        format!("oauth2/{}/{:x}", workspace_id, hasher.finalize())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    /// Unix timestamp in seconds, none if the token doesn't expire.
+    expires_at: Option<i64>,
+}
+
+impl CachedToken {
+    fn new(response: BasicTokenResponse, fallback_refresh_token: Option<String>) -> Self {
+        Self {
+            access_token: response.access_token().secret().clone(),
+            // Servers may keep the refresh token the same without returning it again
+            refresh_token: response
+                .refresh_token()
+                .map(|token| token.secret().clone())
+                .or(fallback_refresh_token),
+            expires_at: response
+                .expires_in()
+                .map(|expires_in| Utc::now().timestamp() + expires_in.as_secs() as i64),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| expires_at - REFRESH_MARGIN_SECS > Utc::now().timestamp())
+    }
+}
+
+/// Involves the user in the grants that need their consent.
+pub trait OAuth2Prompt: Send + Sync {
+    /// Sends the user to the authorization page of the server.
+    fn open_authorization_url(&self, url: &str);
+
+    /// Asks the user to enter `user_code` on the verification page of the server.
+    fn show_device_code(&self, verification_uri: &str, user_code: &str);
+}
+
+/// Obtains access tokens for APIs under test, caching them in the keyring.
+pub struct OAuth2Client {
+    // Must not follow redirects, see `oauth2::AsyncHttpClient`
+    http_client: reqwest::Client,
+    keyring: Arc<dyn KeyringClient>,
+    prompt: Arc<dyn OAuth2Prompt>,
+}
+
+impl OAuth2Client {
+    pub fn new(
+        http_client: reqwest::Client,
+        keyring: Arc<dyn KeyringClient>,
+        prompt: Arc<dyn OAuth2Prompt>,
+    ) -> Self {
+        Self {
+            http_client,
+            keyring,
+            prompt,
+        }
+    }
+
+    /// Returns an access token for `config`, reusing the cached one until it is about to expire.
+    /// Expiring tokens are refreshed, and the grant is run again if that isn't possible.
+    pub async fn access_token(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        workspace_id: &WorkspaceId,
+        config: &OAuth2Config,
+    ) -> joinerror::Result<String> {
+        let key = config.cache_key(workspace_id);
+        let cached = self.cached_token(&key).await;
+        if let Some(cached) = &cached
+            && cached.is_fresh()
+        {
+            return Ok(cached.access_token.clone());
+        }
+
+        let client = build_client(config)?;
+        let refresh_token = cached.and_then(|cached| cached.refresh_token);
+        let token = context::abortable(ctx, async {
+            if let Some(refresh_token) = refresh_token {
+                match self.refresh(&client, config, refresh_token).await {
+                    Ok(token) => return Ok(token),
+                    Err(e) => tracing::warn!("failed to refresh oauth2 token: {}", e),
+                }
+            }
+
+            self.request_token(ctx, &client, config).await
+        })
+        .await
+        .join_err_bare()?;
+
+        match serde_json::to_string(&token) {
+            Ok(secret) => {
+                if let Err(e) = self.keyring.set_secret(&key, &secret).await {
+                    tracing::warn!("failed to cache oauth2 token: {}", e);
+                }
+            }
+            Err(e) => tracing::warn!("failed to serialize oauth2 token: {}", e),
+        }
+
+        Ok(token.access_token)
+    }
+
+    async fn cached_token(&self, key: &str) -> Option<CachedToken> {
+        // A missing secret is reported as an error by the keyring
+        let bytes = self.keyring.get_secret(key).await.ok()?;
+        serde_json::from_slice(&bytes)
+            .inspect_err(|e| tracing::warn!("failed to parse cached oauth2 token: {}", e))
+            .ok()
+    }
+
+    async fn request_token(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        client: &Client,
+        config: &OAuth2Config,
+    ) -> joinerror::Result<CachedToken> {
+        let scopes = config.scopes.iter().cloned().map(Scope::new);
+
+        let response = match config.grant {
+            OAuth2Grant::AuthorizationCode => {
+                let listener = LoopbackListener::bind(config.callback_port.unwrap_or(0)).await?;
+                let redirect_uri = RedirectUrl::new(listener.redirect_uri())
+                    .map_err(|e| Error::new::<()>(format!("invalid redirect uri: {}", e)))?;
+                let (challenge, verifier) = PkceCodeChallenge::new_random_sha256();
+
+                let (url, state) = client
+                    .authorize_url(CsrfToken::new_random)
+                    .map_err(|e| {
+                        Error::new::<()>(format!("failed to build authorization url: {}", e))
+                    })?
+                    .add_scopes(scopes)
+                    .set_pkce_challenge(challenge)
+                    .set_redirect_uri(Cow::Borrowed(&redirect_uri))
+                    .url();
+                self.prompt.open_authorization_url(url.as_str());
+
+                let code = listener.receive_code(ctx, &state).await?;
+
+                client
+                    .exchange_code(AuthorizationCode::new(code))
+                    .set_pkce_verifier(verifier)
+                    .set_redirect_uri(Cow::Borrowed(&redirect_uri))
+                    .request_async(&self.http_client)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            OAuth2Grant::ClientCredentials => client
+                .exchange_client_credentials()
+                .add_scopes(scopes)
+                .request_async(&self.http_client)
+                .await
+                .map_err(|e| e.to_string()),
+            OAuth2Grant::DeviceCode => {
+                let details: StandardDeviceAuthorizationResponse = client
+                    .exchange_device_code()
+                    .map_err(|e| {
+                        Error::new::<()>(format!(
+                            "failed to build device authorization request: {}",
+                            e
+                        ))
+                    })?
+                    .add_scopes(scopes)
+                    .request_async(&self.http_client)
+                    .await
+                    .map_err(|e| {
+                        Error::new::<()>(format!("failed to request device code: {}", e))
+                    })?;
+                self.prompt.show_device_code(
+                    details.verification_uri().as_str(),
+                    details.user_code().secret(),
+                );
+
+                client
+                    .exchange_device_access_token(&details)
+                    .request_async(&self.http_client, tokio::time::sleep, None)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            OAuth2Grant::RefreshToken => {
+                let refresh_token = config
+                    .refresh_token
+                    .clone()
+                    .ok_or_else(|| Error::new::<()>("the refresh token grant needs a token"))?;
+
+                return self.refresh(client, config, refresh_token).await;
+            }
+        };
+
+        response
+            .map(|response| CachedToken::new(response, None))
+            .map_err(|e| Error::new::<()>(format!("failed to request oauth2 token: {}", e)))
+    }
+
+    async fn refresh(
+        &self,
+        client: &Client,
+        config: &OAuth2Config,
+        refresh_token: String,
+    ) -> joinerror::Result<CachedToken> {
+        let token = RefreshToken::new(refresh_token.clone());
+
+        client
+            .exchange_refresh_token(&token)
+            .add_scopes(config.scopes.iter().cloned().map(Scope::new))
+            .request_async(&self.http_client)
+            .await
+            .map(|response| CachedToken::new(response, Some(refresh_token)))
+            .map_err(|e| Error::new::<()>(format!("failed to refresh oauth2 token: {}", e)))
+    }
+}
+
+fn build_client(config: &OAuth2Config) -> joinerror::Result<Client> {
+    let auth_url = config
+        .auth_url
+        .clone()
+        .map(AuthUrl::new)
+        .transpose()
+        .map_err(|e| Error::new::<()>(format!("invalid oauth2 auth url: {}", e)))?;
+    let device_auth_url = config
+        .device_auth_url
+        .clone()
+        .map(DeviceAuthorizationUrl::new)
+        .transpose()
+        .map_err(|e| Error::new::<()>(format!("invalid oauth2 device auth url: {}", e)))?;
+    let token_url = TokenUrl::new(config.token_url.clone())
+        .map_err(|e| Error::new::<()>(format!("invalid oauth2 token url: {}", e)))?;
+
+    let client = BasicClient::new(ClientId::new(config.client_id.clone()))
+        .set_auth_uri_option(auth_url)
+        .set_device_authorization_url_option(device_auth_url)
+        .set_token_uri(token_url);
+
+    Ok(match &config.client_secret {
+        Some(secret) => client.set_client_secret(ClientSecret::new(secret.clone())),
+        None => client,
+    })
+}
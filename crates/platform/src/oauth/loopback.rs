@@ -0,0 +1,181 @@
+use joinerror::Error;
+use oauth2::CsrfToken;
+use sapic_core::context::{self, AnyAsyncContext, ContextResultExt};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    task::JoinSet,
+};
+use url::Url;
+
+const CALLBACK_PATH: &str = "/oauth/callback";
+const NOT_FOUND_RESPONSE: &str =
+    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+/// Receives the redirect of an authorization server on a loopback address.
+pub struct LoopbackListener {
+    listener: TcpListener,
+    port: u16,
+}
+
+impl LoopbackListener {
+    /// Binds to `port` on 127.0.0.1, or to a random free port if `port` is 0.
+    pub async fn bind(port: u16) -> joinerror::Result<Self> {
+        let addr = format!("127.0.0.1:{}", port);
+        let listener = TcpListener::bind(&addr)
+            .await
+            .map_err(|e| Error::new::<()>(format!("failed to bind to port {}: {}", addr, e)))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| Error::new::<()>(format!("failed to get local address: {}", e)))?
+            .port();
+
+        Ok(Self { listener, port })
+    }
+
+    pub fn redirect_uri(&self) -> String {
+        format!("http://127.0.0.1:{}{}", self.port, CALLBACK_PATH)
+    }
+
+    /// Waits for the redirect and returns the authorization code from it,
+    /// once the returned state is checked against `state`.
+    ///
+    /// Browsers also send requests for favicons and preconnects to the address, which are
+    /// answered with 404 until the redirect arrives or `ctx` is canceled or times out.
+    pub async fn receive_code(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        state: &CsrfToken,
+    ) -> joinerror::Result<String> {
+        context::abortable(ctx, async {
+            // Requests are read concurrently, since a preconnect may never send one
+            let mut requests = JoinSet::new();
+            loop {
+                tokio::select! {
+                    accepted = self.listener.accept() => {
+                        let (mut stream, _) = accepted.map_err(|e| {
+                            Error::new::<()>(format!("failed to accept connection: {}", e))
+                        })?;
+                        requests.spawn(async move {
+                            let url = read_request_url(&mut stream).await;
+                            (stream, url)
+                        });
+                    }
+                    Some(Ok((mut stream, url))) = requests.join_next() => match url {
+                        Ok(url) if url.path() == CALLBACK_PATH => {
+                            return receive_callback(stream, &url, state).await;
+                        }
+                        Ok(_) => {
+                            let _ = stream.write_all(NOT_FOUND_RESPONSE.as_bytes()).await;
+                        }
+                        Err(e) => tracing::debug!("ignoring loopback request: {}", e),
+                    },
+                }
+            }
+        })
+        .await
+        .join_err_bare()
+    }
+}
+
+async fn read_request_url(stream: &mut TcpStream) -> joinerror::Result<Url> {
+    let mut buf = String::new();
+    BufReader::new(stream)
+        .read_line(&mut buf)
+        .await
+        .map_err(|e| Error::new::<()>(format!("failed to read request: {}", e)))?;
+
+    let url_path = buf
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| Error::new::<()>("invalid HTTP request"))?;
+
+    Url::parse(&format!("http://localhost{}", url_path))
+        .map_err(|e| Error::new::<()>(format!("failed to parse URL: {}", e)))
+}
+
+async fn receive_callback(
+    mut stream: TcpStream,
+    url: &Url,
+    state: &CsrfToken,
+) -> joinerror::Result<String> {
+    let query_param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.to_string())
+    };
+
+    if let Some(error) = query_param("error") {
+        return Err(Error::new::<()>(format!("authorization failed: {}", error)));
+    }
+
+    let code =
+        query_param("code").ok_or_else(|| Error::new::<()>("authorization code not found"))?;
+    let returned_state =
+        query_param("state").ok_or_else(|| Error::new::<()>("state parameter not found"))?;
+
+    if state.secret() != &returned_state {
+        return Err(Error::new::<()>("state mismatch - possible CSRF attack"));
+    }
+
+    let response = "HTTP/1.1 200 OK\r\n\r\n<html><body><h1>Authorization successful!</h1><p>You can close this window.</p><script>window.close();</script></body></html>";
+    stream
+        .write_all(response.as_bytes())
+        .await
+        .map_err(|e| Error::new::<()>(format!("failed to send response: {}", e)))?;
+
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use sapic_core::context::ArcContext;
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+
+    async fn send(port: u16, path: &str) -> String {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        stream
+            .write_all(format!("GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n", path).as_bytes())
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_receive_code_ignores_other_requests() {
+        let listener = LoopbackListener::bind(0).await.unwrap();
+        let port = listener.port;
+        let state = CsrfToken::new("state".to_string());
+
+        let browser = tokio::spawn(async move {
+            // A preconnect that never sends its request must not hold up the callback
+            let _preconnect = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+            let favicon = send(port, "/favicon.ico").await;
+            let callback = send(port, "/oauth/callback?code=abc&state=state").await;
+            (favicon, callback)
+        });
+
+        let ctx = ArcContext::background_with_timeout(Duration::from_secs(10));
+        let code = listener.receive_code(&ctx, &state).await.unwrap();
+        assert_eq!(code, "abc");
+
+        let (favicon, callback) = browser.await.unwrap();
+        assert!(favicon.starts_with("HTTP/1.1 404"));
+        assert!(callback.starts_with("HTTP/1.1 200"));
+    }
+
+    #[tokio::test]
+    async fn test_receive_code_stops_on_timeout() {
+        let listener = LoopbackListener::bind(0).await.unwrap();
+        let state = CsrfToken::new("state".to_string());
+
+        let ctx = ArcContext::background_with_timeout(Duration::from_millis(100));
+        assert!(listener.receive_code(&ctx, &state).await.is_err());
+    }
+}
@@ -15,8 +15,8 @@ use sapic_core::context::AnyAsyncContext;
 use sapic_system::{
     project::{
         CloneProjectParams, CreateConfigParams, CreateProjectParams, ExportArchiveParams,
-        ImportArchivedProjectParams, ImportExternalProjectParams, LookedUpProject,
-        ProjectServiceFs as ProjectServiceFsPort,
+        ExportHarParams, ExportOpenApiParams, ImportArchivedProjectParams,
+        ImportExternalProjectParams, LookedUpProject, ProjectServiceFs as ProjectServiceFsPort,
     },
     user::account::Account,
 };
@@ -524,6 +524,27 @@ impl ProjectServiceFsPort for ProjectServiceFs {
         }
     }
 
+    async fn create_imported_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        rb: &mut Rollback,
+        id: &ProjectId,
+        params: CreateProjectParams,
+    ) -> joinerror::Result<PathBuf> {
+        let internal_abs_path = self.projects_dir.join(id.to_string());
+
+        if internal_abs_path.exists() {
+            return Err(joinerror::Error::new::<()>(format!(
+                "project directory `{}` already exists",
+                internal_abs_path.display()
+            )));
+        }
+
+        self.create_project_internal(ctx, rb, id, &params).await?;
+
+        Ok(internal_abs_path)
+    }
+
     async fn clone_project(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -637,6 +658,58 @@ impl ProjectServiceFsPort for ProjectServiceFs {
         Ok(())
     }
 
+    async fn export_har(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        params: ExportHarParams,
+    ) -> joinerror::Result<()> {
+        let internal_abs_path = self.projects_dir.join(id.to_string());
+        if params.har_path.starts_with(&internal_abs_path) {
+            bail!("cannot export har file into the project folder");
+        }
+
+        self.fs
+            .create_file_with(
+                ctx,
+                &params.har_path,
+                &params.content,
+                CreateOptions {
+                    overwrite: true,
+                    ignore_if_exists: false,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn export_openapi(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        params: ExportOpenApiParams,
+    ) -> joinerror::Result<()> {
+        let internal_abs_path = self.projects_dir.join(id.to_string());
+        if params.document_path.starts_with(&internal_abs_path) {
+            bail!("cannot export openapi document into the project folder");
+        }
+
+        self.fs
+            .create_file_with(
+                ctx,
+                &params.document_path,
+                &params.content,
+                CreateOptions {
+                    overwrite: true,
+                    ignore_if_exists: false,
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
     // FIXME: Should we delete the external project path as well?
     async fn delete_project(
         &self,
@@ -723,6 +796,38 @@ mod tests {
         tokio::fs::remove_dir_all(&internal_abs_path).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_create_imported_project_rollback() {
+        let (ctx, service_fs, test_path) = set_test_project_service_fs().await;
+        let id = ProjectId::new();
+
+        let mut rb = service_fs.fs.start_rollback(&ctx).await.unwrap();
+        let internal_abs_path = service_fs
+            .create_imported_project(
+                &ctx,
+                &mut rb,
+                &id,
+                CreateProjectParams {
+                    name: Some(random_string(10)),
+                    external_abs_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(internal_abs_path.join(MANIFEST_FILE_NAME).exists());
+
+        // Files written into the project afterwards are removed along with it
+        let resource_path = internal_abs_path.join(dirs::RESOURCES_DIR).join("imported");
+        tokio::fs::create_dir(&resource_path).await.unwrap();
+
+        rb.rollback().await.unwrap();
+        assert!(!internal_abs_path.exists());
+
+        tokio::fs::remove_dir_all(&test_path).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_create_project_no_name() {
         let (ctx, service_fs, test_path) = set_test_project_service_fs().await;
@@ -925,6 +1030,116 @@ mod tests {
         tokio::fs::remove_dir_all(&test_path).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_export_har() {
+        let (ctx, service_fs, test_path) = set_test_project_service_fs().await;
+        let id = ProjectId::new();
+
+        service_fs
+            .create_project(
+                &ctx,
+                &id,
+                CreateProjectParams {
+                    name: None,
+                    external_abs_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let har_path = test_path.join("log.har");
+        let content = br#"{"log":{"version":"1.2","entries":[]}}"#.to_vec();
+        service_fs
+            .export_har(
+                &ctx,
+                &id,
+                ExportHarParams {
+                    har_path: har_path.clone(),
+                    content: content.clone(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(tokio::fs::read(&har_path).await.unwrap(), content);
+
+        let inner_path = test_path
+            .join("projects")
+            .join(id.to_string())
+            .join("log.har");
+        assert!(
+            service_fs
+                .export_har(
+                    &ctx,
+                    &id,
+                    ExportHarParams {
+                        har_path: inner_path,
+                        content,
+                    },
+                )
+                .await
+                .is_err()
+        );
+        tokio::fs::remove_dir_all(&test_path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_export_openapi() {
+        let (ctx, service_fs, test_path) = set_test_project_service_fs().await;
+        let id = ProjectId::new();
+
+        service_fs
+            .create_project(
+                &ctx,
+                &id,
+                CreateProjectParams {
+                    name: None,
+                    external_abs_path: None,
+                    git_params: None,
+                    icon_path: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let document_path = test_path.join("api.openapi.json");
+        let content = br#"{"openapi":"3.1.0"}"#.to_vec();
+        service_fs
+            .export_openapi(
+                &ctx,
+                &id,
+                ExportOpenApiParams {
+                    document_path: document_path.clone(),
+                    content: content.clone(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(tokio::fs::read(&document_path).await.unwrap(), content);
+
+        let inner_path = test_path
+            .join("projects")
+            .join(id.to_string())
+            .join("api.openapi.json");
+        assert!(
+            service_fs
+                .export_openapi(
+                    &ctx,
+                    &id,
+                    ExportOpenApiParams {
+                        document_path: inner_path,
+                        content,
+                    },
+                )
+                .await
+                .is_err()
+        );
+        tokio::fs::remove_dir_all(&test_path).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_import_project_external() {
         let (ctx, service_fs, test_path) = set_test_project_service_fs().await;
@@ -2,6 +2,7 @@ pub mod project_edit_service;
 pub mod project_service;
 
 use async_trait::async_trait;
+use atomic_fs::Rollback;
 use moss_git::{repository::Repository, url::GitUrl};
 use sapic_base::{
     other::GitProviderKind,
@@ -53,6 +54,18 @@ pub struct ExportArchiveParams {
     pub archive_path: PathBuf,
 }
 
+pub struct ExportHarParams {
+    pub har_path: PathBuf,
+    /// The serialized HAR log.
+    pub content: Vec<u8>,
+}
+
+pub struct ExportOpenApiParams {
+    pub document_path: PathBuf,
+    /// The serialized OpenAPI document.
+    pub content: Vec<u8>,
+}
+
 pub struct LookedUpProject {
     pub id: ProjectId,
     pub abs_path: PathBuf,
@@ -95,6 +108,16 @@ pub trait ProjectServiceFs: Send + Sync {
         params: CreateProjectParams,
     ) -> joinerror::Result<PathBuf>;
 
+    /// Creates the project files under the rollback of the caller, which also
+    /// removes whatever is written into the project before it is rolled back.
+    async fn create_imported_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        rb: &mut Rollback,
+        id: &ProjectId,
+        params: CreateProjectParams,
+    ) -> joinerror::Result<PathBuf>;
+
     async fn clone_project(
         &self,
         ctx: &dyn AnyAsyncContext,
@@ -124,6 +147,20 @@ pub trait ProjectServiceFs: Send + Sync {
         params: ExportArchiveParams,
     ) -> joinerror::Result<()>;
 
+    async fn export_har(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        params: ExportHarParams,
+    ) -> joinerror::Result<()>;
+
+    async fn export_openapi(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        params: ExportOpenApiParams,
+    ) -> joinerror::Result<()>;
+
     async fn delete_project(
         &self,
         ctx: &dyn AnyAsyncContext,
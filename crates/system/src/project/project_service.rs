@@ -1,3 +1,4 @@
+use atomic_fs::Rollback;
 use joinerror::ResultExt;
 use moss_fs::FileSystem;
 use moss_git::{repository::Repository, url::GitUrl};
@@ -15,8 +16,8 @@ use std::{
 use crate::{
     project::{
         CloneProjectGitParams, CloneProjectParams, CreateProjectGitParams, CreateProjectParams,
-        ExportArchiveParams, ImportArchivedProjectParams, ImportExternalProjectParams,
-        ProjectServiceFs,
+        ExportArchiveParams, ExportHarParams, ExportOpenApiParams, ImportArchivedProjectParams,
+        ImportExternalProjectParams, ProjectServiceFs,
     },
     user::account::Account,
 };
@@ -83,6 +84,43 @@ impl ProjectService {
         })
     }
 
+    /// Creates an empty project to import resources into. Its files are removed
+    /// along with everything imported if `rb` is rolled back.
+    pub async fn create_imported_project(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        rb: &mut Rollback,
+        name: String,
+        icon_path: Option<PathBuf>,
+    ) -> joinerror::Result<ProjectItem> {
+        let id = ProjectId::new();
+        let internal_abs_path = self
+            .backend
+            .create_imported_project(
+                ctx,
+                rb,
+                &id,
+                CreateProjectParams {
+                    name: Some(name),
+                    external_abs_path: None,
+                    git_params: None,
+                    icon_path,
+                },
+            )
+            .await
+            .join_err::<()>("failed to create project")?;
+
+        let manifest = self.backend.read_project_manifest(ctx, &id).await?;
+        let config = self.backend.read_project_config(ctx, &id).await?;
+
+        Ok(ProjectItem {
+            id,
+            internal_abs_path,
+            manifest,
+            config,
+        })
+    }
+
     // FIXME: I think repo cloning is at the same level as fs operations, handled by platform backends
     // However, we also need to Repository handle when building the Project
     // Not sure if there's a better way than passing the repository from here
@@ -218,6 +256,51 @@ impl ProjectService {
         Ok(archive_path)
     }
 
+    pub async fn export_har(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        destination: &Path,
+        content: Vec<u8>,
+    ) -> joinerror::Result<PathBuf> {
+        let har_path = destination.join(format!("{}.har", id));
+        self.backend
+            .export_har(
+                ctx,
+                id,
+                ExportHarParams {
+                    har_path: har_path.clone(),
+                    content,
+                },
+            )
+            .await?;
+
+        Ok(har_path)
+    }
+
+    pub async fn export_openapi(
+        &self,
+        ctx: &dyn AnyAsyncContext,
+        id: &ProjectId,
+        destination: &Path,
+        extension: &str,
+        content: Vec<u8>,
+    ) -> joinerror::Result<PathBuf> {
+        let document_path = destination.join(format!("{}.openapi.{}", id, extension));
+        self.backend
+            .export_openapi(
+                ctx,
+                id,
+                ExportOpenApiParams {
+                    document_path: document_path.clone(),
+                    content,
+                },
+            )
+            .await?;
+
+        Ok(document_path)
+    }
+
     // FIXME: I'm not sure why ProjectItem requires Manifest and Config
     // In WorkspaceService::workspaces we don't need them
     // I'll keep them for now and if needed we can change it
@@ -4,12 +4,11 @@ use tauri::{
 };
 use tracing::instrument;
 
-use crate::{
-    models::operations::{ParseUrlInput, ParseUrlOutput},
-    parser::UrlParser,
-};
+use crate::models::operations::{ParseUrlInput, ParseUrlOutput};
 mod parser;
 
+pub use parser::UrlParser;
+
 pub mod models;
 
 pub fn init<R: Runtime>() -> TauriPlugin<R> {
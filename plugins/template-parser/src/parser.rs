@@ -21,6 +21,7 @@ impl UrlParser {
         let mut path_part = Vec::new();
         let mut query_part = Vec::new();
         let mut fragment_part = None::<ValueList>;
+        let mut host_trailing_slash = false;
 
         for part in url.into_inner() {
             let rule = part.as_rule();
@@ -29,6 +30,7 @@ impl UrlParser {
                     scheme_part = parse_scheme_part(part)?;
                 }
                 Rule::host_part => {
+                    host_trailing_slash = part.as_str().ends_with('/');
                     host_part = parse_host_part(part)?;
                 }
                 Rule::path_part => {
@@ -52,7 +54,7 @@ impl UrlParser {
         }
 
         raw.extend(host_part.iter().cloned());
-        if path_part.len() > 0 {
+        if host_trailing_slash {
             raw.push(ParsedValue::String("/".to_string()));
             raw.extend(path_part.iter().cloned());
         }
@@ -91,6 +93,26 @@ impl UrlParser {
             raw,
         })
     }
+
+    /// Splits free-form text into literal strings and `{{var}}` variables.
+    pub fn parse_template(text: &str) -> Result<ValueList> {
+        let template = UrlParser::parse(Rule::template, text)
+            .map_err(|e| Error::new::<()>(format!("failed to parse template: {}", e)))?
+            .next()
+            .ok_or_join_err::<()>("no template is matched")?;
+
+        let mut values = Vec::new();
+        for pair in template.into_inner() {
+            let value = match pair.as_rule() {
+                Rule::var => parse_var(pair)?,
+                Rule::template_raw => ParsedValue::String(pair.as_str().to_string()),
+                _ => continue,
+            };
+            values.push(value);
+        }
+
+        Ok(values)
+    }
 }
 
 // {{ident}}
@@ -131,7 +153,7 @@ fn parse_host_part(host: Pair<Rule>) -> Result<ValueList> {
     for pair in host.into_inner() {
         let part = match pair.as_rule() {
             Rule::var => parse_var(pair)?,
-            Rule::host_raw => ParsedValue::String(pair.as_str().to_string()),
+            Rule::host_raw | Rule::port => ParsedValue::String(pair.as_str().to_string()),
             _ => bail!("Invalid host part: `{}`", pair.as_str()),
         };
         host_parts.push(part);
@@ -224,7 +246,21 @@ fn parse_fragment_part(part: Pair<Rule>) -> Result<Option<ValueList>> {
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::UrlParser;
+    use crate::{
+        models::types::{ParsedValue, ValueList},
+        parser::UrlParser,
+    };
+
+    fn render(values: &ValueList) -> String {
+        values
+            .iter()
+            .map(|value| match value {
+                ParsedValue::String(s) => s.clone(),
+                ParsedValue::Variable(name) => format!("{{{{{name}}}}}"),
+                ParsedValue::PathVariable(name) => format!(":{name}"),
+            })
+            .collect()
+    }
 
     #[test]
     fn it_works() {
@@ -242,6 +278,45 @@ mod tests {
         dbg!(&result);
     }
 
+    #[test]
+    fn port() {
+        let input = "http://localhost:8080/users/:id";
+
+        let result = UrlParser::parse_url(input).unwrap();
+        assert_eq!(
+            result.host_part,
+            vec![
+                ParsedValue::String("localhost".to_string()),
+                ParsedValue::String(":8080".to_string()),
+            ]
+        );
+        assert_eq!(render(&result.raw), input);
+    }
+
+    #[test]
+    fn raw_keeps_trailing_slash() {
+        let input = "https://{{host}}/";
+
+        let result = UrlParser::parse_url(input).unwrap();
+        assert_eq!(render(&result.raw), input);
+    }
+
+    #[test]
+    fn template() {
+        let input = "Bearer {{token}} {{ not_a_var }} {{";
+
+        let result = UrlParser::parse_template(input).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                ParsedValue::String("Bearer ".to_string()),
+                ParsedValue::Variable("token".to_string()),
+                ParsedValue::String(" {{ not_a_var }} {{".to_string()),
+            ]
+        );
+        assert!(UrlParser::parse_template("").unwrap().is_empty());
+    }
+
     // #[test]
     // fn example() {
     //     let input = "https://{{var1}}.example.com/:var2/api/users?limit=10&sort={{var3}}#top";
@@ -209,10 +209,55 @@ where
         .as_ref()
         .and_then(|opts| opts.timeout.map(Duration::from_secs))
         .unwrap_or(DEFAULT_OPERATION_TIMEOUT);
+
+    run_in_main_window(ctx, app, window, options, Some(timeout), f).await
+}
+
+/// Runs an operation that lasts until it's canceled, such as a server or a subscription,
+/// so that it's only timed out when the options ask for it. It's stopped by canceling
+/// its request.
+pub(super) async fn with_main_window<R, T, F, Fut>(
+    ctx: &R::AsyncContext,
+    app: State<'_, Arc<sapic_app::App<R>>>,
+    window: TauriWindow<R::EventLoop>,
+    options: Options,
+    f: F,
+) -> joinerror::Result<T>
+where
+    R: AppRuntime<AsyncContext = ArcContext>,
+    F: FnOnce(R::AsyncContext, Arc<sapic_app::App<R>>, AppDelegate<R>, MainWindow<R>) -> Fut
+        + Send
+        + 'static,
+    Fut: std::future::Future<Output = joinerror::Result<T>> + Send + 'static,
+{
+    let timeout = options
+        .as_ref()
+        .and_then(|opts| opts.timeout.map(Duration::from_secs));
+
+    run_in_main_window(ctx, app, window, options, timeout, f).await
+}
+
+async fn run_in_main_window<R, T, F, Fut>(
+    ctx: &R::AsyncContext,
+    app: State<'_, Arc<sapic_app::App<R>>>,
+    window: TauriWindow<R::EventLoop>,
+    options: Options,
+    timeout: Option<Duration>,
+    f: F,
+) -> joinerror::Result<T>
+where
+    R: AppRuntime<AsyncContext = ArcContext>,
+    F: FnOnce(R::AsyncContext, Arc<sapic_app::App<R>>, AppDelegate<R>, MainWindow<R>) -> Fut
+        + Send
+        + 'static,
+    Fut: std::future::Future<Output = joinerror::Result<T>> + Send + 'static,
+{
     let request_id = options.and_then(|opts| opts.request_id);
-    let mut builder = ContextBuilder::new()
-        .with_parent(ctx.clone())
-        .with_timeout(timeout);
+    let mut builder = ContextBuilder::new().with_parent(ctx.clone());
+
+    if let Some(timeout) = timeout {
+        builder = builder.with_timeout(timeout);
+    }
 
     if let Some(ref request_id) = request_id {
         builder = builder.with_value("request_id", request_id.clone());
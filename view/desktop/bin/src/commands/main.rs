@@ -1,10 +1,13 @@
 use joinerror::{OptionExt, ResultExt};
 use sapic_ipc::contracts::{
-    main::{OpenInTarget, environment::*, project::*, resource::*, workspace::*},
+    main::{
+        OpenInTarget, environment::*, graphql::*, grpc::*, mock::*, project::*, proxy::*, queue::*,
+        resource::*, runner::*, sse::*, websocket::*, workspace::*,
+    },
     other::CancelRequestInput,
 };
 use sapic_runtime::errors::Unavailable;
-use tauri::Window as TauriWindow;
+use tauri::{Window as TauriWindow, ipc::Channel as TauriChannel};
 
 use crate::commands::primitives::*;
 
@@ -248,6 +251,538 @@ pub async fn main__list_project_resources<'a, R: tauri::Runtime>(
     )
     .await
 }
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__execute_resource<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<ExecuteResourceEvent>,
+    input: ExecuteResourceInput,
+    options: Options,
+) -> joinerror::Result<ExecuteResourceOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .execute_resource(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__resolve_resource<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ResolveResourceInput,
+    options: Options,
+) -> joinerror::Result<ResolveResourceOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window.resolve_resource(&ctx, &app_delegate, input).await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__export_http_file<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ExportHttpFileInput,
+    options: Options,
+) -> joinerror::Result<ExportHttpFileOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window.export_http_file(&ctx, &app_delegate, &input).await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__import_curl<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ImportCurlInput,
+    options: Options,
+) -> joinerror::Result<ImportCurlOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, _, window| async move { window.import_curl(&ctx, &input).await },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__generate_snippet<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: GenerateSnippetInput,
+    options: Options,
+) -> joinerror::Result<GenerateSnippetOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window.generate_snippet(&ctx, &app_delegate, &input).await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__start_mock_server<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<MockServerEvent>,
+    input: StartMockServerInput,
+    options: Options,
+) -> joinerror::Result<StartMockServerOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .start_mock_server(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__start_recording_proxy<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<RecordingProxyEvent>,
+    input: StartRecordingProxyInput,
+    options: Options,
+) -> joinerror::Result<StartRecordingProxyOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .start_recording_proxy(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__list_recorded_exchanges<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    options: Options,
+) -> joinerror::Result<ListRecordedExchangesOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |_, _, _, window| async move { window.list_recorded_exchanges().await },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__promote_recorded_exchanges<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: PromoteRecordedExchangesInput,
+    options: Options,
+) -> joinerror::Result<PromoteRecordedExchangesOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .promote_recorded_exchanges(&ctx, &app_delegate, &input)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__run_collection<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: RunCollectionInput,
+    options: Options,
+) -> joinerror::Result<RunCollectionOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window.run_collection(&ctx, &app_delegate, &input).await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__connect_websocket<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<WebSocketEvent>,
+    input: ConnectWebSocketInput,
+    options: Options,
+) -> joinerror::Result<ConnectWebSocketOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .connect_websocket(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__send_websocket_message<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: SendWebSocketMessageInput,
+    options: Options,
+) -> joinerror::Result<SendWebSocketMessageOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .send_websocket_message(&ctx, &app_delegate, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__close_websocket<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: CloseWebSocketInput,
+    options: Options,
+) -> joinerror::Result<CloseWebSocketOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, _, window| async move { window.close_websocket(&ctx, input).await },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__list_websocket_frames<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ListWebSocketFramesInput,
+    options: Options,
+) -> joinerror::Result<ListWebSocketFramesOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |_, _, _, window| async move { window.list_websocket_frames(input).await },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__introspect_graphql_schema<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: IntrospectGraphqlSchemaInput,
+    options: Options,
+) -> joinerror::Result<IntrospectGraphqlSchemaOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .introspect_graphql_schema(&ctx, &app_delegate, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__validate_graphql_operation<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ValidateGraphqlOperationInput,
+    options: Options,
+) -> joinerror::Result<ValidateGraphqlOperationOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .validate_graphql_operation(&ctx, &app_delegate, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__execute_graphql<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<GraphqlEvent>,
+    input: ExecuteGraphqlInput,
+    options: Options,
+) -> joinerror::Result<ExecuteGraphqlOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .execute_graphql(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__list_grpc_services<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ListGrpcServicesInput,
+    options: Options,
+) -> joinerror::Result<ListGrpcServicesOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window.list_grpc_services(&ctx, &app_delegate, input).await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__execute_grpc<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<GrpcEvent>,
+    input: ExecuteGrpcInput,
+    options: Options,
+) -> joinerror::Result<ExecuteGrpcOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .execute_grpc(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__execute_sse<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<SseEvent>,
+    input: ExecuteSseInput,
+    options: Options,
+) -> joinerror::Result<ExecuteSseOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .execute_sse(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__list_sse_events<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: ListSseEventsInput,
+    options: Options,
+) -> joinerror::Result<ListSseEventsOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window.list_sse_events(&ctx, &app_delegate, input).await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn main__publish_queue_message<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    input: PublishQueueMessageInput,
+    options: Options,
+) -> joinerror::Result<PublishQueueMessageOutput> {
+    super::with_main_window_timeout(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .publish_queue_message(&ctx, &app_delegate, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[allow(non_snake_case)]
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label(), channel = channel.id()))]
+pub async fn main__subscribe_queue<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: TauriWindow<R>,
+    channel: TauriChannel<QueueEvent>,
+    input: SubscribeQueueInput,
+    options: Options,
+) -> joinerror::Result<SubscribeQueueOutput> {
+    super::with_main_window(
+        ctx.inner(),
+        app,
+        window,
+        options,
+        |ctx, _, app_delegate, window| async move {
+            window
+                .subscribe_queue(&ctx, &app_delegate, input, channel)
+                .await
+        },
+    )
+    .await
+}
+
 #[allow(non_snake_case)]
 #[tauri::command(async)]
 #[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
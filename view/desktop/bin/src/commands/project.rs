@@ -155,6 +155,131 @@ pub async fn describe_project_resource<'a, R: tauri::Runtime>(
     .await
 }
 
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn validate_project_resource_body<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: Window<R>,
+    project_id: ProjectId,
+    input: ValidateResourceBodyInput,
+    options: Options,
+) -> joinerror::Result<ValidateResourceBodyOutput> {
+    super::with_project_timeout(
+        ctx.inner(),
+        app,
+        window,
+        project_id,
+        options,
+        |ctx, _, project| async move {
+            project
+                .validate_resource_body::<TauriAppRuntime<R>>(&ctx, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn create_project_resource_case<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: Window<R>,
+    project_id: ProjectId,
+    input: CreateResourceCaseInput,
+    options: Options,
+) -> joinerror::Result<CreateResourceCaseOutput> {
+    super::with_project_timeout(
+        ctx.inner(),
+        app,
+        window,
+        project_id,
+        options,
+        |ctx, _, project| async move {
+            project
+                .create_resource_case::<TauriAppRuntime<R>>(&ctx, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn update_project_resource_case<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: Window<R>,
+    project_id: ProjectId,
+    input: UpdateResourceCaseInput,
+    options: Options,
+) -> joinerror::Result<UpdateResourceCaseOutput> {
+    super::with_project_timeout(
+        ctx.inner(),
+        app,
+        window,
+        project_id,
+        options,
+        |ctx, _, project| async move {
+            project
+                .update_resource_case::<TauriAppRuntime<R>>(&ctx, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn delete_project_resource_case<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: Window<R>,
+    project_id: ProjectId,
+    input: DeleteResourceCaseInput,
+    options: Options,
+) -> joinerror::Result<DeleteResourceCaseOutput> {
+    super::with_project_timeout(
+        ctx.inner(),
+        app,
+        window,
+        project_id,
+        options,
+        |ctx, _, project| async move {
+            project
+                .delete_resource_case::<TauriAppRuntime<R>>(&ctx, input)
+                .await
+        },
+    )
+    .await
+}
+
+#[tauri::command(async)]
+#[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
+pub async fn list_project_resource_cases<'a, R: tauri::Runtime>(
+    ctx: AsyncContext<'a>,
+    app: App<'a, R>,
+    window: Window<R>,
+    project_id: ProjectId,
+    input: ListResourceCasesInput,
+    options: Options,
+) -> joinerror::Result<ListResourceCasesOutput> {
+    super::with_project_timeout(
+        ctx.inner(),
+        app,
+        window,
+        project_id,
+        options,
+        |ctx, app_delegate, project| async move {
+            project
+                .list_resource_cases(&ctx, &app_delegate, input)
+                .await
+        },
+    )
+    .await
+}
+
 #[tauri::command(async)]
 #[instrument(level = "trace", skip(ctx, app), fields(window = window.label()))]
 pub async fn execute_vcs_operation<'a, R: tauri::Runtime>(
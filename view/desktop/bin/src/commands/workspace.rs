@@ -58,7 +58,9 @@ pub async fn export_project<'a, R: tauri::Runtime>(
         app,
         window,
         options,
-        |ctx, _, _, window| async move { window.export_project(&ctx, &input).await },
+        |ctx, _, app_delegate, window| async move {
+            window.export_project(&ctx, &app_delegate, &input).await
+        },
     )
     .await
 }
@@ -241,7 +241,7 @@ pub async fn run<R: TauriRuntime>() {
                     .with_command(shortcut_println_command)
                     .with_command(shortcut_alert_command)
                     .build(&app_init_ctx, &delegate)
-                    .await;
+                    .await?;
 
                     app
                 };
@@ -282,6 +282,29 @@ pub async fn run<R: TauriRuntime>() {
             commands::main__list_workspace_environments,
             commands::main__list_project_environments,
             commands::main__list_project_resources,
+            commands::main__execute_resource,
+            commands::main__resolve_resource,
+            commands::main__export_http_file,
+            commands::main__import_curl,
+            commands::main__generate_snippet,
+            commands::main__start_mock_server,
+            commands::main__start_recording_proxy,
+            commands::main__list_recorded_exchanges,
+            commands::main__promote_recorded_exchanges,
+            commands::main__run_collection,
+            commands::main__connect_websocket,
+            commands::main__send_websocket_message,
+            commands::main__close_websocket,
+            commands::main__list_websocket_frames,
+            commands::main__introspect_graphql_schema,
+            commands::main__validate_graphql_operation,
+            commands::main__execute_graphql,
+            commands::main__list_grpc_services,
+            commands::main__execute_grpc,
+            commands::main__execute_sse,
+            commands::main__list_sse_events,
+            commands::main__publish_queue_message,
+            commands::main__subscribe_queue,
             //
             // Onboarding
             //
@@ -321,6 +344,11 @@ pub async fn run<R: TauriRuntime>() {
             commands::describe_project_resource,
             commands::batch_update_project_resource,
             commands::batch_create_project_resource,
+            commands::create_project_resource_case,
+            commands::update_project_resource_case,
+            commands::delete_project_resource_case,
+            commands::list_project_resource_cases,
+            commands::validate_project_resource_body,
             commands::execute_vcs_operation,
             //
             // Env